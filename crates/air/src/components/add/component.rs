@@ -1,10 +1,15 @@
-use crate::components::{AddClaim, NodeElements};
+use crate::{
+    components::{AddClaim, NodeElements},
+    preprocessed::eval_structure,
+};
 use num_traits::One;
 use numerair::eval::EvalFixedPoint;
 use stwo_prover::constraint_framework::{
-    EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry,
+    EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry, ORIGINAL_TRACE_IDX,
 };
 
+use super::table::AddTraceTableRow;
+
 /// The STWO AIR component for element-wise addition operations.
 ///
 /// This wraps the `AddEval` logic within the STWO `FrameworkComponent`,
@@ -50,24 +55,26 @@ impl FrameworkEval for AddEval {
     /// Evaluates the Add AIR constraints on a given evaluation point (`eval`).
     ///
     /// Defines constraints ensuring:
+    /// - **Structural:** The IDs, indices, flags and multiplicities match the preprocessed trace.
     /// - **Consistency:** Correctness of individual rows (e.g., `lhs + rhs == out`, boolean flags).
     /// - **Transition:** Correctness of transitions between consecutive rows (e.g., index increments).
     /// - **Interaction (LogUp):** Links values used/produced by Add operations to the global LogUp argument,
     ///   ensuring consistency across the entire computation trace.
     fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
-        // IDs
-        let node_id = eval.next_trace_mask(); // ID of the node in the computational graph.
-        let lhs_id = eval.next_trace_mask(); // ID of first input tensor.
-        let rhs_id = eval.next_trace_mask(); // ID of second input tensor.
-        let idx = eval.next_trace_mask(); // Index in the flattened tensor.
+        // IDs (current and next row, for transition constraints)
+        // ID of the node in the computational graph.
+        let [node_id, next_node_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of first input tensor.
+        let [lhs_id, next_lhs_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of second input tensor.
+        let [rhs_id, next_rhs_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Index in the flattened tensor.
+        let [idx, next_idx] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Positions of the input values within their tensors.
+        let lhs_idx = eval.next_trace_mask();
+        let rhs_idx = eval.next_trace_mask();
         let is_last_idx = eval.next_trace_mask(); // Flag if this is the last index for this operation.
 
-        // Next IDs for transition constraints
-        let next_node_id = eval.next_trace_mask();
-        let next_lhs_id = eval.next_trace_mask();
-        let next_rhs_id = eval.next_trace_mask();
-        let next_idx = eval.next_trace_mask();
-
         // Values for consistency constraints
         let lhs_val = eval.next_trace_mask(); // Value from first tensor at index.
         let rhs_val = eval.next_trace_mask(); // Value from second tensor at index.
@@ -78,6 +85,28 @@ impl FrameworkEval for AddEval {
         let rhs_mult = eval.next_trace_mask();
         let out_mult = eval.next_trace_mask();
 
+        // ┌─────────────────────────────┐
+        // │   Structural Constraints    │
+        // └─────────────────────────────┘
+
+        // The IDs, indices, flags and multiplicities are the ones fixed by the graph.
+        eval_structure::<_, AddTraceTableRow>(
+            &mut eval,
+            "add",
+            vec![
+                node_id.clone(),
+                lhs_id.clone(),
+                rhs_id.clone(),
+                idx.clone(),
+                lhs_idx.clone(),
+                rhs_idx.clone(),
+                is_last_idx.clone(),
+                lhs_mult.clone(),
+                rhs_mult.clone(),
+                out_mult.clone(),
+            ],
+        );
+
         // ┌─────────────────────────────┐
         // │   Consistency Constraints   │
         // └─────────────────────────────┘
@@ -105,7 +134,7 @@ impl FrameworkEval for AddEval {
         eval.add_constraint(not_last.clone() * (next_rhs_id - rhs_id.clone()));
 
         // Index increment by 1
-        eval.add_constraint(not_last * (next_idx - idx - E::F::one()));
+        eval.add_constraint(not_last * (next_idx - idx.clone() - E::F::one()));
 
         // ┌─────────────────────────────┐
         // │   Interaction Constraints   │
@@ -114,19 +143,19 @@ impl FrameworkEval for AddEval {
         eval.add_to_relation(RelationEntry::new(
             &self.node_elements,
             lhs_mult.into(),
-            &[lhs_val, lhs_id],
+            &[lhs_id, lhs_idx, lhs_val],
         ));
 
         eval.add_to_relation(RelationEntry::new(
             &self.node_elements,
             rhs_mult.into(),
-            &[rhs_val, rhs_id],
+            &[rhs_id, rhs_idx, rhs_val],
         ));
 
         eval.add_to_relation(RelationEntry::new(
             &self.node_elements,
             out_mult.into(),
-            &[out_val, node_id],
+            &[node_id, idx, out_val],
         ));
 
         eval.finalize_logup();
@@ -8,7 +8,7 @@ use stwo_prover::core::{
     fields::m31::M31,
 };
 
-use crate::components::TraceColumn;
+use crate::{components::TraceColumn, preprocessed::StructuredRow};
 
 use super::witness::N_TRACE_COLUMNS;
 
@@ -26,7 +26,7 @@ pub struct AddTraceTable {
 /// Represents a single row in the `AddTraceTable`.
 ///
 /// Contains all the necessary values for evaluating the Add AIR constraints,
-/// including current/next state IDs, input/output values, and LogUp multiplicities.
+/// including state IDs, input/output values, and LogUp multiplicities.
 #[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
 pub struct AddTraceTableRow {
     /// ID of the current Add node.
@@ -37,16 +37,12 @@ pub struct AddTraceTableRow {
     pub rhs_id: M31,
     /// Index within the tensor for this operation.
     pub idx: M31,
+    /// Position of the LHS value within the left-hand side input tensor.
+    pub lhs_idx: M31,
+    /// Position of the RHS value within the right-hand side input tensor.
+    pub rhs_idx: M31,
     /// Flag indicating if this is the last element processed for this node (1 if true, 0 otherwise).
     pub is_last_idx: M31,
-    /// ID of the *next* Add node processed in the trace (often the same as `node_id`).
-    pub next_node_id: M31,
-    /// ID of the *next* LHS provider node (often the same as `lhs_id`).
-    pub next_lhs_id: M31,
-    /// ID of the *next* RHS provider node (often the same as `rhs_id`).
-    pub next_rhs_id: M31,
-    /// Index of the *next* element processed (often `idx + 1`).
-    pub next_idx: M31,
     /// Value of the left-hand side input.
     pub lhs: M31,
     /// Value of the right-hand side input.
@@ -71,11 +67,9 @@ impl AddTraceTableRow {
             lhs_id: M31::zero(),
             rhs_id: M31::zero(),
             idx: M31::zero(),
+            lhs_idx: M31::zero(),
+            rhs_idx: M31::zero(),
             is_last_idx: M31::one(),
-            next_node_id: M31::zero(),
-            next_lhs_id: M31::zero(),
-            next_rhs_id: M31::zero(),
-            next_idx: M31::zero(),
             lhs: M31::zero(),
             rhs: M31::zero(),
             out: M31::zero(),
@@ -86,6 +80,39 @@ impl AddTraceTableRow {
     }
 }
 
+/// Implements the `StructuredRow` trait for `AddTraceTableRow`.
+impl StructuredRow for AddTraceTableRow {
+    /// IDs, indices, flags and multiplicities of the row, fixed by the graph.
+    const STRUCTURE: &'static [&'static str] = &[
+        "node_id",
+        "lhs_id",
+        "rhs_id",
+        "idx",
+        "lhs_idx",
+        "rhs_idx",
+        "is_last_idx",
+        "lhs_mult",
+        "rhs_mult",
+        "out_mult",
+    ];
+
+    /// Returns the values of the structural columns of the row.
+    fn structure(&self) -> Vec<M31> {
+        vec![
+            self.node_id,
+            self.lhs_id,
+            self.rhs_id,
+            self.idx,
+            self.lhs_idx,
+            self.rhs_idx,
+            self.is_last_idx,
+            self.lhs_mult,
+            self.rhs_mult,
+            self.out_mult,
+        ]
+    }
+}
+
 /// SIMD-packed representation of an `AddTraceTableRow`.
 /// Holds `N_LANES` rows packed into SIMD registers for efficient processing.
 #[derive(Debug, Copy, Clone)]
@@ -98,16 +125,12 @@ pub struct PackedAddTraceTableRow {
     pub rhs_id: PackedM31,
     /// Packed `idx` values.
     pub idx: PackedM31,
+    /// Packed `lhs_idx` values.
+    pub lhs_idx: PackedM31,
+    /// Packed `rhs_idx` values.
+    pub rhs_idx: PackedM31,
     /// Packed `is_last_idx` values.
     pub is_last_idx: PackedM31,
-    /// Packed `next_node_id` values.
-    pub next_node_id: PackedM31,
-    /// Packed `next_lhs_id` values.
-    pub next_lhs_id: PackedM31,
-    /// Packed `next_rhs_id` values.
-    pub next_rhs_id: PackedM31,
-    /// Packed `next_idx` values.
-    pub next_idx: PackedM31,
     /// Packed `lhs` values.
     pub lhs: PackedM31,
     /// Packed `rhs` values.
@@ -131,11 +154,9 @@ impl Pack for AddTraceTableRow {
             lhs_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs_id)),
             rhs_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs_id)),
             idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].idx)),
+            lhs_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs_idx)),
+            rhs_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs_idx)),
             is_last_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_idx)),
-            next_node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_node_id)),
-            next_lhs_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_lhs_id)),
-            next_rhs_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_rhs_id)),
-            next_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_idx)),
             lhs: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs)),
             rhs: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs)),
             out: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out)),
@@ -155,11 +176,9 @@ impl Unpack for PackedAddTraceTableRow {
             lhs_id,
             rhs_id,
             idx,
+            lhs_idx,
+            rhs_idx,
             is_last_idx,
-            next_node_id,
-            next_lhs_id,
-            next_rhs_id,
-            next_idx,
             lhs,
             rhs,
             out,
@@ -171,11 +190,9 @@ impl Unpack for PackedAddTraceTableRow {
             self.lhs_id.to_array(),
             self.rhs_id.to_array(),
             self.idx.to_array(),
+            self.lhs_idx.to_array(),
+            self.rhs_idx.to_array(),
             self.is_last_idx.to_array(),
-            self.next_node_id.to_array(),
-            self.next_lhs_id.to_array(),
-            self.next_rhs_id.to_array(),
-            self.next_idx.to_array(),
             self.lhs.to_array(),
             self.rhs.to_array(),
             self.out.to_array(),
@@ -189,11 +206,9 @@ impl Unpack for PackedAddTraceTableRow {
             lhs_id: lhs_id[i],
             rhs_id: rhs_id[i],
             idx: idx[i],
+            lhs_idx: lhs_idx[i],
+            rhs_idx: rhs_idx[i],
             is_last_idx: is_last_idx[i],
-            next_node_id: next_node_id[i],
-            next_lhs_id: next_lhs_id[i],
-            next_rhs_id: next_rhs_id[i],
-            next_idx: next_idx[i],
             lhs: lhs[i],
             rhs: rhs[i],
             out: out[i],
@@ -228,16 +243,12 @@ pub enum AddColumn {
     RhsId,
     /// Index within the tensor for this operation.
     Idx,
+    /// Position of the LHS value within the left-hand side input tensor.
+    LhsIdx,
+    /// Position of the RHS value within the right-hand side input tensor.
+    RhsIdx,
     /// Flag indicating if this is the last element processed for this node.
     IsLastIdx,
-    /// ID of the *next* Add node processed in the trace.
-    NextNodeId,
-    /// ID of the *next* LHS provider node.
-    NextLhsId,
-    /// ID of the *next* RHS provider node.
-    NextRhsId,
-    /// Index of the *next* element processed.
-    NextIdx,
     /// Value of the left-hand side input.
     Lhs,
     /// Value of the right-hand side input.
@@ -260,17 +271,15 @@ impl AddColumn {
             Self::LhsId => 1,
             Self::RhsId => 2,
             Self::Idx => 3,
-            Self::IsLastIdx => 4,
-            Self::NextNodeId => 5,
-            Self::NextLhsId => 6,
-            Self::NextRhsId => 7,
-            Self::NextIdx => 8,
-            Self::Lhs => 9,
-            Self::Rhs => 10,
-            Self::Out => 11,
-            Self::LhsMult => 12,
-            Self::RhsMult => 13,
-            Self::OutMult => 14,
+            Self::LhsIdx => 4,
+            Self::RhsIdx => 5,
+            Self::IsLastIdx => 6,
+            Self::Lhs => 7,
+            Self::Rhs => 8,
+            Self::Out => 9,
+            Self::LhsMult => 10,
+            Self::RhsMult => 11,
+            Self::OutMult => 12,
         }
     }
 }
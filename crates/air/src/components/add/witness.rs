@@ -16,13 +16,13 @@ use crate::{
         add::table::{AddColumn, AddTraceTableRow},
         AddClaim, InteractionClaim, NodeElements,
     },
-    utils::{pack_values, TreeBuilder},
+    utils::{pack_values, to_trace_order, TreeBuilder},
 };
 
 use super::table::{AddTraceTable, PackedAddTraceTableRow};
 
 /// Number of main trace columns for the Add component.
-pub(crate) const N_TRACE_COLUMNS: usize = 15;
+pub(crate) const N_TRACE_COLUMNS: usize = 13;
 
 /// Generates the main trace columns and initial data for interaction claims for the Add component.
 ///
@@ -63,7 +63,7 @@ impl ClaimGenerator {
         let log_size = size.ilog2();
 
         self.inputs.table.resize(size, AddTraceTableRow::padding());
-        let packed_inputs = pack_values(&self.inputs.table);
+        let packed_inputs = pack_values(&to_trace_order(&self.inputs.table));
 
         let (trace, lookup_data) = write_trace_simd(packed_inputs);
 
@@ -83,7 +83,7 @@ impl ClaimGenerator {
 ///
 /// This function processes the `PackedAddTraceTableRow` data in parallel:
 /// - It directly maps fields from `PackedAddTraceTableRow` to the corresponding main trace columns.
-/// - It extracts and stores `[value, id]` pairs and their multiplicities (from `lhs_mult`, etc.)
+/// - It extracts and stores `[id, idx, value]` triples and their multiplicities (from `lhs_mult`, etc.)
 ///   into the `LookupData` struct. This data is crucial for building the LogUp argument,
 ///   which links these values to where they are defined or used elsewhere in the graph.
 /// Returns the `ComponentTrace` (main trace columns) and `LookupData`.
@@ -111,11 +111,9 @@ fn write_trace_simd(
             *row[AddColumn::LhsId.index()] = input.lhs_id;
             *row[AddColumn::RhsId.index()] = input.rhs_id;
             *row[AddColumn::Idx.index()] = input.idx;
+            *row[AddColumn::LhsIdx.index()] = input.lhs_idx;
+            *row[AddColumn::RhsIdx.index()] = input.rhs_idx;
             *row[AddColumn::IsLastIdx.index()] = input.is_last_idx;
-            *row[AddColumn::NextNodeId.index()] = input.next_node_id;
-            *row[AddColumn::NextLhsId.index()] = input.next_lhs_id;
-            *row[AddColumn::NextRhsId.index()] = input.next_rhs_id;
-            *row[AddColumn::NextIdx.index()] = input.next_idx;
             *row[AddColumn::Lhs.index()] = input.lhs;
             *row[AddColumn::Rhs.index()] = input.rhs;
             *row[AddColumn::Out.index()] = input.out;
@@ -123,11 +121,11 @@ fn write_trace_simd(
             *row[AddColumn::RhsMult.index()] = input.rhs_mult;
             *row[AddColumn::OutMult.index()] = input.out_mult;
 
-            *lookup_data.lhs = [input.lhs, input.lhs_id];
+            *lookup_data.lhs = [input.lhs_id, input.lhs_idx, input.lhs];
             *lookup_data.lhs_mult = input.lhs_mult;
-            *lookup_data.rhs = [input.rhs, input.rhs_id];
+            *lookup_data.rhs = [input.rhs_id, input.rhs_idx, input.rhs];
             *lookup_data.rhs_mult = input.rhs_mult;
-            *lookup_data.out = [input.out, input.node_id];
+            *lookup_data.out = [input.node_id, input.idx, input.out];
             *lookup_data.out_mult = input.out_mult;
         });
 
@@ -137,21 +135,21 @@ fn write_trace_simd(
 /// Intermediate data structure holding values and multiplicities for LogUp argument construction.
 ///
 /// For each Add operation (LHS, RHS, OUT), it stores:
-/// - `[value, id_of_value_source_or_dest_node]`: The pair used in the LogUp denominator.
+/// - `[id_of_value_source_or_dest_node, idx, value]`: The triple used in the LogUp denominator.
 /// - `multiplicity`: The +1 or -1 count for this value in the LogUp sum.
 /// Derives helper iterators for parallel processing.
 #[derive(Uninitialized, IterMut, ParIterMut)]
 struct LookupData {
-    /// LHS value-ID pairs: `[lhs_value, lhs_node_id]`.
-    lhs: Vec<[PackedM31; 2]>,
+    /// LHS ID-index-value triples: `[lhs_node_id, lhs_idx, lhs_value]`.
+    lhs: Vec<[PackedM31; 3]>,
     /// Multiplicities for LHS values.
     lhs_mult: Vec<PackedM31>,
-    /// RHS value-ID pairs: `[rhs_value, rhs_node_id]`.
-    rhs: Vec<[PackedM31; 2]>,
+    /// RHS ID-index-value triples: `[rhs_node_id, rhs_idx, rhs_value]`.
+    rhs: Vec<[PackedM31; 3]>,
     /// Multiplicities for RHS values.
     rhs_mult: Vec<PackedM31>,
-    /// Output value-ID pairs: `[out_value, add_node_id]`.
-    out: Vec<[PackedM31; 2]>,
+    /// Output ID-index-value triples: `[add_node_id, idx, out_value]`.
+    out: Vec<[PackedM31; 3]>,
     /// Multiplicities for output values.
     out_mult: Vec<PackedM31>,
 }
@@ -165,7 +163,7 @@ struct LookupData {
 pub struct InteractionClaimGenerator {
     /// Log2 size of the trace.
     log_size: u32,
-    /// Data (value-ID pairs and multiplicities) needed for LogUp.
+    /// Data (ID-index-value triples and multiplicities) needed for LogUp.
     lookup_data: LookupData,
 }
 
@@ -175,7 +173,7 @@ impl InteractionClaimGenerator {
     /// For each of LHS, RHS, and OUT:
     /// 1. Initializes a LogUp column generator.
     /// 2. For each entry in `lookup_data`:
-    ///    a. Combines `[value, id]` with `NodeElements` to form the denominator for LogUp.
+    ///    a. Combines `[id, idx, value]` with `NodeElements` to form the denominator for LogUp.
     ///    b. Writes `multiplicity / denominator` to the current LogUp column.
     /// 3. Finalizes the column.
     /// After processing all three, finalizes the `LogupTraceGenerator` to get the interaction trace
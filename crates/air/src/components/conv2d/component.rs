@@ -0,0 +1,186 @@
+use crate::{
+    components::{Conv2dClaim, NodeElements},
+    preprocessed::eval_structure,
+};
+use num_traits::One;
+use stwo_prover::constraint_framework::{
+    EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry, ORIGINAL_TRACE_IDX,
+};
+
+use super::table::Conv2dTraceTableRow;
+
+/// The STWO AIR component for Conv2d operations.
+/// Wraps the `Conv2dEval` logic within the STWO `FrameworkComponent`.
+pub type Conv2dComponent = FrameworkComponent<Conv2dEval>;
+
+/// Defines the AIR constraints evaluation logic for the Conv2d component.
+/// Implements `FrameworkEval` to define trace layout, degrees, and constraints
+/// for the sliding-window accumulation of each output element, read directly from the input
+/// feature map rather than from an im2col copy of it.
+pub struct Conv2dEval {
+    /// Log2 size of the component's trace segment.
+    log_size: u32,
+    /// Interaction elements for node relations (used in LogUp).
+    node_elements: NodeElements,
+}
+
+impl Conv2dEval {
+    /// Creates a new `Conv2dEval` instance.
+    /// Takes the component's claim (for `log_size`) and interaction elements.
+    pub fn new(claim: &Conv2dClaim, node_elements: NodeElements) -> Self {
+        Self {
+            log_size: claim.log_size,
+            node_elements,
+        }
+    }
+}
+
+/// Implements the core constraint evaluation logic for the Conv2d component.
+impl FrameworkEval for Conv2dEval {
+    /// Returns the log2 size of this component's trace segment.
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// Returns the maximum expected log2 degree bound for the component's constraints.
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.log_size + 1
+    }
+
+    /// Evaluates the Conv2d AIR constraints on a given evaluation point (`eval`).
+    ///
+    /// Defines constraints for:
+    /// - **Structural:** The IDs, indices, flags and multiplicities match the preprocessed trace.
+    /// - **Consistency:**
+    ///   - `is_last_idx` and `is_last_step` are boolean.
+    ///   - `input_mult` is 0 or -1, and reads outside of the input tensor (padding) have
+    ///     `input = 0`.
+    ///   - Accumulator update: `next_acc = acc + input * weight`, exact products summed at the
+    ///     sum of the input scales, until a Rescale brings the output back.
+    ///   - Output validity: `out = next_acc` only if `is_last_step` is true.
+    /// - **Transition:** Links each row to the next row of the trace:
+    ///   - The next row's `acc` equals `next_acc`, or 0 once a window ends (`is_last_step`).
+    ///   - When `is_last_idx` is false, node and input tensor IDs remain the same, and `idx`
+    ///     (output element index) increments by 1 once a window ends.
+    /// - **Interaction (LogUp):** Links `input_val`, `weight_val` (from the input tensors) and
+    ///   `out_val` (final window sum) to the global LogUp argument.
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        // IDs (current and next row, for transition constraints)
+        // ID of the node in the computational graph.
+        let [node_id, next_node_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of the input feature map.
+        let [input_id, next_input_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of the weight tensor.
+        let [weight_id, next_weight_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Index in the flattened output tensor.
+        let [idx, next_idx] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Positions of the input values within their tensors.
+        let input_idx = eval.next_trace_mask();
+        let weight_idx = eval.next_trace_mask();
+        let is_last_idx = eval.next_trace_mask(); // Flag if this is the last index for this operation.
+
+        // Values for consistency constraints
+        let input_val = eval.next_trace_mask(); // Value from the input feature map.
+        let weight_val = eval.next_trace_mask(); // Value from the weight tensor.
+        let out_val = eval.next_trace_mask(); // Value in output tensor at index.
+
+        // Accumulative value in result tensor at index, and the one of the next row.
+        let [acc_val, next_row_acc_val] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        let next_acc_val = eval.next_trace_mask(); // Next accumulative value.
+        let is_last_step = eval.next_trace_mask(); // Flag if this is the last step.
+
+        // Multiplicities for interaction constraints
+        let input_mult = eval.next_trace_mask();
+        let weight_mult = eval.next_trace_mask();
+        let out_mult = eval.next_trace_mask();
+
+        // ┌─────────────────────────────┐
+        // │   Structural Constraints    │
+        // └─────────────────────────────┘
+
+        // The IDs, indices, flags and multiplicities are the ones fixed by the graph.
+        eval_structure::<_, Conv2dTraceTableRow>(
+            &mut eval,
+            "conv2d",
+            vec![
+                node_id.clone(),
+                input_id.clone(),
+                weight_id.clone(),
+                idx.clone(),
+                input_idx.clone(),
+                weight_idx.clone(),
+                is_last_idx.clone(),
+                is_last_step.clone(),
+                input_mult.clone(),
+                weight_mult.clone(),
+                out_mult.clone(),
+            ],
+        );
+
+        // ┌─────────────────────────────┐
+        // │   Consistency Constraints   │
+        // └─────────────────────────────┘
+
+        // The is_last_idx and is_last_step flags are either 0 or 1.
+        eval.add_constraint(is_last_idx.clone() * (is_last_idx.clone() - E::F::one()));
+        eval.add_constraint(is_last_step.clone() * (is_last_step.clone() - E::F::one()));
+
+        // Input values are either read from the input tensor, or padding reads equal to 0.
+        eval.add_constraint(input_mult.clone() * (input_mult.clone() + E::F::one()));
+        eval.add_constraint((input_mult.clone() + E::F::one()) * input_val.clone());
+
+        // The output value must equal the sum of the exact products under the kernel.
+        eval.add_constraint(
+            next_acc_val.clone() - (acc_val.clone() + input_val.clone() * weight_val.clone()),
+        );
+        eval.add_constraint((out_val.clone() - next_acc_val.clone()) * is_last_step.clone());
+
+        // ┌────────────────────────────┐
+        // │   Transition Constraints   │
+        // └────────────────────────────┘
+
+        // The accumulator carries over to the next row, and restarts from 0 after the last step.
+        eval.add_constraint(next_row_acc_val - (E::F::one() - is_last_step.clone()) * next_acc_val);
+
+        // If this is not the last index for this operation, then:
+        // 1. The next row should be for the same operation on the same tensors.
+        // 2. The index should increment by 1 once the window of the current index ends.
+        let not_last = E::F::one() - is_last_idx;
+
+        // Same node ID
+        eval.add_constraint(not_last.clone() * (next_node_id - node_id.clone()));
+
+        // Same tensor IDs
+        eval.add_constraint(not_last.clone() * (next_input_id - input_id.clone()));
+        eval.add_constraint(not_last.clone() * (next_weight_id - weight_id.clone()));
+
+        // Index increment by 1
+        eval.add_constraint(not_last * (next_idx - idx.clone() - is_last_step));
+
+        // ┌─────────────────────────────┐
+        // │   Interaction Constraints   │
+        // └─────────────────────────────┘
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            input_mult.into(),
+            &[input_id, input_idx, input_val],
+        ));
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            weight_mult.into(),
+            &[weight_id, weight_idx, weight_val],
+        ));
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            out_mult.into(),
+            &[node_id, idx, out_val],
+        ));
+
+        eval.finalize_logup();
+
+        eval
+    }
+}
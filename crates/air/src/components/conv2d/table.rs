@@ -0,0 +1,317 @@
+use num_traits::{One, Zero};
+use serde::{Deserialize, Serialize};
+use stwo_prover::core::{
+    backend::simd::{
+        conversion::{Pack, Unpack},
+        m31::{PackedM31, N_LANES},
+    },
+    fields::m31::M31,
+};
+
+use crate::{components::TraceColumn, preprocessed::StructuredRow};
+
+use super::witness::N_TRACE_COLUMNS;
+
+/// Represents the raw trace data collected for Conv2d operations.
+///
+/// Stores rows capturing the sliding-window accumulation of each output element,
+/// one row per product of an input value under the kernel and a weight value.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct Conv2dTraceTable {
+    /// Vector containing all rows of the Conv2d trace.
+    pub table: Vec<Conv2dTraceTableRow>,
+}
+
+/// Represents a single row in the `Conv2dTraceTable`.
+///
+/// Contains values for evaluating Conv2d AIR constraints: state IDs, the current input and
+/// weight values, current/next accumulator value, a flag indicating the last step of the
+/// window for an output element, the final output (valid on last step),
+/// and LogUp multiplicities.
+#[derive(Debug, Default, Copy, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Conv2dTraceTableRow {
+    /// ID of the current Conv2d node.
+    pub node_id: M31,
+    /// ID of the node providing the input feature map.
+    pub input_id: M31,
+    /// ID of the node providing the kernel weights.
+    pub weight_id: M31,
+    /// Index of the output element being computed.
+    pub idx: M31,
+    /// Position of the input value within the input tensor, 0 for reads in the padding.
+    pub input_idx: M31,
+    /// Position of the weight value within the weight tensor.
+    pub weight_idx: M31,
+    /// Flag: is this the last output element for this node (1 if true, 0 otherwise).
+    pub is_last_idx: M31,
+    /// Current value of the input, 0 for reads in the padding.
+    pub input: M31,
+    /// Current value of the weight.
+    pub weight: M31,
+    /// Final output value (window sum for `idx`), at the sum of the input scales.
+    /// Valid only if `is_last_step` is 1.
+    pub out: M31,
+    /// Accumulator value *before* adding the current product.
+    pub acc: M31,
+    /// Accumulator value *after* adding the current product (`acc + input * weight`).
+    pub next_acc: M31,
+    /// Flag: is this the last product summed for the current `out` (1 if true, 0 otherwise).
+    pub is_last_step: M31,
+    /// Multiplicity contribution for the LogUp argument (input).
+    pub input_mult: M31,
+    /// Multiplicity contribution for the LogUp argument (weight).
+    pub weight_mult: M31,
+    /// Multiplicity contribution for the LogUp argument (output).
+    pub out_mult: M31,
+}
+
+impl Conv2dTraceTableRow {
+    /// Creates a default padding row for the Conv2d trace.
+    pub(crate) fn padding() -> Self {
+        Self {
+            node_id: M31::zero(),
+            input_id: M31::zero(),
+            weight_id: M31::zero(),
+            idx: M31::zero(),
+            input_idx: M31::zero(),
+            weight_idx: M31::zero(),
+            is_last_idx: M31::one(),
+            input: M31::zero(),
+            weight: M31::zero(),
+            out: M31::zero(),
+            acc: M31::zero(),
+            next_acc: M31::zero(),
+            is_last_step: M31::zero(),
+            input_mult: M31::zero(),
+            weight_mult: M31::zero(),
+            out_mult: M31::zero(),
+        }
+    }
+}
+
+/// Implements the `StructuredRow` trait for `Conv2dTraceTableRow`.
+impl StructuredRow for Conv2dTraceTableRow {
+    /// IDs, indices, flags and multiplicities of the row, fixed by the graph.
+    const STRUCTURE: &'static [&'static str] = &[
+        "node_id",
+        "input_id",
+        "weight_id",
+        "idx",
+        "input_idx",
+        "weight_idx",
+        "is_last_idx",
+        "is_last_step",
+        "input_mult",
+        "weight_mult",
+        "out_mult",
+    ];
+
+    /// Returns the values of the structural columns of the row.
+    fn structure(&self) -> Vec<M31> {
+        vec![
+            self.node_id,
+            self.input_id,
+            self.weight_id,
+            self.idx,
+            self.input_idx,
+            self.weight_idx,
+            self.is_last_idx,
+            self.is_last_step,
+            self.input_mult,
+            self.weight_mult,
+            self.out_mult,
+        ]
+    }
+}
+
+/// SIMD-packed representation of a `Conv2dTraceTableRow`.
+#[derive(Debug, Copy, Clone)]
+pub struct PackedConv2dTraceTableRow {
+    /// Packed `node_id` values.
+    pub node_id: PackedM31,
+    /// Packed `input_id` values.
+    pub input_id: PackedM31,
+    /// Packed `weight_id` values.
+    pub weight_id: PackedM31,
+    /// Packed `idx` (output element index) values.
+    pub idx: PackedM31,
+    /// Packed `input_idx` values.
+    pub input_idx: PackedM31,
+    /// Packed `weight_idx` values.
+    pub weight_idx: PackedM31,
+    /// Packed `is_last_idx` flags.
+    pub is_last_idx: PackedM31,
+    /// Packed `input` values.
+    pub input: PackedM31,
+    /// Packed `weight` values.
+    pub weight: PackedM31,
+    /// Packed `out` (final window sum) values.
+    pub out: PackedM31,
+    /// Packed `acc` (accumulator before the product) values.
+    pub acc: PackedM31,
+    /// Packed `next_acc` (accumulator after the product) values.
+    pub next_acc: PackedM31,
+    /// Packed `is_last_step` flags (for the window).
+    pub is_last_step: PackedM31,
+    /// Packed `input_mult` values.
+    pub input_mult: PackedM31,
+    /// Packed `weight_mult` values.
+    pub weight_mult: PackedM31,
+    /// Packed `out_mult` values.
+    pub out_mult: PackedM31,
+}
+
+impl Pack for Conv2dTraceTableRow {
+    type SimdType = PackedConv2dTraceTableRow;
+
+    fn pack(inputs: [Self; N_LANES]) -> Self::SimdType {
+        PackedConv2dTraceTableRow {
+            node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].node_id)),
+            input_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_id)),
+            weight_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].weight_id)),
+            idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].idx)),
+            input_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_idx)),
+            weight_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].weight_idx)),
+            is_last_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_idx)),
+            input: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input)),
+            weight: PackedM31::from_array(std::array::from_fn(|i| inputs[i].weight)),
+            out: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out)),
+            acc: PackedM31::from_array(std::array::from_fn(|i| inputs[i].acc)),
+            next_acc: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_acc)),
+            is_last_step: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_step)),
+            input_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_mult)),
+            weight_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].weight_mult)),
+            out_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_mult)),
+        }
+    }
+}
+
+impl Unpack for PackedConv2dTraceTableRow {
+    type CpuType = Conv2dTraceTableRow;
+
+    fn unpack(self) -> [Self::CpuType; N_LANES] {
+        let (
+            node_id,
+            input_id,
+            weight_id,
+            idx,
+            input_idx,
+            weight_idx,
+            is_last_idx,
+            input,
+            weight,
+            out,
+            acc,
+            next_acc,
+            is_last_step,
+            input_mult,
+            weight_mult,
+            out_mult,
+        ) = (
+            self.node_id.to_array(),
+            self.input_id.to_array(),
+            self.weight_id.to_array(),
+            self.idx.to_array(),
+            self.input_idx.to_array(),
+            self.weight_idx.to_array(),
+            self.is_last_idx.to_array(),
+            self.input.to_array(),
+            self.weight.to_array(),
+            self.out.to_array(),
+            self.acc.to_array(),
+            self.next_acc.to_array(),
+            self.is_last_step.to_array(),
+            self.input_mult.to_array(),
+            self.weight_mult.to_array(),
+            self.out_mult.to_array(),
+        );
+
+        std::array::from_fn(|i| Conv2dTraceTableRow {
+            node_id: node_id[i],
+            input_id: input_id[i],
+            weight_id: weight_id[i],
+            idx: idx[i],
+            input_idx: input_idx[i],
+            weight_idx: weight_idx[i],
+            is_last_idx: is_last_idx[i],
+            input: input[i],
+            weight: weight[i],
+            out: out[i],
+            acc: acc[i],
+            next_acc: next_acc[i],
+            is_last_step: is_last_step[i],
+            input_mult: input_mult[i],
+            weight_mult: weight_mult[i],
+            out_mult: out_mult[i],
+        })
+    }
+}
+
+impl Conv2dTraceTable {
+    /// Creates a new, empty `Conv2dTraceTable`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a single row to the trace table.
+    pub fn add_row(&mut self, row: Conv2dTraceTableRow) {
+        self.table.push(row);
+    }
+}
+
+/// Enum defining the columns of the Conv2d AIR component's trace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Conv2dColumn {
+    NodeId,
+    InputId,
+    WeightId,
+    Idx,
+    /// Position of the input value within the input tensor.
+    InputIdx,
+    /// Position of the weight value within the weight tensor.
+    WeightIdx,
+    IsLastIdx,
+    Input,
+    Weight,
+    Out,
+    Acc,
+    NextAcc,
+    IsLastStep,
+    InputMult,
+    WeightMult,
+    OutMult,
+}
+
+impl Conv2dColumn {
+    /// Returns the 0-based index for this column within the Conv2d trace segment.
+    pub const fn index(self) -> usize {
+        match self {
+            Self::NodeId => 0,
+            Self::InputId => 1,
+            Self::WeightId => 2,
+            Self::Idx => 3,
+            Self::InputIdx => 4,
+            Self::WeightIdx => 5,
+            Self::IsLastIdx => 6,
+            Self::Input => 7,
+            Self::Weight => 8,
+            Self::Out => 9,
+            Self::Acc => 10,
+            Self::NextAcc => 11,
+            Self::IsLastStep => 12,
+            Self::InputMult => 13,
+            Self::WeightMult => 14,
+            Self::OutMult => 15,
+        }
+    }
+}
+
+/// Implements the `TraceColumn` trait for `Conv2dColumn`.
+impl TraceColumn for Conv2dColumn {
+    /// Specifies the number of columns used by the Conv2d component.
+    /// Returns `(N_TRACE_COLUMNS, 3)`, indicating main trace columns
+    /// and 3 interaction trace columns (for input, weight and output LogUp).
+    fn count() -> (usize, usize) {
+        (N_TRACE_COLUMNS, 3)
+    }
+}
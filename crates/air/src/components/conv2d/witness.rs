@@ -0,0 +1,208 @@
+use luminair_utils::TraceError;
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use stwo_air_utils::trace::component_trace::ComponentTrace;
+use stwo_air_utils_derive::{IterMut, ParIterMut, Uninitialized};
+use stwo_prover::{
+    constraint_framework::{logup::LogupTraceGenerator, Relation},
+    core::backend::simd::{
+        m31::{PackedM31, LOG_N_LANES, N_LANES},
+        qm31::PackedQM31,
+        SimdBackend,
+    },
+};
+
+use crate::{
+    components::{Conv2dClaim, InteractionClaim, NodeElements},
+    utils::{pack_values, to_trace_order, TreeBuilder},
+};
+
+use super::table::{
+    Conv2dColumn, Conv2dTraceTable, Conv2dTraceTableRow, PackedConv2dTraceTableRow,
+};
+
+/// Number of main trace columns for the Conv2d component.
+pub(crate) const N_TRACE_COLUMNS: usize = 16;
+
+/// Generates the main trace columns and initial data for interaction claims for the Conv2d component.
+///
+/// Takes the raw `Conv2dTraceTable`, processes it into main STARK trace columns
+/// (including accumulator states, input/output values), and prepares `LookupData` for LogUp.
+pub struct ClaimGenerator {
+    /// The raw trace data for Conv2d operations.
+    pub inputs: Conv2dTraceTable,
+}
+
+impl ClaimGenerator {
+    /// Creates a new `ClaimGenerator` with the given `Conv2dTraceTable`.
+    pub fn new(inputs: Conv2dTraceTable) -> Self {
+        Self { inputs }
+    }
+
+    /// Writes the main trace columns to the `tree_builder` and returns data for interaction phase.
+    ///
+    /// Follows standard pattern: pads, packs, calls `write_trace_simd`,
+    /// adds main trace to `tree_builder`, returns `Conv2dClaim` and `InteractionClaimGenerator`.
+    /// Returns `TraceError::EmptyTrace` if the input table is empty.
+    pub fn write_trace(
+        mut self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+    ) -> Result<(Conv2dClaim, InteractionClaimGenerator), TraceError> {
+        let n_rows = self.inputs.table.len();
+
+        if n_rows == 0 {
+            return Err(TraceError::EmptyTrace);
+        }
+
+        let size = std::cmp::max(n_rows.next_power_of_two(), N_LANES);
+        let log_size = size.ilog2();
+
+        self.inputs
+            .table
+            .resize(size, Conv2dTraceTableRow::padding());
+        let packed_inputs = pack_values(&to_trace_order(&self.inputs.table));
+
+        let (trace, lookup_data) = write_trace_simd(packed_inputs);
+
+        tree_builder.extend_evals(trace.to_evals());
+
+        Ok((
+            Conv2dClaim::new(log_size),
+            InteractionClaimGenerator {
+                log_size,
+                lookup_data,
+            },
+        ))
+    }
+}
+
+/// Populates main trace columns and `LookupData` from SIMD-packed Conv2d trace rows.
+///
+/// Processes `PackedConv2dTraceTableRow` data in parallel:
+/// - Maps fields (node/input IDs, current/next accumulator, input/weight/out values, flags)
+///   to main trace columns.
+/// - Extracts `[id, idx, value]` triples and multiplicities for the input, weight and output LogUps
+///   into `LookupData`.
+/// Returns the `ComponentTrace` (main trace columns) and `LookupData`.
+fn write_trace_simd(
+    inputs: Vec<PackedConv2dTraceTableRow>,
+) -> (ComponentTrace<N_TRACE_COLUMNS>, LookupData) {
+    let log_n_packed_rows = inputs.len().ilog2();
+    let log_size = log_n_packed_rows + LOG_N_LANES;
+
+    let (mut trace, mut lookup_data) = unsafe {
+        (
+            ComponentTrace::<N_TRACE_COLUMNS>::uninitialized(log_size),
+            LookupData::uninitialized(log_n_packed_rows),
+        )
+    };
+
+    (
+        trace.par_iter_mut(),
+        lookup_data.par_iter_mut(),
+        inputs.into_par_iter(),
+    )
+        .into_par_iter()
+        .for_each(|(mut row, lookup_data, input)| {
+            *row[Conv2dColumn::NodeId.index()] = input.node_id;
+            *row[Conv2dColumn::InputId.index()] = input.input_id;
+            *row[Conv2dColumn::WeightId.index()] = input.weight_id;
+            *row[Conv2dColumn::Idx.index()] = input.idx;
+            *row[Conv2dColumn::InputIdx.index()] = input.input_idx;
+            *row[Conv2dColumn::WeightIdx.index()] = input.weight_idx;
+            *row[Conv2dColumn::IsLastIdx.index()] = input.is_last_idx;
+            *row[Conv2dColumn::Input.index()] = input.input;
+            *row[Conv2dColumn::Weight.index()] = input.weight;
+            *row[Conv2dColumn::Out.index()] = input.out;
+            *row[Conv2dColumn::Acc.index()] = input.acc;
+            *row[Conv2dColumn::NextAcc.index()] = input.next_acc;
+            *row[Conv2dColumn::IsLastStep.index()] = input.is_last_step;
+            *row[Conv2dColumn::InputMult.index()] = input.input_mult;
+            *row[Conv2dColumn::WeightMult.index()] = input.weight_mult;
+            *row[Conv2dColumn::OutMult.index()] = input.out_mult;
+
+            *lookup_data.input = [input.input_id, input.input_idx, input.input];
+            *lookup_data.input_mult = input.input_mult;
+            *lookup_data.weight = [input.weight_id, input.weight_idx, input.weight];
+            *lookup_data.weight_mult = input.weight_mult;
+            *lookup_data.out = [input.node_id, input.idx, input.out];
+            *lookup_data.out_mult = input.out_mult;
+        });
+
+    (trace, lookup_data)
+}
+
+/// Intermediate data structure holding values and multiplicities for the Conv2d LogUp argument.
+/// Stores ID-index-value triples and multiplicities for input, weight and output terms.
+#[derive(Uninitialized, IterMut, ParIterMut)]
+struct LookupData {
+    /// Input ID-index-value triples: `[input_node_id, input_idx, input_value]`.
+    input: Vec<[PackedM31; 3]>,
+    /// Multiplicities for input values.
+    input_mult: Vec<PackedM31>,
+    /// Weight ID-index-value triples: `[weight_node_id, weight_idx, weight_value]`.
+    weight: Vec<[PackedM31; 3]>,
+    /// Multiplicities for weight values.
+    weight_mult: Vec<PackedM31>,
+    /// Output ID-index-value triples: `[conv2d_node_id, idx, out_value]`.
+    out: Vec<[PackedM31; 3]>,
+    /// Multiplicities for output values.
+    out_mult: Vec<PackedM31>,
+}
+
+/// Generates interaction trace columns for the Conv2d component's LogUp argument.
+/// Builds three LogUp columns (input, weight, output) and adds them to the `tree_builder`.
+pub struct InteractionClaimGenerator {
+    /// Log2 size of the trace.
+    log_size: u32,
+    /// Data (ID-index-value triples and multiplicities) needed for LogUp.
+    lookup_data: LookupData,
+}
+
+impl InteractionClaimGenerator {
+    /// Writes the LogUp interaction trace columns to the `tree_builder`.
+    ///
+    /// Similar to MatMul: generates three columns (input, weight, output), writing
+    /// `multiplicity / denom` fractions. Finalizes generator, adds columns to `tree_builder`, returns `InteractionClaim`.
+    pub fn write_interaction_trace(
+        self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+        node_elements: &NodeElements,
+    ) -> InteractionClaim {
+        let mut logup_gen = LogupTraceGenerator::new(self.log_size);
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let values = &self.lookup_data.input[row];
+            let multiplicity = &self.lookup_data.input_mult[row];
+
+            let denom: PackedQM31 = node_elements.combine(values);
+            col_gen.write_frac(row, (*multiplicity).into(), denom);
+        }
+        col_gen.finalize_col();
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let values = &self.lookup_data.weight[row];
+            let multiplicity = &self.lookup_data.weight_mult[row];
+
+            let denom: PackedQM31 = node_elements.combine(values);
+            col_gen.write_frac(row, (*multiplicity).into(), denom);
+        }
+        col_gen.finalize_col();
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let values = &self.lookup_data.out[row];
+            let multiplicity = &self.lookup_data.out_mult[row];
+
+            let denom: PackedQM31 = node_elements.combine(values);
+            col_gen.write_frac(row, (*multiplicity).into(), denom);
+        }
+        col_gen.finalize_col();
+
+        let (trace, claimed_sum) = logup_gen.finalize_last();
+        tree_builder.extend_evals(trace);
+
+        InteractionClaim { claimed_sum }
+    }
+}
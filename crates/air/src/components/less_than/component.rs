@@ -0,0 +1,216 @@
+use crate::{
+    components::{
+        lookups::range_check::{component::eval_limbs, RangeCheckLookupElements},
+        LessThanClaim, NodeElements,
+    },
+    preprocessed::eval_structure,
+};
+use num_traits::One;
+use stwo_prover::{
+    constraint_framework::{
+        EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry, ORIGINAL_TRACE_IDX,
+    },
+    core::fields::m31::M31,
+};
+
+use super::table::{LessThanTraceTableRow, N_DIFF_BITS};
+
+/// The STWO AIR component for element-wise less-than comparisons.
+///
+/// This wraps the `LessThanEval` logic within the STWO `FrameworkComponent`,
+/// which handles common AIR component setup and evaluation.
+pub type LessThanComponent = FrameworkComponent<LessThanEval>;
+
+/// Defines the AIR constraints evaluation logic for the LessThan component.
+///
+/// Implements the `FrameworkEval` trait, providing methods to define the component's
+/// trace layout, constraint degrees, and the core constraint evaluation function.
+pub struct LessThanEval {
+    /// Log2 size of the component's trace segment.
+    log_size: u32,
+    /// Log2 size of the preprocessed range-check table.
+    lut_log_size: u32,
+    /// Interaction elements for node relations (used in LogUp).
+    node_elements: NodeElements,
+    /// Interaction elements for the range checks of the difference limbs.
+    range_check_elements: RangeCheckLookupElements,
+    /// Fixed-point scale of the circuit.
+    fp_scale: u32,
+}
+
+impl LessThanEval {
+    /// Creates a new `LessThanEval` instance.
+    /// Takes the component's claim (for `log_size`), interaction elements for nodes and
+    /// the range check, the log_size of the range-check table and the fixed-point scale.
+    pub fn new(
+        claim: &LessThanClaim,
+        node_elements: NodeElements,
+        range_check_elements: RangeCheckLookupElements,
+        lut_log_size: u32,
+        fp_scale: u32,
+    ) -> Self {
+        Self {
+            log_size: claim.log_size,
+            lut_log_size,
+            node_elements,
+            range_check_elements,
+            fp_scale,
+        }
+    }
+}
+
+/// Implements the core constraint evaluation logic for the LessThan component.
+impl FrameworkEval for LessThanEval {
+    /// Returns the log2 size of this component's trace segment.
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// Returns the max log2 degree bound, considering both main trace and range-check table sizes.
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        std::cmp::max(self.log_size, self.lut_log_size) + 1
+    }
+
+    /// Evaluates the LessThan AIR constraints on a given evaluation point (`eval`).
+    ///
+    /// Defines constraints ensuring:
+    /// - **Structural:** The IDs, indices, flags and multiplicities match the preprocessed trace.
+    /// - **Consistency:** The output is a fixed-point boolean (`0` or `SCALE`) and agrees with the
+    ///   sign of `rhs - lhs`, witnessed by a difference recomposed from its two limbs.
+    /// - **Transition:** Correctness of transitions between consecutive rows (e.g., index increments).
+    /// - **Interaction (LogUp):** Links values used/produced by LessThan operations to the global
+    ///   LogUp argument, ensuring consistency across the entire computation trace. The limbs
+    ///   of the difference, and their gaps to their maximal values, are sent to the shared
+    ///   range-check lookup, so that the difference lies in `[0, 2^N_DIFF_BITS)`.
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        let scale = E::F::from(M31::from_u32_unchecked(1 << self.fp_scale));
+
+        // IDs (current and next row, for transition constraints)
+        // ID of the node in the computational graph.
+        let [node_id, next_node_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of first input tensor.
+        let [lhs_id, next_lhs_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of second input tensor.
+        let [rhs_id, next_rhs_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Index in the flattened tensor.
+        let [idx, next_idx] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Positions of the input values within their tensors.
+        let lhs_idx = eval.next_trace_mask();
+        let rhs_idx = eval.next_trace_mask();
+        let is_last_idx = eval.next_trace_mask(); // Flag if this is the last index for this operation.
+
+        // Values for consistency constraints
+        let lhs_val = eval.next_trace_mask(); // Value from first tensor at index.
+        let rhs_val = eval.next_trace_mask(); // Value from second tensor at index.
+        let out_val = eval.next_trace_mask(); // Comparison result (SCALE if lhs < rhs, else 0).
+        let diff = eval.next_trace_mask(); // Non-negative difference witnessing the comparison.
+        let diff_lo = eval.next_trace_mask(); // Low limb of the difference.
+        let diff_hi = eval.next_trace_mask(); // High limb of the difference.
+
+        // Multiplicities for interaction constraints
+        let lhs_mult = eval.next_trace_mask();
+        let rhs_mult = eval.next_trace_mask();
+        let out_mult = eval.next_trace_mask();
+        let range_check_mult = eval.next_trace_mask();
+
+        // ┌─────────────────────────────┐
+        // │   Structural Constraints    │
+        // └─────────────────────────────┘
+
+        // The IDs, indices, flags and multiplicities are the ones fixed by the graph.
+        eval_structure::<_, LessThanTraceTableRow>(
+            &mut eval,
+            "less_than",
+            vec![
+                node_id.clone(),
+                lhs_id.clone(),
+                rhs_id.clone(),
+                idx.clone(),
+                lhs_idx.clone(),
+                rhs_idx.clone(),
+                is_last_idx.clone(),
+                lhs_mult.clone(),
+                rhs_mult.clone(),
+                out_mult.clone(),
+            ],
+        );
+
+        // ┌─────────────────────────────┐
+        // │   Consistency Constraints   │
+        // └─────────────────────────────┘
+
+        // The is_last_idx flag is either 0 or 1.
+        eval.add_constraint(is_last_idx.clone() * (is_last_idx.clone() - E::F::one()));
+
+        // The output is a fixed-point boolean: either 0 or SCALE.
+        eval.add_constraint(out_val.clone() * (out_val.clone() - scale.clone()));
+
+        // The difference is selected by the output:
+        // - lhs < rhs  (out = SCALE): diff = rhs - lhs - 1
+        // - lhs >= rhs (out = 0):     diff = lhs - rhs
+        eval.add_constraint(
+            scale.clone() * diff.clone()
+                - out_val.clone() * (rhs_val.clone() - lhs_val.clone() - E::F::one())
+                - (scale - out_val.clone()) * (lhs_val.clone() - rhs_val.clone()),
+        );
+
+        // The range-check multiplicity is boolean, and set on every row emitting its output.
+        eval.add_constraint(range_check_mult.clone() * (range_check_mult.clone() - E::F::one()));
+        eval.add_constraint(out_mult.clone() * (E::F::one() - range_check_mult.clone()));
+
+        // ┌────────────────────────────┐
+        // │   Transition Constraints   │
+        // └────────────────────────────┘
+
+        // If this is not the last index for this operation, then:
+        // 1. The next row should be for the same operation on the same tensors.
+        // 2. The index should increment by 1.
+        let not_last = E::F::one() - is_last_idx;
+
+        // Same node ID
+        eval.add_constraint(not_last.clone() * (next_node_id - node_id.clone()));
+
+        // Same tensor IDs
+        eval.add_constraint(not_last.clone() * (next_lhs_id - lhs_id.clone()));
+        eval.add_constraint(not_last.clone() * (next_rhs_id - rhs_id.clone()));
+
+        // Index increment by 1
+        eval.add_constraint(not_last * (next_idx - idx.clone() - E::F::one()));
+
+        // ┌─────────────────────────────┐
+        // │   Interaction Constraints   │
+        // └─────────────────────────────┘
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            lhs_mult.into(),
+            &[lhs_id, lhs_idx, lhs_val],
+        ));
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            rhs_mult.into(),
+            &[rhs_id, rhs_idx, rhs_val],
+        ));
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            out_mult.into(),
+            &[node_id, idx, out_val],
+        ));
+
+        // The difference is non-negative: its limbs lie in [0, 2^lo_bits) and [0, 2^hi_bits).
+        eval_limbs(
+            &mut eval,
+            &self.range_check_elements,
+            &range_check_mult,
+            diff,
+            [diff_lo, diff_hi],
+            N_DIFF_BITS,
+        );
+
+        eval.finalize_logup();
+
+        eval
+    }
+}
@@ -0,0 +1,352 @@
+use num_traits::{One, Zero};
+use serde::{Deserialize, Serialize};
+use stwo_prover::core::{
+    backend::simd::{
+        conversion::{Pack, Unpack},
+        m31::{PackedM31, N_LANES},
+    },
+    fields::m31::M31,
+};
+
+use crate::{
+    components::{lookups::range_check::N_LIMB_RANGE_CHECKS, TraceColumn},
+    preprocessed::StructuredRow,
+};
+
+use super::witness::N_TRACE_COLUMNS;
+
+/// Number of range-checked bits of the comparison difference.
+///
+/// The difference is range-checked to `[0, 2^N_DIFF_BITS)` through two limbs. The differences
+/// of both branches sum to `-1 = P - 1`, so that at most one of them fits.
+/// `gen_trace` returns a `RangeCheckOverflow` error for operands further apart.
+pub const N_DIFF_BITS: u32 = 29;
+
+/// Represents the raw trace data collected for LessThan operations.
+///
+/// Stores rows capturing the inputs, the boolean output (in fixed-point), the comparison
+/// difference and its limbs, and metadata for each LessThan operation.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct LessThanTraceTable {
+    /// Vector containing all rows of the LessThan trace.
+    pub table: Vec<LessThanTraceTableRow>,
+}
+
+/// Represents a single row in the `LessThanTraceTable`.
+///
+/// Contains values for evaluating LessThan AIR constraints: state IDs,
+/// input/output values, the range-checked difference, and LogUp multiplicities.
+#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LessThanTraceTableRow {
+    /// ID of the current LessThan node.
+    pub node_id: M31,
+    /// ID of the node providing the left-hand side input.
+    pub lhs_id: M31,
+    /// ID of the node providing the right-hand side input.
+    pub rhs_id: M31,
+    /// Index within the tensor for this operation.
+    pub idx: M31,
+    /// Position of the LHS value within the left-hand side input tensor.
+    pub lhs_idx: M31,
+    /// Position of the RHS value within the right-hand side input tensor.
+    pub rhs_idx: M31,
+    /// Flag indicating if this is the last element processed for this node (1 if true, 0 otherwise).
+    pub is_last_idx: M31,
+    /// Value of the left-hand side input.
+    pub lhs: M31,
+    /// Value of the right-hand side input.
+    pub rhs: M31,
+    /// Value of the output (`SCALE` if `lhs < rhs`, `0` otherwise).
+    pub out: M31,
+    /// Non-negative difference: `rhs - lhs - 1` if `lhs < rhs`, `lhs - rhs` otherwise.
+    pub diff: M31,
+    /// Low limb of `diff`.
+    pub diff_lo: M31,
+    /// High limb of `diff`.
+    pub diff_hi: M31,
+    /// Multiplicity contribution for the LogUp argument related to the LHS input.
+    pub lhs_mult: M31,
+    /// Multiplicity contribution for the LogUp argument related to the RHS input.
+    pub rhs_mult: M31,
+    /// Multiplicity contribution for the LogUp argument related to the output.
+    pub out_mult: M31,
+    /// Multiplicity contribution for the range checks of the difference limbs.
+    pub range_check_mult: M31,
+}
+
+impl LessThanTraceTableRow {
+    /// Creates a default padding row for the LessThan trace.
+    pub(crate) fn padding() -> Self {
+        Self {
+            node_id: M31::zero(),
+            lhs_id: M31::zero(),
+            rhs_id: M31::zero(),
+            idx: M31::zero(),
+            lhs_idx: M31::zero(),
+            rhs_idx: M31::zero(),
+            is_last_idx: M31::one(),
+            lhs: M31::zero(),
+            rhs: M31::zero(),
+            out: M31::zero(),
+            diff: M31::zero(),
+            diff_lo: M31::zero(),
+            diff_hi: M31::zero(),
+            lhs_mult: M31::zero(),
+            rhs_mult: M31::zero(),
+            out_mult: M31::zero(),
+            range_check_mult: M31::zero(),
+        }
+    }
+}
+
+/// Implements the `StructuredRow` trait for `LessThanTraceTableRow`.
+impl StructuredRow for LessThanTraceTableRow {
+    /// IDs, indices, flags and multiplicities of the row, fixed by the graph.
+    const STRUCTURE: &'static [&'static str] = &[
+        "node_id",
+        "lhs_id",
+        "rhs_id",
+        "idx",
+        "lhs_idx",
+        "rhs_idx",
+        "is_last_idx",
+        "lhs_mult",
+        "rhs_mult",
+        "out_mult",
+    ];
+
+    /// Returns the values of the structural columns of the row.
+    fn structure(&self) -> Vec<M31> {
+        vec![
+            self.node_id,
+            self.lhs_id,
+            self.rhs_id,
+            self.idx,
+            self.lhs_idx,
+            self.rhs_idx,
+            self.is_last_idx,
+            self.lhs_mult,
+            self.rhs_mult,
+            self.out_mult,
+        ]
+    }
+}
+
+/// SIMD-packed representation of a `LessThanTraceTableRow`.
+#[derive(Debug, Copy, Clone)]
+pub struct PackedLessThanTraceTableRow {
+    /// Packed `node_id` values.
+    pub node_id: PackedM31,
+    /// Packed `lhs_id` values.
+    pub lhs_id: PackedM31,
+    /// Packed `rhs_id` values.
+    pub rhs_id: PackedM31,
+    /// Packed `idx` values.
+    pub idx: PackedM31,
+    /// Packed `lhs_idx` values.
+    pub lhs_idx: PackedM31,
+    /// Packed `rhs_idx` values.
+    pub rhs_idx: PackedM31,
+    /// Packed `is_last_idx` values.
+    pub is_last_idx: PackedM31,
+    /// Packed `lhs` values.
+    pub lhs: PackedM31,
+    /// Packed `rhs` values.
+    pub rhs: PackedM31,
+    /// Packed `out` values.
+    pub out: PackedM31,
+    /// Packed `diff` values.
+    pub diff: PackedM31,
+    /// Packed `diff_lo` values.
+    pub diff_lo: PackedM31,
+    /// Packed `diff_hi` values.
+    pub diff_hi: PackedM31,
+    /// Packed `lhs_mult` values.
+    pub lhs_mult: PackedM31,
+    /// Packed `rhs_mult` values.
+    pub rhs_mult: PackedM31,
+    /// Packed `out_mult` values.
+    pub out_mult: PackedM31,
+    /// Packed `range_check_mult` values.
+    pub range_check_mult: PackedM31,
+}
+
+impl Pack for LessThanTraceTableRow {
+    type SimdType = PackedLessThanTraceTableRow;
+
+    fn pack(inputs: [Self; N_LANES]) -> Self::SimdType {
+        PackedLessThanTraceTableRow {
+            node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].node_id)),
+            lhs_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs_id)),
+            rhs_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs_id)),
+            idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].idx)),
+            lhs_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs_idx)),
+            rhs_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs_idx)),
+            is_last_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_idx)),
+            lhs: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs)),
+            rhs: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs)),
+            out: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out)),
+            diff: PackedM31::from_array(std::array::from_fn(|i| inputs[i].diff)),
+            diff_lo: PackedM31::from_array(std::array::from_fn(|i| inputs[i].diff_lo)),
+            diff_hi: PackedM31::from_array(std::array::from_fn(|i| inputs[i].diff_hi)),
+            lhs_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs_mult)),
+            rhs_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs_mult)),
+            out_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_mult)),
+            range_check_mult: PackedM31::from_array(std::array::from_fn(|i| {
+                inputs[i].range_check_mult
+            })),
+        }
+    }
+}
+
+impl Unpack for PackedLessThanTraceTableRow {
+    type CpuType = LessThanTraceTableRow;
+
+    fn unpack(self) -> [Self::CpuType; N_LANES] {
+        let (
+            node_id,
+            lhs_id,
+            rhs_id,
+            idx,
+            lhs_idx,
+            rhs_idx,
+            is_last_idx,
+            lhs,
+            rhs,
+            out,
+            diff,
+            diff_lo,
+            diff_hi,
+            lhs_mult,
+            rhs_mult,
+            out_mult,
+            range_check_mult,
+        ) = (
+            self.node_id.to_array(),
+            self.lhs_id.to_array(),
+            self.rhs_id.to_array(),
+            self.idx.to_array(),
+            self.lhs_idx.to_array(),
+            self.rhs_idx.to_array(),
+            self.is_last_idx.to_array(),
+            self.lhs.to_array(),
+            self.rhs.to_array(),
+            self.out.to_array(),
+            self.diff.to_array(),
+            self.diff_lo.to_array(),
+            self.diff_hi.to_array(),
+            self.lhs_mult.to_array(),
+            self.rhs_mult.to_array(),
+            self.out_mult.to_array(),
+            self.range_check_mult.to_array(),
+        );
+
+        std::array::from_fn(|i| LessThanTraceTableRow {
+            node_id: node_id[i],
+            lhs_id: lhs_id[i],
+            rhs_id: rhs_id[i],
+            idx: idx[i],
+            lhs_idx: lhs_idx[i],
+            rhs_idx: rhs_idx[i],
+            is_last_idx: is_last_idx[i],
+            lhs: lhs[i],
+            rhs: rhs[i],
+            out: out[i],
+            diff: diff[i],
+            diff_lo: diff_lo[i],
+            diff_hi: diff_hi[i],
+            lhs_mult: lhs_mult[i],
+            rhs_mult: rhs_mult[i],
+            out_mult: out_mult[i],
+            range_check_mult: range_check_mult[i],
+        })
+    }
+}
+
+impl LessThanTraceTable {
+    /// Creates a new, empty `LessThanTraceTable`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a single row to the trace table.
+    pub fn add_row(&mut self, row: LessThanTraceTableRow) {
+        self.table.push(row);
+    }
+}
+
+/// Enum defining the columns of the LessThan AIR component's trace.
+/// Provides a mapping from meaningful names to column indices.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum LessThanColumn {
+    /// ID of the current LessThan node.
+    NodeId,
+    /// ID of the node providing the left-hand side input.
+    LhsId,
+    /// ID of the node providing the right-hand side input.
+    RhsId,
+    /// Index within the tensor for this operation.
+    Idx,
+    /// Position of the LHS value within the left-hand side input tensor.
+    LhsIdx,
+    /// Position of the RHS value within the right-hand side input tensor.
+    RhsIdx,
+    /// Flag indicating if this is the last element processed for this node.
+    IsLastIdx,
+    /// Value of the left-hand side input.
+    Lhs,
+    /// Value of the right-hand side input.
+    Rhs,
+    /// Value of the output (`SCALE` or `0`).
+    Out,
+    /// Non-negative comparison difference.
+    Diff,
+    /// Low limb of the comparison difference.
+    DiffLo,
+    /// High limb of the comparison difference.
+    DiffHi,
+    /// Multiplicity for the LogUp argument (LHS input).
+    LhsMult,
+    /// Multiplicity for the LogUp argument (RHS input).
+    RhsMult,
+    /// Multiplicity for the LogUp argument (output).
+    OutMult,
+    /// Multiplicity for the range checks of the difference limbs.
+    RangeCheckMult,
+}
+
+impl LessThanColumn {
+    /// Returns the 0-based index for this column within the LessThan trace segment.
+    pub const fn index(self) -> usize {
+        match self {
+            Self::NodeId => 0,
+            Self::LhsId => 1,
+            Self::RhsId => 2,
+            Self::Idx => 3,
+            Self::LhsIdx => 4,
+            Self::RhsIdx => 5,
+            Self::IsLastIdx => 6,
+            Self::Lhs => 7,
+            Self::Rhs => 8,
+            Self::Out => 9,
+            Self::Diff => 10,
+            Self::DiffLo => 11,
+            Self::DiffHi => 12,
+            Self::LhsMult => 13,
+            Self::RhsMult => 14,
+            Self::OutMult => 15,
+            Self::RangeCheckMult => 16,
+        }
+    }
+}
+
+/// Implements the `TraceColumn` trait for `LessThanColumn`.
+impl TraceColumn for LessThanColumn {
+    /// Specifies the number of columns used by the LessThan component.
+    /// Returns `(N_TRACE_COLUMNS, 3 + N_LIMB_RANGE_CHECKS)`, indicating the number of main
+    /// trace columns and the number of interaction trace columns (for the LHS, RHS and output
+    /// LogUp, and the range checks of the difference limbs).
+    fn count() -> (usize, usize) {
+        (N_TRACE_COLUMNS, 3 + N_LIMB_RANGE_CHECKS)
+    }
+}
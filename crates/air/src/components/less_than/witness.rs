@@ -0,0 +1,258 @@
+use luminair_utils::TraceError;
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use stwo_air_utils::trace::component_trace::ComponentTrace;
+use stwo_air_utils_derive::{IterMut, ParIterMut, Uninitialized};
+use stwo_prover::{
+    constraint_framework::{logup::LogupTraceGenerator, Relation},
+    core::backend::simd::{
+        m31::{PackedM31, LOG_N_LANES, N_LANES},
+        qm31::PackedQM31,
+        SimdBackend,
+    },
+};
+
+use crate::{
+    components::{
+        less_than::table::{LessThanColumn, LessThanTraceTableRow, N_DIFF_BITS},
+        lookups::range_check::{
+            witness::limb_range_checks, RangeCheckLookupElements, N_LIMB_RANGE_CHECKS,
+        },
+        InteractionClaim, LessThanClaim, NodeElements,
+    },
+    utils::{pack_values, to_trace_order, TreeBuilder},
+};
+
+use super::table::{LessThanTraceTable, PackedLessThanTraceTableRow};
+
+/// Number of main trace columns for the LessThan component.
+pub(crate) const N_TRACE_COLUMNS: usize = 17;
+
+/// Generates the main trace columns and initial data for interaction claims for the LessThan component.
+///
+/// Takes the raw `LessThanTraceTable` collected during graph execution, processes it into
+/// the main STARK trace columns, and prepares the necessary data (`LookupData`)
+/// for generating the LogUp interaction trace columns later.
+pub struct ClaimGenerator {
+    /// The raw trace data for LessThan operations.
+    pub inputs: LessThanTraceTable,
+}
+
+impl ClaimGenerator {
+    /// Creates a new `ClaimGenerator` with the given `LessThanTraceTable`.
+    pub fn new(inputs: LessThanTraceTable) -> Self {
+        Self { inputs }
+    }
+
+    /// Writes the main trace columns to the `tree_builder` and returns data for interaction phase.
+    ///
+    /// 1. Pads the input table to a power-of-two size.
+    /// 2. Converts rows to SIMD-packed format.
+    /// 3. Calls `write_trace_simd` to populate main trace columns and `LookupData`.
+    /// 4. Adds the generated main trace columns to the STWO commitment `tree_builder`.
+    /// 5. Returns an `LessThanClaim` (with trace log_size) and an `InteractionClaimGenerator`
+    ///    (containing `LookupData` needed for LogUp).
+    /// Returns `TraceError::EmptyTrace` if the input table is empty.
+    pub fn write_trace(
+        mut self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+    ) -> Result<(LessThanClaim, InteractionClaimGenerator), TraceError> {
+        let n_rows = self.inputs.table.len();
+
+        if n_rows == 0 {
+            return Err(TraceError::EmptyTrace);
+        }
+
+        let size = std::cmp::max(n_rows.next_power_of_two(), N_LANES);
+        let log_size = size.ilog2();
+
+        self.inputs
+            .table
+            .resize(size, LessThanTraceTableRow::padding());
+        let packed_inputs = pack_values(&to_trace_order(&self.inputs.table));
+
+        let (trace, lookup_data) = write_trace_simd(packed_inputs);
+
+        tree_builder.extend_evals(trace.to_evals());
+
+        Ok((
+            LessThanClaim::new(log_size),
+            InteractionClaimGenerator {
+                log_size,
+                lookup_data,
+            },
+        ))
+    }
+}
+
+/// Populates the main trace columns and `LookupData` from SIMD-packed trace rows.
+///
+/// This function processes the `PackedLessThanTraceTableRow` data in parallel:
+/// - It directly maps fields from `PackedLessThanTraceTableRow` to the corresponding main trace columns.
+/// - It extracts and stores `[id, idx, value]` triples and their multiplicities (from `lhs_mult`, etc.)
+///   into the `LookupData` struct. This data is crucial for building the LogUp argument,
+///   which links these values to where they are defined or used elsewhere in the graph.
+/// Returns the `ComponentTrace` (main trace columns) and `LookupData`.
+fn write_trace_simd(
+    inputs: Vec<PackedLessThanTraceTableRow>,
+) -> (ComponentTrace<N_TRACE_COLUMNS>, LookupData) {
+    let log_n_packed_rows = inputs.len().ilog2();
+    let log_size = log_n_packed_rows + LOG_N_LANES;
+
+    let (mut trace, mut lookup_data) = unsafe {
+        (
+            ComponentTrace::<N_TRACE_COLUMNS>::uninitialized(log_size),
+            LookupData::uninitialized(log_n_packed_rows),
+        )
+    };
+
+    (
+        trace.par_iter_mut(),
+        lookup_data.par_iter_mut(),
+        inputs.into_par_iter(),
+    )
+        .into_par_iter()
+        .for_each(|(mut row, lookup_data, input)| {
+            *row[LessThanColumn::NodeId.index()] = input.node_id;
+            *row[LessThanColumn::LhsId.index()] = input.lhs_id;
+            *row[LessThanColumn::RhsId.index()] = input.rhs_id;
+            *row[LessThanColumn::Idx.index()] = input.idx;
+            *row[LessThanColumn::LhsIdx.index()] = input.lhs_idx;
+            *row[LessThanColumn::RhsIdx.index()] = input.rhs_idx;
+            *row[LessThanColumn::IsLastIdx.index()] = input.is_last_idx;
+            *row[LessThanColumn::Lhs.index()] = input.lhs;
+            *row[LessThanColumn::Rhs.index()] = input.rhs;
+            *row[LessThanColumn::Out.index()] = input.out;
+            *row[LessThanColumn::Diff.index()] = input.diff;
+            *row[LessThanColumn::DiffLo.index()] = input.diff_lo;
+            *row[LessThanColumn::DiffHi.index()] = input.diff_hi;
+            *row[LessThanColumn::LhsMult.index()] = input.lhs_mult;
+            *row[LessThanColumn::RhsMult.index()] = input.rhs_mult;
+            *row[LessThanColumn::OutMult.index()] = input.out_mult;
+            *row[LessThanColumn::RangeCheckMult.index()] = input.range_check_mult;
+
+            *lookup_data.lhs = [input.lhs_id, input.lhs_idx, input.lhs];
+            *lookup_data.lhs_mult = input.lhs_mult;
+            *lookup_data.rhs = [input.rhs_id, input.rhs_idx, input.rhs];
+            *lookup_data.rhs_mult = input.rhs_mult;
+            *lookup_data.out = [input.node_id, input.idx, input.out];
+            *lookup_data.out_mult = input.out_mult;
+            *lookup_data.range_checks =
+                limb_range_checks([input.diff_lo, input.diff_hi], N_DIFF_BITS);
+            *lookup_data.range_check_mult = input.range_check_mult;
+        });
+
+    (trace, lookup_data)
+}
+
+/// Intermediate data structure holding values and multiplicities for LogUp argument construction.
+///
+/// For each LessThan operation (LHS, RHS, OUT), it stores:
+/// - `[id_of_value_source_or_dest_node, idx, value]`: The triple used in the LogUp denominator.
+/// - `multiplicity`: The +1 or -1 count for this value in the LogUp sum.
+/// Derives helper iterators for parallel processing.
+#[derive(Uninitialized, IterMut, ParIterMut)]
+struct LookupData {
+    /// LHS ID-index-value triples: `[lhs_node_id, lhs_idx, lhs_value]`.
+    lhs: Vec<[PackedM31; 3]>,
+    /// Multiplicities for LHS values.
+    lhs_mult: Vec<PackedM31>,
+    /// RHS ID-index-value triples: `[rhs_node_id, rhs_idx, rhs_value]`.
+    rhs: Vec<[PackedM31; 3]>,
+    /// Multiplicities for RHS values.
+    rhs_mult: Vec<PackedM31>,
+    /// Output ID-index-value triples: `[less_than_node_id, idx, out_value]`.
+    out: Vec<[PackedM31; 3]>,
+    /// Multiplicities for output values.
+    out_mult: Vec<PackedM31>,
+    /// Limbs of the differences and their gaps to their maximal values, range-checked
+    /// through the shared range-check lookup.
+    range_checks: Vec<[PackedM31; N_LIMB_RANGE_CHECKS]>,
+    /// Multiplicities for the range checks.
+    range_check_mult: Vec<PackedM31>,
+}
+
+/// Generates the interaction trace columns for the LessThan component's LogUp argument.
+///
+/// Takes the `LookupData` (prepared by `ClaimGenerator`) and `NodeElements` (randomness)
+/// to construct the three LogUp interaction columns (one each for LHS, RHS, OUT), followed by
+/// a column per range check of the difference limbs.
+/// These columns prove that the values used/produced by LessThan operations are consistent
+/// with their occurrences elsewhere in the computation graph.
+pub struct InteractionClaimGenerator {
+    /// Log2 size of the trace.
+    log_size: u32,
+    /// Data (ID-index-value triples and multiplicities) needed for LogUp.
+    lookup_data: LookupData,
+}
+
+impl InteractionClaimGenerator {
+    /// Writes the LogUp interaction trace columns to the `tree_builder`.
+    ///
+    /// For each of LHS, RHS, and OUT:
+    /// 1. Initializes a LogUp column generator.
+    /// 2. For each entry in `lookup_data`:
+    ///    a. Combines `[id, idx, value]` with `NodeElements` to form the denominator for LogUp.
+    ///    b. Writes `multiplicity / denominator` to the current LogUp column.
+    /// 3. Finalizes the column.
+    /// Then writes a column per range check, sending the difference limbs and their gaps to the
+    /// range-check lookup.
+    /// After processing all columns, finalizes the `LogupTraceGenerator` to get the interaction trace
+    /// columns and the overall `claimed_sum` for the LogUp argument.
+    /// Adds the interaction trace columns to the STWO `tree_builder`.
+    /// Returns the `InteractionClaim` containing the `claimed_sum`.
+    pub fn write_interaction_trace(
+        self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+        node_elements: &NodeElements,
+        range_check_elements: &RangeCheckLookupElements,
+    ) -> InteractionClaim {
+        let mut logup_gen = LogupTraceGenerator::new(self.log_size);
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let values = &self.lookup_data.lhs[row];
+            let multiplicity = &self.lookup_data.lhs_mult[row];
+
+            let denom: PackedQM31 = node_elements.combine(values);
+            col_gen.write_frac(row, (*multiplicity).into(), denom);
+        }
+        col_gen.finalize_col();
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let values = &self.lookup_data.rhs[row];
+            let multiplicity = &self.lookup_data.rhs_mult[row];
+
+            let denom: PackedQM31 = node_elements.combine(values);
+            col_gen.write_frac(row, (*multiplicity).into(), denom);
+        }
+        col_gen.finalize_col();
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let values = &self.lookup_data.out[row];
+            let multiplicity = &self.lookup_data.out_mult[row];
+
+            let denom: PackedQM31 = node_elements.combine(values);
+            col_gen.write_frac(row, (*multiplicity).into(), denom);
+        }
+        col_gen.finalize_col();
+
+        for i in 0..N_LIMB_RANGE_CHECKS {
+            let mut col_gen = logup_gen.new_col();
+            for row in 0..1 << (self.log_size - LOG_N_LANES) {
+                let value = self.lookup_data.range_checks[row][i];
+                let multiplicity = self.lookup_data.range_check_mult[row];
+
+                let denom: PackedQM31 = range_check_elements.combine(&[value]);
+                col_gen.write_frac(row, multiplicity.into(), denom);
+            }
+            col_gen.finalize_col();
+        }
+
+        let (trace, claimed_sum) = logup_gen.finalize_last();
+        tree_builder.extend_evals(trace);
+
+        InteractionClaim { claimed_sum }
+    }
+}
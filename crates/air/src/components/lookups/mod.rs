@@ -1,35 +1,168 @@
+use range_check::{RangeCheckLookup, RangeCheckLookupElements};
 use serde::{Deserialize, Serialize};
-use sin::{SinLookup, SinLookupElements};
-use stwo_prover::core::channel::Channel;
+use stwo_prover::core::{channel::Channel, fields::m31::M31};
+use unary::{functions::*, LutFunction};
 
-pub mod sin;
+use crate::{fixed_point::Fixed, preprocessed::LookupLayout, utils::AtomicMultiplicityColumn};
 
-/// Container for configurations of all active lookup arguments in the AIR.
+pub mod range_check;
+pub mod unary;
+
+/// Number of bits decomposing the distance of a clamped input beyond the bound of its table.
 ///
-/// Each field is optional, present only if the corresponding lookup is used.
-/// The contained struct (e.g., `SinLookup`) typically holds the `LookupLayout`
-/// defining the LUT's structure and value ranges.
-#[derive(Serialize, Debug, Deserialize, Clone)]
-pub struct Lookups {
-    /// Configuration for the Sine lookup argument, if active.
-    pub sin: Option<SinLookup>,
+/// The decomposition is sound as long as inputs and table bounds differ by less than `P - 2^29`.
+pub const N_CLAMP_BITS: usize = 29;
+
+/// Policy applied to the lookup inputs that are not covered by the layout of their table.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutOfRangePolicy {
+    /// Fails trace generation with `LuminairError::LookupOutOfRange`.
+    #[default]
+    Error,
+    /// Looks up the nearest bound of the table instead, proving that the input lies beyond it.
+    /// Inputs falling between two ranges of the table still fail.
+    Clamp,
+    /// Extends the layout to cover the inputs, then fails with `LuminairError::LookupOutOfRange`
+    /// so that the trace is generated again from the grown settings.
+    Grow,
 }
 
-/// Container for interaction elements specific to each lookup type.
-///
-/// These elements are drawn from the Fiat-Shamir channel and are used to build
-/// the LogUp arguments that connect trace values to the preprocessed lookup tables.
-#[derive(Clone, Debug)]
-pub struct LookupElements {
-    /// Interaction elements for the Sine lookup.
-    pub sin: SinLookupElements,
+/// Input of a function lookup, resolved against the layout of its table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClampedInput {
+    /// Value looked up in the table.
+    pub lut_input: Fixed,
+    /// Whether the input lies below the table, and is clamped to its smallest value.
+    pub low: bool,
+    /// Whether the input lies above the table, and is clamped to its largest value.
+    pub high: bool,
+    /// Distance of the input beyond the clamping bound, minus one (zero when not clamped).
+    pub dist: u32,
 }
 
-impl LookupElements {
-    /// Draws all necessary lookup-specific interaction elements from the channel.
-    pub fn draw(channel: &mut impl Channel) -> Self {
-        Self {
-            sin: SinLookupElements::draw(channel),
+impl ClampedInput {
+    /// Resolves `input` against `layout` under `policy`.
+    ///
+    /// Covered inputs are looked up as is. Under `OutOfRangePolicy::Clamp`, inputs beyond
+    /// the bounds of the table are looked up at the nearest one. Returns `None` otherwise.
+    pub fn resolve(input: Fixed, layout: &LookupLayout, policy: OutOfRangePolicy) -> Option<Self> {
+        if layout.find_index(input.0).is_some() {
+            return Some(Self {
+                lut_input: input,
+                low: false,
+                high: false,
+                dist: 0,
+            });
+        }
+        if policy != OutOfRangePolicy::Clamp {
+            return None;
+        }
+
+        let bound = layout.clamp(input.0)?;
+        let dist = (input.0 - bound.0).unsigned_abs() - 1;
+        if dist >= 1 << N_CLAMP_BITS {
+            return None;
         }
+        Some(Self {
+            lut_input: bound,
+            low: input < bound,
+            high: input > bound,
+            dist: dist as u32,
+        })
+    }
+
+    /// Decomposes the clamp distance into its `N_CLAMP_BITS` little-endian bits.
+    pub fn dist_bits(&self) -> [M31; N_CLAMP_BITS] {
+        std::array::from_fn(|i| M31::from_u32_unchecked((self.dist >> i) & 1))
     }
 }
+
+// Generates the containers of the lookups and of their interaction elements, with a field
+// per function of the `lut_functions!` registry, followed by the shared range check.
+macro_rules! lookups {
+    (
+        unary [$([$name:ident, $field:ident, $lookup:ident, $Lookup:ident])*]
+        all [$([$aname:ident, $afield:ident, $alookup:ident, $ALookup:ident])*]
+    ) => {
+        /// Container for configurations of all active lookup arguments in the AIR.
+        ///
+        /// Each field is optional, present only if the corresponding lookup is used.
+        /// Function lookups are named after their function in the `lut_functions!` registry:
+        /// the contained struct (e.g., `SinLookup`, a `UnaryLookup<Sin>`) holds the `LookupLayout`
+        /// defining the LUT's structure and value ranges.
+        #[derive(Serialize, Debug, Deserialize, Clone, Default)]
+        pub struct Lookups {
+            $(
+                #[doc = concat!(
+                    "Configuration for the ", stringify!($aname), " lookup argument, if active."
+                )]
+                #[serde(default)]
+                pub $afield: Option<$ALookup>,
+            )*
+            /// Configuration for the shared range-check lookup argument, if active.
+            pub range_check: Option<RangeCheckLookup>,
+        }
+
+        impl Lookups {
+            /// Sets the policy applied to inputs not covered by the function lookups.
+            pub fn set_policy(&mut self, policy: OutOfRangePolicy) {
+                $(
+                    if let Some(lookup) = self.$afield.as_mut() {
+                        lookup.policy = policy;
+                    }
+                )*
+            }
+
+            /// Resets the accesses recorded by all lookups, before generating a new trace.
+            pub fn clear_multiplicities(&mut self) {
+                $(
+                    if let Some(lookup) = self.$afield.as_mut() {
+                        lookup.multiplicities =
+                            AtomicMultiplicityColumn::new(1 << lookup.layout.log_size);
+                    }
+                )*
+                if let Some(lookup) = self.range_check.as_mut() {
+                    lookup.multiplicities =
+                        AtomicMultiplicityColumn::new(1 << lookup.layout.log_size);
+                }
+            }
+
+            /// Returns the layouts of the active function lookups, in the order of the registry.
+            pub fn function_layouts(&self) -> Vec<&LookupLayout> {
+                let mut layouts = Vec::new();
+                $(
+                    if let Some(lookup) = self.$afield.as_ref() {
+                        layouts.push(&lookup.layout);
+                    }
+                )*
+                layouts
+            }
+        }
+
+        /// Container for interaction elements specific to each lookup type.
+        ///
+        /// These elements are drawn from the Fiat-Shamir channel and are used to build
+        /// the LogUp arguments that connect trace values to the preprocessed lookup tables.
+        #[derive(Clone, Debug)]
+        pub struct LookupElements {
+            $(
+                #[doc = concat!("Interaction elements for the ", stringify!($aname), " lookup.")]
+                pub $afield: <$aname as LutFunction>::Elements,
+            )*
+            /// Interaction elements for the shared range-check lookup.
+            pub range_check: RangeCheckLookupElements,
+        }
+
+        impl LookupElements {
+            /// Draws all necessary lookup-specific interaction elements from the channel.
+            pub fn draw(channel: &mut impl Channel) -> Self {
+                Self {
+                    $($afield: $aname::draw_elements(channel),)*
+                    range_check: RangeCheckLookupElements::draw(channel),
+                }
+            }
+        }
+    };
+}
+
+crate::lut_functions!(lookups);
@@ -0,0 +1,145 @@
+use num_traits::One;
+use stwo_prover::{
+    constraint_framework::{
+        preprocessed_columns::PreProcessedColumnId, EvalAtRow, FrameworkComponent, FrameworkEval,
+        RelationEntry,
+    },
+    core::fields::m31::M31,
+};
+
+use crate::components::RangeCheckLookupClaim;
+
+use super::{limb_bits, RangeCheckLookupElements};
+
+/// The STWO AIR component for the shared Range Check Lookup Table (LUT) argument.
+///
+/// This component ensures that the multiplicities recorded for each entry of the
+/// preprocessed `[0, 2^k)` table balance the range-check requests emitted by other
+/// components (e.g. the remainders of `MulComponent`, `RecipComponent` and `SqrtComponent`).
+pub type RangeCheckLookupComponent = FrameworkComponent<RangeCheckLookupEval>;
+
+/// Defines the AIR constraints evaluation logic for the RangeCheckLookup component.
+/// Implements `FrameworkEval` to connect the multiplicity trace with the preprocessed LUT.
+pub struct RangeCheckLookupEval {
+    /// Log2 size of the component's main trace segment.
+    log_size: u32,
+    /// Interaction elements specific to the range-check LogUp.
+    lookup_elements: RangeCheckLookupElements,
+}
+
+impl RangeCheckLookupEval {
+    /// Creates a new `RangeCheckLookupEval` instance.
+    /// Takes the component's claim (for `log_size`) and range-check interaction elements.
+    pub fn new(claim: &RangeCheckLookupClaim, lookup_elements: RangeCheckLookupElements) -> Self {
+        Self {
+            log_size: claim.log_size,
+            lookup_elements,
+        }
+    }
+}
+
+/// Implements the core constraint evaluation logic for the RangeCheckLookup component.
+impl FrameworkEval for RangeCheckLookupEval {
+    /// Returns the log2 size of this component's main trace segment.
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// Returns the maximum expected log2 degree bound for the component's constraints.
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.log_size + 1
+    }
+
+    /// Evaluates the RangeCheckLookup AIR constraints on a given evaluation point (`eval`).
+    ///
+    /// 1. Retrieves the preprocessed range-check column (`range_check_lut`).
+    /// 2. Retrieves the `multiplicity` from the RangeCheckLookup component's main trace.
+    /// 3. Adds an entry to the LogUp relation:
+    ///    - Numerator: `-multiplicity` (negative because these are the "table side" entries).
+    ///    - Denominator: Combination of `range_check_lut` with `self.lookup_elements`.
+    /// Balanced against the requests from the other components, this proves that every
+    /// range-checked value belongs to the preprocessed table.
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        let range_check_lut = eval.get_preprocessed_column(PreProcessedColumnId {
+            id: "range_check_lut".to_string(),
+        });
+
+        let multiplicity = eval.next_trace_mask();
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.lookup_elements,
+            -E::EF::from(multiplicity),
+            &[range_check_lut],
+        ));
+
+        eval.finalize_logup();
+
+        eval
+    }
+}
+
+/// Constrains `value` to `[0, 2^bits)`, through its low and high `limbs`.
+///
+/// The limbs recompose `value`, and are sent to the range-check lookup with multiplicity
+/// `mult`, each followed by its gap to its maximal value (`N_LIMB_RANGE_CHECKS` entries).
+pub fn eval_limbs<E: EvalAtRow>(
+    eval: &mut E,
+    range_check_elements: &RangeCheckLookupElements,
+    mult: &E::F,
+    value: E::F,
+    limbs: [E::F; 2],
+    bits: u32,
+) {
+    let recomposed = eval_limbs_range_checks(eval, range_check_elements, mult, limbs, bits);
+    eval.add_constraint(value - recomposed);
+}
+
+/// Constrains `value` to `(-2^bits, 2^bits)`, through its `sign` and the low and high `limbs`
+/// of its magnitude.
+///
+/// The sign is boolean and `value = (1 - 2 * sign) * magnitude`, with the magnitude limbs
+/// range-checked as in `eval_limbs`.
+pub fn eval_signed_limbs<E: EvalAtRow>(
+    eval: &mut E,
+    range_check_elements: &RangeCheckLookupElements,
+    mult: &E::F,
+    value: E::F,
+    sign: E::F,
+    limbs: [E::F; 2],
+    bits: u32,
+) {
+    let magnitude = eval_limbs_range_checks(eval, range_check_elements, mult, limbs, bits);
+    eval.add_constraint(sign.clone() * (sign.clone() - E::F::one()));
+    eval.add_constraint(value - (E::F::one() - sign.clone() - sign) * magnitude);
+}
+
+/// Sends the `limbs` and their gaps to their maximal values to the range-check lookup,
+/// and returns the value they recompose.
+fn eval_limbs_range_checks<E: EvalAtRow>(
+    eval: &mut E,
+    range_check_elements: &RangeCheckLookupElements,
+    mult: &E::F,
+    limbs: [E::F; 2],
+    bits: u32,
+) -> E::F {
+    let (lo_bits, hi_bits) = limb_bits(bits);
+    let [lo, hi] = limbs;
+    let max_lo = E::F::from(M31::from_u32_unchecked((1 << lo_bits) - 1));
+    let max_hi = E::F::from(M31::from_u32_unchecked((1 << hi_bits) - 1));
+    let lo_shift = E::F::from(M31::from_u32_unchecked(1 << lo_bits));
+
+    for value in [
+        lo.clone(),
+        max_lo - lo.clone(),
+        hi.clone(),
+        max_hi - hi.clone(),
+    ] {
+        eval.add_to_relation(RelationEntry::new(
+            range_check_elements,
+            mult.clone().into(),
+            &[value],
+        ));
+    }
+
+    lo + hi * lo_shift
+}
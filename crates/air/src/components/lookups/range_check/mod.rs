@@ -0,0 +1,90 @@
+use std::sync::atomic::Ordering;
+
+use serde::{Deserialize, Serialize};
+use stwo_prover::{core::fields::m31::BaseField, relation};
+use table::{RangeCheckLookupTraceTable, RangeCheckLookupTraceTableRow};
+
+use crate::{
+    fixed_point::Fixed,
+    preprocessed::{LookupLayout, Range},
+    utils::AtomicMultiplicityColumn,
+};
+
+pub mod component;
+pub mod table;
+pub mod witness;
+
+// Interaction elements specifically for the Range Check Lookup Table argument.
+// Drawn from the channel, used to combine the single value of each range-check entry.
+relation!(RangeCheckLookupElements, 1);
+
+/// Number of values sent to the range-check lookup for a value split in two limbs:
+/// each limb, followed by its gap to its maximal value.
+pub const N_LIMB_RANGE_CHECKS: usize = 4;
+
+/// Returns the bits of the low and high limbs of a value in `[0, 2^bits)`.
+///
+/// Values too wide for the range-check table are split in two limbs, each looked up in the
+/// table, so that the table only needs `2^ceil(bits / 2)` entries.
+pub fn limb_bits(bits: u32) -> (u32, u32) {
+    (bits.div_ceil(2), bits / 2)
+}
+
+/// Splits `value` into its low and high limbs, as laid out by `limb_bits`.
+///
+/// Returns `None` if `value` lies outside `[0, 2^bits)`.
+pub fn split_limbs(value: i64, bits: u32) -> Option<[i64; 2]> {
+    if !(0..1 << bits).contains(&value) {
+        return None;
+    }
+    let (lo_bits, _) = limb_bits(bits);
+    Some([value & ((1 << lo_bits) - 1), value >> lo_bits])
+}
+
+/// Configuration and data for the shared Range Check Lookup Table (LUT).
+///
+/// The table covers every integer in `[0, 2^log_size)`. Components holding values
+/// that must be non-negative and bounded (e.g. fixed-point remainders) look them up
+/// in this table, and the `AtomicMultiplicityColumn` tracks how often each entry is used.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RangeCheckLookup {
+    /// The layout of the range-check table, a single range `[0, 2^log_size - 1]`.
+    pub layout: LookupLayout,
+    /// Atomic counters tracking the number of times each LUT entry is accessed.
+    pub multiplicities: AtomicMultiplicityColumn,
+}
+
+impl RangeCheckLookup {
+    /// Creates a new `RangeCheckLookup` covering `[0, 2^log_size)`.
+    ///
+    /// The entries are plain integers, hence a layout at scale zero.
+    pub fn new(log_size: u32) -> Self {
+        let layout = LookupLayout::new(vec![Range(Fixed(0), Fixed((1 << log_size) - 1))], 0);
+        let multiplicities = AtomicMultiplicityColumn::new(1 << layout.log_size);
+        Self {
+            layout,
+            multiplicities,
+        }
+    }
+
+    /// Records one access to the table entry holding `value`.
+    ///
+    /// Returns `None`, recording nothing, if `value` lies outside `[0, 2^log_size)`.
+    pub fn add(&mut self, value: i64) -> Option<()> {
+        let mult_address = self.layout.find_index(value)?;
+        self.multiplicities.increase_at(mult_address);
+        Some(())
+    }
+
+    /// Populates a `RangeCheckLookupTraceTable` with the final multiplicity counts.
+    ///
+    /// This table is used by the `RangeCheckLookupComponent` to generate the trace columns
+    /// for proving the lookup argument (i.e., that the sum of multiplicities matches accesses).
+    pub fn add_multiplicities_to_table(&self, table: &mut RangeCheckLookupTraceTable) {
+        for mult in &self.multiplicities.data {
+            table.add_row(RangeCheckLookupTraceTableRow {
+                multiplicity: BaseField::from_u32_unchecked(mult.load(Ordering::Relaxed)),
+            });
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+use stwo_prover::core::{
+    backend::simd::{
+        conversion::{Pack, Unpack},
+        m31::{PackedM31, N_LANES},
+    },
+    fields::m31::M31,
+};
+
+use crate::components::TraceColumn;
+
+use super::witness::N_TRACE_COLUMNS;
+
+/// Represents the raw trace data for the Range Check Lookup Table (LUT) component.
+///
+/// This table primarily stores the multiplicity (count of accesses) for each entry
+/// in the preprocessed range-check LUT. It's populated from `RangeCheckLookup::multiplicities`.
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RangeCheckLookupTraceTable {
+    /// Vector of rows, where each row corresponds to an entry in the range-check LUT.
+    pub table: Vec<RangeCheckLookupTraceTableRow>,
+}
+
+/// Represents a single row in the `RangeCheckLookupTraceTable`.
+/// Corresponds to one entry in the preprocessed range-check LUT.
+#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RangeCheckLookupTraceTableRow {
+    /// The number of times this specific LUT entry was range-checked
+    /// by operations in the main computation trace.
+    pub multiplicity: M31,
+}
+
+impl RangeCheckLookupTraceTableRow {
+    /// Creates a default padding row for the RangeCheckLookup trace (multiplicity 0).
+    pub(crate) fn padding() -> Self {
+        Self {
+            multiplicity: M31::zero(),
+        }
+    }
+}
+
+/// SIMD-packed representation of a `RangeCheckLookupTraceTableRow`.
+#[derive(Debug, Copy, Clone)]
+pub struct PackedRangeCheckLookupTraceTableRow {
+    /// Packed multiplicity values.
+    pub multiplicity: PackedM31,
+}
+
+impl Pack for RangeCheckLookupTraceTableRow {
+    type SimdType = PackedRangeCheckLookupTraceTableRow;
+
+    fn pack(inputs: [Self; N_LANES]) -> Self::SimdType {
+        PackedRangeCheckLookupTraceTableRow {
+            multiplicity: PackedM31::from_array(std::array::from_fn(|i| inputs[i].multiplicity)),
+        }
+    }
+}
+
+impl Unpack for PackedRangeCheckLookupTraceTableRow {
+    type CpuType = RangeCheckLookupTraceTableRow;
+
+    fn unpack(self) -> [Self::CpuType; N_LANES] {
+        let multiplicities = self.multiplicity.to_array();
+
+        std::array::from_fn(|i| RangeCheckLookupTraceTableRow {
+            multiplicity: multiplicities[i],
+        })
+    }
+}
+
+impl RangeCheckLookupTraceTable {
+    /// Creates a new, empty `RangeCheckLookupTraceTable`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a single row (multiplicity count) to the trace table.
+    pub fn add_row(&mut self, row: RangeCheckLookupTraceTableRow) {
+        self.table.push(row);
+    }
+}
+
+/// Enum defining the columns of the RangeCheckLookup AIR component's trace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum RangeCheckLookupColumn {
+    /// Column storing the multiplicity of access for each LUT entry.
+    Multiplicity,
+}
+
+impl RangeCheckLookupColumn {
+    /// Returns the 0-based index for this column within the RangeCheckLookup trace segment.
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Multiplicity => 0,
+        }
+    }
+}
+
+/// Implements the `TraceColumn` trait for `RangeCheckLookupColumn`.
+impl TraceColumn for RangeCheckLookupColumn {
+    /// Specifies the number of columns used by the RangeCheckLookup component.
+    /// Returns `(N_TRACE_COLUMNS, 1)`, indicating main trace columns for multiplicities
+    /// and 1 interaction trace column for the LogUp argument that connects these
+    /// multiplicities to the preprocessed LUT values.
+    fn count() -> (usize, usize) {
+        (N_TRACE_COLUMNS, 1)
+    }
+}
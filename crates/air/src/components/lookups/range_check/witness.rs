@@ -0,0 +1,187 @@
+use luminair_utils::TraceError;
+use num_traits::One;
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use stwo_air_utils::trace::component_trace::ComponentTrace;
+use stwo_air_utils_derive::{IterMut, ParIterMut, Uninitialized};
+use stwo_prover::{
+    constraint_framework::{logup::LogupTraceGenerator, Relation},
+    core::{
+        backend::simd::{
+            m31::{PackedM31, LOG_N_LANES, N_LANES},
+            qm31::PackedQM31,
+            SimdBackend,
+        },
+        fields::m31::M31,
+    },
+};
+
+use crate::{
+    components::{InteractionClaim, RangeCheckLookupClaim},
+    preprocessed::RangeCheckPreProcessed,
+    utils::{pack_values, TreeBuilder},
+};
+
+use super::{
+    limb_bits,
+    table::{
+        PackedRangeCheckLookupTraceTableRow, RangeCheckLookupColumn, RangeCheckLookupTraceTable,
+        RangeCheckLookupTraceTableRow,
+    },
+    RangeCheckLookupElements, N_LIMB_RANGE_CHECKS,
+};
+
+/// Number of main trace columns for the RangeCheckLookup component (only multiplicity).
+pub(crate) const N_TRACE_COLUMNS: usize = 1;
+
+/// Returns the values range-checked for the low and high `limbs` of values in `[0, 2^bits)`:
+/// each limb, followed by its gap to its maximal value, as sent by `eval_limbs`.
+pub fn limb_range_checks(limbs: [PackedM31; 2], bits: u32) -> [PackedM31; N_LIMB_RANGE_CHECKS] {
+    let (lo_bits, hi_bits) = limb_bits(bits);
+    let [max_lo, max_hi] = [lo_bits, hi_bits]
+        .map(|bits| PackedM31::broadcast(M31::from_u32_unchecked((1 << bits) - 1)));
+    let [lo, hi] = limbs;
+    [lo, max_lo - lo, hi, max_hi - hi]
+}
+
+/// Generates main trace and interaction data for the RangeCheckLookup component.
+///
+/// Takes the `RangeCheckLookupTraceTable` (containing multiplicities), processes it into
+/// a single main trace column, and prepares data for the LogUp interaction.
+pub struct ClaimGenerator {
+    /// The raw trace data (multiplicities) for the RangeCheckLookup.
+    pub inputs: RangeCheckLookupTraceTable,
+}
+
+impl ClaimGenerator {
+    /// Creates a new `ClaimGenerator` with the given `RangeCheckLookupTraceTable`.
+    pub fn new(inputs: RangeCheckLookupTraceTable) -> Self {
+        Self { inputs }
+    }
+
+    /// Writes the main trace column (multiplicities) and returns data for interaction.
+    ///
+    /// Standard procedure: pads, packs, calls `write_trace_simd`,
+    /// adds main trace to `tree_builder`, returns `RangeCheckLookupClaim` and `InteractionClaimGenerator`.
+    /// Returns `TraceError::EmptyTrace` if the input table is empty.
+    pub fn write_trace(
+        mut self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+    ) -> Result<(RangeCheckLookupClaim, InteractionClaimGenerator), TraceError> {
+        let n_rows = self.inputs.table.len();
+
+        if n_rows == 0 {
+            return Err(TraceError::EmptyTrace);
+        }
+
+        let size = std::cmp::max(n_rows.next_power_of_two(), N_LANES);
+        let log_size = size.ilog2();
+
+        self.inputs
+            .table
+            .resize(size, RangeCheckLookupTraceTableRow::padding());
+        let packed_inputs = pack_values(&self.inputs.table);
+
+        let (trace, lookup_data) = write_trace_simd(packed_inputs);
+
+        tree_builder.extend_evals(trace.to_evals());
+
+        Ok((
+            RangeCheckLookupClaim::new(log_size),
+            InteractionClaimGenerator {
+                log_size,
+                lookup_data,
+            },
+        ))
+    }
+}
+
+/// Populates the main trace column (multiplicity) and `LookupData` from packed rows.
+///
+/// - The main trace column directly takes the `multiplicity` values.
+/// - `LookupData` also stores these multiplicities for the interaction phase.
+/// Returns the `ComponentTrace` and `LookupData`.
+fn write_trace_simd(
+    inputs: Vec<PackedRangeCheckLookupTraceTableRow>,
+) -> (ComponentTrace<N_TRACE_COLUMNS>, LookupData) {
+    let log_n_packed_rows = inputs.len().ilog2();
+    let log_size = log_n_packed_rows + LOG_N_LANES;
+
+    let (mut trace, mut lookup_data) = unsafe {
+        (
+            ComponentTrace::<N_TRACE_COLUMNS>::uninitialized(log_size),
+            LookupData::uninitialized(log_n_packed_rows),
+        )
+    };
+
+    (
+        trace.par_iter_mut(),
+        lookup_data.par_iter_mut(),
+        inputs.into_par_iter(),
+    )
+        .into_par_iter()
+        .for_each(|(mut row, lookup_data, input)| {
+            *row[RangeCheckLookupColumn::Multiplicity.index()] = input.multiplicity;
+
+            *lookup_data.multiplicities = input.multiplicity;
+        });
+
+    (trace, lookup_data)
+}
+
+/// Intermediate data structure for the RangeCheckLookup LogUp argument.
+/// Only stores the multiplicities, as the values come from the preprocessed LUT.
+#[derive(Uninitialized, IterMut, ParIterMut)]
+struct LookupData {
+    /// Multiplicities for each entry in the range-check LUT.
+    multiplicities: Vec<PackedM31>,
+}
+
+/// Generates the interaction trace column for the RangeCheckLookup component's LogUp argument.
+///
+/// This LogUp argument connects the multiplicities (from the main RangeCheckLookup trace)
+/// with the values of the preprocessed range-check LUT.
+pub struct InteractionClaimGenerator {
+    /// Log2 size of the trace.
+    log_size: u32,
+    /// Multiplicity data for the LogUp argument.
+    lookup_data: LookupData,
+}
+
+impl InteractionClaimGenerator {
+    /// Writes the LogUp interaction trace column to the `tree_builder`.
+    ///
+    /// 1. Initializes a `LogupTraceGenerator`.
+    /// 2. For each entry:
+    ///    a. Retrieves the table value directly from the preprocessed `RangeCheckPreProcessed` column (`lut`).
+    ///    b. Retrieves the `multiplicity` from `self.lookup_data`.
+    ///    c. Combines the value with `elements` (RangeCheckLookupElements) to form the denominator.
+    ///    d. The numerator for the LogUp fraction is `-multiplicity`.
+    ///    e. Writes the fraction to the LogUp column.
+    /// 3. Finalizes the generator, adds the interaction column to `tree_builder`, returns `InteractionClaim`.
+    pub fn write_interaction_trace(
+        self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+        elements: &RangeCheckLookupElements,
+        lut: &RangeCheckPreProcessed,
+    ) -> InteractionClaim {
+        let mut logup_gen = LogupTraceGenerator::new(self.log_size);
+
+        let mut col_gen = logup_gen.new_col();
+        let lut_col = &lut.evaluation().data;
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let multiplicity: PackedQM31 = self.lookup_data.multiplicities[row].into();
+            let value = lut_col[row];
+
+            let denom: PackedQM31 = elements.combine(&[value]);
+            let num: PackedQM31 = -PackedQM31::one() * multiplicity;
+
+            col_gen.write_frac(row, num, denom);
+        }
+        col_gen.finalize_col();
+
+        let (trace, claimed_sum) = logup_gen.finalize_last();
+        tree_builder.extend_evals(trace);
+
+        InteractionClaim { claimed_sum }
+    }
+}
@@ -0,0 +1,89 @@
+use stwo_prover::constraint_framework::{
+    preprocessed_columns::PreProcessedColumnId, EvalAtRow, FrameworkComponent, FrameworkEval,
+};
+
+use crate::components::UnaryLookupClaim;
+
+use super::LutFunction;
+
+/// The STWO AIR component for the Lookup Table (LUT) argument of the unary function `F`.
+///
+/// This component ensures that the multiplicities recorded for each entry of the
+/// preprocessed LUT of `F` correctly correspond to the actual values in the LUT.
+/// It works in conjunction with the `UnaryComponent` of `F` which records accesses.
+pub type UnaryLookupComponent<F> = FrameworkComponent<UnaryLookupEval<F>>;
+
+/// Defines the AIR constraints evaluation logic for the UnaryLookup component of `F`.
+/// Implements `FrameworkEval` to connect the multiplicity trace with the preprocessed LUT.
+pub struct UnaryLookupEval<F: LutFunction> {
+    /// Log2 size of the component's main trace segment.
+    log_size: u32,
+    /// Interaction elements specific to the LUT LogUp of `F`.
+    lookup_elements: F::Elements,
+    /// Whether the LUT is interpolated, its entries holding a slope column.
+    interpolated: bool,
+}
+
+impl<F: LutFunction> UnaryLookupEval<F> {
+    /// Creates a new `UnaryLookupEval` instance.
+    /// Takes the component's claim (for `log_size`), the LUT interaction elements of `F`,
+    /// and whether its LUT is interpolated.
+    pub fn new(claim: &UnaryLookupClaim, lookup_elements: F::Elements, interpolated: bool) -> Self {
+        Self {
+            log_size: claim.log_size,
+            lookup_elements,
+            interpolated,
+        }
+    }
+}
+
+/// Implements the core constraint evaluation logic for the UnaryLookup component.
+impl<F: LutFunction> FrameworkEval for UnaryLookupEval<F> {
+    /// Returns the log2 size of this component's main trace segment.
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// Returns the maximum expected log2 degree bound for the component's constraints.
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.log_size + 1
+    }
+
+    /// Evaluates the UnaryLookup AIR constraints on a given evaluation point (`eval`).
+    ///
+    /// This component has one primary role: to add terms to the LogUp sum that correspond
+    /// to the preprocessed Lookup Table entries of `F`, weighted by their recorded multiplicities.
+    ///
+    /// 1. Retrieves the preprocessed LUT columns (`{name}_lut_0` for inputs, `{name}_lut_1` for outputs,
+    ///    and `{name}_lut_2` for the slopes of an interpolated LUT).
+    /// 2. Retrieves the `multiplicity` from the UnaryLookup component's main trace.
+    /// 3. Adds an entry to the LogUp relation of `F`:
+    ///    - Numerator: `-multiplicity` (negative because these are the "table side" entries).
+    ///    - Denominator: Combination of the entry `(lut_0, lut_1[, lut_2])` with `self.lookup_elements`.
+    /// This constraint, when combined with the corresponding positive terms from `UnaryComponent`,
+    /// ensures that `sum (access_multiplicity / P(access_val)) - sum (table_multiplicity / P(table_val)) = 0`,
+    /// thus proving that values looked up via `UnaryComponent` correctly match the preprocessed LUT.
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        let n_cols = if self.interpolated { 3 } else { 2 };
+        let entry = (0..n_cols)
+            .map(|col_index| {
+                eval.get_preprocessed_column(PreProcessedColumnId {
+                    id: format!("{}_lut_{}", F::NAME, col_index),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let multiplicity = eval.next_trace_mask();
+
+        F::add_to_relation(
+            &mut eval,
+            &self.lookup_elements,
+            -E::EF::from(multiplicity),
+            &entry,
+        );
+
+        eval.finalize_logup();
+
+        eval
+    }
+}
@@ -0,0 +1,154 @@
+//! Unary functions proven through a Lookup Table.
+//!
+//! Each function is listed once in the `lut_functions!` registry, which declares it
+//! with `lut_function!`: its lookup relation, preprocessed column IDs and `UnaryLookup`.
+//! The structures holding data for every function (lookups, claims, components, trace tables)
+//! are generated from the registry, and the components are generic over the `LutFunction`.
+
+use std::f64::consts::{FRAC_1_SQRT_2, FRAC_2_SQRT_PI};
+
+use luminair_utils::LuminairError;
+use stwo_prover::{
+    constraint_framework::{EvalAtRow, Relation, RelationEntry},
+    core::{
+        backend::simd::{m31::PackedM31, qm31::PackedQM31},
+        channel::Channel,
+    },
+    relation,
+};
+
+use super::{LutFunction, UnaryLookup};
+use crate::{fixed_point::Fixed, preprocessed::Range};
+
+/// Registry of the unary functions proven through a LUT, each listed once.
+///
+/// Functions are split between the ones applied by a unary operator (`unary`), each with
+/// a unary component and a lookup component, and the ones only looked up by other components
+/// (`lookup_only`), such as `Exp` for the softmax. An entry holds the doc and name of the
+/// function, its evaluation, and the optional `domain` and `range` overrides of `LutFunction`.
+///
+/// `lut_functions!(callback)` invokes `callback!` with the functions as
+/// `[Name, name, name_lookup, NameLookup]`: the function, the field of its data and of its
+/// lookup component in the generated structures, and its `UnaryLookup` type. The `unary`
+/// functions are listed first, then `all` the functions, in the order of the registry:
+///
+/// ```ignore
+/// callback! {
+///     unary [$([$name:ident, $field:ident, $lookup:ident, $Lookup:ident])*]
+///     all [$([$name:ident, $field:ident, $lookup:ident, $Lookup:ident])*]
+/// }
+/// ```
+///
+/// Components are laid out after `LessThan`: the unary components first, then the lookup
+/// components of all the functions.
+#[macro_export]
+macro_rules! lut_functions {
+    ($callback:ident) => {
+        $crate::lut_functions! {
+            @list $callback;
+            unary {
+                /// Sine, `sin(x)`.
+                Sin, f64::sin;
+                /// Base-2 exponential, `2^x`.
+                Exp2, f64::exp2;
+                /// Base-2 logarithm, `log2(x)`, only defined on strictly positive inputs.
+                Log2, f64::log2, domain = log2_domain, range = log2_range;
+                /// Hyperbolic tangent, `tanh(x)`.
+                Tanh, f64::tanh;
+                /// Logistic sigmoid, `1 / (1 + e^-x)`.
+                Sigmoid, sigmoid;
+                /// Gaussian Error Linear Unit, in its tanh approximation
+                /// `0.5 * x * (1 + tanh(sqrt(2 / pi) * (x + 0.044715 * x^3)))`.
+                Gelu, gelu;
+                /// Sigmoid Linear Unit, `x * sigmoid(x)`.
+                Silu, silu;
+            }
+            lookup_only {
+                /// Natural exponential, `e^x`, as used by the normalization of the softmax.
+                Exp, f64::exp;
+            }
+        }
+    };
+    (
+        @list declare;
+        unary {$(
+            $(#[$attr:meta])* $name:ident, $f:expr
+            $(, domain = $domain:path)? $(, range = $range:path)?;
+        )*}
+        lookup_only {$(
+            $(#[$lattr:meta])* $lname:ident, $lf:expr
+            $(, domain = $ldomain:path)? $(, range = $lrange:path)?;
+        )*}
+    ) => {
+        $crate::paste::paste! {
+            $(
+                lut_function!(
+                    $(#[$attr])* $name, [<$name:snake>], [<$name LookupElements>],
+                    [<$name Lookup>], $f $(, domain = $domain)? $(, range = $range)?
+                );
+            )*
+            $(
+                lut_function!(
+                    $(#[$lattr])* $lname, [<$lname:snake>], [<$lname LookupElements>],
+                    [<$lname Lookup>], $lf $(, domain = $ldomain)? $(, range = $lrange)?
+                );
+            )*
+        }
+    };
+    (
+        @list $callback:ident;
+        unary {$(
+            $(#[$attr:meta])* $name:ident, $f:expr
+            $(, domain = $domain:path)? $(, range = $range:path)?;
+        )*}
+        lookup_only {$(
+            $(#[$lattr:meta])* $lname:ident, $lf:expr
+            $(, domain = $ldomain:path)? $(, range = $lrange:path)?;
+        )*}
+    ) => {
+        $crate::paste::paste! {
+            $callback! {
+                unary [$([$name, [<$name:snake>], [<$name:snake _lookup>], [<$name Lookup>]])*]
+                all [
+                    $([$name, [<$name:snake>], [<$name:snake _lookup>], [<$name Lookup>]])*
+                    $([$lname, [<$lname:snake>], [<$lname:snake _lookup>], [<$lname Lookup>]])*
+                ]
+            }
+        }
+    };
+}
+
+lut_functions!(declare);
+
+/// Evaluates the logistic sigmoid, `1 / (1 + e^-x)`.
+fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Evaluates the tanh approximation of the GELU, as lowered by luminal.
+fn gelu(x: f64) -> f64 {
+    let inner = FRAC_2_SQRT_PI * FRAC_1_SQRT_2 * (x + 0.044715 * x.powi(3));
+    0.5 * x * (1.0 + inner.tanh())
+}
+
+/// Evaluates the Sigmoid Linear Unit, `x * sigmoid(x)`.
+fn silu(x: f64) -> f64 {
+    x * sigmoid(x)
+}
+
+/// Rejects the non-positive inputs of `Log2`, which have no logarithm.
+fn log2_domain(x: Fixed, scale: u32, node_id: u32, idx: usize) -> Result<(), LuminairError> {
+    if x.0 > 0 {
+        return Ok(());
+    }
+    Err(LuminairError::NonPositiveLog2Input {
+        node_id,
+        idx,
+        value: x.to_f64(scale),
+    })
+}
+
+/// Restricts the inputs of the `Log2` LUT to strictly positive values.
+fn log2_range(range: Range) -> Range {
+    Range(Fixed(range.0 .0.max(1)), Fixed(range.1 .0.max(1)))
+}
@@ -0,0 +1,341 @@
+use std::{fmt::Debug, marker::PhantomData, sync::atomic::Ordering};
+
+use luminair_utils::LuminairError;
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+use stwo_prover::{
+    constraint_framework::EvalAtRow,
+    core::{
+        backend::simd::{m31::PackedM31, qm31::PackedQM31},
+        channel::Channel,
+        fields::m31::BaseField,
+    },
+};
+use table::{UnaryLookupTraceTable, UnaryLookupTraceTableRow};
+
+use super::OutOfRangePolicy;
+use crate::{
+    fixed_point::Fixed,
+    preprocessed::{Interpolation, LookupLayout, Range},
+    utils::{bit_length, AtomicMultiplicityColumn},
+};
+
+/// Defines a `LutFunction`, the relation of its lookup argument and its lookup.
+///
+/// `lut_function!(Name, name, NameLookupElements, NameLookup, f)` declares the relation
+/// `NameLookupElements`, the unit struct `Name` and its `LutFunction` implementation,
+/// evaluating `f: fn(f64) -> f64` under the preprocessed column IDs `name_lut_{0,1}`
+/// (and `name_lut_2`, holding the slopes of an interpolated LUT), and the `NameLookup` alias
+/// of its `UnaryLookup`.
+/// A trailing `domain = check` overrides `LutFunction::check_domain` with the function `check`,
+/// and `range = restrict` overrides `LutFunction::restrict_range` with the function `restrict`.
+/// Invoked for each entry of the `lut_functions!` registry, and expands to items resolved
+/// in the scope of `functions`.
+macro_rules! lut_function {
+    (
+        $(#[$attr:meta])* $name:ident, $field:ident, $elements:ident, $lookup:ident, $f:expr
+        $(, domain = $domain:path)? $(, range = $range:path)?
+    ) => {
+        // Interaction elements for the lookup argument of the function.
+        // Drawn from the channel, used to combine `(input, output)` pairs from its LUT,
+        // along with the slope of the entry for an interpolated LUT.
+        relation!($elements, 3);
+
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct $name;
+
+        impl LutFunction for $name {
+            const NAME: &'static str = stringify!($field);
+            const OP_NAME: &'static str = stringify!($name);
+            type Elements = $elements;
+
+            fn eval(x: f64) -> f64 {
+                ($f)(x)
+            }
+
+            $(
+                fn check_domain(
+                    x: Fixed,
+                    scale: u32,
+                    node_id: u32,
+                    idx: usize,
+                ) -> Result<(), LuminairError> {
+                    $domain(x, scale, node_id, idx)
+                }
+            )?
+
+            $(
+                fn restrict_range(range: Range) -> Range {
+                    $range(range)
+                }
+            )?
+
+            fn draw_elements(channel: &mut impl Channel) -> $elements {
+                $elements::draw(channel)
+            }
+
+            fn add_to_relation<E: EvalAtRow>(
+                eval: &mut E,
+                elements: &$elements,
+                multiplicity: E::EF,
+                values: &[E::F],
+            ) {
+                eval.add_to_relation(RelationEntry::new(elements, multiplicity, values));
+            }
+
+            fn combine(elements: &$elements, values: &[PackedM31]) -> PackedQM31 {
+                <$elements as Relation<PackedM31, PackedQM31>>::combine(elements, values)
+            }
+        }
+
+        #[doc = concat!("Lookup argument into the ", stringify!($name), " LUT.")]
+        pub type $lookup = UnaryLookup<$name>;
+    };
+}
+
+pub mod component;
+pub mod functions;
+pub mod table;
+pub mod witness;
+
+/// A unary function proven through a preprocessed Lookup Table (LUT) of `(x, f(x))` pairs.
+///
+/// The function only defines how it is evaluated and its own lookup relation:
+/// `UnaryLookup`, `UnaryPreProcessed` and the unary components are generic over it.
+/// Implementations are listed in the `lut_functions!` registry, see `functions`.
+pub trait LutFunction: Clone + Debug + Send + Sync + 'static {
+    /// Name of the function, prefixing the IDs of its preprocessed columns (e.g. `sin_lut_0`).
+    const NAME: &'static str;
+    /// Name of the operator applying the function, as reported in errors (e.g. `Sin`).
+    const OP_NAME: &'static str;
+    /// Interaction elements of the lookup relation of the function.
+    type Elements: Clone + Debug + Send + Sync;
+
+    /// Evaluates the function on a real input.
+    fn eval(x: f64) -> f64;
+
+    /// Checks that the function is defined at the input `x` of node `node_id`, at index `idx`.
+    ///
+    /// Defaults to a function defined everywhere.
+    fn check_domain(
+        _x: Fixed,
+        _scale: u32,
+        _node_id: u32,
+        _idx: usize,
+    ) -> Result<(), LuminairError> {
+        Ok(())
+    }
+
+    /// Restricts a range of inputs, observed while inferring the circuit settings,
+    /// to the domain of the function.
+    ///
+    /// Defaults to a function defined everywhere.
+    fn restrict_range(range: Range) -> Range {
+        range
+    }
+
+    /// Draws the interaction elements of the lookup relation from the channel.
+    fn draw_elements(channel: &mut impl Channel) -> Self::Elements;
+
+    /// Adds an `(input, output)` pair to the lookup relation, with the given multiplicity.
+    /// Entries of an interpolated LUT also hold their slope, as a third value.
+    fn add_to_relation<E: EvalAtRow>(
+        eval: &mut E,
+        elements: &Self::Elements,
+        multiplicity: E::EF,
+        values: &[E::F],
+    );
+
+    /// Combines packed LUT entries into the denominators of the lookup relation.
+    fn combine(elements: &Self::Elements, values: &[PackedM31]) -> PackedQM31;
+
+    /// Evaluates the function on a fixed-point input at `scale`, as written in its LUT.
+    ///
+    /// Inputs outside the domain of the function, where it is not finite, evaluate to zero.
+    fn eval_fixed(x: Fixed, scale: u32) -> Fixed {
+        let y = Self::eval(x.to_f64(scale));
+        if y.is_finite() {
+            Fixed::from_f64(y, scale)
+        } else {
+            Fixed::zero()
+        }
+    }
+
+    /// Evaluates the slope of the function from `x` to `x + step`, at `scale`, as written
+    /// in an interpolated LUT with breakpoints every `step` values.
+    ///
+    /// Slopes that are not finite, reaching outside the domain of the function, are zero.
+    fn slope_fixed(x: Fixed, step: i64, scale: u32) -> Fixed {
+        let (x_0, x_1) = (x.to_f64(scale), Fixed(x.0 + step).to_f64(scale));
+        let slope = (Self::eval(x_1) - Self::eval(x_0)) / (x_1 - x_0);
+        if slope.is_finite() {
+            Fixed::from_f64(slope, scale)
+        } else {
+            Fixed::zero()
+        }
+    }
+
+    /// Interpolates the function at `x` from the entry of the LUT at `breakpoint`,
+    /// with breakpoints every `step` values.
+    ///
+    /// In an exact LUT (`step` of one), `x` is the breakpoint and its output is the entry's.
+    fn interpolate(x: Fixed, breakpoint: Fixed, step: i64, scale: u32) -> LutOutput {
+        let base = Self::eval_fixed(breakpoint, scale);
+        let slope = if step > 1 {
+            Self::slope_fixed(breakpoint, step, scale)
+        } else {
+            Fixed::zero()
+        };
+        let offset = x.0 - breakpoint.0;
+        let product = slope.0 * offset;
+        LutOutput {
+            offset,
+            base,
+            slope,
+            rem: product.rem_euclid(1 << scale),
+            out: Fixed(base.0 + product.div_euclid(1 << scale)),
+        }
+    }
+}
+
+/// Output of a unary function at an input of its LUT, and the entry it is derived from.
+///
+/// The output satisfies `(out - base) * 2^scale + rem = slope * offset`, with `rem` in
+/// `[0, 2^scale)`. Entries of an exact LUT have no offset nor slope, and `out` is `base`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LutOutput {
+    /// Distance between the input and the breakpoint of its entry, in `[0, 2^step_bits)`.
+    pub offset: i64,
+    /// Output of the function at the breakpoint.
+    pub base: Fixed,
+    /// Slope of the function from the breakpoint to the next one.
+    pub slope: Fixed,
+    /// Remainder of the rounding of `slope * offset` down to the scale of the LUT.
+    pub rem: i64,
+    /// Output of the function at the input.
+    pub out: Fixed,
+}
+
+/// Chooses the layout of the LUT of `F` over `ranges`, at `scale`.
+///
+/// Without `max_error`, every value of the ranges is an entry of the LUT. Otherwise, the LUT
+/// is interpolated with the widest breakpoint spacing (up to one unit, `2^scale` values) whose
+/// output stays within `max_error` of `F`, or exact if none does.
+///
+/// The error is measured at the middle and at the end of the segment of each breakpoint.
+/// The interpolated increments must also stay below `2^(29 - scale)`, so that the rounding
+/// of `slope * offset` leaves a single increment per remainder in the field.
+pub fn fit_layout<F: LutFunction>(
+    ranges: Vec<Range>,
+    scale: u32,
+    max_error: Option<f64>,
+) -> LookupLayout {
+    let Some(max_error) = max_error else {
+        return LookupLayout::new(ranges, scale);
+    };
+
+    for step_bits in (1..=scale).rev() {
+        let step = 1 << step_bits;
+        let mut max_increment = 0;
+        let mut fits = true;
+        let breakpoints = ranges
+            .iter()
+            .flat_map(|r| (r.0 .0..=r.1 .0).step_by(step as usize));
+        for breakpoint in breakpoints {
+            for offset in [step / 2, step - 1] {
+                let x = Fixed(breakpoint + offset);
+                let output = F::interpolate(x, Fixed(breakpoint), step, scale);
+                let error = (output.out.to_f64(scale) - F::eval(x.to_f64(scale))).abs();
+                // Skip the points outside the domain of the function.
+                if error.is_finite() && error > max_error {
+                    fits = false;
+                    break;
+                }
+                max_increment = max_increment.max((output.out.0 - output.base.0).abs());
+            }
+            if !fits {
+                break;
+            }
+        }
+
+        let delta_bits = bit_length(max_increment);
+        if fits && scale + delta_bits <= 29 {
+            let interpolation = Interpolation {
+                step_bits,
+                delta_bits,
+            };
+            return LookupLayout::interpolated(ranges, scale, interpolation);
+        }
+    }
+
+    LookupLayout::new(ranges, scale)
+}
+
+/// Configuration and data for the Lookup Table (LUT) of the unary function `F`.
+///
+/// Holds the `LookupLayout` (defining value ranges and size), an `AtomicMultiplicityColumn`
+/// to track accesses to LUT entries, and the policy applied to inputs outside of the layout.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(bound = "")]
+pub struct UnaryLookup<F: LutFunction> {
+    /// The layout defining the structure and value ranges of the LUT.
+    pub layout: LookupLayout,
+    /// Atomic counters tracking the number of times each LUT entry is accessed.
+    pub multiplicities: AtomicMultiplicityColumn,
+    /// Policy applied to inputs not covered by the layout.
+    #[serde(default)]
+    pub policy: OutOfRangePolicy,
+    /// Marker binding the lookup to its function.
+    #[serde(skip)]
+    _function: PhantomData<F>,
+}
+
+impl<F: LutFunction> UnaryLookup<F> {
+    /// Creates a new `UnaryLookup` instance based on the provided `LookupLayout`.
+    ///
+    /// Creates an `AtomicMultiplicityColumn` of the appropriate size (padded to power of two).
+    pub fn new(layout: &LookupLayout) -> Self {
+        let multiplicities = AtomicMultiplicityColumn::new(1 << layout.log_size);
+        Self {
+            layout: layout.clone(),
+            multiplicities,
+            policy: OutOfRangePolicy::default(),
+            _function: PhantomData,
+        }
+    }
+
+    /// Extends the layout to cover `targets`, resetting the multiplicities to its new size.
+    pub fn grow(&mut self, targets: Vec<i64>) {
+        self.layout.grow(targets);
+        self.multiplicities = AtomicMultiplicityColumn::new(1 << self.layout.log_size);
+    }
+
+    /// Returns the output of `F` at `lut_input`, a value covered by the layout, from the entry
+    /// of the LUT it is looked up at.
+    pub fn output(&self, lut_input: Fixed) -> LutOutput {
+        let breakpoint = self.layout.breakpoint(lut_input.0).unwrap();
+        let step = 1 << self.layout.step_bits();
+        F::interpolate(lut_input, breakpoint, step, self.layout.scale)
+    }
+
+    /// Returns whether any entry of the LUT was looked up since the multiplicities were reset.
+    pub fn is_used(&self) -> bool {
+        self.multiplicities
+            .data
+            .iter()
+            .any(|mult| mult.load(Ordering::Relaxed) > 0)
+    }
+
+    /// Populates a `UnaryLookupTraceTable` with the final multiplicity counts.
+    ///
+    /// This table is used by the `UnaryLookupComponent` to generate the trace columns
+    /// for proving the lookup argument (i.e., that the sum of multiplicities matches accesses).
+    pub fn add_multiplicities_to_table(&self, table: &mut UnaryLookupTraceTable) {
+        for mult in &self.multiplicities.data {
+            table.add_row(UnaryLookupTraceTableRow {
+                multiplicity: BaseField::from_u32_unchecked(mult.load(Ordering::Relaxed)),
+            });
+        }
+    }
+}
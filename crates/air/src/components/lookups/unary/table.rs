@@ -12,27 +12,27 @@ use crate::components::TraceColumn;
 
 use super::witness::N_TRACE_COLUMNS;
 
-/// Represents the raw trace data for the Sine Lookup Table (LUT) component.
+/// Represents the raw trace data for the Lookup Table (LUT) component of a unary function.
 ///
 /// This table primarily stores the multiplicity (count of accesses) for each entry
-/// in the preprocessed Sine LUT. It's populated from `SinLookup::multiplicities`.
+/// in the preprocessed LUT. It's populated from `UnaryLookup::multiplicities`.
 #[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
-pub struct SinLookupTraceTable {
-    /// Vector of rows, where each row corresponds to an entry in the Sine LUT.
-    pub table: Vec<SinLookupTraceTableRow>,
+pub struct UnaryLookupTraceTable {
+    /// Vector of rows, where each row corresponds to an entry in the LUT.
+    pub table: Vec<UnaryLookupTraceTableRow>,
 }
 
-/// Represents a single row in the `SinLookupTraceTable`.
-/// Corresponds to one entry in the preprocessed Sine LUT.
+/// Represents a single row in the `UnaryLookupTraceTable`.
+/// Corresponds to one entry in the preprocessed LUT.
 #[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
-pub struct SinLookupTraceTableRow {
+pub struct UnaryLookupTraceTableRow {
     /// The number of times this specific LUT entry (a pair of `(input, output)` values)
-    /// was accessed by Sin operations in the main computation trace.
+    /// was accessed by the operations of the function in the main computation trace.
     pub multiplicity: M31,
 }
 
-impl SinLookupTraceTableRow {
-    /// Creates a default padding row for the SinLookup trace (multiplicity 0).
+impl UnaryLookupTraceTableRow {
+    /// Creates a default padding row for the UnaryLookup trace (multiplicity 0).
     pub(crate) fn padding() -> Self {
         Self {
             multiplicity: M31::zero(),
@@ -40,56 +40,56 @@ impl SinLookupTraceTableRow {
     }
 }
 
-/// SIMD-packed representation of a `SinLookupTraceTableRow`.
+/// SIMD-packed representation of a `UnaryLookupTraceTableRow`.
 #[derive(Debug, Copy, Clone)]
-pub struct PackedSinLookupTraceTableRow {
+pub struct PackedUnaryLookupTraceTableRow {
     /// Packed multiplicity values.
     pub multiplicity: PackedM31,
 }
 
-impl Pack for SinLookupTraceTableRow {
-    type SimdType = PackedSinLookupTraceTableRow;
+impl Pack for UnaryLookupTraceTableRow {
+    type SimdType = PackedUnaryLookupTraceTableRow;
 
     fn pack(inputs: [Self; N_LANES]) -> Self::SimdType {
-        PackedSinLookupTraceTableRow {
+        PackedUnaryLookupTraceTableRow {
             multiplicity: PackedM31::from_array(std::array::from_fn(|i| inputs[i].multiplicity)),
         }
     }
 }
 
-impl Unpack for PackedSinLookupTraceTableRow {
-    type CpuType = SinLookupTraceTableRow;
+impl Unpack for PackedUnaryLookupTraceTableRow {
+    type CpuType = UnaryLookupTraceTableRow;
 
     fn unpack(self) -> [Self::CpuType; N_LANES] {
         let multiplicities = self.multiplicity.to_array();
 
-        std::array::from_fn(|i| SinLookupTraceTableRow {
+        std::array::from_fn(|i| UnaryLookupTraceTableRow {
             multiplicity: multiplicities[i],
         })
     }
 }
 
-impl SinLookupTraceTable {
-    /// Creates a new, empty `SinLookupTraceTable`.
+impl UnaryLookupTraceTable {
+    /// Creates a new, empty `UnaryLookupTraceTable`.
     pub fn new() -> Self {
         Self::default()
     }
 
     /// Appends a single row (multiplicity count) to the trace table.
-    pub fn add_row(&mut self, row: SinLookupTraceTableRow) {
+    pub fn add_row(&mut self, row: UnaryLookupTraceTableRow) {
         self.table.push(row);
     }
 }
 
-/// Enum defining the columns of the SinLookup AIR component's trace.
+/// Enum defining the columns of the UnaryLookup AIR component's trace.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum SinLookupColumn {
+pub enum UnaryLookupColumn {
     /// Column storing the multiplicity of access for each LUT entry.
     Multiplicity,
 }
 
-impl SinLookupColumn {
-    /// Returns the 0-based index for this column within the SinLookup trace segment.
+impl UnaryLookupColumn {
+    /// Returns the 0-based index for this column within the UnaryLookup trace segment.
     pub const fn index(self) -> usize {
         match self {
             Self::Multiplicity => 0,
@@ -97,9 +97,9 @@ impl SinLookupColumn {
     }
 }
 
-/// Implements the `TraceColumn` trait for `SinLookupColumn`.
-impl TraceColumn for SinLookupColumn {
-    /// Specifies the number of columns used by the SinLookup component.
+/// Implements the `TraceColumn` trait for `UnaryLookupColumn`.
+impl TraceColumn for UnaryLookupColumn {
+    /// Specifies the number of columns used by the UnaryLookup component.
     /// Returns `(N_TRACE_COLUMNS, 1)`, indicating main trace columns for multiplicities
     /// and 1 interaction trace column for the LogUp argument that connects these
     /// multiplicities to the preprocessed LUT values.
@@ -4,7 +4,7 @@ use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterato
 use stwo_air_utils::trace::component_trace::ComponentTrace;
 use stwo_air_utils_derive::{IterMut, ParIterMut, Uninitialized};
 use stwo_prover::{
-    constraint_framework::{logup::LogupTraceGenerator, Relation},
+    constraint_framework::logup::LogupTraceGenerator,
     core::backend::simd::{
         m31::{PackedM31, LOG_N_LANES, N_LANES},
         qm31::PackedQM31,
@@ -13,45 +13,46 @@ use stwo_prover::{
 };
 
 use crate::{
-    components::{InteractionClaim, SinLookupClaim},
-    preprocessed::SinPreProcessed,
+    components::{InteractionClaim, UnaryLookupClaim},
+    preprocessed::UnaryPreProcessed,
     utils::{pack_values, TreeBuilder},
 };
 
 use super::{
     table::{
-        PackedSinLookupTraceTableRow, SinLookupColumn, SinLookupTraceTable, SinLookupTraceTableRow,
+        PackedUnaryLookupTraceTableRow, UnaryLookupColumn, UnaryLookupTraceTable,
+        UnaryLookupTraceTableRow,
     },
-    SinLookupElements,
+    LutFunction,
 };
 
-/// Number of main trace columns for the SinLookup component (only multiplicity).
+/// Number of main trace columns for the UnaryLookup component (only multiplicity).
 pub(crate) const N_TRACE_COLUMNS: usize = 1;
 
-/// Generates main trace and interaction data for the SinLookup component.
+/// Generates main trace and interaction data for the UnaryLookup component.
 ///
-/// Takes the `SinLookupTraceTable` (containing multiplicities), processes it into
+/// Takes the `UnaryLookupTraceTable` (containing multiplicities), processes it into
 /// a single main trace column, and prepares data for the LogUp interaction.
 pub struct ClaimGenerator {
-    /// The raw trace data (multiplicities) for the SinLookup.
-    pub inputs: SinLookupTraceTable,
+    /// The raw trace data (multiplicities) for the UnaryLookup.
+    pub inputs: UnaryLookupTraceTable,
 }
 
 impl ClaimGenerator {
-    /// Creates a new `ClaimGenerator` with the given `SinLookupTraceTable`.
-    pub fn new(inputs: SinLookupTraceTable) -> Self {
+    /// Creates a new `ClaimGenerator` with the given `UnaryLookupTraceTable`.
+    pub fn new(inputs: UnaryLookupTraceTable) -> Self {
         Self { inputs }
     }
 
     /// Writes the main trace column (multiplicities) and returns data for interaction.
     ///
     /// Standard procedure: pads, packs, calls `write_trace_simd`,
-    /// adds main trace to `tree_builder`, returns `SinLookupClaim` and `InteractionClaimGenerator`.
+    /// adds main trace to `tree_builder`, returns `UnaryLookupClaim` and `InteractionClaimGenerator`.
     /// Returns `TraceError::EmptyTrace` if the input table is empty.
     pub fn write_trace(
         mut self,
         tree_builder: &mut impl TreeBuilder<SimdBackend>,
-    ) -> Result<(SinLookupClaim, InteractionClaimGenerator), TraceError> {
+    ) -> Result<(UnaryLookupClaim, InteractionClaimGenerator), TraceError> {
         let n_rows = self.inputs.table.len();
 
         if n_rows == 0 {
@@ -63,7 +64,7 @@ impl ClaimGenerator {
 
         self.inputs
             .table
-            .resize(size, SinLookupTraceTableRow::padding());
+            .resize(size, UnaryLookupTraceTableRow::padding());
         let packed_inputs = pack_values(&self.inputs.table);
 
         let (trace, lookup_data) = write_trace_simd(packed_inputs);
@@ -71,7 +72,7 @@ impl ClaimGenerator {
         tree_builder.extend_evals(trace.to_evals());
 
         Ok((
-            SinLookupClaim::new(log_size),
+            UnaryLookupClaim::new(log_size),
             InteractionClaimGenerator {
                 log_size,
                 lookup_data,
@@ -86,7 +87,7 @@ impl ClaimGenerator {
 /// - `LookupData` also stores these multiplicities for the interaction phase.
 /// Returns the `ComponentTrace` and `LookupData`.
 fn write_trace_simd(
-    inputs: Vec<PackedSinLookupTraceTableRow>,
+    inputs: Vec<PackedUnaryLookupTraceTableRow>,
 ) -> (ComponentTrace<N_TRACE_COLUMNS>, LookupData) {
     let log_n_packed_rows = inputs.len().ilog2();
     let log_size = log_n_packed_rows + LOG_N_LANES;
@@ -105,7 +106,7 @@ fn write_trace_simd(
     )
         .into_par_iter()
         .for_each(|(mut row, lookup_data, input)| {
-            *row[SinLookupColumn::Multiplicity.index()] = input.multiplicity;
+            *row[UnaryLookupColumn::Multiplicity.index()] = input.multiplicity;
 
             *lookup_data.multiplicities = input.multiplicity;
         });
@@ -113,18 +114,18 @@ fn write_trace_simd(
     (trace, lookup_data)
 }
 
-/// Intermediate data structure for the SinLookup LogUp argument.
+/// Intermediate data structure for the UnaryLookup LogUp argument.
 /// Only stores the multiplicities, as the values come from the preprocessed LUT.
 #[derive(Uninitialized, IterMut, ParIterMut)]
 struct LookupData {
-    /// Multiplicities for each entry in the Sine LUT.
+    /// Multiplicities for each entry in the LUT.
     multiplicities: Vec<PackedM31>,
 }
 
-/// Generates the interaction trace column for the SinLookup component's LogUp argument.
+/// Generates the interaction trace column for the UnaryLookup component's LogUp argument.
 ///
-/// This LogUp argument connects the multiplicities (from the main SinLookup trace)
-/// with the actual input/output values from the preprocessed Sine LUT.
+/// This LogUp argument connects the multiplicities (from the main UnaryLookup trace)
+/// with the actual input/output values from the preprocessed LUT of the function.
 pub struct InteractionClaimGenerator {
     /// Log2 size of the trace.
     log_size: u32,
@@ -137,32 +138,34 @@ impl InteractionClaimGenerator {
     ///
     /// 1. Initializes a `LogupTraceGenerator`.
     /// 2. For each entry:
-    ///    a. Retrieves the input (`lut_col_0`) and output (`lut_col_1`) values directly from the
-    ///       preprocessed `SinPreProcessed` columns (`lut`).
+    ///    a. Retrieves the input (`lut_col_0`), output (`lut_col_1`) and, for an interpolated LUT,
+    ///       slope (`lut_col_2`) values directly from the preprocessed `UnaryPreProcessed` columns (`lut`).
     ///    b. Retrieves the `multiplicity` from `self.lookup_data`.
-    ///    c. Combines `[input, output]` from the LUT with `elements` (SinLookupElements) to form the denominator.
+    ///    c. Combines the entry values from the LUT with the `elements` of `F` to form the denominator.
     ///    d. The numerator for the LogUp fraction is `-multiplicity`.
     ///    e. Writes the fraction to the LogUp column.
     /// 3. Finalizes the generator, adds the interaction column to `tree_builder`, returns `InteractionClaim`.
     /// This proves that `sum_i (multiplicity_i / (alpha_0 * lut_input_i + alpha_1 * lut_output_i + beta)) = 0`
-    /// when balanced with the accesses from the `SinComponent` trace.
-    pub fn write_interaction_trace(
+    /// when balanced with the accesses from the `UnaryComponent` trace of `F`.
+    pub fn write_interaction_trace<F: LutFunction>(
         self,
         tree_builder: &mut impl TreeBuilder<SimdBackend>,
-        elements: &SinLookupElements,          // Randomness for Sin LUT (input, output) combination
-        lut: &Vec<&SinPreProcessed>,        // References to the two preprocessed Sin LUT columns
+        elements: &F::Elements,                // Randomness for the LUT entry combination
+        lut: &Vec<&UnaryPreProcessed<F>>,   // References to the preprocessed LUT columns of F
     ) -> InteractionClaim {
         let mut logup_gen = LogupTraceGenerator::new(self.log_size);
 
         let mut col_gen = logup_gen.new_col();
-        let lut_col_0 = &lut.get(0).expect("missing sin col 0").evaluation().data;
-        let lut_col_1 = &lut.get(1).expect("missing sin col 1").evaluation().data;
+        let lut_cols = lut
+            .iter()
+            .map(|col| col.evaluation().values.data)
+            .collect::<Vec<_>>();
+        assert!(lut_cols.len() >= 2, "missing lut columns");
         for row in 0..1 << (self.log_size - LOG_N_LANES) {
             let multiplicity: PackedQM31 = self.lookup_data.multiplicities[row].into();
-            let input = lut_col_0[row];
-            let output = lut_col_1[row];
+            let entry = lut_cols.iter().map(|col| col[row]).collect::<Vec<_>>();
 
-            let denom: PackedQM31 = elements.combine(&[input, output]);
+            let denom: PackedQM31 = F::combine(elements, &entry);
             let num: PackedQM31 = -PackedQM31::one() * multiplicity;
 
             col_gen.write_frac(row, num, denom);
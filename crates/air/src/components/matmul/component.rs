@@ -0,0 +1,179 @@
+use crate::{
+    components::{MatMulClaim, NodeElements},
+    preprocessed::eval_structure,
+};
+use num_traits::One;
+use stwo_prover::constraint_framework::{
+    EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry, ORIGINAL_TRACE_IDX,
+};
+
+use super::table::MatMulTraceTableRow;
+
+/// The STWO AIR component for MatMul operations.
+/// Wraps the `MatMulEval` logic within the STWO `FrameworkComponent`.
+pub type MatMulComponent = FrameworkComponent<MatMulEval>;
+
+/// Defines the AIR constraints evaluation logic for the MatMul component.
+/// Implements `FrameworkEval` to define trace layout, degrees, and constraints
+/// for the dot-product accumulation of each output element.
+pub struct MatMulEval {
+    /// Log2 size of the component's trace segment.
+    log_size: u32,
+    /// Interaction elements for node relations (used in LogUp).
+    node_elements: NodeElements,
+}
+
+impl MatMulEval {
+    /// Creates a new `MatMulEval` instance.
+    /// Takes the component's claim (for `log_size`) and interaction elements.
+    pub fn new(claim: &MatMulClaim, node_elements: NodeElements) -> Self {
+        Self {
+            log_size: claim.log_size,
+            node_elements,
+        }
+    }
+}
+
+/// Implements the core constraint evaluation logic for the MatMul component.
+impl FrameworkEval for MatMulEval {
+    /// Returns the log2 size of this component's trace segment.
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// Returns the maximum expected log2 degree bound for the component's constraints.
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.log_size + 1
+    }
+
+    /// Evaluates the MatMul AIR constraints on a given evaluation point (`eval`).
+    ///
+    /// Defines constraints for:
+    /// - **Structural:** The IDs, indices, flags and multiplicities match the preprocessed trace.
+    /// - **Consistency:**
+    ///   - `is_last_idx` and `is_last_step` are boolean.
+    ///   - Accumulator update: `next_acc = acc + lhs * rhs`, exact products summed at the sum
+    ///     of the input scales, until a Rescale brings the output back.
+    ///   - Output validity: `out = next_acc` only if `is_last_step` is true.
+    /// - **Transition:** Links each row to the next row of the trace:
+    ///   - The next row's `acc` equals `next_acc`, or 0 once a dot product ends (`is_last_step`).
+    ///   - When `is_last_idx` is false, node and input tensor IDs remain the same, and `idx`
+    ///     (output element index) increments by 1 once a dot product ends.
+    /// - **Interaction (LogUp):** Links `lhs_val`, `rhs_val` (from the input tensors) and `out_val`
+    ///   (final dot product) to the global LogUp argument.
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        // IDs (current and next row, for transition constraints)
+        // ID of the node in the computational graph.
+        let [node_id, next_node_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of first input tensor.
+        let [lhs_id, next_lhs_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of second input tensor.
+        let [rhs_id, next_rhs_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Index in the flattened output tensor.
+        let [idx, next_idx] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Positions of the input values within their tensors.
+        let lhs_idx = eval.next_trace_mask();
+        let rhs_idx = eval.next_trace_mask();
+        let is_last_idx = eval.next_trace_mask(); // Flag if this is the last index for this operation.
+
+        // Values for consistency constraints
+        let lhs_val = eval.next_trace_mask(); // Value from first tensor.
+        let rhs_val = eval.next_trace_mask(); // Value from second tensor.
+        let out_val = eval.next_trace_mask(); // Value in output tensor at index.
+
+        // Accumulative value in result tensor at index, and the one of the next row.
+        let [acc_val, next_row_acc_val] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        let next_acc_val = eval.next_trace_mask(); // Next accumulative value.
+        let is_last_step = eval.next_trace_mask(); // Flag if this is the last step.
+
+        // Multiplicities for interaction constraints
+        let lhs_mult = eval.next_trace_mask();
+        let rhs_mult = eval.next_trace_mask();
+        let out_mult = eval.next_trace_mask();
+
+        // ┌─────────────────────────────┐
+        // │   Structural Constraints    │
+        // └─────────────────────────────┘
+
+        // The IDs, indices, flags and multiplicities are the ones fixed by the graph.
+        eval_structure::<_, MatMulTraceTableRow>(
+            &mut eval,
+            "matmul",
+            vec![
+                node_id.clone(),
+                lhs_id.clone(),
+                rhs_id.clone(),
+                idx.clone(),
+                lhs_idx.clone(),
+                rhs_idx.clone(),
+                is_last_idx.clone(),
+                is_last_step.clone(),
+                lhs_mult.clone(),
+                rhs_mult.clone(),
+                out_mult.clone(),
+            ],
+        );
+
+        // ┌─────────────────────────────┐
+        // │   Consistency Constraints   │
+        // └─────────────────────────────┘
+
+        // The is_last_idx and is_last_step flags are either 0 or 1.
+        eval.add_constraint(is_last_idx.clone() * (is_last_idx.clone() - E::F::one()));
+        eval.add_constraint(is_last_step.clone() * (is_last_step.clone() - E::F::one()));
+
+        // The output value must equal the sum of the exact products of the input values.
+        eval.add_constraint(
+            next_acc_val.clone() - (acc_val.clone() + lhs_val.clone() * rhs_val.clone()),
+        );
+        eval.add_constraint((out_val.clone() - next_acc_val.clone()) * is_last_step.clone());
+
+        // ┌────────────────────────────┐
+        // │   Transition Constraints   │
+        // └────────────────────────────┘
+
+        // The accumulator carries over to the next row, and restarts from 0 after the last step.
+        eval.add_constraint(next_row_acc_val - (E::F::one() - is_last_step.clone()) * next_acc_val);
+
+        // If this is not the last index for this operation, then:
+        // 1. The next row should be for the same operation on the same tensors.
+        // 2. The index should increment by 1 once the dot product of the current index ends.
+        let not_last = E::F::one() - is_last_idx;
+
+        // Same node ID
+        eval.add_constraint(not_last.clone() * (next_node_id - node_id.clone()));
+
+        // Same tensor IDs
+        eval.add_constraint(not_last.clone() * (next_lhs_id - lhs_id.clone()));
+        eval.add_constraint(not_last.clone() * (next_rhs_id - rhs_id.clone()));
+
+        // Index increment by 1
+        eval.add_constraint(not_last * (next_idx - idx.clone() - is_last_step));
+
+        // ┌─────────────────────────────┐
+        // │   Interaction Constraints   │
+        // └─────────────────────────────┘
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            lhs_mult.into(),
+            &[lhs_id, lhs_idx, lhs_val],
+        ));
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            rhs_mult.into(),
+            &[rhs_id, rhs_idx, rhs_val],
+        ));
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            out_mult.into(),
+            &[node_id, idx, out_val],
+        ));
+
+        eval.finalize_logup();
+
+        eval
+    }
+}
@@ -0,0 +1,317 @@
+use num_traits::{One, Zero};
+use serde::{Deserialize, Serialize};
+use stwo_prover::core::{
+    backend::simd::{
+        conversion::{Pack, Unpack},
+        m31::{PackedM31, N_LANES},
+    },
+    fields::m31::M31,
+};
+
+use crate::{components::TraceColumn, preprocessed::StructuredRow};
+
+use super::witness::N_TRACE_COLUMNS;
+
+/// Represents the raw trace data collected for MatMul operations.
+///
+/// Stores rows capturing the dot-product accumulation of each output element,
+/// one row per product of an LHS and an RHS value.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct MatMulTraceTable {
+    /// Vector containing all rows of the MatMul trace.
+    pub table: Vec<MatMulTraceTableRow>,
+}
+
+/// Represents a single row in the `MatMulTraceTable`.
+///
+/// Contains values for evaluating MatMul AIR constraints: state IDs, the current pair of
+/// input values, current/next accumulator value, a flag indicating the last step of the
+/// dot product for an output element, the final output (valid on last step),
+/// and LogUp multiplicities.
+#[derive(Debug, Default, Copy, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MatMulTraceTableRow {
+    /// ID of the current MatMul node.
+    pub node_id: M31,
+    /// ID of the node providing the left-hand side input.
+    pub lhs_id: M31,
+    /// ID of the node providing the right-hand side input.
+    pub rhs_id: M31,
+    /// Index of the output element being computed.
+    pub idx: M31,
+    /// Position of the LHS value within the left-hand side input tensor.
+    pub lhs_idx: M31,
+    /// Position of the RHS value within the right-hand side input tensor.
+    pub rhs_idx: M31,
+    /// Flag: is this the last output element for this node (1 if true, 0 otherwise).
+    pub is_last_idx: M31,
+    /// Current value of the left-hand side input.
+    pub lhs: M31,
+    /// Current value of the right-hand side input.
+    pub rhs: M31,
+    /// Final output value (dot product for `idx`), at the sum of the input scales.
+    /// Valid only if `is_last_step` is 1.
+    pub out: M31,
+    /// Accumulator value *before* adding the current product.
+    pub acc: M31,
+    /// Accumulator value *after* adding the current product (`acc + lhs * rhs`).
+    pub next_acc: M31,
+    /// Flag: is this the last product summed for the current `out` (1 if true, 0 otherwise).
+    pub is_last_step: M31,
+    /// Multiplicity contribution for the LogUp argument (LHS input).
+    pub lhs_mult: M31,
+    /// Multiplicity contribution for the LogUp argument (RHS input).
+    pub rhs_mult: M31,
+    /// Multiplicity contribution for the LogUp argument (output).
+    pub out_mult: M31,
+}
+
+impl MatMulTraceTableRow {
+    /// Creates a default padding row for the MatMul trace.
+    pub(crate) fn padding() -> Self {
+        Self {
+            node_id: M31::zero(),
+            lhs_id: M31::zero(),
+            rhs_id: M31::zero(),
+            idx: M31::zero(),
+            lhs_idx: M31::zero(),
+            rhs_idx: M31::zero(),
+            is_last_idx: M31::one(),
+            lhs: M31::zero(),
+            rhs: M31::zero(),
+            out: M31::zero(),
+            acc: M31::zero(),
+            next_acc: M31::zero(),
+            is_last_step: M31::zero(),
+            lhs_mult: M31::zero(),
+            rhs_mult: M31::zero(),
+            out_mult: M31::zero(),
+        }
+    }
+}
+
+/// Implements the `StructuredRow` trait for `MatMulTraceTableRow`.
+impl StructuredRow for MatMulTraceTableRow {
+    /// IDs, indices, flags and multiplicities of the row, fixed by the graph.
+    const STRUCTURE: &'static [&'static str] = &[
+        "node_id",
+        "lhs_id",
+        "rhs_id",
+        "idx",
+        "lhs_idx",
+        "rhs_idx",
+        "is_last_idx",
+        "is_last_step",
+        "lhs_mult",
+        "rhs_mult",
+        "out_mult",
+    ];
+
+    /// Returns the values of the structural columns of the row.
+    fn structure(&self) -> Vec<M31> {
+        vec![
+            self.node_id,
+            self.lhs_id,
+            self.rhs_id,
+            self.idx,
+            self.lhs_idx,
+            self.rhs_idx,
+            self.is_last_idx,
+            self.is_last_step,
+            self.lhs_mult,
+            self.rhs_mult,
+            self.out_mult,
+        ]
+    }
+}
+
+/// SIMD-packed representation of a `MatMulTraceTableRow`.
+#[derive(Debug, Copy, Clone)]
+pub struct PackedMatMulTraceTableRow {
+    /// Packed `node_id` values.
+    pub node_id: PackedM31,
+    /// Packed `lhs_id` values.
+    pub lhs_id: PackedM31,
+    /// Packed `rhs_id` values.
+    pub rhs_id: PackedM31,
+    /// Packed `idx` (output element index) values.
+    pub idx: PackedM31,
+    /// Packed `lhs_idx` values.
+    pub lhs_idx: PackedM31,
+    /// Packed `rhs_idx` values.
+    pub rhs_idx: PackedM31,
+    /// Packed `is_last_idx` flags.
+    pub is_last_idx: PackedM31,
+    /// Packed `lhs` values.
+    pub lhs: PackedM31,
+    /// Packed `rhs` values.
+    pub rhs: PackedM31,
+    /// Packed `out` (final dot product) values.
+    pub out: PackedM31,
+    /// Packed `acc` (accumulator before the product) values.
+    pub acc: PackedM31,
+    /// Packed `next_acc` (accumulator after the product) values.
+    pub next_acc: PackedM31,
+    /// Packed `is_last_step` flags (for the dot product).
+    pub is_last_step: PackedM31,
+    /// Packed `lhs_mult` values.
+    pub lhs_mult: PackedM31,
+    /// Packed `rhs_mult` values.
+    pub rhs_mult: PackedM31,
+    /// Packed `out_mult` values.
+    pub out_mult: PackedM31,
+}
+
+impl Pack for MatMulTraceTableRow {
+    type SimdType = PackedMatMulTraceTableRow;
+
+    fn pack(inputs: [Self; N_LANES]) -> Self::SimdType {
+        PackedMatMulTraceTableRow {
+            node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].node_id)),
+            lhs_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs_id)),
+            rhs_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs_id)),
+            idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].idx)),
+            lhs_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs_idx)),
+            rhs_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs_idx)),
+            is_last_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_idx)),
+            lhs: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs)),
+            rhs: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs)),
+            out: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out)),
+            acc: PackedM31::from_array(std::array::from_fn(|i| inputs[i].acc)),
+            next_acc: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_acc)),
+            is_last_step: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_step)),
+            lhs_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs_mult)),
+            rhs_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs_mult)),
+            out_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_mult)),
+        }
+    }
+}
+
+impl Unpack for PackedMatMulTraceTableRow {
+    type CpuType = MatMulTraceTableRow;
+
+    fn unpack(self) -> [Self::CpuType; N_LANES] {
+        let (
+            node_id,
+            lhs_id,
+            rhs_id,
+            idx,
+            lhs_idx,
+            rhs_idx,
+            is_last_idx,
+            lhs,
+            rhs,
+            out,
+            acc,
+            next_acc,
+            is_last_step,
+            lhs_mult,
+            rhs_mult,
+            out_mult,
+        ) = (
+            self.node_id.to_array(),
+            self.lhs_id.to_array(),
+            self.rhs_id.to_array(),
+            self.idx.to_array(),
+            self.lhs_idx.to_array(),
+            self.rhs_idx.to_array(),
+            self.is_last_idx.to_array(),
+            self.lhs.to_array(),
+            self.rhs.to_array(),
+            self.out.to_array(),
+            self.acc.to_array(),
+            self.next_acc.to_array(),
+            self.is_last_step.to_array(),
+            self.lhs_mult.to_array(),
+            self.rhs_mult.to_array(),
+            self.out_mult.to_array(),
+        );
+
+        std::array::from_fn(|i| MatMulTraceTableRow {
+            node_id: node_id[i],
+            lhs_id: lhs_id[i],
+            rhs_id: rhs_id[i],
+            idx: idx[i],
+            lhs_idx: lhs_idx[i],
+            rhs_idx: rhs_idx[i],
+            is_last_idx: is_last_idx[i],
+            lhs: lhs[i],
+            rhs: rhs[i],
+            out: out[i],
+            acc: acc[i],
+            next_acc: next_acc[i],
+            is_last_step: is_last_step[i],
+            lhs_mult: lhs_mult[i],
+            rhs_mult: rhs_mult[i],
+            out_mult: out_mult[i],
+        })
+    }
+}
+
+impl MatMulTraceTable {
+    /// Creates a new, empty `MatMulTraceTable`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a single row to the trace table.
+    pub fn add_row(&mut self, row: MatMulTraceTableRow) {
+        self.table.push(row);
+    }
+}
+
+/// Enum defining the columns of the MatMul AIR component's trace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum MatMulColumn {
+    NodeId,
+    LhsId,
+    RhsId,
+    Idx,
+    /// Position of the LHS value within the left-hand side input tensor.
+    LhsIdx,
+    /// Position of the RHS value within the right-hand side input tensor.
+    RhsIdx,
+    IsLastIdx,
+    Lhs,
+    Rhs,
+    Out,
+    Acc,
+    NextAcc,
+    IsLastStep,
+    LhsMult,
+    RhsMult,
+    OutMult,
+}
+
+impl MatMulColumn {
+    /// Returns the 0-based index for this column within the MatMul trace segment.
+    pub const fn index(self) -> usize {
+        match self {
+            Self::NodeId => 0,
+            Self::LhsId => 1,
+            Self::RhsId => 2,
+            Self::Idx => 3,
+            Self::LhsIdx => 4,
+            Self::RhsIdx => 5,
+            Self::IsLastIdx => 6,
+            Self::Lhs => 7,
+            Self::Rhs => 8,
+            Self::Out => 9,
+            Self::Acc => 10,
+            Self::NextAcc => 11,
+            Self::IsLastStep => 12,
+            Self::LhsMult => 13,
+            Self::RhsMult => 14,
+            Self::OutMult => 15,
+        }
+    }
+}
+
+/// Implements the `TraceColumn` trait for `MatMulColumn`.
+impl TraceColumn for MatMulColumn {
+    /// Specifies the number of columns used by the MatMul component.
+    /// Returns `(N_TRACE_COLUMNS, 3)`, indicating main trace columns
+    /// and 3 interaction trace columns (for LHS, RHS and output LogUp).
+    fn count() -> (usize, usize) {
+        (N_TRACE_COLUMNS, 3)
+    }
+}
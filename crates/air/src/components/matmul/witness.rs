@@ -0,0 +1,207 @@
+use luminair_utils::TraceError;
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use stwo_air_utils::trace::component_trace::ComponentTrace;
+use stwo_air_utils_derive::{IterMut, ParIterMut, Uninitialized};
+use stwo_prover::{
+    constraint_framework::{logup::LogupTraceGenerator, Relation},
+    core::backend::simd::{
+        m31::{PackedM31, LOG_N_LANES, N_LANES},
+        qm31::PackedQM31,
+        SimdBackend,
+    },
+};
+
+use crate::{
+    components::{InteractionClaim, MatMulClaim, NodeElements},
+    utils::{pack_values, to_trace_order, TreeBuilder},
+};
+
+use super::table::{
+    MatMulColumn, MatMulTraceTable, MatMulTraceTableRow, PackedMatMulTraceTableRow,
+};
+
+/// Number of main trace columns for the MatMul component.
+pub(crate) const N_TRACE_COLUMNS: usize = 16;
+
+/// Generates the main trace columns and initial data for interaction claims for the MatMul component.
+///
+/// Takes the raw `MatMulTraceTable`, processes it into main STARK trace columns
+/// (including accumulator states, input/output values), and prepares `LookupData` for LogUp.
+pub struct ClaimGenerator {
+    /// The raw trace data for MatMul operations.
+    pub inputs: MatMulTraceTable,
+}
+
+impl ClaimGenerator {
+    /// Creates a new `ClaimGenerator` with the given `MatMulTraceTable`.
+    pub fn new(inputs: MatMulTraceTable) -> Self {
+        Self { inputs }
+    }
+
+    /// Writes the main trace columns to the `tree_builder` and returns data for interaction phase.
+    ///
+    /// Follows standard pattern: pads, packs, calls `write_trace_simd`,
+    /// adds main trace to `tree_builder`, returns `MatMulClaim` and `InteractionClaimGenerator`.
+    /// Returns `TraceError::EmptyTrace` if the input table is empty.
+    pub fn write_trace(
+        mut self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+    ) -> Result<(MatMulClaim, InteractionClaimGenerator), TraceError> {
+        let n_rows = self.inputs.table.len();
+
+        if n_rows == 0 {
+            return Err(TraceError::EmptyTrace);
+        }
+
+        let size = std::cmp::max(n_rows.next_power_of_two(), N_LANES);
+        let log_size = size.ilog2();
+
+        self.inputs
+            .table
+            .resize(size, MatMulTraceTableRow::padding());
+        let packed_inputs = pack_values(&to_trace_order(&self.inputs.table));
+
+        let (trace, lookup_data) = write_trace_simd(packed_inputs);
+
+        tree_builder.extend_evals(trace.to_evals());
+
+        Ok((
+            MatMulClaim::new(log_size),
+            InteractionClaimGenerator {
+                log_size,
+                lookup_data,
+            },
+        ))
+    }
+}
+
+/// Populates main trace columns and `LookupData` from SIMD-packed MatMul trace rows.
+///
+/// Processes `PackedMatMulTraceTableRow` data in parallel:
+/// - Maps fields (node/input IDs, current/next accumulator, input/out values, flags) to main trace columns.
+/// - Extracts `[id, idx, value]` triples and multiplicities for the LHS, RHS and output LogUps
+///   into `LookupData`.
+/// Returns the `ComponentTrace` (main trace columns) and `LookupData`.
+fn write_trace_simd(
+    inputs: Vec<PackedMatMulTraceTableRow>,
+) -> (ComponentTrace<N_TRACE_COLUMNS>, LookupData) {
+    let log_n_packed_rows = inputs.len().ilog2();
+    let log_size = log_n_packed_rows + LOG_N_LANES;
+
+    let (mut trace, mut lookup_data) = unsafe {
+        (
+            ComponentTrace::<N_TRACE_COLUMNS>::uninitialized(log_size),
+            LookupData::uninitialized(log_n_packed_rows),
+        )
+    };
+
+    (
+        trace.par_iter_mut(),
+        lookup_data.par_iter_mut(),
+        inputs.into_par_iter(),
+    )
+        .into_par_iter()
+        .for_each(|(mut row, lookup_data, input)| {
+            *row[MatMulColumn::NodeId.index()] = input.node_id;
+            *row[MatMulColumn::LhsId.index()] = input.lhs_id;
+            *row[MatMulColumn::RhsId.index()] = input.rhs_id;
+            *row[MatMulColumn::Idx.index()] = input.idx;
+            *row[MatMulColumn::LhsIdx.index()] = input.lhs_idx;
+            *row[MatMulColumn::RhsIdx.index()] = input.rhs_idx;
+            *row[MatMulColumn::IsLastIdx.index()] = input.is_last_idx;
+            *row[MatMulColumn::Lhs.index()] = input.lhs;
+            *row[MatMulColumn::Rhs.index()] = input.rhs;
+            *row[MatMulColumn::Out.index()] = input.out;
+            *row[MatMulColumn::Acc.index()] = input.acc;
+            *row[MatMulColumn::NextAcc.index()] = input.next_acc;
+            *row[MatMulColumn::IsLastStep.index()] = input.is_last_step;
+            *row[MatMulColumn::LhsMult.index()] = input.lhs_mult;
+            *row[MatMulColumn::RhsMult.index()] = input.rhs_mult;
+            *row[MatMulColumn::OutMult.index()] = input.out_mult;
+
+            *lookup_data.lhs = [input.lhs_id, input.lhs_idx, input.lhs];
+            *lookup_data.lhs_mult = input.lhs_mult;
+            *lookup_data.rhs = [input.rhs_id, input.rhs_idx, input.rhs];
+            *lookup_data.rhs_mult = input.rhs_mult;
+            *lookup_data.out = [input.node_id, input.idx, input.out];
+            *lookup_data.out_mult = input.out_mult;
+        });
+
+    (trace, lookup_data)
+}
+
+/// Intermediate data structure holding values and multiplicities for the MatMul LogUp argument.
+/// Stores ID-index-value triples and multiplicities for LHS, RHS and output terms.
+#[derive(Uninitialized, IterMut, ParIterMut)]
+struct LookupData {
+    /// LHS ID-index-value triples: `[lhs_node_id, lhs_idx, lhs_value]`.
+    lhs: Vec<[PackedM31; 3]>,
+    /// Multiplicities for LHS values.
+    lhs_mult: Vec<PackedM31>,
+    /// RHS ID-index-value triples: `[rhs_node_id, rhs_idx, rhs_value]`.
+    rhs: Vec<[PackedM31; 3]>,
+    /// Multiplicities for RHS values.
+    rhs_mult: Vec<PackedM31>,
+    /// Output ID-index-value triples: `[matmul_node_id, idx, out_value]`.
+    out: Vec<[PackedM31; 3]>,
+    /// Multiplicities for output values.
+    out_mult: Vec<PackedM31>,
+}
+
+/// Generates interaction trace columns for the MatMul component's LogUp argument.
+/// Builds three LogUp columns (LHS, RHS, output) and adds them to the `tree_builder`.
+pub struct InteractionClaimGenerator {
+    /// Log2 size of the trace.
+    log_size: u32,
+    /// Data (ID-index-value triples and multiplicities) needed for LogUp.
+    lookup_data: LookupData,
+}
+
+impl InteractionClaimGenerator {
+    /// Writes the LogUp interaction trace columns to the `tree_builder`.
+    ///
+    /// Similar to Mul: generates three columns (LHS, RHS, Output), writing `multiplicity / denom`
+    /// fractions. Finalizes generator, adds columns to `tree_builder`, returns `InteractionClaim`.
+    pub fn write_interaction_trace(
+        self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+        node_elements: &NodeElements,
+    ) -> InteractionClaim {
+        let mut logup_gen = LogupTraceGenerator::new(self.log_size);
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let values = &self.lookup_data.lhs[row];
+            let multiplicity = &self.lookup_data.lhs_mult[row];
+
+            let denom: PackedQM31 = node_elements.combine(values);
+            col_gen.write_frac(row, (*multiplicity).into(), denom);
+        }
+        col_gen.finalize_col();
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let values = &self.lookup_data.rhs[row];
+            let multiplicity = &self.lookup_data.rhs_mult[row];
+
+            let denom: PackedQM31 = node_elements.combine(values);
+            col_gen.write_frac(row, (*multiplicity).into(), denom);
+        }
+        col_gen.finalize_col();
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let values = &self.lookup_data.out[row];
+            let multiplicity = &self.lookup_data.out_mult[row];
+
+            let denom: PackedQM31 = node_elements.combine(values);
+            col_gen.write_frac(row, (*multiplicity).into(), denom);
+        }
+        col_gen.finalize_col();
+
+        let (trace, claimed_sum) = logup_gen.finalize_last();
+        tree_builder.extend_evals(trace);
+
+        InteractionClaim { claimed_sum }
+    }
+}
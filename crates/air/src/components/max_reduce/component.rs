@@ -1,9 +1,17 @@
-use crate::components::{MaxReduceClaim, NodeElements};
+use crate::{
+    components::{
+        lookups::range_check::{component::eval_limbs, RangeCheckLookupElements},
+        MaxReduceClaim, NodeElements,
+    },
+    preprocessed::eval_structure,
+};
 use num_traits::One;
 use stwo_prover::constraint_framework::{
-    EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry,
+    EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry, ORIGINAL_TRACE_IDX,
 };
 
+use super::table::{MaxReduceTraceTableRow, N_DIFF_BITS};
+
 /// The STWO AIR component for Max-Reduce operations.
 /// Wraps the `MaxReduceEval` logic within the STWO `FrameworkComponent`.
 pub type MaxReduceComponent = FrameworkComponent<MaxReduceEval>;
@@ -13,17 +21,29 @@ pub type MaxReduceComponent = FrameworkComponent<MaxReduceEval>;
 pub struct MaxReduceEval {
     /// Log2 size of the component's trace segment.
     log_size: u32,
+    /// Log2 size of the preprocessed range-check table.
+    lut_log_size: u32,
     /// Interaction elements for node relations (used in LogUp).
     node_elements: NodeElements,
+    /// Interaction elements for the range checks of the difference limbs.
+    range_check_elements: RangeCheckLookupElements,
 }
 
 impl MaxReduceEval {
     /// Creates a new `MaxReduceEval` instance.
-    /// Takes the component's claim (for `log_size`) and interaction elements.
-    pub fn new(claim: &MaxReduceClaim, node_elements: NodeElements) -> Self {
+    /// Takes the component's claim (for `log_size`), interaction elements for nodes and
+    /// the range check, and the log_size of the range-check table.
+    pub fn new(
+        claim: &MaxReduceClaim,
+        node_elements: NodeElements,
+        range_check_elements: RangeCheckLookupElements,
+        lut_log_size: u32,
+    ) -> Self {
         Self {
             log_size: claim.log_size,
+            lut_log_size,
             node_elements,
+            range_check_elements,
         }
     }
 }
@@ -35,45 +55,87 @@ impl FrameworkEval for MaxReduceEval {
         self.log_size
     }
 
-    /// Returns the maximum expected log2 degree bound for the component's constraints.
+    /// Returns the max log2 degree bound, considering both main trace and range-check table sizes.
     fn max_constraint_log_degree_bound(&self) -> u32 {
-        self.log_size + 1
+        std::cmp::max(self.log_size, self.lut_log_size) + 1
     }
 
     /// Evaluates the MaxReduce AIR constraints on a given evaluation point (`eval`).
     ///
     /// Defines constraints for:
+    /// - **Structural:** The IDs, indices, flags and multiplicities match the preprocessed trace.
     /// - **Consistency:**
     ///   - `is_last_idx`, `is_last_step`, `is_max` are boolean.
+    ///   - The first step of each reduction (following a row with `is_last_step` set) has
+    ///     `is_max` set, so that the running max starts from an input.
     ///   - Max update logic:
     ///     - If `is_max` is 1, then `next_max_val = input_val`.
     ///     - If `is_max` is 0, then `next_max_val = max_val`.
+    ///   - Ordering: `input_val >= max_val` when `is_max` is 1 and `input_val <= max_val` otherwise,
+    ///     witnessed by a non-negative difference recomposed from its two limbs.
     ///   - Output validity: `out = next_max_val` only if `is_last_step` is true.
-    /// - **Transition (for output elements):** Standard logic for node/input IDs and `idx` increment.
+    /// - **Transition:** Links each row to the next row of the trace:
+    ///   - Within a reduction (`is_last_step` false), the next row's `max_val` equals `next_max_val`.
+    ///   - When `is_last_idx` is false, node and input tensor IDs remain the same, and `idx`
+    ///     increments by 1 once a reduction ends.
     /// - **Interaction (LogUp):** Links `input_val` and `out_val` to the global LogUp argument.
+    ///   The limbs of the difference, and their gaps to their maximal values, are sent to the
+    ///   shared range-check lookup on every row of a reduction, so that the difference lies in
+    ///   `[0, 2^N_DIFF_BITS)`.
     fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
-        // IDs
-        let node_id = eval.next_trace_mask(); // ID of the node in the computational graph.
-        let input_id = eval.next_trace_mask(); // ID of the input tensor.
-        let idx = eval.next_trace_mask(); // Index in the flattened tensor.
+        // IDs (current and next row, for transition constraints)
+        // ID of the node in the computational graph.
+        let [node_id, next_node_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of the input tensor.
+        let [input_id, next_input_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Index in the flattened tensor.
+        let [idx, next_idx] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Position of the input value within its tensor.
+        let input_idx = eval.next_trace_mask();
         let is_last_idx = eval.next_trace_mask(); // Flag if this is the last index for this operation.
 
-        // Next IDs for transition constraints
-        let next_node_id = eval.next_trace_mask();
-        let next_input_id = eval.next_trace_mask();
-        let next_idx = eval.next_trace_mask();
-
         // Values for consistency constraints
         let input_val = eval.next_trace_mask(); // Value from the tensor at index.
         let out_val = eval.next_trace_mask(); // Value in output tensor at index.
-        let max_val = eval.next_trace_mask(); // Current max value.
+
+        // Current max value, and the one of the next row.
+        let [max_val, next_row_max_val] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
         let next_max_val = eval.next_trace_mask(); // Next max value.
-        let is_last_step = eval.next_trace_mask(); // Flag if this is the last step.
+
+        // Flag if this is the last step, on the previous and current rows.
+        let [prev_is_last_step, is_last_step] =
+            eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [-1, 0]);
+
         let is_max = eval.next_trace_mask(); // Flag if current input is the max so far.
+        let diff = eval.next_trace_mask(); // Non-negative difference witnessing is_max.
+        let diff_lo = eval.next_trace_mask(); // Low limb of the difference.
+        let diff_hi = eval.next_trace_mask(); // High limb of the difference.
 
         // Multiplicities for interaction constraints
         let input_mult = eval.next_trace_mask();
         let out_mult = eval.next_trace_mask();
+        let range_check_mult = eval.next_trace_mask();
+
+        // ┌─────────────────────────────┐
+        // │   Structural Constraints    │
+        // └─────────────────────────────┘
+
+        // The IDs, indices, flags and multiplicities are the ones fixed by the graph.
+        eval_structure::<_, MaxReduceTraceTableRow>(
+            &mut eval,
+            "max_reduce",
+            vec![
+                node_id.clone(),
+                input_id.clone(),
+                idx.clone(),
+                input_idx.clone(),
+                is_last_idx.clone(),
+                is_last_step.clone(),
+                input_mult.clone(),
+                out_mult.clone(),
+                range_check_mult.clone(),
+            ],
+        );
 
         // ┌─────────────────────────────┐
         // │   Consistency Constraints   │
@@ -84,9 +146,20 @@ impl FrameworkEval for MaxReduceEval {
         eval.add_constraint(is_last_step.clone() * (is_last_step.clone() - E::F::one()));
         eval.add_constraint(is_max.clone() * (is_max.clone() - E::F::one()));
 
-        // If is_max is 1, then input_val >= max_val
-        // To express this constraint: (input_val - max_val) * is_max >= 0
-        // But we need a direct equality constraint, so:
+        // A reduction starts on the row following the last step of the previous one (the trace
+        // wraps around, and its last row always closes a reduction). Its first input becomes the
+        // running max, whatever the initial max_val.
+        eval.add_constraint(prev_is_last_step * (is_max.clone() - E::F::one()));
+
+        // The difference is selected by is_max:
+        // - is_max = 1: diff = input_val - max_val
+        // - is_max = 0: diff = max_val - input_val
+        eval.add_constraint(
+            diff.clone()
+                - (is_max.clone() + is_max.clone() - E::F::one())
+                    * (input_val.clone() - max_val.clone()),
+        );
+
         // If is_max is 1, then input_val == next_max_val (the input becomes the new max)
         // If is_max is 0, then max_val == next_max_val (max doesn't change)
         eval.add_constraint(is_max.clone() * (next_max_val.clone() - input_val.clone()));
@@ -95,15 +168,20 @@ impl FrameworkEval for MaxReduceEval {
         );
 
         // The output value must be the maximum value in the last step
-        eval.add_constraint((out_val.clone() - next_max_val) * is_last_step);
+        eval.add_constraint((out_val.clone() - next_max_val.clone()) * is_last_step.clone());
 
         // ┌────────────────────────────┐
         // │   Transition Constraints   │
         // └────────────────────────────┘
 
+        // The running max carries over to the next row until the reduction ends.
+        eval.add_constraint(
+            (E::F::one() - is_last_step.clone()) * (next_row_max_val - next_max_val),
+        );
+
         // If this is not the last index for this operation, then:
         // 1. The next row should be for the same operation on the same tensors.
-        // 2. The index should increment by 1.
+        // 2. The index should increment by 1 once the reduction of the current index ends.
         let not_last = E::F::one() - is_last_idx;
 
         // Same node ID
@@ -113,7 +191,7 @@ impl FrameworkEval for MaxReduceEval {
         eval.add_constraint(not_last.clone() * (next_input_id - input_id.clone()));
 
         // Index increment by 1
-        eval.add_constraint(not_last * (next_idx - idx - E::F::one()));
+        eval.add_constraint(not_last * (next_idx - idx.clone() - is_last_step));
 
         // ┌─────────────────────────────┐
         // │   Interaction Constraints   │
@@ -122,15 +200,26 @@ impl FrameworkEval for MaxReduceEval {
         eval.add_to_relation(RelationEntry::new(
             &self.node_elements,
             input_mult.into(),
-            &[input_val, input_id],
+            &[input_id, input_idx, input_val],
         ));
 
         eval.add_to_relation(RelationEntry::new(
             &self.node_elements,
             out_mult.into(),
-            &[out_val, node_id],
+            &[node_id, idx, out_val],
         ));
 
+        // The difference is non-negative: its limbs lie in [0, 2^lo_bits) and [0, 2^hi_bits).
+        // This enforces input_val >= max_val when is_max is 1, and input_val <= max_val otherwise.
+        eval_limbs(
+            &mut eval,
+            &self.range_check_elements,
+            &range_check_mult,
+            diff,
+            [diff_lo, diff_hi],
+            N_DIFF_BITS,
+        );
+
         eval.finalize_logup();
 
         eval
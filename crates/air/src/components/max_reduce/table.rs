@@ -7,10 +7,20 @@ use stwo_prover::core::{
     fields::m31::M31,
 };
 
-use crate::components::TraceColumn;
+use crate::{
+    components::{lookups::range_check::N_LIMB_RANGE_CHECKS, TraceColumn},
+    preprocessed::StructuredRow,
+};
 
 use super::witness::N_TRACE_COLUMNS;
 
+/// Number of range-checked bits of the difference between `input` and `max_val`.
+///
+/// The difference is range-checked to `[0, 2^N_DIFF_BITS)` through two limbs. The differences
+/// of both branches sum to `0`, so that both fit only for equal values.
+/// `gen_trace` returns a `RangeCheckOverflow` error for values further apart.
+pub const N_DIFF_BITS: u32 = 29;
+
 /// Represents the raw trace data collected for Max-Reduce operations.
 ///
 /// Stores rows capturing the step-by-step comparison and update process for finding
@@ -23,10 +33,10 @@ pub struct MaxReduceTraceTable {
 
 /// Represents a single row in the `MaxReduceTraceTable`.
 ///
-/// Contains values for MaxReduce AIR constraints: current/next state IDs,
+/// Contains values for MaxReduce AIR constraints: state IDs,
 /// current input value, current/next running maximum (`max_val`, `next_max_val`),
 /// a flag `is_max` indicating if `input` became `next_max_val`,
-/// flags for last step/idx, the final output, and LogUp multiplicities.
+/// the non-negative difference witnessing that choice and its limbs, flags for last step/idx, the final output, and LogUp multiplicities.
 #[derive(Debug, Default, Copy, Clone, serde::Serialize, serde::Deserialize)]
 pub struct MaxReduceTraceTableRow {
     /// ID of the current MaxReduce node.
@@ -35,14 +45,10 @@ pub struct MaxReduceTraceTableRow {
     pub input_id: M31,
     /// Index of the output element being computed.
     pub idx: M31,
+    /// Position of the input value within the input tensor.
+    pub input_idx: M31,
     /// Flag: is this the last output element for this node (1 if true, 0 otherwise).
     pub is_last_idx: M31,
-    /// ID of the *next* MaxReduce node processed in the trace.
-    pub next_node_id: M31,
-    /// ID of the *next* input provider node.
-    pub next_input_id: M31,
-    /// Index of the *next* output element.
-    pub next_idx: M31,
     /// Current input value being processed in the reduction.
     pub input: M31,
     /// Final output value (max for `idx`). Valid only if `is_last_step` is 1.
@@ -55,35 +61,79 @@ pub struct MaxReduceTraceTableRow {
     pub is_last_step: M31,
     /// Flag: is the current `input` value the new maximum (1 if true, 0 otherwise).
     pub is_max: M31,
+    /// Non-negative difference: `input - max_val` if `is_max` is 1, `max_val - input` otherwise.
+    pub diff: M31,
+    /// Low limb of `diff`.
+    pub diff_lo: M31,
+    /// High limb of `diff`.
+    pub diff_hi: M31,
     /// Multiplicity contribution for the LogUp argument (input tensor values).
     pub input_mult: M31,
     /// Multiplicity contribution for the LogUp argument (output tensor values).
     pub out_mult: M31,
+    /// Multiplicity of the range checks of the difference limbs (1 on every row of a reduction).
+    pub range_check_mult: M31,
 }
 
 impl MaxReduceTraceTableRow {
     /// Creates a default padding row for the MaxReduce trace.
+    ///
+    /// Padding rows close a reduction (`is_last_step` is 1), so that no running max
+    /// is carried over to the next row. As each of them starts a reduction, `is_max` is 1.
     pub(crate) fn padding() -> Self {
         Self {
             node_id: M31::zero(),
             input_id: M31::zero(),
             idx: M31::zero(),
+            input_idx: M31::zero(),
             is_last_idx: M31::one(),
-            next_node_id: M31::zero(),
-            next_input_id: M31::zero(),
-            next_idx: M31::zero(),
             input: M31::zero(),
             out: M31::zero(),
             max_val: M31::zero(),
             next_max_val: M31::zero(),
-            is_last_step: M31::zero(),
-            is_max: M31::zero(),
+            is_last_step: M31::one(),
+            is_max: M31::one(),
+            diff: M31::zero(),
+            diff_lo: M31::zero(),
+            diff_hi: M31::zero(),
             input_mult: M31::zero(),
             out_mult: M31::zero(),
+            range_check_mult: M31::zero(),
         }
     }
 }
 
+/// Implements the `StructuredRow` trait for `MaxReduceTraceTableRow`.
+impl StructuredRow for MaxReduceTraceTableRow {
+    /// IDs, indices, flags and multiplicities of the row, fixed by the graph.
+    const STRUCTURE: &'static [&'static str] = &[
+        "node_id",
+        "input_id",
+        "idx",
+        "input_idx",
+        "is_last_idx",
+        "is_last_step",
+        "input_mult",
+        "out_mult",
+        "range_check_mult",
+    ];
+
+    /// Returns the values of the structural columns of the row.
+    fn structure(&self) -> Vec<M31> {
+        vec![
+            self.node_id,
+            self.input_id,
+            self.idx,
+            self.input_idx,
+            self.is_last_idx,
+            self.is_last_step,
+            self.input_mult,
+            self.out_mult,
+            self.range_check_mult,
+        ]
+    }
+}
+
 /// SIMD-packed representation of a `MaxReduceTraceTableRow`.
 #[derive(Debug, Copy, Clone)]
 pub struct PackedMaxReduceTraceTableRow {
@@ -93,14 +143,10 @@ pub struct PackedMaxReduceTraceTableRow {
     pub input_id: PackedM31,
     /// Packed `idx` (output element index) values.
     pub idx: PackedM31,
+    /// Packed `input_idx` values.
+    pub input_idx: PackedM31,
     /// Packed `is_last_idx` flags.
     pub is_last_idx: PackedM31,
-    /// Packed `next_node_id` values.
-    pub next_node_id: PackedM31,
-    /// Packed `next_input_id` values.
-    pub next_input_id: PackedM31,
-    /// Packed `next_idx` values.
-    pub next_idx: PackedM31,
     /// Packed current `input` values for reduction.
     pub input: PackedM31,
     /// Packed `out` (final max) values.
@@ -113,10 +159,18 @@ pub struct PackedMaxReduceTraceTableRow {
     pub is_last_step: PackedM31,
     /// Packed `is_max` flags (if current input is the new max).
     pub is_max: PackedM31,
+    /// Packed `diff` values.
+    pub diff: PackedM31,
+    /// Packed `diff_lo` values.
+    pub diff_lo: PackedM31,
+    /// Packed `diff_hi` values.
+    pub diff_hi: PackedM31,
     /// Packed `input_mult` values.
     pub input_mult: PackedM31,
     /// Packed `out_mult` values.
     pub out_mult: PackedM31,
+    /// Packed `range_check_mult` values.
+    pub range_check_mult: PackedM31,
 }
 
 impl Pack for MaxReduceTraceTableRow {
@@ -127,18 +181,22 @@ impl Pack for MaxReduceTraceTableRow {
             node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].node_id)),
             input_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_id)),
             idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].idx)),
+            input_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_idx)),
             is_last_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_idx)),
-            next_node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_node_id)),
-            next_input_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_input_id)),
-            next_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_idx)),
             input: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input)),
             out: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out)),
             max_val: PackedM31::from_array(std::array::from_fn(|i| inputs[i].max_val)),
             next_max_val: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_max_val)),
             is_last_step: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_step)),
             is_max: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_max)),
+            diff: PackedM31::from_array(std::array::from_fn(|i| inputs[i].diff)),
+            diff_lo: PackedM31::from_array(std::array::from_fn(|i| inputs[i].diff_lo)),
+            diff_hi: PackedM31::from_array(std::array::from_fn(|i| inputs[i].diff_hi)),
             input_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_mult)),
             out_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_mult)),
+            range_check_mult: PackedM31::from_array(std::array::from_fn(|i| {
+                inputs[i].range_check_mult
+            })),
         }
     }
 }
@@ -151,52 +209,58 @@ impl Unpack for PackedMaxReduceTraceTableRow {
             node_id,
             input_id,
             idx,
+            input_idx,
             is_last_idx,
-            next_node_id,
-            next_input_id,
-            next_idx,
             input,
             out,
             max_val,
             next_max_val,
             is_last_step,
             is_max,
+            diff,
+            diff_lo,
+            diff_hi,
             input_mult,
             out_mult,
+            range_check_mult,
         ) = (
             self.node_id.to_array(),
             self.input_id.to_array(),
             self.idx.to_array(),
+            self.input_idx.to_array(),
             self.is_last_idx.to_array(),
-            self.next_node_id.to_array(),
-            self.next_input_id.to_array(),
-            self.next_idx.to_array(),
             self.input.to_array(),
             self.out.to_array(),
             self.max_val.to_array(),
             self.next_max_val.to_array(),
             self.is_last_step.to_array(),
             self.is_max.to_array(),
+            self.diff.to_array(),
+            self.diff_lo.to_array(),
+            self.diff_hi.to_array(),
             self.input_mult.to_array(),
             self.out_mult.to_array(),
+            self.range_check_mult.to_array(),
         );
 
         std::array::from_fn(|i| MaxReduceTraceTableRow {
             node_id: node_id[i],
             input_id: input_id[i],
             idx: idx[i],
+            input_idx: input_idx[i],
             is_last_idx: is_last_idx[i],
-            next_node_id: next_node_id[i],
-            next_input_id: next_input_id[i],
-            next_idx: next_idx[i],
             input: input[i],
             out: out[i],
             max_val: max_val[i],
             next_max_val: next_max_val[i],
             is_last_step: is_last_step[i],
             is_max: is_max[i],
+            diff: diff[i],
+            diff_lo: diff_lo[i],
+            diff_hi: diff_hi[i],
             input_mult: input_mult[i],
             out_mult: out_mult[i],
+            range_check_mult: range_check_mult[i],
         })
     }
 }
@@ -216,7 +280,24 @@ impl MaxReduceTraceTable {
 /// Enum defining the columns of the MaxReduce AIR component's trace.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum MaxReduceColumn {
-    NodeId, InputId, Idx, IsLastIdx, NextNodeId, NextInputId, NextIdx, Input, Out, MaxVal, NextMaxVal, IsLastStep, IsMax, InputMult, OutMult
+    NodeId,
+    InputId,
+    Idx,
+    /// Position of the input value within the input tensor.
+    InputIdx,
+    IsLastIdx,
+    Input,
+    Out,
+    MaxVal,
+    NextMaxVal,
+    IsLastStep,
+    IsMax,
+    Diff,
+    DiffLo,
+    DiffHi,
+    InputMult,
+    OutMult,
+    RangeCheckMult,
 }
 impl MaxReduceColumn {
     /// Returns the 0-based index for this column within the MaxReduce trace segment.
@@ -225,18 +306,20 @@ impl MaxReduceColumn {
             Self::NodeId => 0,
             Self::InputId => 1,
             Self::Idx => 2,
-            Self::IsLastIdx => 3,
-            Self::NextNodeId => 4,
-            Self::NextInputId => 5,
-            Self::NextIdx => 6,
-            Self::Input => 7,
-            Self::Out => 8,
-            Self::MaxVal => 9,
-            Self::NextMaxVal => 10,
-            Self::IsLastStep => 11,
-            Self::IsMax => 12,
-            Self::InputMult => 13,
-            Self::OutMult => 14,
+            Self::InputIdx => 3,
+            Self::IsLastIdx => 4,
+            Self::Input => 5,
+            Self::Out => 6,
+            Self::MaxVal => 7,
+            Self::NextMaxVal => 8,
+            Self::IsLastStep => 9,
+            Self::IsMax => 10,
+            Self::Diff => 11,
+            Self::DiffLo => 12,
+            Self::DiffHi => 13,
+            Self::InputMult => 14,
+            Self::OutMult => 15,
+            Self::RangeCheckMult => 16,
         }
     }
 }
@@ -244,9 +327,10 @@ impl MaxReduceColumn {
 /// Implements the `TraceColumn` trait for `MaxReduceColumn`.
 impl TraceColumn for MaxReduceColumn {
     /// Specifies the number of columns used by the MaxReduce component.
-    /// Returns `(N_TRACE_COLUMNS, 2)`, indicating main trace columns
-    /// and 2 interaction trace columns (for input and output LogUp).
+    /// Returns `(N_TRACE_COLUMNS, 2 + N_LIMB_RANGE_CHECKS)`, indicating main trace columns
+    /// and the interaction trace columns (for input and output LogUp, and the range checks
+    /// of the difference limbs).
     fn count() -> (usize, usize) {
-        (N_TRACE_COLUMNS, 2)
+        (N_TRACE_COLUMNS, 2 + N_LIMB_RANGE_CHECKS)
     }
 }
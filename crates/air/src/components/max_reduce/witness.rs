@@ -12,16 +12,22 @@ use stwo_prover::{
 };
 
 use crate::{
-    components::{InteractionClaim, MaxReduceClaim, NodeElements},
-    utils::{pack_values, TreeBuilder},
+    components::{
+        lookups::range_check::{
+            witness::limb_range_checks, RangeCheckLookupElements, N_LIMB_RANGE_CHECKS,
+        },
+        InteractionClaim, MaxReduceClaim, NodeElements,
+    },
+    utils::{pack_values, to_trace_order, TreeBuilder},
 };
 
 use super::table::{
     MaxReduceColumn, MaxReduceTraceTable, MaxReduceTraceTableRow, PackedMaxReduceTraceTableRow,
+    N_DIFF_BITS,
 };
 
 /// Number of main trace columns for the MaxReduce component.
-pub(crate) const N_TRACE_COLUMNS: usize = 15;
+pub(crate) const N_TRACE_COLUMNS: usize = 17;
 
 /// Generates main trace columns and interaction data for the MaxReduce component.
 ///
@@ -59,7 +65,7 @@ impl ClaimGenerator {
         self.inputs
             .table
             .resize(size, MaxReduceTraceTableRow::padding());
-        let packed_inputs = pack_values(&self.inputs.table);
+        let packed_inputs = pack_values(&to_trace_order(&self.inputs.table));
 
         let (trace, lookup_data) = write_trace_simd(packed_inputs);
 
@@ -79,7 +85,8 @@ impl ClaimGenerator {
 ///
 /// Processes `PackedMaxReduceTraceTableRow` data in parallel:
 /// - Maps fields (node/input IDs, running max, input/out values, flags) to main trace columns.
-/// - Extracts `[value, id]` pairs and multiplicities for input and output LogUps into `LookupData`.
+/// - Extracts `[id, idx, value]` triples and multiplicities for input and output LogUps, and the
+///   range checks of the difference limbs, into `LookupData`.
 /// Returns the `ComponentTrace` (main trace columns) and `LookupData`.
 fn write_trace_simd(
     inputs: Vec<PackedMaxReduceTraceTableRow>,
@@ -104,23 +111,28 @@ fn write_trace_simd(
             *row[MaxReduceColumn::NodeId.index()] = input.node_id;
             *row[MaxReduceColumn::InputId.index()] = input.input_id;
             *row[MaxReduceColumn::Idx.index()] = input.idx;
+            *row[MaxReduceColumn::InputIdx.index()] = input.input_idx;
             *row[MaxReduceColumn::IsLastIdx.index()] = input.is_last_idx;
-            *row[MaxReduceColumn::NextNodeId.index()] = input.next_node_id;
-            *row[MaxReduceColumn::NextInputId.index()] = input.next_input_id;
-            *row[MaxReduceColumn::NextIdx.index()] = input.next_idx;
             *row[MaxReduceColumn::Input.index()] = input.input;
             *row[MaxReduceColumn::Out.index()] = input.out;
             *row[MaxReduceColumn::MaxVal.index()] = input.max_val;
             *row[MaxReduceColumn::NextMaxVal.index()] = input.next_max_val;
             *row[MaxReduceColumn::IsLastStep.index()] = input.is_last_step;
             *row[MaxReduceColumn::IsMax.index()] = input.is_max;
+            *row[MaxReduceColumn::Diff.index()] = input.diff;
+            *row[MaxReduceColumn::DiffLo.index()] = input.diff_lo;
+            *row[MaxReduceColumn::DiffHi.index()] = input.diff_hi;
             *row[MaxReduceColumn::InputMult.index()] = input.input_mult;
             *row[MaxReduceColumn::OutMult.index()] = input.out_mult;
+            *row[MaxReduceColumn::RangeCheckMult.index()] = input.range_check_mult;
 
-            *lookup_data.input = [input.input, input.input_id];
+            *lookup_data.input = [input.input_id, input.input_idx, input.input];
             *lookup_data.input_mult = input.input_mult;
-            *lookup_data.out = [input.out, input.node_id];
+            *lookup_data.out = [input.node_id, input.idx, input.out];
             *lookup_data.out_mult = input.out_mult;
+            *lookup_data.range_checks =
+                limb_range_checks([input.diff_lo, input.diff_hi], N_DIFF_BITS);
+            *lookup_data.range_check_mult = input.range_check_mult;
         });
 
     (trace, lookup_data)
@@ -129,22 +141,28 @@ fn write_trace_simd(
 /// Intermediate data for the MaxReduce LogUp argument (input and output terms).
 #[derive(Uninitialized, IterMut, ParIterMut)]
 struct LookupData {
-    /// Input value-ID pairs: `[input_value, input_node_id]`.
-    input: Vec<[PackedM31; 2]>,
+    /// Input ID-index-value triples: `[input_node_id, input_idx, input_value]`.
+    input: Vec<[PackedM31; 3]>,
     /// Multiplicities for input values.
     input_mult: Vec<PackedM31>,
-    /// Output value-ID pairs: `[out_value, max_reduce_node_id]`.
-    out: Vec<[PackedM31; 2]>,
+    /// Output ID-index-value triples: `[max_reduce_node_id, idx, out_value]`.
+    out: Vec<[PackedM31; 3]>,
     /// Multiplicities for output values.
     out_mult: Vec<PackedM31>,
+    /// Limbs of the differences and their gaps to their maximal values, range-checked
+    /// through the shared range-check lookup.
+    range_checks: Vec<[PackedM31; N_LIMB_RANGE_CHECKS]>,
+    /// Multiplicities for the range checks.
+    range_check_mult: Vec<PackedM31>,
 }
 
 /// Generates interaction trace columns for the MaxReduce component's LogUp argument.
-/// Builds two LogUp columns (input, output) and adds them to the `tree_builder`.
+/// Builds two LogUp columns (input, output), followed by a column per range check of the
+/// difference limbs, and adds them to the `tree_builder`.
 pub struct InteractionClaimGenerator {
     /// Log2 size of the trace.
     log_size: u32,
-    /// Data (value-ID pairs and multiplicities) needed for LogUp.
+    /// Data (ID-index-value triples and multiplicities) needed for LogUp.
     lookup_data: LookupData,
 }
 
@@ -152,11 +170,14 @@ impl InteractionClaimGenerator {
     /// Writes the LogUp interaction trace columns to the `tree_builder`.
     ///
     /// Similar to SumReduce/Recip: generates two columns (Input, Output), writing `multiplicity / denom` fractions.
+    /// Then writes a column per range check, sending the difference limbs and their gaps to the
+    /// range-check lookup.
     /// Finalizes generator, adds columns to `tree_builder`, returns `InteractionClaim`.
     pub fn write_interaction_trace(
         self,
         tree_builder: &mut impl TreeBuilder<SimdBackend>,
         node_elements: &NodeElements,
+        range_check_elements: &RangeCheckLookupElements,
     ) -> InteractionClaim {
         let mut logup_gen = LogupTraceGenerator::new(self.log_size);
 
@@ -180,6 +201,18 @@ impl InteractionClaimGenerator {
         }
         col_gen.finalize_col();
 
+        for i in 0..N_LIMB_RANGE_CHECKS {
+            let mut col_gen = logup_gen.new_col();
+            for row in 0..1 << (self.log_size - LOG_N_LANES) {
+                let value = self.lookup_data.range_checks[row][i];
+                let multiplicity = self.lookup_data.range_check_mult[row];
+
+                let denom: PackedQM31 = range_check_elements.combine(&[value]);
+                col_gen.write_frac(row, multiplicity.into(), denom);
+            }
+            col_gen.finalize_col();
+        }
+
         let (trace, claimed_sum) = logup_gen.finalize_last();
         tree_builder.extend_evals(trace);
 
@@ -4,29 +4,62 @@ use add::{
     component::{AddComponent, AddEval},
     table::AddColumn,
 };
+use conv2d::{
+    component::{Conv2dComponent, Conv2dEval},
+    table::Conv2dColumn,
+};
+use less_than::{
+    component::{LessThanComponent, LessThanEval},
+    table::LessThanColumn,
+};
 use lookups::{
-    sin::{
-        component::{SinLookupComponent, SinLookupEval},
-        table::SinLookupColumn,
+    range_check::{
+        component::{RangeCheckLookupComponent, RangeCheckLookupEval},
+        table::RangeCheckLookupColumn,
+    },
+    unary::{
+        component::{UnaryLookupComponent, UnaryLookupEval},
+        functions::*,
+        table::UnaryLookupColumn,
     },
     LookupElements, Lookups,
 };
+use matmul::{
+    component::{MatMulComponent, MatMulEval},
+    table::MatMulColumn,
+};
 use max_reduce::{
     component::{MaxReduceComponent, MaxReduceEval},
     table::MaxReduceColumn,
 };
+use modulo::{
+    component::{ModComponent, ModEval},
+    table::ModColumn,
+};
 use mul::{
     component::{MulComponent, MulEval},
     table::MulColumn,
 };
+use norm::{
+    component::{NormComponent, NormEval},
+    table::NormColumn,
+};
 use recip::{
     component::{RecipComponent, RecipEval},
     table::RecipColumn,
 };
+use relu::{
+    component::{ReluComponent, ReluEval},
+    table::ReluColumn,
+};
+use rescale::{
+    component::{RescaleComponent, RescaleEval},
+    table::RescaleColumn,
+};
 use serde::{Deserialize, Serialize};
-use sin::{
-    component::{SinComponent, SinEval},
-    table::SinColumn,
+use softmax::{
+    component::{SoftmaxComponent, SoftmaxEval},
+    table::SoftmaxColumn,
 };
 use sqrt::{
     component::{SqrtComponent, SqrtEval},
@@ -50,17 +83,37 @@ use sum_reduce::{
     component::{SumReduceComponent, SumReduceEval},
     table::SumReduceColumn,
 };
+use unary::{
+    component::{UnaryComponent, UnaryEval},
+    table::UnaryColumn,
+};
+use weights::{
+    component::{WeightsComponent, WeightsEval},
+    table::WeightsColumn,
+};
 
-use crate::{preprocessed::PreProcessedTrace, LuminairClaim, LuminairInteractionClaim};
+use crate::{
+    preprocessed::{LookupLayout, PreProcessedTrace},
+    LuminairClaim, LuminairInteractionClaim, LutClaim, LutInteractionClaim,
+};
 
 pub mod add;
+pub mod conv2d;
+pub mod less_than;
 pub mod lookups;
+pub mod matmul;
 pub mod max_reduce;
+pub mod modulo;
 pub mod mul;
+pub mod norm;
 pub mod recip;
-pub mod sin;
+pub mod relu;
+pub mod rescale;
+pub mod softmax;
 pub mod sqrt;
 pub mod sum_reduce;
+pub mod unary;
+pub mod weights;
 
 /// Type alias for a vector of circle evaluations representing trace columns.
 /// Used commonly as the format for trace data passed to the STWO prover/verifier.
@@ -72,16 +125,36 @@ pub type AddClaim = Claim<AddColumn>;
 pub type MulClaim = Claim<MulColumn>;
 /// Type alias for the claim associated with the Recip component's trace.
 pub type RecipClaim = Claim<RecipColumn>;
-/// Type alias for the claim associated with the Sin component's trace.
-pub type SinClaim = Claim<SinColumn>;
-/// Type alias for the claim associated with the SinLookup component's trace.
-pub type SinLookupClaim = Claim<SinLookupColumn>;
+/// Type alias for the claim associated with the trace of a unary LUT function component (e.g. Sin).
+pub type UnaryClaim = Claim<UnaryColumn>;
+/// Type alias for the claim associated with the trace of a UnaryLookup component (e.g. SinLookup).
+pub type UnaryLookupClaim = Claim<UnaryLookupColumn>;
 /// Type alias for the claim associated with the SumReduce component's trace.
 pub type SumReduceClaim = Claim<SumReduceColumn>;
+/// Type alias for the claim associated with the MatMul component's trace.
+pub type MatMulClaim = Claim<MatMulColumn>;
 /// Type alias for the claim associated with the MaxReduce component's trace.
 pub type MaxReduceClaim = Claim<MaxReduceColumn>;
 /// Type alias for the claim associated with the Sqrt component's trace.
 pub type SqrtClaim = Claim<SqrtColumn>;
+/// Type alias for the claim associated with the LessThan component's trace.
+pub type LessThanClaim = Claim<LessThanColumn>;
+/// Type alias for the claim associated with the Softmax component's trace.
+pub type SoftmaxClaim = Claim<SoftmaxColumn>;
+/// Type alias for the claim associated with the Norm component's trace.
+pub type NormClaim = Claim<NormColumn>;
+/// Type alias for the claim associated with the Conv2d component's trace.
+pub type Conv2dClaim = Claim<Conv2dColumn>;
+/// Type alias for the claim associated with the Relu component's trace.
+pub type ReluClaim = Claim<ReluColumn>;
+/// Type alias for the claim associated with the Mod component's trace.
+pub type ModClaim = Claim<ModColumn>;
+/// Type alias for the claim associated with the Rescale component's trace.
+pub type RescaleClaim = Claim<RescaleColumn>;
+/// Type alias for the claim associated with the RangeCheckLookup component's trace.
+pub type RangeCheckLookupClaim = Claim<RangeCheckLookupColumn>;
+/// Type alias for the claim associated with the Weights component's trace.
+pub type WeightsClaim = Claim<WeightsColumn>;
 
 /// Trait implemented by trace column definitions (e.g., `AddColumn`).
 /// Provides metadata about the number of columns used by the component.
@@ -90,6 +163,13 @@ pub trait TraceColumn {
     ///
     /// This information is used to allocate space in the overall trace commitment tree.
     fn count() -> (usize, usize);
+
+    /// Returns the number of columns in the committed weights tree.
+    ///
+    /// Only the Weights component reads from this tree.
+    fn weights_count() -> usize {
+        0
+    }
 }
 
 /// Generic structure representing a claim associated with a specific component's trace.
@@ -114,13 +194,18 @@ impl<T: TraceColumn> Claim<T> {
     }
 
     /// Calculates the log sizes needed for this component in the commitment tree.
-    /// Returns a `TreeVec` containing empty (preprocessed), main trace, and interaction trace log sizes.
+    /// Returns a `TreeVec` containing empty (preprocessed), main trace, and interaction trace log sizes,
+    /// followed by the committed weights log sizes for components reading from that tree.
     pub fn log_sizes(&self) -> TreeVec<Vec<u32>> {
         let (main_trace_cols, interaction_trace_cols) = T::count();
         let trace_log_sizes = vec![self.log_size; main_trace_cols];
         let interaction_trace_log_sizes: Vec<u32> =
             vec![self.log_size; SECURE_EXTENSION_DEGREE * interaction_trace_cols];
-        TreeVec::new(vec![vec![], trace_log_sizes, interaction_trace_log_sizes])
+        let mut log_sizes = vec![vec![], trace_log_sizes, interaction_trace_log_sizes];
+        if T::weights_count() > 0 {
+            log_sizes.push(vec![self.log_size; T::weights_count()]);
+        }
+        TreeVec::new(log_sizes)
     }
 
     /// Mixes the essential claim data (currently just `log_size`) into the Fiat-Shamir channel.
@@ -140,16 +225,34 @@ pub enum ClaimType {
     Mul(Claim<MulColumn>),
     /// Claim for a Recip component trace.
     Recip(Claim<RecipColumn>),
-    /// Claim for a Sin component trace.
-    Sin(Claim<SinColumn>),
-    /// Claim for a SinLookup component trace.
-    SinLookup(Claim<SinLookupColumn>),
     /// Claim for a SumReduce component trace.
     SumReduce(Claim<SumReduceColumn>),
+    /// Claim for a MatMul component trace.
+    MatMul(Claim<MatMulColumn>),
     /// Claim for a MaxReduce component trace.
     MaxReduce(Claim<MaxReduceColumn>),
     /// Claim for a Sqrt component trace.
     Sqrt(Claim<SqrtColumn>),
+    /// Claim for a LessThan component trace.
+    LessThan(Claim<LessThanColumn>),
+    /// Claims for the components of the unary LUT functions.
+    Lut(LutClaim),
+    /// Claim for a Softmax component trace.
+    Softmax(Claim<SoftmaxColumn>),
+    /// Claim for a Norm component trace.
+    Norm(Claim<NormColumn>),
+    /// Claim for a Conv2d component trace.
+    Conv2d(Claim<Conv2dColumn>),
+    /// Claim for a Relu component trace.
+    Relu(Claim<ReluColumn>),
+    /// Claim for a Mod component trace.
+    Mod(Claim<ModColumn>),
+    /// Claim for a Rescale component trace.
+    Rescale(Claim<RescaleColumn>),
+    /// Claim for a RangeCheckLookup component trace.
+    RangeCheckLookup(Claim<RangeCheckLookupColumn>),
+    /// Claim for a Weights component trace.
+    Weights(Claim<WeightsColumn>),
 }
 
 /// Represents the claim resulting from the interaction phase (e.g., LogUp protocol).
@@ -173,7 +276,8 @@ impl InteractionClaim {
 
 // Interaction elements related to graph node structure/connections.
 // Drawn from the channel and used in interaction phase constraints.
-relation!(NodeElements, 2);
+// Each entry is a `(node_id, idx, value)` triple, so that values are tied to their position.
+relation!(NodeElements, 3);
 
 /// Container for all interaction elements drawn from the Fiat-Shamir channel.
 ///
@@ -211,16 +315,34 @@ pub struct LuminairComponents {
     mul: Option<MulComponent>,
     /// Optional Recip component instance.
     recip: Option<RecipComponent>,
-    /// Optional Sin component instance.
-    sin: Option<SinComponent>,
-    /// Optional SinLookup component instance.
-    sin_lookup: Option<SinLookupComponent>,
     /// Optional SumReduce component instance.
     sum_reduce: Option<SumReduceComponent>,
+    /// Optional MatMul component instance.
+    matmul: Option<MatMulComponent>,
     /// Optional MaxReduce component instance.
     max_reduce: Option<MaxReduceComponent>,
     /// Optional Sqrt component instance.
     sqrt: Option<SqrtComponent>,
+    /// Optional LessThan component instance.
+    less_than: Option<LessThanComponent>,
+    /// Components of the unary LUT functions.
+    luts: LutComponents,
+    /// Optional Softmax component instance.
+    softmax: Option<SoftmaxComponent>,
+    /// Optional Norm component instance.
+    norm: Option<NormComponent>,
+    /// Optional Conv2d component instance.
+    conv2d: Option<Conv2dComponent>,
+    /// Optional Relu component instance.
+    relu: Option<ReluComponent>,
+    /// Optional Mod component instance.
+    modulo: Option<ModComponent>,
+    /// Optional Rescale component instance.
+    rescale: Option<RescaleComponent>,
+    /// Optional RangeCheckLookup component instance.
+    range_check_lookup: Option<RangeCheckLookupComponent>,
+    /// Optional Weights component instance.
+    weights: Option<WeightsComponent>,
 }
 
 impl LuminairComponents {
@@ -228,13 +350,15 @@ impl LuminairComponents {
     ///
     /// Initializes only the components that have corresponding claims present in `claim`.
     /// Uses a `TraceLocationAllocator` to assign segments within the overall trace commitment tree.
-    /// Requires preprocessed trace info and lookup configurations for component setup.
+    /// Requires preprocessed trace info, lookup configurations and the fixed-point scale
+    /// of the circuit for component setup.
     pub fn new(
         claim: &LuminairClaim,
         interaction_elements: &LuminairInteractionElements,
         interaction_claim: &LuminairInteractionClaim,
         preprocessed_trace: &PreProcessedTrace,
         lookups: &Lookups,
+        fp_scale: u32,
     ) -> Self {
         let preprocessed_column_ids = &preprocessed_trace.ids();
         // Create a mapping from preprocessed column ID to log size
@@ -267,75 +391,259 @@ impl LuminairComponents {
         };
 
         let recip = if let Some(ref recip_claim) = claim.recip {
+            let lut_log_size = lookups
+                .range_check
+                .as_ref()
+                .map(|s| s.layout.log_size)
+                .unwrap();
             Some(RecipComponent::new(
                 tree_span_provider,
-                RecipEval::new(&recip_claim, interaction_elements.node_elements.clone()),
+                RecipEval::new(
+                    &recip_claim,
+                    interaction_elements.node_elements.clone(),
+                    interaction_elements.lookup_elements.range_check.clone(),
+                    lut_log_size,
+                    fp_scale,
+                ),
                 interaction_claim.recip.as_ref().unwrap().claimed_sum,
             ))
         } else {
             None
         };
 
-        let sin = if let Some(ref sin_claim) = claim.sin {
-            let lut_log_size = lookups.sin.as_ref().map(|s| s.layout.log_size).unwrap();
-            Some(SinComponent::new(
+        let sum_reduce = if let Some(ref sum_reduce_claim) = claim.sum_reduce {
+            Some(SumReduceComponent::new(
+                tree_span_provider,
+                SumReduceEval::new(
+                    &sum_reduce_claim,
+                    interaction_elements.node_elements.clone(),
+                ),
+                interaction_claim.sum_reduce.as_ref().unwrap().claimed_sum,
+            ))
+        } else {
+            None
+        };
+
+        let matmul = if let Some(ref matmul_claim) = claim.matmul {
+            Some(MatMulComponent::new(
+                tree_span_provider,
+                MatMulEval::new(&matmul_claim, interaction_elements.node_elements.clone()),
+                interaction_claim.matmul.as_ref().unwrap().claimed_sum,
+            ))
+        } else {
+            None
+        };
+
+        let max_reduce = if let Some(ref max_reduce_claim) = claim.max_reduce {
+            let lut_log_size = lookups
+                .range_check
+                .as_ref()
+                .map(|s| s.layout.log_size)
+                .unwrap();
+            Some(MaxReduceComponent::new(
+                tree_span_provider,
+                MaxReduceEval::new(
+                    &max_reduce_claim,
+                    interaction_elements.node_elements.clone(),
+                    interaction_elements.lookup_elements.range_check.clone(),
+                    lut_log_size,
+                ),
+                interaction_claim.max_reduce.as_ref().unwrap().claimed_sum,
+            ))
+        } else {
+            None
+        };
+
+        let sqrt = if let Some(ref sqrt_claim) = claim.sqrt {
+            let lut_log_size = lookups
+                .range_check
+                .as_ref()
+                .map(|s| s.layout.log_size)
+                .unwrap();
+            Some(SqrtComponent::new(
                 tree_span_provider,
-                SinEval::new(
-                    &sin_claim,
+                SqrtEval::new(
+                    &sqrt_claim,
                     interaction_elements.node_elements.clone(),
-                    interaction_elements.lookup_elements.sin.clone(),
+                    interaction_elements.lookup_elements.range_check.clone(),
                     lut_log_size,
+                    fp_scale,
                 ),
-                interaction_claim.sin.as_ref().unwrap().claimed_sum,
+                interaction_claim.sqrt.as_ref().unwrap().claimed_sum,
             ))
         } else {
             None
         };
 
-        let sin_lookup = if let Some(ref sin_lookup_claim) = claim.sin_lookup {
-            Some(SinLookupComponent::new(
+        let less_than = if let Some(ref less_than_claim) = claim.less_than {
+            let lut_log_size = lookups
+                .range_check
+                .as_ref()
+                .map(|s| s.layout.log_size)
+                .unwrap();
+            Some(LessThanComponent::new(
                 tree_span_provider,
-                SinLookupEval::new(
-                    &sin_lookup_claim,
-                    interaction_elements.lookup_elements.sin.clone(),
+                LessThanEval::new(
+                    &less_than_claim,
+                    interaction_elements.node_elements.clone(),
+                    interaction_elements.lookup_elements.range_check.clone(),
+                    lut_log_size,
+                    fp_scale,
                 ),
-                interaction_claim.sin_lookup.as_ref().unwrap().claimed_sum,
+                interaction_claim.less_than.as_ref().unwrap().claimed_sum,
             ))
         } else {
             None
         };
 
-        let sum_reduce = if let Some(ref sum_reduce_claim) = claim.sum_reduce {
-            Some(SumReduceComponent::new(
+        let luts = LutComponents::new(
+            tree_span_provider,
+            &claim.luts,
+            interaction_elements,
+            &interaction_claim.luts,
+            lookups,
+        );
+
+        let softmax = if let Some(ref softmax_claim) = claim.softmax {
+            let layout = &lookups.exp.as_ref().unwrap().layout;
+            let lut_log_size = lookups
+                .range_check
+                .as_ref()
+                .map(|s| s.layout.log_size.max(layout.log_size))
+                .unwrap();
+            Some(SoftmaxComponent::new(
                 tree_span_provider,
-                SumReduceEval::new(
-                    &sum_reduce_claim,
+                SoftmaxEval::new(
+                    &softmax_claim,
                     interaction_elements.node_elements.clone(),
+                    interaction_elements.lookup_elements.exp.clone(),
+                    interaction_elements.lookup_elements.range_check.clone(),
+                    layout,
+                    lut_log_size,
+                    fp_scale,
                 ),
-                interaction_claim.sum_reduce.as_ref().unwrap().claimed_sum,
+                interaction_claim.softmax.as_ref().unwrap().claimed_sum,
             ))
         } else {
             None
         };
 
-        let max_reduce = if let Some(ref max_reduce_claim) = claim.max_reduce {
-            Some(MaxReduceComponent::new(
+        let norm = if let Some(ref norm_claim) = claim.norm {
+            let lut_log_size = lookups
+                .range_check
+                .as_ref()
+                .map(|s| s.layout.log_size)
+                .unwrap();
+            Some(NormComponent::new(
                 tree_span_provider,
-                MaxReduceEval::new(
-                    &max_reduce_claim,
+                NormEval::new(
+                    &norm_claim,
                     interaction_elements.node_elements.clone(),
+                    interaction_elements.lookup_elements.range_check.clone(),
+                    lut_log_size,
+                    fp_scale,
                 ),
-                interaction_claim.max_reduce.as_ref().unwrap().claimed_sum,
+                interaction_claim.norm.as_ref().unwrap().claimed_sum,
             ))
         } else {
             None
         };
 
-        let sqrt = if let Some(ref sqrt_claim) = claim.sqrt {
-            Some(SqrtComponent::new(
+        let conv2d = if let Some(ref conv2d_claim) = claim.conv2d {
+            Some(Conv2dComponent::new(
                 tree_span_provider,
-                SqrtEval::new(&sqrt_claim, interaction_elements.node_elements.clone()),
-                interaction_claim.sqrt.as_ref().unwrap().claimed_sum,
+                Conv2dEval::new(&conv2d_claim, interaction_elements.node_elements.clone()),
+                interaction_claim.conv2d.as_ref().unwrap().claimed_sum,
+            ))
+        } else {
+            None
+        };
+
+        let relu = if let Some(ref relu_claim) = claim.relu {
+            let lut_log_size = lookups
+                .range_check
+                .as_ref()
+                .map(|s| s.layout.log_size)
+                .unwrap();
+            Some(ReluComponent::new(
+                tree_span_provider,
+                ReluEval::new(
+                    &relu_claim,
+                    interaction_elements.node_elements.clone(),
+                    interaction_elements.lookup_elements.range_check.clone(),
+                    lut_log_size,
+                ),
+                interaction_claim.relu.as_ref().unwrap().claimed_sum,
+            ))
+        } else {
+            None
+        };
+
+        let modulo = if let Some(ref mod_claim) = claim.modulo {
+            let lut_log_size = lookups
+                .range_check
+                .as_ref()
+                .map(|s| s.layout.log_size)
+                .unwrap();
+            Some(ModComponent::new(
+                tree_span_provider,
+                ModEval::new(
+                    &mod_claim,
+                    interaction_elements.node_elements.clone(),
+                    interaction_elements.lookup_elements.range_check.clone(),
+                    lut_log_size,
+                ),
+                interaction_claim.modulo.as_ref().unwrap().claimed_sum,
+            ))
+        } else {
+            None
+        };
+
+        let rescale = if let Some(ref rescale_claim) = claim.rescale {
+            let lut_log_size = lookups
+                .range_check
+                .as_ref()
+                .map(|s| s.layout.log_size)
+                .unwrap();
+            Some(RescaleComponent::new(
+                tree_span_provider,
+                RescaleEval::new(
+                    &rescale_claim,
+                    interaction_elements.node_elements.clone(),
+                    interaction_elements.lookup_elements.range_check.clone(),
+                    lut_log_size,
+                    fp_scale,
+                ),
+                interaction_claim.rescale.as_ref().unwrap().claimed_sum,
+            ))
+        } else {
+            None
+        };
+
+        let range_check_lookup = if let Some(ref range_check_lookup_claim) =
+            claim.range_check_lookup
+        {
+            Some(RangeCheckLookupComponent::new(
+                tree_span_provider,
+                RangeCheckLookupEval::new(
+                    &range_check_lookup_claim,
+                    interaction_elements.lookup_elements.range_check.clone(),
+                ),
+                interaction_claim
+                    .range_check_lookup
+                    .as_ref()
+                    .unwrap()
+                    .claimed_sum,
+            ))
+        } else {
+            None
+        };
+
+        let weights = if let Some(ref weights_claim) = claim.weights {
+            Some(WeightsComponent::new(
+                tree_span_provider,
+                WeightsEval::new(&weights_claim, interaction_elements.node_elements.clone()),
+                interaction_claim.weights.as_ref().unwrap().claimed_sum,
             ))
         } else {
             None
@@ -345,11 +653,20 @@ impl LuminairComponents {
             add,
             mul,
             recip,
-            sin,
-            sin_lookup,
             sum_reduce,
+            matmul,
             max_reduce,
             sqrt,
+            less_than,
+            luts,
+            softmax,
+            norm,
+            conv2d,
+            relu,
+            modulo,
+            rescale,
+            range_check_lookup,
+            weights,
         }
     }
 
@@ -370,23 +687,57 @@ impl LuminairComponents {
             components.push(component);
         }
 
-        if let Some(ref component) = self.sin {
+        if let Some(ref component) = self.sum_reduce {
             components.push(component);
         }
 
-        if let Some(ref component) = self.sin_lookup {
+        if let Some(ref component) = self.matmul {
             components.push(component);
         }
 
-        if let Some(ref component) = self.sum_reduce {
+        if let Some(ref component) = self.max_reduce {
             components.push(component);
         }
 
-        if let Some(ref component) = self.max_reduce {
+        if let Some(ref component) = self.sqrt {
             components.push(component);
         }
 
-        if let Some(ref component) = self.sqrt {
+        if let Some(ref component) = self.less_than {
+            components.push(component);
+        }
+
+        self.luts.push_provers(&mut components);
+
+        if let Some(ref component) = self.softmax {
+            components.push(component);
+        }
+
+        if let Some(ref component) = self.norm {
+            components.push(component);
+        }
+
+        if let Some(ref component) = self.conv2d {
+            components.push(component);
+        }
+
+        if let Some(ref component) = self.relu {
+            components.push(component);
+        }
+
+        if let Some(ref component) = self.modulo {
+            components.push(component);
+        }
+
+        if let Some(ref component) = self.rescale {
+            components.push(component);
+        }
+
+        if let Some(ref component) = self.range_check_lookup {
+            components.push(component);
+        }
+
+        if let Some(ref component) = self.weights {
             components.push(component);
         }
         components
@@ -401,3 +752,107 @@ impl LuminairComponents {
             .collect()
     }
 }
+
+/// Returns the log2 size of the largest preprocessed table used by a unary component with
+/// the LUT `layout`: the LUT itself, or the range check if the LUT is interpolated.
+fn unary_lut_log_size(layout: &LookupLayout, lookups: &Lookups) -> u32 {
+    match (&layout.interpolation, &lookups.range_check) {
+        (Some(_), Some(range_check)) => layout.log_size.max(range_check.layout.log_size),
+        _ => layout.log_size,
+    }
+}
+
+// Generates the components of the unary LUT functions, from the `lut_functions!` registry.
+macro_rules! lut_components {
+    (
+        unary [$([$name:ident, $field:ident, $lookup:ident, $Lookup:ident])*]
+        all [$([$aname:ident, $afield:ident, $alookup:ident, $ALookup:ident])*]
+    ) => {
+        /// Aggregates the active components of the unary LUT functions.
+        ///
+        /// Holds the unary components, then the lookup components of all the functions,
+        /// in the order of the `lut_functions!` registry.
+        struct LutComponents {
+            $(
+                #[doc = concat!("Optional ", stringify!($name), " component instance.")]
+                $field: Option<UnaryComponent<$name>>,
+            )*
+            $(
+                #[doc = concat!("Optional ", stringify!($ALookup), " component instance.")]
+                $alookup: Option<UnaryLookupComponent<$aname>>,
+            )*
+        }
+
+        impl LutComponents {
+            /// Creates the components that have a claim in `claim`.
+            fn new(
+                tree_span_provider: &mut TraceLocationAllocator,
+                claim: &LutClaim,
+                interaction_elements: &LuminairInteractionElements,
+                interaction_claim: &LutInteractionClaim,
+                lookups: &Lookups,
+            ) -> Self {
+                $(
+                    let $field = if let Some(ref unary_claim) = claim.$field {
+                        let layout = &lookups.$field.as_ref().unwrap().layout;
+                        let lut_log_size = unary_lut_log_size(layout, lookups);
+                        Some(UnaryComponent::new(
+                            tree_span_provider,
+                            UnaryEval::<$name>::new(
+                                unary_claim,
+                                interaction_elements.node_elements.clone(),
+                                interaction_elements.lookup_elements.$field.clone(),
+                                interaction_elements.lookup_elements.range_check.clone(),
+                                layout,
+                                lut_log_size,
+                            ),
+                            interaction_claim.$field.as_ref().unwrap().claimed_sum,
+                        ))
+                    } else {
+                        None
+                    };
+                )*
+                $(
+                    let $alookup = if let Some(ref lookup_claim) = claim.$alookup {
+                        let layout = &lookups.$afield.as_ref().unwrap().layout;
+                        Some(UnaryLookupComponent::new(
+                            tree_span_provider,
+                            UnaryLookupEval::<$aname>::new(
+                                lookup_claim,
+                                interaction_elements.lookup_elements.$afield.clone(),
+                                layout.interpolation.is_some(),
+                            ),
+                            interaction_claim.$alookup.as_ref().unwrap().claimed_sum,
+                        ))
+                    } else {
+                        None
+                    };
+                )*
+
+                Self {
+                    $($field,)*
+                    $($alookup,)*
+                }
+            }
+
+            /// Pushes the active components to `components`, cast as `ComponentProver`.
+            fn push_provers<'a>(
+                &'a self,
+                components: &mut Vec<&'a dyn ComponentProver<SimdBackend>>,
+            ) {
+                $(
+                    if let Some(ref component) = self.$field {
+                        components.push(component);
+                    }
+                )*
+                $(
+                    if let Some(ref component) = self.$alookup {
+                        components.push(component);
+                    }
+                )*
+            }
+        }
+    };
+}
+
+crate::lut_functions!(lut_components);
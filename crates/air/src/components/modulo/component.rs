@@ -0,0 +1,231 @@
+use crate::{
+    components::{
+        lookups::range_check::{
+            component::{eval_limbs, eval_signed_limbs},
+            RangeCheckLookupElements,
+        },
+        ModClaim, NodeElements,
+    },
+    preprocessed::eval_structure,
+};
+use num_traits::One;
+use stwo_prover::constraint_framework::{
+    EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry, ORIGINAL_TRACE_IDX,
+};
+
+use super::table::{ModTraceTableRow, N_QUOTIENT_BITS, N_RANGE_BITS};
+
+/// The STWO AIR component for element-wise Mod operations.
+///
+/// This wraps the `ModEval` logic within the STWO `FrameworkComponent`,
+/// which handles common AIR component setup and evaluation.
+pub type ModComponent = FrameworkComponent<ModEval>;
+
+/// Defines the AIR constraints evaluation logic for the Mod component.
+///
+/// Implements the `FrameworkEval` trait, providing methods to define the component's
+/// trace layout, constraint degrees, and the core constraint evaluation function.
+pub struct ModEval {
+    /// Log2 size of the component's trace segment.
+    log_size: u32,
+    /// Log2 size of the preprocessed range-check table.
+    lut_log_size: u32,
+    /// Interaction elements for node relations (used in LogUp).
+    node_elements: NodeElements,
+    /// Interaction elements for the range checks of the limbs.
+    range_check_elements: RangeCheckLookupElements,
+}
+
+impl ModEval {
+    /// Creates a new `ModEval` instance.
+    /// Takes the component's claim (for `log_size`), interaction elements for nodes and
+    /// the range check, and the log_size of the range-check table.
+    pub fn new(
+        claim: &ModClaim,
+        node_elements: NodeElements,
+        range_check_elements: RangeCheckLookupElements,
+        lut_log_size: u32,
+    ) -> Self {
+        Self {
+            log_size: claim.log_size,
+            lut_log_size,
+            node_elements,
+            range_check_elements,
+        }
+    }
+}
+
+/// Implements the core constraint evaluation logic for the Mod component.
+impl FrameworkEval for ModEval {
+    /// Returns the log2 size of this component's trace segment.
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// Returns the max log2 degree bound, considering both main trace and range-check table sizes.
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        std::cmp::max(self.log_size, self.lut_log_size) + 1
+    }
+
+    /// Evaluates the Mod AIR constraints on a given evaluation point (`eval`).
+    ///
+    /// Defines constraints ensuring:
+    /// - **Structural:** The IDs, indices, flags and multiplicities match the preprocessed trace.
+    /// - **Consistency:** `lhs = quotient * rhs + out` with `0 <= out < |rhs|`. Both `out` and
+    ///   `|rhs| - 1 - out` lie in `[0, 2^N_RANGE_BITS)`, and the quotient is its sign and magnitude,
+    ///   in `(-2^N_QUOTIENT_BITS, 2^N_QUOTIENT_BITS)`, so that `|quotient * rhs| + out` stays below
+    ///   `2^30` and the division identity holds over the integers.
+    /// - **Transition:** Correctness of transitions between consecutive rows (e.g., index increments).
+    /// - **Interaction (LogUp):** Links values used/produced by Mod operations to the global
+    ///   LogUp argument, ensuring consistency across the entire computation trace. The limbs of
+    ///   the remainder, the slack and the quotient magnitude are sent, with their gaps to their
+    ///   maximal values, to the shared range-check lookup.
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        // IDs (current and next row, for transition constraints)
+        // ID of the node in the computational graph.
+        let [node_id, next_node_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of first input tensor.
+        let [lhs_id, next_lhs_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of second input tensor.
+        let [rhs_id, next_rhs_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Index in the flattened tensor.
+        let [idx, next_idx] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Positions of the input values within their tensors.
+        let lhs_idx = eval.next_trace_mask();
+        let rhs_idx = eval.next_trace_mask();
+        let is_last_idx = eval.next_trace_mask(); // Flag if this is the last index for this operation.
+
+        // Values for consistency constraints
+        let lhs_val = eval.next_trace_mask(); // Dividend.
+        let rhs_val = eval.next_trace_mask(); // Divisor.
+        let out_val = eval.next_trace_mask(); // Remainder.
+        let quotient = eval.next_trace_mask(); // Integer quotient.
+        let rhs_sign = eval.next_trace_mask(); // Sign of the divisor (1 or -1).
+        let rem_limbs: [E::F; 2] = std::array::from_fn(|_| eval.next_trace_mask());
+        let slack_limbs: [E::F; 2] = std::array::from_fn(|_| eval.next_trace_mask());
+        let quotient_sign = eval.next_trace_mask(); // Sign of the quotient.
+        let quotient_limbs: [E::F; 2] = std::array::from_fn(|_| eval.next_trace_mask());
+
+        // Multiplicities for interaction constraints
+        let lhs_mult = eval.next_trace_mask();
+        let rhs_mult = eval.next_trace_mask();
+        let out_mult = eval.next_trace_mask();
+        let range_check_mult = eval.next_trace_mask();
+
+        // ┌─────────────────────────────┐
+        // │   Structural Constraints    │
+        // └─────────────────────────────┘
+
+        // The IDs, indices, flags and multiplicities are the ones fixed by the graph.
+        eval_structure::<_, ModTraceTableRow>(
+            &mut eval,
+            "modulo",
+            vec![
+                node_id.clone(),
+                lhs_id.clone(),
+                rhs_id.clone(),
+                idx.clone(),
+                lhs_idx.clone(),
+                rhs_idx.clone(),
+                is_last_idx.clone(),
+                lhs_mult.clone(),
+                rhs_mult.clone(),
+                out_mult.clone(),
+            ],
+        );
+
+        // ┌─────────────────────────────┐
+        // │   Consistency Constraints   │
+        // └─────────────────────────────┘
+
+        // The is_last_idx flag is either 0 or 1.
+        eval.add_constraint(is_last_idx.clone() * (is_last_idx.clone() - E::F::one()));
+
+        // The divisor sign is either 1 or -1.
+        eval.add_constraint(rhs_sign.clone() * rhs_sign.clone() - E::F::one());
+
+        // Euclidean division: lhs = quotient * rhs + out.
+        eval.add_constraint(lhs_val.clone() - quotient.clone() * rhs_val.clone() - out_val.clone());
+
+        // The range-check multiplicity is boolean, and set on every row emitting its output.
+        eval.add_constraint(range_check_mult.clone() * (range_check_mult.clone() - E::F::one()));
+        eval.add_constraint(out_mult.clone() * (E::F::one() - range_check_mult.clone()));
+
+        // ┌────────────────────────────┐
+        // │   Transition Constraints   │
+        // └────────────────────────────┘
+
+        // If this is not the last index for this operation, then:
+        // 1. The next row should be for the same operation on the same tensors.
+        // 2. The index should increment by 1.
+        let not_last = E::F::one() - is_last_idx;
+
+        // Same node ID
+        eval.add_constraint(not_last.clone() * (next_node_id - node_id.clone()));
+
+        // Same tensor IDs
+        eval.add_constraint(not_last.clone() * (next_lhs_id - lhs_id.clone()));
+        eval.add_constraint(not_last.clone() * (next_rhs_id - rhs_id.clone()));
+
+        // Index increment by 1
+        eval.add_constraint(not_last * (next_idx - idx.clone() - E::F::one()));
+
+        // ┌─────────────────────────────┐
+        // │   Interaction Constraints   │
+        // └─────────────────────────────┘
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            lhs_mult.into(),
+            &[lhs_id, lhs_idx, lhs_val],
+        ));
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            rhs_mult.into(),
+            &[rhs_id, rhs_idx, rhs_val.clone()],
+        ));
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            out_mult.into(),
+            &[node_id, idx, out_val.clone()],
+        ));
+
+        // The remainder is non-negative: its limbs lie in [0, 2^lo_bits) and [0, 2^hi_bits).
+        eval_limbs(
+            &mut eval,
+            &self.range_check_elements,
+            &range_check_mult,
+            out_val.clone(),
+            rem_limbs,
+            N_RANGE_BITS,
+        );
+
+        // The remainder is strictly below |rhs|: the slack |rhs| - 1 - out is non-negative.
+        // This also rules out a zero divisor.
+        eval_limbs(
+            &mut eval,
+            &self.range_check_elements,
+            &range_check_mult,
+            rhs_sign * rhs_val - E::F::one() - out_val,
+            slack_limbs,
+            N_RANGE_BITS,
+        );
+
+        // The quotient is bounded, so that quotient * rhs cannot wrap around the field.
+        eval_signed_limbs(
+            &mut eval,
+            &self.range_check_elements,
+            &range_check_mult,
+            quotient,
+            quotient_sign,
+            quotient_limbs,
+            N_QUOTIENT_BITS,
+        );
+
+        eval.finalize_logup();
+
+        eval
+    }
+}
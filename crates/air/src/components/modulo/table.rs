@@ -0,0 +1,398 @@
+use num_traits::{One, Zero};
+use serde::{Deserialize, Serialize};
+use stwo_prover::core::{
+    backend::simd::{
+        conversion::{Pack, Unpack},
+        m31::{PackedM31, N_LANES},
+    },
+    fields::m31::M31,
+};
+
+use crate::{
+    components::{lookups::range_check::N_LIMB_RANGE_CHECKS, TraceColumn},
+    preprocessed::StructuredRow,
+};
+
+use super::witness::N_TRACE_COLUMNS;
+
+/// Number of range-checked bits of the remainder.
+///
+/// Both `r` and `|rhs| - 1 - r` are range-checked to `[0, 2^N_RANGE_BITS)`, which bounds the
+/// divisor to `|rhs| < 2^(N_RANGE_BITS + 1)` in fixed-point representation.
+pub const N_RANGE_BITS: u32 = 15;
+
+/// Number of range-checked bits of the quotient magnitude.
+///
+/// With `|q| < 2^N_QUOTIENT_BITS`, `|q * rhs| + r` stays below `2^(N_QUOTIENT_BITS + N_RANGE_BITS + 2)`,
+/// i.e. `2^30`, so that `lhs = q * rhs + r` cannot wrap around the field.
+pub const N_QUOTIENT_BITS: u32 = 13;
+
+/// Represents the raw trace data collected for Mod operations.
+///
+/// Stores rows capturing the inputs, the remainder, the quotient, the limbs of their
+/// range checks, and metadata for each Mod operation.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ModTraceTable {
+    /// Vector containing all rows of the Mod trace.
+    pub table: Vec<ModTraceTableRow>,
+}
+
+/// Represents a single row in the `ModTraceTable`.
+///
+/// Contains values for evaluating Mod AIR constraints: state IDs,
+/// input/output values, quotient and range-check witnesses, and LogUp multiplicities.
+#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ModTraceTableRow {
+    /// ID of the current Mod node.
+    pub node_id: M31,
+    /// ID of the node providing the left-hand side input.
+    pub lhs_id: M31,
+    /// ID of the node providing the right-hand side input.
+    pub rhs_id: M31,
+    /// Index within the tensor for this operation.
+    pub idx: M31,
+    /// Position of the LHS value within the left-hand side input tensor.
+    pub lhs_idx: M31,
+    /// Position of the RHS value within the right-hand side input tensor.
+    pub rhs_idx: M31,
+    /// Flag indicating if this is the last element processed for this node (1 if true, 0 otherwise).
+    pub is_last_idx: M31,
+    /// Value of the left-hand side input (dividend).
+    pub lhs: M31,
+    /// Value of the right-hand side input (divisor).
+    pub rhs: M31,
+    /// Value of the output, the remainder `r` with `0 <= r < |rhs|`.
+    pub out: M31,
+    /// Integer quotient `q` such that `lhs = q * rhs + r`.
+    pub quotient: M31,
+    /// Sign of the divisor (`1` or `-1`), so that `rhs_sign * rhs = |rhs|`.
+    pub rhs_sign: M31,
+    /// Low and high limbs of the remainder `r`.
+    pub rem_limbs: [M31; 2],
+    /// Low and high limbs of the slack `|rhs| - 1 - r`.
+    pub slack_limbs: [M31; 2],
+    /// Sign of the quotient (1 if negative, 0 otherwise).
+    pub quotient_sign: M31,
+    /// Low and high limbs of the quotient magnitude.
+    pub quotient_limbs: [M31; 2],
+    /// Multiplicity contribution for the LogUp argument related to the LHS input.
+    pub lhs_mult: M31,
+    /// Multiplicity contribution for the LogUp argument related to the RHS input.
+    pub rhs_mult: M31,
+    /// Multiplicity contribution for the LogUp argument related to the output.
+    pub out_mult: M31,
+    /// Multiplicity contribution for the range checks of the limbs.
+    pub range_check_mult: M31,
+}
+
+impl ModTraceTableRow {
+    /// Creates a default padding row for the Mod trace.
+    ///
+    /// Uses `rhs = 1` so that the padding row satisfies `0 <= r < |rhs|`.
+    pub(crate) fn padding() -> Self {
+        Self {
+            node_id: M31::zero(),
+            lhs_id: M31::zero(),
+            rhs_id: M31::zero(),
+            idx: M31::zero(),
+            lhs_idx: M31::zero(),
+            rhs_idx: M31::zero(),
+            is_last_idx: M31::one(),
+            lhs: M31::zero(),
+            rhs: M31::one(),
+            out: M31::zero(),
+            quotient: M31::zero(),
+            rhs_sign: M31::one(),
+            rem_limbs: [M31::zero(); 2],
+            slack_limbs: [M31::zero(); 2],
+            quotient_sign: M31::zero(),
+            quotient_limbs: [M31::zero(); 2],
+            lhs_mult: M31::zero(),
+            rhs_mult: M31::zero(),
+            out_mult: M31::zero(),
+            range_check_mult: M31::zero(),
+        }
+    }
+}
+
+/// Implements the `StructuredRow` trait for `ModTraceTableRow`.
+impl StructuredRow for ModTraceTableRow {
+    /// IDs, indices, flags and multiplicities of the row, fixed by the graph.
+    const STRUCTURE: &'static [&'static str] = &[
+        "node_id",
+        "lhs_id",
+        "rhs_id",
+        "idx",
+        "lhs_idx",
+        "rhs_idx",
+        "is_last_idx",
+        "lhs_mult",
+        "rhs_mult",
+        "out_mult",
+    ];
+
+    /// Returns the values of the structural columns of the row.
+    fn structure(&self) -> Vec<M31> {
+        vec![
+            self.node_id,
+            self.lhs_id,
+            self.rhs_id,
+            self.idx,
+            self.lhs_idx,
+            self.rhs_idx,
+            self.is_last_idx,
+            self.lhs_mult,
+            self.rhs_mult,
+            self.out_mult,
+        ]
+    }
+}
+
+/// SIMD-packed representation of a `ModTraceTableRow`.
+#[derive(Debug, Copy, Clone)]
+pub struct PackedModTraceTableRow {
+    /// Packed `node_id` values.
+    pub node_id: PackedM31,
+    /// Packed `lhs_id` values.
+    pub lhs_id: PackedM31,
+    /// Packed `rhs_id` values.
+    pub rhs_id: PackedM31,
+    /// Packed `idx` values.
+    pub idx: PackedM31,
+    /// Packed `lhs_idx` values.
+    pub lhs_idx: PackedM31,
+    /// Packed `rhs_idx` values.
+    pub rhs_idx: PackedM31,
+    /// Packed `is_last_idx` values.
+    pub is_last_idx: PackedM31,
+    /// Packed `lhs` values.
+    pub lhs: PackedM31,
+    /// Packed `rhs` values.
+    pub rhs: PackedM31,
+    /// Packed `out` values.
+    pub out: PackedM31,
+    /// Packed `quotient` values.
+    pub quotient: PackedM31,
+    /// Packed `rhs_sign` values.
+    pub rhs_sign: PackedM31,
+    /// Packed `rem_limbs` values, one packed column per limb.
+    pub rem_limbs: [PackedM31; 2],
+    /// Packed `slack_limbs` values, one packed column per limb.
+    pub slack_limbs: [PackedM31; 2],
+    /// Packed `quotient_sign` values.
+    pub quotient_sign: PackedM31,
+    /// Packed `quotient_limbs` values, one packed column per limb.
+    pub quotient_limbs: [PackedM31; 2],
+    /// Packed `lhs_mult` values.
+    pub lhs_mult: PackedM31,
+    /// Packed `rhs_mult` values.
+    pub rhs_mult: PackedM31,
+    /// Packed `out_mult` values.
+    pub out_mult: PackedM31,
+    /// Packed `range_check_mult` values.
+    pub range_check_mult: PackedM31,
+}
+
+impl Pack for ModTraceTableRow {
+    type SimdType = PackedModTraceTableRow;
+
+    fn pack(inputs: [Self; N_LANES]) -> Self::SimdType {
+        PackedModTraceTableRow {
+            node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].node_id)),
+            lhs_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs_id)),
+            rhs_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs_id)),
+            idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].idx)),
+            lhs_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs_idx)),
+            rhs_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs_idx)),
+            is_last_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_idx)),
+            lhs: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs)),
+            rhs: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs)),
+            out: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out)),
+            quotient: PackedM31::from_array(std::array::from_fn(|i| inputs[i].quotient)),
+            rhs_sign: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs_sign)),
+            rem_limbs: std::array::from_fn(|b| {
+                PackedM31::from_array(std::array::from_fn(|i| inputs[i].rem_limbs[b]))
+            }),
+            slack_limbs: std::array::from_fn(|b| {
+                PackedM31::from_array(std::array::from_fn(|i| inputs[i].slack_limbs[b]))
+            }),
+            quotient_sign: PackedM31::from_array(std::array::from_fn(|i| inputs[i].quotient_sign)),
+            quotient_limbs: std::array::from_fn(|b| {
+                PackedM31::from_array(std::array::from_fn(|i| inputs[i].quotient_limbs[b]))
+            }),
+            lhs_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs_mult)),
+            rhs_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs_mult)),
+            out_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_mult)),
+            range_check_mult: PackedM31::from_array(std::array::from_fn(|i| {
+                inputs[i].range_check_mult
+            })),
+        }
+    }
+}
+
+impl Unpack for PackedModTraceTableRow {
+    type CpuType = ModTraceTableRow;
+
+    fn unpack(self) -> [Self::CpuType; N_LANES] {
+        let (
+            node_id,
+            lhs_id,
+            rhs_id,
+            idx,
+            lhs_idx,
+            rhs_idx,
+            is_last_idx,
+            lhs,
+            rhs,
+            out,
+            quotient,
+            rhs_sign,
+            quotient_sign,
+            lhs_mult,
+            rhs_mult,
+            out_mult,
+            range_check_mult,
+        ) = (
+            self.node_id.to_array(),
+            self.lhs_id.to_array(),
+            self.rhs_id.to_array(),
+            self.idx.to_array(),
+            self.lhs_idx.to_array(),
+            self.rhs_idx.to_array(),
+            self.is_last_idx.to_array(),
+            self.lhs.to_array(),
+            self.rhs.to_array(),
+            self.out.to_array(),
+            self.quotient.to_array(),
+            self.rhs_sign.to_array(),
+            self.quotient_sign.to_array(),
+            self.lhs_mult.to_array(),
+            self.rhs_mult.to_array(),
+            self.out_mult.to_array(),
+            self.range_check_mult.to_array(),
+        );
+        let rem_limbs = self.rem_limbs.map(|b| b.to_array());
+        let slack_limbs = self.slack_limbs.map(|b| b.to_array());
+        let quotient_limbs = self.quotient_limbs.map(|b| b.to_array());
+
+        std::array::from_fn(|i| ModTraceTableRow {
+            node_id: node_id[i],
+            lhs_id: lhs_id[i],
+            rhs_id: rhs_id[i],
+            idx: idx[i],
+            lhs_idx: lhs_idx[i],
+            rhs_idx: rhs_idx[i],
+            is_last_idx: is_last_idx[i],
+            lhs: lhs[i],
+            rhs: rhs[i],
+            out: out[i],
+            quotient: quotient[i],
+            rhs_sign: rhs_sign[i],
+            rem_limbs: std::array::from_fn(|b| rem_limbs[b][i]),
+            slack_limbs: std::array::from_fn(|b| slack_limbs[b][i]),
+            quotient_sign: quotient_sign[i],
+            quotient_limbs: std::array::from_fn(|b| quotient_limbs[b][i]),
+            lhs_mult: lhs_mult[i],
+            rhs_mult: rhs_mult[i],
+            out_mult: out_mult[i],
+            range_check_mult: range_check_mult[i],
+        })
+    }
+}
+
+impl ModTraceTable {
+    /// Creates a new, empty `ModTraceTable`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a single row to the trace table.
+    pub fn add_row(&mut self, row: ModTraceTableRow) {
+        self.table.push(row);
+    }
+}
+
+/// Enum defining the columns of the Mod AIR component's trace.
+/// Provides a mapping from meaningful names to column indices.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ModColumn {
+    /// ID of the current Mod node.
+    NodeId,
+    /// ID of the node providing the left-hand side input.
+    LhsId,
+    /// ID of the node providing the right-hand side input.
+    RhsId,
+    /// Index within the tensor for this operation.
+    Idx,
+    /// Position of the LHS value within the left-hand side input tensor.
+    LhsIdx,
+    /// Position of the RHS value within the right-hand side input tensor.
+    RhsIdx,
+    /// Flag indicating if this is the last element processed for this node.
+    IsLastIdx,
+    /// Value of the left-hand side input (dividend).
+    Lhs,
+    /// Value of the right-hand side input (divisor).
+    Rhs,
+    /// Value of the output (remainder).
+    Out,
+    /// Integer quotient.
+    Quotient,
+    /// Sign of the divisor.
+    RhsSign,
+    /// The `i`-th limb of the remainder.
+    RemLimb(usize),
+    /// The `i`-th limb of the slack `|rhs| - 1 - r`.
+    SlackLimb(usize),
+    /// Sign of the quotient.
+    QuotientSign,
+    /// The `i`-th limb of the quotient magnitude.
+    QuotientLimb(usize),
+    /// Multiplicity for the LogUp argument (LHS input).
+    LhsMult,
+    /// Multiplicity for the LogUp argument (RHS input).
+    RhsMult,
+    /// Multiplicity for the LogUp argument (output).
+    OutMult,
+    /// Multiplicity for the range checks of the limbs.
+    RangeCheckMult,
+}
+
+impl ModColumn {
+    /// Returns the 0-based index for this column within the Mod trace segment.
+    pub const fn index(self) -> usize {
+        match self {
+            Self::NodeId => 0,
+            Self::LhsId => 1,
+            Self::RhsId => 2,
+            Self::Idx => 3,
+            Self::LhsIdx => 4,
+            Self::RhsIdx => 5,
+            Self::IsLastIdx => 6,
+            Self::Lhs => 7,
+            Self::Rhs => 8,
+            Self::Out => 9,
+            Self::Quotient => 10,
+            Self::RhsSign => 11,
+            Self::RemLimb(i) => 12 + i,
+            Self::SlackLimb(i) => 14 + i,
+            Self::QuotientSign => 16,
+            Self::QuotientLimb(i) => 17 + i,
+            Self::LhsMult => 19,
+            Self::RhsMult => 20,
+            Self::OutMult => 21,
+            Self::RangeCheckMult => 22,
+        }
+    }
+}
+
+/// Implements the `TraceColumn` trait for `ModColumn`.
+impl TraceColumn for ModColumn {
+    /// Specifies the number of columns used by the Mod component.
+    /// Returns `(N_TRACE_COLUMNS, 3 + 3 * N_LIMB_RANGE_CHECKS)`, indicating the number of main
+    /// trace columns and the number of interaction trace columns (for the LHS, RHS and output
+    /// LogUp, and the range checks of the remainder, slack and quotient limbs).
+    fn count() -> (usize, usize) {
+        (N_TRACE_COLUMNS, 3 + 3 * N_LIMB_RANGE_CHECKS)
+    }
+}
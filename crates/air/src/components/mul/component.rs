@@ -1,14 +1,14 @@
 use crate::{
     components::{MulClaim, NodeElements},
-    DEFAULT_FP_SCALE,
+    preprocessed::eval_structure,
 };
 use num_traits::One;
-use numerair::eval::EvalFixedPoint;
-use stwo_prover::{
-    constraint_framework::{EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry},
-    core::fields::m31::M31,
+use stwo_prover::constraint_framework::{
+    EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry, ORIGINAL_TRACE_IDX,
 };
 
+use super::table::MulTraceTableRow;
+
 /// The STWO AIR component for element-wise multiplication operations.
 /// Wraps the `MulEval` logic within the STWO `FrameworkComponent`.
 pub type MulComponent = FrameworkComponent<MulEval>;
@@ -48,38 +48,60 @@ impl FrameworkEval for MulEval {
     /// Evaluates the Mul AIR constraints on a given evaluation point (`eval`).
     ///
     /// Defines constraints for:
-    /// - **Consistency:** Checks the fixed-point multiplication relation (`lhs * rhs = out * SCALE + rem`)
-    ///   using `eval_fixed_mul`, and boolean flags.
+    /// - **Structural:** The IDs, indices, flags and multiplicities match the preprocessed trace.
+    /// - **Consistency:** Checks the exact product `out = lhs * rhs`, and boolean flags.
+    ///   The output is at the sum of the input scales, until a Rescale brings it back.
+    ///   `gen_trace` rejects products outside the signed M31 range, which would wrap around.
     /// - **Transition:** Ensures correct state transitions between consecutive rows (same node/input IDs,
     ///   index increments by 1) when `is_last_idx` is false.
     /// - **Interaction (LogUp):** Links LHS, RHS, and OUT values to the global LogUp argument.
     /// Receives an evaluator `E` and adds constraint evaluations to it.
     fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
-        // IDs
-        let node_id = eval.next_trace_mask(); // ID of the node in the computational graph.
-        let lhs_id = eval.next_trace_mask(); // ID of first input tensor.
-        let rhs_id = eval.next_trace_mask(); // ID of second input tensor.
-        let idx = eval.next_trace_mask(); // Index in the flattened tensor.
+        // IDs (current and next row, for transition constraints)
+        // ID of the node in the computational graph.
+        let [node_id, next_node_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of first input tensor.
+        let [lhs_id, next_lhs_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of second input tensor.
+        let [rhs_id, next_rhs_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Index in the flattened tensor.
+        let [idx, next_idx] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Positions of the input values within their tensors.
+        let lhs_idx = eval.next_trace_mask();
+        let rhs_idx = eval.next_trace_mask();
         let is_last_idx = eval.next_trace_mask(); // Flag if this is the last index for this operation.
 
-        // Next IDs for transition constraints
-        let next_node_id = eval.next_trace_mask();
-        let next_lhs_id = eval.next_trace_mask();
-        let next_rhs_id = eval.next_trace_mask();
-        let next_idx = eval.next_trace_mask();
-
         // Values for consistency constraints
         let lhs_val = eval.next_trace_mask(); // Value from first tensor at index.
         let rhs_val = eval.next_trace_mask(); // Value from second tensor at index.
         let out_val = eval.next_trace_mask(); // Value in output tensor at index.
-        let rem_val = eval.next_trace_mask(); // Rem value in result tensor at index.
 
         // Multiplicities for interaction constraints
         let lhs_mult = eval.next_trace_mask();
         let rhs_mult = eval.next_trace_mask();
         let out_mult = eval.next_trace_mask();
 
-        let scale_factor = E::F::from(M31::from_u32_unchecked(1 << DEFAULT_FP_SCALE));
+        // ┌─────────────────────────────┐
+        // │   Structural Constraints    │
+        // └─────────────────────────────┘
+
+        // The IDs, indices, flags and multiplicities are the ones fixed by the graph.
+        eval_structure::<_, MulTraceTableRow>(
+            &mut eval,
+            "mul",
+            vec![
+                node_id.clone(),
+                lhs_id.clone(),
+                rhs_id.clone(),
+                idx.clone(),
+                lhs_idx.clone(),
+                rhs_idx.clone(),
+                is_last_idx.clone(),
+                lhs_mult.clone(),
+                rhs_mult.clone(),
+                out_mult.clone(),
+            ],
+        );
 
         // ┌─────────────────────────────┐
         // │   Consistency Constraints   │
@@ -88,14 +110,9 @@ impl FrameworkEval for MulEval {
         // The is_last_idx flag is either 0 or 1.
         eval.add_constraint(is_last_idx.clone() * (is_last_idx.clone() - E::F::one()));
 
-        // Evaluates fixed point multiplication.
-        eval.eval_fixed_mul(
-            lhs_val.clone(),
-            rhs_val.clone(),
-            scale_factor,
-            out_val.clone(),
-            rem_val,
-        );
+        // The output is the exact product, at the sum of the input scales.
+        eval.add_constraint(out_val.clone() - lhs_val.clone() * rhs_val.clone());
+
         // ┌────────────────────────────┐
         // │   Transition Constraints   │
         // └────────────────────────────┘
@@ -113,7 +130,7 @@ impl FrameworkEval for MulEval {
         eval.add_constraint(not_last.clone() * (next_rhs_id - rhs_id.clone()));
 
         // Index increment by 1
-        eval.add_constraint(not_last * (next_idx - idx - E::F::one()));
+        eval.add_constraint(not_last * (next_idx - idx.clone() - E::F::one()));
 
         // ┌─────────────────────────────┐
         // │   Interaction Constraints   │
@@ -122,19 +139,19 @@ impl FrameworkEval for MulEval {
         eval.add_to_relation(RelationEntry::new(
             &self.node_elements,
             lhs_mult.into(),
-            &[lhs_val, lhs_id],
+            &[lhs_id, lhs_idx, lhs_val],
         ));
 
         eval.add_to_relation(RelationEntry::new(
             &self.node_elements,
             rhs_mult.into(),
-            &[rhs_val, rhs_id],
+            &[rhs_id, rhs_idx, rhs_val],
         ));
 
         eval.add_to_relation(RelationEntry::new(
             &self.node_elements,
             out_mult.into(),
-            &[out_val, node_id],
+            &[node_id, idx, out_val],
         ));
 
         eval.finalize_logup();
@@ -8,12 +8,14 @@ use stwo_prover::core::{
     fields::m31::M31,
 };
 
-use crate::components::TraceColumn;
+use crate::{components::TraceColumn, preprocessed::StructuredRow};
+
+use super::witness::N_TRACE_COLUMNS;
 
 /// Represents the raw trace data collected for Multiplication operations.
 ///
 /// Stores rows generated during the `gen_trace` phase, capturing the inputs,
-/// outputs, remainder (for fixed-point), and metadata for each Mul operation.
+/// outputs, and metadata for each Mul operation.
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct MulTraceTable {
     /// Vector containing all rows of the Mul trace.
@@ -22,8 +24,8 @@ pub struct MulTraceTable {
 
 /// Represents a single row in the `MulTraceTable`.
 ///
-/// Contains values for evaluating Mul AIR constraints, including current/next state IDs,
-/// input/output values, fixed-point remainder, and LogUp multiplicities.
+/// Contains values for evaluating Mul AIR constraints, including state IDs,
+/// input/output values, and LogUp multiplicities.
 #[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
 pub struct MulTraceTableRow {
     /// ID of the current Mul node.
@@ -34,24 +36,18 @@ pub struct MulTraceTableRow {
     pub rhs_id: M31,
     /// Index within the tensor for this operation.
     pub idx: M31,
+    /// Position of the LHS value within the left-hand side input tensor.
+    pub lhs_idx: M31,
+    /// Position of the RHS value within the right-hand side input tensor.
+    pub rhs_idx: M31,
     /// Flag indicating if this is the last element processed for this node (1 if true, 0 otherwise).
     pub is_last_idx: M31,
-    /// ID of the *next* Mul node processed in the trace.
-    pub next_node_id: M31,
-    /// ID of the *next* LHS provider node.
-    pub next_lhs_id: M31,
-    /// ID of the *next* RHS provider node.
-    pub next_rhs_id: M31,
-    /// Index of the *next* element processed.
-    pub next_idx: M31,
     /// Value of the left-hand side input.
     pub lhs: M31,
     /// Value of the right-hand side input.
     pub rhs: M31,
-    /// Value of the output (`(lhs * rhs) / SCALE`).
+    /// Value of the output (`lhs * rhs`), at the sum of the input scales.
     pub out: M31,
-    /// Remainder from fixed-point multiplication (`lhs * rhs % SCALE`).
-    pub rem: M31,
     /// Multiplicity contribution for the LogUp argument (LHS input).
     pub lhs_mult: M31,
     /// Multiplicity contribution for the LogUp argument (RHS input).
@@ -68,15 +64,12 @@ impl MulTraceTableRow {
             lhs_id: M31::zero(),
             rhs_id: M31::zero(),
             idx: M31::zero(),
+            lhs_idx: M31::zero(),
+            rhs_idx: M31::zero(),
             is_last_idx: M31::one(),
-            next_node_id: M31::zero(),
-            next_lhs_id: M31::zero(),
-            next_rhs_id: M31::zero(),
-            next_idx: M31::zero(),
             lhs: M31::zero(),
             rhs: M31::zero(),
             out: M31::zero(),
-            rem: M31::zero(),
             lhs_mult: M31::zero(),
             rhs_mult: M31::zero(),
             out_mult: M31::zero(),
@@ -84,6 +77,39 @@ impl MulTraceTableRow {
     }
 }
 
+/// Implements the `StructuredRow` trait for `MulTraceTableRow`.
+impl StructuredRow for MulTraceTableRow {
+    /// IDs, indices, flags and multiplicities of the row, fixed by the graph.
+    const STRUCTURE: &'static [&'static str] = &[
+        "node_id",
+        "lhs_id",
+        "rhs_id",
+        "idx",
+        "lhs_idx",
+        "rhs_idx",
+        "is_last_idx",
+        "lhs_mult",
+        "rhs_mult",
+        "out_mult",
+    ];
+
+    /// Returns the values of the structural columns of the row.
+    fn structure(&self) -> Vec<M31> {
+        vec![
+            self.node_id,
+            self.lhs_id,
+            self.rhs_id,
+            self.idx,
+            self.lhs_idx,
+            self.rhs_idx,
+            self.is_last_idx,
+            self.lhs_mult,
+            self.rhs_mult,
+            self.out_mult,
+        ]
+    }
+}
+
 /// SIMD-packed representation of a `MulTraceTableRow`.
 #[derive(Debug, Copy, Clone)]
 pub struct PackedMulTraceTableRow {
@@ -95,24 +121,18 @@ pub struct PackedMulTraceTableRow {
     pub rhs_id: PackedM31,
     /// Packed `idx` values.
     pub idx: PackedM31,
+    /// Packed `lhs_idx` values.
+    pub lhs_idx: PackedM31,
+    /// Packed `rhs_idx` values.
+    pub rhs_idx: PackedM31,
     /// Packed `is_last_idx` values.
     pub is_last_idx: PackedM31,
-    /// Packed `next_node_id` values.
-    pub next_node_id: PackedM31,
-    /// Packed `next_lhs_id` values.
-    pub next_lhs_id: PackedM31,
-    /// Packed `next_rhs_id` values.
-    pub next_rhs_id: PackedM31,
-    /// Packed `next_idx` values.
-    pub next_idx: PackedM31,
     /// Packed `lhs` values.
     pub lhs: PackedM31,
     /// Packed `rhs` values.
     pub rhs: PackedM31,
     /// Packed `out` values.
     pub out: PackedM31,
-    /// Packed `rem` values.
-    pub rem: PackedM31,
     /// Packed `lhs_mult` values.
     pub lhs_mult: PackedM31,
     /// Packed `rhs_mult` values.
@@ -130,15 +150,12 @@ impl Pack for MulTraceTableRow {
             lhs_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs_id)),
             rhs_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs_id)),
             idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].idx)),
+            lhs_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs_idx)),
+            rhs_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs_idx)),
             is_last_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_idx)),
-            next_node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_node_id)),
-            next_lhs_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_lhs_id)),
-            next_rhs_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_rhs_id)),
-            next_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_idx)),
             lhs: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs)),
             rhs: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs)),
             out: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out)),
-            rem: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rem)),
             lhs_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lhs_mult)),
             rhs_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rhs_mult)),
             out_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_mult)),
@@ -155,15 +172,12 @@ impl Unpack for PackedMulTraceTableRow {
             lhs_id,
             rhs_id,
             idx,
+            lhs_idx,
+            rhs_idx,
             is_last_idx,
-            next_node_id,
-            next_lhs_id,
-            next_rhs_id,
-            next_idx,
             lhs,
             rhs,
             out,
-            rem,
             lhs_mult,
             rhs_mult,
             out_mult,
@@ -172,15 +186,12 @@ impl Unpack for PackedMulTraceTableRow {
             self.lhs_id.to_array(),
             self.rhs_id.to_array(),
             self.idx.to_array(),
+            self.lhs_idx.to_array(),
+            self.rhs_idx.to_array(),
             self.is_last_idx.to_array(),
-            self.next_node_id.to_array(),
-            self.next_lhs_id.to_array(),
-            self.next_rhs_id.to_array(),
-            self.next_idx.to_array(),
             self.lhs.to_array(),
             self.rhs.to_array(),
             self.out.to_array(),
-            self.rem.to_array(),
             self.lhs_mult.to_array(),
             self.rhs_mult.to_array(),
             self.out_mult.to_array(),
@@ -191,15 +202,12 @@ impl Unpack for PackedMulTraceTableRow {
             lhs_id: lhs_id[i],
             rhs_id: rhs_id[i],
             idx: idx[i],
+            lhs_idx: lhs_idx[i],
+            rhs_idx: rhs_idx[i],
             is_last_idx: is_last_idx[i],
-            next_node_id: next_node_id[i],
-            next_lhs_id: next_lhs_id[i],
-            next_rhs_id: next_rhs_id[i],
-            next_idx: next_idx[i],
             lhs: lhs[i],
             rhs: rhs[i],
             out: out[i],
-            rem: rem[i],
             lhs_mult: lhs_mult[i],
             rhs_mult: rhs_mult[i],
             out_mult: out_mult[i],
@@ -230,24 +238,18 @@ pub enum MulColumn {
     RhsId,
     /// Index within the tensor for this operation.
     Idx,
+    /// Position of the LHS value within the left-hand side input tensor.
+    LhsIdx,
+    /// Position of the RHS value within the right-hand side input tensor.
+    RhsIdx,
     /// Flag indicating if this is the last element processed for this node.
     IsLastIdx,
-    /// ID of the *next* Mul node processed in the trace.
-    NextNodeId,
-    /// ID of the *next* LHS provider node.
-    NextLhsId,
-    /// ID of the *next* RHS provider node.
-    NextRhsId,
-    /// Index of the *next* element processed.
-    NextIdx,
     /// Value of the left-hand side input.
     Lhs,
     /// Value of the right-hand side input.
     Rhs,
     /// Value of the output.
     Out,
-    /// Remainder from fixed-point multiplication.
-    Rem,
     /// Multiplicity for the LogUp argument (LHS input).
     LhsMult,
     /// Multiplicity for the LogUp argument (RHS input).
@@ -264,18 +266,15 @@ impl MulColumn {
             Self::LhsId => 1,
             Self::RhsId => 2,
             Self::Idx => 3,
-            Self::IsLastIdx => 4,
-            Self::NextNodeId => 5,
-            Self::NextLhsId => 6,
-            Self::NextRhsId => 7,
-            Self::NextIdx => 8,
-            Self::Lhs => 9,
-            Self::Rhs => 10,
-            Self::Out => 11,
-            Self::Rem => 12,
-            Self::LhsMult => 13,
-            Self::RhsMult => 14,
-            Self::OutMult => 15,
+            Self::LhsIdx => 4,
+            Self::RhsIdx => 5,
+            Self::IsLastIdx => 6,
+            Self::Lhs => 7,
+            Self::Rhs => 8,
+            Self::Out => 9,
+            Self::LhsMult => 10,
+            Self::RhsMult => 11,
+            Self::OutMult => 12,
         }
     }
 }
@@ -283,8 +282,9 @@ impl MulColumn {
 /// Implements the `TraceColumn` trait for `MulColumn`.
 impl TraceColumn for MulColumn {
     /// Specifies the number of columns used by the Mul component.
-    /// Returns `(16, 3)`, indicating 16 main trace columns and 3 interaction trace columns.
+    /// Returns `(N_TRACE_COLUMNS, 3)`, indicating the number of main trace columns
+    /// and the number of interaction trace columns (for LogUp).
     fn count() -> (usize, usize) {
-        (16, 3)
+        (N_TRACE_COLUMNS, 3)
     }
 }
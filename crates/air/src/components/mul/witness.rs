@@ -1,6 +1,6 @@
 use crate::{
     components::{InteractionClaim, MulClaim, NodeElements},
-    utils::{pack_values, TreeBuilder},
+    utils::{pack_values, to_trace_order, TreeBuilder},
 };
 use luminair_utils::TraceError;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
@@ -18,12 +18,12 @@ use stwo_prover::{
 use super::table::{MulColumn, MulTraceTable, MulTraceTableRow, PackedMulTraceTableRow};
 
 /// Number of main trace columns for the Mul component.
-pub(crate) const N_TRACE_COLUMNS: usize = 16;
+pub(crate) const N_TRACE_COLUMNS: usize = 13;
 
 /// Generates the main trace columns and initial data for interaction claims for the Mul component.
 ///
-/// Takes the raw `MulTraceTable`, processes it into the main STARK trace columns
-/// (including the fixed-point remainder `rem`), and prepares `LookupData` for LogUp.
+/// Takes the raw `MulTraceTable`, processes it into the main STARK trace columns,
+/// and prepares `LookupData` for LogUp.
 pub struct ClaimGenerator {
     /// The raw trace data for Mul operations.
     pub inputs: MulTraceTable,
@@ -55,7 +55,7 @@ impl ClaimGenerator {
         let log_size = size.ilog2();
 
         self.inputs.table.resize(size, MulTraceTableRow::padding());
-        let packed_inputs = pack_values(&self.inputs.table);
+        let packed_inputs = pack_values(&to_trace_order(&self.inputs.table));
 
         let (trace, lookup_data) = write_trace_simd(packed_inputs);
 
@@ -74,8 +74,8 @@ impl ClaimGenerator {
 /// Populates the main trace columns and `LookupData` from SIMD-packed Mul trace rows.
 ///
 /// Processes `PackedMulTraceTableRow` data in parallel:
-/// - Maps fields (including `rem`) to the corresponding main trace columns.
-/// - Extracts `[value, id]` pairs and multiplicities into `LookupData` for the LogUp argument
+/// - Maps fields to the corresponding main trace columns.
+/// - Extracts `[id, idx, value]` triples and multiplicities into `LookupData` for the LogUp argument
 ///   (LHS, RHS, OUT).
 /// Returns the `ComponentTrace` (main trace columns) and `LookupData`.
 fn write_trace_simd(
@@ -102,24 +102,21 @@ fn write_trace_simd(
             *row[MulColumn::LhsId.index()] = input.lhs_id;
             *row[MulColumn::RhsId.index()] = input.rhs_id;
             *row[MulColumn::Idx.index()] = input.idx;
+            *row[MulColumn::LhsIdx.index()] = input.lhs_idx;
+            *row[MulColumn::RhsIdx.index()] = input.rhs_idx;
             *row[MulColumn::IsLastIdx.index()] = input.is_last_idx;
-            *row[MulColumn::NextNodeId.index()] = input.next_node_id;
-            *row[MulColumn::NextLhsId.index()] = input.next_lhs_id;
-            *row[MulColumn::NextRhsId.index()] = input.next_rhs_id;
-            *row[MulColumn::NextIdx.index()] = input.next_idx;
             *row[MulColumn::Lhs.index()] = input.lhs;
             *row[MulColumn::Rhs.index()] = input.rhs;
             *row[MulColumn::Out.index()] = input.out;
-            *row[MulColumn::Rem.index()] = input.rem;
             *row[MulColumn::LhsMult.index()] = input.lhs_mult;
             *row[MulColumn::RhsMult.index()] = input.rhs_mult;
             *row[MulColumn::OutMult.index()] = input.out_mult;
 
-            *lookup_data.lhs = [input.lhs, input.lhs_id];
+            *lookup_data.lhs = [input.lhs_id, input.lhs_idx, input.lhs];
             *lookup_data.lhs_mult = input.lhs_mult;
-            *lookup_data.rhs = [input.rhs, input.rhs_id];
+            *lookup_data.rhs = [input.rhs_id, input.rhs_idx, input.rhs];
             *lookup_data.rhs_mult = input.rhs_mult;
-            *lookup_data.out = [input.out, input.node_id];
+            *lookup_data.out = [input.node_id, input.idx, input.out];
             *lookup_data.out_mult = input.out_mult;
         });
 
@@ -129,20 +126,20 @@ fn write_trace_simd(
 /// Intermediate data structure holding values and multiplicities for the Mul LogUp argument.
 ///
 /// Structure and purpose are identical to the `LookupData` in the Add component,
-/// storing value-ID pairs and multiplicities for LHS, RHS, and OUT terms.
+/// storing ID-index-value triples and multiplicities for LHS, RHS, and OUT terms.
 /// Derives helper iterators for parallel processing.
 #[derive(Uninitialized, IterMut, ParIterMut)]
 struct LookupData {
-    /// LHS value-ID pairs: `[lhs_value, lhs_node_id]`.
-    lhs: Vec<[PackedM31; 2]>,
+    /// LHS ID-index-value triples: `[lhs_node_id, lhs_idx, lhs_value]`.
+    lhs: Vec<[PackedM31; 3]>,
     /// Multiplicities for LHS values.
     lhs_mult: Vec<PackedM31>,
-    /// RHS value-ID pairs: `[rhs_value, rhs_node_id]`.
-    rhs: Vec<[PackedM31; 2]>,
+    /// RHS ID-index-value triples: `[rhs_node_id, rhs_idx, rhs_value]`.
+    rhs: Vec<[PackedM31; 3]>,
     /// Multiplicities for RHS values.
     rhs_mult: Vec<PackedM31>,
-    /// Output value-ID pairs: `[out_value, mul_node_id]`.
-    out: Vec<[PackedM31; 2]>,
+    /// Output ID-index-value triples: `[mul_node_id, idx, out_value]`.
+    out: Vec<[PackedM31; 3]>,
     /// Multiplicities for output values.
     out_mult: Vec<PackedM31>,
 }
@@ -150,12 +147,12 @@ struct LookupData {
 /// Generates the interaction trace columns for the Mul component's LogUp argument.
 ///
 /// Structure and purpose are identical to the `InteractionClaimGenerator` in the Add component.
-/// It takes `LookupData` and `NodeElements` to build the three LogUp interaction columns
-/// (LHS, RHS, OUT) and adds them to the `tree_builder`.
+/// It takes `LookupData` and `NodeElements` to build the three LogUp interaction
+/// columns (LHS, RHS, OUT) and adds them to the `tree_builder`.
 pub struct InteractionClaimGenerator {
     /// Log2 size of the trace.
     log_size: u32,
-    /// Data (value-ID pairs and multiplicities) needed for LogUp.
+    /// Data (ID-index-value triples and multiplicities) needed for LogUp.
     lookup_data: LookupData,
 }
 
@@ -165,7 +162,7 @@ impl InteractionClaimGenerator {
     /// The logic is identical to the Add component's implementation:
     /// - Creates a `LogupTraceGenerator`.
     /// - Generates three columns (LHS, RHS, OUT), writing `multiplicity / denom` fractions,
-    ///   where `denom` is derived from `[value, id]` and `NodeElements`.
+    ///   where `denom` is derived from `[id, idx, value]` and `NodeElements`.
     /// - Finalizes the generator, obtaining interaction trace columns and the `claimed_sum`.
     /// - Adds interaction columns to the `tree_builder`.
     /// - Returns the `InteractionClaim` containing the `claimed_sum`.
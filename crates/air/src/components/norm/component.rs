@@ -0,0 +1,423 @@
+use crate::{
+    components::{
+        lookups::range_check::{component::eval_signed_limbs, RangeCheckLookupElements},
+        NodeElements, NormClaim,
+    },
+    preprocessed::eval_structure,
+};
+use num_traits::One;
+use stwo_prover::{
+    constraint_framework::{
+        EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry, ORIGINAL_TRACE_IDX,
+    },
+    core::fields::m31::M31,
+};
+
+use super::table::{quotient_bits, NormTraceTableRow};
+
+/// The STWO AIR component for row-wise LayerNorm and RMSNorm operations.
+/// Wraps the `NormEval` logic within the STWO `FrameworkComponent`.
+/// The remainders and quotients of the normalization are proven through the shared range-check
+/// lookup.
+pub type NormComponent = FrameworkComponent<NormEval>;
+
+/// Defines the AIR constraints evaluation logic for the Norm component.
+/// Implements `FrameworkEval` to define trace layout, degrees, and constraints.
+///
+/// Each reduction along the last dimension spans consecutive rows, one per input `x`.
+/// The output is `out = floor(norm * w / 2^s) + b`, with `norm = floor((x - mean) * inv_std / 2^s)`
+/// and `inv_std = floor(2^(2s) / sqrt((var + eps) * 2^s))` the single inverse standard
+/// deviation of the reduction. Without centering (RMSNorm), the mean is zero.
+///
+/// The divisions are checked in the field, where they wrap around modulo P. The mean, variance,
+/// inverse, normalized input and output are bounded through their sign and limbs, see
+/// `quotient_bits`.
+pub struct NormEval {
+    /// Log2 size of the component's main trace segment.
+    log_size: u32,
+    /// Log2 size of the preprocessed range-check table.
+    lut_log_size: u32,
+    /// Interaction elements for node relations (used in input/output LogUp).
+    node_elements: NodeElements,
+    /// Interaction elements for the shared range-check lookup.
+    range_check_elements: RangeCheckLookupElements,
+    /// Fixed-point scale of the circuit.
+    fp_scale: u32,
+}
+
+impl NormEval {
+    /// Creates a new `NormEval` instance.
+    /// Takes the component's claim (for `log_size`), interaction elements for nodes and
+    /// the range check, the log_size of the range-check table and the fixed-point scale.
+    pub fn new(
+        claim: &NormClaim,
+        node_elements: NodeElements,
+        range_check_elements: RangeCheckLookupElements,
+        lut_log_size: u32,
+        fp_scale: u32,
+    ) -> Self {
+        Self {
+            log_size: claim.log_size,
+            lut_log_size,
+            node_elements,
+            range_check_elements,
+            fp_scale,
+        }
+    }
+}
+
+/// Implements the core constraint evaluation logic for the Norm component.
+impl FrameworkEval for NormEval {
+    /// Returns the log2 size of this component's main trace segment.
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// Returns the max log2 degree bound, considering both main trace and range-check table sizes.
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        std::cmp::max(self.log_size, self.lut_log_size) + 1
+    }
+
+    /// Evaluates the Norm AIR constraints on a given evaluation point (`eval`).
+    ///
+    /// Defines constraints for:
+    /// - **Structural:** The IDs, indices, flags and multiplicities match the preprocessed trace.
+    /// - **Mean:** The inputs are summed into `total = mean * count + mean_rem`, and centered as
+    ///   `centered = x - mean`. Without centering, the mean and its remainder are zero.
+    /// - **Variance:** The squared centered inputs are summed into
+    ///   `sq_total = var * divisor + var_rem`, with `divisor = count * scale`.
+    /// - **Normalization:**
+    ///   - Standard deviation: `std^2 + std_rem = (var + eps) * scale`.
+    ///   - Inverse: `inv_std * std + inv_std_rem = scale^2`.
+    ///   - Normalized input: `norm * scale + norm_rem = centered * inv_std`.
+    ///   - Output: `out * scale + out_rem = norm * w + b * scale`, with `w = scale` without
+    ///     a weight and `b = 0` without a bias.
+    ///   - Rows emitting their output use the circuit scale.
+    /// - **Transition:**
+    ///   - Within a reduction, `count`, `total`, `mean` and `sq_total` are constant, and the
+    ///     running sums and step carry over. They restart from 0 once the reduction ends,
+    ///     after `count` steps.
+    ///   - When `is_last_idx` is false, node and tensor IDs, flags, epsilon and scale remain
+    ///     the same, and the index increments by 1.
+    /// - **Interaction (LogUp):** Links the input, weight, bias and output values to the global
+    ///   LogUp argument. The remainders are sent to the shared range-check lookup along with
+    ///   their gaps to their bounds: `mean_rem` in `[0, count)`, `var_rem` in `[0, divisor)`,
+    ///   `std_rem` in `[0, 2 * std]`, `inv_std_rem` in `[0, std)`, and `norm_rem` and
+    ///   `out_rem` in `[0, scale)`, on every row emitting its output. The limbs of the
+    ///   magnitudes of `mean`, `var`, `inv_std`, `norm` and `out` are range-checked in the
+    ///   same way, see `quotient_bits`.
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        // IDs (current and next row, for transition constraints)
+        // ID of the node in the computational graph.
+        let [node_id, next_node_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of the input tensor.
+        let [input_id, next_input_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of the weight tensor.
+        let [weight_id, next_weight_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of the bias tensor.
+        let [bias_id, next_bias_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Index in the flattened tensor.
+        let [idx, next_idx] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Positions of the input, weight and bias values within their tensors.
+        let input_idx = eval.next_trace_mask();
+        let weight_idx = eval.next_trace_mask();
+        let bias_idx = eval.next_trace_mask();
+        let is_last_idx = eval.next_trace_mask(); // Flag if this is the last index for this operation.
+
+        // Values for consistency constraints
+        let input_val = eval.next_trace_mask(); // Value from the input tensor at index.
+        let weight_val = eval.next_trace_mask(); // Value from the weight tensor at index.
+        let bias_val = eval.next_trace_mask(); // Value from the bias tensor at index.
+        let out_val = eval.next_trace_mask(); // Value in output tensor at index.
+
+        // Kind of normalization, the same for every row of the node.
+        let [is_centered, next_is_centered] =
+            eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        let [has_weight, next_has_weight] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        let [has_bias, next_has_bias] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        let [eps, next_eps] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+
+        // Position of the input within its reduction, and the one of the next row.
+        let [step, next_row_step] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Number of inputs of the reduction, and the one of the next row.
+        let [count, next_row_count] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        let is_last_step = eval.next_trace_mask(); // Flag if this is the last step.
+
+        // Sum of the inputs before this row, and the one of the next row.
+        let [sum, next_row_sum] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        let next_sum = eval.next_trace_mask(); // Sum of the inputs after this row.
+
+        // Sum of all the inputs of the reduction, and the one of the next row.
+        let [total, next_row_total] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Mean of the inputs, and the one of the next row.
+        let [mean, next_row_mean] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        let mean_rem = eval.next_trace_mask(); // Remainder of the mean.
+        let centered = eval.next_trace_mask(); // Input minus the mean.
+
+        // Sum of the squared centered inputs before this row, and the one of the next row.
+        let [sq_sum, next_row_sq_sum] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        let next_sq_sum = eval.next_trace_mask(); // Sum of the squares after this row.
+
+        // Sum of all the squared centered inputs of the reduction, and the one of the next row.
+        let [sq_total, next_row_sq_total] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        let divisor = eval.next_trace_mask(); // Divisor of the variance.
+        let var = eval.next_trace_mask(); // Variance of the inputs.
+        let var_rem = eval.next_trace_mask(); // Remainder of the variance.
+
+        // Normalization
+        let std = eval.next_trace_mask(); // Standard deviation.
+        let std_rem = eval.next_trace_mask(); // Remainder of the standard deviation.
+        let inv_std = eval.next_trace_mask(); // Inverse of the standard deviation.
+        let inv_std_rem = eval.next_trace_mask(); // Remainder of the inverse.
+        let norm = eval.next_trace_mask(); // Normalized input.
+        let norm_rem = eval.next_trace_mask(); // Remainder of the normalized input.
+        let out_rem = eval.next_trace_mask(); // Remainder of the output.
+        let [scale, next_scale] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+
+        // Sign of mean, and limbs of |mean|.
+        let mean_sign = eval.next_trace_mask();
+        let mean_limbs: [E::F; 2] = std::array::from_fn(|_| eval.next_trace_mask());
+        // Sign of var, and limbs of |var|.
+        let var_sign = eval.next_trace_mask();
+        let var_limbs: [E::F; 2] = std::array::from_fn(|_| eval.next_trace_mask());
+        // Sign of inv_std, and limbs of |inv_std|.
+        let inv_std_sign = eval.next_trace_mask();
+        let inv_std_limbs: [E::F; 2] = std::array::from_fn(|_| eval.next_trace_mask());
+        // Sign of norm, and limbs of |norm|.
+        let norm_sign = eval.next_trace_mask();
+        let norm_limbs: [E::F; 2] = std::array::from_fn(|_| eval.next_trace_mask());
+        // Sign of out, and limbs of |out|.
+        let out_sign = eval.next_trace_mask();
+        let out_limbs: [E::F; 2] = std::array::from_fn(|_| eval.next_trace_mask());
+
+        // Multiplicities for interaction constraints
+        let input_mult = eval.next_trace_mask();
+        let weight_mult = eval.next_trace_mask();
+        let bias_mult = eval.next_trace_mask();
+        let out_mult = eval.next_trace_mask();
+        let range_check_mult = eval.next_trace_mask();
+
+        // ┌─────────────────────────────┐
+        // │   Structural Constraints    │
+        // └─────────────────────────────┘
+
+        // The IDs, indices, flags and multiplicities are the ones fixed by the graph.
+        eval_structure::<_, NormTraceTableRow>(
+            &mut eval,
+            "norm",
+            vec![
+                node_id.clone(),
+                input_id.clone(),
+                weight_id.clone(),
+                bias_id.clone(),
+                idx.clone(),
+                input_idx.clone(),
+                weight_idx.clone(),
+                bias_idx.clone(),
+                is_last_idx.clone(),
+                is_centered.clone(),
+                has_weight.clone(),
+                has_bias.clone(),
+                eps.clone(),
+                count.clone(),
+                is_last_step.clone(),
+                input_mult.clone(),
+                weight_mult.clone(),
+                bias_mult.clone(),
+                out_mult.clone(),
+            ],
+        );
+
+        // ┌─────────────────────────────┐
+        // │   Consistency Constraints   │
+        // └─────────────────────────────┘
+
+        // The flags are either 0 or 1.
+        for flag in [
+            is_last_idx.clone(),
+            is_last_step.clone(),
+            is_centered.clone(),
+            has_weight.clone(),
+            has_bias.clone(),
+        ] {
+            eval.add_constraint(flag.clone() * (flag - E::F::one()));
+        }
+
+        // The inputs are summed, and the last sum of the reduction is its total.
+        eval.add_constraint(next_sum.clone() - (sum + input_val.clone()));
+        eval.add_constraint(is_last_step.clone() * (next_sum.clone() - total.clone()));
+
+        // The mean is rounded down: mean * count + mean_rem = total.
+        // It is zero without centering, and so is its remainder.
+        eval.add_constraint(
+            mean.clone() * count.clone() + mean_rem.clone() - is_centered.clone() * total.clone(),
+        );
+        eval.add_constraint((E::F::one() - is_centered.clone()) * mean.clone());
+        eval.add_constraint(centered.clone() - (input_val.clone() - mean.clone()));
+
+        // The squared centered inputs are summed, and the last sum of the reduction is its total.
+        eval.add_constraint(next_sq_sum.clone() - (sq_sum + centered.clone() * centered.clone()));
+        eval.add_constraint(is_last_step.clone() * (next_sq_sum.clone() - sq_total.clone()));
+
+        // The variance is rounded down: var * count * scale + var_rem = sq_total.
+        eval.add_constraint(divisor.clone() - count.clone() * scale.clone());
+        eval.add_constraint(var.clone() * divisor.clone() + var_rem.clone() - sq_total.clone());
+
+        // Rows emitting their output use the circuit scale.
+        // Padding rows emit nothing and hold a zero scale.
+        let circuit_scale = E::F::from(M31::from_u32_unchecked(1 << self.fp_scale));
+        eval.add_constraint(out_mult.clone() * (scale.clone() - circuit_scale));
+
+        // The range-check multiplicity is boolean, and set on every row emitting its output.
+        eval.add_constraint(range_check_mult.clone() * (range_check_mult.clone() - E::F::one()));
+        eval.add_constraint(out_mult.clone() * (E::F::one() - range_check_mult.clone()));
+
+        // The standard deviation is rounded down: std^2 + std_rem = (var + eps) * scale.
+        eval.add_constraint(
+            std.clone() * std.clone() + std_rem.clone()
+                - (var.clone() + eps.clone()) * scale.clone(),
+        );
+
+        // The inverse is rounded down: inv_std * std + inv_std_rem = scale^2.
+        eval.add_constraint(
+            inv_std.clone() * std.clone() + inv_std_rem.clone() - scale.clone() * scale.clone(),
+        );
+
+        // The normalized input is rounded down: norm * scale + norm_rem = centered * inv_std.
+        eval.add_constraint(
+            norm.clone() * scale.clone() + norm_rem.clone() - centered * inv_std.clone(),
+        );
+
+        // Without a weight the output is multiplied by one, and without a bias nothing is added.
+        eval.add_constraint(
+            (E::F::one() - has_weight.clone()) * (weight_val.clone() - scale.clone()),
+        );
+        eval.add_constraint((E::F::one() - has_bias.clone()) * bias_val.clone());
+
+        // The output is rounded down: out * scale + out_rem = norm * w + b * scale.
+        eval.add_constraint(
+            out_val.clone() * scale.clone() + out_rem.clone()
+                - (norm.clone() * weight_val.clone() + bias_val.clone() * scale.clone()),
+        );
+
+        // The reduction ends after count steps.
+        eval.add_constraint(is_last_step.clone() * (step.clone() + E::F::one() - count.clone()));
+
+        // ┌────────────────────────────┐
+        // │   Transition Constraints   │
+        // └────────────────────────────┘
+
+        // Within a reduction, the count, the totals and the mean are the same on every row, and
+        // the running sums and step carry over. They restart from 0 after the last step.
+        let not_last_step = E::F::one() - is_last_step;
+        eval.add_constraint(not_last_step.clone() * (next_row_count - count.clone()));
+        eval.add_constraint(not_last_step.clone() * (next_row_total - total));
+        eval.add_constraint(not_last_step.clone() * (next_row_mean - mean.clone()));
+        eval.add_constraint(not_last_step.clone() * (next_row_sq_total - sq_total));
+        eval.add_constraint(next_row_sum - not_last_step.clone() * next_sum);
+        eval.add_constraint(next_row_sq_sum - not_last_step.clone() * next_sq_sum);
+        eval.add_constraint(next_row_step - not_last_step * (step + E::F::one()));
+
+        // If this is not the last index for this operation, then:
+        // 1. The next row should be for the same operation on the same tensors, with the same
+        //    kind of normalization, epsilon and scale.
+        // 2. The index should increment by 1.
+        let not_last = E::F::one() - is_last_idx;
+
+        // Same node ID
+        eval.add_constraint(not_last.clone() * (next_node_id - node_id.clone()));
+
+        // Same tensor IDs
+        eval.add_constraint(not_last.clone() * (next_input_id - input_id.clone()));
+        eval.add_constraint(not_last.clone() * (next_weight_id - weight_id.clone()));
+        eval.add_constraint(not_last.clone() * (next_bias_id - bias_id.clone()));
+
+        // Same kind of normalization, epsilon and scale
+        eval.add_constraint(not_last.clone() * (next_is_centered - is_centered));
+        eval.add_constraint(not_last.clone() * (next_has_weight - has_weight));
+        eval.add_constraint(not_last.clone() * (next_has_bias - has_bias));
+        eval.add_constraint(not_last.clone() * (next_eps - eps));
+        eval.add_constraint(not_last.clone() * (next_scale - scale.clone()));
+
+        // Index increment by 1
+        eval.add_constraint(not_last * (next_idx - idx.clone() - E::F::one()));
+
+        // ┌─────────────────────────────┐
+        // │   Interaction Constraints   │
+        // └─────────────────────────────┘
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            input_mult.into(),
+            &[input_id, input_idx, input_val],
+        ));
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            weight_mult.into(),
+            &[weight_id, weight_idx, weight_val],
+        ));
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            bias_mult.into(),
+            &[bias_id, bias_idx, bias_val],
+        ));
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            out_mult.into(),
+            &[node_id, idx, out_val.clone()],
+        ));
+
+        // Both each remainder and its gap to its bound are non-negative, hence the remainder
+        // lies in its range.
+        for value in [
+            mean_rem.clone(),
+            count - E::F::one() - mean_rem,
+            var_rem.clone(),
+            divisor - E::F::one() - var_rem,
+            std_rem.clone(),
+            std.clone() + std.clone() - std_rem,
+            inv_std_rem.clone(),
+            std - E::F::one() - inv_std_rem,
+            norm_rem.clone(),
+            scale.clone() - E::F::one() - norm_rem,
+            out_rem.clone(),
+            scale - E::F::one() - out_rem,
+        ] {
+            eval.add_to_relation(RelationEntry::new(
+                &self.range_check_elements,
+                range_check_mult.clone().into(),
+                &[value],
+            ));
+        }
+
+        // The quotients are bounded, so that the divisions have no other solution modulo P
+        // within their ranges.
+        for ((value, sign, limbs), bits) in [
+            (mean, mean_sign, mean_limbs),
+            (var, var_sign, var_limbs),
+            (inv_std, inv_std_sign, inv_std_limbs),
+            (norm, norm_sign, norm_limbs),
+            (out_val, out_sign, out_limbs),
+        ]
+        .into_iter()
+        .zip(quotient_bits(self.fp_scale))
+        {
+            eval_signed_limbs(
+                &mut eval,
+                &self.range_check_elements,
+                &range_check_mult,
+                value,
+                sign,
+                limbs,
+                bits,
+            );
+        }
+
+        eval.finalize_logup();
+
+        eval
+    }
+}
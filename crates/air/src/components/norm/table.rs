@@ -0,0 +1,845 @@
+use num_traits::{One, Zero};
+use serde::{Deserialize, Serialize};
+use stwo_prover::core::{
+    backend::simd::{
+        conversion::{Pack, Unpack},
+        m31::{PackedM31, N_LANES},
+    },
+    fields::m31::M31,
+};
+
+use crate::{
+    components::TraceColumn, fixed_point::M31_MAGNITUDE_BITS, preprocessed::StructuredRow,
+};
+
+use super::witness::{N_RANGE_CHECKS, N_TRACE_COLUMNS};
+
+/// Returns the number of range-checked bits of the magnitudes of the mean, the variance,
+/// the inverse standard deviation, the normalized input and the output, at the scale `fp_scale`.
+///
+/// The mean lies within the inputs, and the variance within the sum of their squares over
+/// `2^s`. The inverse `SCALE^2 / std` is the largest for the smallest standard deviation, at
+/// `SCALE^2`. The normalized input and the output are rounded down divisions by `2^s`,
+/// bounded to `2^(30 - s)` as Rescale outputs, so that they have a single solution modulo P.
+pub const fn quotient_bits(fp_scale: u32) -> [u32; 5] {
+    [
+        M31_MAGNITUDE_BITS,
+        M31_MAGNITUDE_BITS - fp_scale,
+        2 * fp_scale + 1,
+        M31_MAGNITUDE_BITS - fp_scale,
+        M31_MAGNITUDE_BITS - fp_scale,
+    ]
+}
+
+/// Represents the raw trace data collected for Norm operations.
+///
+/// Stores one row per input element, grouped by reduction along the last dimension.
+/// Each row carries the mean, the variance and the inverse standard deviation of its
+/// reduction, so that a whole LayerNorm or RMSNorm, along with its optional affine weight and
+/// bias, is proven in a single trace.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct NormTraceTable {
+    /// Vector containing all rows of the Norm trace.
+    pub table: Vec<NormTraceTableRow>,
+}
+
+/// Represents a single row in the `NormTraceTable`.
+///
+/// Contains values for evaluating the Norm AIR constraints: state IDs, input/weight/bias/output
+/// values, the running sums of the inputs and of their squared centered values, the mean,
+/// variance, standard deviation and its inverse with their remainders, the signs and limbs of
+/// the quotients, and multiplicities for LogUp (input/weight/bias/output) and range checks.
+#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
+pub struct NormTraceTableRow {
+    /// ID of the current Norm node.
+    pub node_id: M31,
+    /// ID of the node providing the input.
+    pub input_id: M31,
+    /// ID of the node providing the weight, zero without one.
+    pub weight_id: M31,
+    /// ID of the node providing the bias, zero without one.
+    pub bias_id: M31,
+    /// Index within the tensor for this operation.
+    pub idx: M31,
+    /// Position of the input value within the input tensor.
+    pub input_idx: M31,
+    /// Position of the weight value within the weight tensor.
+    pub weight_idx: M31,
+    /// Position of the bias value within the bias tensor.
+    pub bias_idx: M31,
+    /// Flag indicating if this is the last element processed for this node (1 if true, 0 otherwise).
+    pub is_last_idx: M31,
+    /// Value of the input (`x`).
+    pub input: M31,
+    /// Value of the weight (`w`), `2^scale` without one.
+    pub weight: M31,
+    /// Value of the bias (`b`), zero without one.
+    pub bias: M31,
+    /// Value of the output (`norm * w + b`).
+    pub out: M31,
+    /// Flag indicating if the mean is subtracted from the inputs (LayerNorm), or not (RMSNorm).
+    pub is_centered: M31,
+    /// Flag indicating if the output is multiplied by a weight.
+    pub has_weight: M31,
+    /// Flag indicating if a bias is added to the output.
+    pub has_bias: M31,
+    /// Epsilon added to the variance, at the fixed-point scale.
+    pub eps: M31,
+    /// Position of the input within its reduction.
+    pub step: M31,
+    /// Number of inputs of the reduction.
+    pub count: M31,
+    /// Flag indicating if this is the last input of the reduction (1 if true, 0 otherwise).
+    pub is_last_step: M31,
+    /// Sum of the inputs of the reduction *before* this row.
+    pub sum: M31,
+    /// Sum of the inputs of the reduction *after* this row (`sum + x`).
+    pub next_sum: M31,
+    /// Sum of all the inputs of the reduction.
+    pub total: M31,
+    /// Mean of the inputs, `total / count` rounded down, or zero without centering.
+    pub mean: M31,
+    /// Remainder of the mean, `total - mean * count`, or zero without centering.
+    pub mean_rem: M31,
+    /// Centered input, `x - mean`.
+    pub centered: M31,
+    /// Sum of the squared centered inputs *before* this row.
+    pub sq_sum: M31,
+    /// Sum of the squared centered inputs *after* this row (`sq_sum + centered^2`).
+    pub next_sq_sum: M31,
+    /// Sum of all the squared centered inputs of the reduction.
+    pub sq_total: M31,
+    /// Divisor of the variance, `count * 2^scale`.
+    pub divisor: M31,
+    /// Variance, `sq_total / divisor` rounded down.
+    pub var: M31,
+    /// Remainder of the variance, `sq_total - var * divisor`.
+    pub var_rem: M31,
+    /// Standard deviation, `sqrt((var + eps) * 2^scale)` rounded down.
+    pub std: M31,
+    /// Remainder of the standard deviation, `(var + eps) * 2^scale - std^2`.
+    pub std_rem: M31,
+    /// Inverse of the standard deviation, `2^(2 * scale) / std` rounded down.
+    pub inv_std: M31,
+    /// Remainder of the inverse, `2^(2 * scale) - inv_std * std`.
+    pub inv_std_rem: M31,
+    /// Normalized input, `centered * inv_std / 2^scale` rounded down.
+    pub norm: M31,
+    /// Remainder of the normalized input, `centered * inv_std - norm * 2^scale`.
+    pub norm_rem: M31,
+    /// Remainder of the output, `norm * w + b * 2^scale - out * 2^scale`.
+    pub out_rem: M31,
+    /// Fixed-point scale factor, `2^scale`.
+    pub scale: M31,
+    /// Sign of the mean (1 if negative, 0 otherwise).
+    pub mean_sign: M31,
+    /// Low and high limbs of the magnitude of the mean.
+    pub mean_limbs: [M31; 2],
+    /// Sign of the variance (1 if negative, 0 otherwise).
+    pub var_sign: M31,
+    /// Low and high limbs of the magnitude of the variance.
+    pub var_limbs: [M31; 2],
+    /// Sign of the inverse standard deviation (1 if negative, 0 otherwise).
+    pub inv_std_sign: M31,
+    /// Low and high limbs of the magnitude of the inverse standard deviation.
+    pub inv_std_limbs: [M31; 2],
+    /// Sign of the normalized input (1 if negative, 0 otherwise).
+    pub norm_sign: M31,
+    /// Low and high limbs of the magnitude of the normalized input.
+    pub norm_limbs: [M31; 2],
+    /// Sign of the output (1 if negative, 0 otherwise).
+    pub out_sign: M31,
+    /// Low and high limbs of the magnitude of the output.
+    pub out_limbs: [M31; 2],
+    /// Multiplicity contribution for the LogUp argument (input).
+    pub input_mult: M31,
+    /// Multiplicity contribution for the LogUp argument (weight).
+    pub weight_mult: M31,
+    /// Multiplicity contribution for the LogUp argument (bias).
+    pub bias_mult: M31,
+    /// Multiplicity contribution for the LogUp argument (output).
+    pub out_mult: M31,
+    /// Multiplicity contribution for the range checks.
+    pub range_check_mult: M31,
+}
+
+impl NormTraceTableRow {
+    /// Creates a padding row for the Norm trace.
+    ///
+    /// Padding rows end their own reduction, of a single input.
+    pub(crate) fn padding() -> Self {
+        Self {
+            node_id: M31::zero(),
+            input_id: M31::zero(),
+            weight_id: M31::zero(),
+            bias_id: M31::zero(),
+            idx: M31::zero(),
+            input_idx: M31::zero(),
+            weight_idx: M31::zero(),
+            bias_idx: M31::zero(),
+            is_last_idx: M31::one(),
+            input: M31::zero(),
+            weight: M31::zero(),
+            bias: M31::zero(),
+            out: M31::zero(),
+            is_centered: M31::zero(),
+            has_weight: M31::zero(),
+            has_bias: M31::zero(),
+            eps: M31::zero(),
+            step: M31::zero(),
+            count: M31::one(),
+            is_last_step: M31::one(),
+            sum: M31::zero(),
+            next_sum: M31::zero(),
+            total: M31::zero(),
+            mean: M31::zero(),
+            mean_rem: M31::zero(),
+            centered: M31::zero(),
+            sq_sum: M31::zero(),
+            next_sq_sum: M31::zero(),
+            sq_total: M31::zero(),
+            divisor: M31::zero(),
+            var: M31::zero(),
+            var_rem: M31::zero(),
+            std: M31::zero(),
+            std_rem: M31::zero(),
+            inv_std: M31::zero(),
+            inv_std_rem: M31::zero(),
+            norm: M31::zero(),
+            norm_rem: M31::zero(),
+            out_rem: M31::zero(),
+            scale: M31::zero(),
+            mean_sign: M31::zero(),
+            mean_limbs: [M31::zero(); 2],
+            var_sign: M31::zero(),
+            var_limbs: [M31::zero(); 2],
+            inv_std_sign: M31::zero(),
+            inv_std_limbs: [M31::zero(); 2],
+            norm_sign: M31::zero(),
+            norm_limbs: [M31::zero(); 2],
+            out_sign: M31::zero(),
+            out_limbs: [M31::zero(); 2],
+            input_mult: M31::zero(),
+            weight_mult: M31::zero(),
+            bias_mult: M31::zero(),
+            out_mult: M31::zero(),
+            range_check_mult: M31::zero(),
+        }
+    }
+}
+
+/// Implements the `StructuredRow` trait for `NormTraceTableRow`.
+impl StructuredRow for NormTraceTableRow {
+    /// IDs, indices, flags and multiplicities of the row, fixed by the graph.
+    const STRUCTURE: &'static [&'static str] = &[
+        "node_id",
+        "input_id",
+        "weight_id",
+        "bias_id",
+        "idx",
+        "input_idx",
+        "weight_idx",
+        "bias_idx",
+        "is_last_idx",
+        "is_centered",
+        "has_weight",
+        "has_bias",
+        "eps",
+        "count",
+        "is_last_step",
+        "input_mult",
+        "weight_mult",
+        "bias_mult",
+        "out_mult",
+    ];
+
+    /// Returns the values of the structural columns of the row.
+    fn structure(&self) -> Vec<M31> {
+        vec![
+            self.node_id,
+            self.input_id,
+            self.weight_id,
+            self.bias_id,
+            self.idx,
+            self.input_idx,
+            self.weight_idx,
+            self.bias_idx,
+            self.is_last_idx,
+            self.is_centered,
+            self.has_weight,
+            self.has_bias,
+            self.eps,
+            self.count,
+            self.is_last_step,
+            self.input_mult,
+            self.weight_mult,
+            self.bias_mult,
+            self.out_mult,
+        ]
+    }
+}
+
+/// SIMD-packed representation of a `NormTraceTableRow`.
+#[derive(Debug, Copy, Clone)]
+pub struct PackedNormTraceTableRow {
+    /// Packed `node_id` values.
+    pub node_id: PackedM31,
+    /// Packed `input_id` values.
+    pub input_id: PackedM31,
+    /// Packed `weight_id` values.
+    pub weight_id: PackedM31,
+    /// Packed `bias_id` values.
+    pub bias_id: PackedM31,
+    /// Packed `idx` values.
+    pub idx: PackedM31,
+    /// Packed `input_idx` values.
+    pub input_idx: PackedM31,
+    /// Packed `weight_idx` values.
+    pub weight_idx: PackedM31,
+    /// Packed `bias_idx` values.
+    pub bias_idx: PackedM31,
+    /// Packed `is_last_idx` values.
+    pub is_last_idx: PackedM31,
+    /// Packed `input` values.
+    pub input: PackedM31,
+    /// Packed `weight` values.
+    pub weight: PackedM31,
+    /// Packed `bias` values.
+    pub bias: PackedM31,
+    /// Packed `out` values.
+    pub out: PackedM31,
+    /// Packed `is_centered` values.
+    pub is_centered: PackedM31,
+    /// Packed `has_weight` values.
+    pub has_weight: PackedM31,
+    /// Packed `has_bias` values.
+    pub has_bias: PackedM31,
+    /// Packed `eps` values.
+    pub eps: PackedM31,
+    /// Packed `step` values.
+    pub step: PackedM31,
+    /// Packed `count` values.
+    pub count: PackedM31,
+    /// Packed `is_last_step` values.
+    pub is_last_step: PackedM31,
+    /// Packed `sum` values.
+    pub sum: PackedM31,
+    /// Packed `next_sum` values.
+    pub next_sum: PackedM31,
+    /// Packed `total` values.
+    pub total: PackedM31,
+    /// Packed `mean` values.
+    pub mean: PackedM31,
+    /// Packed `mean_rem` values.
+    pub mean_rem: PackedM31,
+    /// Packed `centered` values.
+    pub centered: PackedM31,
+    /// Packed `sq_sum` values.
+    pub sq_sum: PackedM31,
+    /// Packed `next_sq_sum` values.
+    pub next_sq_sum: PackedM31,
+    /// Packed `sq_total` values.
+    pub sq_total: PackedM31,
+    /// Packed `divisor` values.
+    pub divisor: PackedM31,
+    /// Packed `var` values.
+    pub var: PackedM31,
+    /// Packed `var_rem` values.
+    pub var_rem: PackedM31,
+    /// Packed `std` values.
+    pub std: PackedM31,
+    /// Packed `std_rem` values.
+    pub std_rem: PackedM31,
+    /// Packed `inv_std` values.
+    pub inv_std: PackedM31,
+    /// Packed `inv_std_rem` values.
+    pub inv_std_rem: PackedM31,
+    /// Packed `norm` values.
+    pub norm: PackedM31,
+    /// Packed `norm_rem` values.
+    pub norm_rem: PackedM31,
+    /// Packed `out_rem` values.
+    pub out_rem: PackedM31,
+    /// Packed `scale` values.
+    pub scale: PackedM31,
+    /// Packed `mean_sign` values.
+    pub mean_sign: PackedM31,
+    /// Packed `mean_limbs` values, one packed column per limb.
+    pub mean_limbs: [PackedM31; 2],
+    /// Packed `var_sign` values.
+    pub var_sign: PackedM31,
+    /// Packed `var_limbs` values, one packed column per limb.
+    pub var_limbs: [PackedM31; 2],
+    /// Packed `inv_std_sign` values.
+    pub inv_std_sign: PackedM31,
+    /// Packed `inv_std_limbs` values, one packed column per limb.
+    pub inv_std_limbs: [PackedM31; 2],
+    /// Packed `norm_sign` values.
+    pub norm_sign: PackedM31,
+    /// Packed `norm_limbs` values, one packed column per limb.
+    pub norm_limbs: [PackedM31; 2],
+    /// Packed `out_sign` values.
+    pub out_sign: PackedM31,
+    /// Packed `out_limbs` values, one packed column per limb.
+    pub out_limbs: [PackedM31; 2],
+    /// Packed `input_mult` values.
+    pub input_mult: PackedM31,
+    /// Packed `weight_mult` values.
+    pub weight_mult: PackedM31,
+    /// Packed `bias_mult` values.
+    pub bias_mult: PackedM31,
+    /// Packed `out_mult` values.
+    pub out_mult: PackedM31,
+    /// Packed `range_check_mult` values.
+    pub range_check_mult: PackedM31,
+}
+
+impl Pack for NormTraceTableRow {
+    type SimdType = PackedNormTraceTableRow;
+
+    fn pack(inputs: [Self; N_LANES]) -> Self::SimdType {
+        PackedNormTraceTableRow {
+            node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].node_id)),
+            input_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_id)),
+            weight_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].weight_id)),
+            bias_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].bias_id)),
+            idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].idx)),
+            input_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_idx)),
+            weight_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].weight_idx)),
+            bias_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].bias_idx)),
+            is_last_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_idx)),
+            input: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input)),
+            weight: PackedM31::from_array(std::array::from_fn(|i| inputs[i].weight)),
+            bias: PackedM31::from_array(std::array::from_fn(|i| inputs[i].bias)),
+            out: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out)),
+            is_centered: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_centered)),
+            has_weight: PackedM31::from_array(std::array::from_fn(|i| inputs[i].has_weight)),
+            has_bias: PackedM31::from_array(std::array::from_fn(|i| inputs[i].has_bias)),
+            eps: PackedM31::from_array(std::array::from_fn(|i| inputs[i].eps)),
+            step: PackedM31::from_array(std::array::from_fn(|i| inputs[i].step)),
+            count: PackedM31::from_array(std::array::from_fn(|i| inputs[i].count)),
+            is_last_step: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_step)),
+            sum: PackedM31::from_array(std::array::from_fn(|i| inputs[i].sum)),
+            next_sum: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_sum)),
+            total: PackedM31::from_array(std::array::from_fn(|i| inputs[i].total)),
+            mean: PackedM31::from_array(std::array::from_fn(|i| inputs[i].mean)),
+            mean_rem: PackedM31::from_array(std::array::from_fn(|i| inputs[i].mean_rem)),
+            centered: PackedM31::from_array(std::array::from_fn(|i| inputs[i].centered)),
+            sq_sum: PackedM31::from_array(std::array::from_fn(|i| inputs[i].sq_sum)),
+            next_sq_sum: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_sq_sum)),
+            sq_total: PackedM31::from_array(std::array::from_fn(|i| inputs[i].sq_total)),
+            divisor: PackedM31::from_array(std::array::from_fn(|i| inputs[i].divisor)),
+            var: PackedM31::from_array(std::array::from_fn(|i| inputs[i].var)),
+            var_rem: PackedM31::from_array(std::array::from_fn(|i| inputs[i].var_rem)),
+            std: PackedM31::from_array(std::array::from_fn(|i| inputs[i].std)),
+            std_rem: PackedM31::from_array(std::array::from_fn(|i| inputs[i].std_rem)),
+            inv_std: PackedM31::from_array(std::array::from_fn(|i| inputs[i].inv_std)),
+            inv_std_rem: PackedM31::from_array(std::array::from_fn(|i| inputs[i].inv_std_rem)),
+            norm: PackedM31::from_array(std::array::from_fn(|i| inputs[i].norm)),
+            norm_rem: PackedM31::from_array(std::array::from_fn(|i| inputs[i].norm_rem)),
+            out_rem: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_rem)),
+            scale: PackedM31::from_array(std::array::from_fn(|i| inputs[i].scale)),
+            mean_sign: PackedM31::from_array(std::array::from_fn(|i| inputs[i].mean_sign)),
+            mean_limbs: std::array::from_fn(|b| {
+                PackedM31::from_array(std::array::from_fn(|i| inputs[i].mean_limbs[b]))
+            }),
+            var_sign: PackedM31::from_array(std::array::from_fn(|i| inputs[i].var_sign)),
+            var_limbs: std::array::from_fn(|b| {
+                PackedM31::from_array(std::array::from_fn(|i| inputs[i].var_limbs[b]))
+            }),
+            inv_std_sign: PackedM31::from_array(std::array::from_fn(|i| inputs[i].inv_std_sign)),
+            inv_std_limbs: std::array::from_fn(|b| {
+                PackedM31::from_array(std::array::from_fn(|i| inputs[i].inv_std_limbs[b]))
+            }),
+            norm_sign: PackedM31::from_array(std::array::from_fn(|i| inputs[i].norm_sign)),
+            norm_limbs: std::array::from_fn(|b| {
+                PackedM31::from_array(std::array::from_fn(|i| inputs[i].norm_limbs[b]))
+            }),
+            out_sign: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_sign)),
+            out_limbs: std::array::from_fn(|b| {
+                PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_limbs[b]))
+            }),
+            input_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_mult)),
+            weight_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].weight_mult)),
+            bias_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].bias_mult)),
+            out_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_mult)),
+            range_check_mult: PackedM31::from_array(std::array::from_fn(|i| {
+                inputs[i].range_check_mult
+            })),
+        }
+    }
+}
+
+impl Unpack for PackedNormTraceTableRow {
+    type CpuType = NormTraceTableRow;
+
+    fn unpack(self) -> [Self::CpuType; N_LANES] {
+        let (
+            node_id,
+            input_id,
+            weight_id,
+            bias_id,
+            idx,
+            input_idx,
+            weight_idx,
+            bias_idx,
+            is_last_idx,
+            input,
+            weight,
+            bias,
+            out,
+            is_centered,
+            has_weight,
+            has_bias,
+            eps,
+            step,
+            count,
+            is_last_step,
+            sum,
+            next_sum,
+            total,
+            mean,
+            mean_rem,
+            centered,
+            sq_sum,
+            next_sq_sum,
+            sq_total,
+            divisor,
+            var,
+            var_rem,
+            std,
+            std_rem,
+            inv_std,
+            inv_std_rem,
+            norm,
+            norm_rem,
+            out_rem,
+            scale,
+            mean_sign,
+            var_sign,
+            inv_std_sign,
+            norm_sign,
+            out_sign,
+            input_mult,
+            weight_mult,
+            bias_mult,
+            out_mult,
+            range_check_mult,
+        ) = (
+            self.node_id.to_array(),
+            self.input_id.to_array(),
+            self.weight_id.to_array(),
+            self.bias_id.to_array(),
+            self.idx.to_array(),
+            self.input_idx.to_array(),
+            self.weight_idx.to_array(),
+            self.bias_idx.to_array(),
+            self.is_last_idx.to_array(),
+            self.input.to_array(),
+            self.weight.to_array(),
+            self.bias.to_array(),
+            self.out.to_array(),
+            self.is_centered.to_array(),
+            self.has_weight.to_array(),
+            self.has_bias.to_array(),
+            self.eps.to_array(),
+            self.step.to_array(),
+            self.count.to_array(),
+            self.is_last_step.to_array(),
+            self.sum.to_array(),
+            self.next_sum.to_array(),
+            self.total.to_array(),
+            self.mean.to_array(),
+            self.mean_rem.to_array(),
+            self.centered.to_array(),
+            self.sq_sum.to_array(),
+            self.next_sq_sum.to_array(),
+            self.sq_total.to_array(),
+            self.divisor.to_array(),
+            self.var.to_array(),
+            self.var_rem.to_array(),
+            self.std.to_array(),
+            self.std_rem.to_array(),
+            self.inv_std.to_array(),
+            self.inv_std_rem.to_array(),
+            self.norm.to_array(),
+            self.norm_rem.to_array(),
+            self.out_rem.to_array(),
+            self.scale.to_array(),
+            self.mean_sign.to_array(),
+            self.var_sign.to_array(),
+            self.inv_std_sign.to_array(),
+            self.norm_sign.to_array(),
+            self.out_sign.to_array(),
+            self.input_mult.to_array(),
+            self.weight_mult.to_array(),
+            self.bias_mult.to_array(),
+            self.out_mult.to_array(),
+            self.range_check_mult.to_array(),
+        );
+        let mean_limbs = self.mean_limbs.map(|b| b.to_array());
+        let var_limbs = self.var_limbs.map(|b| b.to_array());
+        let inv_std_limbs = self.inv_std_limbs.map(|b| b.to_array());
+        let norm_limbs = self.norm_limbs.map(|b| b.to_array());
+        let out_limbs = self.out_limbs.map(|b| b.to_array());
+
+        std::array::from_fn(|i| NormTraceTableRow {
+            node_id: node_id[i],
+            input_id: input_id[i],
+            weight_id: weight_id[i],
+            bias_id: bias_id[i],
+            idx: idx[i],
+            input_idx: input_idx[i],
+            weight_idx: weight_idx[i],
+            bias_idx: bias_idx[i],
+            is_last_idx: is_last_idx[i],
+            input: input[i],
+            weight: weight[i],
+            bias: bias[i],
+            out: out[i],
+            is_centered: is_centered[i],
+            has_weight: has_weight[i],
+            has_bias: has_bias[i],
+            eps: eps[i],
+            step: step[i],
+            count: count[i],
+            is_last_step: is_last_step[i],
+            sum: sum[i],
+            next_sum: next_sum[i],
+            total: total[i],
+            mean: mean[i],
+            mean_rem: mean_rem[i],
+            centered: centered[i],
+            sq_sum: sq_sum[i],
+            next_sq_sum: next_sq_sum[i],
+            sq_total: sq_total[i],
+            divisor: divisor[i],
+            var: var[i],
+            var_rem: var_rem[i],
+            std: std[i],
+            std_rem: std_rem[i],
+            inv_std: inv_std[i],
+            inv_std_rem: inv_std_rem[i],
+            norm: norm[i],
+            norm_rem: norm_rem[i],
+            out_rem: out_rem[i],
+            scale: scale[i],
+            mean_sign: mean_sign[i],
+            mean_limbs: std::array::from_fn(|b| mean_limbs[b][i]),
+            var_sign: var_sign[i],
+            var_limbs: std::array::from_fn(|b| var_limbs[b][i]),
+            inv_std_sign: inv_std_sign[i],
+            inv_std_limbs: std::array::from_fn(|b| inv_std_limbs[b][i]),
+            norm_sign: norm_sign[i],
+            norm_limbs: std::array::from_fn(|b| norm_limbs[b][i]),
+            out_sign: out_sign[i],
+            out_limbs: std::array::from_fn(|b| out_limbs[b][i]),
+            input_mult: input_mult[i],
+            weight_mult: weight_mult[i],
+            bias_mult: bias_mult[i],
+            out_mult: out_mult[i],
+            range_check_mult: range_check_mult[i],
+        })
+    }
+}
+
+impl NormTraceTable {
+    /// Creates a new, empty `NormTraceTable`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a single row to the trace table.
+    pub fn add_row(&mut self, row: NormTraceTableRow) {
+        self.table.push(row);
+    }
+}
+
+/// Enum defining the columns of the Norm AIR component's trace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum NormColumn {
+    /// ID of the current node.
+    NodeId,
+    /// ID of the node providing the input.
+    InputId,
+    /// ID of the node providing the weight.
+    WeightId,
+    /// ID of the node providing the bias.
+    BiasId,
+    /// Index within the tensor for this operation.
+    Idx,
+    /// Position of the input value within the input tensor.
+    InputIdx,
+    /// Position of the weight value within the weight tensor.
+    WeightIdx,
+    /// Position of the bias value within the bias tensor.
+    BiasIdx,
+    /// Flag indicating if this is the last element processed for this node.
+    IsLastIdx,
+    /// Value of the input (`x`).
+    Input,
+    /// Value of the weight (`w`).
+    Weight,
+    /// Value of the bias (`b`).
+    Bias,
+    /// Value of the output (`norm * w + b`).
+    Out,
+    /// Flag indicating if the mean is subtracted from the inputs.
+    IsCentered,
+    /// Flag indicating if the output is multiplied by a weight.
+    HasWeight,
+    /// Flag indicating if a bias is added to the output.
+    HasBias,
+    /// Epsilon added to the variance.
+    Eps,
+    /// Position of the input within its reduction.
+    Step,
+    /// Number of inputs of the reduction.
+    Count,
+    /// Flag indicating if this is the last input of the reduction.
+    IsLastStep,
+    /// Sum of the inputs before this row.
+    Sum,
+    /// Sum of the inputs after this row.
+    NextSum,
+    /// Sum of all the inputs of the reduction.
+    Total,
+    /// Mean of the inputs of the reduction.
+    Mean,
+    /// Remainder of the mean.
+    MeanRem,
+    /// Centered input.
+    Centered,
+    /// Sum of the squared centered inputs before this row.
+    SqSum,
+    /// Sum of the squared centered inputs after this row.
+    NextSqSum,
+    /// Sum of all the squared centered inputs of the reduction.
+    SqTotal,
+    /// Divisor of the variance.
+    Divisor,
+    /// Variance of the inputs of the reduction.
+    Var,
+    /// Remainder of the variance.
+    VarRem,
+    /// Standard deviation of the inputs of the reduction.
+    Std,
+    /// Remainder of the standard deviation.
+    StdRem,
+    /// Inverse of the standard deviation.
+    InvStd,
+    /// Remainder of the inverse of the standard deviation.
+    InvStdRem,
+    /// Normalized input.
+    Norm,
+    /// Remainder of the normalized input.
+    NormRem,
+    /// Remainder of the output.
+    OutRem,
+    /// Fixed-point scale factor.
+    Scale,
+    /// Sign of the mean.
+    MeanSign,
+    /// The `i`-th limb of the magnitude of the mean.
+    MeanLimb(usize),
+    /// Sign of the variance.
+    VarSign,
+    /// The `i`-th limb of the magnitude of the variance.
+    VarLimb(usize),
+    /// Sign of the inverse standard deviation.
+    InvStdSign,
+    /// The `i`-th limb of the magnitude of the inverse standard deviation.
+    InvStdLimb(usize),
+    /// Sign of the normalized input.
+    NormSign,
+    /// The `i`-th limb of the magnitude of the normalized input.
+    NormLimb(usize),
+    /// Sign of the output.
+    OutSign,
+    /// The `i`-th limb of the magnitude of the output.
+    OutLimb(usize),
+    /// Multiplicity for the LogUp argument (input).
+    InputMult,
+    /// Multiplicity for the LogUp argument (weight).
+    WeightMult,
+    /// Multiplicity for the LogUp argument (bias).
+    BiasMult,
+    /// Multiplicity for the LogUp argument (output).
+    OutMult,
+    /// Multiplicity for the range checks.
+    RangeCheckMult,
+}
+
+impl NormColumn {
+    /// Returns the 0-based index for this column within the Norm trace segment.
+    pub const fn index(self) -> usize {
+        match self {
+            Self::NodeId => 0,
+            Self::InputId => 1,
+            Self::WeightId => 2,
+            Self::BiasId => 3,
+            Self::Idx => 4,
+            Self::InputIdx => 5,
+            Self::WeightIdx => 6,
+            Self::BiasIdx => 7,
+            Self::IsLastIdx => 8,
+            Self::Input => 9,
+            Self::Weight => 10,
+            Self::Bias => 11,
+            Self::Out => 12,
+            Self::IsCentered => 13,
+            Self::HasWeight => 14,
+            Self::HasBias => 15,
+            Self::Eps => 16,
+            Self::Step => 17,
+            Self::Count => 18,
+            Self::IsLastStep => 19,
+            Self::Sum => 20,
+            Self::NextSum => 21,
+            Self::Total => 22,
+            Self::Mean => 23,
+            Self::MeanRem => 24,
+            Self::Centered => 25,
+            Self::SqSum => 26,
+            Self::NextSqSum => 27,
+            Self::SqTotal => 28,
+            Self::Divisor => 29,
+            Self::Var => 30,
+            Self::VarRem => 31,
+            Self::Std => 32,
+            Self::StdRem => 33,
+            Self::InvStd => 34,
+            Self::InvStdRem => 35,
+            Self::Norm => 36,
+            Self::NormRem => 37,
+            Self::OutRem => 38,
+            Self::Scale => 39,
+            Self::MeanSign => 40,
+            Self::MeanLimb(i) => 41 + i,
+            Self::VarSign => 43,
+            Self::VarLimb(i) => 44 + i,
+            Self::InvStdSign => 46,
+            Self::InvStdLimb(i) => 47 + i,
+            Self::NormSign => 49,
+            Self::NormLimb(i) => 50 + i,
+            Self::OutSign => 52,
+            Self::OutLimb(i) => 53 + i,
+            Self::InputMult => 55,
+            Self::WeightMult => 56,
+            Self::BiasMult => 57,
+            Self::OutMult => 58,
+            Self::RangeCheckMult => 59,
+        }
+    }
+}
+
+/// Implements the `TraceColumn` trait for `NormColumn`.
+impl TraceColumn for NormColumn {
+    /// Specifies the number of columns used by the Norm component.
+    /// Returns `(N_TRACE_COLUMNS, 4 + N_RANGE_CHECKS)`, indicating the number of main trace
+    /// columns and the interaction trace columns (input, weight, bias and output LogUps, the
+    /// twelve range checks of the remainders and those of the limbs of the five quotients).
+    fn count() -> (usize, usize) {
+        (N_TRACE_COLUMNS, 4 + N_RANGE_CHECKS)
+    }
+}
@@ -0,0 +1,330 @@
+use crate::{
+    components::{
+        lookups::range_check::{
+            witness::limb_range_checks, RangeCheckLookupElements, N_LIMB_RANGE_CHECKS,
+        },
+        InteractionClaim, NodeElements, NormClaim,
+    },
+    utils::{pack_values, to_trace_order, TreeBuilder},
+};
+use luminair_utils::TraceError;
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use stwo_air_utils::trace::component_trace::ComponentTrace;
+use stwo_air_utils_derive::{IterMut, ParIterMut, Uninitialized};
+use stwo_prover::{
+    constraint_framework::{logup::LogupTraceGenerator, Relation},
+    core::{
+        backend::simd::{
+            m31::{PackedM31, LOG_N_LANES, N_LANES},
+            qm31::PackedQM31,
+            SimdBackend,
+        },
+        fields::m31::M31,
+    },
+};
+
+use super::table::{
+    quotient_bits, NormColumn, NormTraceTable, NormTraceTableRow, PackedNormTraceTableRow,
+};
+
+/// Number of main trace columns for the Norm component.
+pub(crate) const N_TRACE_COLUMNS: usize = 60;
+
+/// Number of remainders and gaps to their bounds range-checked by each row.
+const N_REM_RANGE_CHECKS: usize = 12;
+
+/// Number of values range-checked by each row of the Norm component.
+pub(crate) const N_RANGE_CHECKS: usize = N_REM_RANGE_CHECKS + 5 * N_LIMB_RANGE_CHECKS;
+
+/// Generates main trace and interaction data for the Norm component.
+///
+/// Takes the raw `NormTraceTable`, processes it into main STARK trace columns,
+/// and prepares `LookupData` for the LogUp arguments: input, weight, bias, output,
+/// and the range checks of the remainders of the normalization.
+pub struct ClaimGenerator {
+    /// The raw trace data for Norm operations.
+    pub inputs: NormTraceTable,
+    /// Fixed-point scale of the circuit, bounding the quotients.
+    pub fp_scale: u32,
+}
+
+impl ClaimGenerator {
+    /// Creates a new `ClaimGenerator` with the given `NormTraceTable` and fixed-point scale.
+    pub fn new(inputs: NormTraceTable, fp_scale: u32) -> Self {
+        Self { inputs, fp_scale }
+    }
+
+    /// Writes the main trace columns and returns data for the interaction phase.
+    ///
+    /// Standard procedure: pads table, packs rows, calls `write_trace_simd`,
+    /// adds main trace to `tree_builder`, returns `NormClaim` and `InteractionClaimGenerator`.
+    /// Returns `TraceError::EmptyTrace` if the input table is empty.
+    pub fn write_trace(
+        mut self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+    ) -> Result<(NormClaim, InteractionClaimGenerator), TraceError> {
+        let n_rows = self.inputs.table.len();
+
+        if n_rows == 0 {
+            return Err(TraceError::EmptyTrace);
+        }
+
+        let size = std::cmp::max(n_rows.next_power_of_two(), N_LANES);
+        let log_size = size.ilog2();
+
+        self.inputs.table.resize(size, NormTraceTableRow::padding());
+        let packed_inputs = pack_values(&to_trace_order(&self.inputs.table));
+
+        let (trace, lookup_data) = write_trace_simd(packed_inputs, quotient_bits(self.fp_scale));
+
+        tree_builder.extend_evals(trace.to_evals());
+
+        Ok((
+            NormClaim::new(log_size),
+            InteractionClaimGenerator {
+                log_size,
+                lookup_data,
+            },
+        ))
+    }
+}
+
+/// Populates main trace columns and `LookupData` from SIMD-packed Norm trace rows.
+///
+/// Processes `PackedNormTraceTableRow` data in parallel:
+/// - Maps fields to corresponding main trace columns.
+/// - Extracts `[id, idx, value]` triples and multiplicities for input, weight, bias and
+///   output LogUps, and the remainders of the normalization with their gaps to their bounds,
+///   followed by the limbs of the quotients in `[0, 2^quotient_bits)` with their gaps to their
+///   maximal values, into `LookupData`.
+/// Returns the `ComponentTrace` and `LookupData`.
+fn write_trace_simd(
+    inputs: Vec<PackedNormTraceTableRow>,
+    quotient_bits: [u32; 5],
+) -> (ComponentTrace<N_TRACE_COLUMNS>, LookupData) {
+    let log_n_packed_rows = inputs.len().ilog2();
+    let log_size = log_n_packed_rows + LOG_N_LANES;
+
+    let (mut trace, mut lookup_data) = unsafe {
+        (
+            ComponentTrace::<N_TRACE_COLUMNS>::uninitialized(log_size),
+            LookupData::uninitialized(log_n_packed_rows),
+        )
+    };
+
+    let one = PackedM31::broadcast(M31::from_u32_unchecked(1));
+
+    (
+        trace.par_iter_mut(),
+        lookup_data.par_iter_mut(),
+        inputs.into_par_iter(),
+    )
+        .into_par_iter()
+        .for_each(|(mut row, lookup_data, input)| {
+            *row[NormColumn::NodeId.index()] = input.node_id;
+            *row[NormColumn::InputId.index()] = input.input_id;
+            *row[NormColumn::WeightId.index()] = input.weight_id;
+            *row[NormColumn::BiasId.index()] = input.bias_id;
+            *row[NormColumn::Idx.index()] = input.idx;
+            *row[NormColumn::InputIdx.index()] = input.input_idx;
+            *row[NormColumn::WeightIdx.index()] = input.weight_idx;
+            *row[NormColumn::BiasIdx.index()] = input.bias_idx;
+            *row[NormColumn::IsLastIdx.index()] = input.is_last_idx;
+            *row[NormColumn::Input.index()] = input.input;
+            *row[NormColumn::Weight.index()] = input.weight;
+            *row[NormColumn::Bias.index()] = input.bias;
+            *row[NormColumn::Out.index()] = input.out;
+            *row[NormColumn::IsCentered.index()] = input.is_centered;
+            *row[NormColumn::HasWeight.index()] = input.has_weight;
+            *row[NormColumn::HasBias.index()] = input.has_bias;
+            *row[NormColumn::Eps.index()] = input.eps;
+            *row[NormColumn::Step.index()] = input.step;
+            *row[NormColumn::Count.index()] = input.count;
+            *row[NormColumn::IsLastStep.index()] = input.is_last_step;
+            *row[NormColumn::Sum.index()] = input.sum;
+            *row[NormColumn::NextSum.index()] = input.next_sum;
+            *row[NormColumn::Total.index()] = input.total;
+            *row[NormColumn::Mean.index()] = input.mean;
+            *row[NormColumn::MeanRem.index()] = input.mean_rem;
+            *row[NormColumn::Centered.index()] = input.centered;
+            *row[NormColumn::SqSum.index()] = input.sq_sum;
+            *row[NormColumn::NextSqSum.index()] = input.next_sq_sum;
+            *row[NormColumn::SqTotal.index()] = input.sq_total;
+            *row[NormColumn::Divisor.index()] = input.divisor;
+            *row[NormColumn::Var.index()] = input.var;
+            *row[NormColumn::VarRem.index()] = input.var_rem;
+            *row[NormColumn::Std.index()] = input.std;
+            *row[NormColumn::StdRem.index()] = input.std_rem;
+            *row[NormColumn::InvStd.index()] = input.inv_std;
+            *row[NormColumn::InvStdRem.index()] = input.inv_std_rem;
+            *row[NormColumn::Norm.index()] = input.norm;
+            *row[NormColumn::NormRem.index()] = input.norm_rem;
+            *row[NormColumn::OutRem.index()] = input.out_rem;
+            *row[NormColumn::Scale.index()] = input.scale;
+            *row[NormColumn::MeanSign.index()] = input.mean_sign;
+            for (i, limb) in input.mean_limbs.into_iter().enumerate() {
+                *row[NormColumn::MeanLimb(i).index()] = limb;
+            }
+            *row[NormColumn::VarSign.index()] = input.var_sign;
+            for (i, limb) in input.var_limbs.into_iter().enumerate() {
+                *row[NormColumn::VarLimb(i).index()] = limb;
+            }
+            *row[NormColumn::InvStdSign.index()] = input.inv_std_sign;
+            for (i, limb) in input.inv_std_limbs.into_iter().enumerate() {
+                *row[NormColumn::InvStdLimb(i).index()] = limb;
+            }
+            *row[NormColumn::NormSign.index()] = input.norm_sign;
+            for (i, limb) in input.norm_limbs.into_iter().enumerate() {
+                *row[NormColumn::NormLimb(i).index()] = limb;
+            }
+            *row[NormColumn::OutSign.index()] = input.out_sign;
+            for (i, limb) in input.out_limbs.into_iter().enumerate() {
+                *row[NormColumn::OutLimb(i).index()] = limb;
+            }
+            *row[NormColumn::InputMult.index()] = input.input_mult;
+            *row[NormColumn::WeightMult.index()] = input.weight_mult;
+            *row[NormColumn::BiasMult.index()] = input.bias_mult;
+            *row[NormColumn::OutMult.index()] = input.out_mult;
+            *row[NormColumn::RangeCheckMult.index()] = input.range_check_mult;
+
+            *lookup_data.input = [input.input_id, input.input_idx, input.input];
+            *lookup_data.input_mult = input.input_mult;
+            *lookup_data.weight = [input.weight_id, input.weight_idx, input.weight];
+            *lookup_data.weight_mult = input.weight_mult;
+            *lookup_data.bias = [input.bias_id, input.bias_idx, input.bias];
+            *lookup_data.bias_mult = input.bias_mult;
+            *lookup_data.out = [input.node_id, input.idx, input.out];
+            *lookup_data.out_mult = input.out_mult;
+            let rem_range_checks = [
+                input.mean_rem,
+                input.count - one - input.mean_rem,
+                input.var_rem,
+                input.divisor - one - input.var_rem,
+                input.std_rem,
+                input.std + input.std - input.std_rem,
+                input.inv_std_rem,
+                input.std - one - input.inv_std_rem,
+                input.norm_rem,
+                input.scale - one - input.norm_rem,
+                input.out_rem,
+                input.scale - one - input.out_rem,
+            ];
+            let limbs = [
+                input.mean_limbs,
+                input.var_limbs,
+                input.inv_std_limbs,
+                input.norm_limbs,
+                input.out_limbs,
+            ];
+            let quotient_range_checks: [[PackedM31; N_LIMB_RANGE_CHECKS]; 5] =
+                std::array::from_fn(|q| limb_range_checks(limbs[q], quotient_bits[q]));
+            *lookup_data.range_checks = std::array::from_fn(|i| {
+                if i < N_REM_RANGE_CHECKS {
+                    rem_range_checks[i]
+                } else {
+                    let i = i - N_REM_RANGE_CHECKS;
+                    quotient_range_checks[i / N_LIMB_RANGE_CHECKS][i % N_LIMB_RANGE_CHECKS]
+                }
+            });
+            *lookup_data.range_check_mult = input.range_check_mult;
+        });
+
+    (trace, lookup_data)
+}
+
+/// Intermediate data for the Norm component's LogUp arguments.
+///
+/// Holds ID-index-value triples and multiplicities for input, weight, bias and output terms,
+/// and the values range-checked by each row.
+/// Derives helper iterators for parallel processing.
+#[derive(Uninitialized, IterMut, ParIterMut)]
+struct LookupData {
+    /// Input ID-index-value triples: `[input_node_id, input_idx, input_value]`.
+    input: Vec<[PackedM31; 3]>,
+    /// Multiplicities for input values (LogUp).
+    input_mult: Vec<PackedM31>,
+    /// Weight ID-index-value triples: `[weight_node_id, weight_idx, weight_value]`.
+    weight: Vec<[PackedM31; 3]>,
+    /// Multiplicities for weight values (LogUp).
+    weight_mult: Vec<PackedM31>,
+    /// Bias ID-index-value triples: `[bias_node_id, bias_idx, bias_value]`.
+    bias: Vec<[PackedM31; 3]>,
+    /// Multiplicities for bias values (LogUp).
+    bias_mult: Vec<PackedM31>,
+    /// Output ID-index-value triples: `[node_id, idx, out_value]`.
+    out: Vec<[PackedM31; 3]>,
+    /// Multiplicities for output values (LogUp).
+    out_mult: Vec<PackedM31>,
+    /// Remainders of the normalization and their gaps, followed by the limbs of the quotients
+    /// and their gaps, range-checked.
+    range_checks: Vec<[PackedM31; N_RANGE_CHECKS]>,
+    /// Multiplicities for the range checks.
+    range_check_mult: Vec<PackedM31>,
+}
+
+/// Generates interaction trace columns for the LogUp arguments of the Norm component.
+///
+/// Builds the LogUp interaction columns:
+/// 1. Input term: `(input_value, input_node_id)` with `NodeElements`.
+/// 2. Weight term: `(weight_value, weight_node_id)` with `NodeElements`.
+/// 3. Bias term: `(bias_value, bias_node_id)` with `NodeElements`.
+/// 4. Output term: `(out_value, node_id)` with `NodeElements`.
+/// 5. A range-check term per remainder, quotient limb and their gaps, with
+///    `RangeCheckLookupElements`.
+pub struct InteractionClaimGenerator {
+    /// Log2 size of the trace.
+    log_size: u32,
+    /// Data for LogUp arguments.
+    lookup_data: LookupData,
+}
+
+impl InteractionClaimGenerator {
+    /// Writes the LogUp interaction trace columns to the `tree_builder`.
+    ///
+    /// - Initializes a `LogupTraceGenerator`.
+    /// - For the Input, Weight, Bias and Output LogUps: combines the ID-index-value triples
+    ///   of `lookup_data` with `node_elements` for the denominator.
+    /// - For the range checks: combines each of `lookup_data.range_checks[i]` with
+    ///   `range_check_elements` for the denominator.
+    /// - Writes `multiplicity / denominator` fractions for each.
+    /// - Finalizes the generator, adds columns to `tree_builder`, returns `InteractionClaim`.
+    pub fn write_interaction_trace(
+        self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+        node_elements: &NodeElements,
+        range_check_elements: &RangeCheckLookupElements,
+    ) -> InteractionClaim {
+        let mut logup_gen = LogupTraceGenerator::new(self.log_size);
+
+        for (values, multiplicities) in [
+            (&self.lookup_data.input, &self.lookup_data.input_mult),
+            (&self.lookup_data.weight, &self.lookup_data.weight_mult),
+            (&self.lookup_data.bias, &self.lookup_data.bias_mult),
+            (&self.lookup_data.out, &self.lookup_data.out_mult),
+        ] {
+            let mut col_gen = logup_gen.new_col();
+            for row in 0..1 << (self.log_size - LOG_N_LANES) {
+                let denom: PackedQM31 = node_elements.combine(&values[row]);
+                col_gen.write_frac(row, multiplicities[row].into(), denom);
+            }
+            col_gen.finalize_col();
+        }
+
+        for i in 0..N_RANGE_CHECKS {
+            let mut col_gen = logup_gen.new_col();
+            for row in 0..1 << (self.log_size - LOG_N_LANES) {
+                let value = self.lookup_data.range_checks[row][i];
+                let multiplicity = self.lookup_data.range_check_mult[row];
+
+                let denom: PackedQM31 = range_check_elements.combine(&[value]);
+                col_gen.write_frac(row, multiplicity.into(), denom);
+            }
+            col_gen.finalize_col();
+        }
+
+        let (trace, claimed_sum) = logup_gen.finalize_last();
+        tree_builder.extend_evals(trace);
+
+        InteractionClaim { claimed_sum }
+    }
+}
@@ -8,10 +8,20 @@ use stwo_prover::core::{
     fields::m31::M31,
 };
 
-use crate::components::TraceColumn;
+use crate::{
+    components::{lookups::range_check::N_LIMB_RANGE_CHECKS, TraceColumn},
+    preprocessed::StructuredRow,
+};
 
 use super::witness::N_TRACE_COLUMNS;
 
+/// Returns the number of range-checked bits of the output magnitude at the scale `fp_scale`.
+///
+/// The output `SCALE^2 / x` is the largest for the smallest non-zero input, at `SCALE^2`.
+pub const fn out_bits(fp_scale: u32) -> u32 {
+    2 * fp_scale + 1
+}
+
 /// Represents the raw trace data collected for Reciprocal operations (`1/x`).
 ///
 /// Stores rows capturing inputs, outputs, remainder (for fixed-point reciprocal),
@@ -24,8 +34,9 @@ pub struct RecipTraceTable {
 
 /// Represents a single row in the `RecipTraceTable`.
 ///
-/// Contains values for evaluating Recip AIR constraints: current/next state IDs,
-/// input/output values, fixed-point remainder, scale factor, and LogUp multiplicities.
+/// Contains values for evaluating Recip AIR constraints: state IDs,
+/// input/output values, fixed-point remainder, scale factor, the sign and limbs of the output,
+/// and LogUp multiplicities.
 #[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
 pub struct RecipTraceTableRow {
     /// ID of the current Recip node.
@@ -34,26 +45,31 @@ pub struct RecipTraceTableRow {
     pub input_id: M31,
     /// Index within the tensor for this operation.
     pub idx: M31,
+    /// Position of the input value within the input tensor.
+    pub input_idx: M31,
     /// Flag indicating if this is the last element processed for this node (1 if true, 0 otherwise).
     pub is_last_idx: M31,
-    /// ID of the *next* Recip node processed in the trace.
-    pub next_node_id: M31,
-    /// ID of the *next* input provider node.
-    pub next_input_id: M31,
-    /// Index of the *next* element processed.
-    pub next_idx: M31,
     /// Value of the input (`x`).
     pub input: M31,
     /// Value of the output (`SCALE / x`).
     pub out: M31,
     /// Remainder from fixed-point reciprocal (`SCALE % x`).
     pub rem: M31,
-    /// The scale factor used (typically `numerair::SCALE_FACTOR`).
+    /// The scale factor of the circuit (`2^fp_scale`), zero on padding rows.
     pub scale: M31,
+    /// Absolute value of the input (`|x|`).
+    pub abs_input: M31,
+    /// Sign of the output (1 if negative, 0 otherwise).
+    pub out_sign: M31,
+    /// Low and high limbs of the output magnitude.
+    pub out_limbs: [M31; 2],
     /// Multiplicity contribution for the LogUp argument (input).
     pub input_mult: M31,
     /// Multiplicity contribution for the LogUp argument (output).
     pub out_mult: M31,
+    /// Multiplicity contribution for the range checks of the remainder, its gap to `|x|` and
+    /// the output limbs.
+    pub rem_mult: M31,
 }
 
 impl RecipTraceTableRow {
@@ -63,20 +79,49 @@ impl RecipTraceTableRow {
             node_id: M31::zero(),
             input_id: M31::zero(),
             idx: M31::zero(),
+            input_idx: M31::zero(),
             is_last_idx: M31::one(),
-            next_node_id: M31::zero(),
-            next_input_id: M31::zero(),
-            next_idx: M31::zero(),
             input: M31::zero(),
             out: M31::zero(),
             rem: M31::zero(),
             scale: M31::zero(),
+            abs_input: M31::zero(),
+            out_sign: M31::zero(),
+            out_limbs: [M31::zero(); 2],
             input_mult: M31::zero(),
             out_mult: M31::zero(),
+            rem_mult: M31::zero(),
         }
     }
 }
 
+/// Implements the `StructuredRow` trait for `RecipTraceTableRow`.
+impl StructuredRow for RecipTraceTableRow {
+    /// IDs, indices, flags and multiplicities of the row, fixed by the graph.
+    const STRUCTURE: &'static [&'static str] = &[
+        "node_id",
+        "input_id",
+        "idx",
+        "input_idx",
+        "is_last_idx",
+        "input_mult",
+        "out_mult",
+    ];
+
+    /// Returns the values of the structural columns of the row.
+    fn structure(&self) -> Vec<M31> {
+        vec![
+            self.node_id,
+            self.input_id,
+            self.idx,
+            self.input_idx,
+            self.is_last_idx,
+            self.input_mult,
+            self.out_mult,
+        ]
+    }
+}
+
 /// SIMD-packed representation of a `RecipTraceTableRow`.
 #[derive(Debug, Copy, Clone)]
 pub struct PackedRecipTraceTableRow {
@@ -86,14 +131,10 @@ pub struct PackedRecipTraceTableRow {
     pub input_id: PackedM31,
     /// Packed `idx` values.
     pub idx: PackedM31,
+    /// Packed `input_idx` values.
+    pub input_idx: PackedM31,
     /// Packed `is_last_idx` values.
     pub is_last_idx: PackedM31,
-    /// Packed `next_node_id` values.
-    pub next_node_id: PackedM31,
-    /// Packed `next_input_id` values.
-    pub next_input_id: PackedM31,
-    /// Packed `next_idx` values.
-    pub next_idx: PackedM31,
     /// Packed `input` values.
     pub input: PackedM31,
     /// Packed `out` values.
@@ -102,10 +143,18 @@ pub struct PackedRecipTraceTableRow {
     pub rem: PackedM31,
     /// Packed `scale` values.
     pub scale: PackedM31,
+    /// Packed `abs_input` values.
+    pub abs_input: PackedM31,
+    /// Packed `out_sign` values.
+    pub out_sign: PackedM31,
+    /// Packed `out_limbs` values, one packed column per limb.
+    pub out_limbs: [PackedM31; 2],
     /// Packed `input_mult` values.
     pub input_mult: PackedM31,
     /// Packed `out_mult` values.
     pub out_mult: PackedM31,
+    /// Packed `rem_mult` values.
+    pub rem_mult: PackedM31,
 }
 
 impl Pack for RecipTraceTableRow {
@@ -116,16 +165,20 @@ impl Pack for RecipTraceTableRow {
             node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].node_id)),
             input_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_id)),
             idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].idx)),
+            input_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_idx)),
             is_last_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_idx)),
-            next_node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_node_id)),
-            next_input_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_input_id)),
-            next_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_idx)),
             input: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input)),
             out: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out)),
             rem: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rem)),
             scale: PackedM31::from_array(std::array::from_fn(|i| inputs[i].scale)),
+            abs_input: PackedM31::from_array(std::array::from_fn(|i| inputs[i].abs_input)),
+            out_sign: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_sign)),
+            out_limbs: std::array::from_fn(|b| {
+                PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_limbs[b]))
+            }),
             input_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_mult)),
             out_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_mult)),
+            rem_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rem_mult)),
         }
     }
 }
@@ -138,46 +191,51 @@ impl Unpack for PackedRecipTraceTableRow {
             node_id,
             input_id,
             idx,
+            input_idx,
             is_last_idx,
-            next_node_id,
-            next_input_id,
-            next_idx,
             input,
             out,
             rem,
             scale,
+            abs_input,
+            out_sign,
             input_mult,
             out_mult,
+            rem_mult,
         ) = (
             self.node_id.to_array(),
             self.input_id.to_array(),
             self.idx.to_array(),
+            self.input_idx.to_array(),
             self.is_last_idx.to_array(),
-            self.next_node_id.to_array(),
-            self.next_input_id.to_array(),
-            self.next_idx.to_array(),
             self.input.to_array(),
             self.out.to_array(),
             self.rem.to_array(),
             self.scale.to_array(),
+            self.abs_input.to_array(),
+            self.out_sign.to_array(),
             self.input_mult.to_array(),
             self.out_mult.to_array(),
+            self.rem_mult.to_array(),
         );
+        let out_limbs = self.out_limbs.map(|b| b.to_array());
 
         std::array::from_fn(|i| RecipTraceTableRow {
             node_id: node_id[i],
             input_id: input_id[i],
             idx: idx[i],
+            input_idx: input_idx[i],
             is_last_idx: is_last_idx[i],
-            next_node_id: next_node_id[i],
-            next_input_id: next_input_id[i],
-            next_idx: next_idx[i],
             input: input[i],
             out: out[i],
             rem: rem[i],
             scale: scale[i],
+            abs_input: abs_input[i],
+            out_sign: out_sign[i],
+            out_limbs: std::array::from_fn(|b| out_limbs[b][i]),
             input_mult: input_mult[i],
             out_mult: out_mult[i],
+            rem_mult: rem_mult[i],
         })
     }
 }
@@ -203,14 +261,10 @@ pub enum RecipColumn {
     InputId,
     /// Index within the tensor for this operation.
     Idx,
+    /// Position of the input value within the input tensor.
+    InputIdx,
     /// Flag indicating if this is the last element processed for this node.
     IsLastIdx,
-    /// ID of the *next* Recip node processed in the trace.
-    NextNodeId,
-    /// ID of the *next* input provider node.
-    NextInputId,
-    /// Index of the *next* element processed.
-    NextIdx,
     /// Value of the input (`x`).
     Input,
     /// Value of the output (`SCALE / x`).
@@ -219,10 +273,18 @@ pub enum RecipColumn {
     Rem,
     /// The scale factor used.
     Scale,
+    /// Absolute value of the input (`|x|`).
+    AbsInput,
+    /// Sign of the output.
+    OutSign,
+    /// The `i`-th limb of the output magnitude.
+    OutLimb(usize),
     /// Multiplicity for the LogUp argument (input).
     InputMult,
     /// Multiplicity for the LogUp argument (output).
     OutMult,
+    /// Multiplicity for the range checks of the remainder, its gap to `|x|` and the output limbs.
+    RemMult,
 }
 
 impl RecipColumn {
@@ -232,16 +294,18 @@ impl RecipColumn {
             Self::NodeId => 0,
             Self::InputId => 1,
             Self::Idx => 2,
-            Self::IsLastIdx => 3,
-            Self::NextNodeId => 4,
-            Self::NextInputId => 5,
-            Self::NextIdx => 6,
-            Self::Input => 7,
-            Self::Out => 8,
-            Self::Rem => 9,
-            Self::Scale => 10,
-            Self::InputMult => 11,
-            Self::OutMult => 12,
+            Self::InputIdx => 3,
+            Self::IsLastIdx => 4,
+            Self::Input => 5,
+            Self::Out => 6,
+            Self::Rem => 7,
+            Self::Scale => 8,
+            Self::AbsInput => 9,
+            Self::OutSign => 10,
+            Self::OutLimb(i) => 11 + i,
+            Self::InputMult => 13,
+            Self::OutMult => 14,
+            Self::RemMult => 15,
         }
     }
 }
@@ -249,9 +313,10 @@ impl RecipColumn {
 /// Implements the `TraceColumn` trait for `RecipColumn`.
 impl TraceColumn for RecipColumn {
     /// Specifies the number of columns used by the Recip component.
-    /// Returns `(N_TRACE_COLUMNS, 2)`, indicating the number of main trace columns
-    /// and 2 interaction trace columns (for input and output LogUp).
+    /// Returns `(N_TRACE_COLUMNS, 4 + N_LIMB_RANGE_CHECKS)`, indicating the number of main
+    /// trace columns and the interaction trace columns (for input, output, the range checks
+    /// of the remainder and of its gap to `|x|`, and those of the output limbs).
     fn count() -> (usize, usize) {
-        (N_TRACE_COLUMNS, 2)
+        (N_TRACE_COLUMNS, 4 + N_LIMB_RANGE_CHECKS)
     }
 }
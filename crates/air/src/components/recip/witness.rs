@@ -1,6 +1,11 @@
 use crate::{
-    components::{InteractionClaim, NodeElements, RecipClaim},
-    utils::{pack_values, TreeBuilder},
+    components::{
+        lookups::range_check::{
+            witness::limb_range_checks, RangeCheckLookupElements, N_LIMB_RANGE_CHECKS,
+        },
+        InteractionClaim, NodeElements, RecipClaim,
+    },
+    utils::{pack_values, to_trace_order, TreeBuilder},
 };
 use luminair_utils::TraceError;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
@@ -8,31 +13,41 @@ use stwo_air_utils::trace::component_trace::ComponentTrace;
 use stwo_air_utils_derive::{IterMut, ParIterMut, Uninitialized};
 use stwo_prover::{
     constraint_framework::{logup::LogupTraceGenerator, Relation},
-    core::backend::simd::{
-        m31::{PackedM31, LOG_N_LANES, N_LANES},
-        qm31::PackedQM31,
-        SimdBackend,
+    core::{
+        backend::simd::{
+            m31::{PackedM31, LOG_N_LANES, N_LANES},
+            qm31::PackedQM31,
+            SimdBackend,
+        },
+        fields::m31::M31,
     },
 };
 
-use super::table::{PackedRecipTraceTableRow, RecipColumn, RecipTraceTable, RecipTraceTableRow};
+use super::table::{
+    out_bits, PackedRecipTraceTableRow, RecipColumn, RecipTraceTable, RecipTraceTableRow,
+};
 
 /// Number of main trace columns for the Recip component.
-pub(crate) const N_TRACE_COLUMNS: usize = 13;
+pub(crate) const N_TRACE_COLUMNS: usize = 16;
+
+/// Number of values range-checked by each row of the Recip component.
+pub(crate) const N_RANGE_CHECKS: usize = 2 + N_LIMB_RANGE_CHECKS;
 
 /// Generates the main trace columns and initial data for interaction claims for the Recip component.
 ///
 /// Takes the raw `RecipTraceTable`, processes it into the main STARK trace columns
-/// (including input, output, remainder, scale), and prepares `LookupData` for LogUp.
+/// (including input, output, remainder, scale, absolute input), and prepares `LookupData` for LogUp.
 pub struct ClaimGenerator {
     /// The raw trace data for Recip operations.
     pub inputs: RecipTraceTable,
+    /// Fixed-point scale of the circuit, bounding the output magnitude.
+    pub fp_scale: u32,
 }
 
 impl ClaimGenerator {
-    /// Creates a new `ClaimGenerator` with the given `RecipTraceTable`.
-    pub fn new(inputs: RecipTraceTable) -> Self {
-        Self { inputs }
+    /// Creates a new `ClaimGenerator` with the given `RecipTraceTable` and fixed-point scale.
+    pub fn new(inputs: RecipTraceTable, fp_scale: u32) -> Self {
+        Self { inputs, fp_scale }
     }
 
     /// Writes the main trace columns to the `tree_builder` and returns data for interaction phase.
@@ -56,9 +71,9 @@ impl ClaimGenerator {
         self.inputs
             .table
             .resize(size, RecipTraceTableRow::padding());
-        let packed_inputs = pack_values(&self.inputs.table);
+        let packed_inputs = pack_values(&to_trace_order(&self.inputs.table));
 
-        let (trace, lookup_data) = write_trace_simd(packed_inputs);
+        let (trace, lookup_data) = write_trace_simd(packed_inputs, out_bits(self.fp_scale));
 
         tree_builder.extend_evals(trace.to_evals());
 
@@ -76,11 +91,14 @@ impl ClaimGenerator {
 ///
 /// Processes `PackedRecipTraceTableRow` data in parallel:
 /// - Maps fields (input, out, rem, scale, etc.) to the corresponding main trace columns.
-/// - Extracts `[value, id]` pairs and multiplicities into `LookupData` for the LogUp argument
+/// - Extracts `[id, idx, value]` triples and multiplicities into `LookupData` for the LogUp argument
 ///   (only for input and output, as reciprocal is unary).
+/// - Collects the remainder, its gap `|x| - 1 - rem`, and the limbs of the output magnitude
+///   in `[0, 2^out_bits)` with their gaps to their maximal values, for the range checks.
 /// Returns the `ComponentTrace` (main trace columns) and `LookupData`.
 fn write_trace_simd(
     inputs: Vec<PackedRecipTraceTableRow>,
+    out_bits: u32,
 ) -> (ComponentTrace<N_TRACE_COLUMNS>, LookupData) {
     let log_n_packed_rows = inputs.len().ilog2();
     let log_size = log_n_packed_rows + LOG_N_LANES;
@@ -92,6 +110,8 @@ fn write_trace_simd(
         )
     };
 
+    let one = PackedM31::broadcast(M31::from_u32_unchecked(1));
+
     (
         trace.par_iter_mut(),
         lookup_data.par_iter_mut(),
@@ -102,21 +122,32 @@ fn write_trace_simd(
             *row[RecipColumn::NodeId.index()] = input.node_id;
             *row[RecipColumn::InputId.index()] = input.input_id;
             *row[RecipColumn::Idx.index()] = input.idx;
+            *row[RecipColumn::InputIdx.index()] = input.input_idx;
             *row[RecipColumn::IsLastIdx.index()] = input.is_last_idx;
-            *row[RecipColumn::NextNodeId.index()] = input.next_node_id;
-            *row[RecipColumn::NextInputId.index()] = input.next_input_id;
-            *row[RecipColumn::NextIdx.index()] = input.next_idx;
             *row[RecipColumn::Input.index()] = input.input;
             *row[RecipColumn::Out.index()] = input.out;
             *row[RecipColumn::Rem.index()] = input.rem;
             *row[RecipColumn::Scale.index()] = input.scale;
+            *row[RecipColumn::AbsInput.index()] = input.abs_input;
+            *row[RecipColumn::OutSign.index()] = input.out_sign;
+            for (i, limb) in input.out_limbs.into_iter().enumerate() {
+                *row[RecipColumn::OutLimb(i).index()] = limb;
+            }
             *row[RecipColumn::InputMult.index()] = input.input_mult;
             *row[RecipColumn::OutMult.index()] = input.out_mult;
+            *row[RecipColumn::RemMult.index()] = input.rem_mult;
 
-            *lookup_data.input = [input.input, input.input_id];
+            *lookup_data.input = [input.input_id, input.input_idx, input.input];
             *lookup_data.input_mult = input.input_mult;
-            *lookup_data.out = [input.out, input.node_id];
+            *lookup_data.out = [input.node_id, input.idx, input.out];
             *lookup_data.out_mult = input.out_mult;
+            let out_range_checks = limb_range_checks(input.out_limbs, out_bits);
+            *lookup_data.range_checks = std::array::from_fn(|i| match i {
+                0 => input.rem,
+                1 => input.abs_input - one - input.rem,
+                _ => out_range_checks[i - 2],
+            });
+            *lookup_data.rem_mult = input.rem_mult;
         });
 
     (trace, lookup_data)
@@ -124,37 +155,44 @@ fn write_trace_simd(
 
 /// Intermediate data structure holding values and multiplicities for the Recip LogUp argument.
 ///
-/// Stores value-ID pairs and multiplicities only for the input and output terms.
+/// Stores ID-index-value triples and multiplicities only for the input and output terms.
 /// Derives helper iterators for parallel processing.
 #[derive(Uninitialized, IterMut, ParIterMut)]
 struct LookupData {
-    /// Input value-ID pairs: `[input_value, input_node_id]`.
-    input: Vec<[PackedM31; 2]>,
+    /// Input ID-index-value triples: `[input_node_id, input_idx, input_value]`.
+    input: Vec<[PackedM31; 3]>,
     /// Multiplicities for input values.
     input_mult: Vec<PackedM31>,
-    /// Output value-ID pairs: `[out_value, recip_node_id]`.
-    out: Vec<[PackedM31; 2]>,
+    /// Output ID-index-value triples: `[recip_node_id, idx, out_value]`.
+    out: Vec<[PackedM31; 3]>,
     /// Multiplicities for output values.
     out_mult: Vec<PackedM31>,
+    /// Fixed-point remainders and their gaps to `|x|`, followed by the output limbs and their
+    /// gaps to their maximal values, range-checked through the shared range-check lookup.
+    range_checks: Vec<[PackedM31; N_RANGE_CHECKS]>,
+    /// Multiplicities for the range checks.
+    rem_mult: Vec<PackedM31>,
 }
 
 /// Generates the interaction trace columns for the Recip component's LogUp argument.
 ///
-/// Takes `LookupData` and `NodeElements` to build the two LogUp interaction columns
-/// (one for input, one for output) and adds them to the `tree_builder`.
+/// Takes `LookupData`, `NodeElements` and `RangeCheckLookupElements` to build the LogUp
+/// interaction columns (input, output, and a column per range check) and adds them to the `tree_builder`.
 pub struct InteractionClaimGenerator {
     /// Log2 size of the trace.
     log_size: u32,
-    /// Data (value-ID pairs and multiplicities) needed for LogUp.
+    /// Data (ID-index-value triples and multiplicities) needed for LogUp.
     lookup_data: LookupData,
 }
 
 impl InteractionClaimGenerator {
     /// Writes the LogUp interaction trace columns to the `tree_builder`.
     ///
-    /// Logic is similar to Add/Mul, but only generates two columns (Input, Output):
+    /// Logic is similar to Add/Mul, but generates columns for Input, Output and Rem:
     /// - Creates a `LogupTraceGenerator`.
-    /// - Generates two columns, writing `multiplicity / denom` fractions.
+    /// - Generates two node columns, writing `multiplicity / denom` fractions.
+    /// - Generates a column per range check, sending `rem`, `|x| - 1 - rem` and the output
+    ///   limbs with their gaps to the range-check lookup.
     /// - Finalizes the generator, obtaining interaction trace columns and `claimed_sum`.
     /// - Adds interaction columns to the `tree_builder`.
     /// - Returns the `InteractionClaim` containing the `claimed_sum`.
@@ -162,6 +200,7 @@ impl InteractionClaimGenerator {
         self,
         tree_builder: &mut impl TreeBuilder<SimdBackend>,
         node_elements: &NodeElements,
+        range_check_elements: &RangeCheckLookupElements,
     ) -> InteractionClaim {
         let mut logup_gen = LogupTraceGenerator::new(self.log_size);
 
@@ -185,6 +224,18 @@ impl InteractionClaimGenerator {
         }
         col_gen.finalize_col();
 
+        for i in 0..N_RANGE_CHECKS {
+            let mut col_gen = logup_gen.new_col();
+            for row in 0..1 << (self.log_size - LOG_N_LANES) {
+                let value = self.lookup_data.range_checks[row][i];
+                let multiplicity = self.lookup_data.rem_mult[row];
+
+                let denom: PackedQM31 = range_check_elements.combine(&[value]);
+                col_gen.write_frac(row, multiplicity.into(), denom);
+            }
+            col_gen.finalize_col();
+        }
+
         let (trace, claimed_sum) = logup_gen.finalize_last();
         tree_builder.extend_evals(trace);
 
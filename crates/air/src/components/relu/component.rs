@@ -0,0 +1,183 @@
+use crate::{
+    components::{lookups::range_check::RangeCheckLookupElements, NodeElements, ReluClaim},
+    preprocessed::eval_structure,
+};
+use num_traits::One;
+use stwo_prover::constraint_framework::{
+    EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry, ORIGINAL_TRACE_IDX,
+};
+
+use super::table::ReluTraceTableRow;
+
+/// The STWO AIR component for element-wise relu operations.
+/// Wraps the `ReluEval` logic within the STWO `FrameworkComponent`.
+pub type ReluComponent = FrameworkComponent<ReluEval>;
+
+/// Defines the AIR constraints evaluation logic for the Relu component.
+/// Implements `FrameworkEval` to define trace layout, degrees, and constraints.
+pub struct ReluEval {
+    /// Log2 size of the component's trace segment.
+    log_size: u32,
+    /// Log2 size of the preprocessed range-check table.
+    lut_log_size: u32,
+    /// Interaction elements for node relations (used in LogUp).
+    node_elements: NodeElements,
+    /// Interaction elements for the magnitude range check.
+    range_check_elements: RangeCheckLookupElements,
+}
+
+impl ReluEval {
+    /// Creates a new `ReluEval` instance.
+    /// Takes the component's claim (for `log_size`), interaction elements for nodes and
+    /// the range check, and the log_size of the range-check table.
+    pub fn new(
+        claim: &ReluClaim,
+        node_elements: NodeElements,
+        range_check_elements: RangeCheckLookupElements,
+        lut_log_size: u32,
+    ) -> Self {
+        Self {
+            log_size: claim.log_size,
+            lut_log_size,
+            node_elements,
+            range_check_elements,
+        }
+    }
+}
+
+/// Implements the core constraint evaluation logic for the Relu component.
+impl FrameworkEval for ReluEval {
+    /// Returns the log2 size of this component's trace segment.
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// Returns the max log2 degree bound, considering both main trace and range-check table sizes.
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        std::cmp::max(self.log_size, self.lut_log_size) + 1
+    }
+
+    /// Evaluates the Relu AIR constraints on a given evaluation point (`eval`).
+    ///
+    /// Defines constraints for:
+    /// - **Structural:** The IDs, indices, flags and multiplicities match the preprocessed trace.
+    /// - **Consistency:** Decomposes the input into a sign bit and a magnitude, and checks
+    ///   that the output is the input when the sign bit is 0 and zero otherwise.
+    /// - **Transition:** Ensures correct state transitions between consecutive rows (same node/input ID,
+    ///   index increments by 1) when `is_last_idx` is false.
+    /// - **Interaction (LogUp):** Links input and output values to the global LogUp argument,
+    ///   and sends `magnitude` to the shared range-check lookup so that it lies in `[0, 2^k)`.
+    /// Receives an evaluator `E` and adds constraint evaluations to it.
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        // IDs (current and next row, for transition constraints)
+        // ID of the node in the computational graph.
+        let [node_id, next_node_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of the input tensor.
+        let [input_id, next_input_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Index in the flattened tensor.
+        let [idx, next_idx] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Position of the input value within its tensor.
+        let input_idx = eval.next_trace_mask();
+        let is_last_idx = eval.next_trace_mask(); // Flag if this is the last index for this operation.
+
+        // Values for consistency constraints
+        let input_val = eval.next_trace_mask(); // Value from the tensor at index.
+        let out_val = eval.next_trace_mask(); // Value in output tensor at index.
+        let magnitude = eval.next_trace_mask(); // Magnitude of the input.
+        let sign = eval.next_trace_mask(); // Sign bit of the input.
+
+        // Multiplicities for interaction constraints
+        let input_mult = eval.next_trace_mask();
+        let out_mult = eval.next_trace_mask();
+        let magnitude_mult = eval.next_trace_mask();
+
+        // ┌─────────────────────────────┐
+        // │   Structural Constraints    │
+        // └─────────────────────────────┘
+
+        // The IDs, indices, flags and multiplicities are the ones fixed by the graph.
+        eval_structure::<_, ReluTraceTableRow>(
+            &mut eval,
+            "relu",
+            vec![
+                node_id.clone(),
+                input_id.clone(),
+                idx.clone(),
+                input_idx.clone(),
+                is_last_idx.clone(),
+                input_mult.clone(),
+                out_mult.clone(),
+            ],
+        );
+
+        // ┌─────────────────────────────┐
+        // │   Consistency Constraints   │
+        // └─────────────────────────────┘
+
+        // The is_last_idx flag is either 0 or 1.
+        eval.add_constraint(is_last_idx.clone() * (is_last_idx.clone() - E::F::one()));
+
+        // The sign bit is either 0 or 1.
+        eval.add_constraint(sign.clone() * (sign.clone() - E::F::one()));
+
+        // input = magnitude when the sign bit is 0, and -(magnitude + 1) when it is 1.
+        // With the magnitude range-checked, this pins the sign bit to the sign of the input.
+        eval.add_constraint(
+            input_val.clone() - magnitude.clone()
+                + sign.clone() * (magnitude.clone() + magnitude.clone() + E::F::one()),
+        );
+
+        // Rows skipping the range check (padding) must decompose to zero,
+        // so the magnitude of every other row is range-checked.
+        eval.add_constraint(magnitude_mult.clone() * (magnitude_mult.clone() - E::F::one()));
+        eval.add_constraint((E::F::one() - magnitude_mult.clone()) * magnitude.clone());
+        eval.add_constraint((E::F::one() - magnitude_mult.clone()) * sign.clone());
+
+        // out = max(input, 0)
+        eval.add_constraint(out_val.clone() - (E::F::one() - sign) * input_val.clone());
+
+        // ┌────────────────────────────┐
+        // │   Transition Constraints   │
+        // └────────────────────────────┘
+
+        // If this is not the last index for this operation, then:
+        // 1. The next row should be for the same operation on the same tensors.
+        // 2. The index should increment by 1.
+        let not_last = E::F::one() - is_last_idx;
+
+        // Same node ID
+        eval.add_constraint(not_last.clone() * (next_node_id - node_id.clone()));
+
+        // Same tensor IDs
+        eval.add_constraint(not_last.clone() * (next_input_id - input_id.clone()));
+
+        // Index increment by 1
+        eval.add_constraint(not_last * (next_idx - idx.clone() - E::F::one()));
+
+        // ┌─────────────────────────────┐
+        // │   Interaction Constraints   │
+        // └─────────────────────────────┘
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            input_mult.into(),
+            &[input_id, input_idx, input_val],
+        ));
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            out_mult.into(),
+            &[node_id, idx, out_val],
+        ));
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.range_check_elements,
+            magnitude_mult.into(),
+            &[magnitude],
+        ));
+
+        eval.finalize_logup();
+
+        eval
+    }
+}
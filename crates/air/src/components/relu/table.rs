@@ -0,0 +1,274 @@
+use num_traits::{One, Zero};
+use serde::{Deserialize, Serialize};
+use stwo_prover::core::{
+    backend::simd::{
+        conversion::{Pack, Unpack},
+        m31::{PackedM31, N_LANES},
+    },
+    fields::m31::M31,
+};
+
+use crate::{components::TraceColumn, preprocessed::StructuredRow};
+
+use super::witness::N_TRACE_COLUMNS;
+
+/// Represents the raw trace data collected for Relu operations.
+///
+/// Stores rows capturing inputs, outputs, the sign-bit decomposition of the input,
+/// and metadata for each Relu operation.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ReluTraceTable {
+    /// Vector containing all rows of the Relu trace.
+    pub table: Vec<ReluTraceTableRow>,
+}
+
+/// Represents a single row in the `ReluTraceTable`.
+///
+/// Contains values for evaluating Relu AIR constraints: state IDs,
+/// input/output values, sign bit, magnitude, and LogUp multiplicities.
+#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ReluTraceTableRow {
+    /// ID of the current Relu node.
+    pub node_id: M31,
+    /// ID of the node providing the input.
+    pub input_id: M31,
+    /// Index within the tensor for this operation.
+    pub idx: M31,
+    /// Position of the input value within the input tensor.
+    pub input_idx: M31,
+    /// Flag indicating if this is the last element processed for this node (1 if true, 0 otherwise).
+    pub is_last_idx: M31,
+    /// Value of the input (`x`).
+    pub input: M31,
+    /// Value of the output (`max(x, 0)`).
+    pub out: M31,
+    /// Magnitude of the input: `x` if non-negative, `-x - 1` otherwise.
+    pub magnitude: M31,
+    /// Sign bit of the input (1 if `x` is negative, 0 otherwise).
+    pub sign: M31,
+    /// Multiplicity contribution for the LogUp argument (input).
+    pub input_mult: M31,
+    /// Multiplicity contribution for the LogUp argument (output).
+    pub out_mult: M31,
+    /// Multiplicity contribution for the range check of the magnitude.
+    pub magnitude_mult: M31,
+}
+
+impl ReluTraceTableRow {
+    /// Creates a default padding row for the Relu trace.
+    pub(crate) fn padding() -> Self {
+        Self {
+            node_id: M31::zero(),
+            input_id: M31::zero(),
+            idx: M31::zero(),
+            input_idx: M31::zero(),
+            is_last_idx: M31::one(),
+            input: M31::zero(),
+            out: M31::zero(),
+            magnitude: M31::zero(),
+            sign: M31::zero(),
+            input_mult: M31::zero(),
+            out_mult: M31::zero(),
+            magnitude_mult: M31::zero(),
+        }
+    }
+}
+
+/// Implements the `StructuredRow` trait for `ReluTraceTableRow`.
+impl StructuredRow for ReluTraceTableRow {
+    /// IDs, indices, flags and multiplicities of the row, fixed by the graph.
+    const STRUCTURE: &'static [&'static str] = &[
+        "node_id",
+        "input_id",
+        "idx",
+        "input_idx",
+        "is_last_idx",
+        "input_mult",
+        "out_mult",
+    ];
+
+    /// Returns the values of the structural columns of the row.
+    fn structure(&self) -> Vec<M31> {
+        vec![
+            self.node_id,
+            self.input_id,
+            self.idx,
+            self.input_idx,
+            self.is_last_idx,
+            self.input_mult,
+            self.out_mult,
+        ]
+    }
+}
+
+/// SIMD-packed representation of a `ReluTraceTableRow`.
+#[derive(Debug, Copy, Clone)]
+pub struct PackedReluTraceTableRow {
+    /// Packed `node_id` values.
+    pub node_id: PackedM31,
+    /// Packed `input_id` values.
+    pub input_id: PackedM31,
+    /// Packed `idx` values.
+    pub idx: PackedM31,
+    /// Packed `input_idx` values.
+    pub input_idx: PackedM31,
+    /// Packed `is_last_idx` values.
+    pub is_last_idx: PackedM31,
+    /// Packed `input` values.
+    pub input: PackedM31,
+    /// Packed `out` values.
+    pub out: PackedM31,
+    /// Packed `magnitude` values.
+    pub magnitude: PackedM31,
+    /// Packed `sign` values.
+    pub sign: PackedM31,
+    /// Packed `input_mult` values.
+    pub input_mult: PackedM31,
+    /// Packed `out_mult` values.
+    pub out_mult: PackedM31,
+    /// Packed `magnitude_mult` values.
+    pub magnitude_mult: PackedM31,
+}
+
+impl Pack for ReluTraceTableRow {
+    type SimdType = PackedReluTraceTableRow;
+
+    fn pack(inputs: [Self; N_LANES]) -> Self::SimdType {
+        PackedReluTraceTableRow {
+            node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].node_id)),
+            input_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_id)),
+            idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].idx)),
+            input_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_idx)),
+            is_last_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_idx)),
+            input: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input)),
+            out: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out)),
+            magnitude: PackedM31::from_array(std::array::from_fn(|i| inputs[i].magnitude)),
+            sign: PackedM31::from_array(std::array::from_fn(|i| inputs[i].sign)),
+            input_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_mult)),
+            out_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_mult)),
+            magnitude_mult: PackedM31::from_array(std::array::from_fn(|i| {
+                inputs[i].magnitude_mult
+            })),
+        }
+    }
+}
+
+impl Unpack for PackedReluTraceTableRow {
+    type CpuType = ReluTraceTableRow;
+
+    fn unpack(self) -> [Self::CpuType; N_LANES] {
+        let (
+            node_id,
+            input_id,
+            idx,
+            input_idx,
+            is_last_idx,
+            input,
+            out,
+            magnitude,
+            sign,
+            input_mult,
+            out_mult,
+            magnitude_mult,
+        ) = (
+            self.node_id.to_array(),
+            self.input_id.to_array(),
+            self.idx.to_array(),
+            self.input_idx.to_array(),
+            self.is_last_idx.to_array(),
+            self.input.to_array(),
+            self.out.to_array(),
+            self.magnitude.to_array(),
+            self.sign.to_array(),
+            self.input_mult.to_array(),
+            self.out_mult.to_array(),
+            self.magnitude_mult.to_array(),
+        );
+
+        std::array::from_fn(|i| ReluTraceTableRow {
+            node_id: node_id[i],
+            input_id: input_id[i],
+            idx: idx[i],
+            input_idx: input_idx[i],
+            is_last_idx: is_last_idx[i],
+            input: input[i],
+            out: out[i],
+            magnitude: magnitude[i],
+            sign: sign[i],
+            input_mult: input_mult[i],
+            out_mult: out_mult[i],
+            magnitude_mult: magnitude_mult[i],
+        })
+    }
+}
+
+impl ReluTraceTable {
+    /// Creates a new, empty `ReluTraceTable`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a single row to the trace table.
+    pub fn add_row(&mut self, row: ReluTraceTableRow) {
+        self.table.push(row);
+    }
+}
+
+/// Enum defining the columns of the Relu AIR component's trace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ReluColumn {
+    /// ID of the current Relu node.
+    NodeId,
+    /// ID of the node providing the input.
+    InputId,
+    /// Index within the tensor for this operation.
+    Idx,
+    /// Position of the input value within the input tensor.
+    InputIdx,
+    /// Flag indicating if this is the last element processed for this node.
+    IsLastIdx,
+    /// Value of the input (`x`).
+    Input,
+    /// Value of the output (`max(x, 0)`).
+    Out,
+    /// Magnitude of the input.
+    Magnitude,
+    /// Sign bit of the input.
+    Sign,
+    /// Multiplicity for the LogUp argument (input).
+    InputMult,
+    /// Multiplicity for the LogUp argument (output).
+    OutMult,
+    /// Multiplicity for the range check of the magnitude.
+    MagnitudeMult,
+}
+
+impl ReluColumn {
+    /// Returns the 0-based index for this column within the Relu trace segment.
+    pub const fn index(self) -> usize {
+        match self {
+            Self::NodeId => 0,
+            Self::InputId => 1,
+            Self::Idx => 2,
+            Self::InputIdx => 3,
+            Self::IsLastIdx => 4,
+            Self::Input => 5,
+            Self::Out => 6,
+            Self::Magnitude => 7,
+            Self::Sign => 8,
+            Self::InputMult => 9,
+            Self::OutMult => 10,
+            Self::MagnitudeMult => 11,
+        }
+    }
+}
+
+/// Implements the `TraceColumn` trait for `ReluColumn`.
+impl TraceColumn for ReluColumn {
+    /// Specifies the number of columns used by the Relu component.
+    /// Returns `(N_TRACE_COLUMNS, 3)`, indicating the number of main trace columns
+    /// and 3 interaction trace columns (for input, output and magnitude range check LogUp).
+    fn count() -> (usize, usize) {
+        (N_TRACE_COLUMNS, 3)
+    }
+}
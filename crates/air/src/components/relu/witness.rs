@@ -0,0 +1,211 @@
+use luminair_utils::TraceError;
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use stwo_air_utils::trace::component_trace::ComponentTrace;
+use stwo_air_utils_derive::{IterMut, ParIterMut, Uninitialized};
+use stwo_prover::{
+    constraint_framework::{logup::LogupTraceGenerator, Relation},
+    core::backend::simd::{
+        m31::{PackedM31, LOG_N_LANES, N_LANES},
+        qm31::PackedQM31,
+        SimdBackend,
+    },
+};
+
+use crate::{
+    components::{
+        lookups::range_check::RangeCheckLookupElements, InteractionClaim, NodeElements, ReluClaim,
+    },
+    utils::{pack_values, to_trace_order, TreeBuilder},
+};
+
+use super::table::{PackedReluTraceTableRow, ReluColumn, ReluTraceTable, ReluTraceTableRow};
+
+/// Number of main trace columns for the Relu component.
+pub(crate) const N_TRACE_COLUMNS: usize = 12;
+
+/// Generates the main trace columns and initial data for interaction claims for the Relu component.
+///
+/// Takes the raw `ReluTraceTable`, processes it into the main STARK trace columns
+/// (including input, output, magnitude, sign), and prepares `LookupData` for LogUp.
+pub struct ClaimGenerator {
+    /// The raw trace data for Relu operations.
+    pub inputs: ReluTraceTable,
+}
+
+impl ClaimGenerator {
+    /// Creates a new `ClaimGenerator` with the given `ReluTraceTable`.
+    pub fn new(inputs: ReluTraceTable) -> Self {
+        Self { inputs }
+    }
+
+    /// Writes the main trace columns to the `tree_builder` and returns data for interaction phase.
+    ///
+    /// Follows the standard pattern: pads the table, packs rows, calls `write_trace_simd`,
+    /// adds main trace to `tree_builder`, returns `ReluClaim` and `InteractionClaimGenerator`.
+    /// Returns `TraceError::EmptyTrace` if the input table is empty.
+    pub fn write_trace(
+        mut self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+    ) -> Result<(ReluClaim, InteractionClaimGenerator), TraceError> {
+        let n_rows = self.inputs.table.len();
+
+        if n_rows == 0 {
+            return Err(TraceError::EmptyTrace);
+        }
+
+        let size = std::cmp::max(n_rows.next_power_of_two(), N_LANES);
+        let log_size = size.ilog2();
+
+        self.inputs.table.resize(size, ReluTraceTableRow::padding());
+        let packed_inputs = pack_values(&to_trace_order(&self.inputs.table));
+
+        let (trace, lookup_data) = write_trace_simd(packed_inputs);
+
+        tree_builder.extend_evals(trace.to_evals());
+
+        Ok((
+            ReluClaim::new(log_size),
+            InteractionClaimGenerator {
+                log_size,
+                lookup_data,
+            },
+        ))
+    }
+}
+
+/// Populates the main trace columns and `LookupData` from SIMD-packed Relu trace rows.
+///
+/// Processes `PackedReluTraceTableRow` data in parallel:
+/// - Maps fields (input, out, magnitude, sign, etc.) to the corresponding main trace columns.
+/// - Extracts `[id, idx, value]` triples and multiplicities into `LookupData` for the LogUp argument
+///   (only for input and output, as relu is unary).
+/// Returns the `ComponentTrace` (main trace columns) and `LookupData`.
+fn write_trace_simd(
+    inputs: Vec<PackedReluTraceTableRow>,
+) -> (ComponentTrace<N_TRACE_COLUMNS>, LookupData) {
+    let log_n_packed_rows = inputs.len().ilog2();
+    let log_size = log_n_packed_rows + LOG_N_LANES;
+
+    let (mut trace, mut lookup_data) = unsafe {
+        (
+            ComponentTrace::<N_TRACE_COLUMNS>::uninitialized(log_size),
+            LookupData::uninitialized(log_n_packed_rows),
+        )
+    };
+
+    (
+        trace.par_iter_mut(),
+        lookup_data.par_iter_mut(),
+        inputs.into_par_iter(),
+    )
+        .into_par_iter()
+        .for_each(|(mut row, lookup_data, input)| {
+            *row[ReluColumn::NodeId.index()] = input.node_id;
+            *row[ReluColumn::InputId.index()] = input.input_id;
+            *row[ReluColumn::Idx.index()] = input.idx;
+            *row[ReluColumn::InputIdx.index()] = input.input_idx;
+            *row[ReluColumn::IsLastIdx.index()] = input.is_last_idx;
+            *row[ReluColumn::Input.index()] = input.input;
+            *row[ReluColumn::Out.index()] = input.out;
+            *row[ReluColumn::Magnitude.index()] = input.magnitude;
+            *row[ReluColumn::Sign.index()] = input.sign;
+            *row[ReluColumn::InputMult.index()] = input.input_mult;
+            *row[ReluColumn::OutMult.index()] = input.out_mult;
+            *row[ReluColumn::MagnitudeMult.index()] = input.magnitude_mult;
+
+            *lookup_data.input = [input.input_id, input.input_idx, input.input];
+            *lookup_data.input_mult = input.input_mult;
+            *lookup_data.out = [input.node_id, input.idx, input.out];
+            *lookup_data.out_mult = input.out_mult;
+            *lookup_data.magnitude = input.magnitude;
+            *lookup_data.magnitude_mult = input.magnitude_mult;
+        });
+
+    (trace, lookup_data)
+}
+
+/// Intermediate data structure holding values and multiplicities for the Relu LogUp argument.
+///
+/// Stores ID-index-value triples and multiplicities only for the input and output terms.
+/// Derives helper iterators for parallel processing.
+#[derive(Uninitialized, IterMut, ParIterMut)]
+struct LookupData {
+    /// Input ID-index-value triples: `[input_node_id, input_idx, input_value]`.
+    input: Vec<[PackedM31; 3]>,
+    /// Multiplicities for input values.
+    input_mult: Vec<PackedM31>,
+    /// Output ID-index-value triples: `[relu_node_id, idx, out_value]`.
+    out: Vec<[PackedM31; 3]>,
+    /// Multiplicities for output values.
+    out_mult: Vec<PackedM31>,
+    /// Input magnitudes, range-checked through the shared range-check lookup.
+    magnitude: Vec<PackedM31>,
+    /// Multiplicities for the magnitude range check.
+    magnitude_mult: Vec<PackedM31>,
+}
+
+/// Generates the interaction trace columns for the Relu component's LogUp argument.
+///
+/// Takes `LookupData`, `NodeElements` and `RangeCheckLookupElements` to build the three LogUp
+/// interaction columns (input, output, and the magnitude range check) and adds them to the `tree_builder`.
+pub struct InteractionClaimGenerator {
+    /// Log2 size of the trace.
+    log_size: u32,
+    /// Data (ID-index-value triples and multiplicities) needed for LogUp.
+    lookup_data: LookupData,
+}
+
+impl InteractionClaimGenerator {
+    /// Writes the LogUp interaction trace columns to the `tree_builder`.
+    ///
+    /// Logic is similar to Add/Mul, but generates columns for Input, Output and Magnitude:
+    /// - Creates a `LogupTraceGenerator`.
+    /// - Generates two node columns, writing `multiplicity / denom` fractions.
+    /// - Generates a third column sending `magnitude` to the range-check lookup.
+    /// - Finalizes the generator, obtaining interaction trace columns and `claimed_sum`.
+    /// - Adds interaction columns to the `tree_builder`.
+    /// - Returns the `InteractionClaim` containing the `claimed_sum`.
+    pub fn write_interaction_trace(
+        self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+        node_elements: &NodeElements,
+        range_check_elements: &RangeCheckLookupElements,
+    ) -> InteractionClaim {
+        let mut logup_gen = LogupTraceGenerator::new(self.log_size);
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let values = &self.lookup_data.input[row];
+            let multiplicity = &self.lookup_data.input_mult[row];
+
+            let denom: PackedQM31 = node_elements.combine(values);
+            col_gen.write_frac(row, (*multiplicity).into(), denom);
+        }
+        col_gen.finalize_col();
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let values = &self.lookup_data.out[row];
+            let multiplicity = &self.lookup_data.out_mult[row];
+
+            let denom: PackedQM31 = node_elements.combine(values);
+            col_gen.write_frac(row, (*multiplicity).into(), denom);
+        }
+        col_gen.finalize_col();
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let magnitude = self.lookup_data.magnitude[row];
+            let multiplicity = self.lookup_data.magnitude_mult[row];
+
+            let denom: PackedQM31 = range_check_elements.combine(&[magnitude]);
+            col_gen.write_frac(row, multiplicity.into(), denom);
+        }
+        col_gen.finalize_col();
+
+        let (trace, claimed_sum) = logup_gen.finalize_last();
+        tree_builder.extend_evals(trace);
+
+        InteractionClaim { claimed_sum }
+    }
+}
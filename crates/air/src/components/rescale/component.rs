@@ -0,0 +1,214 @@
+use crate::{
+    components::{
+        lookups::range_check::{component::eval_signed_limbs, RangeCheckLookupElements},
+        NodeElements, RescaleClaim,
+    },
+    preprocessed::eval_structure,
+};
+use num_traits::One;
+use stwo_prover::{
+    constraint_framework::{
+        EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry, ORIGINAL_TRACE_IDX,
+    },
+    core::fields::m31::M31,
+};
+
+use super::table::{out_bits, RescaleTraceTableRow};
+
+/// The STWO AIR component for Rescale operations, which move a tensor to another fixed-point scale.
+/// Wraps the `RescaleEval` logic within the STWO `FrameworkComponent`.
+pub type RescaleComponent = FrameworkComponent<RescaleEval>;
+
+/// Defines the AIR constraints evaluation logic for the Rescale component.
+/// Implements `FrameworkEval` to define trace layout, degrees, and constraints.
+///
+/// Rescales are inserted by the compiler to bring products, held at twice the circuit scale,
+/// back to the circuit scale. The shift `s_in - s_out` is therefore the circuit scale.
+///
+/// The division is checked in the field, where `out * 2^s + rem` wraps around modulo P.
+/// Bounding the output to `|out| < 2^(30 - s)` through its sign and limbs leaves a single
+/// output per input. That the products themselves did not overflow is checked by `gen_trace`.
+pub struct RescaleEval {
+    /// Log2 size of the component's trace segment.
+    log_size: u32,
+    /// Log2 size of the preprocessed range-check table.
+    lut_log_size: u32,
+    /// Interaction elements for node relations (used in LogUp).
+    node_elements: NodeElements,
+    /// Interaction elements for the remainder and output range checks.
+    range_check_elements: RangeCheckLookupElements,
+    /// Fixed-point scale of the circuit.
+    fp_scale: u32,
+}
+
+impl RescaleEval {
+    /// Creates a new `RescaleEval` instance.
+    /// Takes the component's claim (for `log_size`), interaction elements for nodes and
+    /// the range check, the log_size of the range-check table and the fixed-point scale.
+    pub fn new(
+        claim: &RescaleClaim,
+        node_elements: NodeElements,
+        range_check_elements: RangeCheckLookupElements,
+        lut_log_size: u32,
+        fp_scale: u32,
+    ) -> Self {
+        Self {
+            log_size: claim.log_size,
+            lut_log_size,
+            node_elements,
+            range_check_elements,
+            fp_scale,
+        }
+    }
+}
+
+/// Implements the core constraint evaluation logic for the Rescale component.
+impl FrameworkEval for RescaleEval {
+    /// Returns the log2 size of this component's trace segment.
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// Returns the max log2 degree bound, considering both main trace and range-check table sizes.
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        std::cmp::max(self.log_size, self.lut_log_size) + 1
+    }
+
+    /// Evaluates the Rescale AIR constraints on a given evaluation point (`eval`).
+    ///
+    /// Defines constraints for:
+    /// - **Structural:** The IDs, indices, flags and multiplicities match the preprocessed trace.
+    /// - **Consistency:** Checks the rounding division `input + 2^(s - 1) = out * 2^s + rem`,
+    ///   with `s` the shift between the scales, i.e. `out = round(input * 2^(s_out - s_in))`,
+    ///   boolean flags, and that the range checks are enabled on every row emitting an output.
+    /// - **Transition:** Ensures correct state transitions between consecutive rows (same node/input ID,
+    ///   index increments by 1) when `is_last_idx` is false.
+    /// - **Interaction (LogUp):** Links input and output values to the global LogUp argument,
+    ///   and sends both `rem` and `2^s - 1 - rem` to the shared range-check lookup,
+    ///   so that `rem` lies in `[0, 2^s)`. The limbs of the output magnitude are range-checked
+    ///   in the same way, so that `|out| < 2^(30 - s)`.
+    /// Receives an evaluator `E` and adds constraint evaluations to it.
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        // IDs (current and next row, for transition constraints)
+        // ID of the node in the computational graph.
+        let [node_id, next_node_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of the input tensor.
+        let [input_id, next_input_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Index in the flattened tensor.
+        let [idx, next_idx] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Position of the input value within its tensor.
+        let input_idx = eval.next_trace_mask();
+        let is_last_idx = eval.next_trace_mask(); // Flag if this is the last index for this operation.
+
+        // Values for consistency constraints
+        let input_val = eval.next_trace_mask(); // Value from the tensor at index.
+        let out_val = eval.next_trace_mask(); // Value in output tensor at index.
+        let rem_val = eval.next_trace_mask(); // Rem value of the rounding division.
+        let out_sign = eval.next_trace_mask(); // Sign of the output.
+        let out_limbs: [E::F; 2] = std::array::from_fn(|_| eval.next_trace_mask()); // Limbs of |out|.
+
+        // Multiplicities for interaction constraints
+        let input_mult = eval.next_trace_mask();
+        let out_mult = eval.next_trace_mask();
+        let rem_mult = eval.next_trace_mask();
+
+        let divisor = E::F::from(M31::from_u32_unchecked(1 << self.fp_scale));
+        let half = E::F::from(M31::from_u32_unchecked((1 << self.fp_scale) >> 1));
+        let max_rem = E::F::from(M31::from_u32_unchecked((1 << self.fp_scale) - 1));
+
+        // ┌─────────────────────────────┐
+        // │   Structural Constraints    │
+        // └─────────────────────────────┘
+
+        // The IDs, indices, flags and multiplicities are the ones fixed by the graph.
+        eval_structure::<_, RescaleTraceTableRow>(
+            &mut eval,
+            "rescale",
+            vec![
+                node_id.clone(),
+                input_id.clone(),
+                idx.clone(),
+                input_idx.clone(),
+                is_last_idx.clone(),
+                input_mult.clone(),
+                out_mult.clone(),
+            ],
+        );
+
+        // ┌─────────────────────────────┐
+        // │   Consistency Constraints   │
+        // └─────────────────────────────┘
+
+        // The is_last_idx flag is either 0 or 1.
+        eval.add_constraint(is_last_idx.clone() * (is_last_idx.clone() - E::F::one()));
+
+        // Rounding division by 2^s: adding half of the divisor rounds to the nearest value.
+        eval.add_constraint(input_val.clone() + half - out_val.clone() * divisor - rem_val.clone());
+
+        // The range-check multiplicity is boolean, and set on every row emitting its output.
+        eval.add_constraint(rem_mult.clone() * (rem_mult.clone() - E::F::one()));
+        eval.add_constraint(out_mult.clone() * (E::F::one() - rem_mult.clone()));
+
+        // ┌────────────────────────────┐
+        // │   Transition Constraints   │
+        // └────────────────────────────┘
+
+        // If this is not the last index for this operation, then:
+        // 1. The next row should be for the same operation on the same tensors.
+        // 2. The index should increment by 1.
+        let not_last = E::F::one() - is_last_idx;
+
+        // Same node ID
+        eval.add_constraint(not_last.clone() * (next_node_id - node_id.clone()));
+
+        // Same tensor IDs
+        eval.add_constraint(not_last.clone() * (next_input_id - input_id.clone()));
+
+        // Index increment by 1
+        eval.add_constraint(not_last * (next_idx - idx.clone() - E::F::one()));
+
+        // ┌─────────────────────────────┐
+        // │   Interaction Constraints   │
+        // └─────────────────────────────┘
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            input_mult.into(),
+            &[input_id, input_idx, input_val],
+        ));
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            out_mult.into(),
+            &[node_id, idx, out_val.clone()],
+        ));
+
+        // Both rem and its gap to 2^s - 1 are non-negative, hence rem lies in [0, 2^s).
+        eval.add_to_relation(RelationEntry::new(
+            &self.range_check_elements,
+            rem_mult.clone().into(),
+            &[rem_val.clone()],
+        ));
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.range_check_elements,
+            rem_mult.clone().into(),
+            &[max_rem - rem_val],
+        ));
+
+        // |out| < 2^(30 - s), so that the division has a single solution modulo P.
+        eval_signed_limbs(
+            &mut eval,
+            &self.range_check_elements,
+            &rem_mult,
+            out_val,
+            out_sign,
+            out_limbs,
+            out_bits(self.fp_scale),
+        );
+
+        eval.finalize_logup();
+
+        eval
+    }
+}
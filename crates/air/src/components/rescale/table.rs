@@ -0,0 +1,299 @@
+use num_traits::{One, Zero};
+use serde::{Deserialize, Serialize};
+use stwo_prover::core::{
+    backend::simd::{
+        conversion::{Pack, Unpack},
+        m31::{PackedM31, N_LANES},
+    },
+    fields::m31::M31,
+};
+
+use crate::{
+    components::{lookups::range_check::N_LIMB_RANGE_CHECKS, TraceColumn},
+    fixed_point::M31_MAGNITUDE_BITS,
+    preprocessed::StructuredRow,
+};
+
+use super::witness::N_TRACE_COLUMNS;
+
+/// Returns the number of range-checked bits of the output magnitude for a shift `s`.
+///
+/// With `|out| < 2^(30 - s)` and `rem` in `[0, 2^s)`, `out * 2^s + rem` spans fewer than P
+/// integers, so that the rounding division has a single solution modulo P.
+pub const fn out_bits(shift: u32) -> u32 {
+    M31_MAGNITUDE_BITS - shift
+}
+
+/// Represents the raw trace data collected for Rescale operations.
+///
+/// Stores rows capturing inputs, rescaled outputs, the rounding remainder,
+/// and metadata for each Rescale operation.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct RescaleTraceTable {
+    /// Vector containing all rows of the Rescale trace.
+    pub table: Vec<RescaleTraceTableRow>,
+}
+
+/// Represents a single row in the `RescaleTraceTable`.
+///
+/// Contains values for evaluating Rescale AIR constraints: state IDs,
+/// input/output values, rounding remainder, the sign and limbs of the output,
+/// and LogUp multiplicities.
+#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RescaleTraceTableRow {
+    /// ID of the current Rescale node.
+    pub node_id: M31,
+    /// ID of the node providing the input.
+    pub input_id: M31,
+    /// Index within the tensor for this operation.
+    pub idx: M31,
+    /// Position of the input value within the input tensor.
+    pub input_idx: M31,
+    /// Flag indicating if this is the last element processed for this node (1 if true, 0 otherwise).
+    pub is_last_idx: M31,
+    /// Value of the input, at the input scale.
+    pub input: M31,
+    /// Value of the output (`round(input * 2^(s_out - s_in))`), at the output scale.
+    pub out: M31,
+    /// Remainder of the rounding division (`(input + 2^(shift - 1)) % 2^shift`).
+    pub rem: M31,
+    /// Sign of the output (1 if negative, 0 otherwise).
+    pub out_sign: M31,
+    /// Low and high limbs of the output magnitude.
+    pub out_limbs: [M31; 2],
+    /// Multiplicity contribution for the LogUp argument (input).
+    pub input_mult: M31,
+    /// Multiplicity contribution for the LogUp argument (output).
+    pub out_mult: M31,
+    /// Multiplicity contribution for the range checks of the remainder and the output limbs.
+    pub rem_mult: M31,
+}
+
+impl RescaleTraceTableRow {
+    /// Creates a default padding row for the Rescale trace.
+    pub(crate) fn padding() -> Self {
+        Self {
+            node_id: M31::zero(),
+            input_id: M31::zero(),
+            idx: M31::zero(),
+            input_idx: M31::zero(),
+            is_last_idx: M31::one(),
+            input: M31::zero(),
+            out: M31::zero(),
+            rem: M31::zero(),
+            out_sign: M31::zero(),
+            out_limbs: [M31::zero(); 2],
+            input_mult: M31::zero(),
+            out_mult: M31::zero(),
+            rem_mult: M31::zero(),
+        }
+    }
+}
+
+/// Implements the `StructuredRow` trait for `RescaleTraceTableRow`.
+impl StructuredRow for RescaleTraceTableRow {
+    /// IDs, indices, flags and multiplicities of the row, fixed by the graph.
+    const STRUCTURE: &'static [&'static str] = &[
+        "node_id",
+        "input_id",
+        "idx",
+        "input_idx",
+        "is_last_idx",
+        "input_mult",
+        "out_mult",
+    ];
+
+    /// Returns the values of the structural columns of the row.
+    fn structure(&self) -> Vec<M31> {
+        vec![
+            self.node_id,
+            self.input_id,
+            self.idx,
+            self.input_idx,
+            self.is_last_idx,
+            self.input_mult,
+            self.out_mult,
+        ]
+    }
+}
+
+/// SIMD-packed representation of a `RescaleTraceTableRow`.
+#[derive(Debug, Copy, Clone)]
+pub struct PackedRescaleTraceTableRow {
+    /// Packed `node_id` values.
+    pub node_id: PackedM31,
+    /// Packed `input_id` values.
+    pub input_id: PackedM31,
+    /// Packed `idx` values.
+    pub idx: PackedM31,
+    /// Packed `input_idx` values.
+    pub input_idx: PackedM31,
+    /// Packed `is_last_idx` values.
+    pub is_last_idx: PackedM31,
+    /// Packed `input` values.
+    pub input: PackedM31,
+    /// Packed `out` values.
+    pub out: PackedM31,
+    /// Packed `rem` values.
+    pub rem: PackedM31,
+    /// Packed `out_sign` values.
+    pub out_sign: PackedM31,
+    /// Packed `out_limbs` values, one packed column per limb.
+    pub out_limbs: [PackedM31; 2],
+    /// Packed `input_mult` values.
+    pub input_mult: PackedM31,
+    /// Packed `out_mult` values.
+    pub out_mult: PackedM31,
+    /// Packed `rem_mult` values.
+    pub rem_mult: PackedM31,
+}
+
+impl Pack for RescaleTraceTableRow {
+    type SimdType = PackedRescaleTraceTableRow;
+
+    fn pack(inputs: [Self; N_LANES]) -> Self::SimdType {
+        PackedRescaleTraceTableRow {
+            node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].node_id)),
+            input_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_id)),
+            idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].idx)),
+            input_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_idx)),
+            is_last_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_idx)),
+            input: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input)),
+            out: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out)),
+            rem: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rem)),
+            out_sign: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_sign)),
+            out_limbs: std::array::from_fn(|b| {
+                PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_limbs[b]))
+            }),
+            input_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_mult)),
+            out_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_mult)),
+            rem_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rem_mult)),
+        }
+    }
+}
+
+impl Unpack for PackedRescaleTraceTableRow {
+    type CpuType = RescaleTraceTableRow;
+
+    fn unpack(self) -> [Self::CpuType; N_LANES] {
+        let (
+            node_id,
+            input_id,
+            idx,
+            input_idx,
+            is_last_idx,
+            input,
+            out,
+            rem,
+            out_sign,
+            input_mult,
+            out_mult,
+            rem_mult,
+        ) = (
+            self.node_id.to_array(),
+            self.input_id.to_array(),
+            self.idx.to_array(),
+            self.input_idx.to_array(),
+            self.is_last_idx.to_array(),
+            self.input.to_array(),
+            self.out.to_array(),
+            self.rem.to_array(),
+            self.out_sign.to_array(),
+            self.input_mult.to_array(),
+            self.out_mult.to_array(),
+            self.rem_mult.to_array(),
+        );
+        let out_limbs = self.out_limbs.map(|b| b.to_array());
+
+        std::array::from_fn(|i| RescaleTraceTableRow {
+            node_id: node_id[i],
+            input_id: input_id[i],
+            idx: idx[i],
+            input_idx: input_idx[i],
+            is_last_idx: is_last_idx[i],
+            input: input[i],
+            out: out[i],
+            rem: rem[i],
+            out_sign: out_sign[i],
+            out_limbs: std::array::from_fn(|b| out_limbs[b][i]),
+            input_mult: input_mult[i],
+            out_mult: out_mult[i],
+            rem_mult: rem_mult[i],
+        })
+    }
+}
+
+impl RescaleTraceTable {
+    /// Creates a new, empty `RescaleTraceTable`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a single row to the trace table.
+    pub fn add_row(&mut self, row: RescaleTraceTableRow) {
+        self.table.push(row);
+    }
+}
+
+/// Enum defining the columns of the Rescale AIR component's trace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum RescaleColumn {
+    /// ID of the current Rescale node.
+    NodeId,
+    /// ID of the node providing the input.
+    InputId,
+    /// Index within the tensor for this operation.
+    Idx,
+    /// Position of the input value within the input tensor.
+    InputIdx,
+    /// Flag indicating if this is the last element processed for this node.
+    IsLastIdx,
+    /// Value of the input, at the input scale.
+    Input,
+    /// Value of the output, at the output scale.
+    Out,
+    /// Remainder of the rounding division.
+    Rem,
+    /// Sign of the output.
+    OutSign,
+    /// The `i`-th limb of the output magnitude.
+    OutLimb(usize),
+    /// Multiplicity for the LogUp argument (input).
+    InputMult,
+    /// Multiplicity for the LogUp argument (output).
+    OutMult,
+    /// Multiplicity for the range checks of the remainder and the output limbs.
+    RemMult,
+}
+
+impl RescaleColumn {
+    /// Returns the 0-based index for this column within the Rescale trace segment.
+    pub const fn index(self) -> usize {
+        match self {
+            Self::NodeId => 0,
+            Self::InputId => 1,
+            Self::Idx => 2,
+            Self::InputIdx => 3,
+            Self::IsLastIdx => 4,
+            Self::Input => 5,
+            Self::Out => 6,
+            Self::Rem => 7,
+            Self::OutSign => 8,
+            Self::OutLimb(i) => 9 + i,
+            Self::InputMult => 11,
+            Self::OutMult => 12,
+            Self::RemMult => 13,
+        }
+    }
+}
+
+/// Implements the `TraceColumn` trait for `RescaleColumn`.
+impl TraceColumn for RescaleColumn {
+    /// Specifies the number of columns used by the Rescale component.
+    /// Returns `(N_TRACE_COLUMNS, 4 + N_LIMB_RANGE_CHECKS)`, indicating the number of main
+    /// trace columns and the interaction trace columns (for input, output, the two remainder
+    /// range checks and those of the output limbs).
+    fn count() -> (usize, usize) {
+        (N_TRACE_COLUMNS, 4 + N_LIMB_RANGE_CHECKS)
+    }
+}
@@ -0,0 +1,246 @@
+use crate::{
+    components::{
+        lookups::range_check::{
+            witness::limb_range_checks, RangeCheckLookupElements, N_LIMB_RANGE_CHECKS,
+        },
+        InteractionClaim, NodeElements, RescaleClaim,
+    },
+    utils::{pack_values, to_trace_order, TreeBuilder},
+};
+use luminair_utils::TraceError;
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use stwo_air_utils::trace::component_trace::ComponentTrace;
+use stwo_air_utils_derive::{IterMut, ParIterMut, Uninitialized};
+use stwo_prover::{
+    constraint_framework::{logup::LogupTraceGenerator, Relation},
+    core::{
+        backend::simd::{
+            m31::{PackedM31, LOG_N_LANES, N_LANES},
+            qm31::PackedQM31,
+            SimdBackend,
+        },
+        fields::m31::M31,
+    },
+};
+
+use super::table::{
+    out_bits, PackedRescaleTraceTableRow, RescaleColumn, RescaleTraceTable, RescaleTraceTableRow,
+};
+
+/// Number of main trace columns for the Rescale component.
+pub(crate) const N_TRACE_COLUMNS: usize = 14;
+
+/// Number of values range-checked by each row of the Rescale component.
+pub(crate) const N_RANGE_CHECKS: usize = 2 + N_LIMB_RANGE_CHECKS;
+
+/// Generates the main trace columns and initial data for interaction claims for the Rescale component.
+///
+/// Takes the raw `RescaleTraceTable`, processes it into the main STARK trace columns
+/// (including input, output and remainder), and prepares `LookupData` for LogUp.
+pub struct ClaimGenerator {
+    /// The raw trace data for Rescale operations.
+    pub inputs: RescaleTraceTable,
+    /// Fixed-point scale of the circuit, which is also the shift of the rescale.
+    pub fp_scale: u32,
+}
+
+impl ClaimGenerator {
+    /// Creates a new `ClaimGenerator` with the given `RescaleTraceTable` and fixed-point scale.
+    pub fn new(inputs: RescaleTraceTable, fp_scale: u32) -> Self {
+        Self { inputs, fp_scale }
+    }
+
+    /// Writes the main trace columns to the `tree_builder` and returns data for interaction phase.
+    ///
+    /// Follows the standard pattern: pads the table, packs rows, calls `write_trace_simd`,
+    /// adds main trace to `tree_builder`, returns `RescaleClaim` and `InteractionClaimGenerator`.
+    /// Returns `TraceError::EmptyTrace` if the input table is empty.
+    pub fn write_trace(
+        mut self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+    ) -> Result<(RescaleClaim, InteractionClaimGenerator), TraceError> {
+        let n_rows = self.inputs.table.len();
+
+        if n_rows == 0 {
+            return Err(TraceError::EmptyTrace);
+        }
+
+        let size = std::cmp::max(n_rows.next_power_of_two(), N_LANES);
+        let log_size = size.ilog2();
+
+        self.inputs
+            .table
+            .resize(size, RescaleTraceTableRow::padding());
+        let packed_inputs = pack_values(&to_trace_order(&self.inputs.table));
+
+        let max_rem = M31::from_u32_unchecked((1 << self.fp_scale) - 1);
+        let (trace, lookup_data) =
+            write_trace_simd(packed_inputs, max_rem, out_bits(self.fp_scale));
+
+        tree_builder.extend_evals(trace.to_evals());
+
+        Ok((
+            RescaleClaim::new(log_size),
+            InteractionClaimGenerator {
+                log_size,
+                lookup_data,
+            },
+        ))
+    }
+}
+
+/// Populates the main trace columns and `LookupData` from SIMD-packed Rescale trace rows.
+///
+/// Processes `PackedRescaleTraceTableRow` data in parallel:
+/// - Maps fields (input, out, rem, etc.) to the corresponding main trace columns.
+/// - Extracts `[id, idx, value]` triples and multiplicities into `LookupData` for the LogUp argument,
+///   along with the remainder and its gap to `max_rem`, and the limbs of the output magnitude
+///   in `[0, 2^out_bits)` with their gaps to their maximal values, for the range checks.
+/// Returns the `ComponentTrace` (main trace columns) and `LookupData`.
+fn write_trace_simd(
+    inputs: Vec<PackedRescaleTraceTableRow>,
+    max_rem: M31,
+    out_bits: u32,
+) -> (ComponentTrace<N_TRACE_COLUMNS>, LookupData) {
+    let log_n_packed_rows = inputs.len().ilog2();
+    let log_size = log_n_packed_rows + LOG_N_LANES;
+
+    let (mut trace, mut lookup_data) = unsafe {
+        (
+            ComponentTrace::<N_TRACE_COLUMNS>::uninitialized(log_size),
+            LookupData::uninitialized(log_n_packed_rows),
+        )
+    };
+
+    let max_rem = PackedM31::broadcast(max_rem);
+
+    (
+        trace.par_iter_mut(),
+        lookup_data.par_iter_mut(),
+        inputs.into_par_iter(),
+    )
+        .into_par_iter()
+        .for_each(|(mut row, lookup_data, input)| {
+            *row[RescaleColumn::NodeId.index()] = input.node_id;
+            *row[RescaleColumn::InputId.index()] = input.input_id;
+            *row[RescaleColumn::Idx.index()] = input.idx;
+            *row[RescaleColumn::InputIdx.index()] = input.input_idx;
+            *row[RescaleColumn::IsLastIdx.index()] = input.is_last_idx;
+            *row[RescaleColumn::Input.index()] = input.input;
+            *row[RescaleColumn::Out.index()] = input.out;
+            *row[RescaleColumn::Rem.index()] = input.rem;
+            *row[RescaleColumn::OutSign.index()] = input.out_sign;
+            for (i, limb) in input.out_limbs.into_iter().enumerate() {
+                *row[RescaleColumn::OutLimb(i).index()] = limb;
+            }
+            *row[RescaleColumn::InputMult.index()] = input.input_mult;
+            *row[RescaleColumn::OutMult.index()] = input.out_mult;
+            *row[RescaleColumn::RemMult.index()] = input.rem_mult;
+
+            *lookup_data.input = [input.input_id, input.input_idx, input.input];
+            *lookup_data.input_mult = input.input_mult;
+            *lookup_data.out = [input.node_id, input.idx, input.out];
+            *lookup_data.out_mult = input.out_mult;
+            let out_range_checks = limb_range_checks(input.out_limbs, out_bits);
+            *lookup_data.range_checks = std::array::from_fn(|i| match i {
+                0 => input.rem,
+                1 => max_rem - input.rem,
+                _ => out_range_checks[i - 2],
+            });
+            *lookup_data.rem_mult = input.rem_mult;
+        });
+
+    (trace, lookup_data)
+}
+
+/// Intermediate data structure holding values and multiplicities for the Rescale LogUp argument.
+///
+/// Stores ID-index-value triples and multiplicities for the input and output terms,
+/// along with the values range-checked by each row.
+/// Derives helper iterators for parallel processing.
+#[derive(Uninitialized, IterMut, ParIterMut)]
+struct LookupData {
+    /// Input ID-index-value triples: `[input_node_id, input_idx, input_value]`.
+    input: Vec<[PackedM31; 3]>,
+    /// Multiplicities for input values.
+    input_mult: Vec<PackedM31>,
+    /// Output ID-index-value triples: `[rescale_node_id, idx, out_value]`.
+    out: Vec<[PackedM31; 3]>,
+    /// Multiplicities for output values.
+    out_mult: Vec<PackedM31>,
+    /// Rounding remainders and their gaps to the maximal remainder, followed by the output
+    /// limbs and their gaps to their maximal values, range-checked through the shared
+    /// range-check lookup.
+    range_checks: Vec<[PackedM31; N_RANGE_CHECKS]>,
+    /// Multiplicities for the range checks.
+    rem_mult: Vec<PackedM31>,
+}
+
+/// Generates the interaction trace columns for the Rescale component's LogUp argument.
+///
+/// Takes `LookupData`, `NodeElements` and `RangeCheckLookupElements` to build the LogUp
+/// interaction columns (input, output, and a column per range check) and adds them to the `tree_builder`.
+pub struct InteractionClaimGenerator {
+    /// Log2 size of the trace.
+    log_size: u32,
+    /// Data (ID-index-value triples and multiplicities) needed for LogUp.
+    lookup_data: LookupData,
+}
+
+impl InteractionClaimGenerator {
+    /// Writes the LogUp interaction trace columns to the `tree_builder`.
+    ///
+    /// Logic is similar to Recip:
+    /// - Creates a `LogupTraceGenerator`.
+    /// - Generates two node columns, writing `multiplicity / denom` fractions.
+    /// - Generates a column per range check, sending `rem`, its gap and the output limbs
+    ///   with their gaps to the range-check lookup.
+    /// - Finalizes the generator, obtaining interaction trace columns and `claimed_sum`.
+    /// - Adds interaction columns to the `tree_builder`.
+    /// - Returns the `InteractionClaim` containing the `claimed_sum`.
+    pub fn write_interaction_trace(
+        self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+        node_elements: &NodeElements,
+        range_check_elements: &RangeCheckLookupElements,
+    ) -> InteractionClaim {
+        let mut logup_gen = LogupTraceGenerator::new(self.log_size);
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let values = &self.lookup_data.input[row];
+            let multiplicity = &self.lookup_data.input_mult[row];
+
+            let denom: PackedQM31 = node_elements.combine(values);
+            col_gen.write_frac(row, (*multiplicity).into(), denom);
+        }
+        col_gen.finalize_col();
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let values = &self.lookup_data.out[row];
+            let multiplicity = &self.lookup_data.out_mult[row];
+
+            let denom: PackedQM31 = node_elements.combine(values);
+            col_gen.write_frac(row, (*multiplicity).into(), denom);
+        }
+        col_gen.finalize_col();
+
+        for i in 0..N_RANGE_CHECKS {
+            let mut col_gen = logup_gen.new_col();
+            for row in 0..1 << (self.log_size - LOG_N_LANES) {
+                let value = self.lookup_data.range_checks[row][i];
+                let multiplicity = self.lookup_data.rem_mult[row];
+
+                let denom: PackedQM31 = range_check_elements.combine(&[value]);
+                col_gen.write_frac(row, multiplicity.into(), denom);
+            }
+            col_gen.finalize_col();
+        }
+
+        let (trace, claimed_sum) = logup_gen.finalize_last();
+        tree_builder.extend_evals(trace);
+
+        InteractionClaim { claimed_sum }
+    }
+}
@@ -0,0 +1,392 @@
+use crate::{
+    components::{
+        lookups::{
+            range_check::{component::eval_limbs, RangeCheckLookupElements},
+            unary::{
+                functions::{Exp, ExpLookupElements},
+                LutFunction,
+            },
+            N_CLAMP_BITS,
+        },
+        unary::component::interpolation_max_values,
+        NodeElements, SoftmaxClaim,
+    },
+    preprocessed::{eval_structure, Interpolation, LookupLayout},
+};
+use num_traits::{One, Zero};
+use stwo_prover::{
+    constraint_framework::{
+        EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry, ORIGINAL_TRACE_IDX,
+    },
+    core::fields::m31::M31,
+};
+
+use super::table::{SoftmaxTraceTableRow, N_DIFF_BITS};
+
+/// The STWO AIR component for row-wise Softmax operations.
+/// Wraps the `SoftmaxEval` logic within the STWO `FrameworkComponent`.
+/// The exponentials are proven through a lookup argument into the preprocessed Exp LUT.
+pub type SoftmaxComponent = FrameworkComponent<SoftmaxEval>;
+
+/// Defines the AIR constraints evaluation logic for the Softmax component.
+/// Implements `FrameworkEval` to define trace layout, degrees, and constraints.
+///
+/// Each reduction along the last dimension spans consecutive rows, one per input `x`.
+/// The output is `out = floor(e^(x - max) * recip / 2^s)`, with `e^(x - max)` looked up in the
+/// Exp LUT and `recip = floor(2^(2s) / total)` the single reciprocal of the reduction.
+pub struct SoftmaxEval {
+    /// Log2 size of the component's main trace segment.
+    log_size: u32,
+    /// Log2 size of the largest preprocessed table used, the Exp LUT or the range check.
+    lut_log_size: u32,
+    /// Smallest value of the Exp LUT, to which inputs below the table are clamped.
+    lut_min: M31,
+    /// Largest value of the Exp LUT, to which inputs above the table are clamped.
+    lut_max: M31,
+    /// Fixed-point scale of the Exp LUT, at which interpolated exponentials are rounded.
+    lut_scale: u32,
+    /// Interpolation of the Exp LUT, or `None` if every value is an entry.
+    interpolation: Option<Interpolation>,
+    /// Interaction elements for node relations (used in input/output LogUp).
+    node_elements: NodeElements,
+    /// Interaction elements for the Exp LUT LogUp.
+    lookup_elements: ExpLookupElements,
+    /// Interaction elements for the shared range-check lookup.
+    range_check_elements: RangeCheckLookupElements,
+    /// Fixed-point scale of the circuit.
+    fp_scale: u32,
+}
+
+impl SoftmaxEval {
+    /// Creates a new `SoftmaxEval` instance.
+    /// Takes the component's claim, interaction elements for nodes and lookups, the layout of
+    /// the Exp LUT, the log_size of the largest preprocessed table used and the fixed-point scale.
+    pub fn new(
+        claim: &SoftmaxClaim,
+        node_elements: NodeElements,
+        lookup_elements: ExpLookupElements,
+        range_check_elements: RangeCheckLookupElements,
+        layout: &LookupLayout,
+        lut_log_size: u32,
+        fp_scale: u32,
+    ) -> Self {
+        let (lut_min, lut_max) = layout.bounds().unwrap();
+        Self {
+            log_size: claim.log_size,
+            lut_log_size,
+            lut_min: lut_min.to_m31(),
+            lut_max: lut_max.to_m31(),
+            lut_scale: layout.scale,
+            interpolation: layout.interpolation,
+            node_elements,
+            lookup_elements,
+            range_check_elements,
+            fp_scale,
+        }
+    }
+}
+
+/// Implements the core constraint evaluation logic for the Softmax component.
+impl FrameworkEval for SoftmaxEval {
+    /// Returns the log2 size of this component's main trace segment.
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// Returns the max log2 degree bound, considering both main trace and LUT sizes.
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        std::cmp::max(self.log_size, self.lut_log_size) + 1
+    }
+
+    /// Evaluates the Softmax AIR constraints on a given evaluation point (`eval`).
+    ///
+    /// Defines constraints for:
+    /// - **Structural:** The IDs, indices, flags and multiplicities match the preprocessed trace.
+    ///   The lookup and range-check multiplicities are set on every row of a reduction, as the
+    ///   maximum and the total are shared by its rows, whether they emit their output or not.
+    /// - **Maximum:** `diff = max - input_val` lies in `[0, 2^N_DIFF_BITS)`, so `max` bounds
+    ///   every input, and exactly one input of the reduction, flagged by `is_max`, equals it.
+    /// - **Clamp:** Ensures the looked-up `lut_input` is `input_val - max`, or the LUT bound that
+    ///   it lies beyond, witnessed by a distance decomposed into `N_CLAMP_BITS` bits.
+    /// - **Interpolation:** Ensures `(exp - base_exp) * 2^s + exp_rem = slope * offset`.
+    ///   In an exact LUT, `offset` and `exp_rem` are zero, hence `exp = base_exp`.
+    /// - **Normalization:**
+    ///   - Accumulator update: `next_sum = sum + exp`, equal to `total` on the last step.
+    ///   - Reciprocal: `recip * total + recip_rem = scale^2`.
+    ///   - Output: `out * scale + out_rem = exp * recip`.
+    ///   - Rows emitting their output use the circuit scale, and look their exponential up.
+    /// - **Transition:**
+    ///   - Within a reduction, `max`, `total` and `scale` are constant, and the running sum and
+    ///     count of maxima carry over. Both restart from 0 once the reduction ends.
+    ///   - When `is_last_idx` is false, node and input tensor IDs remain the same, the index
+    ///     increments by 1 and the scale is constant.
+    /// - **Interaction (LogUp):** Links `input_val` and `out_val` to the global LogUp argument,
+    ///   and the entry `(lut_input - offset, base_exp[, slope])` to the Exp LUT.
+    ///   The interpolation values are sent to the shared range-check lookup along with their
+    ///   gaps to their bounds, as in the unary component, as well as `out_rem` in `[0, scale)`,
+    ///   `recip_rem` in `[0, total)`, `recip` and `out` in `[0, scale]`. The limbs of `diff`
+    ///   are range-checked in the same way.
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        // IDs (current and next row, for transition constraints)
+        // ID of the node in the computational graph.
+        let [node_id, next_node_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of the input tensor.
+        let [input_id, next_input_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Index in the flattened tensor.
+        let [idx, next_idx] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Position of the input value within its tensor.
+        let input_idx = eval.next_trace_mask();
+        let is_last_idx = eval.next_trace_mask(); // Flag if this is the last index for this operation.
+
+        // Values for consistency constraints
+        let input_val = eval.next_trace_mask(); // Value from the tensor at index.
+        let out_val = eval.next_trace_mask(); // Value in output tensor at index.
+
+        // Maximum of the reduction, and the one of the next row.
+        let [max_val, next_row_max_val] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        let diff = eval.next_trace_mask(); // Non-negative difference max - input.
+        let diff_limbs: [E::F; 2] = std::array::from_fn(|_| eval.next_trace_mask()); // Limbs of diff.
+        let is_max = eval.next_trace_mask(); // Flag if the input is the one the max is taken from.
+
+        // Count of flagged inputs before this row, and the one of the next row.
+        let [max_count, next_row_max_count] =
+            eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        let next_max_count = eval.next_trace_mask(); // Count of flagged inputs after this row.
+
+        // Clamp of the input to the LUT bounds
+        let lut_input = eval.next_trace_mask(); // Value looked up in the LUT.
+        let clamp_low = eval.next_trace_mask(); // Flag if the input lies below the LUT.
+        let clamp_high = eval.next_trace_mask(); // Flag if the input lies above the LUT.
+        let clamp_dist = eval.next_trace_mask(); // Distance beyond the LUT bound, minus one.
+        let clamp_dist_bits: [E::F; N_CLAMP_BITS] = std::array::from_fn(|_| eval.next_trace_mask());
+
+        // Interpolation from the LUT entry
+        let offset = eval.next_trace_mask(); // Distance from the breakpoint of the entry.
+        let base_exp = eval.next_trace_mask(); // Output of the entry.
+        let slope = eval.next_trace_mask(); // Slope of the entry.
+        let exp_rem = eval.next_trace_mask(); // Remainder of the rounding of slope * offset.
+        let exp = eval.next_trace_mask(); // Exponential of input - max.
+
+        // Sum of the exponentials before this row, and the one of the next row.
+        let [sum, next_row_sum] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        let next_sum = eval.next_trace_mask(); // Sum of the exponentials after this row.
+
+        // Sum of all the exponentials of the reduction, and the one of the next row.
+        let [total, next_row_total] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        let is_last_step = eval.next_trace_mask(); // Flag if this is the last step.
+
+        // Normalization
+        let recip = eval.next_trace_mask(); // Reciprocal of the total.
+        let recip_rem = eval.next_trace_mask(); // Remainder of the reciprocal.
+        let out_rem = eval.next_trace_mask(); // Remainder of the output.
+        let [scale, next_scale] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+
+        // Multiplicities for interaction constraints
+        let input_mult = eval.next_trace_mask();
+        let out_mult = eval.next_trace_mask();
+        let lookup_mult = eval.next_trace_mask();
+        let range_check_mult = eval.next_trace_mask();
+
+        // ┌─────────────────────────────┐
+        // │   Structural Constraints    │
+        // └─────────────────────────────┘
+
+        // The IDs, indices, flags and multiplicities are the ones fixed by the graph.
+        eval_structure::<_, SoftmaxTraceTableRow>(
+            &mut eval,
+            "softmax",
+            vec![
+                node_id.clone(),
+                input_id.clone(),
+                idx.clone(),
+                input_idx.clone(),
+                is_last_idx.clone(),
+                is_last_step.clone(),
+                input_mult.clone(),
+                out_mult.clone(),
+                lookup_mult.clone(),
+                range_check_mult.clone(),
+            ],
+        );
+
+        // ┌─────────────────────────────┐
+        // │   Consistency Constraints   │
+        // └─────────────────────────────┘
+
+        // The is_last_idx, is_last_step and is_max flags are either 0 or 1.
+        eval.add_constraint(is_last_idx.clone() * (is_last_idx.clone() - E::F::one()));
+        eval.add_constraint(is_last_step.clone() * (is_last_step.clone() - E::F::one()));
+        eval.add_constraint(is_max.clone() * (is_max.clone() - E::F::one()));
+
+        // The difference to the max, range-checked through its limbs below.
+        eval.add_constraint(diff.clone() - (max_val.clone() - input_val.clone()));
+
+        // The flagged input equals the max, and exactly one input of the reduction is flagged.
+        eval.add_constraint(is_max.clone() * diff.clone());
+        eval.add_constraint(next_max_count.clone() - (max_count + is_max));
+        eval.add_constraint(is_last_step.clone() * (next_max_count.clone() - E::F::one()));
+
+        // The clamp flags are boolean and exclusive.
+        eval.add_constraint(clamp_low.clone() * (clamp_low.clone() - E::F::one()));
+        eval.add_constraint(clamp_high.clone() * (clamp_high.clone() - E::F::one()));
+        eval.add_constraint(clamp_low.clone() * clamp_high.clone());
+
+        // An input that is not clamped is looked up as is, at input_val - max = -diff.
+        let not_clamped = E::F::one() - clamp_low.clone() - clamp_high.clone();
+        eval.add_constraint(not_clamped.clone() * (lut_input.clone() + diff.clone()));
+        eval.add_constraint(not_clamped * clamp_dist.clone());
+
+        // A clamped input is looked up at the LUT bound it lies beyond, by clamp_dist + 1.
+        let lut_min = E::F::from(self.lut_min);
+        let lut_max = E::F::from(self.lut_max);
+        eval.add_constraint(clamp_low.clone() * (lut_input.clone() - lut_min.clone()));
+        eval.add_constraint(clamp_high.clone() * (lut_input.clone() - lut_max.clone()));
+        eval.add_constraint(
+            clamp_low * (lut_min - E::F::one() + diff.clone() - clamp_dist.clone()),
+        );
+        eval.add_constraint(
+            clamp_high * (E::F::zero() - diff.clone() - lut_max - E::F::one() - clamp_dist.clone()),
+        );
+
+        // The clamp distance is non-negative: it decomposes into N_CLAMP_BITS boolean bits.
+        let mut recomposed = E::F::zero();
+        for (i, bit) in clamp_dist_bits.into_iter().enumerate() {
+            eval.add_constraint(bit.clone() * (bit.clone() - E::F::one()));
+            recomposed = recomposed + bit * E::F::from(M31::from_u32_unchecked(1 << i));
+        }
+        eval.add_constraint(clamp_dist - recomposed);
+
+        // The exponential is interpolated from the entry, rounded down at the scale of the LUT.
+        let divisor = E::F::from(M31::from_u32_unchecked(1 << self.lut_scale));
+        let increment = exp.clone() - base_exp.clone();
+        eval.add_constraint(
+            increment.clone() * divisor + exp_rem.clone() - slope.clone() * offset.clone(),
+        );
+
+        // An exact LUT holds every value: the exponential is the one of the entry.
+        if self.interpolation.is_none() {
+            eval.add_constraint(offset.clone());
+            eval.add_constraint(exp_rem.clone());
+        }
+
+        // The exponentials are summed, and the last sum of the reduction is its total.
+        eval.add_constraint(next_sum.clone() - (sum + exp.clone()));
+        eval.add_constraint(is_last_step.clone() * (next_sum.clone() - total.clone()));
+
+        // Rows emitting their output use the circuit scale.
+        // Padding rows emit nothing and hold a zero scale.
+        let circuit_scale = E::F::from(M31::from_u32_unchecked(1 << self.fp_scale));
+        eval.add_constraint(out_mult.clone() * (scale.clone() - circuit_scale));
+
+        // The lookup and range-check multiplicities are boolean, and set on every row emitting
+        // its output.
+        eval.add_constraint(lookup_mult.clone() * (lookup_mult.clone() - E::F::one()));
+        eval.add_constraint(out_mult.clone() * (E::F::one() - lookup_mult.clone()));
+        eval.add_constraint(range_check_mult.clone() * (range_check_mult.clone() - E::F::one()));
+        eval.add_constraint(out_mult.clone() * (E::F::one() - range_check_mult.clone()));
+
+        // The reciprocal of the total is rounded down: recip * total + recip_rem = scale^2.
+        eval.add_constraint(
+            recip.clone() * total.clone() + recip_rem.clone() - scale.clone() * scale.clone(),
+        );
+
+        // The output is rounded down: out * scale + out_rem = exp * recip.
+        eval.add_constraint(
+            out_val.clone() * scale.clone() + out_rem.clone() - exp * recip.clone(),
+        );
+
+        // ┌────────────────────────────┐
+        // │   Transition Constraints   │
+        // └────────────────────────────┘
+
+        // Within a reduction, the max and the total are the same on every row, and the running
+        // sum and count carry over. They restart from 0 after the last step.
+        let not_last_step = E::F::one() - is_last_step;
+        eval.add_constraint(not_last_step.clone() * (next_row_max_val - max_val));
+        eval.add_constraint(not_last_step.clone() * (next_row_total - total.clone()));
+        eval.add_constraint(next_row_sum - not_last_step.clone() * next_sum);
+        eval.add_constraint(next_row_max_count - not_last_step * next_max_count);
+
+        // If this is not the last index for this operation, then:
+        // 1. The next row should be for the same operation on the same tensors, at the same scale.
+        // 2. The index should increment by 1.
+        let not_last = E::F::one() - is_last_idx;
+
+        // Same node ID
+        eval.add_constraint(not_last.clone() * (next_node_id - node_id.clone()));
+
+        // Same tensor IDs
+        eval.add_constraint(not_last.clone() * (next_input_id - input_id.clone()));
+
+        // Same scale
+        eval.add_constraint(not_last.clone() * (next_scale - scale.clone()));
+
+        // Index increment by 1
+        eval.add_constraint(not_last * (next_idx - idx.clone() - E::F::one()));
+
+        // ┌─────────────────────────────┐
+        // │   Interaction Constraints   │
+        // └─────────────────────────────┘
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            input_mult.into(),
+            &[input_id, input_idx, input_val],
+        ));
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            out_mult.into(),
+            &[node_id, idx, out_val.clone()],
+        ));
+
+        let breakpoint = lut_input - offset.clone();
+        let entry = if self.interpolation.is_some() {
+            vec![breakpoint, base_exp, slope]
+        } else {
+            vec![breakpoint, base_exp]
+        };
+        Exp::add_to_relation(&mut eval, &self.lookup_elements, lookup_mult.into(), &entry);
+
+        // Both offset and its gap to 2^step_bits - 1 are non-negative, hence offset lies in
+        // [0, 2^step_bits). Likewise for exp_rem in [0, 2^s), the shifted increment,
+        // out_rem in [0, scale), recip_rem in [0, total) and out in [0, scale].
+        let [max_offset, max_rem, max_delta] =
+            interpolation_max_values(self.interpolation, self.lut_scale).map(E::F::from);
+        for value in [
+            offset.clone(),
+            max_offset - offset,
+            exp_rem.clone(),
+            max_rem - exp_rem,
+            max_delta.clone() + increment.clone(),
+            max_delta - increment,
+            out_rem.clone(),
+            scale.clone() - E::F::one() - out_rem,
+            recip_rem.clone(),
+            total - E::F::one() - recip_rem,
+            recip,
+            out_val.clone(),
+            scale - out_val,
+        ] {
+            eval.add_to_relation(RelationEntry::new(
+                &self.range_check_elements,
+                range_check_mult.clone().into(),
+                &[value],
+            ));
+        }
+
+        // max - input lies in [0, 2^N_DIFF_BITS).
+        eval_limbs(
+            &mut eval,
+            &self.range_check_elements,
+            &range_check_mult,
+            diff,
+            diff_limbs,
+            N_DIFF_BITS,
+        );
+
+        eval.finalize_logup();
+
+        eval
+    }
+}
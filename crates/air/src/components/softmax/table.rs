@@ -0,0 +1,577 @@
+use num_traits::{One, Zero};
+use serde::{Deserialize, Serialize};
+use stwo_prover::core::{
+    backend::simd::{
+        conversion::{Pack, Unpack},
+        m31::{PackedM31, N_LANES},
+    },
+    fields::m31::M31,
+};
+
+use crate::{
+    components::{lookups::N_CLAMP_BITS, TraceColumn},
+    preprocessed::StructuredRow,
+};
+
+use super::witness::{N_RANGE_CHECKS, N_TRACE_COLUMNS};
+
+/// Number of bits of the difference between the maximum and each input.
+///
+/// The difference is range-checked to `[0, 2^N_DIFF_BITS)` through two limbs.
+pub const N_DIFF_BITS: u32 = 29;
+
+/// Represents the raw trace data collected for Softmax operations.
+///
+/// Stores one row per input element, grouped by reduction along the last dimension.
+/// Each row carries the maximum and the sum of the exponentials of its reduction, so that
+/// the whole row-wise softmax is proven in a single trace.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct SoftmaxTraceTable {
+    /// Vector containing all rows of the Softmax trace.
+    pub table: Vec<SoftmaxTraceTableRow>,
+}
+
+/// Represents a single row in the `SoftmaxTraceTable`.
+///
+/// Contains values for evaluating the Softmax AIR constraints: state IDs, input/output values,
+/// the maximum of the reduction and its witnesses, the Exp LUT entry and its interpolation,
+/// the running sum of the exponentials, the reciprocal of their total with its remainder,
+/// and multiplicities for LogUp (input/output), LUT interaction and range checks.
+#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SoftmaxTraceTableRow {
+    /// ID of the current Softmax node.
+    pub node_id: M31,
+    /// ID of the node providing the input.
+    pub input_id: M31,
+    /// Index within the tensor for this operation.
+    pub idx: M31,
+    /// Position of the input value within the input tensor.
+    pub input_idx: M31,
+    /// Flag indicating if this is the last element processed for this node (1 if true, 0 otherwise).
+    pub is_last_idx: M31,
+    /// Value of the input (`x`).
+    pub input: M31,
+    /// Value of the output (`e^(x - max) / total`).
+    pub out: M31,
+    /// Maximum of the inputs of the reduction.
+    pub max: M31,
+    /// Non-negative difference `max - x`.
+    pub diff: M31,
+    /// Low and high limbs of the difference.
+    pub diff_limbs: [M31; 2],
+    /// Flag indicating if the input is the one the maximum is taken from.
+    pub is_max: M31,
+    /// Number of inputs flagged as the maximum *before* this row.
+    pub max_count: M31,
+    /// Number of inputs flagged as the maximum *after* this row (`max_count + is_max`).
+    pub next_max_count: M31,
+    /// Value looked up in the Exp LUT: `x - max`, or the table bound it is clamped to.
+    pub lut_input: M31,
+    /// Flag indicating if `x - max` is clamped to the smallest value of the LUT.
+    pub clamp_low: M31,
+    /// Flag indicating if `x - max` is clamped to the largest value of the LUT.
+    pub clamp_high: M31,
+    /// Distance of a clamped input beyond the table bound, minus one.
+    pub clamp_dist: M31,
+    /// Bits of the clamp distance, little-endian.
+    pub clamp_dist_bits: [M31; N_CLAMP_BITS],
+    /// Distance between the looked up value and the breakpoint of its LUT entry.
+    pub offset: M31,
+    /// Output of the LUT entry (`e^(x_i)`).
+    pub base_exp: M31,
+    /// Slope of the LUT entry, zero in an exact LUT.
+    pub slope: M31,
+    /// Remainder of the rounding of `slope * offset` down to the scale of the LUT.
+    pub exp_rem: M31,
+    /// Exponential of the input, `e^(x - max)`.
+    pub exp: M31,
+    /// Sum of the exponentials of the reduction *before* this row.
+    pub sum: M31,
+    /// Sum of the exponentials of the reduction *after* this row (`sum + exp`).
+    pub next_sum: M31,
+    /// Sum of all the exponentials of the reduction.
+    pub total: M31,
+    /// Flag indicating if this is the last input of the reduction (1 if true, 0 otherwise).
+    pub is_last_step: M31,
+    /// Reciprocal of the total, `2^(2 * scale) / total` rounded down.
+    pub recip: M31,
+    /// Remainder of the reciprocal, `2^(2 * scale) - recip * total`.
+    pub recip_rem: M31,
+    /// Remainder of the product, `exp * recip - out * 2^scale`.
+    pub out_rem: M31,
+    /// Fixed-point scale factor, `2^scale`.
+    pub scale: M31,
+    /// Multiplicity contribution for the LogUp argument (input).
+    pub input_mult: M31,
+    /// Multiplicity contribution for the LogUp argument (output).
+    pub out_mult: M31,
+    /// Multiplicity contribution for the Exp LUT interaction.
+    pub lookup_mult: M31,
+    /// Multiplicity contribution for the range checks.
+    pub range_check_mult: M31,
+}
+
+impl SoftmaxTraceTableRow {
+    /// Creates a padding row for the Softmax trace.
+    ///
+    /// Padding rows end their own reduction, holding the maximum as their only input.
+    pub(crate) fn padding() -> Self {
+        Self {
+            node_id: M31::zero(),
+            input_id: M31::zero(),
+            idx: M31::zero(),
+            input_idx: M31::zero(),
+            is_last_idx: M31::one(),
+            input: M31::zero(),
+            out: M31::zero(),
+            max: M31::zero(),
+            diff: M31::zero(),
+            diff_limbs: [M31::zero(); 2],
+            is_max: M31::one(),
+            max_count: M31::zero(),
+            next_max_count: M31::one(),
+            lut_input: M31::zero(),
+            clamp_low: M31::zero(),
+            clamp_high: M31::zero(),
+            clamp_dist: M31::zero(),
+            clamp_dist_bits: [M31::zero(); N_CLAMP_BITS],
+            offset: M31::zero(),
+            base_exp: M31::zero(),
+            slope: M31::zero(),
+            exp_rem: M31::zero(),
+            exp: M31::zero(),
+            sum: M31::zero(),
+            next_sum: M31::zero(),
+            total: M31::zero(),
+            is_last_step: M31::one(),
+            recip: M31::zero(),
+            recip_rem: M31::zero(),
+            out_rem: M31::zero(),
+            scale: M31::zero(),
+            input_mult: M31::zero(),
+            out_mult: M31::zero(),
+            lookup_mult: M31::zero(),
+            range_check_mult: M31::zero(),
+        }
+    }
+}
+
+/// Implements the `StructuredRow` trait for `SoftmaxTraceTableRow`.
+impl StructuredRow for SoftmaxTraceTableRow {
+    /// IDs, indices, flags and multiplicities of the row, fixed by the graph.
+    const STRUCTURE: &'static [&'static str] = &[
+        "node_id",
+        "input_id",
+        "idx",
+        "input_idx",
+        "is_last_idx",
+        "is_last_step",
+        "input_mult",
+        "out_mult",
+        "lookup_mult",
+        "range_check_mult",
+    ];
+
+    /// Returns the values of the structural columns of the row.
+    fn structure(&self) -> Vec<M31> {
+        vec![
+            self.node_id,
+            self.input_id,
+            self.idx,
+            self.input_idx,
+            self.is_last_idx,
+            self.is_last_step,
+            self.input_mult,
+            self.out_mult,
+            self.lookup_mult,
+            self.range_check_mult,
+        ]
+    }
+}
+
+/// SIMD-packed representation of a `SoftmaxTraceTableRow`.
+#[derive(Debug, Copy, Clone)]
+pub struct PackedSoftmaxTraceTableRow {
+    /// Packed `node_id` values.
+    pub node_id: PackedM31,
+    /// Packed `input_id` values.
+    pub input_id: PackedM31,
+    /// Packed `idx` values.
+    pub idx: PackedM31,
+    /// Packed `input_idx` values.
+    pub input_idx: PackedM31,
+    /// Packed `is_last_idx` values.
+    pub is_last_idx: PackedM31,
+    /// Packed `input` values.
+    pub input: PackedM31,
+    /// Packed `out` values.
+    pub out: PackedM31,
+    /// Packed `max` values.
+    pub max: PackedM31,
+    /// Packed `diff` values.
+    pub diff: PackedM31,
+    /// Packed `diff_limbs` values, one packed column per limb.
+    pub diff_limbs: [PackedM31; 2],
+    /// Packed `is_max` values.
+    pub is_max: PackedM31,
+    /// Packed `max_count` values.
+    pub max_count: PackedM31,
+    /// Packed `next_max_count` values.
+    pub next_max_count: PackedM31,
+    /// Packed `lut_input` values.
+    pub lut_input: PackedM31,
+    /// Packed `clamp_low` values.
+    pub clamp_low: PackedM31,
+    /// Packed `clamp_high` values.
+    pub clamp_high: PackedM31,
+    /// Packed `clamp_dist` values.
+    pub clamp_dist: PackedM31,
+    /// Packed `clamp_dist_bits` values.
+    pub clamp_dist_bits: [PackedM31; N_CLAMP_BITS],
+    /// Packed `offset` values.
+    pub offset: PackedM31,
+    /// Packed `base_exp` values.
+    pub base_exp: PackedM31,
+    /// Packed `slope` values.
+    pub slope: PackedM31,
+    /// Packed `exp_rem` values.
+    pub exp_rem: PackedM31,
+    /// Packed `exp` values.
+    pub exp: PackedM31,
+    /// Packed `sum` values.
+    pub sum: PackedM31,
+    /// Packed `next_sum` values.
+    pub next_sum: PackedM31,
+    /// Packed `total` values.
+    pub total: PackedM31,
+    /// Packed `is_last_step` values.
+    pub is_last_step: PackedM31,
+    /// Packed `recip` values.
+    pub recip: PackedM31,
+    /// Packed `recip_rem` values.
+    pub recip_rem: PackedM31,
+    /// Packed `out_rem` values.
+    pub out_rem: PackedM31,
+    /// Packed `scale` values.
+    pub scale: PackedM31,
+    /// Packed `input_mult` values.
+    pub input_mult: PackedM31,
+    /// Packed `out_mult` values.
+    pub out_mult: PackedM31,
+    /// Packed `lookup_mult` values.
+    pub lookup_mult: PackedM31,
+    /// Packed `range_check_mult` values.
+    pub range_check_mult: PackedM31,
+}
+
+impl Pack for SoftmaxTraceTableRow {
+    type SimdType = PackedSoftmaxTraceTableRow;
+
+    fn pack(inputs: [Self; N_LANES]) -> Self::SimdType {
+        PackedSoftmaxTraceTableRow {
+            node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].node_id)),
+            input_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_id)),
+            idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].idx)),
+            input_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_idx)),
+            is_last_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_idx)),
+            input: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input)),
+            out: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out)),
+            max: PackedM31::from_array(std::array::from_fn(|i| inputs[i].max)),
+            diff: PackedM31::from_array(std::array::from_fn(|i| inputs[i].diff)),
+            diff_limbs: std::array::from_fn(|b| {
+                PackedM31::from_array(std::array::from_fn(|i| inputs[i].diff_limbs[b]))
+            }),
+            is_max: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_max)),
+            max_count: PackedM31::from_array(std::array::from_fn(|i| inputs[i].max_count)),
+            next_max_count: PackedM31::from_array(std::array::from_fn(|i| {
+                inputs[i].next_max_count
+            })),
+            lut_input: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lut_input)),
+            clamp_low: PackedM31::from_array(std::array::from_fn(|i| inputs[i].clamp_low)),
+            clamp_high: PackedM31::from_array(std::array::from_fn(|i| inputs[i].clamp_high)),
+            clamp_dist: PackedM31::from_array(std::array::from_fn(|i| inputs[i].clamp_dist)),
+            clamp_dist_bits: std::array::from_fn(|b| {
+                PackedM31::from_array(std::array::from_fn(|i| inputs[i].clamp_dist_bits[b]))
+            }),
+            offset: PackedM31::from_array(std::array::from_fn(|i| inputs[i].offset)),
+            base_exp: PackedM31::from_array(std::array::from_fn(|i| inputs[i].base_exp)),
+            slope: PackedM31::from_array(std::array::from_fn(|i| inputs[i].slope)),
+            exp_rem: PackedM31::from_array(std::array::from_fn(|i| inputs[i].exp_rem)),
+            exp: PackedM31::from_array(std::array::from_fn(|i| inputs[i].exp)),
+            sum: PackedM31::from_array(std::array::from_fn(|i| inputs[i].sum)),
+            next_sum: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_sum)),
+            total: PackedM31::from_array(std::array::from_fn(|i| inputs[i].total)),
+            is_last_step: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_step)),
+            recip: PackedM31::from_array(std::array::from_fn(|i| inputs[i].recip)),
+            recip_rem: PackedM31::from_array(std::array::from_fn(|i| inputs[i].recip_rem)),
+            out_rem: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_rem)),
+            scale: PackedM31::from_array(std::array::from_fn(|i| inputs[i].scale)),
+            input_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_mult)),
+            out_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_mult)),
+            lookup_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lookup_mult)),
+            range_check_mult: PackedM31::from_array(std::array::from_fn(|i| {
+                inputs[i].range_check_mult
+            })),
+        }
+    }
+}
+
+impl Unpack for PackedSoftmaxTraceTableRow {
+    type CpuType = SoftmaxTraceTableRow;
+
+    fn unpack(self) -> [Self::CpuType; N_LANES] {
+        let (
+            node_id,
+            input_id,
+            idx,
+            input_idx,
+            is_last_idx,
+            input,
+            out,
+            max,
+            diff,
+            diff_limbs,
+            is_max,
+            max_count,
+            next_max_count,
+            lut_input,
+            clamp_low,
+            clamp_high,
+            clamp_dist,
+            clamp_dist_bits,
+            offset,
+            base_exp,
+            slope,
+            exp_rem,
+            exp,
+            sum,
+            next_sum,
+            total,
+            is_last_step,
+            recip,
+            recip_rem,
+            out_rem,
+            scale,
+            input_mult,
+            out_mult,
+            lookup_mult,
+            range_check_mult,
+        ) = (
+            self.node_id.to_array(),
+            self.input_id.to_array(),
+            self.idx.to_array(),
+            self.input_idx.to_array(),
+            self.is_last_idx.to_array(),
+            self.input.to_array(),
+            self.out.to_array(),
+            self.max.to_array(),
+            self.diff.to_array(),
+            self.diff_limbs.map(|b| b.to_array()),
+            self.is_max.to_array(),
+            self.max_count.to_array(),
+            self.next_max_count.to_array(),
+            self.lut_input.to_array(),
+            self.clamp_low.to_array(),
+            self.clamp_high.to_array(),
+            self.clamp_dist.to_array(),
+            self.clamp_dist_bits.map(|b| b.to_array()),
+            self.offset.to_array(),
+            self.base_exp.to_array(),
+            self.slope.to_array(),
+            self.exp_rem.to_array(),
+            self.exp.to_array(),
+            self.sum.to_array(),
+            self.next_sum.to_array(),
+            self.total.to_array(),
+            self.is_last_step.to_array(),
+            self.recip.to_array(),
+            self.recip_rem.to_array(),
+            self.out_rem.to_array(),
+            self.scale.to_array(),
+            self.input_mult.to_array(),
+            self.out_mult.to_array(),
+            self.lookup_mult.to_array(),
+            self.range_check_mult.to_array(),
+        );
+
+        std::array::from_fn(|i| SoftmaxTraceTableRow {
+            node_id: node_id[i],
+            input_id: input_id[i],
+            idx: idx[i],
+            input_idx: input_idx[i],
+            is_last_idx: is_last_idx[i],
+            input: input[i],
+            out: out[i],
+            max: max[i],
+            diff: diff[i],
+            diff_limbs: diff_limbs.map(|b| b[i]),
+            is_max: is_max[i],
+            max_count: max_count[i],
+            next_max_count: next_max_count[i],
+            lut_input: lut_input[i],
+            clamp_low: clamp_low[i],
+            clamp_high: clamp_high[i],
+            clamp_dist: clamp_dist[i],
+            clamp_dist_bits: clamp_dist_bits.map(|b| b[i]),
+            offset: offset[i],
+            base_exp: base_exp[i],
+            slope: slope[i],
+            exp_rem: exp_rem[i],
+            exp: exp[i],
+            sum: sum[i],
+            next_sum: next_sum[i],
+            total: total[i],
+            is_last_step: is_last_step[i],
+            recip: recip[i],
+            recip_rem: recip_rem[i],
+            out_rem: out_rem[i],
+            scale: scale[i],
+            input_mult: input_mult[i],
+            out_mult: out_mult[i],
+            lookup_mult: lookup_mult[i],
+            range_check_mult: range_check_mult[i],
+        })
+    }
+}
+
+impl SoftmaxTraceTable {
+    /// Creates a new, empty `SoftmaxTraceTable`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a single row to the trace table.
+    pub fn add_row(&mut self, row: SoftmaxTraceTableRow) {
+        self.table.push(row);
+    }
+}
+
+/// Enum defining the columns of the Softmax AIR component's trace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SoftmaxColumn {
+    /// ID of the current node.
+    NodeId,
+    /// ID of the node providing the input.
+    InputId,
+    /// Index within the tensor for this operation.
+    Idx,
+    /// Position of the input value within the input tensor.
+    InputIdx,
+    /// Flag indicating if this is the last element processed for this node.
+    IsLastIdx,
+    /// Value of the input (`x`).
+    Input,
+    /// Value of the output (`e^(x - max) / total`).
+    Out,
+    /// Maximum of the inputs of the reduction.
+    Max,
+    /// Non-negative difference `max - x`.
+    Diff,
+    /// The `i`-th limb of the difference.
+    DiffLimb(usize),
+    /// Flag indicating if the input is the one the maximum is taken from.
+    IsMax,
+    /// Number of inputs flagged as the maximum before this row.
+    MaxCount,
+    /// Number of inputs flagged as the maximum after this row.
+    NextMaxCount,
+    /// Value looked up in the Exp LUT.
+    LutInput,
+    /// Flag indicating if `x - max` is clamped to the smallest value of the LUT.
+    ClampLow,
+    /// Flag indicating if `x - max` is clamped to the largest value of the LUT.
+    ClampHigh,
+    /// Distance of a clamped input beyond the table bound, minus one.
+    ClampDist,
+    /// The `i`-th bit of the clamp distance.
+    ClampDistBit(usize),
+    /// Distance between the looked up value and the breakpoint of its LUT entry.
+    Offset,
+    /// Output of the LUT entry.
+    BaseExp,
+    /// Slope of the LUT entry.
+    Slope,
+    /// Remainder of the rounding of `slope * offset`.
+    ExpRem,
+    /// Exponential of the input, `e^(x - max)`.
+    Exp,
+    /// Sum of the exponentials before this row.
+    Sum,
+    /// Sum of the exponentials after this row.
+    NextSum,
+    /// Sum of all the exponentials of the reduction.
+    Total,
+    /// Flag indicating if this is the last input of the reduction.
+    IsLastStep,
+    /// Reciprocal of the total.
+    Recip,
+    /// Remainder of the reciprocal.
+    RecipRem,
+    /// Remainder of the product of the exponential and the reciprocal.
+    OutRem,
+    /// Fixed-point scale factor.
+    Scale,
+    /// Multiplicity for the LogUp argument (input).
+    InputMult,
+    /// Multiplicity for the LogUp argument (output).
+    OutMult,
+    /// Multiplicity for the Exp LUT interaction.
+    LookupMult,
+    /// Multiplicity for the range checks.
+    RangeCheckMult,
+}
+
+impl SoftmaxColumn {
+    /// Returns the 0-based index for this column within the Softmax trace segment.
+    pub const fn index(self) -> usize {
+        match self {
+            Self::NodeId => 0,
+            Self::InputId => 1,
+            Self::Idx => 2,
+            Self::InputIdx => 3,
+            Self::IsLastIdx => 4,
+            Self::Input => 5,
+            Self::Out => 6,
+            Self::Max => 7,
+            Self::Diff => 8,
+            Self::DiffLimb(i) => 9 + i,
+            Self::IsMax => 11,
+            Self::MaxCount => 12,
+            Self::NextMaxCount => 13,
+            Self::LutInput => 14,
+            Self::ClampLow => 15,
+            Self::ClampHigh => 16,
+            Self::ClampDist => 17,
+            Self::ClampDistBit(i) => 18 + i,
+            Self::Offset => 18 + N_CLAMP_BITS,
+            Self::BaseExp => 19 + N_CLAMP_BITS,
+            Self::Slope => 20 + N_CLAMP_BITS,
+            Self::ExpRem => 21 + N_CLAMP_BITS,
+            Self::Exp => 22 + N_CLAMP_BITS,
+            Self::Sum => 23 + N_CLAMP_BITS,
+            Self::NextSum => 24 + N_CLAMP_BITS,
+            Self::Total => 25 + N_CLAMP_BITS,
+            Self::IsLastStep => 26 + N_CLAMP_BITS,
+            Self::Recip => 27 + N_CLAMP_BITS,
+            Self::RecipRem => 28 + N_CLAMP_BITS,
+            Self::OutRem => 29 + N_CLAMP_BITS,
+            Self::Scale => 30 + N_CLAMP_BITS,
+            Self::InputMult => 31 + N_CLAMP_BITS,
+            Self::OutMult => 32 + N_CLAMP_BITS,
+            Self::LookupMult => 33 + N_CLAMP_BITS,
+            Self::RangeCheckMult => 34 + N_CLAMP_BITS,
+        }
+    }
+}
+
+/// Implements the `TraceColumn` trait for `SoftmaxColumn`.
+impl TraceColumn for SoftmaxColumn {
+    /// Specifies the number of columns used by the Softmax component.
+    /// Returns `(N_TRACE_COLUMNS, 3 + N_RANGE_CHECKS)`, indicating the number of main trace
+    /// columns and the interaction trace columns (input LogUp, output LogUp, Exp LUT
+    /// interaction, the six range checks of the interpolation, the seven of the normalization
+    /// and those of the difference limbs).
+    fn count() -> (usize, usize) {
+        (N_TRACE_COLUMNS, 3 + N_RANGE_CHECKS)
+    }
+}
@@ -0,0 +1,335 @@
+use crate::{
+    components::{
+        lookups::{
+            range_check::{
+                witness::limb_range_checks, RangeCheckLookupElements, N_LIMB_RANGE_CHECKS,
+            },
+            unary::{
+                functions::{Exp, ExpLookupElements},
+                LutFunction,
+            },
+            N_CLAMP_BITS,
+        },
+        unary::component::interpolation_max_values,
+        InteractionClaim, NodeElements, SoftmaxClaim,
+    },
+    preprocessed::{Interpolation, LookupLayout},
+    utils::{pack_values, to_trace_order, TreeBuilder},
+};
+use luminair_utils::TraceError;
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use stwo_air_utils::trace::component_trace::ComponentTrace;
+use stwo_air_utils_derive::{IterMut, ParIterMut, Uninitialized};
+use stwo_prover::{
+    constraint_framework::{logup::LogupTraceGenerator, Relation},
+    core::{
+        backend::simd::{
+            m31::{PackedM31, LOG_N_LANES, N_LANES},
+            qm31::PackedQM31,
+            SimdBackend,
+        },
+        fields::m31::M31,
+    },
+};
+
+use super::table::{
+    PackedSoftmaxTraceTableRow, SoftmaxColumn, SoftmaxTraceTable, SoftmaxTraceTableRow, N_DIFF_BITS,
+};
+
+/// Number of main trace columns for the Softmax component.
+pub(crate) const N_TRACE_COLUMNS: usize = 35 + N_CLAMP_BITS;
+
+/// Number of values range-checked by each row of the Softmax component, before the limbs
+/// of the difference.
+const N_VALUE_RANGE_CHECKS: usize = 13;
+
+/// Number of values range-checked by each row of the Softmax component.
+pub(crate) const N_RANGE_CHECKS: usize = N_VALUE_RANGE_CHECKS + N_LIMB_RANGE_CHECKS;
+
+/// Generates main trace and interaction data for the Softmax component.
+///
+/// Takes the raw `SoftmaxTraceTable`, processes it into main STARK trace columns,
+/// and prepares `LookupData` for the LogUp arguments: input, output, Exp LUT interaction,
+/// and the range checks of the interpolation and of the normalization.
+pub struct ClaimGenerator {
+    /// The raw trace data for Softmax operations.
+    pub inputs: SoftmaxTraceTable,
+    /// Interpolation of the Exp LUT, or `None` if every value is an entry.
+    pub interpolation: Option<Interpolation>,
+    /// Fixed-point scale of the Exp LUT.
+    pub lut_scale: u32,
+}
+
+impl ClaimGenerator {
+    /// Creates a new `ClaimGenerator` with the given `SoftmaxTraceTable` and the layout
+    /// of the Exp LUT.
+    pub fn new(inputs: SoftmaxTraceTable, layout: &LookupLayout) -> Self {
+        Self {
+            inputs,
+            interpolation: layout.interpolation,
+            lut_scale: layout.scale,
+        }
+    }
+
+    /// Writes the main trace columns and returns data for the interaction phase.
+    ///
+    /// Standard procedure: pads table, packs rows, calls `write_trace_simd`,
+    /// adds main trace to `tree_builder`, returns `SoftmaxClaim` and `InteractionClaimGenerator`.
+    /// Returns `TraceError::EmptyTrace` if the input table is empty.
+    pub fn write_trace(
+        mut self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+    ) -> Result<(SoftmaxClaim, InteractionClaimGenerator), TraceError> {
+        let n_rows = self.inputs.table.len();
+
+        if n_rows == 0 {
+            return Err(TraceError::EmptyTrace);
+        }
+
+        let size = std::cmp::max(n_rows.next_power_of_two(), N_LANES);
+        let log_size = size.ilog2();
+
+        self.inputs
+            .table
+            .resize(size, SoftmaxTraceTableRow::padding());
+        let packed_inputs = pack_values(&to_trace_order(&self.inputs.table));
+
+        let max_values = interpolation_max_values(self.interpolation, self.lut_scale);
+        let (trace, lookup_data) = write_trace_simd(packed_inputs, max_values);
+
+        tree_builder.extend_evals(trace.to_evals());
+
+        Ok((
+            SoftmaxClaim::new(log_size),
+            InteractionClaimGenerator {
+                log_size,
+                lookup_data,
+                interpolated: self.interpolation.is_some(),
+            },
+        ))
+    }
+}
+
+/// Populates main trace columns and `LookupData` from SIMD-packed Softmax trace rows.
+///
+/// Processes `PackedSoftmaxTraceTableRow` data in parallel:
+/// - Maps fields to corresponding main trace columns.
+/// - Extracts `[id, idx, value]` triples and multiplicities for input and output LogUps,
+///   the Exp LUT entry and `lookup_mult` for the LUT interaction, and the values
+///   range-checked by the interpolation (with their gaps to `max_values`), the
+///   normalization and the limbs of the difference into `LookupData`.
+/// Returns the `ComponentTrace` and `LookupData`.
+fn write_trace_simd(
+    inputs: Vec<PackedSoftmaxTraceTableRow>,
+    max_values: [M31; 3],
+) -> (ComponentTrace<N_TRACE_COLUMNS>, LookupData) {
+    let log_n_packed_rows = inputs.len().ilog2();
+    let log_size = log_n_packed_rows + LOG_N_LANES;
+
+    let (mut trace, mut lookup_data) = unsafe {
+        (
+            ComponentTrace::<N_TRACE_COLUMNS>::uninitialized(log_size),
+            LookupData::uninitialized(log_n_packed_rows),
+        )
+    };
+
+    let [max_offset, max_rem, max_delta] = max_values.map(PackedM31::broadcast);
+    let one = PackedM31::broadcast(M31::from_u32_unchecked(1));
+
+    (
+        trace.par_iter_mut(),
+        lookup_data.par_iter_mut(),
+        inputs.into_par_iter(),
+    )
+        .into_par_iter()
+        .for_each(|(mut row, lookup_data, input)| {
+            *row[SoftmaxColumn::NodeId.index()] = input.node_id;
+            *row[SoftmaxColumn::InputId.index()] = input.input_id;
+            *row[SoftmaxColumn::Idx.index()] = input.idx;
+            *row[SoftmaxColumn::InputIdx.index()] = input.input_idx;
+            *row[SoftmaxColumn::IsLastIdx.index()] = input.is_last_idx;
+            *row[SoftmaxColumn::Input.index()] = input.input;
+            *row[SoftmaxColumn::Out.index()] = input.out;
+            *row[SoftmaxColumn::Max.index()] = input.max;
+            *row[SoftmaxColumn::Diff.index()] = input.diff;
+            for (i, limb) in input.diff_limbs.into_iter().enumerate() {
+                *row[SoftmaxColumn::DiffLimb(i).index()] = limb;
+            }
+            *row[SoftmaxColumn::IsMax.index()] = input.is_max;
+            *row[SoftmaxColumn::MaxCount.index()] = input.max_count;
+            *row[SoftmaxColumn::NextMaxCount.index()] = input.next_max_count;
+            *row[SoftmaxColumn::LutInput.index()] = input.lut_input;
+            *row[SoftmaxColumn::ClampLow.index()] = input.clamp_low;
+            *row[SoftmaxColumn::ClampHigh.index()] = input.clamp_high;
+            *row[SoftmaxColumn::ClampDist.index()] = input.clamp_dist;
+            for (i, bit) in input.clamp_dist_bits.into_iter().enumerate() {
+                *row[SoftmaxColumn::ClampDistBit(i).index()] = bit;
+            }
+            *row[SoftmaxColumn::Offset.index()] = input.offset;
+            *row[SoftmaxColumn::BaseExp.index()] = input.base_exp;
+            *row[SoftmaxColumn::Slope.index()] = input.slope;
+            *row[SoftmaxColumn::ExpRem.index()] = input.exp_rem;
+            *row[SoftmaxColumn::Exp.index()] = input.exp;
+            *row[SoftmaxColumn::Sum.index()] = input.sum;
+            *row[SoftmaxColumn::NextSum.index()] = input.next_sum;
+            *row[SoftmaxColumn::Total.index()] = input.total;
+            *row[SoftmaxColumn::IsLastStep.index()] = input.is_last_step;
+            *row[SoftmaxColumn::Recip.index()] = input.recip;
+            *row[SoftmaxColumn::RecipRem.index()] = input.recip_rem;
+            *row[SoftmaxColumn::OutRem.index()] = input.out_rem;
+            *row[SoftmaxColumn::Scale.index()] = input.scale;
+            *row[SoftmaxColumn::InputMult.index()] = input.input_mult;
+            *row[SoftmaxColumn::OutMult.index()] = input.out_mult;
+            *row[SoftmaxColumn::LookupMult.index()] = input.lookup_mult;
+            *row[SoftmaxColumn::RangeCheckMult.index()] = input.range_check_mult;
+
+            let increment = input.exp - input.base_exp;
+
+            *lookup_data.input = [input.input_id, input.input_idx, input.input];
+            *lookup_data.input_mult = input.input_mult;
+            *lookup_data.out = [input.node_id, input.idx, input.out];
+            *lookup_data.out_mult = input.out_mult;
+            *lookup_data.lut_entry = [input.lut_input - input.offset, input.base_exp, input.slope];
+            *lookup_data.lookup_mult = input.lookup_mult;
+            let values: [PackedM31; N_VALUE_RANGE_CHECKS] = [
+                input.offset,
+                max_offset - input.offset,
+                input.exp_rem,
+                max_rem - input.exp_rem,
+                max_delta + increment,
+                max_delta - increment,
+                input.out_rem,
+                input.scale - one - input.out_rem,
+                input.recip_rem,
+                input.total - one - input.recip_rem,
+                input.recip,
+                input.out,
+                input.scale - input.out,
+            ];
+            let diff_range_checks = limb_range_checks(input.diff_limbs, N_DIFF_BITS);
+            *lookup_data.range_checks = std::array::from_fn(|i| {
+                if i < N_VALUE_RANGE_CHECKS {
+                    values[i]
+                } else {
+                    diff_range_checks[i - N_VALUE_RANGE_CHECKS]
+                }
+            });
+            *lookup_data.range_check_mult = input.range_check_mult;
+        });
+
+    (trace, lookup_data)
+}
+
+/// Intermediate data for the Softmax component's LogUp arguments.
+///
+/// Holds ID-index-value triples and multiplicities for input and output terms,
+/// the entries and multiplicities for the interaction with the Exp LUT,
+/// and the values range-checked by each row.
+/// Derives helper iterators for parallel processing.
+#[derive(Uninitialized, IterMut, ParIterMut)]
+struct LookupData {
+    /// Input ID-index-value triples: `[input_node_id, input_idx, input_value]`.
+    input: Vec<[PackedM31; 3]>,
+    /// Multiplicities for input values (LogUp).
+    input_mult: Vec<PackedM31>,
+    /// Output ID-index-value triples: `[node_id, idx, out_value]`.
+    out: Vec<[PackedM31; 3]>,
+    /// Multiplicities for output values (LogUp).
+    out_mult: Vec<PackedM31>,
+    /// Exp LUT entries looked up: `[breakpoint, base_exp, slope]`.
+    lut_entry: Vec<[PackedM31; 3]>,
+    /// Multiplicities for the Exp LUT interaction.
+    lookup_mult: Vec<PackedM31>,
+    /// Interpolation and normalization values and their gaps, followed by the difference
+    /// limbs and their gaps, range-checked.
+    range_checks: Vec<[PackedM31; N_RANGE_CHECKS]>,
+    /// Multiplicities for the range checks.
+    range_check_mult: Vec<PackedM31>,
+}
+
+/// Generates interaction trace columns for the LogUp arguments of the Softmax component.
+///
+/// Builds the LogUp interaction columns:
+/// 1. Input term: `(input_value, input_node_id)` with `NodeElements`.
+/// 2. Output term: `(out_value, node_id)` with `NodeElements`.
+/// 3. LUT term: `(breakpoint, base_exp[, slope])` with `ExpLookupElements`.
+/// 4. A range-check term per value of `N_RANGE_CHECKS`, with `RangeCheckLookupElements`.
+pub struct InteractionClaimGenerator {
+    /// Log2 size of the trace.
+    log_size: u32,
+    /// Data for LogUp arguments.
+    lookup_data: LookupData,
+    /// Whether the Exp LUT is interpolated, its entries holding a slope.
+    interpolated: bool,
+}
+
+impl InteractionClaimGenerator {
+    /// Writes the LogUp interaction trace columns to the `tree_builder`.
+    ///
+    /// - Initializes a `LogupTraceGenerator`.
+    /// - For Input LogUp: combines `lookup_data.input[i]` with `node_elements` for denominator.
+    /// - For Output LogUp: combines `lookup_data.out[i]` with `node_elements` for denominator.
+    /// - For LUT Interaction: combines `lookup_data.lut_entry[i]` (raw values, without the slope
+    ///   for an exact LUT) with `lookup_elements` for the denominator.
+    /// - For the range checks: combines each of `lookup_data.range_checks[i]` with
+    ///   `range_check_elements` for the denominator.
+    /// - Writes `multiplicity / denominator` fractions for each.
+    /// - Finalizes the generator, adds columns to `tree_builder`, returns `InteractionClaim`.
+    pub fn write_interaction_trace(
+        self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+        node_elements: &NodeElements,
+        lookup_elements: &ExpLookupElements,
+        range_check_elements: &RangeCheckLookupElements,
+    ) -> InteractionClaim {
+        let mut logup_gen = LogupTraceGenerator::new(self.log_size);
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let values = &self.lookup_data.input[row];
+            let multiplicity = &self.lookup_data.input_mult[row];
+
+            let denom: PackedQM31 = node_elements.combine(values);
+            col_gen.write_frac(row, (*multiplicity).into(), denom);
+        }
+        col_gen.finalize_col();
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let values = &self.lookup_data.out[row];
+            let multiplicity = &self.lookup_data.out_mult[row];
+
+            let denom: PackedQM31 = node_elements.combine(values);
+            col_gen.write_frac(row, (*multiplicity).into(), denom);
+        }
+        col_gen.finalize_col();
+
+        let entry_len = if self.interpolated { 3 } else { 2 };
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let entry = &self.lookup_data.lut_entry[row][..entry_len];
+            let multiplicity = self.lookup_data.lookup_mult[row];
+
+            let denom: PackedQM31 = Exp::combine(lookup_elements, entry);
+            col_gen.write_frac(row, multiplicity.into(), denom);
+        }
+        col_gen.finalize_col();
+
+        for i in 0..N_RANGE_CHECKS {
+            let mut col_gen = logup_gen.new_col();
+            for row in 0..1 << (self.log_size - LOG_N_LANES) {
+                let value = self.lookup_data.range_checks[row][i];
+                let multiplicity = self.lookup_data.range_check_mult[row];
+
+                let denom: PackedQM31 = range_check_elements.combine(&[value]);
+                col_gen.write_frac(row, multiplicity.into(), denom);
+            }
+            col_gen.finalize_col();
+        }
+
+        let (trace, claimed_sum) = logup_gen.finalize_last();
+        tree_builder.extend_evals(trace);
+
+        InteractionClaim { claimed_sum }
+    }
+}
@@ -1,10 +1,18 @@
-use crate::components::{NodeElements, SqrtClaim};
+use crate::{
+    components::{lookups::range_check::RangeCheckLookupElements, NodeElements, SqrtClaim},
+    preprocessed::eval_structure,
+};
 use num_traits::One;
 use numerair::eval::EvalFixedPoint;
-use stwo_prover::constraint_framework::{
-    EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry,
+use stwo_prover::{
+    constraint_framework::{
+        EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry, ORIGINAL_TRACE_IDX,
+    },
+    core::fields::m31::M31,
 };
 
+use super::table::SqrtTraceTableRow;
+
 /// The STWO AIR component for element-wise sqrt operations.
 /// Wraps the `SqrtEval` logic within the STWO `FrameworkComponent`.
 pub type SqrtComponent = FrameworkComponent<SqrtEval>;
@@ -14,17 +22,33 @@ pub type SqrtComponent = FrameworkComponent<SqrtEval>;
 pub struct SqrtEval {
     /// Log2 size of the component's trace segment.
     log_size: u32,
+    /// Log2 size of the preprocessed range-check table.
+    lut_log_size: u32,
     /// Interaction elements for node relations (used in LogUp).
     node_elements: NodeElements,
+    /// Interaction elements for the remainder range checks.
+    range_check_elements: RangeCheckLookupElements,
+    /// Fixed-point scale of the circuit.
+    fp_scale: u32,
 }
 
 impl SqrtEval {
     /// Creates a new `SqrtEval` instance.
-    /// Takes the component's claim (for `log_size`) and interaction elements.
-    pub fn new(claim: &SqrtClaim, node_elements: NodeElements) -> Self {
+    /// Takes the component's claim (for `log_size`), interaction elements for nodes and
+    /// the range check, the log_size of the range-check table and the fixed-point scale.
+    pub fn new(
+        claim: &SqrtClaim,
+        node_elements: NodeElements,
+        range_check_elements: RangeCheckLookupElements,
+        lut_log_size: u32,
+        fp_scale: u32,
+    ) -> Self {
         Self {
             log_size: claim.log_size,
+            lut_log_size,
             node_elements,
+            range_check_elements,
+            fp_scale,
         }
     }
 }
@@ -36,32 +60,35 @@ impl FrameworkEval for SqrtEval {
         self.log_size
     }
 
-    /// Returns the maximum expected log2 degree bound for the component's constraints.
+    /// Returns the max log2 degree bound, considering both main trace and range-check table sizes.
     fn max_constraint_log_degree_bound(&self) -> u32 {
-        self.log_size + 1
+        std::cmp::max(self.log_size, self.lut_log_size) + 1
     }
 
     /// Evaluates the Sqrt AIR constraints on a given evaluation point (`eval`).
     ///
     /// Defines constraints for:
-    /// - **Consistency:** Checks the fixed-point sqrt constraint.
-    ///   using `eval_fixed_sqrt`.
+    /// - **Structural:** The IDs, indices, flags and multiplicities match the preprocessed trace.
+    /// - **Consistency:** Checks the fixed-point sqrt constraint
+    ///   using `eval_fixed_sqrt`, and that the scale column holds the circuit scale.
     /// - **Transition:** Ensures correct state transitions between consecutive rows (same node/input ID,
     ///   index increments by 1) when `is_last_idx` is false.
-    /// - **Interaction (LogUp):** Links input and output values to the global LogUp argument.
+    /// - **Interaction (LogUp):** Links input and output values to the global LogUp argument,
+    ///   and sends `rem`, `2 * out - rem` and `out` to the shared range-check lookup, so that
+    ///   `0 <= rem <= 2 * out` with a non-negative root.
     /// Receives an evaluator `E` and adds constraint evaluations to it.
     fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
-        // IDs
-        let node_id = eval.next_trace_mask(); // ID of the node in the computational graph.
-        let input_id = eval.next_trace_mask(); // ID of the input tensor.
-        let idx = eval.next_trace_mask(); // Index in the flattened tensor.
+        // IDs (current and next row, for transition constraints)
+        // ID of the node in the computational graph.
+        let [node_id, next_node_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of the input tensor.
+        let [input_id, next_input_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Index in the flattened tensor.
+        let [idx, next_idx] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Position of the input value within its tensor.
+        let input_idx = eval.next_trace_mask();
         let is_last_idx = eval.next_trace_mask(); // Flag if this is the last index for this operation.
 
-        // Next IDs for transition constraints
-        let next_node_id = eval.next_trace_mask();
-        let next_input_id = eval.next_trace_mask();
-        let next_idx = eval.next_trace_mask();
-
         // Values for consistency constraints
         let input_val = eval.next_trace_mask(); // Value from the tensor at index.
         let out_val = eval.next_trace_mask(); // Value in output tensor at index.
@@ -71,6 +98,26 @@ impl FrameworkEval for SqrtEval {
         // Multiplicities for interaction constraints
         let input_mult = eval.next_trace_mask();
         let out_mult = eval.next_trace_mask();
+        let rem_mult = eval.next_trace_mask();
+
+        // ┌─────────────────────────────┐
+        // │   Structural Constraints    │
+        // └─────────────────────────────┘
+
+        // The IDs, indices, flags and multiplicities are the ones fixed by the graph.
+        eval_structure::<_, SqrtTraceTableRow>(
+            &mut eval,
+            "sqrt",
+            vec![
+                node_id.clone(),
+                input_id.clone(),
+                idx.clone(),
+                input_idx.clone(),
+                is_last_idx.clone(),
+                input_mult.clone(),
+                out_mult.clone(),
+            ],
+        );
 
         // ┌─────────────────────────────┐
         // │   Consistency Constraints   │
@@ -79,8 +126,17 @@ impl FrameworkEval for SqrtEval {
         // The is_last_idx flag is either 0 or 1.
         eval.add_constraint(is_last_idx.clone() * (is_last_idx.clone() - E::F::one()));
 
+        // Rows emitting their output use the circuit scale.
+        // Padding rows emit nothing and hold a zero scale.
+        let circuit_scale = E::F::from(M31::from_u32_unchecked(1 << self.fp_scale));
+        eval.add_constraint(out_mult.clone() * (scale.clone() - circuit_scale));
+
+        // The range-check multiplicity is boolean, and set on every row emitting its output.
+        eval.add_constraint(rem_mult.clone() * (rem_mult.clone() - E::F::one()));
+        eval.add_constraint(out_mult.clone() * (E::F::one() - rem_mult.clone()));
+
         // Evaluates fixed point sqrt.
-        eval.eval_fixed_sqrt(input_val.clone(), out_val.clone(), rem_val, scale);
+        eval.eval_fixed_sqrt(input_val.clone(), out_val.clone(), rem_val.clone(), scale);
 
         // ┌────────────────────────────┐
         // │   Transition Constraints   │
@@ -98,7 +154,7 @@ impl FrameworkEval for SqrtEval {
         eval.add_constraint(not_last.clone() * (next_input_id - input_id.clone()));
 
         // Index increment by 1
-        eval.add_constraint(not_last * (next_idx - idx - E::F::one()));
+        eval.add_constraint(not_last * (next_idx - idx.clone() - E::F::one()));
 
         // ┌─────────────────────────────┐
         // │   Interaction Constraints   │
@@ -107,15 +163,25 @@ impl FrameworkEval for SqrtEval {
         eval.add_to_relation(RelationEntry::new(
             &self.node_elements,
             input_mult.into(),
-            &[input_val, input_id],
+            &[input_id, input_idx, input_val],
         ));
 
         eval.add_to_relation(RelationEntry::new(
             &self.node_elements,
             out_mult.into(),
-            &[out_val, node_id],
+            &[node_id, idx, out_val.clone()],
         ));
 
+        // 0 <= rem <= 2 * out, with out >= 0
+        let two_out = out_val.clone() + out_val.clone();
+        for value in [rem_val.clone(), two_out - rem_val, out_val] {
+            eval.add_to_relation(RelationEntry::new(
+                &self.range_check_elements,
+                rem_mult.clone().into(),
+                &[value],
+            ));
+        }
+
         eval.finalize_logup();
 
         eval
@@ -8,7 +8,7 @@ use stwo_prover::core::{
     fields::m31::M31,
 };
 
-use crate::components::TraceColumn;
+use crate::{components::TraceColumn, preprocessed::StructuredRow};
 
 use super::witness::N_TRACE_COLUMNS;
 
@@ -24,7 +24,7 @@ pub struct SqrtTraceTable {
 
 /// Represents a single row in the `SqrtTraceTable`.
 ///
-/// Contains values for evaluating Sqrt AIR constraints: current/next state IDs,
+/// Contains values for evaluating Sqrt AIR constraints: state IDs,
 /// input/output values, fixed-point remainder, scale factor, and LogUp multiplicities.
 #[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SqrtTraceTableRow {
@@ -34,26 +34,25 @@ pub struct SqrtTraceTableRow {
     pub input_id: M31,
     /// Index within the tensor for this operation.
     pub idx: M31,
+    /// Position of the input value within the input tensor.
+    pub input_idx: M31,
     /// Flag indicating if this is the last element processed for this node (1 if true, 0 otherwise).
     pub is_last_idx: M31,
-    /// ID of the *next* Sqrt node processed in the trace.
-    pub next_node_id: M31,
-    /// ID of the *next* input provider node.
-    pub next_input_id: M31,
-    /// Index of the *next* element processed.
-    pub next_idx: M31,
     /// Value of the input (`x`).
     pub input: M31,
     /// Value of the output (`Sqrt(x)`).
     pub out: M31,
     /// Remainder from fixed-point sqrt (`SCALE % x`).
     pub rem: M31,
-    /// The scale factor used (typically `numerair::SCALE_FACTOR`).
+    /// The scale factor of the circuit (`2^fp_scale`), zero on padding rows.
     pub scale: M31,
     /// Multiplicity contribution for the LogUp argument (input).
     pub input_mult: M31,
     /// Multiplicity contribution for the LogUp argument (output).
     pub out_mult: M31,
+    /// Multiplicity contribution for the range checks of the remainder, its gap to `2 * out`
+    /// and the root.
+    pub rem_mult: M31,
 }
 
 impl SqrtTraceTableRow {
@@ -63,20 +62,46 @@ impl SqrtTraceTableRow {
             node_id: M31::zero(),
             input_id: M31::zero(),
             idx: M31::zero(),
+            input_idx: M31::zero(),
             is_last_idx: M31::one(),
-            next_node_id: M31::zero(),
-            next_input_id: M31::zero(),
-            next_idx: M31::zero(),
             input: M31::zero(),
             out: M31::zero(),
             rem: M31::zero(),
             scale: M31::zero(),
             input_mult: M31::zero(),
             out_mult: M31::zero(),
+            rem_mult: M31::zero(),
         }
     }
 }
 
+/// Implements the `StructuredRow` trait for `SqrtTraceTableRow`.
+impl StructuredRow for SqrtTraceTableRow {
+    /// IDs, indices, flags and multiplicities of the row, fixed by the graph.
+    const STRUCTURE: &'static [&'static str] = &[
+        "node_id",
+        "input_id",
+        "idx",
+        "input_idx",
+        "is_last_idx",
+        "input_mult",
+        "out_mult",
+    ];
+
+    /// Returns the values of the structural columns of the row.
+    fn structure(&self) -> Vec<M31> {
+        vec![
+            self.node_id,
+            self.input_id,
+            self.idx,
+            self.input_idx,
+            self.is_last_idx,
+            self.input_mult,
+            self.out_mult,
+        ]
+    }
+}
+
 /// SIMD-packed representation of a `SqrtTraceTableRow`.
 #[derive(Debug, Copy, Clone)]
 pub struct PackedSqrtTraceTableRow {
@@ -86,14 +111,10 @@ pub struct PackedSqrtTraceTableRow {
     pub input_id: PackedM31,
     /// Packed `idx` values.
     pub idx: PackedM31,
+    /// Packed `input_idx` values.
+    pub input_idx: PackedM31,
     /// Packed `is_last_idx` values.
     pub is_last_idx: PackedM31,
-    /// Packed `next_node_id` values.
-    pub next_node_id: PackedM31,
-    /// Packed `next_input_id` values.
-    pub next_input_id: PackedM31,
-    /// Packed `next_idx` values.
-    pub next_idx: PackedM31,
     /// Packed `input` values.
     pub input: PackedM31,
     /// Packed `out` values.
@@ -106,6 +127,8 @@ pub struct PackedSqrtTraceTableRow {
     pub input_mult: PackedM31,
     /// Packed `out_mult` values.
     pub out_mult: PackedM31,
+    /// Packed `rem_mult` values.
+    pub rem_mult: PackedM31,
 }
 
 impl Pack for SqrtTraceTableRow {
@@ -116,16 +139,15 @@ impl Pack for SqrtTraceTableRow {
             node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].node_id)),
             input_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_id)),
             idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].idx)),
+            input_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_idx)),
             is_last_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_idx)),
-            next_node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_node_id)),
-            next_input_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_input_id)),
-            next_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_idx)),
             input: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input)),
             out: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out)),
             rem: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rem)),
             scale: PackedM31::from_array(std::array::from_fn(|i| inputs[i].scale)),
             input_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_mult)),
             out_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_mult)),
+            rem_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rem_mult)),
         }
     }
 }
@@ -138,46 +160,43 @@ impl Unpack for PackedSqrtTraceTableRow {
             node_id,
             input_id,
             idx,
+            input_idx,
             is_last_idx,
-            next_node_id,
-            next_input_id,
-            next_idx,
             input,
             out,
             rem,
             scale,
             input_mult,
             out_mult,
+            rem_mult,
         ) = (
             self.node_id.to_array(),
             self.input_id.to_array(),
             self.idx.to_array(),
+            self.input_idx.to_array(),
             self.is_last_idx.to_array(),
-            self.next_node_id.to_array(),
-            self.next_input_id.to_array(),
-            self.next_idx.to_array(),
             self.input.to_array(),
             self.out.to_array(),
             self.rem.to_array(),
             self.scale.to_array(),
             self.input_mult.to_array(),
             self.out_mult.to_array(),
+            self.rem_mult.to_array(),
         );
 
         std::array::from_fn(|i| SqrtTraceTableRow {
             node_id: node_id[i],
             input_id: input_id[i],
             idx: idx[i],
+            input_idx: input_idx[i],
             is_last_idx: is_last_idx[i],
-            next_node_id: next_node_id[i],
-            next_input_id: next_input_id[i],
-            next_idx: next_idx[i],
             input: input[i],
             out: out[i],
             rem: rem[i],
             scale: scale[i],
             input_mult: input_mult[i],
             out_mult: out_mult[i],
+            rem_mult: rem_mult[i],
         })
     }
 }
@@ -203,14 +222,10 @@ pub enum SqrtColumn {
     InputId,
     /// Index within the tensor for this operation.
     Idx,
+    /// Position of the input value within the input tensor.
+    InputIdx,
     /// Flag indicating if this is the last element processed for this node.
     IsLastIdx,
-    /// ID of the *next* Sqrt node processed in the trace.
-    NextNodeId,
-    /// ID of the *next* input provider node.
-    NextInputId,
-    /// Index of the *next* element processed.
-    NextIdx,
     /// Value of the input (`x`).
     Input,
     /// Value of the output (`Sqrt(x)`).
@@ -223,6 +238,8 @@ pub enum SqrtColumn {
     InputMult,
     /// Multiplicity for the LogUp argument (output).
     OutMult,
+    /// Multiplicity for the range checks of the remainder, its gap to `2 * out` and the root.
+    RemMult,
 }
 
 impl SqrtColumn {
@@ -232,16 +249,15 @@ impl SqrtColumn {
             Self::NodeId => 0,
             Self::InputId => 1,
             Self::Idx => 2,
-            Self::IsLastIdx => 3,
-            Self::NextNodeId => 4,
-            Self::NextInputId => 5,
-            Self::NextIdx => 6,
-            Self::Input => 7,
-            Self::Out => 8,
-            Self::Rem => 9,
-            Self::Scale => 10,
-            Self::InputMult => 11,
-            Self::OutMult => 12,
+            Self::InputIdx => 3,
+            Self::IsLastIdx => 4,
+            Self::Input => 5,
+            Self::Out => 6,
+            Self::Rem => 7,
+            Self::Scale => 8,
+            Self::InputMult => 9,
+            Self::OutMult => 10,
+            Self::RemMult => 11,
         }
     }
 }
@@ -249,9 +265,10 @@ impl SqrtColumn {
 /// Implements the `TraceColumn` trait for `SqrtColumn`.
 impl TraceColumn for SqrtColumn {
     /// Specifies the number of columns used by the Sqrt component.
-    /// Returns `(N_TRACE_COLUMNS, 2)`, indicating the number of main trace columns
-    /// and 2 interaction trace columns (for input and output LogUp).
+    /// Returns `(N_TRACE_COLUMNS, 5)`, indicating the number of main trace columns
+    /// and 5 interaction trace columns (for input, output, and the range checks of the
+    /// remainder, of its gap to `2 * out` and of the root).
     fn count() -> (usize, usize) {
-        (N_TRACE_COLUMNS, 2)
+        (N_TRACE_COLUMNS, 5)
     }
 }
@@ -12,14 +12,19 @@ use stwo_prover::{
 };
 
 use crate::{
-    components::{InteractionClaim, NodeElements, SqrtClaim},
-    utils::{pack_values, TreeBuilder},
+    components::{
+        lookups::range_check::RangeCheckLookupElements, InteractionClaim, NodeElements, SqrtClaim,
+    },
+    utils::{pack_values, to_trace_order, TreeBuilder},
 };
 
 use super::table::{PackedSqrtTraceTableRow, SqrtColumn, SqrtTraceTable, SqrtTraceTableRow};
 
 /// Number of main trace columns for the Sqrt component.
-pub(crate) const N_TRACE_COLUMNS: usize = 13;
+pub(crate) const N_TRACE_COLUMNS: usize = 12;
+
+/// Number of values range-checked by each row of the Sqrt component.
+pub(crate) const N_RANGE_CHECKS: usize = 3;
 
 /// Generates the main trace columns and initial data for interaction claims for the Sqrt component.
 ///
@@ -55,7 +60,7 @@ impl ClaimGenerator {
         let log_size = size.ilog2();
 
         self.inputs.table.resize(size, SqrtTraceTableRow::padding());
-        let packed_inputs = pack_values(&self.inputs.table);
+        let packed_inputs = pack_values(&to_trace_order(&self.inputs.table));
 
         let (trace, lookup_data) = write_trace_simd(packed_inputs);
 
@@ -75,7 +80,7 @@ impl ClaimGenerator {
 ///
 /// Processes `PackedSqrtTraceTableRow` data in parallel:
 /// - Maps fields (input, out, rem, scale, etc.) to the corresponding main trace columns.
-/// - Extracts `[value, id]` pairs and multiplicities into `LookupData` for the LogUp argument
+/// - Extracts `[id, idx, value]` triples and multiplicities into `LookupData` for the LogUp argument
 ///   (only for input and output, as sqrt is unary).
 /// Returns the `ComponentTrace` (main trace columns) and `LookupData`.
 fn write_trace_simd(
@@ -101,21 +106,22 @@ fn write_trace_simd(
             *row[SqrtColumn::NodeId.index()] = input.node_id;
             *row[SqrtColumn::InputId.index()] = input.input_id;
             *row[SqrtColumn::Idx.index()] = input.idx;
+            *row[SqrtColumn::InputIdx.index()] = input.input_idx;
             *row[SqrtColumn::IsLastIdx.index()] = input.is_last_idx;
-            *row[SqrtColumn::NextNodeId.index()] = input.next_node_id;
-            *row[SqrtColumn::NextInputId.index()] = input.next_input_id;
-            *row[SqrtColumn::NextIdx.index()] = input.next_idx;
             *row[SqrtColumn::Input.index()] = input.input;
             *row[SqrtColumn::Out.index()] = input.out;
             *row[SqrtColumn::Rem.index()] = input.rem;
             *row[SqrtColumn::Scale.index()] = input.scale;
             *row[SqrtColumn::InputMult.index()] = input.input_mult;
             *row[SqrtColumn::OutMult.index()] = input.out_mult;
+            *row[SqrtColumn::RemMult.index()] = input.rem_mult;
 
-            *lookup_data.input = [input.input, input.input_id];
+            *lookup_data.input = [input.input_id, input.input_idx, input.input];
             *lookup_data.input_mult = input.input_mult;
-            *lookup_data.out = [input.out, input.node_id];
+            *lookup_data.out = [input.node_id, input.idx, input.out];
             *lookup_data.out_mult = input.out_mult;
+            *lookup_data.range_checks = [input.rem, input.out + input.out - input.rem, input.out];
+            *lookup_data.rem_mult = input.rem_mult;
         });
 
     (trace, lookup_data)
@@ -123,37 +129,44 @@ fn write_trace_simd(
 
 /// Intermediate data structure holding values and multiplicities for the Sqrt LogUp argument.
 ///
-/// Stores value-ID pairs and multiplicities only for the input and output terms.
+/// Stores ID-index-value triples and multiplicities only for the input and output terms.
 /// Derives helper iterators for parallel processing.
 #[derive(Uninitialized, IterMut, ParIterMut)]
 struct LookupData {
-    /// Input value-ID pairs: `[input_value, input_node_id]`.
-    input: Vec<[PackedM31; 2]>,
+    /// Input ID-index-value triples: `[input_node_id, input_idx, input_value]`.
+    input: Vec<[PackedM31; 3]>,
     /// Multiplicities for input values.
     input_mult: Vec<PackedM31>,
-    /// Output value-ID pairs: `[out_value, sqrt_node_id]`.
-    out: Vec<[PackedM31; 2]>,
+    /// Output ID-index-value triples: `[sqrt_node_id, idx, out_value]`.
+    out: Vec<[PackedM31; 3]>,
     /// Multiplicities for output values.
     out_mult: Vec<PackedM31>,
+    /// Fixed-point remainders, their gaps to `2 * out` and the roots, range-checked through
+    /// the shared range-check lookup.
+    range_checks: Vec<[PackedM31; N_RANGE_CHECKS]>,
+    /// Multiplicities for the range checks.
+    rem_mult: Vec<PackedM31>,
 }
 
 /// Generates the interaction trace columns for the Sqrt component's LogUp argument.
 ///
-/// Takes `LookupData` and `NodeElements` to build the two LogUp interaction columns
-/// (one for input, one for output) and adds them to the `tree_builder`.
+/// Takes `LookupData`, `NodeElements` and `RangeCheckLookupElements` to build the five LogUp
+/// interaction columns (input, output, and the three range checks) and adds them to the `tree_builder`.
 pub struct InteractionClaimGenerator {
     /// Log2 size of the trace.
     log_size: u32,
-    /// Data (value-ID pairs and multiplicities) needed for LogUp.
+    /// Data (ID-index-value triples and multiplicities) needed for LogUp.
     lookup_data: LookupData,
 }
 
 impl InteractionClaimGenerator {
     /// Writes the LogUp interaction trace columns to the `tree_builder`.
     ///
-    /// Logic is similar to Add/Mul, but only generates two columns (Input, Output):
+    /// Logic is similar to Add/Mul, but generates columns for Input, Output and Rem:
     /// - Creates a `LogupTraceGenerator`.
-    /// - Generates two columns, writing `multiplicity / denom` fractions.
+    /// - Generates two node columns, writing `multiplicity / denom` fractions.
+    /// - Generates a column per range check, sending `rem`, `2 * out - rem` and `out` to the
+    ///   range-check lookup.
     /// - Finalizes the generator, obtaining interaction trace columns and `claimed_sum`.
     /// - Adds interaction columns to the `tree_builder`.
     /// - Returns the `InteractionClaim` containing the `claimed_sum`.
@@ -161,6 +174,7 @@ impl InteractionClaimGenerator {
         self,
         tree_builder: &mut impl TreeBuilder<SimdBackend>,
         node_elements: &NodeElements,
+        range_check_elements: &RangeCheckLookupElements,
     ) -> InteractionClaim {
         let mut logup_gen = LogupTraceGenerator::new(self.log_size);
 
@@ -184,6 +198,18 @@ impl InteractionClaimGenerator {
         }
         col_gen.finalize_col();
 
+        for i in 0..N_RANGE_CHECKS {
+            let mut col_gen = logup_gen.new_col();
+            for row in 0..1 << (self.log_size - LOG_N_LANES) {
+                let value = self.lookup_data.range_checks[row][i];
+                let multiplicity = self.lookup_data.rem_mult[row];
+
+                let denom: PackedQM31 = range_check_elements.combine(&[value]);
+                col_gen.write_frac(row, multiplicity.into(), denom);
+            }
+            col_gen.finalize_col();
+        }
+
         let (trace, claimed_sum) = logup_gen.finalize_last();
         tree_builder.extend_evals(trace);
 
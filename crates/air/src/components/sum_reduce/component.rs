@@ -1,9 +1,14 @@
-use crate::components::{NodeElements, SumReduceClaim};
+use crate::{
+    components::{NodeElements, SumReduceClaim},
+    preprocessed::eval_structure,
+};
 use num_traits::One;
 use stwo_prover::constraint_framework::{
-    EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry,
+    EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry, ORIGINAL_TRACE_IDX,
 };
 
+use super::table::SumReduceTraceTableRow;
+
 /// The STWO AIR component for Sum-Reduce operations.
 /// Wraps the `SumReduceEval` logic within the STWO `FrameworkComponent`.
 pub type SumReduceComponent = FrameworkComponent<SumReduceEval>;
@@ -44,31 +49,35 @@ impl FrameworkEval for SumReduceEval {
     /// Evaluates the SumReduce AIR constraints on a given evaluation point (`eval`).
     ///
     /// Defines constraints for:
+    /// - **Structural:** The IDs, indices, flags and multiplicities match the preprocessed trace.
     /// - **Consistency:**
     ///   - `is_last_idx` and `is_last_step` are boolean.
     ///   - Accumulator update: `next_acc = acc + input`.
     ///   - Output validity: `out = next_acc` only if `is_last_step` is true.
-    /// - **Transition (for output elements):** When `is_last_idx` is false (more output elements for this node):
-    ///   - Node and input tensor IDs remain the same.
-    ///   - `idx` (output element index) increments by 1.
+    /// - **Transition:** Links each row to the next row of the trace:
+    ///   - The next row's `acc` equals `next_acc`, or 0 once a reduction ends (`is_last_step`).
+    ///   - When `is_last_idx` is false, node and input tensor IDs remain the same, and `idx`
+    ///     (output element index) increments by 1 once a reduction ends.
     /// - **Interaction (LogUp):** Links `input_val` (from input tensor) and `out_val` (final sum)
     ///   to the global LogUp argument.
     fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
-        // IDs
-        let node_id = eval.next_trace_mask(); // ID of the node in the computational graph.
-        let input_id = eval.next_trace_mask(); // ID of the input tensor.
-        let idx = eval.next_trace_mask(); // Index in the flattened tensor.
+        // IDs (current and next row, for transition constraints)
+        // ID of the node in the computational graph.
+        let [node_id, next_node_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of the input tensor.
+        let [input_id, next_input_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Index in the flattened tensor.
+        let [idx, next_idx] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Position of the input value within its tensor.
+        let input_idx = eval.next_trace_mask();
         let is_last_idx = eval.next_trace_mask(); // Flag if this is the last index for this operation.
 
-        // Next IDs for transition constraints
-        let next_node_id = eval.next_trace_mask();
-        let next_input_id = eval.next_trace_mask();
-        let next_idx = eval.next_trace_mask();
-
         // Values for consistency constraints
         let input_val = eval.next_trace_mask(); // Value from the tensor at index.
         let out_val = eval.next_trace_mask(); // Value in output tensor at index.
-        let acc_val = eval.next_trace_mask(); // Accumulative value in result tensor at index.
+
+        // Accumulative value in result tensor at index, and the one of the next row.
+        let [acc_val, next_row_acc_val] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
         let next_acc_val = eval.next_trace_mask(); // Next accumulative value.
         let is_last_step = eval.next_trace_mask(); // Flag if this is the last step.
 
@@ -76,6 +85,26 @@ impl FrameworkEval for SumReduceEval {
         let input_mult = eval.next_trace_mask();
         let out_mult = eval.next_trace_mask();
 
+        // ┌─────────────────────────────┐
+        // │   Structural Constraints    │
+        // └─────────────────────────────┘
+
+        // The IDs, indices, flags and multiplicities are the ones fixed by the graph.
+        eval_structure::<_, SumReduceTraceTableRow>(
+            &mut eval,
+            "sum_reduce",
+            vec![
+                node_id.clone(),
+                input_id.clone(),
+                idx.clone(),
+                input_idx.clone(),
+                is_last_idx.clone(),
+                is_last_step.clone(),
+                input_mult.clone(),
+                out_mult.clone(),
+            ],
+        );
+
         // ┌─────────────────────────────┐
         // │   Consistency Constraints   │
         // └─────────────────────────────┘
@@ -86,15 +115,18 @@ impl FrameworkEval for SumReduceEval {
 
         // The output value must equal the sum of the input values.
         eval.add_constraint(next_acc_val.clone() - (acc_val.clone() + input_val.clone()));
-        eval.add_constraint((out_val.clone() - next_acc_val) * is_last_step);
+        eval.add_constraint((out_val.clone() - next_acc_val.clone()) * is_last_step.clone());
 
         // ┌────────────────────────────┐
         // │   Transition Constraints   │
         // └────────────────────────────┘
 
+        // The accumulator carries over to the next row, and restarts from 0 after the last step.
+        eval.add_constraint(next_row_acc_val - (E::F::one() - is_last_step.clone()) * next_acc_val);
+
         // If this is not the last index for this operation, then:
         // 1. The next row should be for the same operation on the same tensors.
-        // 2. The index should increment by 1.
+        // 2. The index should increment by 1 once the reduction of the current index ends.
         let not_last = E::F::one() - is_last_idx;
 
         // Same node ID
@@ -104,7 +136,7 @@ impl FrameworkEval for SumReduceEval {
         eval.add_constraint(not_last.clone() * (next_input_id - input_id.clone()));
 
         // Index increment by 1
-        eval.add_constraint(not_last * (next_idx - idx - E::F::one()));
+        eval.add_constraint(not_last * (next_idx - idx.clone() - is_last_step));
 
         // ┌─────────────────────────────┐
         // │   Interaction Constraints   │
@@ -113,13 +145,13 @@ impl FrameworkEval for SumReduceEval {
         eval.add_to_relation(RelationEntry::new(
             &self.node_elements,
             input_mult.into(),
-            &[input_val, input_id],
+            &[input_id, input_idx, input_val],
         ));
 
         eval.add_to_relation(RelationEntry::new(
             &self.node_elements,
             out_mult.into(),
-            &[out_val, node_id],
+            &[node_id, idx, out_val],
         ));
 
         eval.finalize_logup();
@@ -8,7 +8,7 @@ use stwo_prover::core::{
     fields::m31::M31,
 };
 
-use crate::components::TraceColumn;
+use crate::{components::TraceColumn, preprocessed::StructuredRow};
 
 use super::witness::N_TRACE_COLUMNS;
 
@@ -24,7 +24,7 @@ pub struct SumReduceTraceTable {
 
 /// Represents a single row in the `SumReduceTraceTable`.
 ///
-/// Contains values for evaluating SumReduce AIR constraints: current/next state IDs,
+/// Contains values for evaluating SumReduce AIR constraints: state IDs,
 /// current input value, current/next accumulator value, a flag indicating the last step
 /// of reduction for an output element, the final output (valid on last step),
 /// and LogUp multiplicities.
@@ -36,14 +36,10 @@ pub struct SumReduceTraceTableRow {
     pub input_id: M31,
     /// Index of the output element being computed.
     pub idx: M31,
+    /// Position of the input value within the input tensor.
+    pub input_idx: M31,
     /// Flag: is this the last output element for this node (1 if true, 0 otherwise).
     pub is_last_idx: M31,
-    /// ID of the *next* SumReduce node processed in the trace.
-    pub next_node_id: M31,
-    /// ID of the *next* input provider node.
-    pub next_input_id: M31,
-    /// Index of the *next* output element.
-    pub next_idx: M31,
     /// Current input value being processed in the reduction sum.
     pub input: M31,
     /// Final output value (sum for `idx`). Valid only if `is_last_step` is 1.
@@ -67,10 +63,8 @@ impl SumReduceTraceTableRow {
             node_id: M31::zero(),
             input_id: M31::zero(),
             idx: M31::zero(),
+            input_idx: M31::zero(),
             is_last_idx: M31::one(),
-            next_node_id: M31::zero(),
-            next_input_id: M31::zero(),
-            next_idx: M31::zero(),
             input: M31::zero(),
             out: M31::zero(),
             acc: M31::zero(),
@@ -82,6 +76,35 @@ impl SumReduceTraceTableRow {
     }
 }
 
+/// Implements the `StructuredRow` trait for `SumReduceTraceTableRow`.
+impl StructuredRow for SumReduceTraceTableRow {
+    /// IDs, indices, flags and multiplicities of the row, fixed by the graph.
+    const STRUCTURE: &'static [&'static str] = &[
+        "node_id",
+        "input_id",
+        "idx",
+        "input_idx",
+        "is_last_idx",
+        "is_last_step",
+        "input_mult",
+        "out_mult",
+    ];
+
+    /// Returns the values of the structural columns of the row.
+    fn structure(&self) -> Vec<M31> {
+        vec![
+            self.node_id,
+            self.input_id,
+            self.idx,
+            self.input_idx,
+            self.is_last_idx,
+            self.is_last_step,
+            self.input_mult,
+            self.out_mult,
+        ]
+    }
+}
+
 /// SIMD-packed representation of a `SumReduceTraceTableRow`.
 #[derive(Debug, Copy, Clone)]
 pub struct PackedSumReduceTraceTableRow {
@@ -91,14 +114,10 @@ pub struct PackedSumReduceTraceTableRow {
     pub input_id: PackedM31,
     /// Packed `idx` (output element index) values.
     pub idx: PackedM31,
+    /// Packed `input_idx` values.
+    pub input_idx: PackedM31,
     /// Packed `is_last_idx` flags.
     pub is_last_idx: PackedM31,
-    /// Packed `next_node_id` values.
-    pub next_node_id: PackedM31,
-    /// Packed `next_input_id` values.
-    pub next_input_id: PackedM31,
-    /// Packed `next_idx` values.
-    pub next_idx: PackedM31,
     /// Packed current `input` values for reduction.
     pub input: PackedM31,
     /// Packed `out` (final sum) values.
@@ -123,10 +142,8 @@ impl Pack for SumReduceTraceTableRow {
             node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].node_id)),
             input_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_id)),
             idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].idx)),
+            input_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_idx)),
             is_last_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_idx)),
-            next_node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_node_id)),
-            next_input_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_input_id)),
-            next_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].next_idx)),
             input: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input)),
             out: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out)),
             acc: PackedM31::from_array(std::array::from_fn(|i| inputs[i].acc)),
@@ -146,10 +163,8 @@ impl Unpack for PackedSumReduceTraceTableRow {
             node_id,
             input_id,
             idx,
+            input_idx,
             is_last_idx,
-            next_node_id,
-            next_input_id,
-            next_idx,
             input,
             out,
             acc,
@@ -161,10 +176,8 @@ impl Unpack for PackedSumReduceTraceTableRow {
             self.node_id.to_array(),
             self.input_id.to_array(),
             self.idx.to_array(),
+            self.input_idx.to_array(),
             self.is_last_idx.to_array(),
-            self.next_node_id.to_array(),
-            self.next_input_id.to_array(),
-            self.next_idx.to_array(),
             self.input.to_array(),
             self.out.to_array(),
             self.acc.to_array(),
@@ -178,10 +191,8 @@ impl Unpack for PackedSumReduceTraceTableRow {
             node_id: node_id[i],
             input_id: input_id[i],
             idx: idx[i],
+            input_idx: input_idx[i],
             is_last_idx: is_last_idx[i],
-            next_node_id: next_node_id[i],
-            next_input_id: next_input_id[i],
-            next_idx: next_idx[i],
             input: input[i],
             out: out[i],
             acc: acc[i],
@@ -208,7 +219,19 @@ impl SumReduceTraceTable {
 /// Enum defining the columns of the SumReduce AIR component's trace.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SumReduceColumn {
-    NodeId, InputId, Idx, IsLastIdx, NextNodeId, NextInputId, NextIdx, Input, Out, Acc, NextAcc, IsLastStep, InputMult, OutMult
+    NodeId,
+    InputId,
+    Idx,
+    /// Position of the input value within the input tensor.
+    InputIdx,
+    IsLastIdx,
+    Input,
+    Out,
+    Acc,
+    NextAcc,
+    IsLastStep,
+    InputMult,
+    OutMult,
 }
 
 impl SumReduceColumn {
@@ -218,17 +241,15 @@ impl SumReduceColumn {
             Self::NodeId => 0,
             Self::InputId => 1,
             Self::Idx => 2,
-            Self::IsLastIdx => 3,
-            Self::NextNodeId => 4,
-            Self::NextInputId => 5,
-            Self::NextIdx => 6,
-            Self::Input => 7,
-            Self::Out => 8,
-            Self::Acc => 9,
-            Self::NextAcc => 10,
-            Self::IsLastStep => 11,
-            Self::InputMult => 12,
-            Self::OutMult => 13,
+            Self::InputIdx => 3,
+            Self::IsLastIdx => 4,
+            Self::Input => 5,
+            Self::Out => 6,
+            Self::Acc => 7,
+            Self::NextAcc => 8,
+            Self::IsLastStep => 9,
+            Self::InputMult => 10,
+            Self::OutMult => 11,
         }
     }
 }
@@ -13,7 +13,7 @@ use stwo_prover::{
 
 use crate::{
     components::{InteractionClaim, NodeElements, SumReduceClaim},
-    utils::{pack_values, TreeBuilder},
+    utils::{pack_values, to_trace_order, TreeBuilder},
 };
 
 use super::table::{
@@ -21,7 +21,7 @@ use super::table::{
 };
 
 /// Number of main trace columns for the SumReduce component.
-pub(crate) const N_TRACE_COLUMNS: usize = 14;
+pub(crate) const N_TRACE_COLUMNS: usize = 12;
 
 /// Generates the main trace columns and initial data for interaction claims for the SumReduce component.
 ///
@@ -59,7 +59,7 @@ impl ClaimGenerator {
         self.inputs
             .table
             .resize(size, SumReduceTraceTableRow::padding());
-        let packed_inputs = pack_values(&self.inputs.table);
+        let packed_inputs = pack_values(&to_trace_order(&self.inputs.table));
 
         let (trace, lookup_data) = write_trace_simd(packed_inputs);
 
@@ -79,7 +79,7 @@ impl ClaimGenerator {
 ///
 /// Processes `PackedSumReduceTraceTableRow` data in parallel:
 /// - Maps fields (node/input IDs, current/next accumulator, input/out values, flags) to main trace columns.
-/// - Extracts `[value, id]` pairs and multiplicities for input and output LogUps into `LookupData`.
+/// - Extracts `[id, idx, value]` triples and multiplicities for input and output LogUps into `LookupData`.
 /// Returns the `ComponentTrace` (main trace columns) and `LookupData`.
 fn write_trace_simd(
     inputs: Vec<PackedSumReduceTraceTableRow>,
@@ -104,10 +104,8 @@ fn write_trace_simd(
             *row[SumReduceColumn::NodeId.index()] = input.node_id;
             *row[SumReduceColumn::InputId.index()] = input.input_id;
             *row[SumReduceColumn::Idx.index()] = input.idx;
+            *row[SumReduceColumn::InputIdx.index()] = input.input_idx;
             *row[SumReduceColumn::IsLastIdx.index()] = input.is_last_idx;
-            *row[SumReduceColumn::NextNodeId.index()] = input.next_node_id;
-            *row[SumReduceColumn::NextInputId.index()] = input.next_input_id;
-            *row[SumReduceColumn::NextIdx.index()] = input.next_idx;
             *row[SumReduceColumn::Input.index()] = input.input;
             *row[SumReduceColumn::Out.index()] = input.out;
             *row[SumReduceColumn::Acc.index()] = input.acc;
@@ -116,9 +114,9 @@ fn write_trace_simd(
             *row[SumReduceColumn::InputMult.index()] = input.input_mult;
             *row[SumReduceColumn::OutMult.index()] = input.out_mult;
 
-            *lookup_data.input = [input.input, input.input_id];
+            *lookup_data.input = [input.input_id, input.input_idx, input.input];
             *lookup_data.input_mult = input.input_mult;
-            *lookup_data.out = [input.out, input.node_id];
+            *lookup_data.out = [input.node_id, input.idx, input.out];
             *lookup_data.out_mult = input.out_mult;
         });
 
@@ -126,15 +124,15 @@ fn write_trace_simd(
 }
 
 /// Intermediate data structure holding values and multiplicities for the SumReduce LogUp argument.
-/// Stores value-ID pairs and multiplicities for input and output terms.
+/// Stores ID-index-value triples and multiplicities for input and output terms.
 #[derive(Uninitialized, IterMut, ParIterMut)]
 struct LookupData {
-    /// Input value-ID pairs: `[input_value, input_node_id]`.
-    input: Vec<[PackedM31; 2]>,
+    /// Input ID-index-value triples: `[input_node_id, input_idx, input_value]`.
+    input: Vec<[PackedM31; 3]>,
     /// Multiplicities for input values.
     input_mult: Vec<PackedM31>,
-    /// Output value-ID pairs: `[out_value, sum_reduce_node_id]`.
-    out: Vec<[PackedM31; 2]>,
+    /// Output ID-index-value triples: `[sum_reduce_node_id, idx, out_value]`.
+    out: Vec<[PackedM31; 3]>,
     /// Multiplicities for output values.
     out_mult: Vec<PackedM31>,
 }
@@ -144,7 +142,7 @@ struct LookupData {
 pub struct InteractionClaimGenerator {
     /// Log2 size of the trace.
     log_size: u32,
-    /// Data (value-ID pairs and multiplicities) needed for LogUp.
+    /// Data (ID-index-value triples and multiplicities) needed for LogUp.
     lookup_data: LookupData,
 }
 
@@ -0,0 +1,305 @@
+use crate::{
+    components::{
+        lookups::{range_check::RangeCheckLookupElements, unary::LutFunction, N_CLAMP_BITS},
+        NodeElements, UnaryClaim,
+    },
+    preprocessed::{eval_structure, Interpolation, LookupLayout},
+};
+use num_traits::{One, Zero};
+use stwo_prover::{
+    constraint_framework::{
+        EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry, ORIGINAL_TRACE_IDX,
+    },
+    core::fields::m31::M31,
+};
+
+use super::table::UnaryTraceTableRow;
+
+/// The STWO AIR component for element-wise operations of a unary LUT function `F` (`f(x)`).
+/// Wraps the `UnaryEval` logic within the STWO `FrameworkComponent`.
+/// Correctness of `f(x)` is enforced via a lookup argument into a preprocessed table.
+pub type UnaryComponent<F> = FrameworkComponent<UnaryEval<F>>;
+
+/// Defines the AIR constraints evaluation logic for the unary component of `F`.
+/// Implements `FrameworkEval` to define trace layout, degrees, and constraints.
+/// Relies heavily on LogUp arguments for consistency.
+///
+/// With an interpolated LUT, the output is `y_i + slope_i * offset` rounded down at the
+/// scale `s` of the LUT. For each remainder, the rounding admits another increment in the
+/// field every `2^(31 - s)`: bounding the increment below `2^delta_bits`, with
+/// `delta_bits <= 29 - s`, leaves a single one.
+pub struct UnaryEval<F: LutFunction> {
+    /// Log2 size of the component's main trace segment.
+    log_size: u32,
+    /// Log2 size of the largest preprocessed table used, the LUT of `F` or the range check.
+    lut_log_size: u32,
+    /// Smallest value of the LUT, to which inputs below the table are clamped.
+    lut_min: M31,
+    /// Largest value of the LUT, to which inputs above the table are clamped.
+    lut_max: M31,
+    /// Fixed-point scale of the LUT, at which interpolated outputs are rounded.
+    lut_scale: u32,
+    /// Interpolation of the LUT, or `None` if every value is an entry.
+    interpolation: Option<Interpolation>,
+    /// Interaction elements for node relations (used in input/output LogUp).
+    node_elements: NodeElements,
+    /// Specific interaction elements for the LUT LogUp of `F`.
+    lookup_elements: F::Elements,
+    /// Interaction elements for the shared range-check lookup, used by interpolated LUTs.
+    range_check_elements: RangeCheckLookupElements,
+}
+
+impl<F: LutFunction> UnaryEval<F> {
+    /// Creates a new `UnaryEval` instance.
+    /// Takes the component's claim, interaction elements for nodes and lookups,
+    /// the layout of the LUT of `F`, and the log_size of the largest preprocessed table used.
+    pub fn new(
+        claim: &UnaryClaim,
+        node_elements: NodeElements,
+        lookup_elements: F::Elements,
+        range_check_elements: RangeCheckLookupElements,
+        layout: &LookupLayout,
+        lut_log_size: u32,
+    ) -> Self {
+        let (lut_min, lut_max) = layout.bounds().unwrap();
+        Self {
+            log_size: claim.log_size,
+            lut_log_size,
+            lut_min: lut_min.to_m31(),
+            lut_max: lut_max.to_m31(),
+            lut_scale: layout.scale,
+            interpolation: layout.interpolation,
+            node_elements,
+            lookup_elements,
+            range_check_elements,
+        }
+    }
+}
+
+/// Returns the largest offset `2^step_bits - 1`, remainder `2^s - 1` and increment magnitude
+/// `2^delta_bits - 1` of the interpolation of a LUT at scale `s`, or zeros for an exact LUT.
+pub fn interpolation_max_values(interpolation: Option<Interpolation>, scale: u32) -> [M31; 3] {
+    match interpolation {
+        Some(interpolation) => [interpolation.step_bits, scale, interpolation.delta_bits]
+            .map(|bits| M31::from_u32_unchecked((1 << bits) - 1)),
+        None => [M31::zero(); 3],
+    }
+}
+
+/// Implements the core constraint evaluation logic for the unary component.
+impl<F: LutFunction> FrameworkEval for UnaryEval<F> {
+    /// Returns the log2 size of this component's main trace segment.
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// Returns the max log2 degree bound, considering both main trace and LUT sizes.
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        std::cmp::max(self.log_size, self.lut_log_size) + 1
+    }
+
+    /// Evaluates the unary AIR constraints on a given evaluation point (`eval`).
+    ///
+    /// Defines constraints for:
+    /// - **Structural:** The IDs, indices, flags and multiplicities match the preprocessed trace.
+    /// - **Consistency:** Ensures `is_last_idx` is boolean, and that the LUT lookup is enabled
+    ///   on every row emitting its output.
+    /// - **Clamp:** Ensures the looked-up `lut_input` is `input_val`, or the LUT bound that
+    ///   `input_val` lies beyond, witnessed by a distance decomposed into `N_CLAMP_BITS` bits.
+    /// - **Interpolation:** Ensures `(out_val - base_out) * 2^s + rem = slope * offset`.
+    ///   In an exact LUT, `offset` and `rem` are zero, hence `out_val = base_out`.
+    ///   In an interpolated LUT, the range checks are enabled on every row emitting its output.
+    /// - **Transition:** Correct state transitions (node/input ID, index increment).
+    /// - **Interaction (LogUp):** Three LogUp arguments are crucial here:
+    ///     1. Links `input_val` (from this trace) to where it's defined elsewhere.
+    ///     2. Links `out_val` (from this trace) to where it's used elsewhere.
+    ///     3. Links the entry `(lut_input - offset, base_out[, slope])` to the preprocessed
+    ///        Lookup Table of `F`, effectively constraining `out_val` to be `f(input_val)`.
+    ///   With an interpolated LUT, `offset`, `rem` and the increment `out_val - base_out`
+    ///   are also sent to the shared range-check lookup along with their gaps to their bounds,
+    ///   so that `offset` lies in `[0, 2^step_bits)`, `rem` in `[0, 2^s)` and the increment
+    ///   in `(-2^delta_bits, 2^delta_bits)`.
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        // IDs (current and next row, for transition constraints)
+        // ID of the node in the computational graph.
+        let [node_id, next_node_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // ID of the input tensor.
+        let [input_id, next_input_id] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Index in the flattened tensor.
+        let [idx, next_idx] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+        // Position of the input value within its tensor.
+        let input_idx = eval.next_trace_mask();
+        let is_last_idx = eval.next_trace_mask(); // Flag if this is the last index for this operation.
+
+        // Values for consistency constraints
+        let input_val = eval.next_trace_mask(); // Value from the tensor at index.
+        let out_val = eval.next_trace_mask(); // Value in output tensor at index.
+
+        // Clamp of the input to the LUT bounds
+        let lut_input = eval.next_trace_mask(); // Value looked up in the LUT.
+        let clamp_low = eval.next_trace_mask(); // Flag if the input lies below the LUT.
+        let clamp_high = eval.next_trace_mask(); // Flag if the input lies above the LUT.
+        let clamp_dist = eval.next_trace_mask(); // Distance beyond the LUT bound, minus one.
+        let clamp_dist_bits: [E::F; N_CLAMP_BITS] = std::array::from_fn(|_| eval.next_trace_mask());
+
+        // Interpolation from the LUT entry
+        let offset = eval.next_trace_mask(); // Distance from the breakpoint of the entry.
+        let base_out = eval.next_trace_mask(); // Output of the entry.
+        let slope = eval.next_trace_mask(); // Slope of the entry.
+        let rem = eval.next_trace_mask(); // Remainder of the rounding of slope * offset.
+
+        // Multiplicities for interaction constraints
+        let input_mult = eval.next_trace_mask();
+        let out_mult = eval.next_trace_mask();
+        let lookup_mult = eval.next_trace_mask();
+        let range_check_mult = eval.next_trace_mask();
+
+        // ┌─────────────────────────────┐
+        // │   Structural Constraints    │
+        // └─────────────────────────────┘
+
+        // The IDs, indices, flags and multiplicities are the ones fixed by the graph.
+        eval_structure::<_, UnaryTraceTableRow>(
+            &mut eval,
+            F::NAME,
+            vec![
+                node_id.clone(),
+                input_id.clone(),
+                idx.clone(),
+                input_idx.clone(),
+                is_last_idx.clone(),
+                input_mult.clone(),
+                out_mult.clone(),
+            ],
+        );
+
+        // ┌─────────────────────────────┐
+        // │   Consistency Constraints   │
+        // └─────────────────────────────┘
+
+        // The is_last_idx flag is either 0 or 1.
+        eval.add_constraint(is_last_idx.clone() * (is_last_idx.clone() - E::F::one()));
+
+        // The clamp flags are boolean and exclusive.
+        eval.add_constraint(clamp_low.clone() * (clamp_low.clone() - E::F::one()));
+        eval.add_constraint(clamp_high.clone() * (clamp_high.clone() - E::F::one()));
+        eval.add_constraint(clamp_low.clone() * clamp_high.clone());
+
+        // An input that is not clamped is looked up as is.
+        let not_clamped = E::F::one() - clamp_low.clone() - clamp_high.clone();
+        eval.add_constraint(not_clamped.clone() * (input_val.clone() - lut_input.clone()));
+        eval.add_constraint(not_clamped * clamp_dist.clone());
+
+        // A clamped input is looked up at the LUT bound it lies beyond, by clamp_dist + 1.
+        let lut_min = E::F::from(self.lut_min);
+        let lut_max = E::F::from(self.lut_max);
+        eval.add_constraint(clamp_low.clone() * (lut_input.clone() - lut_min.clone()));
+        eval.add_constraint(clamp_high.clone() * (lut_input.clone() - lut_max.clone()));
+        eval.add_constraint(
+            clamp_low * (lut_min - E::F::one() - input_val.clone() - clamp_dist.clone()),
+        );
+        eval.add_constraint(
+            clamp_high * (input_val.clone() - lut_max - E::F::one() - clamp_dist.clone()),
+        );
+
+        // The clamp distance is non-negative: it decomposes into N_CLAMP_BITS boolean bits.
+        let mut recomposed = E::F::zero();
+        for (i, bit) in clamp_dist_bits.into_iter().enumerate() {
+            eval.add_constraint(bit.clone() * (bit.clone() - E::F::one()));
+            recomposed = recomposed + bit * E::F::from(M31::from_u32_unchecked(1 << i));
+        }
+        eval.add_constraint(clamp_dist - recomposed);
+
+        // The output is interpolated from the entry, rounded down at the scale of the LUT.
+        let divisor = E::F::from(M31::from_u32_unchecked(1 << self.lut_scale));
+        let increment = out_val.clone() - base_out.clone();
+        eval.add_constraint(
+            increment.clone() * divisor + rem.clone() - slope.clone() * offset.clone(),
+        );
+
+        // An exact LUT holds every value: the output is the one of the entry.
+        if self.interpolation.is_none() {
+            eval.add_constraint(offset.clone());
+            eval.add_constraint(rem.clone());
+        }
+
+        // The lookup multiplicity is boolean, and set on every row emitting its output, so that
+        // every output is looked up in the LUT.
+        eval.add_constraint(lookup_mult.clone() * (lookup_mult.clone() - E::F::one()));
+        eval.add_constraint(out_mult.clone() * (E::F::one() - lookup_mult.clone()));
+
+        // The range-check multiplicity is boolean, and set on every row emitting its output
+        // when the LUT is interpolated. An exact LUT range-checks nothing.
+        eval.add_constraint(range_check_mult.clone() * (range_check_mult.clone() - E::F::one()));
+        if self.interpolation.is_some() {
+            eval.add_constraint(out_mult.clone() * (E::F::one() - range_check_mult.clone()));
+        } else {
+            eval.add_constraint(range_check_mult.clone());
+        }
+
+        // ┌────────────────────────────┐
+        // │   Transition Constraints   │
+        // └────────────────────────────┘
+
+        // If this is not the last index for this operation, then:
+        // 1. The next row should be for the same operation on the same tensors.
+        // 2. The index should increment by 1.
+        let not_last = E::F::one() - is_last_idx;
+
+        // Same node ID
+        eval.add_constraint(not_last.clone() * (next_node_id - node_id.clone()));
+
+        // Same tensor IDs
+        eval.add_constraint(not_last.clone() * (next_input_id - input_id.clone()));
+
+        // Index increment by 1
+        eval.add_constraint(not_last * (next_idx - idx.clone() - E::F::one()));
+
+        // ┌─────────────────────────────┐
+        // │   Interaction Constraints   │
+        // └─────────────────────────────┘
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            input_mult.into(),
+            &[input_id, input_idx, input_val.clone()],
+        ));
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            out_mult.into(),
+            &[node_id, idx, out_val.clone()],
+        ));
+
+        let breakpoint = lut_input - offset.clone();
+        let entry = if self.interpolation.is_some() {
+            vec![breakpoint, base_out, slope]
+        } else {
+            vec![breakpoint, base_out]
+        };
+        F::add_to_relation(&mut eval, &self.lookup_elements, lookup_mult.into(), &entry);
+
+        // Both offset and its gap to 2^step_bits - 1 are non-negative, hence offset lies in
+        // [0, 2^step_bits). Likewise for rem in [0, 2^s) and the shifted increment.
+        let [max_offset, max_rem, max_delta] =
+            interpolation_max_values(self.interpolation, self.lut_scale).map(E::F::from);
+        for value in [
+            offset.clone(),
+            max_offset - offset,
+            rem.clone(),
+            max_rem - rem,
+            max_delta.clone() + increment.clone(),
+            max_delta - increment,
+        ] {
+            eval.add_to_relation(RelationEntry::new(
+                &self.range_check_elements,
+                range_check_mult.clone().into(),
+                &[value],
+            ));
+        }
+
+        eval.finalize_logup();
+
+        eval
+    }
+}
@@ -0,0 +1,378 @@
+use num_traits::{One, Zero};
+use serde::{Deserialize, Serialize};
+use stwo_prover::core::{
+    backend::simd::{
+        conversion::{Pack, Unpack},
+        m31::{PackedM31, N_LANES},
+    },
+    fields::m31::M31,
+};
+
+use crate::{
+    components::{lookups::N_CLAMP_BITS, TraceColumn},
+    preprocessed::StructuredRow,
+};
+
+use super::witness::N_TRACE_COLUMNS;
+
+/// Represents the raw trace data collected for the operations of a unary LUT function (`f(x)`).
+///
+/// Stores rows capturing inputs, outputs, and metadata for each operation,
+/// including multiplicities for LogUp and the interaction with the LUT of the function.
+/// The table is shared by all `LutFunction`s: the function is carried by the `TraceTable` variant.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct UnaryTraceTable {
+    /// Vector containing all rows of the trace.
+    pub table: Vec<UnaryTraceTableRow>,
+}
+
+/// Represents a single row in the `UnaryTraceTable`.
+///
+/// Contains values for evaluating the AIR constraints of the unary component: state IDs,
+/// input/output values, the LUT entry and its interpolation, and multiplicities for LogUp
+/// (input/output), LUT interaction and range checks.
+#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
+pub struct UnaryTraceTableRow {
+    /// ID of the current node.
+    pub node_id: M31,
+    /// ID of the node providing the input.
+    pub input_id: M31,
+    /// Index within the tensor for this operation.
+    pub idx: M31,
+    /// Position of the input value within the input tensor.
+    pub input_idx: M31,
+    /// Flag indicating if this is the last element processed for this node (1 if true, 0 otherwise).
+    pub is_last_idx: M31,
+    /// Value of the input (`x`).
+    pub input: M31,
+    /// Value of the output (`f(x)`).
+    pub out: M31,
+    /// Value looked up in the LUT: the input, or the table bound it is clamped to.
+    pub lut_input: M31,
+    /// Flag indicating if the input is clamped to the smallest value of the LUT.
+    pub clamp_low: M31,
+    /// Flag indicating if the input is clamped to the largest value of the LUT.
+    pub clamp_high: M31,
+    /// Distance of a clamped input beyond the table bound, minus one.
+    pub clamp_dist: M31,
+    /// Bits of the clamp distance, little-endian.
+    pub clamp_dist_bits: [M31; N_CLAMP_BITS],
+    /// Distance between the looked up value and the breakpoint of its LUT entry.
+    pub offset: M31,
+    /// Output of the LUT entry (`f(x_i)`).
+    pub base_out: M31,
+    /// Slope of the LUT entry, zero in an exact LUT.
+    pub slope: M31,
+    /// Remainder of the rounding of `slope * offset` down to the scale of the LUT.
+    pub rem: M31,
+    /// Multiplicity contribution for the LogUp argument (input).
+    pub input_mult: M31,
+    /// Multiplicity contribution for the LogUp argument (output).
+    pub out_mult: M31,
+    /// Multiplicity contribution for the Lookup Table interaction.
+    pub lookup_mult: M31,
+    /// Multiplicity contribution for the range checks of an interpolated LUT.
+    pub range_check_mult: M31,
+}
+
+impl UnaryTraceTableRow {
+    /// Creates a default padding row for the unary trace.
+    pub(crate) fn padding() -> Self {
+        Self {
+            node_id: M31::zero(),
+            input_id: M31::zero(),
+            idx: M31::zero(),
+            input_idx: M31::zero(),
+            is_last_idx: M31::one(),
+            input: M31::zero(),
+            out: M31::zero(),
+            lut_input: M31::zero(),
+            clamp_low: M31::zero(),
+            clamp_high: M31::zero(),
+            clamp_dist: M31::zero(),
+            clamp_dist_bits: [M31::zero(); N_CLAMP_BITS],
+            offset: M31::zero(),
+            base_out: M31::zero(),
+            slope: M31::zero(),
+            rem: M31::zero(),
+            input_mult: M31::zero(),
+            out_mult: M31::zero(),
+            lookup_mult: M31::zero(),
+            range_check_mult: M31::zero(),
+        }
+    }
+}
+
+/// Implements the `StructuredRow` trait for `UnaryTraceTableRow`.
+impl StructuredRow for UnaryTraceTableRow {
+    /// IDs, indices, flags and multiplicities of the row, fixed by the graph.
+    const STRUCTURE: &'static [&'static str] = &[
+        "node_id",
+        "input_id",
+        "idx",
+        "input_idx",
+        "is_last_idx",
+        "input_mult",
+        "out_mult",
+    ];
+
+    /// Returns the values of the structural columns of the row.
+    fn structure(&self) -> Vec<M31> {
+        vec![
+            self.node_id,
+            self.input_id,
+            self.idx,
+            self.input_idx,
+            self.is_last_idx,
+            self.input_mult,
+            self.out_mult,
+        ]
+    }
+}
+
+/// SIMD-packed representation of a `UnaryTraceTableRow`.
+#[derive(Debug, Copy, Clone)]
+pub struct PackedUnaryTraceTableRow {
+    /// Packed `node_id` values.
+    pub node_id: PackedM31,
+    /// Packed `input_id` values.
+    pub input_id: PackedM31,
+    /// Packed `idx` values.
+    pub idx: PackedM31,
+    /// Packed `input_idx` values.
+    pub input_idx: PackedM31,
+    /// Packed `is_last_idx` values.
+    pub is_last_idx: PackedM31,
+    /// Packed `input` values.
+    pub input: PackedM31,
+    /// Packed `out` values.
+    pub out: PackedM31,
+    /// Packed `lut_input` values.
+    pub lut_input: PackedM31,
+    /// Packed `clamp_low` values.
+    pub clamp_low: PackedM31,
+    /// Packed `clamp_high` values.
+    pub clamp_high: PackedM31,
+    /// Packed `clamp_dist` values.
+    pub clamp_dist: PackedM31,
+    /// Packed `clamp_dist_bits` values.
+    pub clamp_dist_bits: [PackedM31; N_CLAMP_BITS],
+    /// Packed `offset` values.
+    pub offset: PackedM31,
+    /// Packed `base_out` values.
+    pub base_out: PackedM31,
+    /// Packed `slope` values.
+    pub slope: PackedM31,
+    /// Packed `rem` values.
+    pub rem: PackedM31,
+    /// Packed `input_mult` values.
+    pub input_mult: PackedM31,
+    /// Packed `out_mult` values.
+    pub out_mult: PackedM31,
+    /// Packed `lookup_mult` values.
+    pub lookup_mult: PackedM31,
+    /// Packed `range_check_mult` values.
+    pub range_check_mult: PackedM31,
+}
+
+impl Pack for UnaryTraceTableRow {
+    type SimdType = PackedUnaryTraceTableRow;
+
+    fn pack(inputs: [Self; N_LANES]) -> Self::SimdType {
+        PackedUnaryTraceTableRow {
+            node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].node_id)),
+            input_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_id)),
+            idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].idx)),
+            input_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_idx)),
+            is_last_idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].is_last_idx)),
+            input: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input)),
+            out: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out)),
+            lut_input: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lut_input)),
+            clamp_low: PackedM31::from_array(std::array::from_fn(|i| inputs[i].clamp_low)),
+            clamp_high: PackedM31::from_array(std::array::from_fn(|i| inputs[i].clamp_high)),
+            clamp_dist: PackedM31::from_array(std::array::from_fn(|i| inputs[i].clamp_dist)),
+            clamp_dist_bits: std::array::from_fn(|b| {
+                PackedM31::from_array(std::array::from_fn(|i| inputs[i].clamp_dist_bits[b]))
+            }),
+            offset: PackedM31::from_array(std::array::from_fn(|i| inputs[i].offset)),
+            base_out: PackedM31::from_array(std::array::from_fn(|i| inputs[i].base_out)),
+            slope: PackedM31::from_array(std::array::from_fn(|i| inputs[i].slope)),
+            rem: PackedM31::from_array(std::array::from_fn(|i| inputs[i].rem)),
+            input_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].input_mult)),
+            out_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].out_mult)),
+            lookup_mult: PackedM31::from_array(std::array::from_fn(|i| inputs[i].lookup_mult)),
+            range_check_mult: PackedM31::from_array(std::array::from_fn(|i| {
+                inputs[i].range_check_mult
+            })),
+        }
+    }
+}
+
+impl Unpack for PackedUnaryTraceTableRow {
+    type CpuType = UnaryTraceTableRow;
+
+    fn unpack(self) -> [Self::CpuType; N_LANES] {
+        let (
+            node_id,
+            input_id,
+            idx,
+            input_idx,
+            is_last_idx,
+            input,
+            out,
+            lut_input,
+            clamp_low,
+            clamp_high,
+            clamp_dist,
+            clamp_dist_bits,
+            offset,
+            base_out,
+            slope,
+            rem,
+            input_mult,
+            out_mult,
+            lookup_mult,
+            range_check_mult,
+        ) = (
+            self.node_id.to_array(),
+            self.input_id.to_array(),
+            self.idx.to_array(),
+            self.input_idx.to_array(),
+            self.is_last_idx.to_array(),
+            self.input.to_array(),
+            self.out.to_array(),
+            self.lut_input.to_array(),
+            self.clamp_low.to_array(),
+            self.clamp_high.to_array(),
+            self.clamp_dist.to_array(),
+            self.clamp_dist_bits.map(|b| b.to_array()),
+            self.offset.to_array(),
+            self.base_out.to_array(),
+            self.slope.to_array(),
+            self.rem.to_array(),
+            self.input_mult.to_array(),
+            self.out_mult.to_array(),
+            self.lookup_mult.to_array(),
+            self.range_check_mult.to_array(),
+        );
+
+        std::array::from_fn(|i| UnaryTraceTableRow {
+            node_id: node_id[i],
+            input_id: input_id[i],
+            idx: idx[i],
+            input_idx: input_idx[i],
+            is_last_idx: is_last_idx[i],
+            input: input[i],
+            out: out[i],
+            lut_input: lut_input[i],
+            clamp_low: clamp_low[i],
+            clamp_high: clamp_high[i],
+            clamp_dist: clamp_dist[i],
+            clamp_dist_bits: clamp_dist_bits.map(|b| b[i]),
+            offset: offset[i],
+            base_out: base_out[i],
+            slope: slope[i],
+            rem: rem[i],
+            input_mult: input_mult[i],
+            out_mult: out_mult[i],
+            lookup_mult: lookup_mult[i],
+            range_check_mult: range_check_mult[i],
+        })
+    }
+}
+
+impl UnaryTraceTable {
+    /// Creates a new, empty `UnaryTraceTable`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a single row to the trace table.
+    pub fn add_row(&mut self, row: UnaryTraceTableRow) {
+        self.table.push(row);
+    }
+}
+
+/// Enum defining the columns of the unary AIR component's trace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum UnaryColumn {
+    /// ID of the current node.
+    NodeId,
+    /// ID of the node providing the input.
+    InputId,
+    /// Index within the tensor for this operation.
+    Idx,
+    /// Position of the input value within the input tensor.
+    InputIdx,
+    /// Flag indicating if this is the last element processed for this node.
+    IsLastIdx,
+    /// Value of the input (`x`).
+    Input,
+    /// Value of the output (`f(x)`).
+    Out,
+    /// Value looked up in the LUT.
+    LutInput,
+    /// Flag indicating if the input is clamped to the smallest value of the LUT.
+    ClampLow,
+    /// Flag indicating if the input is clamped to the largest value of the LUT.
+    ClampHigh,
+    /// Distance of a clamped input beyond the table bound, minus one.
+    ClampDist,
+    /// The `i`-th bit of the clamp distance.
+    ClampDistBit(usize),
+    /// Distance between the looked up value and the breakpoint of its LUT entry.
+    Offset,
+    /// Output of the LUT entry.
+    BaseOut,
+    /// Slope of the LUT entry.
+    Slope,
+    /// Remainder of the rounding of `slope * offset`.
+    Rem,
+    /// Multiplicity for the LogUp argument (input).
+    InputMult,
+    /// Multiplicity for the LogUp argument (output).
+    OutMult,
+    /// Multiplicity for the Lookup Table interaction.
+    LookupMult,
+    /// Multiplicity for the range checks of an interpolated LUT.
+    RangeCheckMult,
+}
+
+impl UnaryColumn {
+    /// Returns the 0-based index for this column within the unary trace segment.
+    pub const fn index(self) -> usize {
+        match self {
+            Self::NodeId => 0,
+            Self::InputId => 1,
+            Self::Idx => 2,
+            Self::InputIdx => 3,
+            Self::IsLastIdx => 4,
+            Self::Input => 5,
+            Self::Out => 6,
+            Self::LutInput => 7,
+            Self::ClampLow => 8,
+            Self::ClampHigh => 9,
+            Self::ClampDist => 10,
+            Self::ClampDistBit(i) => 11 + i,
+            Self::Offset => 11 + N_CLAMP_BITS,
+            Self::BaseOut => 12 + N_CLAMP_BITS,
+            Self::Slope => 13 + N_CLAMP_BITS,
+            Self::Rem => 14 + N_CLAMP_BITS,
+            Self::InputMult => 15 + N_CLAMP_BITS,
+            Self::OutMult => 16 + N_CLAMP_BITS,
+            Self::LookupMult => 17 + N_CLAMP_BITS,
+            Self::RangeCheckMult => 18 + N_CLAMP_BITS,
+        }
+    }
+}
+
+/// Implements the `TraceColumn` trait for `UnaryColumn`.
+impl TraceColumn for UnaryColumn {
+    /// Specifies the number of columns used by the unary component.
+    /// Returns `(N_TRACE_COLUMNS, 9)`, indicating the number of main trace columns
+    /// and 9 interaction trace columns (input LogUp, output LogUp, LUT interaction,
+    /// and the six range checks of an interpolated LUT).
+    fn count() -> (usize, usize) {
+        (N_TRACE_COLUMNS, 9)
+    }
+}
@@ -0,0 +1,292 @@
+use std::marker::PhantomData;
+
+use crate::{
+    components::{
+        lookups::{range_check::RangeCheckLookupElements, unary::LutFunction, N_CLAMP_BITS},
+        InteractionClaim, NodeElements, UnaryClaim,
+    },
+    preprocessed::{Interpolation, LookupLayout},
+    utils::{pack_values, to_trace_order, TreeBuilder},
+};
+use luminair_utils::TraceError;
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use stwo_air_utils::trace::component_trace::ComponentTrace;
+use stwo_air_utils_derive::{IterMut, ParIterMut, Uninitialized};
+use stwo_prover::{
+    constraint_framework::{logup::LogupTraceGenerator, Relation},
+    core::{
+        backend::simd::{
+            m31::{PackedM31, LOG_N_LANES, N_LANES},
+            qm31::PackedQM31,
+            SimdBackend,
+        },
+        fields::m31::M31,
+    },
+};
+
+use super::{
+    component::interpolation_max_values,
+    table::{PackedUnaryTraceTableRow, UnaryColumn, UnaryTraceTable, UnaryTraceTableRow},
+};
+
+/// Number of main trace columns for the unary component.
+pub(crate) const N_TRACE_COLUMNS: usize = 19 + N_CLAMP_BITS;
+
+/// Generates main trace and interaction data for the unary component of the function `F`.
+///
+/// Takes the raw `UnaryTraceTable`, processes it into main STARK trace columns,
+/// and prepares `LookupData` for the LogUp arguments: input, output, LUT interaction,
+/// and the range checks of an interpolated LUT.
+pub struct ClaimGenerator<F: LutFunction> {
+    /// The raw trace data for the operations of `F`.
+    pub inputs: UnaryTraceTable,
+    /// Interpolation of the LUT of `F`, or `None` if every value is an entry.
+    pub interpolation: Option<Interpolation>,
+    /// Fixed-point scale of the LUT of `F`.
+    pub lut_scale: u32,
+    /// Marker binding the generator to the LUT function.
+    _function: PhantomData<F>,
+}
+
+impl<F: LutFunction> ClaimGenerator<F> {
+    /// Creates a new `ClaimGenerator` with the given `UnaryTraceTable` and the layout of the LUT.
+    pub fn new(inputs: UnaryTraceTable, layout: &LookupLayout) -> Self {
+        Self {
+            inputs,
+            interpolation: layout.interpolation,
+            lut_scale: layout.scale,
+            _function: PhantomData,
+        }
+    }
+
+    /// Writes the main trace columns and returns data for the interaction phase.
+    ///
+    /// Standard procedure: pads table, packs rows, calls `write_trace_simd`,
+    /// adds main trace to `tree_builder`, returns `UnaryClaim` and `InteractionClaimGenerator`.
+    /// Returns `TraceError::EmptyTrace` if the input table is empty.
+    pub fn write_trace(
+        mut self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+    ) -> Result<(UnaryClaim, InteractionClaimGenerator<F>), TraceError> {
+        let n_rows = self.inputs.table.len();
+
+        if n_rows == 0 {
+            return Err(TraceError::EmptyTrace);
+        }
+
+        let size = std::cmp::max(n_rows.next_power_of_two(), N_LANES);
+        let log_size = size.ilog2();
+
+        self.inputs
+            .table
+            .resize(size, UnaryTraceTableRow::padding());
+        let packed_inputs = pack_values(&to_trace_order(&self.inputs.table));
+
+        let max_values = interpolation_max_values(self.interpolation, self.lut_scale);
+        let (trace, lookup_data) = write_trace_simd(packed_inputs, max_values);
+
+        tree_builder.extend_evals(trace.to_evals());
+
+        Ok((
+            UnaryClaim::new(log_size),
+            InteractionClaimGenerator {
+                log_size,
+                lookup_data,
+                interpolated: self.interpolation.is_some(),
+                _function: PhantomData,
+            },
+        ))
+    }
+}
+
+/// Populates main trace columns and `LookupData` from SIMD-packed unary trace rows.
+///
+/// Processes `PackedUnaryTraceTableRow` data in parallel:
+/// - Maps fields to corresponding main trace columns.
+/// - Extracts `[id, idx, value]` triples and multiplicities for input and output LogUps,
+///   the LUT entry and `lookup_mult` for the LUT interaction, and the offset, remainder and
+///   increment of the interpolation with their gaps to `max_values` for the range checks,
+///   into `LookupData`.
+/// Returns the `ComponentTrace` and `LookupData`.
+fn write_trace_simd(
+    inputs: Vec<PackedUnaryTraceTableRow>,
+    max_values: [M31; 3],
+) -> (ComponentTrace<N_TRACE_COLUMNS>, LookupData) {
+    let log_n_packed_rows = inputs.len().ilog2();
+    let log_size = log_n_packed_rows + LOG_N_LANES;
+
+    let (mut trace, mut lookup_data) = unsafe {
+        (
+            ComponentTrace::<N_TRACE_COLUMNS>::uninitialized(log_size),
+            LookupData::uninitialized(log_n_packed_rows),
+        )
+    };
+
+    let [max_offset, max_rem, max_delta] = max_values.map(PackedM31::broadcast);
+
+    (
+        trace.par_iter_mut(),
+        lookup_data.par_iter_mut(),
+        inputs.into_par_iter(),
+    )
+        .into_par_iter()
+        .for_each(|(mut row, lookup_data, input)| {
+            *row[UnaryColumn::NodeId.index()] = input.node_id;
+            *row[UnaryColumn::InputId.index()] = input.input_id;
+            *row[UnaryColumn::Idx.index()] = input.idx;
+            *row[UnaryColumn::InputIdx.index()] = input.input_idx;
+            *row[UnaryColumn::IsLastIdx.index()] = input.is_last_idx;
+            *row[UnaryColumn::Input.index()] = input.input;
+            *row[UnaryColumn::Out.index()] = input.out;
+            *row[UnaryColumn::LutInput.index()] = input.lut_input;
+            *row[UnaryColumn::ClampLow.index()] = input.clamp_low;
+            *row[UnaryColumn::ClampHigh.index()] = input.clamp_high;
+            *row[UnaryColumn::ClampDist.index()] = input.clamp_dist;
+            for (i, bit) in input.clamp_dist_bits.into_iter().enumerate() {
+                *row[UnaryColumn::ClampDistBit(i).index()] = bit;
+            }
+            *row[UnaryColumn::Offset.index()] = input.offset;
+            *row[UnaryColumn::BaseOut.index()] = input.base_out;
+            *row[UnaryColumn::Slope.index()] = input.slope;
+            *row[UnaryColumn::Rem.index()] = input.rem;
+            *row[UnaryColumn::InputMult.index()] = input.input_mult;
+            *row[UnaryColumn::OutMult.index()] = input.out_mult;
+            *row[UnaryColumn::LookupMult.index()] = input.lookup_mult;
+            *row[UnaryColumn::RangeCheckMult.index()] = input.range_check_mult;
+
+            let increment = input.out - input.base_out;
+
+            *lookup_data.input = [input.input_id, input.input_idx, input.input];
+            *lookup_data.input_mult = input.input_mult;
+            *lookup_data.out = [input.node_id, input.idx, input.out];
+            *lookup_data.out_mult = input.out_mult;
+            *lookup_data.lut_entry = [input.lut_input - input.offset, input.base_out, input.slope];
+            *lookup_data.lookup_mult = input.lookup_mult;
+            *lookup_data.range_checks = [
+                input.offset,
+                max_offset - input.offset,
+                input.rem,
+                max_rem - input.rem,
+                max_delta + increment,
+                max_delta - increment,
+            ];
+            *lookup_data.range_check_mult = input.range_check_mult;
+        });
+
+    (trace, lookup_data)
+}
+
+/// Intermediate data for the unary component's LogUp arguments.
+///
+/// Holds ID-index-value triples and multiplicities for input and output terms,
+/// the entries and multiplicities for the interaction with the Lookup Table of the function,
+/// and the values range-checked by an interpolated LUT.
+/// Derives helper iterators for parallel processing.
+#[derive(Uninitialized, IterMut, ParIterMut)]
+struct LookupData {
+    /// Input ID-index-value triples: `[input_node_id, input_idx, input_value]`.
+    input: Vec<[PackedM31; 3]>,
+    /// LUT entries looked up: `[breakpoint, base_out, slope]`.
+    lut_entry: Vec<[PackedM31; 3]>,
+    /// Multiplicities for input values (LogUp).
+    input_mult: Vec<PackedM31>,
+    /// Output ID-index-value triples: `[node_id, idx, out_value]`.
+    out: Vec<[PackedM31; 3]>,
+    /// Multiplicities for output values (LogUp).
+    out_mult: Vec<PackedM31>,
+    /// Multiplicities for the LUT interaction.
+    lookup_mult: Vec<PackedM31>,
+    /// Offsets, remainders, shifted increments and their gaps, range-checked.
+    range_checks: Vec<[PackedM31; 6]>,
+    /// Multiplicities for the range checks.
+    range_check_mult: Vec<PackedM31>,
+}
+
+/// Generates interaction trace columns for the LogUp arguments of the unary component of `F`.
+///
+/// Builds nine LogUp interaction columns:
+/// 1. Input term: `(input_value, input_node_id)` with `NodeElements`.
+/// 2. Output term: `(out_value, node_id)` with `NodeElements`.
+/// 3. LUT term: `(breakpoint, base_out[, slope])` with the lookup elements of `F`.
+/// 4. Six range-check terms for the interpolation, with `RangeCheckLookupElements`.
+pub struct InteractionClaimGenerator<F: LutFunction> {
+    /// Log2 size of the trace.
+    log_size: u32,
+    /// Data for LogUp arguments.
+    lookup_data: LookupData,
+    /// Whether the LUT is interpolated, its entries holding a slope.
+    interpolated: bool,
+    /// Marker binding the generator to the LUT function.
+    _function: PhantomData<F>,
+}
+
+impl<F: LutFunction> InteractionClaimGenerator<F> {
+    /// Writes the nine LogUp interaction trace columns to the `tree_builder`.
+    ///
+    /// - Initializes a `LogupTraceGenerator`.
+    /// - For Input LogUp: combines `lookup_data.input[i]` with `node_elements` for denominator.
+    /// - For Output LogUp: combines `lookup_data.out[i]` with `node_elements` for denominator.
+    /// - For LUT Interaction: combines `lookup_data.lut_entry[i]` (raw values, without the slope
+    ///   for an exact LUT) with `lookup_elements` for the denominator.
+    /// - For the range checks: combines each of `lookup_data.range_checks[i]` with
+    ///   `range_check_elements` for the denominator.
+    /// - Writes `multiplicity / denominator` fractions for each.
+    /// - Finalizes the generator, adds columns to `tree_builder`, returns `InteractionClaim`.
+    pub fn write_interaction_trace(
+        self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+        node_elements: &NodeElements,
+        lookup_elements: &F::Elements, // Specific randomness for the LUT of F
+        range_check_elements: &RangeCheckLookupElements,
+    ) -> InteractionClaim {
+        let mut logup_gen = LogupTraceGenerator::new(self.log_size);
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let values = &self.lookup_data.input[row];
+            let multiplicity = &self.lookup_data.input_mult[row];
+
+            let denom: PackedQM31 = node_elements.combine(values);
+            col_gen.write_frac(row, (*multiplicity).into(), denom);
+        }
+        col_gen.finalize_col();
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let values = &self.lookup_data.out[row];
+            let multiplicity = &self.lookup_data.out_mult[row];
+
+            let denom: PackedQM31 = node_elements.combine(values);
+            col_gen.write_frac(row, (*multiplicity).into(), denom);
+        }
+        col_gen.finalize_col();
+
+        let entry_len = if self.interpolated { 3 } else { 2 };
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let entry = &self.lookup_data.lut_entry[row][..entry_len];
+            let multiplicity = self.lookup_data.lookup_mult[row];
+
+            let denom: PackedQM31 = F::combine(lookup_elements, entry);
+            col_gen.write_frac(row, multiplicity.into(), denom);
+        }
+        col_gen.finalize_col();
+
+        for i in 0..6 {
+            let mut col_gen = logup_gen.new_col();
+            for row in 0..1 << (self.log_size - LOG_N_LANES) {
+                let value = self.lookup_data.range_checks[row][i];
+                let multiplicity = self.lookup_data.range_check_mult[row];
+
+                let denom: PackedQM31 = range_check_elements.combine(&[value]);
+                col_gen.write_frac(row, multiplicity.into(), denom);
+            }
+            col_gen.finalize_col();
+        }
+
+        let (trace, claimed_sum) = logup_gen.finalize_last();
+        tree_builder.extend_evals(trace);
+
+        InteractionClaim { claimed_sum }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{
+    components::{NodeElements, WeightsClaim},
+    preprocessed::eval_structure,
+};
+use stwo_prover::constraint_framework::{
+    EvalAtRow, FrameworkComponent, FrameworkEval, RelationEntry,
+};
+
+use super::{table::WeightsTraceTableRow, WEIGHTS_TRACE_IDX};
+
+/// The STWO AIR component for the committed weights.
+///
+/// This wraps the `WeightsEval` logic within the STWO `FrameworkComponent`,
+/// which handles common AIR component setup and evaluation.
+pub type WeightsComponent = FrameworkComponent<WeightsEval>;
+
+/// Defines the AIR constraints evaluation logic for the Weights component.
+///
+/// Implements the `FrameworkEval` trait, providing methods to define the component's
+/// trace layout, constraint degrees, and the core constraint evaluation function.
+pub struct WeightsEval {
+    /// Log2 size of the component's trace segment.
+    log_size: u32,
+    /// Interaction elements for node relations (used in LogUp).
+    node_elements: NodeElements,
+}
+
+impl WeightsEval {
+    /// Creates a new `WeightsEval` instance.
+    /// Takes the component's claim (for `log_size`) and interaction elements.
+    pub fn new(claim: &WeightsClaim, node_elements: NodeElements) -> Self {
+        Self {
+            log_size: claim.log_size,
+            node_elements,
+        }
+    }
+}
+
+/// Implements the core constraint evaluation logic for the Weights component.
+impl FrameworkEval for WeightsEval {
+    /// Returns the log2 size of this component's trace segment.
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// Returns the maximum expected log2 degree bound for the component's constraints.
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.log_size + 1
+    }
+
+    /// Evaluates the Weights AIR constraints on a given evaluation point (`eval`).
+    ///
+    /// The node IDs, indices and values are read from the committed weights tree, whose
+    /// root is fixed by the verifying key. The LogUp interaction emits each committed weight
+    /// in the node relation with the multiplicity found in the main trace, which matches the
+    /// preprocessed trace. Since the weights are not public inputs, and the node IDs of the
+    /// other components are fixed by the preprocessed trace, the reads of the consuming nodes
+    /// can only be balanced by these entries. The blinding rows of the tree have a zero
+    /// multiplicity, and emit nothing.
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        let [node_id] = eval.next_interaction_mask(WEIGHTS_TRACE_IDX, [0]);
+        let [idx] = eval.next_interaction_mask(WEIGHTS_TRACE_IDX, [0]);
+        let [value] = eval.next_interaction_mask(WEIGHTS_TRACE_IDX, [0]);
+
+        let multiplicity = eval.next_trace_mask();
+
+        // ┌─────────────────────────────┐
+        // │   Structural Constraints    │
+        // └─────────────────────────────┘
+
+        // The number of reads of each weight is the one fixed by the graph.
+        eval_structure::<_, WeightsTraceTableRow>(&mut eval, "weights", vec![multiplicity.clone()]);
+
+        // ┌─────────────────────────────┐
+        // │   Interaction Constraints   │
+        // └─────────────────────────────┘
+
+        eval.add_to_relation(RelationEntry::new(
+            &self.node_elements,
+            multiplicity.into(),
+            &[node_id, idx, value],
+        ));
+
+        eval.finalize_logup();
+
+        eval
+    }
+}
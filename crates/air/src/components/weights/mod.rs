@@ -0,0 +1,21 @@
+pub mod component;
+pub mod table;
+pub mod witness;
+
+use stwo_prover::core::pcs::PcsConfig;
+
+/// Index of the committed weights tree in the commitment scheme.
+///
+/// The weights tree is committed after the interaction trace, but its root is fixed in advance
+/// (it is published with the model and mixed into the channel before the main trace), so the
+/// committed values cannot depend on the interaction elements.
+pub const WEIGHTS_TRACE_IDX: usize = 3;
+
+/// Returns the number of blinding rows appended to the committed weights tree.
+///
+/// The proof reveals each column of the tree at the out-of-domain point, and at the two
+/// points of each FRI query of the default `PcsConfig`. As many uniformly random rows make
+/// these evaluations independent of the weights.
+pub fn n_blinding_rows() -> usize {
+    2 * PcsConfig::default().fri_config.n_queries + 1
+}
@@ -0,0 +1,188 @@
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+use stwo_prover::core::{
+    backend::simd::{
+        conversion::{Pack, Unpack},
+        m31::{PackedM31, N_LANES},
+    },
+    channel::{Blake2sChannel, Channel},
+    fields::m31::M31,
+};
+
+use crate::{components::TraceColumn, preprocessed::StructuredRow};
+
+use super::n_blinding_rows;
+
+use super::witness::{N_TRACE_COLUMNS, N_WEIGHTS_COLUMNS};
+
+/// Represents the raw trace data collected for the committed weights.
+///
+/// Each row holds one element of a weight tensor, along with the number of times
+/// it is read by the operations of the graph.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct WeightsTraceTable {
+    /// Vector containing all rows of the Weights trace.
+    pub table: Vec<WeightsTraceTableRow>,
+}
+
+/// Represents a single row in the `WeightsTraceTable`.
+#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
+pub struct WeightsTraceTableRow {
+    /// ID of the node emitting the weight tensor in the node relation.
+    pub node_id: M31,
+    /// Position of the value within the weight tensor.
+    pub idx: M31,
+    /// Value of the weight.
+    pub value: M31,
+    /// Number of times the value is read by the consuming nodes.
+    pub multiplicity: M31,
+}
+
+impl WeightsTraceTableRow {
+    /// Creates a default padding row for the Weights trace (multiplicity 0).
+    pub(crate) fn padding() -> Self {
+        Self {
+            node_id: M31::zero(),
+            idx: M31::zero(),
+            value: M31::zero(),
+            multiplicity: M31::zero(),
+        }
+    }
+}
+
+/// Implements the `StructuredRow` trait for `WeightsTraceTableRow`.
+impl StructuredRow for WeightsTraceTableRow {
+    /// IDs, indices, flags and multiplicities of the row, fixed by the graph.
+    const STRUCTURE: &'static [&'static str] = &["multiplicity"];
+
+    /// Returns the values of the structural columns of the row.
+    fn structure(&self) -> Vec<M31> {
+        vec![self.multiplicity]
+    }
+}
+
+/// SIMD-packed representation of a `WeightsTraceTableRow`.
+#[derive(Debug, Copy, Clone)]
+pub struct PackedWeightsTraceTableRow {
+    pub node_id: PackedM31,
+    pub idx: PackedM31,
+    pub value: PackedM31,
+    pub multiplicity: PackedM31,
+}
+
+impl Pack for WeightsTraceTableRow {
+    type SimdType = PackedWeightsTraceTableRow;
+
+    fn pack(inputs: [Self; N_LANES]) -> Self::SimdType {
+        PackedWeightsTraceTableRow {
+            node_id: PackedM31::from_array(std::array::from_fn(|i| inputs[i].node_id)),
+            idx: PackedM31::from_array(std::array::from_fn(|i| inputs[i].idx)),
+            value: PackedM31::from_array(std::array::from_fn(|i| inputs[i].value)),
+            multiplicity: PackedM31::from_array(std::array::from_fn(|i| inputs[i].multiplicity)),
+        }
+    }
+}
+
+impl Unpack for PackedWeightsTraceTableRow {
+    type CpuType = WeightsTraceTableRow;
+
+    fn unpack(self) -> [Self::CpuType; N_LANES] {
+        let (node_ids, idxs, values, multiplicities) = (
+            self.node_id.to_array(),
+            self.idx.to_array(),
+            self.value.to_array(),
+            self.multiplicity.to_array(),
+        );
+
+        std::array::from_fn(|i| WeightsTraceTableRow {
+            node_id: node_ids[i],
+            idx: idxs[i],
+            value: values[i],
+            multiplicity: multiplicities[i],
+        })
+    }
+}
+
+impl WeightsTraceTable {
+    /// Creates a new, empty `WeightsTraceTable`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a single row to the trace table.
+    pub fn add_row(&mut self, row: WeightsTraceTableRow) {
+        self.table.push(row);
+    }
+
+    /// Appends the blinding rows of the committed weights tree, derived from `salt`.
+    ///
+    /// Blinding rows hold a pseudo-random value and a zero multiplicity, so that they emit
+    /// nothing in the node relation. The same salt always yields the same rows.
+    pub fn add_blinding_rows(&mut self, salt: &[u64; 4]) {
+        let n_rows = n_blinding_rows();
+        let channel = &mut Blake2sChannel::default();
+        for word in salt {
+            channel.mix_u64(*word);
+        }
+        let values = channel
+            .draw_felts(n_rows.div_ceil(4))
+            .into_iter()
+            .flat_map(|felt| felt.to_m31_array());
+
+        for value in values.take(n_rows) {
+            self.add_row(WeightsTraceTableRow {
+                value,
+                ..WeightsTraceTableRow::padding()
+            });
+        }
+    }
+}
+
+/// Enum defining the columns of the Weights AIR component's main trace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum WeightsColumn {
+    /// Column storing the number of reads of each weight value.
+    Multiplicity,
+}
+
+impl WeightsColumn {
+    /// Returns the 0-based index for this column within the Weights main trace segment.
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Multiplicity => 0,
+        }
+    }
+}
+
+/// Enum defining the columns of the committed weights tree.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum WeightsCommittedColumn {
+    NodeId,
+    Idx,
+    Value,
+}
+
+impl WeightsCommittedColumn {
+    /// Returns the 0-based index for this column within the committed weights tree.
+    pub const fn index(self) -> usize {
+        match self {
+            Self::NodeId => 0,
+            Self::Idx => 1,
+            Self::Value => 2,
+        }
+    }
+}
+
+/// Implements the `TraceColumn` trait for `WeightsColumn`.
+impl TraceColumn for WeightsColumn {
+    /// Returns `(N_TRACE_COLUMNS, 1)`: the multiplicity column in the main trace,
+    /// and one interaction column for the LogUp argument emitting the weights.
+    fn count() -> (usize, usize) {
+        (N_TRACE_COLUMNS, 1)
+    }
+
+    /// The node IDs, positions and values of the weights live in the committed weights tree.
+    fn weights_count() -> usize {
+        N_WEIGHTS_COLUMNS
+    }
+}
@@ -0,0 +1,237 @@
+use luminair_utils::TraceError;
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use stwo_air_utils::trace::component_trace::ComponentTrace;
+use stwo_air_utils_derive::{IterMut, ParIterMut, Uninitialized};
+use stwo_prover::{
+    constraint_framework::{logup::LogupTraceGenerator, Relation},
+    core::{
+        backend::simd::{
+            m31::{PackedM31, LOG_N_LANES, N_LANES},
+            qm31::PackedQM31,
+            SimdBackend,
+        },
+        channel::Blake2sChannel,
+        pcs::{CommitmentSchemeProver, PcsConfig},
+        poly::circle::{CanonicCoset, PolyOps},
+        vcs::{blake2_hash::Blake2sHash, blake2_merkle::Blake2sMerkleChannel},
+    },
+};
+
+use crate::{
+    components::{InteractionClaim, NodeElements, WeightsClaim},
+    utils::{pack_values, TreeBuilder},
+};
+
+use super::table::{
+    PackedWeightsTraceTableRow, WeightsColumn, WeightsCommittedColumn, WeightsTraceTable,
+    WeightsTraceTableRow,
+};
+
+/// Number of main trace columns for the Weights component (only multiplicity).
+pub(crate) const N_TRACE_COLUMNS: usize = 1;
+/// Number of columns of the committed weights tree (node ID, index and value).
+pub(crate) const N_WEIGHTS_COLUMNS: usize = 3;
+
+/// Generates main trace and interaction data for the Weights component.
+///
+/// Takes the `WeightsTraceTable` collected during graph execution, writes the multiplicities
+/// to the main trace, and prepares data for the LogUp interaction.
+/// The weight values themselves are written to the committed weights tree by `write_weights_trace`.
+pub struct ClaimGenerator {
+    /// The raw trace data for the committed weights.
+    pub inputs: WeightsTraceTable,
+}
+
+impl ClaimGenerator {
+    /// Creates a new `ClaimGenerator` with the given `WeightsTraceTable`.
+    pub fn new(inputs: WeightsTraceTable) -> Self {
+        Self { inputs }
+    }
+
+    /// Writes the main trace column (multiplicities) and returns data for interaction.
+    ///
+    /// Standard procedure: pads, packs, calls `write_trace_simd`,
+    /// adds main trace to `tree_builder`, returns `WeightsClaim` and `InteractionClaimGenerator`.
+    /// Returns `TraceError::EmptyTrace` if the input table is empty.
+    pub fn write_trace(
+        mut self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+    ) -> Result<(WeightsClaim, InteractionClaimGenerator), TraceError> {
+        let n_rows = self.inputs.table.len();
+
+        if n_rows == 0 {
+            return Err(TraceError::EmptyTrace);
+        }
+
+        let size = std::cmp::max(n_rows.next_power_of_two(), N_LANES);
+        let log_size = size.ilog2();
+
+        self.inputs
+            .table
+            .resize(size, WeightsTraceTableRow::padding());
+        let packed_inputs = pack_values(&self.inputs.table);
+
+        let (trace, lookup_data) = write_trace_simd(packed_inputs);
+
+        tree_builder.extend_evals(trace.to_evals());
+
+        Ok((
+            WeightsClaim::new(log_size),
+            InteractionClaimGenerator {
+                log_size,
+                lookup_data,
+            },
+        ))
+    }
+}
+
+/// Writes the committed weights tree (node IDs, indices and values) to the `tree_builder`.
+///
+/// Rows are padded and ordered exactly as in `ClaimGenerator::write_trace`, so that
+/// both trees describe the same rows.
+/// Returns `TraceError::EmptyTrace` if the input table is empty.
+pub fn write_weights_trace(
+    mut inputs: WeightsTraceTable,
+    tree_builder: &mut impl TreeBuilder<SimdBackend>,
+) -> Result<(), TraceError> {
+    let n_rows = inputs.table.len();
+
+    if n_rows == 0 {
+        return Err(TraceError::EmptyTrace);
+    }
+
+    let size = std::cmp::max(n_rows.next_power_of_two(), N_LANES);
+    let log_size = size.ilog2();
+
+    inputs.table.resize(size, WeightsTraceTableRow::padding());
+    let packed_inputs: Vec<PackedWeightsTraceTableRow> = pack_values(&inputs.table);
+
+    let mut trace = unsafe { ComponentTrace::<N_WEIGHTS_COLUMNS>::uninitialized(log_size) };
+    (trace.par_iter_mut(), packed_inputs.into_par_iter())
+        .into_par_iter()
+        .for_each(|(mut row, input)| {
+            *row[WeightsCommittedColumn::NodeId.index()] = input.node_id;
+            *row[WeightsCommittedColumn::Idx.index()] = input.idx;
+            *row[WeightsCommittedColumn::Value.index()] = input.value;
+        });
+
+    tree_builder.extend_evals(trace.to_evals());
+
+    Ok(())
+}
+
+/// Computes the Merkle root of the committed weights tree.
+///
+/// The tree is built in a standalone commitment scheme with the default `PcsConfig`,
+/// which is the one used by the prover, so the root matches the one committed in the proof.
+/// It is computed once by the model publisher and stored in the verifying key.
+/// Returns `TraceError::EmptyTrace` if the input table is empty.
+pub fn commit_weights(inputs: WeightsTraceTable) -> Result<Blake2sHash, TraceError> {
+    let n_rows = inputs.table.len();
+
+    if n_rows == 0 {
+        return Err(TraceError::EmptyTrace);
+    }
+
+    let log_size = std::cmp::max(n_rows.next_power_of_two(), N_LANES).ilog2();
+    let config = PcsConfig::default();
+    let twiddles = SimdBackend::precompute_twiddles(
+        CanonicCoset::new(log_size + config.fri_config.log_blowup_factor + 2)
+            .circle_domain()
+            .half_coset,
+    );
+    let channel = &mut Blake2sChannel::default();
+    let mut commitment_scheme =
+        CommitmentSchemeProver::<_, Blake2sMerkleChannel>::new(config, &twiddles);
+
+    let mut tree_builder = commitment_scheme.tree_builder();
+    write_weights_trace(inputs, &mut tree_builder)?;
+    tree_builder.commit(channel);
+
+    Ok(commitment_scheme.roots()[0])
+}
+
+/// Populates the main trace column and `LookupData` from SIMD-packed trace rows.
+///
+/// Writes the multiplicity column, and stores the `[node_id, idx, value]` triples
+/// with their multiplicities into `LookupData` for the LogUp argument.
+/// Returns the `ComponentTrace` (main trace column) and `LookupData`.
+fn write_trace_simd(
+    inputs: Vec<PackedWeightsTraceTableRow>,
+) -> (ComponentTrace<N_TRACE_COLUMNS>, LookupData) {
+    let log_n_packed_rows = inputs.len().ilog2();
+    let log_size = log_n_packed_rows + LOG_N_LANES;
+
+    let (mut trace, mut lookup_data) = unsafe {
+        (
+            ComponentTrace::<N_TRACE_COLUMNS>::uninitialized(log_size),
+            LookupData::uninitialized(log_n_packed_rows),
+        )
+    };
+
+    (
+        trace.par_iter_mut(),
+        lookup_data.par_iter_mut(),
+        inputs.into_par_iter(),
+    )
+        .into_par_iter()
+        .for_each(|(mut row, lookup_data, input)| {
+            *row[WeightsColumn::Multiplicity.index()] = input.multiplicity;
+
+            *lookup_data.weight = [input.node_id, input.idx, input.value];
+            *lookup_data.multiplicity = input.multiplicity;
+        });
+
+    (trace, lookup_data)
+}
+
+/// Intermediate data structure holding the committed weights and their multiplicities
+/// for LogUp argument construction.
+#[derive(Uninitialized, IterMut, ParIterMut)]
+struct LookupData {
+    /// Weight ID-index-value triples: `[node_id, idx, value]`.
+    weight: Vec<[PackedM31; 3]>,
+    /// Number of reads of each weight value.
+    multiplicity: Vec<PackedM31>,
+}
+
+/// Generates the interaction trace column for the Weights component's LogUp argument.
+///
+/// Emits every committed weight in the node relation with its multiplicity, balancing
+/// the reads of the consuming nodes.
+pub struct InteractionClaimGenerator {
+    /// Log2 size of the trace.
+    log_size: u32,
+    /// Data (ID-index-value triples and multiplicities) needed for LogUp.
+    lookup_data: LookupData,
+}
+
+impl InteractionClaimGenerator {
+    /// Writes the LogUp interaction trace column to the `tree_builder`.
+    ///
+    /// For each row, combines `[node_id, idx, value]` with `NodeElements` and writes
+    /// `multiplicity / denominator`.
+    /// Returns the `InteractionClaim` containing the `claimed_sum`.
+    pub fn write_interaction_trace(
+        self,
+        tree_builder: &mut impl TreeBuilder<SimdBackend>,
+        node_elements: &NodeElements,
+    ) -> InteractionClaim {
+        let mut logup_gen = LogupTraceGenerator::new(self.log_size);
+
+        let mut col_gen = logup_gen.new_col();
+        for row in 0..1 << (self.log_size - LOG_N_LANES) {
+            let values = &self.lookup_data.weight[row];
+            let multiplicity = &self.lookup_data.multiplicity[row];
+
+            let denom: PackedQM31 = node_elements.combine(values);
+            col_gen.write_frac(row, (*multiplicity).into(), denom);
+        }
+        col_gen.finalize_col();
+
+        let (trace, claimed_sum) = logup_gen.finalize_last();
+        tree_builder.extend_evals(trace);
+
+        InteractionClaim { claimed_sum }
+    }
+}
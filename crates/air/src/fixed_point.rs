@@ -0,0 +1,183 @@
+use std::ops::Add;
+
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+use stwo_prover::core::fields::m31::{BaseField, P};
+
+/// Number of bits of the largest magnitude a value can have in the trace.
+///
+/// Values in `(-2^30, 2^30)` map to distinct M31 elements, the others wrap around `P`.
+pub const M31_MAGNITUDE_BITS: u32 = 30;
+
+/// A signed fixed-point number whose scale is chosen at runtime.
+///
+/// The raw value represents `value / 2^scale`. The scale is not stored with each
+/// number: it is carried by the tensor or the circuit settings the number belongs to,
+/// and passed to the operations that depend on it.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Fixed(pub i64);
+
+impl Fixed {
+    /// Converts a float to fixed-point at the given `scale`, rounding to the nearest value.
+    pub fn from_f64(value: f64, scale: u32) -> Self {
+        Self((value * (1u64 << scale) as f64).round() as i64)
+    }
+
+    /// Converts the fixed-point value at the given `scale` back to a float.
+    pub fn to_f64(&self, scale: u32) -> f64 {
+        self.0 as f64 / (1u64 << scale) as f64
+    }
+
+    /// Maps the raw value to the M31 field, negative values wrapping around `P`.
+    pub fn to_m31(&self) -> BaseField {
+        let value = self.0.rem_euclid(P as i64);
+        BaseField::from_u32_unchecked(value as u32)
+    }
+
+    /// Returns whether the value lies in `(-2^30, 2^30)`, where it maps to M31 without wrapping.
+    pub fn fits_m31(&self) -> bool {
+        self.0.unsigned_abs() < 1 << M31_MAGNITUDE_BITS
+    }
+
+    /// Multiplies two values at the given `scale`.
+    ///
+    /// Returns `(out, rem)` such that `lhs * rhs = out * 2^scale + rem`, with `rem` in `[0, 2^scale)`.
+    pub fn mul(self, rhs: Self, scale: u32) -> (Self, Self) {
+        let product = self.0 * rhs.0;
+        let one = 1i64 << scale;
+        (Self(product.div_euclid(one)), Self(product.rem_euclid(one)))
+    }
+
+    /// Moves the value from scale `from` to scale `to`, rounding to the nearest value.
+    ///
+    /// Returns `(out, rem)` with `out = round(x * 2^(to - from))`. When the scale decreases
+    /// by `s`, `x + 2^(s - 1) = out * 2^s + rem` with `rem` in `[0, 2^s)`; otherwise `rem` is zero.
+    pub fn rescale(self, from: u32, to: u32) -> (Self, Self) {
+        if to >= from {
+            return (Self(self.0 << (to - from)), Self::zero());
+        }
+        let divisor = 1i64 << (from - to);
+        let shifted = self.0 + (divisor >> 1);
+        (
+            Self(shifted.div_euclid(divisor)),
+            Self(shifted.rem_euclid(divisor)),
+        )
+    }
+
+    /// Computes the reciprocal at the given `scale`.
+    ///
+    /// Returns `(out, rem)` such that `x * out + rem = 2^(2 * scale)`, with `rem` in `[0, |x|)`.
+    /// The reciprocal of zero is zero, which leaves no valid remainder.
+    pub fn recip(self, scale: u32) -> (Self, Self) {
+        let one_squared = 1i64 << (2 * scale);
+        if self.0 == 0 {
+            return (Self::zero(), Self(one_squared));
+        }
+        (
+            Self(one_squared.div_euclid(self.0)),
+            Self(one_squared.rem_euclid(self.0)),
+        )
+    }
+
+    /// Computes the square root at the given `scale`.
+    ///
+    /// Returns `(out, rem)` such that `x * 2^scale = out^2 + rem`, with `rem` in `[0, 2 * out]`.
+    /// Negative inputs have a zero root, which leaves no valid remainder.
+    pub fn sqrt(self, scale: u32) -> (Self, Self) {
+        let scaled = self.0 << scale;
+        if scaled <= 0 {
+            return (Self::zero(), Self(scaled));
+        }
+
+        // Integer square root, corrected for the rounding of the float estimate.
+        let mut out = (scaled as f64).sqrt() as i64;
+        while out * out > scaled {
+            out -= 1;
+        }
+        while (out + 1) * (out + 1) <= scaled {
+            out += 1;
+        }
+
+        (Self(out), Self(scaled - out * out))
+    }
+
+    /// Computes `max(x, 0)` along with the sign-bit decomposition of the input.
+    ///
+    /// Returns `(out, sign, magnitude)` such that `x = magnitude` if `sign` is 0 and
+    /// `x = -(magnitude + 1)` if it is 1, with `magnitude` non-negative.
+    pub fn relu(self) -> (Self, Self, Self) {
+        if self.0 < 0 {
+            (Self::zero(), Self(1), Self(-self.0 - 1))
+        } else {
+            (self, Self::zero(), self)
+        }
+    }
+}
+
+impl Add for Fixed {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Zero for Fixed {
+    fn zero() -> Self {
+        Self(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mul_rescale_recip_sqrt_relations() {
+        for scale in [8, 12, 15] {
+            let x = Fixed::from_f64(-2.75, scale);
+            let y = Fixed::from_f64(1.3, scale);
+            let one = 1i64 << scale;
+
+            let (out, rem) = x.mul(y, scale);
+            assert_eq!(x.0 * y.0, out.0 * one + rem.0);
+            assert!((0..one).contains(&rem.0));
+
+            let (out, rem) = x.recip(scale);
+            assert_eq!(x.0 * out.0 + rem.0, one * one);
+            assert!((0..x.0.abs()).contains(&rem.0));
+
+            let (out, rem) = x.rescale(2 * scale, scale);
+            assert_eq!(x.0 + one / 2, out.0 * one + rem.0);
+            assert!((0..one).contains(&rem.0));
+            assert_eq!(
+                out.rescale(scale, 2 * scale),
+                (Fixed(out.0 * one), Fixed(0))
+            );
+
+            let (out, rem) = y.sqrt(scale);
+            assert_eq!(y.0 * one, out.0 * out.0 + rem.0);
+            assert!((0..=2 * out.0).contains(&rem.0));
+            assert!((out.to_f64(scale) - 1.3f64.sqrt()).abs() < 2.0 / one as f64);
+        }
+    }
+
+    #[test]
+    fn test_m31_range_and_relu() {
+        let max = (1i64 << M31_MAGNITUDE_BITS) - 1;
+        assert!(Fixed(max).fits_m31() && Fixed(-max).fits_m31());
+        assert!(!Fixed(max + 1).fits_m31() && !Fixed(-max - 1).fits_m31());
+        assert_eq!(Fixed(-max).to_m31(), -Fixed(max).to_m31());
+
+        for x in [0, 5, -5, 1000, -123456, max, -max] {
+            let (out, sign, magnitude) = Fixed(x).relu();
+            assert!((0..=1).contains(&sign.0) && magnitude.0 >= 0);
+            assert_eq!(x, magnitude.0 - sign.0 * (2 * magnitude.0 + 1));
+            assert_eq!(out.0, x.max(0));
+        }
+    }
+}
@@ -2,26 +2,46 @@
 
 use ::serde::{Deserialize, Serialize};
 use components::{
-    add, lookups, max_reduce, mul, recip, sin, sqrt, sum_reduce, AddClaim, InteractionClaim,
-    MaxReduceClaim, MulClaim, RecipClaim, SinClaim, SinLookupClaim, SqrtClaim, SumReduceClaim,
+    add, conv2d, less_than,
+    lookups::{self, unary::functions::*, LookupElements, Lookups},
+    matmul, max_reduce, modulo, mul, norm, recip, relu, rescale, softmax, sqrt, sum_reduce, unary,
+    weights, AddClaim, Claim, Conv2dClaim, InteractionClaim, LessThanClaim, MatMulClaim,
+    MaxReduceClaim, ModClaim, MulClaim, NodeElements, NormClaim, RangeCheckLookupClaim, RecipClaim,
+    ReluClaim, RescaleClaim, SoftmaxClaim, SqrtClaim, SumReduceClaim, UnaryClaim, UnaryLookupClaim,
+    WeightsClaim,
 };
-use stwo_prover::core::{channel::Channel, pcs::TreeVec};
+use luminair_utils::TraceError;
+use pie::{LutTraceTable, TraceTable};
+use preprocessed::{PreProcessedTrace, UnaryPreProcessed};
+use stwo_prover::core::{backend::simd::SimdBackend, channel::Channel, pcs::TreeVec};
+use utils::{calculate_log_size, TreeBuilder};
 
 pub mod components;
+pub mod fixed_point;
 pub mod pie;
 pub mod preprocessed;
+pub mod public_io;
 pub mod settings;
 pub mod utils;
+pub mod verifying_key;
 
-// TODO (@raphaelDkhn): We should parametizing the fixed pointscale.
+#[doc(hidden)]
+pub use paste;
+
+/// Default fixed-point scale, used when the circuit settings do not choose one.
 pub const DEFAULT_FP_SCALE: u32 = 12;
 
+/// Largest supported fixed-point scale.
+///
+/// The reciprocal relation involves `2^(2 * scale)`, which must fit in the M31 field.
+pub const MAX_FP_SCALE: u32 = 15;
+
 /// Container for claims related to the main execution trace of LuminAIR components.
 ///
 /// Each field corresponds to a specific AIR component (like Add, Mul, Sin) and holds
 /// the claim generated for that component's trace segment, if present in the computation.
 /// These claims typically include commitments to the component's trace columns.
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
 pub struct LuminairClaim {
     /// Claim for the Add component's trace.
     pub add: Option<AddClaim>,
@@ -29,16 +49,34 @@ pub struct LuminairClaim {
     pub mul: Option<MulClaim>,
     /// Claim for the Recip component's trace.
     pub recip: Option<RecipClaim>,
-    /// Claim for the Sin component's trace.
-    pub sin: Option<SinClaim>,
-    /// Claim for the Sin Lookup component's trace.
-    pub sin_lookup: Option<SinLookupClaim>,
     /// Claim for the SumReduce component's trace.
     pub sum_reduce: Option<SumReduceClaim>,
+    /// Claim for the MatMul component's trace.
+    pub matmul: Option<MatMulClaim>,
     /// Claim for the MaxReduce component's trace.
     pub max_reduce: Option<MaxReduceClaim>,
     /// Claim for the Sqrt component's trace.
     pub sqrt: Option<SqrtClaim>,
+    /// Claim for the LessThan component's trace.
+    pub less_than: Option<LessThanClaim>,
+    /// Claims for the components of the unary LUT functions.
+    pub luts: LutClaim,
+    /// Claim for the Softmax component's trace.
+    pub softmax: Option<SoftmaxClaim>,
+    /// Claim for the Norm component's trace.
+    pub norm: Option<NormClaim>,
+    /// Claim for the Conv2d component's trace.
+    pub conv2d: Option<Conv2dClaim>,
+    /// Claim for the Relu component's trace.
+    pub relu: Option<ReluClaim>,
+    /// Claim for the Mod component's trace.
+    pub modulo: Option<ModClaim>,
+    /// Claim for the Rescale component's trace.
+    pub rescale: Option<RescaleClaim>,
+    /// Claim for the Range Check Lookup component's trace.
+    pub range_check_lookup: Option<RangeCheckLookupClaim>,
+    /// Claim for the Weights component's trace.
+    pub weights: Option<WeightsClaim>,
 }
 
 impl LuminairClaim {
@@ -55,13 +93,10 @@ impl LuminairClaim {
         if let Some(ref claim) = self.recip {
             claim.mix_into(channel);
         }
-        if let Some(ref claim) = self.sin {
-            claim.mix_into(channel);
-        }
-        if let Some(ref claim) = self.sin_lookup {
+        if let Some(ref claim) = self.sum_reduce {
             claim.mix_into(channel);
         }
-        if let Some(ref claim) = self.sum_reduce {
+        if let Some(ref claim) = self.matmul {
             claim.mix_into(channel);
         }
         if let Some(ref claim) = self.max_reduce {
@@ -70,6 +105,34 @@ impl LuminairClaim {
         if let Some(ref claim) = self.sqrt {
             claim.mix_into(channel);
         }
+        if let Some(ref claim) = self.less_than {
+            claim.mix_into(channel);
+        }
+        self.luts.mix_into(channel);
+        if let Some(ref claim) = self.softmax {
+            claim.mix_into(channel);
+        }
+        if let Some(ref claim) = self.norm {
+            claim.mix_into(channel);
+        }
+        if let Some(ref claim) = self.conv2d {
+            claim.mix_into(channel);
+        }
+        if let Some(ref claim) = self.relu {
+            claim.mix_into(channel);
+        }
+        if let Some(ref claim) = self.modulo {
+            claim.mix_into(channel);
+        }
+        if let Some(ref claim) = self.rescale {
+            claim.mix_into(channel);
+        }
+        if let Some(ref claim) = self.range_check_lookup {
+            claim.mix_into(channel);
+        }
+        if let Some(ref claim) = self.weights {
+            claim.mix_into(channel);
+        }
     }
 
     /// Aggregates the log-sizes (dimensions) of all present component trace segments.
@@ -87,13 +150,10 @@ impl LuminairClaim {
         if let Some(ref claim) = self.recip {
             log_sizes.push(claim.log_sizes());
         }
-        if let Some(ref claim) = self.sin {
-            log_sizes.push(claim.log_sizes());
-        }
-        if let Some(ref claim) = self.sin_lookup {
+        if let Some(ref claim) = self.sum_reduce {
             log_sizes.push(claim.log_sizes());
         }
-        if let Some(ref claim) = self.sum_reduce {
+        if let Some(ref claim) = self.matmul {
             log_sizes.push(claim.log_sizes());
         }
         if let Some(ref claim) = self.max_reduce {
@@ -102,8 +162,99 @@ impl LuminairClaim {
         if let Some(ref claim) = self.sqrt {
             log_sizes.push(claim.log_sizes());
         }
+        if let Some(ref claim) = self.less_than {
+            log_sizes.push(claim.log_sizes());
+        }
+        log_sizes.extend(self.luts.log_sizes());
+        if let Some(ref claim) = self.softmax {
+            log_sizes.push(claim.log_sizes());
+        }
+        if let Some(ref claim) = self.norm {
+            log_sizes.push(claim.log_sizes());
+        }
+        if let Some(ref claim) = self.conv2d {
+            log_sizes.push(claim.log_sizes());
+        }
+        if let Some(ref claim) = self.relu {
+            log_sizes.push(claim.log_sizes());
+        }
+        if let Some(ref claim) = self.modulo {
+            log_sizes.push(claim.log_sizes());
+        }
+        if let Some(ref claim) = self.rescale {
+            log_sizes.push(claim.log_sizes());
+        }
+        if let Some(ref claim) = self.range_check_lookup {
+            log_sizes.push(claim.log_sizes());
+        }
+        if let Some(ref claim) = self.weights {
+            log_sizes.push(claim.log_sizes());
+        }
         TreeVec::concat_cols(log_sizes.into_iter())
     }
+
+    /// Derives the claim the prover makes for `trace_tables`, from the number of rows of each table.
+    ///
+    /// The sizes of the traces only depend on the shapes of the graph, so that the verifying key
+    /// fixes the claim of every proof of the graph.
+    pub fn from_trace_tables(trace_tables: &[TraceTable]) -> Self {
+        let mut claim = Self::default();
+        for table in trace_tables {
+            match table {
+                TraceTable::Add { table } => {
+                    claim.add = Some(Claim::new(calculate_log_size(table.table.len())))
+                }
+                TraceTable::Mul { table } => {
+                    claim.mul = Some(Claim::new(calculate_log_size(table.table.len())))
+                }
+                TraceTable::Recip { table } => {
+                    claim.recip = Some(Claim::new(calculate_log_size(table.table.len())))
+                }
+                TraceTable::SumReduce { table } => {
+                    claim.sum_reduce = Some(Claim::new(calculate_log_size(table.table.len())))
+                }
+                TraceTable::MatMul { table } => {
+                    claim.matmul = Some(Claim::new(calculate_log_size(table.table.len())))
+                }
+                TraceTable::MaxReduce { table } => {
+                    claim.max_reduce = Some(Claim::new(calculate_log_size(table.table.len())))
+                }
+                TraceTable::Sqrt { table } => {
+                    claim.sqrt = Some(Claim::new(calculate_log_size(table.table.len())))
+                }
+                TraceTable::LessThan { table } => {
+                    claim.less_than = Some(Claim::new(calculate_log_size(table.table.len())))
+                }
+                TraceTable::Lut { table } => claim.luts.set_trace_table(table),
+                TraceTable::Softmax { table } => {
+                    claim.softmax = Some(Claim::new(calculate_log_size(table.table.len())))
+                }
+                TraceTable::Norm { table } => {
+                    claim.norm = Some(Claim::new(calculate_log_size(table.table.len())))
+                }
+                TraceTable::Conv2d { table } => {
+                    claim.conv2d = Some(Claim::new(calculate_log_size(table.table.len())))
+                }
+                TraceTable::Relu { table } => {
+                    claim.relu = Some(Claim::new(calculate_log_size(table.table.len())))
+                }
+                TraceTable::Mod { table } => {
+                    claim.modulo = Some(Claim::new(calculate_log_size(table.table.len())))
+                }
+                TraceTable::Rescale { table } => {
+                    claim.rescale = Some(Claim::new(calculate_log_size(table.table.len())))
+                }
+                TraceTable::RangeCheckLookup { table } => {
+                    claim.range_check_lookup =
+                        Some(Claim::new(calculate_log_size(table.table.len())))
+                }
+                TraceTable::Weights { table } => {
+                    claim.weights = Some(Claim::new(calculate_log_size(table.table.len())))
+                }
+            }
+        }
+        claim
+    }
 }
 
 /// Container for interaction claim generators for each LuminAIR component.
@@ -119,16 +270,34 @@ pub struct LuminairInteractionClaimGenerator {
     pub mul: Option<mul::witness::InteractionClaimGenerator>,
     /// Generator for the Recip component's interaction claim.
     pub recip: Option<recip::witness::InteractionClaimGenerator>,
-    /// Generator for the Sin component's interaction claim.
-    pub sin: Option<sin::witness::InteractionClaimGenerator>,
-    /// Generator for the Sin Lookup component's interaction claim.
-    pub sin_lookup: Option<lookups::sin::witness::InteractionClaimGenerator>,
     /// Generator for the SumReduce component's interaction claim.
     pub sum_reduce: Option<sum_reduce::witness::InteractionClaimGenerator>,
+    /// Generator for the MatMul component's interaction claim.
+    pub matmul: Option<matmul::witness::InteractionClaimGenerator>,
     /// Generator for the MaxReduce component's interaction claim.
     pub max_reduce: Option<max_reduce::witness::InteractionClaimGenerator>,
     /// Generator for the Sqrt component's interaction claim.
     pub sqrt: Option<sqrt::witness::InteractionClaimGenerator>,
+    /// Generator for the LessThan component's interaction claim.
+    pub less_than: Option<less_than::witness::InteractionClaimGenerator>,
+    /// Generators for the interaction claims of the components of the unary LUT functions.
+    pub luts: LutInteractionClaimGenerator,
+    /// Generator for the Softmax component's interaction claim.
+    pub softmax: Option<softmax::witness::InteractionClaimGenerator>,
+    /// Generator for the Norm component's interaction claim.
+    pub norm: Option<norm::witness::InteractionClaimGenerator>,
+    /// Generator for the Conv2d component's interaction claim.
+    pub conv2d: Option<conv2d::witness::InteractionClaimGenerator>,
+    /// Generator for the Relu component's interaction claim.
+    pub relu: Option<relu::witness::InteractionClaimGenerator>,
+    /// Generator for the Mod component's interaction claim.
+    pub modulo: Option<modulo::witness::InteractionClaimGenerator>,
+    /// Generator for the Rescale component's interaction claim.
+    pub rescale: Option<rescale::witness::InteractionClaimGenerator>,
+    /// Generator for the Range Check Lookup component's interaction claim.
+    pub range_check_lookup: Option<lookups::range_check::witness::InteractionClaimGenerator>,
+    /// Generator for the Weights component's interaction claim.
+    pub weights: Option<weights::witness::InteractionClaimGenerator>,
 }
 
 /// Container for claims related to the interaction trace of LuminAIR components.
@@ -145,16 +314,34 @@ pub struct LuminairInteractionClaim {
     pub mul: Option<InteractionClaim>,
     /// Interaction claim for the Recip component.
     pub recip: Option<InteractionClaim>,
-    /// Interaction claim for the Sin component.
-    pub sin: Option<InteractionClaim>,
-    /// Interaction claim for the Sin Lookup component.
-    pub sin_lookup: Option<InteractionClaim>,
     /// Interaction claim for the SumReduce component.
     pub sum_reduce: Option<InteractionClaim>,
+    /// Interaction claim for the MatMul component.
+    pub matmul: Option<InteractionClaim>,
     /// Interaction claim for the MaxReduce component.
     pub max_reduce: Option<InteractionClaim>,
     /// Interaction claim for the Sqrt component.
     pub sqrt: Option<InteractionClaim>,
+    /// Interaction claim for the LessThan component.
+    pub less_than: Option<InteractionClaim>,
+    /// Interaction claims for the components of the unary LUT functions.
+    pub luts: LutInteractionClaim,
+    /// Interaction claim for the Softmax component.
+    pub softmax: Option<InteractionClaim>,
+    /// Interaction claim for the Norm component.
+    pub norm: Option<InteractionClaim>,
+    /// Interaction claim for the Conv2d component.
+    pub conv2d: Option<InteractionClaim>,
+    /// Interaction claim for the Relu component.
+    pub relu: Option<InteractionClaim>,
+    /// Interaction claim for the Mod component.
+    pub modulo: Option<InteractionClaim>,
+    /// Interaction claim for the Rescale component.
+    pub rescale: Option<InteractionClaim>,
+    /// Interaction claim for the Range Check Lookup component.
+    pub range_check_lookup: Option<InteractionClaim>,
+    /// Interaction claim for the Weights component.
+    pub weights: Option<InteractionClaim>,
 }
 
 impl LuminairInteractionClaim {
@@ -170,13 +357,10 @@ impl LuminairInteractionClaim {
         if let Some(ref claim) = self.recip {
             claim.mix_into(channel);
         }
-        if let Some(ref claim) = self.sin {
-            claim.mix_into(channel);
-        }
-        if let Some(ref claim) = self.sin_lookup {
+        if let Some(ref claim) = self.sum_reduce {
             claim.mix_into(channel);
         }
-        if let Some(ref claim) = self.sum_reduce {
+        if let Some(ref claim) = self.matmul {
             claim.mix_into(channel);
         }
         if let Some(ref claim) = self.max_reduce {
@@ -185,5 +369,241 @@ impl LuminairInteractionClaim {
         if let Some(ref claim) = self.sqrt {
             claim.mix_into(channel);
         }
+        if let Some(ref claim) = self.less_than {
+            claim.mix_into(channel);
+        }
+        self.luts.mix_into(channel);
+        if let Some(ref claim) = self.softmax {
+            claim.mix_into(channel);
+        }
+        if let Some(ref claim) = self.norm {
+            claim.mix_into(channel);
+        }
+        if let Some(ref claim) = self.conv2d {
+            claim.mix_into(channel);
+        }
+        if let Some(ref claim) = self.relu {
+            claim.mix_into(channel);
+        }
+        if let Some(ref claim) = self.modulo {
+            claim.mix_into(channel);
+        }
+        if let Some(ref claim) = self.rescale {
+            claim.mix_into(channel);
+        }
+        if let Some(ref claim) = self.range_check_lookup {
+            claim.mix_into(channel);
+        }
+        if let Some(ref claim) = self.weights {
+            claim.mix_into(channel);
+        }
     }
 }
+
+// Generates the claims, interaction claims and trace writers of the components of the unary
+// LUT functions, from the `lut_functions!` registry.
+macro_rules! lut_claims {
+    (
+        unary [$([$name:ident, $field:ident, $lookup:ident, $Lookup:ident])*]
+        all [$([$aname:ident, $afield:ident, $alookup:ident, $ALookup:ident])*]
+    ) => {
+        /// Container for claims related to the main trace of the unary LUT function components.
+        ///
+        /// Holds the claims of the unary components, then the claims of the lookup components
+        /// of all the functions, in the order of the `lut_functions!` registry.
+        #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+        pub struct LutClaim {
+            $(
+                #[doc = concat!("Claim for the ", stringify!($name), " component's trace.")]
+                pub $field: Option<UnaryClaim>,
+            )*
+            $(
+                #[doc = concat!("Claim for the ", stringify!($aname), " Lookup component's trace.")]
+                pub $alookup: Option<UnaryLookupClaim>,
+            )*
+        }
+
+        impl LutClaim {
+            /// Mixes the claims of the present components into the Fiat-Shamir channel.
+            pub fn mix_into(&self, channel: &mut impl Channel) {
+                $(
+                    if let Some(ref claim) = self.$field {
+                        claim.mix_into(channel);
+                    }
+                )*
+                $(
+                    if let Some(ref claim) = self.$alookup {
+                        claim.mix_into(channel);
+                    }
+                )*
+            }
+
+            /// Returns the log-sizes of the trace segments of the present components.
+            pub fn log_sizes(&self) -> Vec<TreeVec<Vec<u32>>> {
+                let mut log_sizes = vec![];
+                $(
+                    if let Some(ref claim) = self.$field {
+                        log_sizes.push(claim.log_sizes());
+                    }
+                )*
+                $(
+                    if let Some(ref claim) = self.$alookup {
+                        log_sizes.push(claim.log_sizes());
+                    }
+                )*
+                log_sizes
+            }
+
+            /// Sets the claim of the component of `table`, from the number of rows of the table.
+            pub fn set_trace_table(&mut self, table: &LutTraceTable) {
+                match table {
+                    $(
+                        LutTraceTable::$name(table) => {
+                            self.$field = Some(Claim::new(calculate_log_size(table.table.len())))
+                        }
+                    )*
+                    $(
+                        LutTraceTable::$ALookup(table) => {
+                            self.$alookup = Some(Claim::new(calculate_log_size(table.table.len())))
+                        }
+                    )*
+                }
+            }
+        }
+
+        /// Container for interaction claim generators of the unary LUT function components.
+        #[derive(Default)]
+        pub struct LutInteractionClaimGenerator {
+            $(
+                #[doc = concat!(
+                    "Generator for the ", stringify!($name), " component's interaction claim."
+                )]
+                pub $field: Option<unary::witness::InteractionClaimGenerator<$name>>,
+            )*
+            $(
+                #[doc = concat!(
+                    "Generator for the ",
+                    stringify!($aname),
+                    " Lookup component's interaction claim."
+                )]
+                pub $alookup: Option<lookups::unary::witness::InteractionClaimGenerator>,
+            )*
+        }
+
+        impl LutInteractionClaimGenerator {
+            /// Writes the interaction trace of the present components to the `tree_builder`,
+            /// and returns their interaction claims.
+            ///
+            /// The lookup components read the entries of their LUT from the `preprocessed_trace`.
+            pub fn write_interaction_trace(
+                self,
+                tree_builder: &mut impl TreeBuilder<SimdBackend>,
+                node_elements: &NodeElements,
+                lookup_elements: &LookupElements,
+                preprocessed_trace: &PreProcessedTrace,
+            ) -> LutInteractionClaim {
+                let mut interaction_claim = LutInteractionClaim::default();
+                $(
+                    if let Some(claim_gen) = self.$field {
+                        let claim = claim_gen.write_interaction_trace(
+                            tree_builder,
+                            node_elements,
+                            &lookup_elements.$field,
+                            &lookup_elements.range_check,
+                        );
+                        interaction_claim.$field = Some(claim)
+                    }
+                )*
+                $(
+                    if let Some(claim_gen) = self.$alookup {
+                        let mut luts =
+                            preprocessed_trace.columns_of::<UnaryPreProcessed<$aname>>();
+                        luts.sort_by_key(|c| c.col_index);
+
+                        let claim = claim_gen.write_interaction_trace(
+                            tree_builder,
+                            &lookup_elements.$afield,
+                            &luts,
+                        );
+                        interaction_claim.$alookup = Some(claim)
+                    }
+                )*
+                interaction_claim
+            }
+        }
+
+        /// Container for interaction claims of the unary LUT function components.
+        #[derive(Serialize, Deserialize, Default, Debug)]
+        pub struct LutInteractionClaim {
+            $(
+                #[doc = concat!("Interaction claim for the ", stringify!($name), " component.")]
+                pub $field: Option<InteractionClaim>,
+            )*
+            $(
+                #[doc = concat!(
+                    "Interaction claim for the ", stringify!($aname), " Lookup component."
+                )]
+                pub $alookup: Option<InteractionClaim>,
+            )*
+        }
+
+        impl LutInteractionClaim {
+            /// Mixes the interaction claims of the present components into the Fiat-Shamir channel.
+            pub fn mix_into(&self, channel: &mut impl Channel) {
+                $(
+                    if let Some(ref claim) = self.$field {
+                        claim.mix_into(channel);
+                    }
+                )*
+                $(
+                    if let Some(ref claim) = self.$alookup {
+                        claim.mix_into(channel);
+                    }
+                )*
+            }
+
+            /// Returns the interaction claims of all the components, present or not.
+            pub fn claims(&self) -> Vec<&Option<InteractionClaim>> {
+                vec![$(&self.$field,)* $(&self.$alookup,)*]
+            }
+        }
+
+        impl LutTraceTable {
+            /// Writes the main trace of the component of the table to the `tree_builder`.
+            ///
+            /// Records the claim of the component in `claim`, and its interaction claim
+            /// generator in `interaction_claim_gen`.
+            pub fn write_trace(
+                self,
+                tree_builder: &mut impl TreeBuilder<SimdBackend>,
+                lookups: &Lookups,
+                claim: &mut LutClaim,
+                interaction_claim_gen: &mut LutInteractionClaimGenerator,
+            ) -> Result<(), TraceError> {
+                match self {
+                    $(
+                        Self::$name(table) => {
+                            let layout = &lookups.$field.as_ref().unwrap().layout;
+                            let claim_gen =
+                                unary::witness::ClaimGenerator::<$name>::new(table, layout);
+                            let (cl, in_cl_gen) = claim_gen.write_trace(tree_builder)?;
+                            claim.$field = Some(cl);
+                            interaction_claim_gen.$field = Some(in_cl_gen);
+                        }
+                    )*
+                    $(
+                        Self::$ALookup(table) => {
+                            let claim_gen = lookups::unary::witness::ClaimGenerator::new(table);
+                            let (cl, in_cl_gen) = claim_gen.write_trace(tree_builder)?;
+                            claim.$alookup = Some(cl);
+                            interaction_claim_gen.$alookup = Some(in_cl_gen);
+                        }
+                    )*
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+crate::lut_functions!(lut_claims);
@@ -2,13 +2,20 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     components::{
-        add::table::AddTraceTable, lookups::sin::table::SinLookupTraceTable,
-        max_reduce::table::MaxReduceTraceTable, mul::table::MulTraceTable,
-        recip::table::RecipTraceTable, sin::table::SinTraceTable, sqrt::table::SqrtTraceTable,
-        sum_reduce::table::SumReduceTraceTable,
+        add::table::AddTraceTable, conv2d::table::Conv2dTraceTable,
+        less_than::table::LessThanTraceTable,
+        lookups::range_check::table::RangeCheckLookupTraceTable,
+        lookups::unary::table::UnaryLookupTraceTable, matmul::table::MatMulTraceTable,
+        max_reduce::table::MaxReduceTraceTable, modulo::table::ModTraceTable,
+        mul::table::MulTraceTable, norm::table::NormTraceTable, recip::table::RecipTraceTable,
+        relu::table::ReluTraceTable, rescale::table::RescaleTraceTable,
+        softmax::table::SoftmaxTraceTable, sqrt::table::SqrtTraceTable,
+        sum_reduce::table::SumReduceTraceTable, unary::table::UnaryTraceTable,
+        weights::table::WeightsTraceTable,
     },
-    utils::AtomicMultiplicityColumn,
+    public_io::PublicIo,
 };
+use stwo_prover::core::vcs::blake2_hash::Blake2sHash;
 
 /// Enum wrapping the trace table generated for a specific AIR component.
 ///
@@ -23,16 +30,34 @@ pub enum TraceTable {
     Mul { table: MulTraceTable },
     /// Trace table for Recip operations.
     Recip { table: RecipTraceTable },
-    /// Trace table for Sin operations.
-    Sin { table: SinTraceTable },
-    /// Trace table for Sin lookup operations.
-    SinLookup { table: SinLookupTraceTable },
     /// Trace table for SumReduce operations.
     SumReduce { table: SumReduceTraceTable },
+    /// Trace table for MatMul operations.
+    MatMul { table: MatMulTraceTable },
     /// Trace table for MaxReduce operations.
     MaxReduce { table: MaxReduceTraceTable },
     /// Trace table for Sqrt operations.
     Sqrt { table: SqrtTraceTable },
+    /// Trace table for LessThan operations.
+    LessThan { table: LessThanTraceTable },
+    /// Trace table of a unary LUT function, for its operations or its lookups.
+    Lut { table: LutTraceTable },
+    /// Trace table for Softmax operations.
+    Softmax { table: SoftmaxTraceTable },
+    /// Trace table for Norm operations.
+    Norm { table: NormTraceTable },
+    /// Trace table for Conv2d operations.
+    Conv2d { table: Conv2dTraceTable },
+    /// Trace table for Relu operations.
+    Relu { table: ReluTraceTable },
+    /// Trace table for Mod operations.
+    Mod { table: ModTraceTable },
+    /// Trace table for Rescale operations.
+    Rescale { table: RescaleTraceTable },
+    /// Trace table for range-check lookup operations.
+    RangeCheckLookup { table: RangeCheckLookupTraceTable },
+    /// Trace table for the committed weights.
+    Weights { table: WeightsTraceTable },
 }
 
 impl TraceTable {
@@ -48,18 +73,14 @@ impl TraceTable {
     pub fn from_recip(table: RecipTraceTable) -> Self {
         Self::Recip { table }
     }
-    /// Creates a `TraceTable::Sin` variant.
-    pub fn from_sin(table: SinTraceTable) -> Self {
-        Self::Sin { table }
-    }
-    /// Creates a `TraceTable::SinLookup` variant.
-    pub fn from_sin_lookup(table: SinLookupTraceTable) -> Self {
-        Self::SinLookup { table }
-    }
     /// Creates a `TraceTable::SumReduce` variant.
     pub fn from_sum_reduce(table: SumReduceTraceTable) -> Self {
         Self::SumReduce { table }
     }
+    /// Creates a `TraceTable::MatMul` variant.
+    pub fn from_matmul(table: MatMulTraceTable) -> Self {
+        Self::MatMul { table }
+    }
     /// Creates a `TraceTable::MaxReduce` variant.
     pub fn from_max_reduce(table: MaxReduceTraceTable) -> Self {
         Self::MaxReduce { table }
@@ -68,8 +89,75 @@ impl TraceTable {
     pub fn from_sqrt(table: SqrtTraceTable) -> Self {
         Self::Sqrt { table }
     }
+    /// Creates a `TraceTable::LessThan` variant.
+    pub fn from_less_than(table: LessThanTraceTable) -> Self {
+        Self::LessThan { table }
+    }
+    /// Creates a `TraceTable::Lut` variant.
+    pub fn from_lut(table: LutTraceTable) -> Self {
+        Self::Lut { table }
+    }
+    /// Creates a `TraceTable::Softmax` variant.
+    pub fn from_softmax(table: SoftmaxTraceTable) -> Self {
+        Self::Softmax { table }
+    }
+    /// Creates a `TraceTable::Norm` variant.
+    pub fn from_norm(table: NormTraceTable) -> Self {
+        Self::Norm { table }
+    }
+    /// Creates a `TraceTable::Conv2d` variant.
+    pub fn from_conv2d(table: Conv2dTraceTable) -> Self {
+        Self::Conv2d { table }
+    }
+    /// Creates a `TraceTable::Relu` variant.
+    pub fn from_relu(table: ReluTraceTable) -> Self {
+        Self::Relu { table }
+    }
+    /// Creates a `TraceTable::Mod` variant.
+    pub fn from_mod(table: ModTraceTable) -> Self {
+        Self::Mod { table }
+    }
+    /// Creates a `TraceTable::Rescale` variant.
+    pub fn from_rescale(table: RescaleTraceTable) -> Self {
+        Self::Rescale { table }
+    }
+    /// Creates a `TraceTable::RangeCheckLookup` variant.
+    pub fn from_range_check_lookup(table: RangeCheckLookupTraceTable) -> Self {
+        Self::RangeCheckLookup { table }
+    }
+    /// Creates a `TraceTable::Weights` variant.
+    pub fn from_weights(table: WeightsTraceTable) -> Self {
+        Self::Weights { table }
+    }
+}
+
+// Generates the trace tables of the unary LUT functions, with a variant per unary component
+// and per lookup component of the `lut_functions!` registry.
+macro_rules! lut_trace_table {
+    (
+        unary [$([$name:ident, $field:ident, $lookup:ident, $Lookup:ident])*]
+        all [$([$aname:ident, $afield:ident, $alookup:ident, $ALookup:ident])*]
+    ) => {
+        /// Enum wrapping the trace table generated for a component of a unary LUT function.
+        ///
+        /// Holds a variant per unary component (e.g. `Sin`) and per lookup component
+        /// (e.g. `SinLookup`) of the functions of the `lut_functions!` registry.
+        #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+        pub enum LutTraceTable {
+            $(
+                #[doc = concat!("Trace table for ", stringify!($name), " operations.")]
+                $name(UnaryTraceTable),
+            )*
+            $(
+                #[doc = concat!("Trace table for ", stringify!($aname), " lookup operations.")]
+                $ALookup(UnaryLookupTraceTable),
+            )*
+        }
+    };
 }
 
+crate::lut_functions!(lut_trace_table);
+
 /// Primary container for the PIE generated during trace execution.
 ///
 /// This structure holds all the computed trace data (`trace_tables`) and essential metadata
@@ -79,6 +167,10 @@ impl TraceTable {
 pub struct LuminairPie {
     /// A collection of trace tables, one entry for each AIR component instance used.
     pub trace_tables: Vec<TraceTable>,
+    /// The public inputs and outputs of the execution.
+    pub public_io: PublicIo,
+    /// Digest of the verifying key of the executed graph.
+    pub verifying_key_digest: Blake2sHash,
     /// Metadata about the execution, such as trace dimensions and operation counts.
     pub metadata: Metadata,
 }
@@ -92,12 +184,6 @@ pub struct Metadata {
     pub graph_view: String,
 }
 
-/// Struct for all LUT multiplicities
-#[derive(Serialize, Deserialize, Debug)]
-pub struct LUTMultiplicities {
-    pub sin: AtomicMultiplicityColumn,
-}
-
 /// Holds resource usage metadata gathered during graph execution.
 ///
 /// This includes the maximum trace log-size required across all components
@@ -110,25 +196,54 @@ pub struct ExecutionResources {
     pub max_log_size: u32,
 }
 
-/// Counts the occurrences of each specific AIR operation type during graph execution.
-#[derive(Serialize, Deserialize, Debug, Default)]
-pub struct OpCounter {
-    /// Number of Add operations.
-    pub add: usize,
-    /// Number of Mul operations.
-    pub mul: usize,
-    /// Number of Recip operations.
-    pub recip: usize,
-    /// Number of Sin operations.
-    pub sin: usize,
-    /// Number of SumReduce operations.
-    pub sum_reduce: usize,
-    /// Number of MaxReduce operations.
-    pub max_reduce: usize,
-    /// Number of Sqrt operations.
-    pub sqrt: usize,
+// Generates the operation counter, with a field per unary function of the `lut_functions!`
+// registry.
+macro_rules! op_counter {
+    (
+        unary [$([$name:ident, $field:ident, $lookup:ident, $Lookup:ident])*]
+        all [$([$aname:ident, $afield:ident, $alookup:ident, $ALookup:ident])*]
+    ) => {
+        /// Counts the occurrences of each specific AIR operation type during graph execution.
+        #[derive(Serialize, Deserialize, Debug, Default)]
+        pub struct OpCounter {
+            /// Number of Add operations.
+            pub add: usize,
+            /// Number of Mul operations.
+            pub mul: usize,
+            /// Number of Recip operations.
+            pub recip: usize,
+            /// Number of SumReduce operations.
+            pub sum_reduce: usize,
+            /// Number of MatMul operations.
+            pub matmul: usize,
+            /// Number of MaxReduce operations.
+            pub max_reduce: usize,
+            /// Number of Sqrt operations.
+            pub sqrt: usize,
+            /// Number of LessThan operations.
+            pub less_than: usize,
+            $(
+                #[doc = concat!("Number of ", stringify!($name), " operations.")]
+                pub $field: usize,
+            )*
+            /// Number of Softmax operations.
+            pub softmax: usize,
+            /// Number of Norm operations.
+            pub norm: usize,
+            /// Number of Conv2d operations.
+            pub conv2d: usize,
+            /// Number of Relu operations.
+            pub relu: usize,
+            /// Number of Mod operations.
+            pub modulo: usize,
+            /// Number of Rescale operations.
+            pub rescale: usize,
+        }
+    };
 }
 
+crate::lut_functions!(op_counter);
+
 /// Metadata about a specific input to a graph node.
 /// Indicates if a node input is an initializer (i.e., from initial input).
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -157,8 +272,9 @@ pub struct NodeInfo {
     pub inputs: Vec<InputInfo>,
     /// Information about the node's output.
     pub output: OutputInfo,
-    /// The number of nodes that consume the output of this node.
-    pub num_consumers: u32,
+    /// Number of times each output element is read by the consuming nodes,
+    /// indexed by its position in the output tensor.
+    pub consumer_reads: Vec<u32>,
     /// The unique ID of this node.
     pub id: u32,
 }
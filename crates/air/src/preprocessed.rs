@@ -1,82 +1,199 @@
-use std::{any::Any, cmp::Reverse};
+use std::{any::Any, cmp::Reverse, marker::PhantomData};
 
 use crate::{
     components::{
+        add::table::AddTraceTableRow,
+        conv2d::table::Conv2dTraceTableRow,
+        less_than::table::LessThanTraceTableRow,
         //lookups::Lookups,
-        lookups::Lookups,
+        lookups::{
+            unary::{functions::*, LutFunction, UnaryLookup},
+            Lookups,
+        },
+        matmul::table::MatMulTraceTableRow,
+        max_reduce::table::MaxReduceTraceTableRow,
+        modulo::table::ModTraceTableRow,
+        mul::table::MulTraceTableRow,
+        norm::table::NormTraceTableRow,
+        recip::table::RecipTraceTableRow,
+        relu::table::ReluTraceTableRow,
+        rescale::table::RescaleTraceTableRow,
+        softmax::table::SoftmaxTraceTableRow,
+        sqrt::table::SqrtTraceTableRow,
+        sum_reduce::table::SumReduceTraceTableRow,
+        unary::table::UnaryTraceTableRow,
+        weights::table::WeightsTraceTableRow,
         TraceEval,
     },
+    fixed_point::Fixed,
+    pie::{LutTraceTable, TraceTable},
     utils::calculate_log_size,
-    DEFAULT_FP_SCALE,
+    LuminairClaim, LutClaim,
 };
-use numerair::Fixed;
 use serde::{Deserialize, Serialize};
 use stwo_prover::{
-    constraint_framework::preprocessed_columns::PreProcessedColumnId,
+    constraint_framework::{preprocessed_columns::PreProcessedColumnId, EvalAtRow},
     core::{
         backend::{
             simd::{column::BaseColumn, SimdBackend},
             Column,
         },
+        channel::Blake2sChannel,
         fields::m31::BaseField,
+        pcs::{CommitmentSchemeProver, PcsConfig},
         poly::{
-            circle::{CanonicCoset, CircleEvaluation},
+            circle::{CanonicCoset, CircleEvaluation, PolyOps},
             BitReversedOrder,
         },
+        vcs::{blake2_hash::Blake2sHash, blake2_merkle::Blake2sMerkleChannel},
     },
 };
 
 /// Represents a closed range [min, max] using fixed-point numbers.
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct Range(pub Fixed<DEFAULT_FP_SCALE>, pub Fixed<DEFAULT_FP_SCALE>);
+pub struct Range(pub Fixed, pub Fixed);
 
 /// Defines the layout of a lookup table (LUT) based on value ranges.
 ///
 /// Stores the value ranges covered by the LUT and calculates the necessary
 /// log2 size for the table, padded to a power of two.
+/// An interpolated LUT only holds the breakpoints of its ranges, every `2^step_bits` values.
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct LookupLayout {
     /// The vector of disjoint value ranges covered by this LUT.
     pub ranges: Vec<Range>,
     /// The log2 size of the LUT column (padded to a power of two).
     pub log_size: u32,
+    /// The fixed-point scale of the values covered by the LUT.
+    pub scale: u32,
+    /// Spacing of the breakpoints of an interpolated LUT, or `None` if every value is an entry.
+    #[serde(default)]
+    pub interpolation: Option<Interpolation>,
+}
+
+/// Parameters of a LUT interpolated piecewise-linearly between sparse breakpoints.
+///
+/// An input `x` is looked up at the breakpoint `x_i` below it, and its output is
+/// `y_i + slope_i * (x - x_i)`, rounded down at the scale of the layout.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Interpolation {
+    /// Log2 of the distance between two consecutive breakpoints.
+    pub step_bits: u32,
+    /// Number of bits bounding the magnitude of the interpolated increment `out - y_i`.
+    pub delta_bits: u32,
 }
 
 impl LookupLayout {
-    /// Creates a new `LookupLayout` from a vector of potentially overlapping ranges.
+    /// Creates a new `LookupLayout` from a vector of potentially overlapping ranges
+    /// of fixed-point values at `scale`.
     ///
     /// Calculates the total number of unique integer values across the ranges
     /// and determines the minimum power-of-two `log_size` required.
-    pub fn new(ranges: Vec<Range>) -> Self {
-        let log_size = calculate_log_size(value_count(&ranges) as usize);
-        Self { ranges, log_size }
+    pub fn new(ranges: Vec<Range>, scale: u32) -> Self {
+        Self::with_interpolation(ranges, scale, None)
+    }
+
+    /// Creates a new interpolated `LookupLayout`, holding a breakpoint every
+    /// `2^interpolation.step_bits` values of each range.
+    pub fn interpolated(ranges: Vec<Range>, scale: u32, interpolation: Interpolation) -> Self {
+        Self::with_interpolation(ranges, scale, Some(interpolation))
+    }
+
+    fn with_interpolation(
+        ranges: Vec<Range>,
+        scale: u32,
+        interpolation: Option<Interpolation>,
+    ) -> Self {
+        let step_bits = interpolation.map_or(0, |i| i.step_bits);
+        let log_size = calculate_log_size(value_count(&ranges, step_bits) as usize);
+        Self {
+            ranges,
+            log_size,
+            scale,
+            interpolation,
+        }
+    }
+
+    /// Returns the log2 distance between two consecutive entries, zero if not interpolated.
+    pub fn step_bits(&self) -> u32 {
+        self.interpolation.map_or(0, |i| i.step_bits)
     }
 
     /// Finds the row index within the conceptual LUT for a given target value.
     ///
     /// The LUT is conceptually ordered by the ranges. This function calculates the index
-    /// based on the cumulative count of values in preceding ranges plus the offset within
-    /// the containing range.
+    /// based on the cumulative count of entries in preceding ranges plus the offset within
+    /// the containing range. In an interpolated LUT, this is the index of the breakpoint below.
     /// Returns `None` if the `target` value is not covered by any range in the layout.
     pub fn find_index(&self, target: i64) -> Option<usize> {
+        let step_bits = self.step_bits();
         // Binary search to find the range containing the target
         match self.find_containing_range(target) {
             Some((range_idx, range)) => {
-                // Calculate the cumulative count of values before this range
-                let mut cumulative_count = 0;
-                for i in 0..range_idx {
-                    let r = &self.ranges[i];
-                    cumulative_count += (r.1 .0 - r.0 .0 + 1) as usize;
-                }
+                // Calculate the cumulative count of entries before this range
+                let cumulative_count = value_count(&self.ranges[..range_idx], step_bits) as usize;
 
                 // Add the offset within the found range
-                let offset = (target - range.0 .0) as usize;
+                let offset = ((target - range.0 .0) >> step_bits) as usize;
                 Some(cumulative_count + offset)
             }
             None => None,
         }
     }
 
+    /// Returns the entry of the LUT that `target` is looked up at: `target` itself, or
+    /// the breakpoint below it in an interpolated LUT.
+    /// Returns `None` if the `target` value is not covered by any range in the layout.
+    pub fn breakpoint(&self, target: i64) -> Option<Fixed> {
+        let step_bits = self.step_bits();
+        let (_, range) = self.find_containing_range(target)?;
+        let offset = (target - range.0 .0) >> step_bits << step_bits;
+        Some(Fixed(range.0 .0 + offset))
+    }
+
+    /// Enumerates the entries of the LUT in order, every `2^step_bits` values of each range.
+    pub fn entries(&self) -> Vec<i64> {
+        let step = 1 << self.step_bits();
+        self.ranges
+            .iter()
+            .flat_map(|r| (r.0 .0..=r.1 .0).step_by(step))
+            .collect()
+    }
+
+    /// Returns the smallest and the largest values covered by the layout.
+    pub fn bounds(&self) -> Option<(Fixed, Fixed)> {
+        Some((self.ranges.first()?.0, self.ranges.last()?.1))
+    }
+
+    /// Returns the nearest bound of the layout if `target` lies beyond it.
+    ///
+    /// Returns `None` if `target` lies between the bounds, covered or not by a range.
+    pub fn clamp(&self, target: i64) -> Option<Fixed> {
+        let (min, max) = self.bounds()?;
+        if target < min.0 {
+            Some(min)
+        } else if target > max.0 {
+            Some(max)
+        } else {
+            None
+        }
+    }
+
+    /// Extends the layout to cover the `targets` values, and updates its `log_size`.
+    pub fn grow(&mut self, targets: impl IntoIterator<Item = i64>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.extend(targets.into_iter().map(|t| Range(Fixed(t), Fixed(t))));
+        *self = Self::with_interpolation(coalesce_ranges(ranges), self.scale, self.interpolation);
+    }
+
+    /// Returns the ranges of the layout as real values.
+    pub fn real_ranges(&self) -> Vec<(f64, f64)> {
+        self.ranges
+            .iter()
+            .map(|r| (r.0.to_f64(self.scale), r.1.to_f64(self.scale)))
+            .collect()
+    }
+
     /// Finds the specific `Range` within the layout that contains the `target` value.
     /// Returns the index of the range and a reference to the range itself.
     fn find_containing_range(&self, target: i64) -> Option<(usize, &Range)> {
@@ -118,10 +235,45 @@ impl LookupLayout {
     }
 }
 
-/// Counts the total number of unique integer values covered by a set of ranges.
+/// Counts the total number of entries of a set of ranges, one every `2^step_bits` values.
 /// Used to determine the minimum required size of a lookup table before padding.
-fn value_count(ranges: &Vec<Range>) -> u32 {
-    ranges.iter().map(|r| (r.1 .0 - r.0 .0 + 1) as u32).sum()
+fn value_count(ranges: &[Range], step_bits: u32) -> u32 {
+    ranges
+        .iter()
+        .map(|r| (((r.1 .0 - r.0 .0) >> step_bits) + 1) as u32)
+        .sum()
+}
+
+/// Merges overlapping or adjacent ranges into a minimal set of disjoint ranges.
+///
+/// Used to consolidate the input ranges identified for lookup operations during
+/// the `gen_circuit_settings` phase, optimizing the lookup table structure.
+pub fn coalesce_ranges(mut ranges: Vec<Range>) -> Vec<Range> {
+    if ranges.is_empty() {
+        return Vec::new();
+    }
+
+    // Sort by lower bound
+    ranges.sort_unstable_by_key(|r| r.0 .0);
+
+    // Use the first element as the starting point
+    let mut result = Vec::with_capacity(ranges.len());
+    let mut current_range = ranges[0].clone();
+
+    // Merge overlapping or adjacent ranges
+    for range in ranges.into_iter().skip(1) {
+        if range.0 .0 <= current_range.1 .0 + 1 {
+            // Merge ranges if they overlap or are adjacent
+            current_range.1 = Fixed(current_range.1 .0.max(range.1 .0));
+        } else {
+            // No overlap, push the current range and start a new one
+            result.push(current_range);
+            current_range = range;
+        }
+    }
+
+    result.push(current_range);
+    result
 }
 
 /// Trait representing a preprocessed column in the trace.
@@ -145,7 +297,8 @@ pub trait PreProcessedColumn: Any {
 /// Container holding all preprocessed columns for a STARK proof.
 ///
 /// This structure groups different types of `PreProcessedColumn` instances together.
-/// The columns are typically sorted by log_size for efficiency in the PCS.
+/// The columns are sorted by log_size for efficiency in the PCS, then by ID so that the
+/// prover and the verifier lay them out the same way.
 pub struct PreProcessedTrace {
     /// Vector of boxed preprocessed column objects.
     pub(crate) columns: Vec<Box<dyn PreProcessedColumn>>,
@@ -153,9 +306,9 @@ pub struct PreProcessedTrace {
 
 impl PreProcessedTrace {
     /// Creates a new `PreProcessedTrace` from a vector of columns.
-    /// Sorts the columns internally by descending log_size.
+    /// Sorts the columns internally by descending log_size, then by ID.
     pub fn new(mut columns: Vec<Box<dyn PreProcessedColumn>>) -> Self {
-        columns.sort_by_key(|c| Reverse(c.log_size()));
+        columns.sort_by_key(|c| (Reverse(c.log_size()), c.id().id));
         Self { columns }
     }
 
@@ -174,6 +327,29 @@ impl PreProcessedTrace {
         self.columns.iter().map(|c| c.gen_column()).collect()
     }
 
+    /// Commits to the columns and returns the Merkle root of the preprocessed trace.
+    ///
+    /// The root is recorded in the verifying key, and the verifier checks the preprocessed
+    /// trace of a proof against it.
+    pub fn root(&self) -> Blake2sHash {
+        let config = PcsConfig::default();
+        let max_log_size = self.log_sizes().into_iter().max().unwrap_or_default();
+        let twiddles = SimdBackend::precompute_twiddles(
+            CanonicCoset::new(max_log_size + config.fri_config.log_blowup_factor + 2)
+                .circle_domain()
+                .half_coset,
+        );
+        let channel = &mut Blake2sChannel::default();
+        let mut commitment_scheme =
+            CommitmentSchemeProver::<_, Blake2sMerkleChannel>::new(config, &twiddles);
+
+        let mut tree_builder = commitment_scheme.tree_builder();
+        tree_builder.extend_evals(self.gen_trace());
+        tree_builder.commit(channel);
+
+        commitment_scheme.roots()[0]
+    }
+
     /// Filters the columns and returns references to those of a specific concrete type `T`.
     pub fn columns_of<T: Any>(&self) -> Vec<&T> {
         self.columns
@@ -183,42 +359,297 @@ impl PreProcessedTrace {
     }
 }
 
-/// Converts configured `Lookups` into a vector of corresponding `PreProcessedColumn` boxes.
-pub fn lookups_to_preprocessed_column(lookups: &Lookups) -> Vec<Box<dyn PreProcessedColumn>> {
-    let mut lut_cols: Vec<Box<dyn PreProcessedColumn>> = Vec::new();
-    if let Some(sin_lookup) = &lookups.sin {
-        let col_0 = SinPreProcessed::new(sin_lookup.layout.clone(), 0);
-        let col_1 = SinPreProcessed::new(sin_lookup.layout.clone(), 1);
-        lut_cols.push(Box::new(col_0));
-        lut_cols.push(Box::new(col_1));
+// Generates the preprocessed columns of the unary LUT functions, from the `lut_functions!`
+// registry.
+macro_rules! lut_preprocessed_columns {
+    (
+        unary [$([$name:ident, $field:ident, $lookup:ident, $Lookup:ident])*]
+        all [$([$aname:ident, $afield:ident, $alookup:ident, $ALookup:ident])*]
+    ) => {
+        /// Converts configured `Lookups` into a vector of corresponding `PreProcessedColumn` boxes.
+        pub fn lookups_to_preprocessed_column(
+            lookups: &Lookups,
+        ) -> Vec<Box<dyn PreProcessedColumn>> {
+            let mut lut_cols: Vec<Box<dyn PreProcessedColumn>> = Vec::new();
+            $(push_unary_columns(&lookups.$afield, &mut lut_cols);)*
+            if let Some(range_check_lookup) = &lookups.range_check {
+                let col = RangeCheckPreProcessed::new(range_check_lookup.layout.clone());
+                lut_cols.push(Box::new(col));
+            }
+            lut_cols
+        }
+
+        /// Converts the trace table of a unary LUT function component into its structural columns.
+        ///
+        /// The lookup components only hold the multiplicities of their tables.
+        fn lut_table_to_preprocessed_column(
+            table: &LutTraceTable,
+        ) -> Vec<Box<dyn PreProcessedColumn>> {
+            match table {
+                $(
+                    LutTraceTable::$name(table) => StructurePreProcessed::columns(
+                        $name::NAME,
+                        &table.table,
+                        UnaryTraceTableRow::padding(),
+                    ),
+                )*
+                _ => Vec::new(),
+            }
+        }
+
+        /// Lays out the structural columns of the unary LUT function components of a claim.
+        fn lut_claim_to_preprocessed_column(claim: &LutClaim) -> Vec<Box<dyn PreProcessedColumn>> {
+            let mut cols: Vec<Box<dyn PreProcessedColumn>> = Vec::new();
+            $(
+                if let Some(ref claim) = claim.$field {
+                    cols.extend(StructurePreProcessed::layout::<UnaryTraceTableRow>(
+                        $name::NAME,
+                        claim.log_size,
+                    ));
+                }
+            )*
+            cols
+        }
+    };
+}
+
+crate::lut_functions!(lut_preprocessed_columns);
+
+/// Converts the trace tables of a graph execution into the structural columns of their
+/// components (Cf. `StructuredRow`).
+pub fn trace_tables_to_preprocessed_column(
+    trace_tables: &[TraceTable],
+) -> Vec<Box<dyn PreProcessedColumn>> {
+    trace_tables
+        .iter()
+        .flat_map(|table| match table {
+            TraceTable::Add { table } => {
+                StructurePreProcessed::columns("add", &table.table, AddTraceTableRow::padding())
+            }
+            TraceTable::Mul { table } => {
+                StructurePreProcessed::columns("mul", &table.table, MulTraceTableRow::padding())
+            }
+            TraceTable::Recip { table } => {
+                StructurePreProcessed::columns("recip", &table.table, RecipTraceTableRow::padding())
+            }
+            TraceTable::SumReduce { table } => StructurePreProcessed::columns(
+                "sum_reduce",
+                &table.table,
+                SumReduceTraceTableRow::padding(),
+            ),
+            TraceTable::MatMul { table } => StructurePreProcessed::columns(
+                "matmul",
+                &table.table,
+                MatMulTraceTableRow::padding(),
+            ),
+            TraceTable::MaxReduce { table } => StructurePreProcessed::columns(
+                "max_reduce",
+                &table.table,
+                MaxReduceTraceTableRow::padding(),
+            ),
+            TraceTable::Sqrt { table } => {
+                StructurePreProcessed::columns("sqrt", &table.table, SqrtTraceTableRow::padding())
+            }
+            TraceTable::LessThan { table } => StructurePreProcessed::columns(
+                "less_than",
+                &table.table,
+                LessThanTraceTableRow::padding(),
+            ),
+            TraceTable::Softmax { table } => StructurePreProcessed::columns(
+                "softmax",
+                &table.table,
+                SoftmaxTraceTableRow::padding(),
+            ),
+            TraceTable::Norm { table } => {
+                StructurePreProcessed::columns("norm", &table.table, NormTraceTableRow::padding())
+            }
+            TraceTable::Conv2d { table } => StructurePreProcessed::columns(
+                "conv2d",
+                &table.table,
+                Conv2dTraceTableRow::padding(),
+            ),
+            TraceTable::Relu { table } => {
+                StructurePreProcessed::columns("relu", &table.table, ReluTraceTableRow::padding())
+            }
+            TraceTable::Mod { table } => {
+                StructurePreProcessed::columns("modulo", &table.table, ModTraceTableRow::padding())
+            }
+            TraceTable::Rescale { table } => StructurePreProcessed::columns(
+                "rescale",
+                &table.table,
+                RescaleTraceTableRow::padding(),
+            ),
+            TraceTable::Weights { table } => StructurePreProcessed::columns(
+                "weights",
+                &table.table,
+                WeightsTraceTableRow::padding(),
+            ),
+            TraceTable::Lut { table } => lut_table_to_preprocessed_column(table),
+            // The range check lookup component only holds the multiplicities of its table.
+            TraceTable::RangeCheckLookup { .. } => Vec::new(),
+        })
+        .collect()
+}
+
+/// Lays out the structural columns of the components of a claim, without their values.
+///
+/// Used by the verifier, which only needs the IDs and sizes of the columns to check the
+/// preprocessed trace committed in the verifying key.
+pub fn claim_to_preprocessed_column(claim: &LuminairClaim) -> Vec<Box<dyn PreProcessedColumn>> {
+    let mut cols: Vec<Box<dyn PreProcessedColumn>> = Vec::new();
+    if let Some(ref claim) = claim.add {
+        cols.extend(StructurePreProcessed::layout::<AddTraceTableRow>(
+            "add",
+            claim.log_size,
+        ));
+    }
+    if let Some(ref claim) = claim.mul {
+        cols.extend(StructurePreProcessed::layout::<MulTraceTableRow>(
+            "mul",
+            claim.log_size,
+        ));
+    }
+    if let Some(ref claim) = claim.recip {
+        cols.extend(StructurePreProcessed::layout::<RecipTraceTableRow>(
+            "recip",
+            claim.log_size,
+        ));
+    }
+    if let Some(ref claim) = claim.sum_reduce {
+        cols.extend(StructurePreProcessed::layout::<SumReduceTraceTableRow>(
+            "sum_reduce",
+            claim.log_size,
+        ));
+    }
+    if let Some(ref claim) = claim.matmul {
+        cols.extend(StructurePreProcessed::layout::<MatMulTraceTableRow>(
+            "matmul",
+            claim.log_size,
+        ));
+    }
+    if let Some(ref claim) = claim.max_reduce {
+        cols.extend(StructurePreProcessed::layout::<MaxReduceTraceTableRow>(
+            "max_reduce",
+            claim.log_size,
+        ));
+    }
+    if let Some(ref claim) = claim.sqrt {
+        cols.extend(StructurePreProcessed::layout::<SqrtTraceTableRow>(
+            "sqrt",
+            claim.log_size,
+        ));
+    }
+    if let Some(ref claim) = claim.less_than {
+        cols.extend(StructurePreProcessed::layout::<LessThanTraceTableRow>(
+            "less_than",
+            claim.log_size,
+        ));
+    }
+    cols.extend(lut_claim_to_preprocessed_column(&claim.luts));
+    if let Some(ref claim) = claim.softmax {
+        cols.extend(StructurePreProcessed::layout::<SoftmaxTraceTableRow>(
+            "softmax",
+            claim.log_size,
+        ));
+    }
+    if let Some(ref claim) = claim.norm {
+        cols.extend(StructurePreProcessed::layout::<NormTraceTableRow>(
+            "norm",
+            claim.log_size,
+        ));
     }
-    lut_cols
+    if let Some(ref claim) = claim.conv2d {
+        cols.extend(StructurePreProcessed::layout::<Conv2dTraceTableRow>(
+            "conv2d",
+            claim.log_size,
+        ));
+    }
+    if let Some(ref claim) = claim.relu {
+        cols.extend(StructurePreProcessed::layout::<ReluTraceTableRow>(
+            "relu",
+            claim.log_size,
+        ));
+    }
+    if let Some(ref claim) = claim.modulo {
+        cols.extend(StructurePreProcessed::layout::<ModTraceTableRow>(
+            "modulo",
+            claim.log_size,
+        ));
+    }
+    if let Some(ref claim) = claim.rescale {
+        cols.extend(StructurePreProcessed::layout::<RescaleTraceTableRow>(
+            "rescale",
+            claim.log_size,
+        ));
+    }
+    if let Some(ref claim) = claim.weights {
+        cols.extend(StructurePreProcessed::layout::<WeightsTraceTableRow>(
+            "weights",
+            claim.log_size,
+        ));
+    }
+    cols
 }
 
-// ================== SIN ==================
+/// Pushes the input and output columns of the LUT of a unary function, if active,
+/// and the slope column of an interpolated LUT.
+fn push_unary_columns<F: LutFunction>(
+    lookup: &Option<UnaryLookup<F>>,
+    lut_cols: &mut Vec<Box<dyn PreProcessedColumn>>,
+) {
+    if let Some(lookup) = lookup {
+        for col_index in 0..unary_column_count(&lookup.layout) {
+            let col = UnaryPreProcessed::<F>::new(lookup.layout.clone(), col_index);
+            lut_cols.push(Box::new(col));
+        }
+    }
+}
+
+// ================== UNARY FUNCTIONS ==================
+
+/// Returns the number of columns of a unary function LUT: input and output, plus the
+/// slope if interpolated.
+fn unary_column_count(layout: &LookupLayout) -> usize {
+    if layout.interpolation.is_some() {
+        3
+    } else {
+        2
+    }
+}
 
-/// Concrete implementation of `PreProcessedColumn` for the Sine Lookup Table (LUT).
+/// Concrete implementation of `PreProcessedColumn` for the Lookup Table (LUT) of a unary function `F`.
 ///
-/// Stores the layout (`LookupLayout`) and generates two columns:
+/// Stores the layout (`LookupLayout`) and generates the columns:
 /// - Column 0: Input values `x` (as `Fixed` point `M31` elements).
-/// - Column 1: Output values `sin(x)` (as `Fixed` point `M31` elements).
+/// - Column 1: Output values `f(x)` (as `Fixed` point `M31` elements).
+/// - Column 2: Slopes of `f` from each breakpoint `x` to the next, for an interpolated LUT only.
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct SinPreProcessed {
+#[serde(bound = "")]
+pub struct UnaryPreProcessed<F: LutFunction> {
     /// The layout defining the ranges and size of the LUT.
     pub layout: LookupLayout,
-    /// The index of the column (0 for input `x`, 1 for output `sin(x)`).
+    /// The index of the column (0 for input `x`, 1 for output `f(x)`, 2 for the slope).
     pub col_index: usize,
+    /// Marker binding the column to its function.
+    #[serde(skip)]
+    _function: PhantomData<F>,
 }
 
-impl SinPreProcessed {
-    /// Creates a new `SinPreProcessed` column instance.
-    /// Panics if `col_index` is not 0 or 1.
+impl<F: LutFunction> UnaryPreProcessed<F> {
+    /// Creates a new `UnaryPreProcessed` column instance.
+    /// Panics if `col_index` is not 0 or 1, or 2 for an interpolated layout.
     pub fn new(layout: LookupLayout, col_index: usize) -> Self {
-        assert!(col_index < 2, "Sin LUT must have 2 columns");
+        let n_cols = unary_column_count(&layout);
+        assert!(
+            col_index < n_cols,
+            "{} LUT must have {n_cols} columns",
+            F::NAME
+        );
 
         Self {
             layout,
             col_index,
+            _function: PhantomData,
         }
     }
 
@@ -228,29 +659,103 @@ impl SinPreProcessed {
     }
 }
 
-impl PreProcessedColumn for SinPreProcessed {
+impl<F: LutFunction> PreProcessedColumn for UnaryPreProcessed<F> {
     /// Returns the log_size defined by the layout.
     fn log_size(&self) -> u32 {
         self.layout.log_size
     }
 
-    /// Returns the ID string `sin_lut_0` or `sin_lut_1`.
+    /// Returns the ID string `{name}_lut_{col_index}`, e.g. `sin_lut_0`.
     fn id(&self) -> PreProcessedColumnId {
         PreProcessedColumnId {
-            id: format!("sin_lut_{}", self.col_index),
+            id: format!("{}_lut_{}", F::NAME, self.col_index),
         }
     }
 
-    /// Creates a boxed clone of this `SinPreProcessed` instance.
+    /// Creates a boxed clone of this `UnaryPreProcessed` instance.
     fn clone_box(&self) -> Box<dyn PreProcessedColumn> {
         Box::new(self.clone())
     }
 
-    /// Generates the `CircleEvaluation` for this specific column (input or output).
+    /// Generates the `CircleEvaluation` for this specific column (input, output or slope).
     ///
-    /// It iterates through all unique integer values covered by the `layout` ranges,
-    /// calculates the corresponding `Fixed` point value (`x` or `sin(x)`),
-    /// converts it to `BaseField` (`M31`), and places it in the evaluation column.
+    /// It iterates through the entries of the `layout` (every value covered by its ranges,
+    /// or their breakpoints if interpolated), calculates the corresponding `Fixed` point value
+    /// (`x`, `f(x)` or the slope), converts it to `BaseField` (`M31`), and places it in the
+    /// evaluation column.
+    /// The column is padded with zeros to the power-of-two size defined by `log_size`.
+    fn gen_column(&self) -> CircleEvaluation<SimdBackend, BaseField, BitReversedOrder> {
+        let log_size = self.log_size();
+        let domain = CanonicCoset::new(log_size).circle_domain();
+
+        let trace_size = 1 << log_size;
+        let mut column = BaseColumn::zeros(trace_size);
+
+        let scale = self.layout.scale;
+        let step = 1 << self.layout.step_bits();
+        for (i, value) in self.layout.entries().into_iter().enumerate() {
+            match self.col_index {
+                0 => column.set(i, Fixed(value).to_m31()),
+                1 => column.set(i, F::eval_fixed(Fixed(value), scale).to_m31()),
+                2 => column.set(i, F::slope_fixed(Fixed(value), step, scale).to_m31()),
+                _ => unreachable!(),
+            }
+        }
+
+        CircleEvaluation::new(domain, column)
+    }
+
+    /// Returns this instance as `&dyn Any` for downcasting.
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+// ================== RANGE CHECK ==================
+
+/// Concrete implementation of `PreProcessedColumn` for the shared Range Check Lookup Table (LUT).
+///
+/// Stores the layout (`LookupLayout`) and generates a single column holding
+/// every integer value covered by the layout, i.e. `0, 1, ..., 2^k - 1`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RangeCheckPreProcessed {
+    /// The layout defining the range and size of the LUT.
+    pub layout: LookupLayout,
+}
+
+impl RangeCheckPreProcessed {
+    /// Creates a new `RangeCheckPreProcessed` column instance.
+    pub fn new(layout: LookupLayout) -> Self {
+        Self { layout }
+    }
+
+    /// Returns a reference to the generated `CircleEvaluation` for this column.
+    pub fn evaluation(&self) -> CircleEvaluation<SimdBackend, BaseField, BitReversedOrder> {
+        self.gen_column()
+    }
+}
+
+impl PreProcessedColumn for RangeCheckPreProcessed {
+    /// Returns the log_size defined by the layout.
+    fn log_size(&self) -> u32 {
+        self.layout.log_size
+    }
+
+    /// Returns the ID string `range_check_lut`.
+    fn id(&self) -> PreProcessedColumnId {
+        PreProcessedColumnId {
+            id: "range_check_lut".to_string(),
+        }
+    }
+
+    /// Creates a boxed clone of this `RangeCheckPreProcessed` instance.
+    fn clone_box(&self) -> Box<dyn PreProcessedColumn> {
+        Box::new(self.clone())
+    }
+
+    /// Generates the `CircleEvaluation` for the range-check column.
+    ///
+    /// Writes every integer value covered by the `layout` ranges, in increasing order.
     /// The column is padded with zeros to the power-of-two size defined by `log_size`.
     fn gen_column(&self) -> CircleEvaluation<SimdBackend, BaseField, BitReversedOrder> {
         let log_size = self.log_size();
@@ -270,17 +775,128 @@ impl PreProcessedColumn for SinPreProcessed {
         let mut column = BaseColumn::zeros(trace_size);
 
         for (i, value) in all_values.iter().enumerate() {
-            match self.col_index {
-                0 => column.set(i, Fixed::<DEFAULT_FP_SCALE>(*value).to_m31()),
-                1 => column.set(
-                    i,
-                    Fixed::<DEFAULT_FP_SCALE>::from_f64(
-                        Fixed::<DEFAULT_FP_SCALE>(*value).to_f64().sin(),
-                    )
-                    .to_m31(),
-                ),
-                _ => unreachable!(),
-            }
+            column.set(i, Fixed(*value).to_m31());
+        }
+
+        CircleEvaluation::new(domain, column)
+    }
+
+    /// Returns this instance as `&dyn Any` for downcasting.
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+// ================== STRUCTURE ==================
+
+/// Trait implemented by the rows of a component trace, exposing its structural columns.
+///
+/// The structural columns of a trace hold the node and tensor IDs, the indices, the flags
+/// and the multiplicities of its rows, which only depend on the graph. They are committed in
+/// the preprocessed trace, whose root is part of the verifying key, and each component
+/// constrains its own columns to them, so that a proof can only be made for the graph of the key.
+pub trait StructuredRow {
+    /// Names of the structural columns of the trace.
+    const STRUCTURE: &'static [&'static str];
+
+    /// Returns the values of the structural columns of the row, in the order of `STRUCTURE`.
+    fn structure(&self) -> Vec<BaseField>;
+}
+
+/// Concrete implementation of `PreProcessedColumn` for a structural column of a component trace.
+///
+/// The prover builds the column from the rows of the trace table, padded like the main trace.
+/// The verifier only needs its ID and size, which follow from the claim of the verifying key,
+/// and builds it without values.
+#[derive(Clone, Debug)]
+pub struct StructurePreProcessed {
+    /// Name of the component owning the column.
+    pub component: String,
+    /// Name of the column, from `StructuredRow::STRUCTURE`.
+    pub column: &'static str,
+    /// Log2 size of the component trace.
+    pub log_size: u32,
+    /// Values of the column, empty if only its layout is known.
+    pub values: Vec<BaseField>,
+}
+
+impl StructurePreProcessed {
+    /// Returns the structural columns of a component trace, padded to its power-of-two size
+    /// with the padding row of the component.
+    pub fn columns<R: StructuredRow>(
+        component: &str,
+        rows: &[R],
+        padding: R,
+    ) -> Vec<Box<dyn PreProcessedColumn>> {
+        let log_size = calculate_log_size(rows.len());
+        let padding = padding.structure();
+        let mut structures: Vec<Vec<BaseField>> = rows.iter().map(|row| row.structure()).collect();
+        structures.resize(1 << log_size, padding);
+
+        R::STRUCTURE
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                Box::new(Self {
+                    component: component.to_string(),
+                    column,
+                    log_size,
+                    values: structures.iter().map(|structure| structure[i]).collect(),
+                }) as Box<dyn PreProcessedColumn>
+            })
+            .collect()
+    }
+
+    /// Returns the layout of the structural columns of a component trace of the given size,
+    /// without their values.
+    pub fn layout<R: StructuredRow>(
+        component: &str,
+        log_size: u32,
+    ) -> Vec<Box<dyn PreProcessedColumn>> {
+        R::STRUCTURE
+            .iter()
+            .map(|column| {
+                Box::new(Self {
+                    component: component.to_string(),
+                    column,
+                    log_size,
+                    values: Vec::new(),
+                }) as Box<dyn PreProcessedColumn>
+            })
+            .collect()
+    }
+
+    /// Returns the ID of a structural column, `<component>_<column>`.
+    pub fn column_id(component: &str, column: &str) -> PreProcessedColumnId {
+        PreProcessedColumnId {
+            id: format!("{}_{}", component, column),
+        }
+    }
+}
+
+impl PreProcessedColumn for StructurePreProcessed {
+    /// Returns the log_size of the component trace.
+    fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// Returns the ID string `<component>_<column>`.
+    fn id(&self) -> PreProcessedColumnId {
+        Self::column_id(&self.component, self.column)
+    }
+
+    /// Creates a boxed clone of this `StructurePreProcessed` instance.
+    fn clone_box(&self) -> Box<dyn PreProcessedColumn> {
+        Box::new(self.clone())
+    }
+
+    /// Generates the `CircleEvaluation` for the structural column, row by row.
+    fn gen_column(&self) -> CircleEvaluation<SimdBackend, BaseField, BitReversedOrder> {
+        let domain = CanonicCoset::new(self.log_size).circle_domain();
+        let mut column = BaseColumn::zeros(1 << self.log_size);
+
+        for (i, value) in self.values.iter().enumerate() {
+            column.set(i, *value);
         }
 
         CircleEvaluation::new(domain, column)
@@ -292,6 +908,23 @@ impl PreProcessedColumn for SinPreProcessed {
     }
 }
 
+/// Constrains the structural columns of a component trace to the ones of the preprocessed trace.
+///
+/// Takes the name of the component and the values of its structural columns, in the order
+/// of `R::STRUCTURE`.
+pub(crate) fn eval_structure<E: EvalAtRow, R: StructuredRow>(
+    eval: &mut E,
+    component: &str,
+    values: Vec<E::F>,
+) {
+    assert_eq!(values.len(), R::STRUCTURE.len());
+    for (column, value) in R::STRUCTURE.iter().zip(values) {
+        let fixed =
+            eval.get_preprocessed_column(StructurePreProcessed::column_id(component, column));
+        eval.add_constraint(value - fixed);
+    }
+}
+
 #[cfg(test)]
 mod range_tests {
 
@@ -305,7 +938,7 @@ mod range_tests {
         // Get all values from ranges
         let mut all_values: Vec<i64> = ranges.iter().flat_map(|r| (r.0 .0..=r.1 .0)).collect();
 
-        // Sort and deduplicate (mimicking what SinPreProcessed does)
+        // Sort and deduplicate (mimicking what UnaryPreProcessed does)
         all_values.sort_unstable();
         all_values.dedup();
 
@@ -326,7 +959,7 @@ mod range_tests {
             range(200, 210),  // 11 values
         ];
 
-        let layout = LookupLayout::new(ranges.clone());
+        let layout = LookupLayout::new(ranges.clone(), 12);
 
         // Compute expected indices for validation
         let expected_indices = calculate_expected_indices(&ranges);
@@ -370,4 +1003,42 @@ mod range_tests {
             "Value 199 should not be in the LUT"
         );
     }
+
+    #[test]
+    fn test_find_index_interpolated() {
+        // Breakpoints every 8 values
+        let interpolation = Interpolation {
+            step_bits: 3,
+            delta_bits: 0,
+        };
+        let ranges = vec![
+            range(-100, -50), // Breakpoints -100, -92, ..., -52: 7 entries
+            range(0, 10),     // Breakpoints 0, 8: 2 entries
+        ];
+
+        let layout = LookupLayout::interpolated(ranges, 12, interpolation);
+        assert_eq!(layout.entries().len(), 9);
+        assert_eq!(layout.log_size, 4);
+
+        // Inputs are looked up at the breakpoint below them
+        assert_eq!(layout.find_index(-100), Some(0));
+        assert_eq!(layout.find_index(-93), Some(0));
+        assert_eq!(layout.find_index(-92), Some(1));
+        assert_eq!(layout.find_index(-50), Some(6));
+        assert_eq!(layout.find_index(7), Some(7));
+        assert_eq!(layout.find_index(10), Some(8));
+        assert_eq!(layout.breakpoint(-93).map(|x| x.0), Some(-100));
+        assert_eq!(layout.breakpoint(-50).map(|x| x.0), Some(-52));
+        assert_eq!(layout.breakpoint(10).map(|x| x.0), Some(8));
+
+        // Values in the gaps are not covered
+        assert_eq!(layout.find_index(-49), None);
+        assert_eq!(layout.breakpoint(11), None);
+
+        // Growing the layout keeps its interpolation
+        let mut grown = layout.clone();
+        grown.grow(vec![20]);
+        assert_eq!(grown.interpolation, Some(interpolation));
+        assert_eq!(grown.find_index(20), Some(9));
+    }
 }
@@ -0,0 +1,140 @@
+use luminair_utils::LuminairError;
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+use stwo_prover::{
+    constraint_framework::Relation,
+    core::{
+        channel::Channel,
+        fields::{m31::M31, qm31::SecureField, FieldExpOps},
+    },
+};
+
+use crate::{components::NodeElements, fixed_point::Fixed};
+
+/// Public inputs and outputs of a LuminAIR computation.
+///
+/// Inputs are the initializers of the graph (graph inputs and constants) and outputs are
+/// the retrieved tensors. No component produces the inputs or consumes the outputs, so their
+/// entries in the node relation are balanced by a boundary term computed by the verifier
+/// from these values (see `log_sum_valid`).
+/// The verifier only takes the values from the proof: it rebuilds the emitting nodes and
+/// the multiplicities from the verifying key (see `VerifyingKey::public_io`).
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PublicIo {
+    /// Initializer tensors read by the graph.
+    pub inputs: Vec<PublicTensor>,
+    /// Final output tensors of the graph.
+    pub outputs: Vec<PublicTensor>,
+}
+
+/// A tensor whose values are part of the public statement.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PublicTensor {
+    /// ID of the node emitting the tensor values in the node relation.
+    pub node_id: u32,
+    /// ID of the graph tensor holding the values on the user side
+    /// (the `Function` node of an input, or the retrieved node of an output).
+    pub tensor_id: u32,
+    /// Values of the tensor, in fixed-point representation.
+    pub values: Vec<M31>,
+    /// Number of entries of each element in the node relation: the number of reads of
+    /// each input element, and 1 for each output element.
+    pub multiplicities: Vec<u32>,
+}
+
+impl PublicIo {
+    /// Mixes the public inputs and outputs into the Fiat-Shamir channel.
+    ///
+    /// Must happen before the main trace is committed, so that the interaction
+    /// elements depend on the statement being proven.
+    pub fn mix_into(&self, channel: &mut impl Channel) {
+        for tensors in [&self.inputs, &self.outputs] {
+            channel.mix_u64(tensors.len() as u64);
+            for tensor in tensors {
+                tensor.mix_into(channel);
+            }
+        }
+    }
+
+    /// Computes the boundary term of the node relation for the public tensors.
+    ///
+    /// Inputs are emitted with their multiplicities and outputs are consumed with theirs,
+    /// as a producing and a consuming component would do.
+    pub fn logup_sum(&self, node_elements: &NodeElements) -> SecureField {
+        let inputs = self.inputs.iter().map(|t| t.logup_sum(node_elements));
+        let outputs = self.outputs.iter().map(|t| t.logup_sum(node_elements));
+
+        inputs.sum::<SecureField>() - outputs.sum::<SecureField>()
+    }
+
+    /// Checks the public outputs against the expected ones.
+    ///
+    /// Each expected output is given as the ID of the retrieved tensor and its values,
+    /// which are compared after conversion to fixed-point at `fp_scale`.
+    pub fn check_outputs(
+        &self,
+        expected_outputs: &[(u32, Vec<f32>)],
+        fp_scale: u32,
+    ) -> Result<(), LuminairError> {
+        for (tensor_id, expected) in expected_outputs {
+            let output = self
+                .outputs
+                .iter()
+                .find(|t| t.tensor_id == *tensor_id)
+                .ok_or(LuminairError::MissingPublicOutput {
+                    tensor_id: *tensor_id,
+                })?;
+
+            let matches = output.values.len() == expected.len()
+                && output.values.iter().zip(expected).all(|(value, expected)| {
+                    *value == Fixed::from_f64(*expected as f64, fp_scale).to_m31()
+                });
+            if !matches {
+                return Err(LuminairError::PublicOutputMismatch {
+                    tensor_id: *tensor_id,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl PublicTensor {
+    /// Mixes the tensor IDs, values and multiplicities into the Fiat-Shamir channel.
+    fn mix_into(&self, channel: &mut impl Channel) {
+        channel.mix_u64(self.node_id.into());
+        channel.mix_u64(self.tensor_id.into());
+        channel.mix_u64(self.values.len() as u64);
+        channel.mix_felts(
+            &self
+                .values
+                .iter()
+                .map(|v| SecureField::from(*v))
+                .collect::<Vec<_>>(),
+        );
+        channel.mix_felts(
+            &self
+                .multiplicities
+                .iter()
+                .map(|m| SecureField::from(M31::from(*m)))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    /// Sums `multiplicity / combine(node_id, idx, value)` over the elements of the tensor.
+    fn logup_sum(&self, node_elements: &NodeElements) -> SecureField {
+        let node_id = M31::from(self.node_id);
+        let mut sum = SecureField::zero();
+
+        for (idx, (value, mult)) in self.values.iter().zip(&self.multiplicities).enumerate() {
+            if *mult == 0 {
+                continue;
+            }
+            let denom: SecureField = node_elements.combine(&[node_id, M31::from(idx), *value]);
+            sum += denom.inverse() * M31::from(*mult);
+        }
+
+        sum
+    }
+}
@@ -4,7 +4,7 @@ use std::{
     path::Path,
 };
 
-use crate::lookups::Lookups;
+use crate::{lookups::Lookups, DEFAULT_FP_SCALE};
 use luminair_utils::LuminairError;
 use serde::{Deserialize, Serialize};
 
@@ -16,6 +16,36 @@ use serde::{Deserialize, Serialize};
 pub struct CircuitSettings {
     /// Lookup table configurations required by the circuit.
     pub lookups: Lookups,
+    /// Fixed-point scale of the circuit: values are represented as `x * 2^fp_scale`.
+    #[serde(default = "default_fp_scale")]
+    pub fp_scale: u32,
+    /// IDs of the input tensors (`Function` nodes) holding the model weights.
+    ///
+    /// These tensors are committed in the verifying key instead of being public inputs,
+    /// so the proof is bound to the published weights.
+    #[serde(default)]
+    pub committed_weights: Vec<u32>,
+    /// Secret salt from which the blinding rows of the committed weights tree are derived.
+    ///
+    /// It is shared by the model publisher and the prover, so that both build the same tree,
+    /// and must not be published with the verifying key.
+    #[serde(default = "random_weights_salt")]
+    pub weights_salt: [u64; 4],
+    /// Maximum error of the interpolated lookup tables, or `None` if every lookup table
+    /// holds an entry per value of its ranges.
+    #[serde(default)]
+    pub interpolation_error: Option<f64>,
+}
+
+fn default_fp_scale() -> u32 {
+    DEFAULT_FP_SCALE
+}
+
+/// Draws a fresh salt for the blinding rows of the committed weights tree.
+pub fn random_weights_salt() -> [u64; 4] {
+    let mut bytes = [0u8; 32];
+    getrandom::getrandom(&mut bytes).expect("System randomness should be available");
+    std::array::from_fn(|i| u64::from_le_bytes(bytes[8 * i..8 * (i + 1)].try_into().unwrap()))
 }
 
 impl CircuitSettings {
@@ -14,9 +14,10 @@ use stwo_prover::core::{
     fields::m31::M31,
     pcs::TreeSubspan,
     poly::{circle::CircleEvaluation, BitReversedOrder},
+    utils::{bit_reverse_index, coset_index_to_circle_domain_index},
 };
 
-use crate::LuminairInteractionClaim;
+use crate::{components::NodeElements, public_io::PublicIo, LuminairInteractionClaim};
 
 /// Calculates the minimum power-of-two log size for a trace column.
 ///
@@ -32,25 +33,46 @@ pub fn calculate_log_size(max_size: usize) -> u32 {
         + LOG_N_LANES
 }
 
+/// Returns the number of bits needed to represent a non-negative `value`.
+pub fn bit_length(value: i64) -> u32 {
+    64 - value.max(0).leading_zeros()
+}
+
 /// Verifies the LogUp interaction claim consistency.
 ///
 /// In the LogUp protocol (used for lookups and permutations), the sum of accumulated
 /// interaction values across all related columns must equal zero for the proof to be valid.
-/// This function sums the `claimed_sum` from all component interaction claims and checks this condition.
+/// This function sums the `claimed_sum` from all component interaction claims, together with
+/// the boundary term of the public inputs and outputs, and checks this condition.
 /// Returns `true` if the sums balance to zero, `false` otherwise.
-pub fn log_sum_valid(interaction_claim: &LuminairInteractionClaim) -> bool {
-    let mut sum = PackedSecureField::zero();
+pub fn log_sum_valid(
+    interaction_claim: &LuminairInteractionClaim,
+    public_io: &PublicIo,
+    node_elements: &NodeElements,
+) -> bool {
+    let mut sum: PackedSecureField = public_io.logup_sum(node_elements).into();
 
     for claim_opt in [
         &interaction_claim.add,
         &interaction_claim.mul,
         &interaction_claim.sum_reduce,
+        &interaction_claim.matmul,
         &interaction_claim.recip,
         &interaction_claim.max_reduce,
-        &interaction_claim.sin,
-        &interaction_claim.sin_lookup,
         &interaction_claim.sqrt,
-    ] {
+        &interaction_claim.less_than,
+        &interaction_claim.softmax,
+        &interaction_claim.norm,
+        &interaction_claim.conv2d,
+        &interaction_claim.relu,
+        &interaction_claim.modulo,
+        &interaction_claim.rescale,
+        &interaction_claim.range_check_lookup,
+        &interaction_claim.weights,
+    ]
+    .into_iter()
+    .chain(interaction_claim.luts.claims())
+    {
         if let Some(ref int_cl) = claim_opt {
             sum += int_cl.claimed_sum.into();
         }
@@ -70,6 +92,22 @@ pub fn pack_values<T: Pack>(values: &[T]) -> Vec<T::SimdType> {
         .collect()
 }
 
+/// Reorders trace rows so that a mask offset of `1` reads the next row of `rows`.
+///
+/// Trace columns are committed in bit-reversed circle domain order, while mask offsets
+/// step along the canonic coset. Placing the `i`-th row at the bit-reversed position of
+/// the `i`-th coset point lets transition constraints link consecutive rows.
+/// The length of `rows` must be a power of two.
+pub fn to_trace_order<T: Copy>(rows: &[T]) -> Vec<T> {
+    let log_size = rows.len().ilog2();
+    let mut ordered = rows.to_vec();
+    for (i, row) in rows.iter().enumerate() {
+        let position = bit_reverse_index(coset_index_to_circle_domain_index(i, log_size), log_size);
+        ordered[position] = *row;
+    }
+    ordered
+}
+
 /// A thread-safe column for tracking lookup argument multiplicities.
 ///
 /// This is essential for proving lookup arguments correctly.
@@ -0,0 +1,348 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+    path::Path,
+};
+
+use crate::{
+    components::WeightsClaim,
+    lookups::{range_check::RangeCheckLookup, unary::functions::*, Lookups},
+    preprocessed::LookupLayout,
+    public_io::{PublicIo, PublicTensor},
+    LuminairClaim,
+};
+use itertools::Itertools;
+use luminair_utils::LuminairError;
+use serde::{Deserialize, Serialize};
+use stwo_prover::core::{
+    channel::Channel,
+    vcs::blake2_hash::{Blake2sHash, Blake2sHasher},
+};
+
+/// Verifying key of a compiled LuminAIR graph.
+///
+/// Captures everything the verifier needs to know about the model: the graph topology,
+/// the layout of the public inputs and outputs, the claim of the traces, the root of the
+/// preprocessed trace, the fixed-point scale, the layouts of the lookup tables and, if any,
+/// the commitment to the model weights.
+/// It is derived from the compiled graph by the model publisher, and its digest is mixed
+/// into the Fiat-Shamir channel by both the prover and the verifier, so that a proof
+/// only verifies against the key it was generated for.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct VerifyingKey {
+    /// Nodes of the compiled graph, ordered by ID.
+    pub nodes: Vec<NodeKey>,
+    /// Data edges of the compiled graph.
+    pub edges: Vec<EdgeKey>,
+    /// Layout of the public inputs and outputs of the compiled graph.
+    pub public_io: PublicIoKey,
+    /// Claim of the traces of the compiled graph: the components of its proofs and their sizes.
+    pub claim: LuminairClaim,
+    /// Merkle root of the preprocessed trace: the lookup tables and the structure of the traces.
+    pub preprocessed_root: Blake2sHash,
+    /// Fixed-point scale of the circuit.
+    pub fp_scale: u32,
+    /// Layouts of the lookup tables used by the circuit.
+    pub lookups: LookupLayouts,
+    /// Commitment to the model weights, if the graph has committed weights.
+    pub weights: Option<WeightsKey>,
+}
+
+/// Commitment to the weight tensors of a model.
+///
+/// The weights are not public inputs: the root of the committed weights tree is published,
+/// and binds the proof to these weights. The tree is blinded by random rows derived from
+/// `CircuitSettings::weights_salt`, so that neither the root nor the evaluations of the tree
+/// opened by the proof reveal the weights.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WeightsKey {
+    /// IDs of the nodes emitting the weights in the node relation.
+    pub node_ids: Vec<u32>,
+    /// Log2 size of the committed weights tree.
+    pub log_size: u32,
+    /// Merkle root of the committed weights tree.
+    pub root: Blake2sHash,
+}
+
+/// A node of the compiled graph.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct NodeKey {
+    /// ID of the node in the computational graph.
+    pub id: u32,
+    /// Kind of operation performed by the node, including its parameters.
+    pub op: String,
+}
+
+/// A data edge of the compiled graph.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct EdgeKey {
+    /// ID of the node producing the tensor.
+    pub src: u32,
+    /// ID of the node consuming the tensor.
+    pub dst: u32,
+    /// Position of the tensor among the inputs of the consuming node.
+    pub input_order: u8,
+    /// Position of the tensor among the outputs of the producing node.
+    pub output_order: u8,
+    /// Shape (view) through which the tensor is read.
+    pub shape: String,
+}
+
+/// Layout of the public inputs and outputs, without their values.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PublicIoKey {
+    /// Initializer tensors read by the graph, other than the committed weights.
+    pub inputs: Vec<PublicTensorKey>,
+    /// Retrieved tensors of the graph.
+    pub outputs: Vec<PublicTensorKey>,
+}
+
+/// A tensor of the public statement, without its values.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PublicTensorKey {
+    /// ID of the node emitting the tensor values in the node relation.
+    pub node_id: u32,
+    /// ID of the graph tensor holding the values on the user side.
+    pub tensor_id: u32,
+    /// Number of reads of each element by the consumers of an input, up to its last read element.
+    /// Empty for outputs, whose elements are each consumed once.
+    pub multiplicities: Vec<u32>,
+}
+
+// Generates the layouts of the lookup tables, with a field per function of the
+// `lut_functions!` registry, followed by the shared range check.
+macro_rules! lookup_layouts {
+    (
+        unary [$([$name:ident, $field:ident, $lookup:ident, $Lookup:ident])*]
+        all [$([$aname:ident, $afield:ident, $alookup:ident, $ALookup:ident])*]
+    ) => {
+        /// Layouts of the lookup tables, without their multiplicities.
+        #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+        pub struct LookupLayouts {
+            $(
+                #[doc = concat!("Layout of the ", stringify!($aname), " LUT, if active.")]
+                #[serde(default)]
+                pub $afield: Option<LookupLayout>,
+            )*
+            /// Layout of the shared range-check LUT, if active.
+            pub range_check: Option<LookupLayout>,
+        }
+
+        impl From<&Lookups> for LookupLayouts {
+            fn from(lookups: &Lookups) -> Self {
+                Self {
+                    $($afield: lookups.$afield.as_ref().map(|l| l.layout.clone()),)*
+                    range_check: lookups.range_check.as_ref().map(|l| l.layout.clone()),
+                }
+            }
+        }
+
+        impl LookupLayouts {
+            /// Builds the lookup configurations described by these layouts, with empty multiplicities.
+            pub fn to_lookups(&self) -> Lookups {
+                Lookups {
+                    $($afield: self.$afield.as_ref().map($ALookup::new),)*
+                    range_check: self
+                        .range_check
+                        .as_ref()
+                        .map(|layout| RangeCheckLookup::new(layout.log_size)),
+                }
+            }
+        }
+    };
+}
+
+crate::lut_functions!(lookup_layouts);
+
+impl VerifyingKey {
+    /// Computes the Blake2s digest of the verifying key.
+    pub fn digest(&self) -> Blake2sHash {
+        let data = bincode::serialize(self).expect("Verifying key should be serializable");
+        Blake2sHasher::hash(&data)
+    }
+
+    /// Checks that a proof is consistent with the committed weights of the verifying key.
+    ///
+    /// The proof must carry a Weights component of the committed size and the committed root
+    /// if, and only if, the key has committed weights. None of the committed weights may be
+    /// supplied as a public input, as their reads would then be balanced without opening the tree.
+    pub fn check_weights(
+        &self,
+        claim: Option<&WeightsClaim>,
+        root: Option<Blake2sHash>,
+        public_io: &PublicIo,
+    ) -> Result<(), LuminairError> {
+        let valid = match (&self.weights, claim, root) {
+            (None, None, None) => true,
+            (Some(weights), Some(claim), Some(root)) => {
+                claim.log_size == weights.log_size
+                    && root == weights.root
+                    && public_io
+                        .inputs
+                        .iter()
+                        .all(|input| !weights.node_ids.contains(&input.node_id))
+            }
+            _ => false,
+        };
+
+        if !valid {
+            return Err(LuminairError::WeightsCommitmentMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Rebuilds the public inputs and outputs of a proof from the layout of the verifying key.
+    ///
+    /// Only the values of the public tensors are taken from the proof. The proof must list
+    /// the tensors of the key, each with the node emitting it in the key, and the multiplicities
+    /// of their elements come from the key, each output element being consumed once.
+    pub fn public_io(&self, public_io: &PublicIo) -> Result<PublicIo, LuminairError> {
+        Ok(PublicIo {
+            inputs: rebuild_public_tensors(&self.public_io.inputs, &public_io.inputs, false)?,
+            outputs: rebuild_public_tensors(&self.public_io.outputs, &public_io.outputs, true)?,
+        })
+    }
+
+    /// Mixes the digest of the verifying key into the Fiat-Shamir channel.
+    pub fn mix_into(&self, channel: &mut impl Channel) {
+        mix_digest(self.digest(), channel);
+    }
+
+    // --- Serde Binary ---
+    pub fn to_bincode(&self) -> Result<Vec<u8>, LuminairError> {
+        bincode::serialize(self).map_err(|e| {
+            LuminairError::SerializationError(format!(
+                "Failed to serialize verifying key to bincode: {}",
+                e
+            ))
+        })
+    }
+
+    pub fn from_bincode(data: &[u8]) -> Result<Self, LuminairError> {
+        bincode::deserialize(data).map_err(|e| {
+            LuminairError::SerializationError(format!(
+                "Failed to deserialize verifying key from bincode: {}",
+                e
+            ))
+        })
+    }
+
+    pub fn to_bincode_file<P: AsRef<Path>>(&self, path: P) -> Result<(), LuminairError> {
+        let data = self.to_bincode()?;
+        std::fs::write(path, data).map_err(|e| {
+            LuminairError::SerializationError(format!("Failed to write bincode file: {}", e))
+        })
+    }
+
+    pub fn from_bincode_file<P: AsRef<Path>>(path: P) -> Result<Self, LuminairError> {
+        let data = std::fs::read(path).map_err(|e| {
+            LuminairError::SerializationError(format!("Failed to read bincode file: {}", e))
+        })?;
+        Self::from_bincode(&data)
+    }
+
+    // --- Serde JSON ---
+    pub fn to_json(&self) -> Result<String, LuminairError> {
+        serde_json::to_string_pretty(self).map_err(|e| {
+            LuminairError::SerializationError(format!(
+                "Failed to serialize verifying key to JSON: {}",
+                e
+            ))
+        })
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, LuminairError> {
+        serde_json::from_str(json).map_err(|e| {
+            LuminairError::SerializationError(format!(
+                "Failed to deserialize verifying key from JSON: {}",
+                e
+            ))
+        })
+    }
+
+    pub fn to_json_file<P: AsRef<Path>>(&self, path: P) -> Result<(), LuminairError> {
+        let file = File::create(path).map_err(|e| {
+            LuminairError::SerializationError(format!("Failed to create file: {}", e))
+        })?;
+        let mut writer = BufWriter::new(file);
+
+        serde_json::to_writer_pretty(&mut writer, self).map_err(|e| {
+            LuminairError::SerializationError(format!(
+                "Failed to write verifying key to JSON file: {}",
+                e
+            ))
+        })?;
+
+        writer.flush().map_err(|e| {
+            LuminairError::SerializationError(format!("Failed to flush writer: {}", e))
+        })?;
+
+        Ok(())
+    }
+
+    pub fn from_json_file<P: AsRef<Path>>(path: P) -> Result<Self, LuminairError> {
+        let file = File::open(path).map_err(|e| {
+            LuminairError::SerializationError(format!("Failed to open file: {}", e))
+        })?;
+        let reader = BufReader::new(file);
+
+        serde_json::from_reader(reader).map_err(|e| {
+            LuminairError::SerializationError(format!(
+                "Failed to read verifying key from JSON file: {}",
+                e
+            ))
+        })
+    }
+}
+
+/// Rebuilds public tensors from their layout in the verifying key and their values in a proof.
+///
+/// Keeps the order of the proof, which is the order in which the prover mixed them
+/// into the Fiat-Shamir channel.
+fn rebuild_public_tensors(
+    keys: &[PublicTensorKey],
+    tensors: &[PublicTensor],
+    is_output: bool,
+) -> Result<Vec<PublicTensor>, LuminairError> {
+    let key_ids = keys.iter().map(|k| (k.node_id, k.tensor_id)).sorted();
+    let proof_ids = tensors.iter().map(|t| (t.node_id, t.tensor_id)).sorted();
+    if !key_ids.eq(proof_ids) {
+        return Err(LuminairError::PublicIoMismatch);
+    }
+
+    tensors
+        .iter()
+        .map(|tensor| {
+            let key = keys
+                .iter()
+                .find(|k| k.node_id == tensor.node_id && k.tensor_id == tensor.tensor_id)
+                .unwrap();
+            let multiplicities = if is_output {
+                vec![1; tensor.values.len()]
+            } else if key.multiplicities.len() <= tensor.values.len() {
+                let mut multiplicities = key.multiplicities.clone();
+                multiplicities.resize(tensor.values.len(), 0);
+                multiplicities
+            } else {
+                return Err(LuminairError::PublicIoMismatch);
+            };
+
+            Ok(PublicTensor {
+                node_id: key.node_id,
+                tensor_id: key.tensor_id,
+                values: tensor.values.clone(),
+                multiplicities,
+            })
+        })
+        .collect()
+}
+
+/// Mixes a verifying key digest into the Fiat-Shamir channel.
+///
+/// Used by the prover, which only holds the digest of the key.
+pub fn mix_digest(digest: Blake2sHash, channel: &mut impl Channel) {
+    for chunk in digest.0.chunks_exact(8) {
+        channel.mix_u64(u64::from_le_bytes(chunk.try_into().unwrap()));
+    }
+}
@@ -151,12 +151,14 @@ fn benchmark_add(c: &mut Criterion) {
                     let trace = graph
                         .gen_trace(&mut settings)
                         .expect("Trace generation failed");
-                    let (proof, _) =
-                        prove(trace, settings.clone()).expect("Proof generation failed");
-                    (settings, proof)
+                    let verifying_key = graph
+                        .gen_verifying_key(&settings)
+                        .expect("Verifying key generation failed");
+                    let (proof, _) = prove(trace, settings).expect("Proof generation failed");
+                    (verifying_key, proof)
                 },
-                |(settings, proof)| {
-                    verify(proof, settings).expect("Proof verification failed");
+                |(verifying_key, proof)| {
+                    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
                 },
             )
         });
@@ -228,12 +230,175 @@ fn benchmark_mul(c: &mut Criterion) {
                     let trace = graph
                         .gen_trace(&mut settings)
                         .expect("Trace generation failed");
-                    let (proof, _) =
-                        prove(trace, settings.clone()).expect("Proof generation failed");
-                    (settings, proof)
+                    let verifying_key = graph
+                        .gen_verifying_key(&settings)
+                        .expect("Verifying key generation failed");
+                    let (proof, _) = prove(trace, settings).expect("Proof generation failed");
+                    (verifying_key, proof)
                 },
-                |(settings, proof)| {
-                    verify(proof, settings).expect("Proof verification failed");
+                |(verifying_key, proof)| {
+                    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+                },
+            )
+        });
+    }
+
+    group.finish();
+}
+
+// Benchmark for LessThan operator
+fn benchmark_less_than(c: &mut Criterion) {
+    let mut group = c.benchmark_group("LessThan Operator");
+    group
+        .plot_config(PlotConfiguration::default().summary_scale(criterion::AxisScale::Logarithmic));
+
+    let sizes = [(32, 32)];
+
+    for &size in &sizes {
+        let (rows, cols) = size;
+
+        // Trace generation
+        let params = BenchParams {
+            stage: Stage::TraceGeneration,
+            size,
+        };
+        group.bench_function(params.to_string(), |b| {
+            b.iter_with_setup(
+                || {
+                    let mut graph =
+                        create_binary!(|a, b| a.less_than(b), (rows, cols), (rows, cols), false);
+                    let settings = graph.gen_circuit_settings();
+                    (graph, settings)
+                },
+                |(mut graph, mut settings)| {
+                    let _trace = graph.gen_trace(&mut settings);
+                },
+            )
+        });
+
+        // Proof generation
+        let params = BenchParams {
+            stage: Stage::Proving,
+            size,
+        };
+        group.bench_function(params.to_string(), |b| {
+            b.iter_with_setup(
+                || {
+                    let mut graph =
+                        create_binary!(|a, b| a.less_than(b), (rows, cols), (rows, cols), false);
+                    let mut settings = graph.gen_circuit_settings();
+                    let trace = graph
+                        .gen_trace(&mut settings)
+                        .expect("Trace generation failed");
+                    (settings, trace)
+                },
+                |(settings, trace)| {
+                    let _proof = prove(trace, settings).expect("Proof generation failed");
+                },
+            )
+        });
+
+        // Verification
+        let params = BenchParams {
+            stage: Stage::Verification,
+            size,
+        };
+        group.bench_function(params.to_string(), |b| {
+            b.iter_with_setup(
+                || {
+                    let mut graph =
+                        create_binary!(|a, b| a.less_than(b), (rows, cols), (rows, cols), false);
+                    let mut settings = graph.gen_circuit_settings();
+                    let trace = graph
+                        .gen_trace(&mut settings)
+                        .expect("Trace generation failed");
+                    let verifying_key = graph
+                        .gen_verifying_key(&settings)
+                        .expect("Verifying key generation failed");
+                    let (proof, _) = prove(trace, settings).expect("Proof generation failed");
+                    (verifying_key, proof)
+                },
+                |(verifying_key, proof)| {
+                    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+                },
+            )
+        });
+    }
+
+    group.finish();
+}
+
+// Benchmark for Mod operator
+fn benchmark_mod(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Mod Operator");
+    group
+        .plot_config(PlotConfiguration::default().summary_scale(criterion::AxisScale::Logarithmic));
+
+    let sizes = [(32, 32)];
+
+    for &size in &sizes {
+        let (rows, cols) = size;
+
+        // Trace generation
+        let params = BenchParams {
+            stage: Stage::TraceGeneration,
+            size,
+        };
+        group.bench_function(params.to_string(), |b| {
+            b.iter_with_setup(
+                || {
+                    let mut graph = create_binary!(|a, b| a % b, (rows, cols), (rows, cols), true);
+                    let settings = graph.gen_circuit_settings();
+                    (graph, settings)
+                },
+                |(mut graph, mut settings)| {
+                    let _trace = graph.gen_trace(&mut settings);
+                },
+            )
+        });
+
+        // Proof generation
+        let params = BenchParams {
+            stage: Stage::Proving,
+            size,
+        };
+        group.bench_function(params.to_string(), |b| {
+            b.iter_with_setup(
+                || {
+                    let mut graph = create_binary!(|a, b| a % b, (rows, cols), (rows, cols), true);
+                    let mut settings = graph.gen_circuit_settings();
+                    let trace = graph
+                        .gen_trace(&mut settings)
+                        .expect("Trace generation failed");
+                    (settings, trace)
+                },
+                |(settings, trace)| {
+                    let _proof = prove(trace, settings).expect("Proof generation failed");
+                },
+            )
+        });
+
+        // Verification
+        let params = BenchParams {
+            stage: Stage::Verification,
+            size,
+        };
+        group.bench_function(params.to_string(), |b| {
+            b.iter_with_setup(
+                || {
+                    let mut graph = create_binary!(|a, b| a % b, (rows, cols), (rows, cols), true);
+                    let mut settings = graph.gen_circuit_settings();
+                    let trace = graph
+                        .gen_trace(&mut settings)
+                        .expect("Trace generation failed");
+                    let verifying_key = graph
+                        .gen_verifying_key(&settings)
+                        .expect("Verifying key generation failed");
+                    let (proof, _) = prove(trace, settings).expect("Proof generation failed");
+                    (verifying_key, proof)
+                },
+                |(verifying_key, proof)| {
+                    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
                 },
             )
         });
@@ -305,12 +470,14 @@ fn benchmark_recip(c: &mut Criterion) {
                     let trace = graph
                         .gen_trace(&mut settings)
                         .expect("Trace generation failed");
-                    let (proof, _) =
-                        prove(trace, settings.clone()).expect("Proof generation failed");
-                    (settings, proof)
+                    let verifying_key = graph
+                        .gen_verifying_key(&settings)
+                        .expect("Verifying key generation failed");
+                    let (proof, _) = prove(trace, settings).expect("Proof generation failed");
+                    (verifying_key, proof)
                 },
-                |(settings, proof)| {
-                    verify(proof, settings).expect("Proof verification failed");
+                |(verifying_key, proof)| {
+                    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
                 },
             )
         });
@@ -385,12 +552,14 @@ fn benchmark_sum_reduce(c: &mut Criterion) {
                     let trace = graph
                         .gen_trace(&mut settings)
                         .expect("Trace generation failed");
-                    let (proof, _) =
-                        prove(trace, settings.clone()).expect("Proof generation failed");
-                    (settings, proof)
+                    let verifying_key = graph
+                        .gen_verifying_key(&settings)
+                        .expect("Verifying key generation failed");
+                    let (proof, _) = prove(trace, settings).expect("Proof generation failed");
+                    (verifying_key, proof)
                 },
-                |(settings, proof)| {
-                    verify(proof, settings).expect("Proof verification failed");
+                |(verifying_key, proof)| {
+                    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
                 },
             )
         });
@@ -465,12 +634,14 @@ fn benchmark_max_reduce(c: &mut Criterion) {
                     let trace = graph
                         .gen_trace(&mut settings)
                         .expect("Trace generation failed");
-                    let (proof, _) =
-                        prove(trace, settings.clone()).expect("Proof generation failed");
-                    (settings, proof)
+                    let verifying_key = graph
+                        .gen_verifying_key(&settings)
+                        .expect("Verifying key generation failed");
+                    let (proof, _) = prove(trace, settings).expect("Proof generation failed");
+                    (verifying_key, proof)
                 },
-                |(settings, proof)| {
-                    verify(proof, settings).expect("Proof verification failed");
+                |(verifying_key, proof)| {
+                    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
                 },
             )
         });
@@ -542,12 +713,172 @@ fn benchmark_sin(c: &mut Criterion) {
                     let trace = graph
                         .gen_trace(&mut settings)
                         .expect("Trace generation failed");
-                    let (proof, _) =
-                        prove(trace, settings.clone()).expect("Proof generation failed");
-                    (settings, proof)
+                    let verifying_key = graph
+                        .gen_verifying_key(&settings)
+                        .expect("Verifying key generation failed");
+                    let (proof, _) = prove(trace, settings).expect("Proof generation failed");
+                    (verifying_key, proof)
+                },
+                |(verifying_key, proof)| {
+                    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+                },
+            )
+        });
+    }
+
+    group.finish();
+}
+
+// Benchmark for Exp2 operator
+fn benchmark_exp2(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Exp2 Operator");
+    group
+        .plot_config(PlotConfiguration::default().summary_scale(criterion::AxisScale::Logarithmic));
+
+    let sizes = [(32, 32)];
+
+    for &size in &sizes {
+        let (rows, cols) = size;
+
+        // Trace generation
+        let params = BenchParams {
+            stage: Stage::TraceGeneration,
+            size,
+        };
+        group.bench_function(params.to_string(), |b| {
+            b.iter_with_setup(
+                || {
+                    let mut graph = create_unary!(|a: GraphTensor| a.exp2(), (rows, cols), true);
+                    let settings = graph.gen_circuit_settings();
+                    (graph, settings)
+                },
+                |(mut graph, mut settings)| {
+                    let _trace = graph.gen_trace(&mut settings);
+                },
+            )
+        });
+
+        // Proof generation
+        let params = BenchParams {
+            stage: Stage::Proving,
+            size,
+        };
+        group.bench_function(params.to_string(), |b| {
+            b.iter_with_setup(
+                || {
+                    let mut graph = create_unary!(|a: GraphTensor| a.exp2(), (rows, cols), true);
+                    let mut settings = graph.gen_circuit_settings();
+                    let trace = graph
+                        .gen_trace(&mut settings)
+                        .expect("Trace generation failed");
+                    (settings, trace)
+                },
+                |(settings, trace)| {
+                    let _proof = prove(trace, settings).expect("Proof generation failed");
+                },
+            )
+        });
+
+        // Verification
+        let params = BenchParams {
+            stage: Stage::Verification,
+            size,
+        };
+        group.bench_function(params.to_string(), |b| {
+            b.iter_with_setup(
+                || {
+                    let mut graph = create_unary!(|a: GraphTensor| a.exp2(), (rows, cols), true);
+                    let mut settings = graph.gen_circuit_settings();
+                    let trace = graph
+                        .gen_trace(&mut settings)
+                        .expect("Trace generation failed");
+                    let verifying_key = graph
+                        .gen_verifying_key(&settings)
+                        .expect("Verifying key generation failed");
+                    let (proof, _) = prove(trace, settings).expect("Proof generation failed");
+                    (verifying_key, proof)
+                },
+                |(verifying_key, proof)| {
+                    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+                },
+            )
+        });
+    }
+
+    group.finish();
+}
+
+// Benchmark for Log2 operator
+fn benchmark_log2(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Log2 Operator");
+    group
+        .plot_config(PlotConfiguration::default().summary_scale(criterion::AxisScale::Logarithmic));
+
+    let sizes = [(32, 32)];
+
+    for &size in &sizes {
+        let (rows, cols) = size;
+
+        // Trace generation
+        let params = BenchParams {
+            stage: Stage::TraceGeneration,
+            size,
+        };
+        group.bench_function(params.to_string(), |b| {
+            b.iter_with_setup(
+                || {
+                    let mut graph = create_unary!(|a: GraphTensor| a.log2(), (rows, cols), true);
+                    let settings = graph.gen_circuit_settings();
+                    (graph, settings)
+                },
+                |(mut graph, mut settings)| {
+                    let _trace = graph.gen_trace(&mut settings);
+                },
+            )
+        });
+
+        // Proof generation
+        let params = BenchParams {
+            stage: Stage::Proving,
+            size,
+        };
+        group.bench_function(params.to_string(), |b| {
+            b.iter_with_setup(
+                || {
+                    let mut graph = create_unary!(|a: GraphTensor| a.log2(), (rows, cols), true);
+                    let mut settings = graph.gen_circuit_settings();
+                    let trace = graph
+                        .gen_trace(&mut settings)
+                        .expect("Trace generation failed");
+                    (settings, trace)
+                },
+                |(settings, trace)| {
+                    let _proof = prove(trace, settings).expect("Proof generation failed");
+                },
+            )
+        });
+
+        // Verification
+        let params = BenchParams {
+            stage: Stage::Verification,
+            size,
+        };
+        group.bench_function(params.to_string(), |b| {
+            b.iter_with_setup(
+                || {
+                    let mut graph = create_unary!(|a: GraphTensor| a.log2(), (rows, cols), true);
+                    let mut settings = graph.gen_circuit_settings();
+                    let trace = graph
+                        .gen_trace(&mut settings)
+                        .expect("Trace generation failed");
+                    let verifying_key = graph
+                        .gen_verifying_key(&settings)
+                        .expect("Verifying key generation failed");
+                    let (proof, _) = prove(trace, settings).expect("Proof generation failed");
+                    (verifying_key, proof)
                 },
-                |(settings, proof)| {
-                    verify(proof, settings).expect("Proof verification failed");
+                |(verifying_key, proof)| {
+                    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
                 },
             )
         });
@@ -619,12 +950,14 @@ fn benchmark_sqrt(c: &mut Criterion) {
                     let trace = graph
                         .gen_trace(&mut settings)
                         .expect("Trace generation failed");
-                    let (proof, _) =
-                        prove(trace, settings.clone()).expect("Proof generation failed");
-                    (settings, proof)
+                    let verifying_key = graph
+                        .gen_verifying_key(&settings)
+                        .expect("Verifying key generation failed");
+                    let (proof, _) = prove(trace, settings).expect("Proof generation failed");
+                    (verifying_key, proof)
                 },
-                |(settings, proof)| {
-                    verify(proof, settings).expect("Proof verification failed");
+                |(verifying_key, proof)| {
+                    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
                 },
             )
         });
@@ -637,10 +970,14 @@ criterion_group!(
     benches,
     benchmark_add,
     benchmark_mul,
+    benchmark_less_than,
+    benchmark_mod,
     benchmark_recip,
     benchmark_sum_reduce,
     benchmark_max_reduce,
     benchmark_sin,
+    benchmark_exp2,
+    benchmark_log2,
     benchmark_sqrt
 );
 criterion_main!(benches);
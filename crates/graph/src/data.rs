@@ -1,36 +1,53 @@
-use luminair_air::DEFAULT_FP_SCALE;
+use luminair_air::fixed_point::Fixed;
 use luminal::prelude::*;
 use num_traits::Zero;
-use numerair::Fixed;
 use std::sync::Arc;
 
 /// A wrapper for tensor data using `Fixed` point numbers, suitable for the STWO prover.
 ///
-/// Stores data as an `Arc<Vec<Fixed>>` for efficient sharing.
+/// Stores data as an `Arc<Vec<Fixed>>` for efficient sharing, along with the
+/// fixed-point scale the values are represented at.
 #[derive(Clone, Debug)]
-pub(crate) struct StwoData(pub(crate) Arc<Vec<Fixed<DEFAULT_FP_SCALE>>>);
+pub(crate) struct StwoData {
+    /// The fixed-point values of the tensor.
+    pub(crate) values: Arc<Vec<Fixed>>,
+    /// The fixed-point scale of the values.
+    pub(crate) scale: u32,
+}
 
 impl StwoData {
-    /// Creates a new `StwoData` instance by converting a slice of `f32` values to `Fixed` point.
-    pub(crate) fn from_f32(data: &[f32]) -> Self {
+    /// Creates a new `StwoData` instance from fixed-point values at `scale`.
+    pub(crate) fn new(values: Vec<Fixed>, scale: u32) -> Self {
+        StwoData {
+            values: Arc::new(values),
+            scale,
+        }
+    }
+
+    /// Creates a new `StwoData` instance by converting a slice of `f32` values to `Fixed` point
+    /// at `scale`.
+    pub(crate) fn from_f32(data: &[f32], scale: u32) -> Self {
         let fixed_data = data
             .iter()
-            .map(|&d| Fixed::from_f64(d as f64))
+            .map(|&d| Fixed::from_f64(d as f64, scale))
             .collect::<Vec<_>>();
 
-        StwoData(Arc::new(fixed_data))
+        StwoData::new(fixed_data, scale)
     }
 
     /// Converts the internal `Fixed` point data back to a vector of `f32` values.
     pub(crate) fn to_f32(&self) -> Vec<f32> {
-        self.0.iter().map(|&d| d.to_f64() as f32).collect()
+        self.values
+            .iter()
+            .map(|&d| d.to_f64(self.scale) as f32)
+            .collect()
     }
 
     /// Finds the minimum and maximum `Fixed` point values within the data.
-    pub(crate) fn min_max(&self) -> (Fixed<DEFAULT_FP_SCALE>, Fixed<DEFAULT_FP_SCALE>) {
-        self.0.iter().fold(
+    pub(crate) fn min_max(&self) -> (Fixed, Fixed) {
+        self.values.iter().fold(
             (Fixed::zero(), Fixed::zero()),
-            |(min_val, max_val), &val| match self.0.len() {
+            |(min_val, max_val), &val| match self.values.len() {
                 0 => (Fixed::zero(), Fixed::zero()),
                 _ if min_val.0 == 0 && max_val.0 == 0 => (val, val),
                 _ => (
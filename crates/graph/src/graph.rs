@@ -1,36 +1,72 @@
 use crate::{
+    data::StwoData,
     op::{
-        prim::{CopyFromStwo, CopyToStwo, LuminairConstant},
-        HasProcessTrace,
+        prim::{
+            CopyFromStwo, CopyToStwo, LuminairConstant, LuminairConv2d, LuminairNorm,
+            LuminairRescale,
+        },
+        wrapped_operator, HasProcessTrace, IntoOperator,
+    },
+    settings::{CalibrationSample, CircuitSettingsBuilder},
+    utils::{
+        compute_padded_range_from_srcs, get_source_indices, range_check_interpolation,
+        range_check_softmax,
     },
-    utils::compute_padded_range_from_srcs,
 };
 use itertools::Itertools;
 use luminair_air::{
     components::{
         add::table::{AddColumn, AddTraceTable},
+        conv2d::table::{Conv2dColumn, Conv2dTraceTable},
+        less_than::table::{LessThanColumn, LessThanTraceTable, N_DIFF_BITS},
         lookups::{
-            sin::{table::SinLookupTraceTable, SinLookup},
+            range_check::{limb_bits, table::RangeCheckLookupTraceTable, RangeCheckLookup},
+            unary::{
+                fit_layout,
+                functions::{self, ExpLookup},
+                table::UnaryLookupTraceTable,
+                LutFunction,
+            },
             Lookups,
         },
-        max_reduce::table::{MaxReduceColumn, MaxReduceTraceTable},
+        matmul::table::{MatMulColumn, MatMulTraceTable},
+        max_reduce::table::{
+            MaxReduceColumn, MaxReduceTraceTable, N_DIFF_BITS as N_MAX_REDUCE_DIFF_BITS,
+        },
+        modulo::table::{ModColumn, ModTraceTable, N_QUOTIENT_BITS, N_RANGE_BITS},
         mul::table::{MulColumn, MulTraceTable},
-        recip::table::{RecipColumn, RecipTraceTable},
-        sin::table::{SinColumn, SinTraceTable},
+        norm::table::{quotient_bits, NormColumn, NormTraceTable},
+        recip::table::{out_bits, RecipColumn, RecipTraceTable},
+        relu::table::{ReluColumn, ReluTraceTable},
+        rescale::table::{out_bits as rescale_out_bits, RescaleColumn, RescaleTraceTable},
+        softmax::table::{SoftmaxColumn, SoftmaxTraceTable, N_DIFF_BITS as N_SOFTMAX_DIFF_BITS},
         sqrt::table::{SqrtColumn, SqrtTraceTable},
         sum_reduce::table::{SumReduceColumn, SumReduceTraceTable},
+        unary::table::{UnaryColumn, UnaryTraceTable},
+        weights::{
+            table::{WeightsTraceTable, WeightsTraceTableRow},
+            witness::commit_weights,
+        },
     },
+    fixed_point::Fixed,
     pie::{
-        ExecutionResources, InputInfo, LuminairPie, Metadata, NodeInfo, OpCounter, OutputInfo,
-        TraceTable,
+        ExecutionResources, InputInfo, LuminairPie, LutTraceTable, Metadata, NodeInfo, OpCounter,
+        OutputInfo, TraceTable,
+    },
+    preprocessed::{
+        coalesce_ranges, lookups_to_preprocessed_column, trace_tables_to_preprocessed_column,
+        PreProcessedTrace, Range,
     },
-    preprocessed::{LookupLayout, Range},
-    settings::CircuitSettings,
-    utils::calculate_log_size,
+    public_io::{PublicIo, PublicTensor},
+    settings::{random_weights_salt, CircuitSettings},
+    utils::{bit_length, calculate_log_size},
+    verifying_key::{
+        EdgeKey, LookupLayouts, NodeKey, PublicIoKey, PublicTensorKey, VerifyingKey, WeightsKey,
+    },
+    LuminairClaim, DEFAULT_FP_SCALE, MAX_FP_SCALE,
 };
 use luminair_utils::LuminairError;
 use luminal::{op::*, prelude::*};
-use numerair::Fixed;
 use petgraph::{
     stable_graph::{EdgeIndex, StableGraph},
     visit::EdgeRef,
@@ -38,15 +74,34 @@ use petgraph::{
 };
 use regex::Regex;
 use rustc_hash::FxHashMap;
+use stwo_prover::core::fields::m31::M31;
 
 /// Trait defining the core functionality of a LuminAIR computation graph.
 ///
 /// Provides methods to generate execution traces, retrieve outputs, and handle proof
 /// generation and verification using Stwo.
 pub trait LuminairGraph {
-    /// Infers circuit settings using simulated representative inputs.
+    /// Infers circuit settings using simulated representative inputs, at the default
+    /// fixed-point scale.
     fn gen_circuit_settings(&mut self) -> CircuitSettings;
 
+    /// Infers circuit settings using simulated representative inputs, at the given
+    /// fixed-point scale.
+    fn gen_circuit_settings_with_scale(&mut self, fp_scale: u32) -> CircuitSettings;
+
+    /// Infers circuit settings over several input samples, at the default fixed-point scale,
+    /// so that a single set of settings serves a whole dataset.
+    fn gen_circuit_settings_from_calibration(
+        &mut self,
+        samples: &[CalibrationSample],
+    ) -> CircuitSettings;
+
+    /// Derives the verifying key of the compiled graph.
+    fn gen_verifying_key(
+        &mut self,
+        settings: &CircuitSettings,
+    ) -> Result<VerifyingKey, LuminairError>;
+
     /// Generates an execution trace for the graph's computation.
     fn gen_trace(&mut self, settings: &mut CircuitSettings) -> Result<LuminairPie, LuminairError>;
 
@@ -56,65 +111,42 @@ pub trait LuminairGraph {
 
 /// Implementation of `LuminairGraph` for the `luminal::Graph` struct.
 impl LuminairGraph for Graph {
-    /// Generates circuit settings, primarily by inferring lookup table requirements.
-    ///
-    /// Runs a pass over the graph to identify the range of values used
-    /// by lookup-based operations (like `sin`).
-    /// This information is crucial for constructing the preprocessed trace later.
+    /// Generates circuit settings at the default fixed-point scale.
     fn gen_circuit_settings(&mut self) -> CircuitSettings {
-        // Track the number of views pointing to each tensor so we know when to clear
-        if self.linearized_graph.is_none() {
-            self.toposort();
-        }
-        let mut consumers = self.consumers_map.as_ref().unwrap().clone();
-        let mut dim_stack = Vec::new();
-
-        // Accumulate ranges per non-linear op
-        let mut sin_ranges: Vec<Range> = Vec::new();
-
-        for (node, src_ids) in self.linearized_graph.as_ref().unwrap() {
-            if self.tensors.contains_key(&(*node, 0)) {
-                continue;
-            }
-
-            let mut srcs =
-                get_source_tensors(&self.no_delete, &mut self.tensors, src_ids, &consumers);
-
-            // Substitute in the dyn dims
-            for (_, st) in srcs.iter_mut() {
-                st.resolve_global_dyn_dims_stack(&self.dyn_map, &mut dim_stack);
-            }
-
-            // Range
-            let op = &*self.graph.node_weight(*node).unwrap();
-            if <Box<dyn Operator> as HasProcessTrace<SinColumn, SinTraceTable, SinLookup>>::has_process_trace(op) {
-                sin_ranges.push(compute_padded_range_from_srcs(&srcs));
-            }
-
-            // Execute
-            let tensors = self.graph.node_weight_mut(*node).unwrap().process(srcs);
-            for (i, tensor) in tensors.into_iter().enumerate() {
-                self.tensors.insert((*node, i as u8), tensor);
-            }
+        self.gen_circuit_settings_with_scale(DEFAULT_FP_SCALE)
+    }
 
-            // Bookkeep remaining consumers
-            for (id, ind, _) in src_ids {
-                *consumers.get_mut(&(*id, *ind)).unwrap() -= 1;
-            }
-        }
+    /// Generates circuit settings at the given fixed-point scale, on the data currently set.
+    fn gen_circuit_settings_with_scale(&mut self, fp_scale: u32) -> CircuitSettings {
+        CircuitSettingsBuilder::new().fp_scale(fp_scale).build(self)
+    }
 
-        self.reset();
+    /// Generates circuit settings at the default fixed-point scale, over several input samples.
+    fn gen_circuit_settings_from_calibration(
+        &mut self,
+        samples: &[CalibrationSample],
+    ) -> CircuitSettings {
+        CircuitSettingsBuilder::new().build_from_calibration(self, samples)
+    }
 
-        let sin_lookup = if !sin_ranges.is_empty() {
-            let layout = LookupLayout::new(coalesce_ranges(sin_ranges));
-            Some(SinLookup::new(&layout))
-        } else {
-            None
-        };
+    /// Derives the verifying key of the compiled graph.
+    ///
+    /// Captures the graph topology (nodes with their operation kind, and data edges with
+    /// the shape through which each tensor is read), the layout of the public inputs and
+    /// outputs, the claim of the traces and the root of the preprocessed trace, along with the
+    /// fixed-point scale and the lookup layouts of `settings`.
+    /// The sizes and the structure of the traces only depend on the graph: they are read from
+    /// a trace of the data currently set, which must be covered by `settings`.
+    /// If `settings` lists committed weights, their tensors are loaded and committed,
+    /// and only the root of the commitment is kept in the key.
+    /// It is meant to be called once the graph is compiled and published alongside the model.
+    fn gen_verifying_key(
+        &mut self,
+        settings: &CircuitSettings,
+    ) -> Result<VerifyingKey, LuminairError> {
+        let pie = self.gen_trace(&mut settings.clone())?;
 
-        CircuitSettings {
-            lookups: Lookups { sin: sin_lookup },
-        }
+        Ok(verifying_key(self, settings, &pie.trace_tables))
     }
 
     /// Generates the execution trace (witness) for the computation graph.
@@ -126,6 +158,12 @@ impl LuminairGraph for Graph {
     ///
     /// Returns a `LuminairPie` containing all the trace tables and execution resources.
     fn gen_trace(&mut self, settings: &mut CircuitSettings) -> Result<LuminairPie, LuminairError> {
+        set_fp_scale(self, settings.fp_scale);
+
+        // Start from a clean state, whatever a previous failed run left behind.
+        self.reset();
+        settings.lookups.clear_multiplicities();
+
         // Track the number of views pointing to each tensor so we know when to clear
         if self.linearized_graph.is_none() {
             self.toposort();
@@ -141,11 +179,23 @@ impl LuminairGraph for Graph {
         let mut add_table = AddTraceTable::new();
         let mut mul_table = MulTraceTable::new();
         let mut recip_table = RecipTraceTable::new();
-        let mut sin_table = SinTraceTable::new();
-        let mut sin_lookup_table = SinLookupTraceTable::new();
         let mut sum_reduce_table = SumReduceTraceTable::new();
+        let mut matmul_table = MatMulTraceTable::new();
         let mut max_reduce_table = MaxReduceTraceTable::new();
         let mut sqrt_table = SqrtTraceTable::new();
+        let mut less_than_table = LessThanTraceTable::new();
+        let mut lut_tables = LutTables::default();
+        let mut softmax_table = SoftmaxTraceTable::new();
+        let mut norm_table = NormTraceTable::new();
+        let mut conv2d_table = Conv2dTraceTable::new();
+        let mut relu_table = ReluTraceTable::new();
+        let mut mod_table = ModTraceTable::new();
+        let mut rescale_table = RescaleTraceTable::new();
+        let mut range_check_lookup_table = RangeCheckLookupTraceTable::new();
+
+        // Initializes public inputs and outputs, and committed weights
+        let mut public_io = PublicIo::default();
+        let mut weights = Vec::new();
 
         for (node, src_ids) in self.linearized_graph.as_ref().unwrap() {
             if self.tensors.contains_key(&(*node, 0)) {
@@ -163,86 +213,23 @@ impl LuminairGraph for Graph {
             // Gather input source information
             let input_info: Vec<InputInfo> = src_ids
                 .iter()
-                .map(|(id, _, _)| {
-                    let node_weight = self.node_weight(*id).unwrap();
-
-                    let is_function = node_weight.as_any().is::<Function>();
-                    let is_constant = node_weight.as_any().is::<LuminairConstant>()
-                        || node_weight.as_any().is::<luminal::op::Constant>();
-                    let is_copy_to = node_weight.as_any().is::<CopyToStwo>();
-
-                    // Check if this is a CopyToStwo that wraps a Function node or a Constant
-                    let is_copy_of_initializer = if is_copy_to {
-                        self.get_sources(*id).iter().any(|(src_id, _, _)| {
-                            let src_weight = self.node_weight(*src_id).unwrap();
-                            src_weight.as_any().is::<Function>()
-                                || src_weight.as_any().is::<LuminairConstant>()
-                                || src_weight.as_any().is::<luminal::op::Constant>()
-                        })
-                    } else {
-                        false
-                    };
-
-                    InputInfo {
-                        is_initializer: is_function || is_constant || is_copy_of_initializer,
-                        id: id.index() as u32,
-                    }
+                .map(|(id, _, _)| InputInfo {
+                    is_initializer: is_initializer(self, *id),
+                    id: id.index() as u32,
                 })
                 .collect();
 
             // Determine output status
             let is_direct_output = self.to_retrieve.contains_key(&node);
-            let is_output_via_copy = self
-                .graph
-                .edges_directed(*node, petgraph::Direction::Outgoing)
-                .any(|e| {
-                    let target = e.target();
-                    self.to_retrieve.contains_key(&target)
-                        && self
-                            .node_weight(target)
-                            .unwrap()
-                            .as_any()
-                            .is::<CopyFromStwo>()
-                });
-
-            // Calculate expansion-adjusted consumer count
-            let base_consumers = *consumers.get(&(*node, 0)).unwrap_or(&0);
-            let mut expansion_adjusted_consumers = 0u32;
-
-            if base_consumers > 0 {
-                // Iterate through each consumer edge to calculate expansion factors
-                for edge in self
-                    .graph
-                    .edges_directed(*node, petgraph::Direction::Outgoing)
-                {
-                    if let Some((_, _, shape)) = edge.weight().as_data() {
-                        // Calculate expansion factor for this consumer based on fake dimensions
-                        let expansion_factor: u32 = (0..shape.len())
-                            .map(|i| {
-                                let dim_index = shape.indexes[i];
-                                if shape.fake[dim_index] {
-                                    // This dimension is fake (expanded), so count its size
-                                    shape.dims[dim_index].to_usize().unwrap_or(1) as u32
-                                } else {
-                                    // This dimension is real, contributes factor of 1
-                                    1
-                                }
-                            })
-                            .product();
-
-                        expansion_adjusted_consumers += expansion_factor;
-                    }
-                }
-            } else {
-                expansion_adjusted_consumers = base_consumers as u32;
-            }
+            let output_copy = output_copy(self, *node);
+            let is_output_via_copy = output_copy.is_some();
 
             let node_info = NodeInfo {
                 inputs: input_info,
                 output: OutputInfo {
                     is_final_output: is_direct_output || is_output_via_copy,
                 },
-                num_consumers: expansion_adjusted_consumers,
+                consumer_reads: consumer_reads(self, *node),
                 id: node.index() as u32,
             };
 
@@ -261,7 +248,7 @@ impl LuminairGraph for Graph {
                         op_counter.add += 1;
                         <Box<dyn Operator> as HasProcessTrace<AddColumn, AddTraceTable, ()>>::call_process_trace(
                         node_op, srcs, &mut add_table, &node_info, &mut ()
-                    ).unwrap()
+                    ).unwrap()?
                     }
                     _
                         if <Box<dyn Operator> as HasProcessTrace<
@@ -271,42 +258,40 @@ impl LuminairGraph for Graph {
                         >>::has_process_trace(node_op) =>
                     {
                         op_counter.mul += 1;
-                        <Box<dyn Operator> as HasProcessTrace<MulColumn, MulTraceTable, ()>>::call_process_trace(
-                        node_op, srcs, &mut mul_table, &node_info, &mut ()
-                    ).unwrap()
+                        <Box<dyn Operator> as HasProcessTrace<
+                            MulColumn,
+                            MulTraceTable,
+                            (),
+                        >>::call_process_trace(
+                            node_op,
+                            srcs,
+                            &mut mul_table,
+                            &node_info,
+                            &mut (),
+                        )
+                        .unwrap()?
                     }
                     _ if <Box<dyn Operator> as HasProcessTrace<
                         RecipColumn,
                         RecipTraceTable,
-                        (),
+                        RangeCheckLookup,
                     >>::has_process_trace(node_op) =>
                     {
                         op_counter.recip += 1;
-                        <Box<dyn Operator> as HasProcessTrace<RecipColumn, RecipTraceTable, ()>>::call_process_trace(
-                        node_op, srcs, &mut recip_table, &node_info, &mut ()
-                    ).unwrap()
-                    }
-                    _ if <Box<dyn Operator> as HasProcessTrace<
-                        SinColumn,
-                        SinTraceTable,
-                        SinLookup,
-                    >>::has_process_trace(node_op) =>
-                    {
-                        op_counter.sin += 1;
-                        match settings.lookups.sin.as_mut() {
+                        match settings.lookups.range_check.as_mut() {
                             Some(lookup) => <Box<dyn Operator> as HasProcessTrace<
-                                SinColumn,
-                                SinTraceTable,
-                                SinLookup,
+                                RecipColumn,
+                                RecipTraceTable,
+                                RangeCheckLookup,
                             >>::call_process_trace(
                                 node_op,
                                 srcs,
-                                &mut sin_table,
+                                &mut recip_table,
                                 &node_info,
                                 lookup,
                             )
-                            .unwrap(),
-                            None => unreachable!("Sin lookup table must be initialised"),
+                            .unwrap()?,
+                            None => unreachable!("Range check lookup table must be initialised"),
                         }
                     }
                     _ if <Box<dyn Operator> as HasProcessTrace<
@@ -327,42 +312,291 @@ impl LuminairGraph for Graph {
                             &node_info,
                             &mut (),
                         )
-                        .unwrap()
+                        .unwrap()?
                     }
                     _ if <Box<dyn Operator> as HasProcessTrace<
-                        MaxReduceColumn,
-                        MaxReduceTraceTable,
+                        MatMulColumn,
+                        MatMulTraceTable,
                         (),
                     >>::has_process_trace(node_op) =>
                     {
-                        op_counter.max_reduce += 1;
+                        op_counter.matmul += 1;
                         <Box<dyn Operator> as HasProcessTrace<
-                            MaxReduceColumn,
-                            MaxReduceTraceTable,
+                            MatMulColumn,
+                            MatMulTraceTable,
                             (),
                         >>::call_process_trace(
                             node_op,
                             srcs,
-                            &mut max_reduce_table,
+                            &mut matmul_table,
                             &node_info,
                             &mut (),
                         )
-                        .unwrap()
+                        .unwrap()?
+                    }
+                    _ if <Box<dyn Operator> as HasProcessTrace<
+                        MaxReduceColumn,
+                        MaxReduceTraceTable,
+                        RangeCheckLookup,
+                    >>::has_process_trace(node_op) =>
+                    {
+                        op_counter.max_reduce += 1;
+                        match settings.lookups.range_check.as_mut() {
+                            Some(lookup) => <Box<dyn Operator> as HasProcessTrace<
+                                MaxReduceColumn,
+                                MaxReduceTraceTable,
+                                RangeCheckLookup,
+                            >>::call_process_trace(
+                                node_op,
+                                srcs,
+                                &mut max_reduce_table,
+                                &node_info,
+                                lookup,
+                            )
+                            .unwrap()?,
+                            None => unreachable!("Range check lookup table must be initialised"),
+                        }
                     }
                     _ if <Box<dyn Operator> as HasProcessTrace<
                         SqrtColumn,
                         SqrtTraceTable,
-                        (),
+                        RangeCheckLookup,
                     >>::has_process_trace(node_op) =>
                     {
                         op_counter.sqrt += 1;
-                        <Box<dyn Operator> as HasProcessTrace<SqrtColumn, SqrtTraceTable, ()>>::call_process_trace(
-                        node_op, srcs, &mut sqrt_table, &node_info, &mut ()
-                    ).unwrap()
+                        match settings.lookups.range_check.as_mut() {
+                            Some(lookup) => <Box<dyn Operator> as HasProcessTrace<
+                                SqrtColumn,
+                                SqrtTraceTable,
+                                RangeCheckLookup,
+                            >>::call_process_trace(
+                                node_op,
+                                srcs,
+                                &mut sqrt_table,
+                                &node_info,
+                                lookup,
+                            )
+                            .unwrap()?,
+                            None => unreachable!("Range check lookup table must be initialised"),
+                        }
+                    }
+                    _ if <Box<dyn Operator> as HasProcessTrace<
+                        LessThanColumn,
+                        LessThanTraceTable,
+                        RangeCheckLookup,
+                    >>::has_process_trace(node_op) =>
+                    {
+                        op_counter.less_than += 1;
+                        match settings.lookups.range_check.as_mut() {
+                            Some(lookup) => <Box<dyn Operator> as HasProcessTrace<
+                                LessThanColumn,
+                                LessThanTraceTable,
+                                RangeCheckLookup,
+                            >>::call_process_trace(
+                                node_op,
+                                srcs,
+                                &mut less_than_table,
+                                &node_info,
+                                lookup,
+                            )
+                            .unwrap()?,
+                            None => unreachable!("Range check lookup table must be initialised"),
+                        }
+                    }
+                    _ if node_op.is_lut_function() => node_op.call_lut_process_trace(
+                        srcs,
+                        &mut lut_tables,
+                        &node_info,
+                        &mut settings.lookups,
+                        &mut op_counter,
+                    )?,
+                    _ if <Box<dyn Operator> as HasProcessTrace<
+                        SoftmaxColumn,
+                        SoftmaxTraceTable,
+                        ExpLookup,
+                    >>::has_process_trace(node_op) =>
+                    {
+                        op_counter.softmax += 1;
+                        match settings.lookups.exp.as_mut() {
+                            Some(lookup) => <Box<dyn Operator> as HasProcessTrace<
+                                SoftmaxColumn,
+                                SoftmaxTraceTable,
+                                ExpLookup,
+                            >>::call_process_trace(
+                                node_op,
+                                srcs,
+                                &mut softmax_table,
+                                &node_info,
+                                lookup,
+                            )
+                            .unwrap()?,
+                            None => unreachable!("Exp lookup table must be initialised"),
+                        }
+                    }
+                    _ if <Box<dyn Operator> as HasProcessTrace<
+                        NormColumn,
+                        NormTraceTable,
+                        RangeCheckLookup,
+                    >>::has_process_trace(node_op) =>
+                    {
+                        op_counter.norm += 1;
+                        match settings.lookups.range_check.as_mut() {
+                            Some(lookup) => <Box<dyn Operator> as HasProcessTrace<
+                                NormColumn,
+                                NormTraceTable,
+                                RangeCheckLookup,
+                            >>::call_process_trace(
+                                node_op,
+                                srcs,
+                                &mut norm_table,
+                                &node_info,
+                                lookup,
+                            )
+                            .unwrap()?,
+                            None => unreachable!("Range check lookup table must be initialised"),
+                        }
+                    }
+                    _ if <Box<dyn Operator> as HasProcessTrace<
+                        Conv2dColumn,
+                        Conv2dTraceTable,
+                        (),
+                    >>::has_process_trace(node_op) =>
+                    {
+                        op_counter.conv2d += 1;
+                        <Box<dyn Operator> as HasProcessTrace<
+                            Conv2dColumn,
+                            Conv2dTraceTable,
+                            (),
+                        >>::call_process_trace(
+                            node_op,
+                            srcs,
+                            &mut conv2d_table,
+                            &node_info,
+                            &mut (),
+                        )
+                        .unwrap()?
+                    }
+                    _ if <Box<dyn Operator> as HasProcessTrace<
+                        ReluColumn,
+                        ReluTraceTable,
+                        RangeCheckLookup,
+                    >>::has_process_trace(node_op) =>
+                    {
+                        op_counter.relu += 1;
+                        match settings.lookups.range_check.as_mut() {
+                            Some(lookup) => <Box<dyn Operator> as HasProcessTrace<
+                                ReluColumn,
+                                ReluTraceTable,
+                                RangeCheckLookup,
+                            >>::call_process_trace(
+                                node_op,
+                                srcs,
+                                &mut relu_table,
+                                &node_info,
+                                lookup,
+                            )
+                            .unwrap()?,
+                            None => unreachable!("Range check lookup table must be initialised"),
+                        }
+                    }
+                    _ if <Box<dyn Operator> as HasProcessTrace<
+                        ModColumn,
+                        ModTraceTable,
+                        RangeCheckLookup,
+                    >>::has_process_trace(node_op) =>
+                    {
+                        op_counter.modulo += 1;
+                        match settings.lookups.range_check.as_mut() {
+                            Some(lookup) => <Box<dyn Operator> as HasProcessTrace<
+                                ModColumn,
+                                ModTraceTable,
+                                RangeCheckLookup,
+                            >>::call_process_trace(
+                                node_op,
+                                srcs,
+                                &mut mod_table,
+                                &node_info,
+                                lookup,
+                            )
+                            .unwrap()?,
+                            None => unreachable!("Range check lookup table must be initialised"),
+                        }
+                    }
+                    _ if <Box<dyn Operator> as HasProcessTrace<
+                        RescaleColumn,
+                        RescaleTraceTable,
+                        RangeCheckLookup,
+                    >>::has_process_trace(node_op) =>
+                    {
+                        op_counter.rescale += 1;
+                        match settings.lookups.range_check.as_mut() {
+                            Some(lookup) => <Box<dyn Operator> as HasProcessTrace<
+                                RescaleColumn,
+                                RescaleTraceTable,
+                                RangeCheckLookup,
+                            >>::call_process_trace(
+                                node_op,
+                                srcs,
+                                &mut rescale_table,
+                                &node_info,
+                                lookup,
+                            )
+                            .unwrap()?,
+                            None => unreachable!("Range check lookup table must be initialised"),
+                        }
                     }
                     _ => node_op.process(srcs),
                 };
 
+            // Record the public inputs and outputs. Only values handed over to the trace
+            // (i.e. `StwoData`) enter the node relation.
+            if let Some(data) = tensors.first().and_then(|t| t.downcast_ref::<StwoData>()) {
+                // Values outside the signed M31 range would silently wrap around in the trace.
+                // This includes products, above the circuit scale: a Rescale only proves the
+                // rounding of its input within that range.
+                if let Some((idx, value)) = data.values.iter().find_position(|v| !v.fits_m31()) {
+                    return Err(LuminairError::FixedPointOverflow {
+                        node_id: node_info.id,
+                        idx,
+                        value: value.to_f64(data.scale),
+                    });
+                }
+                let values = data.values.iter().map(|v| v.to_m31()).collect::<Vec<_>>();
+
+                if is_initializer(self, *node) {
+                    // Inputs copied to the trace are identified by their source `Function` node.
+                    let tensor_id = if self.node_weight(*node).unwrap().as_any().is::<CopyToStwo>() {
+                        self.get_sources(*node)[0].0
+                    } else {
+                        *node
+                    };
+                    let mut multiplicities = node_info.consumer_reads.clone();
+                    multiplicities.resize(values.len(), 0);
+
+                    // Committed weights are opened by the Weights component instead.
+                    if settings
+                        .committed_weights
+                        .contains(&(tensor_id.index() as u32))
+                    {
+                        weights.push((node.index() as u32, multiplicities, values));
+                    } else {
+                        public_io.inputs.push(PublicTensor {
+                            node_id: node.index() as u32,
+                            tensor_id: tensor_id.index() as u32,
+                            multiplicities,
+                            values,
+                        });
+                    }
+                } else if node_info.output.is_final_output {
+                    public_io.outputs.push(PublicTensor {
+                        node_id: node.index() as u32,
+                        tensor_id: output_copy.unwrap_or(*node).index() as u32,
+                        multiplicities: vec![1; values.len()],
+                        values,
+                    });
+                }
+            }
+
             // Store output tensors
             for (i, tensor) in tensors.into_iter().enumerate() {
                 self.tensors.insert((*node, i as u8), tensor);
@@ -376,10 +610,32 @@ impl LuminairGraph for Graph {
 
         self.reset();
 
+        // Interpolated lookups range-check the rows of their ops, once all are generated.
+        lut_tables.range_check_interpolation(&mut settings.lookups)?;
+        if let Some(range_check_lookup) = settings.lookups.range_check.as_mut() {
+            if let Some(lookup) = settings.lookups.exp.as_ref() {
+                range_check_softmax(&softmax_table, lookup, range_check_lookup)?;
+            }
+        }
+
         // Convert tables to traces - determine max log size while building
         let mut max_log_size = 0;
         let mut trace_tables = Vec::new();
 
+        // The shared range check is only needed if an op sent values to it.
+        let lookups = &settings.lookups;
+        let interpolates = lut_tables.interpolates(lookups);
+        let needs_range_check = !rescale_table.table.is_empty()
+            || !recip_table.table.is_empty()
+            || !sqrt_table.table.is_empty()
+            || !softmax_table.table.is_empty()
+            || !norm_table.table.is_empty()
+            || !relu_table.table.is_empty()
+            || !less_than_table.table.is_empty()
+            || !mod_table.table.is_empty()
+            || !max_reduce_table.table.is_empty()
+            || interpolates;
+
         if !add_table.table.is_empty() {
             let log_size = calculate_log_size(add_table.table.len());
             max_log_size = max_log_size.max(log_size);
@@ -395,22 +651,16 @@ impl LuminairGraph for Graph {
             max_log_size = max_log_size.max(log_size);
             trace_tables.push(TraceTable::from_recip(recip_table));
         }
-        if !sin_table.table.is_empty() {
-            let log_size = calculate_log_size(sin_table.table.len());
-            max_log_size = max_log_size.max(log_size);
-            trace_tables.push(TraceTable::from_sin(sin_table));
-
-            if let Some(lookup) = settings.lookups.sin.as_ref() {
-                lookup.add_multiplicities_to_table(&mut sin_lookup_table);
-                max_log_size = max_log_size.max(lookup.layout.log_size);
-                trace_tables.push(TraceTable::from_sin_lookup(sin_lookup_table))
-            } // TODO (@raphaelDkhn): though error if LUT not present.
-        }
         if !sum_reduce_table.table.is_empty() {
             let log_size = calculate_log_size(sum_reduce_table.table.len());
             max_log_size = max_log_size.max(log_size);
             trace_tables.push(TraceTable::from_sum_reduce(sum_reduce_table));
         }
+        if !matmul_table.table.is_empty() {
+            let log_size = calculate_log_size(matmul_table.table.len());
+            max_log_size = max_log_size.max(log_size);
+            trace_tables.push(TraceTable::from_matmul(matmul_table));
+        }
         if !max_reduce_table.table.is_empty() {
             let log_size = calculate_log_size(max_reduce_table.table.len());
             max_log_size = max_log_size.max(log_size);
@@ -421,9 +671,65 @@ impl LuminairGraph for Graph {
             max_log_size = max_log_size.max(log_size);
             trace_tables.push(TraceTable::from_sqrt(sqrt_table));
         }
+        if !less_than_table.table.is_empty() {
+            let log_size = calculate_log_size(less_than_table.table.len());
+            max_log_size = max_log_size.max(log_size);
+            trace_tables.push(TraceTable::from_less_than(less_than_table));
+        }
+        let log_size = lut_tables.push_trace_tables(lookups, &mut trace_tables);
+        max_log_size = max_log_size.max(log_size);
+        if !softmax_table.table.is_empty() {
+            let log_size = calculate_log_size(softmax_table.table.len());
+            max_log_size = max_log_size.max(log_size);
+            trace_tables.push(TraceTable::from_softmax(softmax_table));
+        }
+        if !norm_table.table.is_empty() {
+            let log_size = calculate_log_size(norm_table.table.len());
+            max_log_size = max_log_size.max(log_size);
+            trace_tables.push(TraceTable::from_norm(norm_table));
+        }
+        if !conv2d_table.table.is_empty() {
+            let log_size = calculate_log_size(conv2d_table.table.len());
+            max_log_size = max_log_size.max(log_size);
+            trace_tables.push(TraceTable::from_conv2d(conv2d_table));
+        }
+        if !relu_table.table.is_empty() {
+            let log_size = calculate_log_size(relu_table.table.len());
+            max_log_size = max_log_size.max(log_size);
+            trace_tables.push(TraceTable::from_relu(relu_table));
+        }
+        if !mod_table.table.is_empty() {
+            let log_size = calculate_log_size(mod_table.table.len());
+            max_log_size = max_log_size.max(log_size);
+            trace_tables.push(TraceTable::from_mod(mod_table));
+        }
+        if !rescale_table.table.is_empty() {
+            let log_size = calculate_log_size(rescale_table.table.len());
+            max_log_size = max_log_size.max(log_size);
+            trace_tables.push(TraceTable::from_rescale(rescale_table));
+        }
+        if needs_range_check {
+            if let Some(lookup) = settings.lookups.range_check.as_ref() {
+                lookup.add_multiplicities_to_table(&mut range_check_lookup_table);
+                max_log_size = max_log_size.max(lookup.layout.log_size);
+                trace_tables.push(TraceTable::from_range_check_lookup(
+                    range_check_lookup_table,
+                ))
+            }
+        }
+        if !weights.is_empty() {
+            let table = weights_table(weights, &settings.weights_salt);
+            let log_size = calculate_log_size(table.table.len());
+            max_log_size = max_log_size.max(log_size);
+            trace_tables.push(TraceTable::from_weights(table));
+        }
+
+        let verifying_key_digest = verifying_key(self, settings, &trace_tables).digest();
 
         Ok(LuminairPie {
             trace_tables,
+            public_io,
+            verifying_key_digest,
             metadata: Metadata {
                 execution_resources: ExecutionResources {
                     op_counter,
@@ -510,34 +816,685 @@ impl LuminairGraph for Graph {
     }
 }
 
-/// Merges overlapping or adjacent ranges into a minimal set of disjoint ranges.
+/// Infers circuit settings, primarily lookup table requirements.
+///
+/// Runs a pass over the graph at the given fixed-point scale for each calibration sample
+/// (or a single pass on the data currently set, without samples) to identify the range
+/// of values used by lookup-based operations (like `sin`), and the number of bits needed
+/// to range-check the fixed-point remainders of Rescale, Recip, Sqrt, Softmax and Norm,
+/// and the input magnitudes of Relu.
+/// Each observed range is widened by `range_margin` of its span, and the ranges of all passes
+/// are merged, so that the settings serve every sample.
+/// With an `interpolation_error`, the function lookup tables are interpolated within it,
+/// see `fit_layout`.
+/// This information is crucial for constructing the preprocessed trace later.
 ///
-/// Used to consolidate the input ranges identified for lookup operations during
-/// the `gen_circuit_settings` phase, optimizing the lookup table structure.
-fn coalesce_ranges(mut ranges: Vec<Range>) -> Vec<Range> {
-    if ranges.is_empty() {
-        return Vec::new();
+/// Panics if `fp_scale` exceeds `MAX_FP_SCALE`.
+pub(crate) fn infer_circuit_settings(
+    graph: &mut Graph,
+    samples: &[CalibrationSample],
+    fp_scale: u32,
+    range_margin: f64,
+    interpolation_error: Option<f64>,
+) -> CircuitSettings {
+    assert!(
+        fp_scale <= MAX_FP_SCALE,
+        "Fixed-point scale {fp_scale} exceeds the maximum of {MAX_FP_SCALE}"
+    );
+    set_fp_scale(graph, fp_scale);
+
+    // Track the number of views pointing to each tensor so we know when to clear
+    if graph.linearized_graph.is_none() {
+        graph.toposort();
     }
+    let mut dim_stack = Vec::new();
 
-    // Sort by lower bound
-    ranges.sort_unstable_by_key(|r| r.0 .0);
+    // Accumulate ranges per non-linear op
+    let mut lut_ranges = LutRanges::default();
 
-    // Use the first element as the starting point
-    let mut result = Vec::with_capacity(ranges.len());
-    let mut current_range = ranges[0].clone();
+    // Bits needed by the shared range check, if any op requires it
+    let mut range_check_bits: Option<u32> = None;
 
-    // Merge overlapping or adjacent ranges
-    for range in ranges.into_iter().skip(1) {
-        if range.0 .0 <= current_range.1 .0 + 1 {
-            // Merge ranges if they overlap or are adjacent
-            current_range.1 = Fixed(current_range.1 .0.max(range.1 .0));
-        } else {
-            // No overlap, push the current range and start a new one
-            result.push(current_range);
-            current_range = range;
+    // Without calibration samples, a single pass runs on the data currently set.
+    let current_data = [CalibrationSample::new()];
+    let samples = if samples.is_empty() {
+        &current_data[..]
+    } else {
+        samples
+    };
+
+    for sample in samples {
+        // The sample data takes the place of the input tensors for this pass.
+        for (id, data) in sample {
+            graph.tensors.insert((*id, 0), Tensor::new(data.clone()));
+        }
+        let mut consumers = graph.consumers_map.as_ref().unwrap().clone();
+
+        for (node, src_ids) in graph.linearized_graph.as_ref().unwrap() {
+            if graph.tensors.contains_key(&(*node, 0)) {
+                continue;
+            }
+
+            let mut srcs =
+                get_source_tensors(&graph.no_delete, &mut graph.tensors, src_ids, &consumers);
+
+            // Substitute in the dyn dims
+            for (_, st) in srcs.iter_mut() {
+                st.resolve_global_dyn_dims_stack(&graph.dyn_map, &mut dim_stack);
+            }
+
+            // Range
+            let op = &*graph.graph.node_weight(*node).unwrap();
+            if op.is_lut_function() {
+                op.record_lut_range(
+                    compute_padded_range_from_srcs(&srcs, range_margin),
+                    &mut lut_ranges,
+                );
+            }
+            if <Box<dyn Operator> as HasProcessTrace<SoftmaxColumn, SoftmaxTraceTable, ExpLookup>>::has_process_trace(op) {
+                // Exp is looked up at x - max, which lies in [min - max, 0].
+                let range = compute_padded_range_from_srcs(&srcs, range_margin);
+                lut_ranges
+                    .exp
+                    .push(Range(Fixed(range.0 .0 - range.1 .0), Fixed(0)));
+                // Reciprocal and output lie in [0, 2^SCALE], and the reciprocal remainder in
+                // [0, total), with the total of n exponentials at most n * 2^SCALE. The
+                // difference to the max is split in two limbs of at most ceil(N_DIFF_BITS / 2) bits.
+                let n = *srcs[0].1.shape_usize().last().unwrap() as i64;
+                let (diff_lo_bits, _) = limb_bits(N_SOFTMAX_DIFF_BITS);
+                let bits = range_check_bits
+                    .unwrap_or(0)
+                    .max(fp_scale + 1)
+                    .max(bit_length(n << fp_scale))
+                    .max(diff_lo_bits);
+                range_check_bits = Some(bits);
+            }
+            if <Box<dyn Operator> as HasProcessTrace<RescaleColumn, RescaleTraceTable, RangeCheckLookup>>::has_process_trace(op) {
+                // Rescale remainder and its gap lie in [0, 2^SCALE), and the output magnitude
+                // is split in two limbs of at most ceil(out_bits / 2) bits.
+                let (out_lo_bits, _) = limb_bits(rescale_out_bits(fp_scale));
+                let bits = range_check_bits
+                    .unwrap_or(0)
+                    .max(fp_scale)
+                    .max(out_lo_bits);
+                range_check_bits = Some(bits);
+            }
+            if <Box<dyn Operator> as HasProcessTrace<RecipColumn, RecipTraceTable, RangeCheckLookup>>::has_process_trace(op) {
+                // Recip remainder and its gap to |x| lie in [0, |x|), and the output magnitude
+                // is split in two limbs of at most ceil(out_bits / 2) bits.
+                let range = compute_padded_range_from_srcs(&srcs, range_margin);
+                let max_abs = range.0 .0.abs().max(range.1 .0.abs());
+                let (out_lo_bits, _) = limb_bits(out_bits(fp_scale));
+                let bits = range_check_bits
+                    .unwrap_or(0)
+                    .max(bit_length(max_abs))
+                    .max(out_lo_bits);
+                range_check_bits = Some(bits);
+            }
+            if <Box<dyn Operator> as HasProcessTrace<SqrtColumn, SqrtTraceTable, RangeCheckLookup>>::has_process_trace(op) {
+                // Sqrt remainder, its gap to 2 * out and the root lie in [0, 2 * out],
+                // with out = sqrt(x * SCALE).
+                let range = compute_padded_range_from_srcs(&srcs, range_margin);
+                let max_out = ((range.1 .0.max(0) as f64) * (1u64 << fp_scale) as f64)
+                    .sqrt()
+                    .ceil() as i64;
+                let bits = range_check_bits.unwrap_or(0).max(bit_length(2 * max_out + 1));
+                range_check_bits = Some(bits);
+            }
+            if <Box<dyn Operator> as HasProcessTrace<NormColumn, NormTraceTable, RangeCheckLookup>>::has_process_trace(op) {
+                // Norm remainders lie in [0, 2^SCALE) for the products, in [0, n) and
+                // [0, n * 2^SCALE) for the mean and variance, and in [0, 2 * std] for
+                // std = sqrt((var + eps) * SCALE), at most twice the input magnitude plus sqrt(eps).
+                // The magnitudes of the quotients are split in two limbs each.
+                let norm =
+                    wrapped_operator::<LuminairNorm, NormColumn, NormTraceTable, RangeCheckLookup>(
+                        &**op,
+                    );
+                let eps = norm.unwrap().eps as f64;
+                let range = compute_padded_range_from_srcs(&srcs, range_margin);
+                let max_abs = range.0 .0.abs().max(range.1 .0.abs());
+                let max_std =
+                    2 * max_abs + (eps * (1u64 << (2 * fp_scale)) as f64).sqrt().ceil() as i64;
+                let n = *srcs[0].1.shape_usize().last().unwrap() as i64;
+                let quotient_lo_bits = quotient_bits(fp_scale)
+                    .into_iter()
+                    .map(|bits| limb_bits(bits).0)
+                    .max()
+                    .unwrap();
+                let bits = range_check_bits
+                    .unwrap_or(0)
+                    .max(fp_scale)
+                    .max(bit_length(n << fp_scale))
+                    .max(bit_length(2 * max_std + 1))
+                    .max(quotient_lo_bits);
+                range_check_bits = Some(bits);
+            }
+            if <Box<dyn Operator> as HasProcessTrace<LessThanColumn, LessThanTraceTable, RangeCheckLookup>>::has_process_trace(op) {
+                // LessThan difference is split in two limbs of at most ceil(N_DIFF_BITS / 2) bits.
+                let (lo_bits, _) = limb_bits(N_DIFF_BITS);
+                let bits = range_check_bits.unwrap_or(0).max(lo_bits);
+                range_check_bits = Some(bits);
+            }
+            if <Box<dyn Operator> as HasProcessTrace<MaxReduceColumn, MaxReduceTraceTable, RangeCheckLookup>>::has_process_trace(op) {
+                // MaxReduce difference is split in two limbs of at most ceil(N_DIFF_BITS / 2) bits.
+                let (lo_bits, _) = limb_bits(N_MAX_REDUCE_DIFF_BITS);
+                let bits = range_check_bits.unwrap_or(0).max(lo_bits);
+                range_check_bits = Some(bits);
+            }
+            if <Box<dyn Operator> as HasProcessTrace<ModColumn, ModTraceTable, RangeCheckLookup>>::has_process_trace(op) {
+                // Mod remainder, slack and quotient magnitude are split in two limbs each.
+                let (rem_lo_bits, _) = limb_bits(N_RANGE_BITS);
+                let (quotient_lo_bits, _) = limb_bits(N_QUOTIENT_BITS);
+                let bits = range_check_bits
+                    .unwrap_or(0)
+                    .max(rem_lo_bits)
+                    .max(quotient_lo_bits);
+                range_check_bits = Some(bits);
+            }
+            if <Box<dyn Operator> as HasProcessTrace<ReluColumn, ReluTraceTable, RangeCheckLookup>>::has_process_trace(op) {
+                // Relu magnitude lies in [0, |x|], x for non-negative inputs and -x - 1 otherwise.
+                let range = compute_padded_range_from_srcs(&srcs, range_margin);
+                let max_abs = range.0 .0.abs().max(range.1 .0.abs());
+                let bits = range_check_bits.unwrap_or(0).max(bit_length(max_abs));
+                range_check_bits = Some(bits);
+            }
+
+            // Execute
+            let tensors = graph.graph.node_weight_mut(*node).unwrap().process(srcs);
+            for (i, tensor) in tensors.into_iter().enumerate() {
+                graph.tensors.insert((*node, i as u8), tensor);
+            }
+
+            // Bookkeep remaining consumers
+            for (id, ind, _) in src_ids {
+                *consumers.get_mut(&(*id, *ind)).unwrap() -= 1;
+            }
         }
+
+        graph.reset();
+        for (id, _) in sample {
+            graph.tensors.remove(&(*id, 0));
+        }
+    }
+
+    let mut lookups = lut_ranges.into_lookups(fp_scale, interpolation_error);
+
+    // Interpolated lookups range-check their offsets in [0, 2^step_bits), their remainders
+    // in [0, 2^SCALE), and their increments shifted into [0, 2^(delta_bits + 1)).
+    let interpolations = lookups
+        .function_layouts()
+        .into_iter()
+        .filter_map(|layout| layout.interpolation);
+    for interpolation in interpolations {
+        let bits = range_check_bits
+            .unwrap_or(0)
+            .max(interpolation.step_bits)
+            .max(fp_scale)
+            .max(interpolation.delta_bits + 1);
+        range_check_bits = Some(bits);
     }
 
-    result.push(current_range);
-    result
+    lookups.range_check = range_check_bits.map(RangeCheckLookup::new);
+
+    CircuitSettings {
+        lookups,
+        fp_scale,
+        committed_weights: Vec::new(),
+        weights_salt: random_weights_salt(),
+        interpolation_error,
+    }
 }
+
+/// Builds the verifying key of the compiled graph from the trace tables of one of its executions.
+///
+/// Only the sizes and the structure of the traces are kept: they depend on the graph and
+/// its settings, and not on the data it was run on.
+fn verifying_key(
+    graph: &mut Graph,
+    settings: &CircuitSettings,
+    trace_tables: &[TraceTable],
+) -> VerifyingKey {
+    set_fp_scale(graph, settings.fp_scale);
+
+    let claim = LuminairClaim::from_trace_tables(trace_tables);
+    let mut preprocessed_cols = lookups_to_preprocessed_column(&settings.lookups);
+    preprocessed_cols.extend(trace_tables_to_preprocessed_column(trace_tables));
+    let preprocessed_root = PreProcessedTrace::new(preprocessed_cols).root();
+
+    let nodes = graph
+        .graph
+        .node_indices()
+        .sorted()
+        .map(|id| NodeKey {
+            id: id.index() as u32,
+            op: format!("{:?}", graph.graph.node_weight(id).unwrap()),
+        })
+        .collect();
+
+    let edges = graph
+        .graph
+        .edge_references()
+        .filter_map(|edge| {
+            let (input_order, output_order, shape) = edge.weight().as_data()?;
+            Some(EdgeKey {
+                src: edge.source().index() as u32,
+                dst: edge.target().index() as u32,
+                input_order,
+                output_order,
+                shape: format!("{shape:?}"),
+            })
+        })
+        .sorted_by_key(|edge| (edge.dst, edge.input_order))
+        .collect();
+
+    // Public inputs are the initializers handed over to the trace, except for committed
+    // weights, and public outputs are the retrieved tensors produced in the trace.
+    let mut public_io = PublicIoKey::default();
+    for node in graph.graph.node_indices().sorted() {
+        let op = graph.node_weight(node).unwrap().as_any();
+        let is_copy_to = op.is::<CopyToStwo>();
+        if is_initializer(graph, node) {
+            if !is_copy_to && !op.is::<LuminairConstant>() {
+                continue;
+            }
+            let tensor_id = if is_copy_to {
+                graph.get_sources(node)[0].0
+            } else {
+                node
+            };
+            if settings
+                .committed_weights
+                .contains(&(tensor_id.index() as u32))
+            {
+                continue;
+            }
+            public_io.inputs.push(PublicTensorKey {
+                node_id: node.index() as u32,
+                tensor_id: tensor_id.index() as u32,
+                multiplicities: consumer_reads(graph, node),
+            });
+        } else if !op.is::<CopyFromStwo>() {
+            let output_copy = output_copy(graph, node);
+            if graph.to_retrieve.contains_key(&node) || output_copy.is_some() {
+                public_io.outputs.push(PublicTensorKey {
+                    node_id: node.index() as u32,
+                    tensor_id: output_copy.unwrap_or(node).index() as u32,
+                    multiplicities: vec![],
+                });
+            }
+        }
+    }
+
+    let weights = if settings.committed_weights.is_empty() {
+        None
+    } else {
+        let mut tensors = Vec::new();
+        for tensor_id in &settings.committed_weights {
+            let function = NodeIndex::new(*tensor_id as usize);
+            // Weights enter the node relation through their copy to the trace.
+            let copy = graph
+                .graph
+                .edges_directed(function, Direction::Outgoing)
+                .map(|e| e.target())
+                .find(|target| {
+                    graph
+                        .node_weight(*target)
+                        .unwrap()
+                        .as_any()
+                        .is::<CopyToStwo>()
+                })
+                .expect("Committed weights should be copied to the trace");
+
+            let tensor = graph
+                .graph
+                .node_weight_mut(function)
+                .unwrap()
+                .process(vec![])
+                .remove(0);
+            let data = StwoData::from_f32(
+                tensor.downcast_ref::<Vec<f32>>().unwrap(),
+                settings.fp_scale,
+            );
+            let values = data.values.iter().map(|v| v.to_m31()).collect::<Vec<_>>();
+
+            // Multiplicities live in the main trace and are not committed.
+            tensors.push((copy.index() as u32, vec![0; values.len()], values));
+        }
+
+        let table = weights_table(tensors, &settings.weights_salt);
+        Some(WeightsKey {
+            node_ids: table.table.iter().map(|r| r.node_id.0).dedup().collect(),
+            log_size: calculate_log_size(table.table.len()),
+            root: commit_weights(table).expect("Committed weights should not be empty"),
+        })
+    };
+
+    VerifyingKey {
+        nodes,
+        edges,
+        public_io,
+        claim,
+        preprocessed_root,
+        fp_scale: settings.fp_scale,
+        lookups: LookupLayouts::from(&settings.lookups),
+        weights,
+    }
+}
+
+/// Checks whether the node `id` is an initializer of the graph.
+///
+/// Initializers are graph inputs (`Function` nodes) and constants, as well as their copies
+/// to the trace. Their values are not produced by any component and are part of the public inputs.
+fn is_initializer(graph: &Graph, id: NodeIndex) -> bool {
+    let is_source_initializer = |id: NodeIndex| {
+        let weight = graph.node_weight(id).unwrap().as_any();
+        weight.is::<Function>()
+            || weight.is::<LuminairConstant>()
+            || weight.is::<luminal::op::Constant>()
+    };
+
+    is_source_initializer(id)
+        || (graph.node_weight(id).unwrap().as_any().is::<CopyToStwo>()
+            && graph
+                .get_sources(id)
+                .iter()
+                .any(|(src_id, _, _)| is_source_initializer(*src_id)))
+}
+
+/// Finds the retrieved copy of the output of `node` back to the CPU, if any.
+fn output_copy(graph: &Graph, node: NodeIndex) -> Option<NodeIndex> {
+    graph
+        .graph
+        .edges_directed(node, Direction::Outgoing)
+        .map(|e| e.target())
+        .find(|target| {
+            graph.to_retrieve.contains_key(target)
+                && graph
+                    .node_weight(*target)
+                    .unwrap()
+                    .as_any()
+                    .is::<CopyFromStwo>()
+        })
+}
+
+/// Counts how many times each output element of `node` is read by its consumers.
+///
+/// Expanded (broadcast) dimensions read the same element several times,
+/// while padded accesses do not read the output at all.
+/// Convolutions read their input once per position of the kernel, which is not
+/// expressed by the view of their input edge.
+/// Copies back to the CPU are not part of the trace and are skipped.
+fn consumer_reads(graph: &Graph, node: NodeIndex) -> Vec<u32> {
+    let mut dim_stack = Vec::new();
+    let mut consumer_reads: Vec<u32> = Vec::new();
+    for edge in graph.graph.edges_directed(node, Direction::Outgoing) {
+        let is_copy_from = graph
+            .node_weight(edge.target())
+            .unwrap()
+            .as_any()
+            .is::<CopyFromStwo>();
+        if is_copy_from {
+            continue;
+        }
+        if let Some((input_order, _, mut shape)) = edge.weight().as_data() {
+            shape.resolve_global_dyn_dims_stack(&graph.dyn_map, &mut dim_stack);
+            let conv = wrapped_operator::<LuminairConv2d, Conv2dColumn, Conv2dTraceTable, ()>(
+                &**graph.node_weight(edge.target()).unwrap(),
+            );
+            let positions = match conv {
+                Some(conv) if input_order == 0 => conv.input_positions(&shape),
+                _ => get_source_indices(&shape),
+            };
+            for pos in positions.into_iter().flatten() {
+                if pos >= consumer_reads.len() {
+                    consumer_reads.resize(pos + 1, 0);
+                }
+                consumer_reads[pos] += 1;
+            }
+        }
+    }
+    consumer_reads
+}
+
+/// Sets the fixed-point scale at which data enters the trace.
+///
+/// Inputs are converted, constants are represented and rescales round back at `fp_scale`.
+/// Mul produces its exact product at the sum of its input scales; the other operators
+/// produce their outputs at the scale of their inputs.
+fn set_fp_scale(graph: &mut Graph, fp_scale: u32) {
+    for op in graph.graph.node_weights_mut() {
+        if let Some(copy) = (**op).as_any_mut().downcast_mut::<CopyToStwo>() {
+            copy.scale = fp_scale;
+        } else if let Some(constant) = (**op).as_any_mut().downcast_mut::<LuminairConstant>() {
+            constant.scale = fp_scale;
+        } else if <Box<dyn Operator> as HasProcessTrace<
+            RescaleColumn,
+            RescaleTraceTable,
+            RangeCheckLookup,
+        >>::has_process_trace(op)
+        {
+            *op = LuminairRescale::new(fp_scale).into_operator();
+        }
+    }
+}
+
+/// Builds the table of committed weights from `(node_id, multiplicities, values)` tensors.
+///
+/// Rows are ordered by node ID and index, so that the prover and the verifying key
+/// build the same committed weights tree.
+fn weights_table(
+    mut tensors: Vec<(u32, Vec<u32>, Vec<M31>)>,
+    salt: &[u64; 4],
+) -> WeightsTraceTable {
+    tensors.sort_by_key(|(node_id, _, _)| *node_id);
+
+    let mut table = WeightsTraceTable::new();
+    for (node_id, multiplicities, values) in tensors {
+        for (idx, (multiplicity, value)) in multiplicities.into_iter().zip(values).enumerate() {
+            table.add_row(WeightsTraceTableRow {
+                node_id: M31::from(node_id),
+                idx: M31::from(idx),
+                value,
+                multiplicity: M31::from(multiplicity),
+            });
+        }
+    }
+    table.add_blinding_rows(salt);
+    table
+}
+
+// Generates the trace tables, the dispatch and the input ranges of the unary LUT function
+// operators, from the `lut_functions!` registry.
+macro_rules! lut_operators {
+    (
+        unary [$([$name:ident, $field:ident, $lookup:ident, $Lookup:ident])*]
+        all [$([$aname:ident, $afield:ident, $alookup:ident, $ALookup:ident])*]
+    ) => {
+        /// Trace tables of the unary LUT function operators, filled while generating the trace.
+        #[derive(Default)]
+        struct LutTables {
+            $(
+                #[doc = concat!("Trace table of the ", stringify!($name), " operators.")]
+                $field: UnaryTraceTable,
+            )*
+        }
+
+        impl LutTables {
+            /// Range-checks the rows of the operators with an interpolated lookup table.
+            fn range_check_interpolation(
+                &self,
+                lookups: &mut Lookups,
+            ) -> Result<(), LuminairError> {
+                if let Some(range_check_lookup) = lookups.range_check.as_mut() {
+                    $(
+                        if let Some(lookup) = lookups.$field.as_ref() {
+                            range_check_interpolation(&self.$field, lookup, range_check_lookup)?;
+                        }
+                    )*
+                }
+                Ok(())
+            }
+
+            /// Returns `true` if an operator with an interpolated lookup table was executed,
+            /// sending values to the shared range check.
+            fn interpolates(&self, lookups: &Lookups) -> bool {
+                [$((&self.$field, lookups.$field.as_ref().map(|l| &l.layout)),)*]
+                    .into_iter()
+                    .any(|(table, layout)| {
+                        !table.table.is_empty() && layout.is_some_and(|l| l.interpolation.is_some())
+                    })
+            }
+
+            /// Converts the tables to trace tables: the tables of the executed operators, then
+            /// the tables of the lookups used by any operator.
+            ///
+            /// Returns the largest log size among them.
+            fn push_trace_tables(
+                self,
+                lookups: &Lookups,
+                trace_tables: &mut Vec<TraceTable>,
+            ) -> u32 {
+                let mut max_log_size = 0;
+                $(
+                    if !self.$field.table.is_empty() {
+                        let log_size = calculate_log_size(self.$field.table.len());
+                        max_log_size = max_log_size.max(log_size);
+                        trace_tables.push(TraceTable::from_lut(LutTraceTable::$name(self.$field)));
+                    }
+                )*
+                $(
+                    if let Some(lookup) = lookups.$afield.as_ref().filter(|l| l.is_used()) {
+                        let mut table = UnaryLookupTraceTable::new();
+                        lookup.add_multiplicities_to_table(&mut table);
+                        max_log_size = max_log_size.max(lookup.layout.log_size);
+                        trace_tables.push(TraceTable::from_lut(LutTraceTable::$ALookup(table)));
+                    }
+                )*
+                max_log_size
+            }
+        }
+
+        /// Input ranges of the unary LUT functions, observed while inferring the circuit settings.
+        #[derive(Default)]
+        struct LutRanges {
+            $(
+                #[doc = concat!("Input ranges of the ", stringify!($aname), " LUT.")]
+                $afield: Vec<Range>,
+            )*
+        }
+
+        impl LutRanges {
+            /// Builds the lookups of the functions with observed ranges, fitting their layout
+            /// to the merged ranges (Cf. `fit_layout`).
+            ///
+            /// The shared range check is left unset.
+            fn into_lookups(self, fp_scale: u32, interpolation_error: Option<f64>) -> Lookups {
+                Lookups {
+                    $(
+                        $afield: if self.$afield.is_empty() {
+                            None
+                        } else {
+                            let layout = fit_layout::<functions::$aname>(
+                                coalesce_ranges(self.$afield),
+                                fp_scale,
+                                interpolation_error,
+                            );
+                            Some(functions::$ALookup::new(&layout))
+                        },
+                    )*
+                    range_check: None,
+                }
+            }
+        }
+
+        /// Trait dispatching the unary LUT function operators, whatever their function.
+        trait LutOperator {
+            /// Returns `true` if the operator is a unary LUT function operator.
+            fn is_lut_function(&self) -> bool;
+
+            /// Generates the trace of a unary LUT function operator into its table of `tables`,
+            /// looking up the LUT of its function, and counts it in `op_counter`.
+            fn call_lut_process_trace(
+                &mut self,
+                srcs: Vec<(InputTensor, ShapeTracker)>,
+                tables: &mut LutTables,
+                node_info: &NodeInfo,
+                lookups: &mut Lookups,
+                op_counter: &mut OpCounter,
+            ) -> Result<Vec<Tensor>, LuminairError>;
+
+            /// Records the `range` of the inputs of a unary LUT function operator, restricted to
+            /// the domain of its function.
+            fn record_lut_range(&self, range: Range, ranges: &mut LutRanges);
+        }
+
+        impl LutOperator for Box<dyn Operator> {
+            fn is_lut_function(&self) -> bool {
+                $(
+                    <Self as HasProcessTrace<
+                        UnaryColumn,
+                        UnaryTraceTable,
+                        functions::$Lookup,
+                    >>::has_process_trace(self)
+                )||*
+            }
+
+            fn call_lut_process_trace(
+                &mut self,
+                srcs: Vec<(InputTensor, ShapeTracker)>,
+                tables: &mut LutTables,
+                node_info: &NodeInfo,
+                lookups: &mut Lookups,
+                op_counter: &mut OpCounter,
+            ) -> Result<Vec<Tensor>, LuminairError> {
+                $(
+                    if <Self as HasProcessTrace<
+                        UnaryColumn,
+                        UnaryTraceTable,
+                        functions::$Lookup,
+                    >>::has_process_trace(self)
+                    {
+                        op_counter.$field += 1;
+                        return match lookups.$field.as_mut() {
+                            Some(lookup) => <Self as HasProcessTrace<
+                                UnaryColumn,
+                                UnaryTraceTable,
+                                functions::$Lookup,
+                            >>::call_process_trace(
+                                self,
+                                srcs,
+                                &mut tables.$field,
+                                node_info,
+                                lookup,
+                            )
+                            .unwrap(),
+                            None => unreachable!(
+                                "{} lookup table must be initialised",
+                                stringify!($name)
+                            ),
+                        };
+                    }
+                )*
+                unreachable!("Operator is not a unary LUT function")
+            }
+
+            fn record_lut_range(&self, range: Range, ranges: &mut LutRanges) {
+                $(
+                    if <Self as HasProcessTrace<
+                        UnaryColumn,
+                        UnaryTraceTable,
+                        functions::$Lookup,
+                    >>::has_process_trace(self)
+                    {
+                        let range = range.clone();
+                        ranges
+                            .$field
+                            .push(<functions::$name as LutFunction>::restrict_range(range));
+                    }
+                )*
+            }
+        }
+    };
+}
+
+luminair_air::lut_functions!(lut_operators);
@@ -11,5 +11,17 @@ mod tests;
 ///
 /// Represents the collection of compilers needed to transform a computation graph
 /// defined in LuminAIR into an AIR format compatible with the STWO prover.
-/// It bundles primitive operations and copy constraints compilers.
-pub type StwoCompiler = (op::prim::PrimitiveCompiler, op::other::CopyCompiler);
+/// It bundles the fusion of activation, softmax, normalization, convolution, ReLU and matmul
+/// subgraphs, primitive operations, copy constraints and rescaling compilers.
+pub type StwoCompiler = (
+    op::fused::TanhSigmoidCompiler,
+    op::fused::GeluSiluCompiler,
+    op::fused::SoftmaxCompiler,
+    op::fused::NormCompiler,
+    op::fused::Conv2dCompiler,
+    op::fused::ReluCompiler,
+    op::fused::MatMulCompiler,
+    op::prim::PrimitiveCompiler,
+    op::other::CopyCompiler,
+    op::other::RescaleCompiler,
+);
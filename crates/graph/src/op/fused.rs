@@ -0,0 +1,1144 @@
+use std::f32::consts::{FRAC_1_SQRT_2, FRAC_2_SQRT_PI, LN_2};
+
+use itertools::iproduct;
+use luminair_air::components::{
+    lookups::unary::{
+        functions::{Sigmoid, Tanh},
+        LutFunction, UnaryLookup,
+    },
+    unary::table::{UnaryColumn, UnaryTraceTable},
+};
+use luminal::{
+    op::ConstantValue,
+    prelude::{petgraph::visit::EdgeRef, *},
+};
+
+use crate::utils::get_source_indices;
+
+use super::{
+    prim::{
+        LuminairConv2d, LuminairGelu, LuminairMatMul, LuminairNorm, LuminairRelu, LuminairSigmoid,
+        LuminairSilu, LuminairSoftmax, LuminairTanh,
+    },
+    HasProcessTrace, IntoOperator,
+};
+
+/// Tolerance on the constants of a matched subgraph, which luminal computes in `f32`.
+const CONSTANT_TOLERANCE: f32 = 1e-4;
+
+/// Source of a data edge: node, output index and the shape through which it is read.
+type Source = (NodeIndex, u8, ShapeTracker);
+
+/// Coefficients of a polynomial of the input of an activation, by increasing degree.
+type Polynomial = Vec<f32>;
+
+/// A piecewise polynomial of the input of an activation: its polynomials for negative and
+/// positive inputs, and its value at zero.
+type Branches = (Polynomial, Polynomial, f32);
+
+/// A Luminal `Compiler` pass that fuses the luminal lowerings of `sigmoid` and `tanh`
+/// into `LuminairSigmoid` and `LuminairTanh` operators.
+///
+/// luminal builds `sigmoid(x)` as `1 / (1 + exp2(x * -1 * (1 / ln 2)))` and `tanh(x)` as
+/// `sigmoid(2x) * 2 - 1`, a chain of `Mul`, `Exp2`, `Add` and `Recip` nodes with scalar
+/// constants. Each chain is replaced by a single node reading `x`, proven with its own LUT.
+/// Runs before the `PrimitiveCompiler`, on luminal primitives. Chains whose intermediate
+/// values are used elsewhere in the graph are left as they are.
+#[derive(Debug, Default)]
+pub struct TanhSigmoidCompiler();
+
+impl Compiler for TanhSigmoidCompiler {
+    type Output = ();
+
+    /// Executes the fusion pass on the graph.
+    /// Modifies the graph in-place by replacing each matched chain with a single operator.
+    fn compile<To: ToIdsMut>(&self, graph: &mut Graph, mut ids: To) {
+        for recip in graph
+            .node_indices()
+            .filter(|n| is_op::<luminal::op::Recip>(graph, *n))
+            .collect::<Vec<_>>()
+        {
+            if !graph.contains_node(recip) {
+                continue;
+            }
+            if let Some(fused) = match_tanh(graph, recip) {
+                fuse(graph, &mut ids, fused, LuminairTanh::new().into_operator());
+            } else if let Some(fused) = match_sigmoid(graph, recip) {
+                fuse(
+                    graph,
+                    &mut ids,
+                    fused,
+                    LuminairSigmoid::new().into_operator(),
+                );
+            }
+        }
+    }
+}
+
+/// A Luminal `Compiler` pass that fuses the luminal lowerings of `gelu` and `swish` (SiLU)
+/// into `LuminairGelu` and `LuminairSilu` operators.
+///
+/// Runs after the `TanhSigmoidCompiler`, which leaves `gelu(x)` as
+/// `0.5 * x * (1 + tanh(sqrt(2 / pi) * (x + 0.044715 * x^3)))` and `silu(x)` as `x * sigmoid(x)`
+/// around a single `LuminairTanh` or `LuminairSigmoid`. The `Mul` and `Add` nodes around it
+/// are evaluated as polynomials of `x`, so that any ordering of these products is matched.
+#[derive(Debug, Default)]
+pub struct GeluSiluCompiler();
+
+impl Compiler for GeluSiluCompiler {
+    type Output = ();
+
+    /// Executes the fusion pass on the graph.
+    /// Modifies the graph in-place by replacing each matched activation with a single operator.
+    fn compile<To: ToIdsMut>(&self, graph: &mut Graph, mut ids: To) {
+        for node in graph.node_indices().collect::<Vec<_>>() {
+            if !graph.contains_node(node) {
+                continue;
+            }
+            if is_unary::<Tanh>(graph, node) {
+                if let Some(fused) = match_gelu(graph, node) {
+                    fuse(graph, &mut ids, fused, LuminairGelu::new().into_operator());
+                }
+            } else if is_unary::<Sigmoid>(graph, node) {
+                if let Some(fused) = match_silu(graph, node) {
+                    fuse(graph, &mut ids, fused, LuminairSilu::new().into_operator());
+                }
+            }
+        }
+    }
+}
+
+/// A Luminal `Compiler` pass that fuses the luminal lowering of `softmax` into
+/// `LuminairSoftmax` operators.
+///
+/// luminal builds `x.softmax(dim)` as `e / e.sum_reduce(dim)` with
+/// `e = exp2((x + x.max_reduce(dim) * -1) * (1 / ln 2))`, a chain of `MaxReduce`, `Mul`,
+/// `Add`, `Exp2`, `SumReduce`, `Recip` and `Mul` nodes. Each chain reducing along the last
+/// dimension is replaced by a single node reading `x`, proven with the Exp LUT.
+/// Runs before the `PrimitiveCompiler`, on luminal primitives. Chains whose intermediate
+/// values are used elsewhere in the graph are left as they are.
+#[derive(Debug, Default)]
+pub struct SoftmaxCompiler();
+
+impl Compiler for SoftmaxCompiler {
+    type Output = ();
+
+    /// Executes the fusion pass on the graph.
+    /// Modifies the graph in-place by replacing each matched chain with a single operator.
+    fn compile<To: ToIdsMut>(&self, graph: &mut Graph, mut ids: To) {
+        for recip in graph
+            .node_indices()
+            .filter(|n| is_op::<luminal::op::Recip>(graph, *n))
+            .collect::<Vec<_>>()
+        {
+            if let Some(fused) = match_softmax(graph, recip) {
+                fuse(
+                    graph,
+                    &mut ids,
+                    fused,
+                    LuminairSoftmax::new().into_operator(),
+                );
+            }
+        }
+    }
+}
+
+/// A Luminal `Compiler` pass that fuses the luminal lowerings of `layer_norm` and `std_norm`
+/// (RMSNorm) into `LuminairNorm` operators.
+///
+/// luminal builds `x.std_norm(dim, eps)` as `x * (1 / sqrt(mean(x * x) + eps))`, with
+/// `mean(y) = y.sum_reduce(dim) * (1 / n)`, and `x.layer_norm(dim, eps)` as the `std_norm` of
+/// `x + mean(x) * -1`. Each chain reducing along the last dimension is replaced by a single node
+/// reading `x`, along with the weight and bias of an affine `Mul` and `Add` that follow it.
+/// Runs before the `PrimitiveCompiler`, on luminal primitives, and before the `MatMulCompiler`,
+/// which would otherwise take `sum_reduce(x * x)` for a product. Chains whose intermediate
+/// values are used elsewhere in the graph are left as they are.
+#[derive(Debug, Default)]
+pub struct NormCompiler();
+
+impl Compiler for NormCompiler {
+    type Output = ();
+
+    /// Executes the fusion pass on the graph.
+    /// Modifies the graph in-place by replacing each matched chain with a single operator.
+    fn compile<To: ToIdsMut>(&self, graph: &mut Graph, mut ids: To) {
+        for sqrt in graph
+            .node_indices()
+            .filter(|n| is_op::<luminal::op::Sqrt>(graph, *n))
+            .collect::<Vec<_>>()
+        {
+            if let Some((fused, op)) = match_norm(graph, sqrt) {
+                fuse(graph, &mut ids, fused, op.into_operator());
+            }
+        }
+    }
+}
+
+/// A Luminal `Compiler` pass that fuses the luminal lowering of `Conv2D` into
+/// `LuminairConv2d` operators.
+///
+/// luminal_nn builds a 2D convolution as the `matmul` of its weights with an im2col matrix,
+/// which pools the windows of the input through chains of `Contiguous` nodes. Each such
+/// `Mul` and `SumReduce` pair, along with the nodes gathering the windows, is replaced by a
+/// single node reading the windows from the input, once the gather is found to be that of a
+/// kernel, stride and padding.
+/// Runs before the `PrimitiveCompiler`, on luminal primitives, and before the
+/// `MatMulCompiler`, which would otherwise fuse the product of the weights with the im2col
+/// matrix. Windows that are used elsewhere in the graph are left as they are.
+#[derive(Debug, Default)]
+pub struct Conv2dCompiler();
+
+impl Compiler for Conv2dCompiler {
+    type Output = ();
+
+    /// Executes the fusion pass on the graph.
+    /// Modifies the graph in-place by replacing each matched convolution with a single operator.
+    fn compile<To: ToIdsMut>(&self, graph: &mut Graph, mut ids: To) {
+        for reduce in graph
+            .node_indices()
+            .filter(|n| is_op::<luminal::op::SumReduce>(graph, *n))
+            .collect::<Vec<_>>()
+        {
+            if let Some((fused, op)) = match_conv2d(graph, reduce) {
+                fuse(graph, &mut ids, fused, op.into_operator());
+            }
+        }
+    }
+}
+
+/// A Luminal `Compiler` pass that fuses the luminal lowering of `relu` into
+/// `LuminairRelu` operators.
+///
+/// luminal builds `x.relu()` as `x.max(0)`, which selects between `x` and zero with the
+/// comparison `x < 0` through `Mul` and `Add` nodes, e.g. `(x < 0) * 0 + (1 - (x < 0)) * x`.
+/// The nodes consuming each comparison of a tensor with zero are evaluated on both sides of
+/// zero, so that any arrangement of the selection is matched, and replaced by a single node
+/// reading `x`, proven with a sign bit.
+/// Runs before the `PrimitiveCompiler`, on luminal primitives. Selections whose intermediate
+/// values are used elsewhere in the graph are left as they are.
+#[derive(Debug, Default)]
+pub struct ReluCompiler();
+
+impl Compiler for ReluCompiler {
+    type Output = ();
+
+    /// Executes the fusion pass on the graph.
+    /// Modifies the graph in-place by replacing each matched selection with a single operator.
+    fn compile<To: ToIdsMut>(&self, graph: &mut Graph, mut ids: To) {
+        for less_than in graph
+            .node_indices()
+            .filter(|n| is_op::<luminal::op::LessThan>(graph, *n))
+            .collect::<Vec<_>>()
+        {
+            if !graph.contains_node(less_than) {
+                continue;
+            }
+            if let Some(fused) = match_relu(graph, less_than) {
+                fuse(graph, &mut ids, fused, LuminairRelu::new().into_operator());
+            }
+        }
+    }
+}
+
+/// A Luminal `Compiler` pass that fuses the luminal lowering of `matmul` into
+/// `LuminairMatMul` operators.
+///
+/// luminal builds `a.matmul(b)` as a `Mul` of broadcast views of `a` and `b`, followed by a
+/// `SumReduce` of the products along the shared dimension. Each such pair is replaced by a
+/// single node reading the broadcast views, whose products are only rescaled once summed.
+/// Runs before the `PrimitiveCompiler`, on luminal primitives. Products that are used
+/// elsewhere in the graph, or reduced through a reshaped view, are left as they are.
+#[derive(Debug, Default)]
+pub struct MatMulCompiler();
+
+impl Compiler for MatMulCompiler {
+    type Output = ();
+
+    /// Executes the fusion pass on the graph.
+    /// Modifies the graph in-place by replacing each matched `Mul` and `SumReduce` pair
+    /// with a single operator.
+    fn compile<To: ToIdsMut>(&self, graph: &mut Graph, mut ids: To) {
+        for reduce in graph
+            .node_indices()
+            .filter(|n| is_op::<luminal::op::SumReduce>(graph, *n))
+            .collect::<Vec<_>>()
+        {
+            if let Some((fused, dim)) = match_matmul(graph, reduce) {
+                fuse(
+                    graph,
+                    &mut ids,
+                    fused,
+                    LuminairMatMul::new(dim).into_operator(),
+                );
+            }
+        }
+    }
+}
+
+/// A subgraph matched by a fusion pass.
+pub(crate) struct FusedSubgraph {
+    /// Sources read by the fused operator, in input order.
+    pub inputs: Vec<Source>,
+    /// Nodes of the subgraph, replaced by the fused operator.
+    pub nodes: Vec<NodeIndex>,
+    /// Node of the subgraph producing its output.
+    pub output: NodeIndex,
+}
+
+/// Matches `sigmoid(x)` ending at the `Recip` node `recip`, along with the multiplication
+/// by one luminal applies to its output.
+fn match_sigmoid(graph: &Graph, recip: NodeIndex) -> Option<FusedSubgraph> {
+    let (mut nodes, input) = match_logistic(graph, recip, 1.0)?;
+
+    // Keep the longest run of multiplications by one.
+    let output = scale_chain_down(graph, recip)
+        .into_iter()
+        .take_while(|(_, factor)| approx_eq(*factor, 1.0))
+        .last()
+        .map_or(recip, |(node, _)| node);
+    nodes.extend(chain_between(graph, recip, output));
+
+    Some(FusedSubgraph {
+        inputs: vec![input],
+        nodes,
+        output,
+    })
+}
+
+/// Matches `tanh(x) = sigmoid(2x) * 2 - 1` ending at the `Recip` node of its sigmoid.
+fn match_tanh(graph: &Graph, recip: NodeIndex) -> Option<FusedSubgraph> {
+    let (mut nodes, input) = match_logistic(graph, recip, 2.0)?;
+
+    let (doubled, _) = scale_chain_down(graph, recip)
+        .into_iter()
+        .find(|(_, factor)| approx_eq(*factor, 2.0))?;
+    let output = sole_consumer(graph, doubled)?;
+    let (source, offset) = scalar_operand::<luminal::op::Add>(graph, output)?;
+    if source.0 != doubled || !approx_eq(offset, -1.0) {
+        return None;
+    }
+    nodes.extend(chain_between(graph, recip, doubled));
+    nodes.push(output);
+
+    Some(FusedSubgraph {
+        inputs: vec![input],
+        nodes,
+        output,
+    })
+}
+
+/// Matches `1 / (1 + exp2(x * -alpha / ln 2))`, i.e. `sigmoid(alpha * x)`, ending at `recip`.
+///
+/// Returns the nodes of the match, `recip` included, and the source of `x`.
+fn match_logistic(graph: &Graph, recip: NodeIndex, alpha: f32) -> Option<(Vec<NodeIndex>, Source)> {
+    let add = graph.get_sources(recip).first()?.0;
+    sole_consumer(graph, add)?;
+    let (exp2, one) = scalar_operand::<luminal::op::Add>(graph, add)?;
+    if !approx_eq(one, 1.0) || !is_op::<luminal::op::Exp2>(graph, exp2.0) {
+        return None;
+    }
+    sole_consumer(graph, exp2.0)?;
+
+    // The scale of x may be spread over several multiplications.
+    let exponent = graph.get_sources(exp2.0).first()?.0;
+    let chain = scale_chain_up(graph, exponent);
+    let len = chain
+        .iter()
+        .position(|(_, _, factor)| approx_eq(*factor * LN_2, -alpha))?
+        + 1;
+
+    let mut nodes = chain[..len]
+        .iter()
+        .map(|(node, _, _)| *node)
+        .collect::<Vec<_>>();
+    nodes.extend([exp2.0, add, recip]);
+    Some((nodes, chain[len - 1].1))
+}
+
+/// Matches `gelu(x) = 0.5 * x * (1 + tanh(sqrt(2 / pi) * (x + 0.044715 * x^3)))` around the
+/// `LuminairTanh` node `tanh`.
+fn match_gelu(graph: &Graph, tanh: NodeIndex) -> Option<FusedSubgraph> {
+    let inner = *graph.get_sources(tanh).first()?;
+    let input = polynomial_variable(graph, inner)?;
+
+    let mut nodes = vec![tanh];
+    let k = FRAC_2_SQRT_PI * FRAC_1_SQRT_2;
+    let polynomial = polynomial_of(graph, inner, input, &mut nodes)?;
+    if !polynomial_eq(&polynomial, &[0.0, k, 0.0, 0.044715 * k]) {
+        return None;
+    }
+    let (outer, output) = affine_chain_down(graph, tanh, input, [&[0.0, 0.5], &[0.0, 0.5]])?;
+    nodes.extend(outer);
+
+    Some(FusedSubgraph {
+        inputs: vec![input],
+        nodes,
+        output,
+    })
+}
+
+/// Matches `silu(x) = x * sigmoid(x)` around the `LuminairSigmoid` node `sigmoid`.
+fn match_silu(graph: &Graph, sigmoid: NodeIndex) -> Option<FusedSubgraph> {
+    let input = *graph.get_sources(sigmoid).first()?;
+
+    let (mut nodes, output) = affine_chain_down(graph, sigmoid, input, [&[], &[0.0, 1.0]])?;
+    nodes.push(sigmoid);
+
+    Some(FusedSubgraph {
+        inputs: vec![input],
+        nodes,
+        output,
+    })
+}
+
+/// Matches `relu(x) = max(x, 0)` selected by the `LessThan` node `less_than`, which compares
+/// `x` with zero.
+///
+/// Walks down the `Mul` and `Add` nodes consuming the comparison, and matches the first one
+/// computing `max(x, 0)` whose subgraph is only read through it.
+fn match_relu(graph: &Graph, less_than: NodeIndex) -> Option<FusedSubgraph> {
+    let [a, b] = <[Source; 2]>::try_from(graph.get_sources(less_than)).ok()?;
+    let input = match (constant_value(graph, a.0), constant_value(graph, b.0)) {
+        (None, Some(zero)) if approx_eq(zero, 0.0) => a,
+        (Some(zero), None) if approx_eq(zero, 0.0) => b,
+        _ => return None,
+    };
+
+    let mut candidates = consumers(graph, less_than)?;
+    let mut visited = Vec::new();
+    while !candidates.is_empty() {
+        let candidate = candidates.remove(0);
+        if visited.contains(&candidate)
+            || !(is_op::<luminal::op::Mul>(graph, candidate)
+                || is_op::<luminal::op::Add>(graph, candidate))
+        {
+            continue;
+        }
+        visited.push(candidate);
+
+        // Nodes reading a tensor other than x cannot be evaluated, nor can their consumers.
+        let mut nodes = Vec::new();
+        let Some((negative, positive, zero)) =
+            branches_of(graph, (candidate, 0), input, &mut nodes)
+        else {
+            continue;
+        };
+
+        // Nodes other than the output must not be read from outside the subgraph.
+        let closed = nodes.iter().filter(|node| **node != candidate).all(|node| {
+            consumers(graph, *node).is_some_and(|c| c.iter().all(|c| nodes.contains(c)))
+        });
+        if closed
+            && polynomial_eq(&negative, &[])
+            && polynomial_eq(&positive, &[0.0, 1.0])
+            && approx_eq(zero, 0.0)
+        {
+            return Some(FusedSubgraph {
+                inputs: vec![input],
+                nodes,
+                output: candidate,
+            });
+        }
+        candidates.extend(consumers(graph, candidate).unwrap_or_default());
+    }
+    None
+}
+
+/// Matches the `Mul` of two tensors summed by the `SumReduce` node `reduce`.
+///
+/// Returns the match along with the reduced dimension, which indexes the shape of the
+/// products as long as `reduce` reads them through a plain view.
+fn match_matmul(graph: &Graph, reduce: NodeIndex) -> Option<(FusedSubgraph, usize)> {
+    let dim = graph
+        .node_weight(reduce)?
+        .as_any()
+        .downcast_ref::<luminal::op::SumReduce>()?
+        .0;
+    let (mul, _, view) = *graph.get_sources(reduce).first()?;
+    if !is_op::<luminal::op::Mul>(graph, mul) || sole_consumer(graph, mul) != Some(reduce) {
+        return None;
+    }
+    let inputs = graph.get_sources(mul);
+    if inputs.len() != 2 || view.is_reshaped() || view.dims() != inputs[0].2.dims() {
+        return None;
+    }
+
+    Some((
+        FusedSubgraph {
+            inputs,
+            nodes: vec![mul, reduce],
+            output: reduce,
+        },
+        dim,
+    ))
+}
+
+/// Matches the product of the weights of a 2D convolution with the im2col matrix of its input,
+/// summed by the `SumReduce` node `reduce`.
+///
+/// The positions of the input gathered by the im2col matrix are composed through the
+/// `Contiguous` nodes that build it, and compared with the windows read by a `LuminairConv2d`
+/// for each kernel, stride and padding (without dilation) consistent with the shapes. The
+/// input is the `(ch_in, height, width)` tensor read by the first of these nodes, and the
+/// weights must be read as a `(ch_out, ch_in * kernel.0 * kernel.1)` matrix.
+fn match_conv2d(graph: &Graph, reduce: NodeIndex) -> Option<(FusedSubgraph, LuminairConv2d)> {
+    let dim = graph
+        .node_weight(reduce)?
+        .as_any()
+        .downcast_ref::<luminal::op::SumReduce>()?
+        .0;
+    let (mul, _, view) = *graph.get_sources(reduce).first()?;
+    if !is_op::<luminal::op::Mul>(graph, mul) || sole_consumer(graph, mul) != Some(reduce) {
+        return None;
+    }
+    let [weight, cols] = <[Source; 2]>::try_from(graph.get_sources(mul)).ok()?;
+    if dim != 2 || view.is_reshaped() || view.dims() != weight.2.dims() {
+        return None;
+    }
+    let weight_positions = source_indices(&weight.2)?;
+    let [ch_out, out_size, window] = <[usize; 3]>::try_from(weight.2.shape_usize()).ok()?;
+
+    // Compose the positions read by the products down the chain gathering the windows.
+    let mut nodes = vec![mul, reduce];
+    let mut reads = source_indices(&cols.2)?;
+    let (mut input, mut consumer) = (cols, mul);
+    while is_op::<luminal::op::Contiguous>(graph, input.0) {
+        if sole_consumer(graph, input.0) != Some(consumer) {
+            return None;
+        }
+        let source = *graph.get_sources(input.0).first()?;
+        let positions = source_indices(&source.2)?;
+        reads = reads
+            .into_iter()
+            .map(|read| read.and_then(|pos| positions.get(pos).copied().flatten()))
+            .collect();
+        nodes.push(input.0);
+        (input, consumer) = (source, input.0);
+    }
+
+    // The dimensions of the input are those of its view, without the pooled windows.
+    let dims = input
+        .2
+        .dims
+        .iter()
+        .zip(&input.2.fake)
+        .filter(|(_, fake)| !**fake)
+        .map(|(dim, _)| dim.to_usize())
+        .collect::<Option<Vec<_>>>()?;
+    let split = dims.len().checked_sub(3)?;
+    let &[ch_in, height, width] = &dims[split..] else {
+        return None;
+    };
+    if dims[..split].iter().any(|dim| *dim != 1) || window % ch_in != 0 {
+        return None;
+    }
+
+    // The weights are read as a plain matrix, one row per output channel.
+    if weight_positions
+        .iter()
+        .enumerate()
+        .any(|(i, pos)| *pos != Some(i / (out_size * window) * window + i % window))
+    {
+        return None;
+    }
+
+    let mut input_view = ShapeTracker::new((ch_in, height, width));
+    input_view.expand(0, ch_out);
+    let kernel_size = window / ch_in;
+    let op = (1..=kernel_size)
+        .filter(|kh| kernel_size % kh == 0)
+        .map(|kh| (kh, kernel_size / kh))
+        .flat_map(|kernel| {
+            iproduct!(1..=height, 1..=width, 0..kernel.0, 0..kernel.1)
+                .map(move |(sy, sx, py, px)| LuminairConv2d::new(kernel, (sy, sx), (py, px)))
+        })
+        .filter(|op| {
+            op.output_dims(height, width)
+                .is_some_and(|(out_height, out_width)| out_height * out_width == out_size)
+        })
+        .find(|op| op.input_positions(&input_view) == reads)?;
+
+    let (node, output_order, _) = input;
+    Some((
+        FusedSubgraph {
+            inputs: vec![(node, output_order, input_view), weight],
+            nodes,
+            output: reduce,
+        },
+        op,
+    ))
+}
+
+/// Matches `softmax(x)` along the last dimension around the `Recip` node `recip`, which
+/// inverts the sum of the exponentials.
+fn match_softmax(graph: &Graph, recip: NodeIndex) -> Option<FusedSubgraph> {
+    let sum = graph.get_sources(recip).first()?.0;
+    let dim = graph
+        .node_weight(sum)?
+        .as_any()
+        .downcast_ref::<luminal::op::SumReduce>()?
+        .0;
+    if sole_consumer(graph, sum) != Some(recip) {
+        return None;
+    }
+
+    // The exponentials are read twice: summed, then multiplied by the reciprocal of the sum.
+    let (exp2, _, view) = *graph.get_sources(sum).first()?;
+    if !is_op::<luminal::op::Exp2>(graph, exp2)
+        || view.is_reshaped()
+        || dim + 1 != view.dims().len()
+    {
+        return None;
+    }
+    let output = sole_consumer(graph, recip)?;
+    let mut sources = graph
+        .get_sources(output)
+        .into_iter()
+        .map(|(node, _, _)| node);
+    if !is_op::<luminal::op::Mul>(graph, output)
+        || !sources.any(|node| node == exp2)
+        || !consumers(graph, exp2).is_some_and(|c| c.len() == 2 && c.contains(&sum))
+    {
+        return None;
+    }
+
+    // The exponent is (x - max) / ln 2, its scale possibly spread over several multiplications.
+    let exponent = graph.get_sources(exp2).first()?.0;
+    let chain = scale_chain_up(graph, exponent);
+    let len = chain
+        .iter()
+        .position(|(_, _, factor)| approx_eq(*factor * LN_2, 1.0))?
+        + 1;
+    let add = chain[len - 1].1 .0;
+    if !is_op::<luminal::op::Add>(graph, add) || sole_consumer(graph, add).is_none() {
+        return None;
+    }
+
+    // x - max is the sum of x and of the maximum multiplied by -1.
+    let [a, b] = <[Source; 2]>::try_from(graph.get_sources(add)).ok()?;
+    let (input, neg, max) = [(a, b), (b, a)].into_iter().find_map(|(input, neg)| {
+        let (max, factor) = scalar_operand::<luminal::op::Mul>(graph, neg.0)?;
+        approx_eq(factor, -1.0).then_some((input, neg.0, max.0))
+    })?;
+    let max_dim = graph
+        .node_weight(max)?
+        .as_any()
+        .downcast_ref::<luminal::op::MaxReduce>()?
+        .0;
+    let (source, output_order, _) = *graph.get_sources(max).first()?;
+    if max_dim != dim
+        || sole_consumer(graph, neg) != Some(add)
+        || sole_consumer(graph, max) != Some(neg)
+        || (source, output_order) != (input.0, input.1)
+    {
+        return None;
+    }
+
+    let mut nodes = vec![max, neg, add];
+    nodes.extend(chain[..len].iter().map(|(node, _, _)| *node));
+    nodes.extend([exp2, sum, recip, output]);
+
+    Some(FusedSubgraph {
+        inputs: vec![input],
+        nodes,
+        output,
+    })
+}
+
+/// Matches a LayerNorm or RMSNorm along the last dimension around the `Sqrt` node `sqrt`,
+/// which takes the root of the variance plus epsilon.
+///
+/// Returns the match along with its operator, whose inputs are `x`, then the weight and the
+/// bias when present.
+fn match_norm(graph: &Graph, sqrt: NodeIndex) -> Option<(FusedSubgraph, LuminairNorm)> {
+    // The variance plus epsilon is the mean of the squares of the centered inputs.
+    let var_eps = graph.get_sources(sqrt).first()?.0;
+    let (var, eps) = scalar_operand::<luminal::op::Add>(graph, var_eps)?;
+    let (squares_sum, dim, n) = match_mean(graph, var.0)?;
+    let (squares, _, view) = *graph.get_sources(squares_sum).first()?;
+    if sole_consumer(graph, var.0) != Some(var_eps)
+        || sole_consumer(graph, var_eps) != Some(sqrt)
+        || !is_op::<luminal::op::Mul>(graph, squares)
+        || sole_consumer(graph, squares) != Some(squares_sum)
+        || view.is_reshaped()
+        || dim + 1 != view.dims().len()
+    {
+        return None;
+    }
+
+    // The centered inputs are squared, then multiplied by the inverse of the root.
+    let [a, b] = <[Source; 2]>::try_from(graph.get_sources(squares)).ok()?;
+    let recip = sole_consumer(graph, sqrt)?;
+    let output = sole_consumer(graph, recip)?;
+    let [c, d] = <[Source; 2]>::try_from(graph.get_sources(output)).ok()?;
+    let centered = if c.0 == recip { d } else { c };
+    if !is_op::<luminal::op::Recip>(graph, recip)
+        || !is_op::<luminal::op::Mul>(graph, output)
+        || [a, b, centered]
+            .iter()
+            .any(|(node, order, _)| (*node, *order) != (centered.0, centered.1))
+    {
+        return None;
+    }
+
+    let mut nodes = vec![squares, squares_sum, var.0, var_eps, sqrt, recip, output];
+    let (input, is_centered) = match match_centering(graph, centered, dim, n) {
+        Some((input, centering)) => {
+            nodes.extend(centering);
+            (input, true)
+        }
+        None => (centered, false),
+    };
+
+    // The normalized inputs may be scaled by a weight, then shifted by a bias.
+    let mut inputs = vec![input];
+    let mut output = output;
+    let weight = affine_operand::<luminal::op::Mul>(graph, output);
+    if let Some((node, source)) = weight {
+        nodes.push(node);
+        inputs.push(source);
+        output = node;
+    }
+    let bias = affine_operand::<luminal::op::Add>(graph, output);
+    if let Some((node, source)) = bias {
+        nodes.push(node);
+        inputs.push(source);
+        output = node;
+    }
+
+    Some((
+        FusedSubgraph {
+            inputs,
+            nodes,
+            output,
+        },
+        LuminairNorm::new(is_centered, weight.is_some(), bias.is_some(), eps),
+    ))
+}
+
+/// Matches `x + mean(x) * -1` along `dim` at the source `centered`, which is only read by the
+/// norm: twice to be squared, then once to be normalized.
+///
+/// Returns the source of `x` along with the nodes of the match.
+fn match_centering(
+    graph: &Graph,
+    centered: Source,
+    dim: usize,
+    n: usize,
+) -> Option<(Source, Vec<NodeIndex>)> {
+    let add = centered.0;
+    if !is_op::<luminal::op::Add>(graph, add) || consumers(graph, add)?.len() != 3 {
+        return None;
+    }
+
+    let [a, b] = <[Source; 2]>::try_from(graph.get_sources(add)).ok()?;
+    let (input, neg, mean) = [(a, b), (b, a)].into_iter().find_map(|(input, neg)| {
+        let (mean, factor) = scalar_operand::<luminal::op::Mul>(graph, neg.0)?;
+        approx_eq(factor, -1.0).then_some((input, neg.0, mean.0))
+    })?;
+    let (sum, sum_dim, sum_n) = match_mean(graph, mean)?;
+    let (source, output_order, _) = *graph.get_sources(sum).first()?;
+    if (sum_dim, sum_n) != (dim, n)
+        || sole_consumer(graph, neg) != Some(add)
+        || sole_consumer(graph, mean) != Some(neg)
+        || (source, output_order) != (input.0, input.1)
+    {
+        return None;
+    }
+
+    Some((input, vec![sum, mean, neg, add]))
+}
+
+/// Matches `y.sum_reduce(dim) * (1 / n)` at `mean`, with `n` the size of the reduced dimension.
+///
+/// Returns the `SumReduce` node, along with `dim` and `n`.
+fn match_mean(graph: &Graph, mean: NodeIndex) -> Option<(NodeIndex, usize, usize)> {
+    let (sum, factor) = scalar_operand::<luminal::op::Mul>(graph, mean)?;
+    let dim = graph
+        .node_weight(sum.0)?
+        .as_any()
+        .downcast_ref::<luminal::op::SumReduce>()?
+        .0;
+    let n = graph
+        .get_sources(sum.0)
+        .first()?
+        .2
+        .dims()
+        .get(dim)?
+        .to_usize()?;
+    if sole_consumer(graph, sum.0) != Some(mean) || !approx_eq(factor * n as f32, 1.0) {
+        return None;
+    }
+    Some((sum.0, dim, n))
+}
+
+/// Returns the sole consumer of `node` if it is a binary luminal operator `T` between `node`
+/// and a tensor, along with the source of that tensor.
+fn affine_operand<T: Operator + 'static>(
+    graph: &Graph,
+    node: NodeIndex,
+) -> Option<(NodeIndex, Source)> {
+    let next = sole_consumer(graph, node)?;
+    if !is_op::<T>(graph, next) {
+        return None;
+    }
+    let [a, b] = <[Source; 2]>::try_from(graph.get_sources(next)).ok()?;
+    let other = match (a.0 == node, b.0 == node) {
+        (true, false) => b,
+        (false, true) => a,
+        _ => return None,
+    };
+    constant_value(graph, other.0)
+        .is_none()
+        .then_some((next, other))
+}
+
+/// Replaces the nodes of `fused` with the operator `op`, reading its inputs.
+///
+/// The consumers of the output of the subgraph read the output of `op` instead, and
+/// constants left without consumers are removed.
+pub(crate) fn fuse<To: ToIdsMut>(
+    graph: &mut Graph,
+    ids: &mut To,
+    fused: FusedSubgraph,
+    op: Box<dyn Operator>,
+) {
+    let mut builder = graph.add_op(op);
+    for (node, output_order, shape) in &fused.inputs {
+        builder = builder.input(*node, *output_order, *shape);
+    }
+    let node = builder.finish();
+
+    move_outgoing_edge(fused.output, node, graph);
+    remap(fused.output, node, ids, graph);
+
+    let mut constants = Vec::new();
+    for id in fused.nodes {
+        constants.extend(constant_sources(graph, id));
+        graph.remove_node(id);
+    }
+    // Removing a constant computed from other constants may leave those unused in turn.
+    while let Some(constant) = constants.pop() {
+        if graph.contains_node(constant)
+            && graph.get_dests(constant).is_empty()
+            && !graph.no_delete.contains(&constant)
+        {
+            constants.extend(constant_sources(graph, constant));
+            graph.remove_node(constant);
+        }
+    }
+}
+
+/// Returns the sources of `node` that are scalar constants.
+fn constant_sources(graph: &Graph, node: NodeIndex) -> Vec<NodeIndex> {
+    graph
+        .get_sources(node)
+        .into_iter()
+        .map(|(src, _, _)| src)
+        .filter(|src| constant_value(graph, *src).is_some())
+        .collect()
+}
+
+/// Returns the position in the source buffer read for each element of the view `shape`, if
+/// its dimensions are known at compile time.
+fn source_indices(shape: &ShapeTracker) -> Option<Vec<Option<usize>>> {
+    shape.n_elements().to_usize()?;
+    Some(get_source_indices(shape))
+}
+
+/// Checks whether `node` is the luminal operator `T`.
+pub(crate) fn is_op<T: Operator + 'static>(graph: &Graph, node: NodeIndex) -> bool {
+    graph
+        .node_weight(node)
+        .is_some_and(|op| op.as_any().is::<T>())
+}
+
+/// Checks whether `node` is a `LuminairUnary` operator applying the LUT function `F`.
+pub(crate) fn is_unary<F: LutFunction>(graph: &Graph, node: NodeIndex) -> bool {
+    graph.node_weight(node).is_some_and(|op| {
+        <Box<dyn Operator> as HasProcessTrace<
+            UnaryColumn,
+            UnaryTraceTable,
+            UnaryLookup<F>,
+        >>::has_process_trace(op)
+    })
+}
+
+/// Returns the value of `node` if it is a scalar luminal constant.
+///
+/// The reciprocal of a constant counts as a constant, as luminal divides by a constant
+/// (e.g. the size of a dimension in `mean_reduce`) through its reciprocal.
+pub(crate) fn constant_value(graph: &Graph, node: NodeIndex) -> Option<f32> {
+    if is_op::<luminal::op::Recip>(graph, node) {
+        let source = graph.get_sources(node).first()?.0;
+        return constant_value(graph, source).map(|value| 1.0 / value);
+    }
+    let op = graph.node_weight(node)?.as_any();
+    match &op.downcast_ref::<luminal::op::Constant>()?.0 {
+        ConstantValue::Float(value) => Some(*value),
+        ConstantValue::Expression(expr) => expr.to_usize().map(|value| value as f32),
+    }
+}
+
+/// Returns the non-constant source of `node` and its constant operand, if `node` is a binary
+/// luminal operator `T` (e.g. `Mul`, `Add`) between a tensor and a scalar constant.
+pub(crate) fn scalar_operand<T: Operator + 'static>(
+    graph: &Graph,
+    node: NodeIndex,
+) -> Option<(Source, f32)> {
+    if !is_op::<T>(graph, node) {
+        return None;
+    }
+    let sources = graph.get_sources(node);
+    let [a, b] = <[Source; 2]>::try_from(sources).ok()?;
+    match (constant_value(graph, a.0), constant_value(graph, b.0)) {
+        (None, Some(value)) => Some((a, value)),
+        (Some(value), None) => Some((b, value)),
+        _ => None,
+    }
+}
+
+/// Returns the only consumer of `node`, if its output is only read once and is not
+/// otherwise kept, so that it can be fused away.
+pub(crate) fn sole_consumer(graph: &Graph, node: NodeIndex) -> Option<NodeIndex> {
+    if graph.no_delete.contains(&node) || graph.to_retrieve.contains_key(&node) {
+        return None;
+    }
+    let mut dests = graph
+        .edges_directed(node, petgraph::Direction::Outgoing)
+        .filter(|e| e.weight().as_data().is_some())
+        .map(|e| e.target());
+    match (dests.next(), dests.next()) {
+        (Some(dest), None) => Some(dest),
+        _ => None,
+    }
+}
+
+/// Returns the consumers of `node`, if its output is not otherwise kept, so that it can be
+/// fused away along with all of them.
+fn consumers(graph: &Graph, node: NodeIndex) -> Option<Vec<NodeIndex>> {
+    if graph.no_delete.contains(&node) || graph.to_retrieve.contains_key(&node) {
+        return None;
+    }
+    Some(
+        graph
+            .edges_directed(node, petgraph::Direction::Outgoing)
+            .filter(|e| e.weight().as_data().is_some())
+            .map(|e| e.target())
+            .collect(),
+    )
+}
+
+/// Walks up the multiplications by scalar constants ending at `node`, while they can be fused.
+///
+/// Returns each multiplication with its tensor source and the product of the constants
+/// from `node` up to it.
+fn scale_chain_up(graph: &Graph, mut node: NodeIndex) -> Vec<(NodeIndex, Source, f32)> {
+    let mut chain = Vec::new();
+    let mut factor = 1.0;
+    while sole_consumer(graph, node).is_some() {
+        let Some((source, value)) = scalar_operand::<luminal::op::Mul>(graph, node) else {
+            break;
+        };
+        factor *= value;
+        chain.push((node, source, factor));
+        node = source.0;
+    }
+    chain
+}
+
+/// Walks down the multiplications by scalar constants consuming `node`, while they can be fused.
+///
+/// Returns each multiplication with the product of the constants from `node` down to it.
+fn scale_chain_down(graph: &Graph, mut node: NodeIndex) -> Vec<(NodeIndex, f32)> {
+    let mut chain = Vec::new();
+    let mut factor = 1.0;
+    while let Some(next) = sole_consumer(graph, node) {
+        let Some((source, value)) = scalar_operand::<luminal::op::Mul>(graph, next) else {
+            break;
+        };
+        if source.0 != node {
+            break;
+        }
+        factor *= value;
+        chain.push((next, factor));
+        node = next;
+    }
+    chain
+}
+
+/// Returns the nodes of the chain of sole consumers from `from` (excluded) down to `to`.
+fn chain_between(graph: &Graph, mut from: NodeIndex, to: NodeIndex) -> Vec<NodeIndex> {
+    let mut nodes = Vec::new();
+    while from != to {
+        from = sole_consumer(graph, from).unwrap();
+        nodes.push(from);
+    }
+    nodes
+}
+
+/// Returns the variable of the polynomial computed by `source`: its first source that is
+/// neither a constant nor a `Mul` or `Add` node that can be fused.
+fn polynomial_variable(graph: &Graph, mut source: Source) -> Option<Source> {
+    while is_fusable_arithmetic(graph, source.0) {
+        source = graph
+            .get_sources(source.0)
+            .into_iter()
+            .find(|(node, _, _)| constant_value(graph, *node).is_none())?;
+    }
+    constant_value(graph, source.0).is_none().then_some(source)
+}
+
+/// Evaluates `source` as a polynomial of `x`, through `Mul` and `Add` nodes that can be fused
+/// and scalar constants.
+///
+/// Pushes the `Mul` and `Add` nodes of the polynomial to `nodes`.
+fn polynomial_of(
+    graph: &Graph,
+    source: Source,
+    x: Source,
+    nodes: &mut Vec<NodeIndex>,
+) -> Option<Polynomial> {
+    if (source.0, source.1) == (x.0, x.1) {
+        return Some(vec![0.0, 1.0]);
+    }
+    if let Some(value) = constant_value(graph, source.0) {
+        return Some(vec![value]);
+    }
+    if !is_fusable_arithmetic(graph, source.0) {
+        return None;
+    }
+    let [a, b] = <[Source; 2]>::try_from(graph.get_sources(source.0)).ok()?;
+    let a = polynomial_of(graph, a, x, nodes)?;
+    let b = polynomial_of(graph, b, x, nodes)?;
+    nodes.push(source.0);
+    if is_op::<luminal::op::Mul>(graph, source.0) {
+        Some(polynomial_mul(&a, &b))
+    } else {
+        Some(polynomial_add(&a, &b))
+    }
+}
+
+/// Evaluates the output `source` of a node as a piecewise polynomial of `x`, through `Mul` and
+/// `Add` nodes, scalar constants and the comparisons of `x` with zero.
+///
+/// Pushes the `Mul`, `Add` and `LessThan` nodes of the evaluation to `nodes`, once each.
+fn branches_of(
+    graph: &Graph,
+    source: (NodeIndex, u8),
+    x: Source,
+    nodes: &mut Vec<NodeIndex>,
+) -> Option<Branches> {
+    if source == (x.0, x.1) {
+        return Some((vec![0.0, 1.0], vec![0.0, 1.0], 0.0));
+    }
+    if let Some(value) = constant_value(graph, source.0) {
+        return Some((vec![value], vec![value], value));
+    }
+    let [a, b] = <[Source; 2]>::try_from(graph.get_sources(source.0)).ok()?;
+    let branches = if is_op::<luminal::op::LessThan>(graph, source.0) {
+        let is_x = |s: &Source| (s.0, s.1) == (x.0, x.1);
+        let is_zero = |s: &Source| constant_value(graph, s.0).is_some_and(|v| approx_eq(v, 0.0));
+        if is_x(&a) && is_zero(&b) {
+            (vec![1.0], vec![], 0.0)
+        } else if is_zero(&a) && is_x(&b) {
+            (vec![], vec![1.0], 0.0)
+        } else {
+            return None;
+        }
+    } else if is_op::<luminal::op::Mul>(graph, source.0) {
+        let a = branches_of(graph, (a.0, a.1), x, nodes)?;
+        let b = branches_of(graph, (b.0, b.1), x, nodes)?;
+        (
+            polynomial_mul(&a.0, &b.0),
+            polynomial_mul(&a.1, &b.1),
+            a.2 * b.2,
+        )
+    } else if is_op::<luminal::op::Add>(graph, source.0) {
+        let a = branches_of(graph, (a.0, a.1), x, nodes)?;
+        let b = branches_of(graph, (b.0, b.1), x, nodes)?;
+        (
+            polynomial_add(&a.0, &b.0),
+            polynomial_add(&a.1, &b.1),
+            a.2 + b.2,
+        )
+    } else {
+        return None;
+    };
+    if !nodes.contains(&source.0) {
+        nodes.push(source.0);
+    }
+    Some(branches)
+}
+
+/// Walks down the `Mul` and `Add` nodes consuming `node`, each with a polynomial of `x`,
+/// until they compute `target[0](x) + target[1](x) * node`.
+///
+/// Returns the nodes walked, along with those of the polynomials they read, and the last one.
+fn affine_chain_down(
+    graph: &Graph,
+    node: NodeIndex,
+    x: Source,
+    target: [&[f32]; 2],
+) -> Option<(Vec<NodeIndex>, NodeIndex)> {
+    let (mut offset, mut factor) = (vec![], vec![1.0]);
+    let mut nodes = Vec::new();
+    let mut current = node;
+    while !polynomial_eq(&offset, target[0]) || !polynomial_eq(&factor, target[1]) {
+        let next = sole_consumer(graph, current)?;
+        if !is_fusable_arithmetic(graph, next) {
+            return None;
+        }
+        let [a, b] = <[Source; 2]>::try_from(graph.get_sources(next)).ok()?;
+        let other = match (a.0 == current, b.0 == current) {
+            (true, false) => b,
+            (false, true) => a,
+            _ => return None,
+        };
+        let polynomial = polynomial_of(graph, other, x, &mut nodes)?;
+        if is_op::<luminal::op::Mul>(graph, next) {
+            offset = polynomial_mul(&offset, &polynomial);
+            factor = polynomial_mul(&factor, &polynomial);
+        } else {
+            offset = polynomial_add(&offset, &polynomial);
+        }
+        nodes.push(next);
+        current = next;
+    }
+    Some((nodes, current))
+}
+
+/// Checks whether `node` is a `Mul` or `Add` node whose output can be fused away.
+fn is_fusable_arithmetic(graph: &Graph, node: NodeIndex) -> bool {
+    (is_op::<luminal::op::Mul>(graph, node) || is_op::<luminal::op::Add>(graph, node))
+        && sole_consumer(graph, node).is_some()
+}
+
+/// Adds two polynomials.
+fn polynomial_add(a: &[f32], b: &[f32]) -> Polynomial {
+    (0..a.len().max(b.len()))
+        .map(|i| a.get(i).unwrap_or(&0.0) + b.get(i).unwrap_or(&0.0))
+        .collect()
+}
+
+/// Multiplies two polynomials.
+fn polynomial_mul(a: &[f32], b: &[f32]) -> Polynomial {
+    let mut product = vec![0.0; (a.len() + b.len()).saturating_sub(1)];
+    for (i, a) in a.iter().enumerate() {
+        for (j, b) in b.iter().enumerate() {
+            product[i + j] += a * b;
+        }
+    }
+    product
+}
+
+/// Compares two polynomials coefficient-wise, with `approx_eq`.
+fn polynomial_eq(a: &[f32], b: &[f32]) -> bool {
+    (0..a.len().max(b.len()))
+        .all(|i| approx_eq(*a.get(i).unwrap_or(&0.0), *b.get(i).unwrap_or(&0.0)))
+}
+
+/// Compares two constants of a matched subgraph, up to `CONSTANT_TOLERANCE`.
+pub(crate) fn approx_eq(a: f32, b: f32) -> bool {
+    (a - b).abs() <= CONSTANT_TOLERANCE * b.abs().max(1.0)
+}
@@ -1,8 +1,10 @@
 use std::fmt::Debug;
 
 use luminair_air::{components::TraceColumn, pie::NodeInfo};
+use luminair_utils::LuminairError;
 use luminal::prelude::*;
 
+pub(crate) mod fused;
 pub(crate) mod other;
 pub(crate) mod prim;
 
@@ -22,13 +24,14 @@ pub(crate) trait LuminairOperator<
     ///
     /// This method is responsible for the core logic of the operator when generating
     /// a trace for the STWO prover. It records relevant data into the `table`.
+    /// Returns a `LuminairError` if the inputs cannot be represented in the trace.
     fn process_trace(
         &mut self,
         inp: Vec<(InputTensor, ShapeTracker)>,
         table: &mut T,
         node_info: &NodeInfo,
         lookup: &mut L,
-    ) -> Vec<Tensor>;
+    ) -> Result<Vec<Tensor>, LuminairError>;
 }
 
 /// A trait to dynamically check if an operator supports trace generation and to invoke it.
@@ -49,15 +52,15 @@ pub(crate) trait HasProcessTrace<
 
     /// Calls the `process_trace` method of the underlying `LuminairOperator`.
     ///
-    /// Returns `Some(Vec<Tensor>)` if the operator supports and successfully executes trace generation,
-    /// otherwise `None`.
+    /// Returns `Some(Ok(Vec<Tensor>))` if the operator supports and successfully executes trace generation,
+    /// `Some(Err(_))` if trace generation fails, otherwise `None`.
     fn call_process_trace(
         &mut self,
         _inp: Vec<(InputTensor, ShapeTracker)>,
         _table: &mut T,
         _node_info: &NodeInfo,
         _lookup: &mut L,
-    ) -> Option<Vec<Tensor>> {
+    ) -> Option<Result<Vec<Tensor>, LuminairError>> {
         None
     }
 }
@@ -96,7 +99,7 @@ impl<C: TraceColumn + Debug + 'static, T: Debug + 'static, L: Debug + 'static>
         table: &mut T,
         node_info: &NodeInfo,
         lookup: &mut L,
-    ) -> Option<Vec<Tensor>> {
+    ) -> Option<Result<Vec<Tensor>, LuminairError>> {
         Some(self.0.process_trace(inp, table, node_info, lookup))
     }
 }
@@ -122,7 +125,7 @@ impl<C: TraceColumn + Debug + 'static, T: Debug + 'static, L: Debug + 'static>
         table: &mut T,
         node_info: &NodeInfo,
         lookup: &mut L,
-    ) -> Option<Vec<Tensor>> {
+    ) -> Option<Result<Vec<Tensor>, LuminairError>> {
         if let Some(wrapper) = (**self)
             .as_any_mut()
             .downcast_mut::<LuminairWrapper<C, T, L>>()
@@ -134,6 +137,22 @@ impl<C: TraceColumn + Debug + 'static, T: Debug + 'static, L: Debug + 'static>
     }
 }
 
+/// Returns the `LuminairOperator` `O` wrapped in `op`, so that its parameters can be read.
+///
+/// Returns `None` if `op` does not wrap an operator `O` with the trace generation signature
+/// `C`, `T`, `L`.
+pub(crate) fn wrapped_operator<O, C, T, L>(op: &dyn Operator) -> Option<&O>
+where
+    O: LuminairOperator<C, T, L> + 'static,
+    C: TraceColumn + Debug + 'static,
+    T: Debug + 'static,
+    L: Debug + 'static,
+{
+    op.as_any()
+        .downcast_ref::<LuminairWrapper<C, T, L>>()
+        .and_then(|wrapper| (*wrapper.0).as_any().downcast_ref::<O>())
+}
+
 /// A utility trait to convert a `LuminairOperator` into a `Box<dyn Operator>`.
 ///
 /// This simplifies the creation of graph nodes from custom LuminAIR operators by automatically
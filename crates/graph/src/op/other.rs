@@ -1,7 +1,21 @@
 use itertools::Itertools;
+use luminair_air::{
+    components::{
+        add::table::{AddColumn, AddTraceTable},
+        conv2d::table::{Conv2dColumn, Conv2dTraceTable},
+        matmul::table::{MatMulColumn, MatMulTraceTable},
+        mul::table::{MulColumn, MulTraceTable},
+        sum_reduce::table::{SumReduceColumn, SumReduceTraceTable},
+    },
+    DEFAULT_FP_SCALE,
+};
 use luminal::prelude::{petgraph::visit::EdgeRef, *};
+use rustc_hash::FxHashSet;
 
-use super::prim::{CopyFromStwo, CopyToStwo};
+use super::{
+    prim::{CopyFromStwo, CopyToStwo, LuminairRescale},
+    HasProcessTrace, IntoOperator,
+};
 
 /// A Luminal `Compiler` pass that optimizes redundant copy operations.
 ///
@@ -71,3 +85,110 @@ impl Compiler for CopyCompiler {
         }
     }
 }
+
+/// A Luminal `Compiler` pass that inserts `LuminairRescale` operators after products.
+///
+/// `LuminairMul`, `LuminairMatMul` and `LuminairConv2d` keep their products exact, at the sum
+/// of the input scales. This pass tracks which tensors hold such products and rescales them
+/// back to the circuit scale before they reach an operator that expects it (including other
+/// multiplications and graph outputs).
+/// Additions and sum reductions of products are left at the product scale, so that a sum of
+/// products is rounded only once. Products must still fit the signed M31 range at the product
+/// scale, which `gen_trace` checks like any other value.
+/// The rescales target the scale of the graph the compiler is constructed with, and are moved
+/// to the scale of the circuit settings when the trace is generated.
+#[derive(Debug)]
+pub struct RescaleCompiler {
+    /// Fixed-point scale of the graph.
+    fp_scale: u32,
+}
+
+impl RescaleCompiler {
+    /// Creates a new `RescaleCompiler` targeting the given fixed-point scale.
+    pub fn new(fp_scale: u32) -> Self {
+        Self { fp_scale }
+    }
+}
+
+impl Default for RescaleCompiler {
+    /// Creates a `RescaleCompiler` targeting the default fixed-point scale.
+    fn default() -> Self {
+        Self::new(DEFAULT_FP_SCALE)
+    }
+}
+
+impl Compiler for RescaleCompiler {
+    type Output = ();
+
+    /// Executes the rescale insertion pass on the graph.
+    /// Modifies the graph in-place by rerouting product inputs through rescale nodes.
+    fn compile<To: ToIdsMut>(&self, graph: &mut Graph, _ids: To) {
+        // Nodes whose output is at the product scale.
+        let mut products = FxHashSet::default();
+
+        for node in petgraph::algo::toposort(&graph.graph, None).unwrap() {
+            let op = graph.node_weight(node).unwrap();
+            let is_mul = <Box<dyn Operator> as HasProcessTrace<MulColumn, MulTraceTable, ()>>::has_process_trace(op)
+                || <Box<dyn Operator> as HasProcessTrace<
+                    MatMulColumn,
+                    MatMulTraceTable,
+                    (),
+                >>::has_process_trace(op)
+                || <Box<dyn Operator> as HasProcessTrace<
+                    Conv2dColumn,
+                    Conv2dTraceTable,
+                    (),
+                >>::has_process_trace(op);
+            let keeps_products = <Box<dyn Operator> as HasProcessTrace<
+                AddColumn,
+                AddTraceTable,
+                (),
+            >>::has_process_trace(op)
+                || <Box<dyn Operator> as HasProcessTrace<
+                    SumReduceColumn,
+                    SumReduceTraceTable,
+                    (),
+                >>::has_process_trace(op);
+
+            let inputs = graph
+                .edges_directed(node, petgraph::Direction::Incoming)
+                .filter_map(|e| e.weight().as_data().map(|d| (e.id(), e.source(), d)))
+                .collect::<Vec<_>>();
+            let n_products = inputs
+                .iter()
+                .filter(|(_, src, _)| products.contains(src))
+                .count();
+
+            // Operators that add values up keep products if all their inputs are products:
+            // sums of products are exact, unlike comparisons.
+            if n_products > 0 && !(keeps_products && n_products == inputs.len()) {
+                for (edge, src, (input_order, output_order, shape)) in inputs {
+                    if !products.contains(&src) {
+                        continue;
+                    }
+                    // The rescale materializes the view read by the node, which then reads it contiguously.
+                    let rescale = graph
+                        .add_op(LuminairRescale::new(self.fp_scale).into_operator())
+                        .input(src, output_order, shape)
+                        .finish();
+                    graph.remove_edge(edge);
+                    graph.add_edge(
+                        rescale,
+                        node,
+                        Dependency::Data {
+                            input_order,
+                            output_order: 0,
+                            shape: shape.contiguous(),
+                        },
+                    );
+                }
+            } else if n_products > 0 {
+                products.insert(node);
+            }
+
+            if is_mul {
+                products.insert(node);
+            }
+        }
+    }
+}
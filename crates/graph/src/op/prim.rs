@@ -1,29 +1,60 @@
 use luminair_air::{
     components::{
         add::table::{AddColumn, AddTraceTable, AddTraceTableRow},
-        lookups::sin::SinLookup,
-        max_reduce::table::{MaxReduceColumn, MaxReduceTraceTable, MaxReduceTraceTableRow},
+        conv2d::table::{Conv2dColumn, Conv2dTraceTable, Conv2dTraceTableRow},
+        less_than::table::{
+            LessThanColumn, LessThanTraceTable, LessThanTraceTableRow, N_DIFF_BITS,
+        },
+        lookups::{
+            range_check::{split_limbs, RangeCheckLookup},
+            unary::{
+                functions::{self, ExpLookup},
+                LutFunction, UnaryLookup,
+            },
+        },
+        matmul::table::{MatMulColumn, MatMulTraceTable, MatMulTraceTableRow},
+        max_reduce::table::{
+            MaxReduceColumn, MaxReduceTraceTable, MaxReduceTraceTableRow,
+            N_DIFF_BITS as N_MAX_REDUCE_DIFF_BITS,
+        },
+        modulo::table::{
+            ModColumn, ModTraceTable, ModTraceTableRow, N_QUOTIENT_BITS, N_RANGE_BITS,
+        },
         mul::table::{MulColumn, MulTraceTable, MulTraceTableRow},
-        recip::table::{RecipColumn, RecipTraceTable, RecipTraceTableRow},
-        sin::table::{SinColumn, SinTraceTable, SinTraceTableRow},
+        norm::table::{quotient_bits, NormColumn, NormTraceTable, NormTraceTableRow},
+        recip::table::{out_bits, RecipColumn, RecipTraceTable, RecipTraceTableRow},
+        relu::table::{ReluColumn, ReluTraceTable, ReluTraceTableRow},
+        rescale::table::{
+            out_bits as rescale_out_bits, RescaleColumn, RescaleTraceTable, RescaleTraceTableRow,
+        },
+        softmax::table::{
+            SoftmaxColumn, SoftmaxTraceTable, SoftmaxTraceTableRow,
+            N_DIFF_BITS as N_SOFTMAX_DIFF_BITS,
+        },
         sqrt::table::{SqrtColumn, SqrtTraceTable, SqrtTraceTableRow},
         sum_reduce::table::{SumReduceColumn, SumReduceTraceTable, SumReduceTraceTableRow},
+        unary::table::{UnaryColumn, UnaryTraceTable, UnaryTraceTableRow},
     },
+    fixed_point::Fixed,
     pie::NodeInfo,
     DEFAULT_FP_SCALE,
 };
+use luminair_utils::LuminairError;
 use luminal::{
     op::{Function as LFunction, *},
     prelude::{petgraph::visit::EdgeRef, *},
 };
 use num_traits::{identities::Zero, One};
-use numerair::Fixed;
-use std::{ops::Deref, sync::Arc};
+use std::{marker::PhantomData, ops::Deref};
 use stwo_prover::core::fields::m31::{BaseField, M31};
 
 use crate::{
     data::StwoData,
-    utils::{get_buffer_from_tensor, get_index, is},
+    utils::{
+        get_buffer_from_tensor, get_index, get_product_scale, get_scale, get_source_indices,
+        input_mult, is, output_mult, range_check, range_check_limbs, range_check_signed_limbs,
+        resolve_lookup_inputs,
+    },
 };
 
 use super::{IntoOperator, LuminairOperator};
@@ -33,11 +64,16 @@ use super::{IntoOperator, LuminairOperator};
 /// Operator to convert tensor data from standard `Vec<f32>` to `StwoData` (fixed-point).
 /// No-op if the input tensor is already `StwoData`.
 #[derive(Clone, Debug)]
-pub struct CopyToStwo {}
+pub struct CopyToStwo {
+    /// Fixed-point scale the data is converted to.
+    pub(crate) scale: u32,
+}
 impl CopyToStwo {
-    /// Creates a new `CopyToStwo` operator instance.
+    /// Creates a new `CopyToStwo` operator instance, converting at the default scale.
     pub fn new() -> Self {
-        Self {}
+        Self {
+            scale: DEFAULT_FP_SCALE,
+        }
     }
 }
 
@@ -50,7 +86,7 @@ impl Operator for CopyToStwo {
 
         // Convert Vec<f32> to StwoData
         let cpu_data = inp[0].0.borrowed().downcast_ref::<Vec<f32>>().unwrap();
-        vec![Tensor::new(StwoData::from_f32(cpu_data))]
+        vec![Tensor::new(StwoData::from_f32(cpu_data, self.scale))]
     }
 }
 
@@ -87,12 +123,17 @@ impl Operator for CopyFromStwo {
 pub struct LuminairConstant {
     /// The constant value.
     pub value: ConstantValue,
+    /// Fixed-point scale the value is represented at.
+    pub(crate) scale: u32,
 }
 
 impl LuminairConstant {
-    /// Creates a new `LuminairConstant` operator holding the specified value.
+    /// Creates a new `LuminairConstant` operator holding the specified value at the default scale.
     pub fn new(value: ConstantValue) -> Self {
-        Self { value }
+        Self {
+            value,
+            scale: DEFAULT_FP_SCALE,
+        }
     }
 }
 
@@ -108,8 +149,8 @@ impl Operator for LuminairConstant {
 
         // Create and return a single element with the constant value
         let mut data = Vec::with_capacity(1);
-        data.push(Fixed::<DEFAULT_FP_SCALE>::from_f64(value as f64));
-        vec![Tensor::new(StwoData(Arc::new(data)))]
+        data.push(Fixed::from_f64(value as f64, self.scale));
+        vec![Tensor::new(StwoData::new(data, self.scale))]
     }
 }
 
@@ -134,22 +175,13 @@ impl LuminairRecip {
         &self,
         inp: &[(InputTensor, ShapeTracker)],
         trace_mode: bool,
-    ) -> (
-        Vec<Fixed<DEFAULT_FP_SCALE>>,
-        Option<
-            Vec<(
-                Fixed<DEFAULT_FP_SCALE>,
-                Fixed<DEFAULT_FP_SCALE>,
-                Fixed<DEFAULT_FP_SCALE>,
-            )>,
-        >,
-    ) {
+    ) -> (Vec<Fixed>, Option<Vec<(Fixed, Fixed, Fixed)>>) {
         let input = get_buffer_from_tensor(&inp[0].0).unwrap();
         let expr = (inp[0].1.index_expression(), inp[0].1.valid_expression());
 
         let mut stack: Vec<i64> = vec![];
         let output_size = inp[0].1.n_elements().to_usize().unwrap();
-        let mut out_data = vec![Fixed::<DEFAULT_FP_SCALE>::zero(); output_size];
+        let mut out_data = vec![Fixed::zero(); output_size];
 
         // Only allocate for intermediate values if in trace mode
         let mut intermediate_values = if trace_mode {
@@ -160,7 +192,7 @@ impl LuminairRecip {
 
         for (idx, out) in out_data.iter_mut().enumerate() {
             let input_val = get_index(input, &expr, &mut stack, idx);
-            let (out_val, rem_val) = input_val.recip();
+            let (out_val, rem_val) = input_val.recip(input.scale);
             *out = out_val;
 
             // Only collect intermediate values if in trace mode
@@ -173,14 +205,14 @@ impl LuminairRecip {
     }
 }
 
-impl LuminairOperator<RecipColumn, RecipTraceTable, ()> for LuminairRecip {
+impl LuminairOperator<RecipColumn, RecipTraceTable, RangeCheckLookup> for LuminairRecip {
     fn process_trace(
         &mut self,
         inp: Vec<(InputTensor, ShapeTracker)>,
         table: &mut RecipTraceTable,
         node_info: &NodeInfo,
-        _lookup: &mut (),
-    ) -> Vec<Tensor> {
+        lookup: &mut RangeCheckLookup,
+    ) -> Result<Vec<Tensor>, LuminairError> {
         let (out_data, intermediate_values) = self.compute(&inp, true);
         let intermediate_values = intermediate_values.unwrap();
 
@@ -188,79 +220,109 @@ impl LuminairOperator<RecipColumn, RecipTraceTable, ()> for LuminairRecip {
         let input_id: BaseField = node_info.inputs[0].id.into();
         let output_size = inp[0].1.n_elements().to_usize().unwrap();
 
-        let input_mult = if node_info.inputs[0].is_initializer {
-            BaseField::zero()
-        } else {
-            -BaseField::one()
-        };
-        let out_mult = if node_info.output.is_final_output {
-            BaseField::zero()
-        } else {
-            BaseField::one() * BaseField::from_u32_unchecked(node_info.num_consumers)
-        };
+        let input_positions = get_source_indices(&inp[0].1);
 
         for (idx, (input_val, out_val, rem_val)) in intermediate_values.into_iter().enumerate() {
             let is_last_idx: u32 = if idx == (output_size - 1) { 1 } else { 0 };
 
+            // Send the remainder and its gap to |x| to the shared range check.
+            let abs_input = Fixed(input_val.0.abs());
+            range_check(
+                "Recip",
+                node_info.id,
+                &[rem_val.0, abs_input.0 - 1 - rem_val.0],
+                lookup,
+            )?;
+            // Send the limbs of the output magnitude, at most SCALE^2, to the shared range check.
+            let (out_sign, out_limbs) = range_check_signed_limbs(
+                "Recip",
+                node_info.id,
+                idx,
+                out_val.0,
+                out_bits(get_scale(&inp)),
+                lookup,
+            )?;
+
             table.add_row(RecipTraceTableRow {
                 node_id,
                 input_id,
                 idx: idx.into(),
+                input_idx: input_positions[idx].unwrap_or(0).into(),
                 is_last_idx: (is_last_idx).into(),
-                next_idx: (idx + 1).into(),
-                next_node_id: node_id,
-                next_input_id: input_id,
                 input: input_val.to_m31(),
                 out: out_val.to_m31(),
                 rem: rem_val.to_m31(),
-                scale: M31::from_u32_unchecked(1 << DEFAULT_FP_SCALE),
-                input_mult,
-                out_mult,
+                scale: M31::from_u32_unchecked(1 << get_scale(&inp)),
+                abs_input: abs_input.to_m31(),
+                out_sign,
+                out_limbs,
+                input_mult: input_mult(input_positions[idx]),
+                out_mult: output_mult(node_info, idx),
+                rem_mult: M31::one(),
             });
         }
 
-        vec![Tensor::new(StwoData(Arc::new(out_data)))]
+        Ok(vec![Tensor::new(StwoData::new(out_data, get_scale(&inp)))])
     }
 }
 
 impl Operator for LuminairRecip {
     fn process(&mut self, inp: Vec<(InputTensor, ShapeTracker)>) -> Vec<Tensor> {
         let (out_data, _) = self.compute(&inp, false);
-        vec![Tensor::new(StwoData(Arc::new(out_data)))]
+        vec![Tensor::new(StwoData::new(out_data, get_scale(&inp)))]
     }
 }
 
-/// LuminAIR operator for element-wise sine (`sin(x)`).
+/// LuminAIR operator for the element-wise application of a unary LUT function `F` (`f(x)`).
 ///
 /// Implements both the standard `Operator` trait for graph execution and the
-/// `LuminairOperator` trait to generate trace entries for `SinTraceTable`.
-/// This operator interacts with the `SinLookup` component during trace generation
+/// `LuminairOperator` trait to generate trace entries for `UnaryTraceTable`.
+/// This operator interacts with the `UnaryLookup` component of `F` during trace generation
 /// to record input value occurrences for the lookup argument.
-#[derive(Debug, Clone, Default, PartialEq)]
-pub(crate) struct LuminairSin {}
+/// Inputs outside the domain of `F` fail trace generation with the error of
+/// `LutFunction::check_domain`. With an interpolated LUT, trace generation outputs
+/// the interpolation of `F` proven from its entries.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct LuminairUnary<F: LutFunction> {
+    _function: PhantomData<F>,
+}
 
-impl LuminairSin {
-    /// Creates a new `LuminairSin` operator instance.
+/// LuminAIR operator for element-wise sine (`sin(x)`).
+pub(crate) type LuminairSin = LuminairUnary<functions::Sin>;
+/// LuminAIR operator for element-wise base-2 exponential (`2^x`).
+pub(crate) type LuminairExp2 = LuminairUnary<functions::Exp2>;
+/// LuminAIR operator for element-wise base-2 logarithm (`log2(x)`).
+pub(crate) type LuminairLog2 = LuminairUnary<functions::Log2>;
+/// LuminAIR operator for element-wise hyperbolic tangent (`tanh(x)`).
+pub(crate) type LuminairTanh = LuminairUnary<functions::Tanh>;
+/// LuminAIR operator for element-wise logistic sigmoid (`1 / (1 + e^-x)`).
+pub(crate) type LuminairSigmoid = LuminairUnary<functions::Sigmoid>;
+/// LuminAIR operator for element-wise GELU, in its tanh approximation.
+pub(crate) type LuminairGelu = LuminairUnary<functions::Gelu>;
+/// LuminAIR operator for element-wise SiLU (`x * sigmoid(x)`).
+pub(crate) type LuminairSilu = LuminairUnary<functions::Silu>;
+
+impl<F: LutFunction> LuminairUnary<F> {
+    /// Creates a new `LuminairUnary` operator instance.
     pub fn new() -> Self {
-        Self {}
+        Self {
+            _function: PhantomData,
+        }
     }
 }
 
-impl LuminairSin {
+impl<F: LutFunction> LuminairUnary<F> {
     fn compute(
         &self,
         inp: &[(InputTensor, ShapeTracker)],
         trace_mode: bool,
-    ) -> (
-        Vec<Fixed<DEFAULT_FP_SCALE>>,
-        Option<Vec<(Fixed<DEFAULT_FP_SCALE>, Fixed<DEFAULT_FP_SCALE>)>>,
-    ) {
+    ) -> (Vec<Fixed>, Option<Vec<(Fixed, Fixed)>>) {
         let input = get_buffer_from_tensor(&inp[0].0).unwrap();
         let expr = (inp[0].1.index_expression(), inp[0].1.valid_expression());
 
         let mut stack: Vec<i64> = vec![];
         let output_size = inp[0].1.n_elements().to_usize().unwrap();
-        let mut out_data = vec![Fixed::<DEFAULT_FP_SCALE>::zero(); output_size];
+        let mut out_data = vec![Fixed::zero(); output_size];
 
         // Only allocate for intermediate values if in trace mode
         let mut intermediate_values = if trace_mode {
@@ -271,7 +333,8 @@ impl LuminairSin {
 
         for (idx, out) in out_data.iter_mut().enumerate() {
             let input_val = get_index(input, &expr, &mut stack, idx);
-            let out_val = Fixed::<DEFAULT_FP_SCALE>::from_f64(input_val.to_f64().sin());
+            // Inputs outside the domain of the function evaluate to zero, see `process_trace`.
+            let out_val = F::eval_fixed(input_val, input.scale);
             *out = out_val;
 
             // Only collect intermediate values if in trace mode
@@ -284,67 +347,84 @@ impl LuminairSin {
     }
 }
 
-impl LuminairOperator<SinColumn, SinTraceTable, SinLookup> for LuminairSin {
+impl<F: LutFunction> LuminairOperator<UnaryColumn, UnaryTraceTable, UnaryLookup<F>>
+    for LuminairUnary<F>
+{
     fn process_trace(
         &mut self,
         inp: Vec<(InputTensor, ShapeTracker)>,
-        table: &mut SinTraceTable,
+        table: &mut UnaryTraceTable,
         node_info: &NodeInfo,
-        lookup: &mut SinLookup,
-    ) -> Vec<Tensor> {
-        let (out_data, intermediate_values) = self.compute(&inp, true);
+        lookup: &mut UnaryLookup<F>,
+    ) -> Result<Vec<Tensor>, LuminairError> {
+        let (mut out_data, intermediate_values) = self.compute(&inp, true);
         let intermediate_values = intermediate_values.unwrap();
 
         let node_id: BaseField = node_info.id.into();
         let input_id: BaseField = node_info.inputs[0].id.into();
         let output_size = inp[0].1.n_elements().to_usize().unwrap();
 
-        let input_mult = if node_info.inputs[0].is_initializer {
-            BaseField::zero()
-        } else {
-            -BaseField::one()
-        };
-        let out_mult = if node_info.output.is_final_output {
-            BaseField::zero()
+        let input_positions = get_source_indices(&inp[0].1);
+
+        // Inputs outside the domain are rejected, whatever the policy of the lookup.
+        for (idx, (input_val, _)) in intermediate_values.iter().enumerate() {
+            F::check_domain(*input_val, get_scale(&inp), node_info.id, idx)?;
+        }
+
+        let inputs = intermediate_values
+            .iter()
+            .map(|(x, _)| *x)
+            .collect::<Vec<_>>();
+        let lut_inputs = resolve_lookup_inputs(node_info.id, &inputs, lookup)?;
+
+        let range_check_mult = if lookup.layout.interpolation.is_some() {
+            M31::one()
         } else {
-            BaseField::one() * BaseField::from_u32_unchecked(node_info.num_consumers)
+            M31::zero()
         };
 
-        for (idx, (input_val, out_val)) in intermediate_values.into_iter().enumerate() {
+        for (idx, ((input_val, _), clamped)) in
+            intermediate_values.into_iter().zip(lut_inputs).enumerate()
+        {
             let is_last_idx: u32 = if idx == (output_size - 1) { 1 } else { 0 };
 
-            table.add_row(SinTraceTableRow {
+            // The output is the one proven from the LUT entry: a clamped input takes the output
+            // of the table bound it is clamped to, and an interpolated one is rounded.
+            let output = lookup.output(clamped.lut_input);
+            out_data[idx] = output.out;
+
+            table.add_row(UnaryTraceTableRow {
                 node_id,
                 input_id,
                 idx: idx.into(),
+                input_idx: input_positions[idx].unwrap_or(0).into(),
                 is_last_idx: (is_last_idx).into(),
-                next_idx: (idx + 1).into(),
-                next_node_id: node_id,
-                next_input_id: input_id,
                 input: input_val.to_m31(),
-                out: out_val.to_m31(),
-                input_mult,
-                out_mult,
+                out: output.out.to_m31(),
+                lut_input: clamped.lut_input.to_m31(),
+                clamp_low: (clamped.low as u32).into(),
+                clamp_high: (clamped.high as u32).into(),
+                clamp_dist: clamped.dist.into(),
+                clamp_dist_bits: clamped.dist_bits(),
+                offset: Fixed(output.offset).to_m31(),
+                base_out: output.base.to_m31(),
+                slope: output.slope.to_m31(),
+                rem: Fixed(output.rem).to_m31(),
+                input_mult: input_mult(input_positions[idx]),
+                out_mult: output_mult(node_info, idx),
                 lookup_mult: M31::one(),
+                range_check_mult,
             });
-
-            // Update multiplicities of the lookup.
-            // Allows you to track the occurrence of a specific Sin operation.
-            let mult_address = lookup
-                .layout
-                .find_index(input_val.0)
-                .expect("Value should fit in range.");
-            lookup.multiplicities.increase_at(mult_address);
         }
 
-        vec![Tensor::new(StwoData(Arc::new(out_data)))]
+        Ok(vec![Tensor::new(StwoData::new(out_data, get_scale(&inp)))])
     }
 }
 
-impl Operator for LuminairSin {
+impl<F: LutFunction> Operator for LuminairUnary<F> {
     fn process(&mut self, inp: Vec<(InputTensor, ShapeTracker)>) -> Vec<Tensor> {
         let (out_data, _) = self.compute(&inp, false);
-        vec![Tensor::new(StwoData(Arc::new(out_data)))]
+        vec![Tensor::new(StwoData::new(out_data, get_scale(&inp)))]
     }
 }
 
@@ -367,22 +447,13 @@ impl LuminairSqrt {
         &self,
         inp: &[(InputTensor, ShapeTracker)],
         trace_mode: bool,
-    ) -> (
-        Vec<Fixed<DEFAULT_FP_SCALE>>,
-        Option<
-            Vec<(
-                Fixed<DEFAULT_FP_SCALE>,
-                Fixed<DEFAULT_FP_SCALE>,
-                Fixed<DEFAULT_FP_SCALE>,
-            )>,
-        >,
-    ) {
+    ) -> (Vec<Fixed>, Option<Vec<(Fixed, Fixed, Fixed)>>) {
         let input = get_buffer_from_tensor(&inp[0].0).unwrap();
         let expr = (inp[0].1.index_expression(), inp[0].1.valid_expression());
 
         let mut stack: Vec<i64> = vec![];
         let output_size = inp[0].1.n_elements().to_usize().unwrap();
-        let mut out_data = vec![Fixed::<DEFAULT_FP_SCALE>::zero(); output_size];
+        let mut out_data = vec![Fixed::zero(); output_size];
 
         // Only allocate for intermediate values if in trace mode
         let mut intermediate_values = if trace_mode {
@@ -393,7 +464,7 @@ impl LuminairSqrt {
 
         for (idx, out) in out_data.iter_mut().enumerate() {
             let input_val = get_index(input, &expr, &mut stack, idx);
-            let (out_val, rem_val) = input_val.sqrt();
+            let (out_val, rem_val) = input_val.sqrt(input.scale);
             *out = out_val;
 
             // Only collect intermediate values if in trace mode
@@ -406,14 +477,14 @@ impl LuminairSqrt {
     }
 }
 
-impl LuminairOperator<SqrtColumn, SqrtTraceTable, ()> for LuminairSqrt {
+impl LuminairOperator<SqrtColumn, SqrtTraceTable, RangeCheckLookup> for LuminairSqrt {
     fn process_trace(
         &mut self,
         inp: Vec<(InputTensor, ShapeTracker)>,
         table: &mut SqrtTraceTable,
         node_info: &NodeInfo,
-        _lookup: &mut (),
-    ) -> Vec<Tensor> {
+        lookup: &mut RangeCheckLookup,
+    ) -> Result<Vec<Tensor>, LuminairError> {
         let (out_data, intermediate_values) = self.compute(&inp, true);
         let intermediate_values = intermediate_values.unwrap();
 
@@ -421,45 +492,263 @@ impl LuminairOperator<SqrtColumn, SqrtTraceTable, ()> for LuminairSqrt {
         let input_id: BaseField = node_info.inputs[0].id.into();
         let output_size = inp[0].1.n_elements().to_usize().unwrap();
 
-        let input_mult = if node_info.inputs[0].is_initializer {
-            BaseField::zero()
+        let input_positions = get_source_indices(&inp[0].1);
+
+        for (idx, (input_val, out_val, rem_val)) in intermediate_values.into_iter().enumerate() {
+            let is_last_idx: u32 = if idx == (output_size - 1) { 1 } else { 0 };
+
+            // Send the remainder, its gap to 2 * out and the root to the shared range check.
+            range_check(
+                "Sqrt",
+                node_info.id,
+                &[rem_val.0, 2 * out_val.0 - rem_val.0, out_val.0],
+                lookup,
+            )?;
+
+            table.add_row(SqrtTraceTableRow {
+                node_id,
+                input_id,
+                idx: idx.into(),
+                input_idx: input_positions[idx].unwrap_or(0).into(),
+                is_last_idx: (is_last_idx).into(),
+                input: input_val.to_m31(),
+                out: out_val.to_m31(),
+                rem: rem_val.to_m31(),
+                scale: M31::from_u32_unchecked(1 << get_scale(&inp)),
+                input_mult: input_mult(input_positions[idx]),
+                out_mult: output_mult(node_info, idx),
+                rem_mult: M31::one(),
+            });
+        }
+
+        Ok(vec![Tensor::new(StwoData::new(out_data, get_scale(&inp)))])
+    }
+}
+
+impl Operator for LuminairSqrt {
+    fn process(&mut self, inp: Vec<(InputTensor, ShapeTracker)>) -> Vec<Tensor> {
+        let (out_data, _) = self.compute(&inp, false);
+        vec![Tensor::new(StwoData::new(out_data, get_scale(&inp)))]
+    }
+}
+
+/// LuminAIR operator for element-wise ReLU, `max(x, 0)`.
+///
+/// It replaces the `LessThan`/`Mul` subgraph luminal lowers `relu` to, and is inserted
+/// by the `ReluCompiler`. The output keeps the scale of the input.
+/// Implements both the standard `Operator` trait for graph execution and the
+/// `LuminairOperator` trait to generate trace entries for `ReluTraceTable`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct LuminairRelu {}
+
+impl LuminairRelu {
+    /// Creates a new `LuminairRelu` operator instance.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl LuminairRelu {
+    fn compute(
+        &self,
+        inp: &[(InputTensor, ShapeTracker)],
+        trace_mode: bool,
+    ) -> (Vec<Fixed>, Option<Vec<(Fixed, Fixed, Fixed, Fixed)>>) {
+        let input = get_buffer_from_tensor(&inp[0].0).unwrap();
+        let expr = (inp[0].1.index_expression(), inp[0].1.valid_expression());
+
+        let mut stack: Vec<i64> = vec![];
+        let output_size = inp[0].1.n_elements().to_usize().unwrap();
+        let mut out_data = vec![Fixed::zero(); output_size];
+
+        // Only allocate for intermediate values if in trace mode
+        let mut intermediate_values = if trace_mode {
+            Some(Vec::with_capacity(output_size))
         } else {
-            -BaseField::one()
+            None
         };
-        let out_mult = if node_info.output.is_final_output {
-            BaseField::zero()
+
+        for (idx, out) in out_data.iter_mut().enumerate() {
+            let input_val = get_index(input, &expr, &mut stack, idx);
+            let (out_val, sign, magnitude) = input_val.relu();
+            *out = out_val;
+
+            // Only collect intermediate values if in trace mode
+            if let Some(values) = &mut intermediate_values {
+                values.push((input_val, out_val, sign, magnitude));
+            }
+        }
+
+        (out_data, intermediate_values)
+    }
+}
+
+impl LuminairOperator<ReluColumn, ReluTraceTable, RangeCheckLookup> for LuminairRelu {
+    fn process_trace(
+        &mut self,
+        inp: Vec<(InputTensor, ShapeTracker)>,
+        table: &mut ReluTraceTable,
+        node_info: &NodeInfo,
+        lookup: &mut RangeCheckLookup,
+    ) -> Result<Vec<Tensor>, LuminairError> {
+        let (out_data, intermediate_values) = self.compute(&inp, true);
+        let intermediate_values = intermediate_values.unwrap();
+
+        let node_id: BaseField = node_info.id.into();
+        let input_id: BaseField = node_info.inputs[0].id.into();
+        let output_size = inp[0].1.n_elements().to_usize().unwrap();
+
+        let input_positions = get_source_indices(&inp[0].1);
+
+        for (idx, (input_val, out_val, sign, magnitude)) in
+            intermediate_values.into_iter().enumerate()
+        {
+            let is_last_idx: u32 = if idx == (output_size - 1) { 1 } else { 0 };
+
+            // Send the magnitude to the shared range check, which pins the sign bit.
+            range_check("Relu", node_info.id, &[magnitude.0], lookup)?;
+
+            table.add_row(ReluTraceTableRow {
+                node_id,
+                input_id,
+                idx: idx.into(),
+                input_idx: input_positions[idx].unwrap_or(0).into(),
+                is_last_idx: (is_last_idx).into(),
+                input: input_val.to_m31(),
+                out: out_val.to_m31(),
+                magnitude: magnitude.to_m31(),
+                sign: sign.to_m31(),
+                input_mult: input_mult(input_positions[idx]),
+                out_mult: output_mult(node_info, idx),
+                magnitude_mult: M31::one(),
+            });
+        }
+
+        Ok(vec![Tensor::new(StwoData::new(out_data, get_scale(&inp)))])
+    }
+}
+
+impl Operator for LuminairRelu {
+    fn process(&mut self, inp: Vec<(InputTensor, ShapeTracker)>) -> Vec<Tensor> {
+        let (out_data, _) = self.compute(&inp, false);
+        vec![Tensor::new(StwoData::new(out_data, get_scale(&inp)))]
+    }
+}
+
+/// LuminAIR operator moving a tensor to another fixed-point scale.
+///
+/// Computes `round(x * 2^(scale - s_in))`, where `s_in` is the scale of the input.
+/// It is inserted by the `RescaleCompiler` to bring products back to the circuit scale.
+/// Implements both the standard `Operator` trait for graph execution and the
+/// `LuminairOperator` trait to generate trace entries for `RescaleTraceTable`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct LuminairRescale {
+    /// The fixed-point scale of the output.
+    pub(crate) scale: u32,
+}
+
+impl LuminairRescale {
+    /// Creates a new `LuminairRescale` operator to the given output `scale`.
+    pub fn new(scale: u32) -> Self {
+        Self { scale }
+    }
+}
+
+impl LuminairRescale {
+    fn compute(
+        &self,
+        inp: &[(InputTensor, ShapeTracker)],
+        trace_mode: bool,
+    ) -> (Vec<Fixed>, Option<Vec<(Fixed, Fixed, Fixed)>>) {
+        let input = get_buffer_from_tensor(&inp[0].0).unwrap();
+        let expr = (inp[0].1.index_expression(), inp[0].1.valid_expression());
+
+        let mut stack: Vec<i64> = vec![];
+        let output_size = inp[0].1.n_elements().to_usize().unwrap();
+        let mut out_data = vec![Fixed::zero(); output_size];
+
+        // Only allocate for intermediate values if in trace mode
+        let mut intermediate_values = if trace_mode {
+            Some(Vec::with_capacity(output_size))
         } else {
-            BaseField::one() * BaseField::from_u32_unchecked(node_info.num_consumers)
+            None
         };
 
+        for (idx, out) in out_data.iter_mut().enumerate() {
+            let input_val = get_index(input, &expr, &mut stack, idx);
+            let (out_val, rem_val) = input_val.rescale(input.scale, self.scale);
+            *out = out_val;
+
+            // Only collect intermediate values if in trace mode
+            if let Some(values) = &mut intermediate_values {
+                values.push((input_val, out_val, rem_val));
+            }
+        }
+
+        (out_data, intermediate_values)
+    }
+}
+
+impl LuminairOperator<RescaleColumn, RescaleTraceTable, RangeCheckLookup> for LuminairRescale {
+    fn process_trace(
+        &mut self,
+        inp: Vec<(InputTensor, ShapeTracker)>,
+        table: &mut RescaleTraceTable,
+        node_info: &NodeInfo,
+        lookup: &mut RangeCheckLookup,
+    ) -> Result<Vec<Tensor>, LuminairError> {
+        let (out_data, intermediate_values) = self.compute(&inp, true);
+        let intermediate_values = intermediate_values.unwrap();
+
+        let node_id: BaseField = node_info.id.into();
+        let input_id: BaseField = node_info.inputs[0].id.into();
+        let output_size = inp[0].1.n_elements().to_usize().unwrap();
+
+        let input_positions = get_source_indices(&inp[0].1);
+        let shift = get_scale(&inp).saturating_sub(self.scale);
+        let max_rem = (1i64 << shift) - 1;
+
         for (idx, (input_val, out_val, rem_val)) in intermediate_values.into_iter().enumerate() {
             let is_last_idx: u32 = if idx == (output_size - 1) { 1 } else { 0 };
 
-            table.add_row(SqrtTraceTableRow {
+            // Send the remainder and its gap to the maximal remainder to the shared range check.
+            let range_checked = [rem_val.0, max_rem - rem_val.0];
+            range_check("Rescale", node_info.id, &range_checked, lookup)?;
+            // Send the limbs of the output magnitude, below 2^(30 - shift), to the range check.
+            let (out_sign, out_limbs) = range_check_signed_limbs(
+                "Rescale",
+                node_info.id,
+                idx,
+                out_val.0,
+                rescale_out_bits(shift),
+                lookup,
+            )?;
+
+            table.add_row(RescaleTraceTableRow {
                 node_id,
                 input_id,
                 idx: idx.into(),
+                input_idx: input_positions[idx].unwrap_or(0).into(),
                 is_last_idx: (is_last_idx).into(),
-                next_idx: (idx + 1).into(),
-                next_node_id: node_id,
-                next_input_id: input_id,
                 input: input_val.to_m31(),
                 out: out_val.to_m31(),
                 rem: rem_val.to_m31(),
-                scale: M31::from_u32_unchecked(1 << DEFAULT_FP_SCALE),
-                input_mult,
-                out_mult,
+                out_sign,
+                out_limbs,
+                input_mult: input_mult(input_positions[idx]),
+                out_mult: output_mult(node_info, idx),
+                rem_mult: M31::one(),
             });
         }
 
-        vec![Tensor::new(StwoData(Arc::new(out_data)))]
+        Ok(vec![Tensor::new(StwoData::new(out_data, self.scale))])
     }
 }
 
-impl Operator for LuminairSqrt {
+impl Operator for LuminairRescale {
     fn process(&mut self, inp: Vec<(InputTensor, ShapeTracker)>) -> Vec<Tensor> {
         let (out_data, _) = self.compute(&inp, false);
-        vec![Tensor::new(StwoData(Arc::new(out_data)))]
+        vec![Tensor::new(StwoData::new(out_data, self.scale))]
     }
 }
 
@@ -484,16 +773,7 @@ impl LuminairAdd {
         &self,
         inp: &[(InputTensor, ShapeTracker)],
         trace_mode: bool,
-    ) -> (
-        Vec<Fixed<DEFAULT_FP_SCALE>>,
-        Option<
-            Vec<(
-                Fixed<DEFAULT_FP_SCALE>,
-                Fixed<DEFAULT_FP_SCALE>,
-                Fixed<DEFAULT_FP_SCALE>,
-            )>,
-        >,
-    ) {
+    ) -> (Vec<Fixed>, Option<Vec<(Fixed, Fixed, Fixed)>>) {
         let (lhs, rhs) = (
             get_buffer_from_tensor(&inp[0].0).unwrap(),
             get_buffer_from_tensor(&inp[1].0).unwrap(),
@@ -503,7 +783,7 @@ impl LuminairAdd {
 
         let mut stack: Vec<i64> = vec![];
         let output_size = inp[0].1.n_elements().to_usize().unwrap();
-        let mut out_data = vec![Fixed::<DEFAULT_FP_SCALE>::zero(); output_size];
+        let mut out_data = vec![Fixed::zero(); output_size];
 
         // Only allocate for intermediate values if in trace mode
         let mut intermediate_values = if trace_mode {
@@ -534,7 +814,7 @@ impl LuminairOperator<AddColumn, AddTraceTable, ()> for LuminairAdd {
         table: &mut AddTraceTable,
         node_info: &NodeInfo,
         _lookup: &mut (),
-    ) -> Vec<Tensor> {
+    ) -> Result<Vec<Tensor>, LuminairError> {
         let (out_data, intermediate_values) = self.compute(&inp, true);
         let intermediate_values = intermediate_values.unwrap();
 
@@ -543,21 +823,8 @@ impl LuminairOperator<AddColumn, AddTraceTable, ()> for LuminairAdd {
         let lhs_id: BaseField = node_info.inputs[0].id.into();
         let rhs_id: BaseField = node_info.inputs[1].id.into();
 
-        let lhs_mult = if node_info.inputs[0].is_initializer {
-            BaseField::zero()
-        } else {
-            -BaseField::one()
-        };
-        let rhs_mult = if node_info.inputs[1].is_initializer {
-            BaseField::zero()
-        } else {
-            -BaseField::one()
-        };
-        let out_mult = if node_info.output.is_final_output {
-            BaseField::zero()
-        } else {
-            BaseField::one() * BaseField::from_u32_unchecked(node_info.num_consumers)
-        };
+        let lhs_positions = get_source_indices(&inp[0].1);
+        let rhs_positions = get_source_indices(&inp[1].1);
 
         for (idx, (lhs_val, rhs_val, out_val)) in intermediate_values.into_iter().enumerate() {
             let is_last_idx: u32 = if idx == (output_size - 1) { 1 } else { 0 };
@@ -567,33 +834,33 @@ impl LuminairOperator<AddColumn, AddTraceTable, ()> for LuminairAdd {
                 lhs_id,
                 rhs_id,
                 idx: idx.into(),
+                lhs_idx: lhs_positions[idx].unwrap_or(0).into(),
+                rhs_idx: rhs_positions[idx].unwrap_or(0).into(),
                 is_last_idx: (is_last_idx).into(),
-                next_idx: (idx + 1).into(),
-                next_node_id: node_id,
-                next_lhs_id: lhs_id,
-                next_rhs_id: rhs_id,
                 lhs: lhs_val.to_m31(),
                 rhs: rhs_val.to_m31(),
                 out: out_val.to_m31(),
-                lhs_mult,
-                rhs_mult,
-                out_mult,
+                lhs_mult: input_mult(lhs_positions[idx]),
+                rhs_mult: input_mult(rhs_positions[idx]),
+                out_mult: output_mult(node_info, idx),
             })
         }
 
-        vec![Tensor::new(StwoData(Arc::new(out_data)))]
+        Ok(vec![Tensor::new(StwoData::new(out_data, get_scale(&inp)))])
     }
 }
 
 impl Operator for LuminairAdd {
     fn process(&mut self, inp: Vec<(InputTensor, ShapeTracker)>) -> Vec<Tensor> {
         let (out_data, _) = self.compute(&inp, false);
-        vec![Tensor::new(StwoData(Arc::new(out_data)))]
+        vec![Tensor::new(StwoData::new(out_data, get_scale(&inp)))]
     }
 }
 
 /// LuminAIR operator for element-wise multiplication (`a * b`).
 ///
+/// The product is exact: its scale is the sum of the input scales, until a
+/// `LuminairRescale` brings it back to the circuit scale.
 /// Implements both the standard `Operator` trait for graph execution and the
 /// `LuminairOperator` trait to generate trace entries for `MulTraceTable`.
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -611,17 +878,7 @@ impl LuminairMul {
         &self,
         inp: &[(InputTensor, ShapeTracker)],
         trace_mode: bool,
-    ) -> (
-        Vec<Fixed<DEFAULT_FP_SCALE>>,
-        Option<
-            Vec<(
-                Fixed<DEFAULT_FP_SCALE>,
-                Fixed<DEFAULT_FP_SCALE>,
-                Fixed<DEFAULT_FP_SCALE>,
-                Fixed<DEFAULT_FP_SCALE>,
-            )>,
-        >,
-    ) {
+    ) -> (Vec<Fixed>, Option<Vec<(Fixed, Fixed, Fixed)>>) {
         let (lhs, rhs) = (
             get_buffer_from_tensor(&inp[0].0).unwrap(),
             get_buffer_from_tensor(&inp[1].0).unwrap(),
@@ -631,7 +888,7 @@ impl LuminairMul {
 
         let mut stack: Vec<i64> = vec![];
         let output_size = inp[0].1.n_elements().to_usize().unwrap();
-        let mut out_data = vec![Fixed::<DEFAULT_FP_SCALE>::zero(); output_size];
+        let mut out_data = vec![Fixed::zero(); output_size];
 
         // Only allocate for intermediate values if in trace mode
         let mut intermediate_values = if trace_mode {
@@ -643,12 +900,12 @@ impl LuminairMul {
         for (idx, out) in out_data.iter_mut().enumerate() {
             let lhs_val = get_index(lhs, &lexpr, &mut stack, idx);
             let rhs_val = get_index(rhs, &rexpr, &mut stack, idx);
-            let (out_val, rem_val) = lhs_val * rhs_val;
+            let out_val = Fixed(lhs_val.0 * rhs_val.0);
             *out = out_val;
 
             // Only collect intermediate values if in trace mode
             if let Some(values) = &mut intermediate_values {
-                values.push((lhs_val, rhs_val, out_val, rem_val));
+                values.push((lhs_val, rhs_val, out_val));
             }
         }
 
@@ -663,7 +920,7 @@ impl LuminairOperator<MulColumn, MulTraceTable, ()> for LuminairMul {
         table: &mut MulTraceTable,
         node_info: &NodeInfo,
         _lookup: &mut (),
-    ) -> Vec<Tensor> {
+    ) -> Result<Vec<Tensor>, LuminairError> {
         let (out_data, intermediate_values) = self.compute(&inp, true);
         let intermediate_values = intermediate_values.unwrap();
 
@@ -672,25 +929,10 @@ impl LuminairOperator<MulColumn, MulTraceTable, ()> for LuminairMul {
         let lhs_id: BaseField = node_info.inputs[0].id.into();
         let rhs_id: BaseField = node_info.inputs[1].id.into();
 
-        let lhs_mult = if node_info.inputs[0].is_initializer {
-            BaseField::zero()
-        } else {
-            -BaseField::one()
-        };
-        let rhs_mult = if node_info.inputs[1].is_initializer {
-            BaseField::zero()
-        } else {
-            -BaseField::one()
-        };
-        let out_mult = if node_info.output.is_final_output {
-            BaseField::zero()
-        } else {
-            BaseField::one() * BaseField::from_u32_unchecked(node_info.num_consumers)
-        };
+        let lhs_positions = get_source_indices(&inp[0].1);
+        let rhs_positions = get_source_indices(&inp[1].1);
 
-        for (idx, (lhs_val, rhs_val, out_val, rem_val)) in
-            intermediate_values.into_iter().enumerate()
-        {
+        for (idx, (lhs_val, rhs_val, out_val)) in intermediate_values.into_iter().enumerate() {
             let is_last_idx: u32 = if idx == (output_size - 1) { 1 } else { 0 };
 
             table.add_row(MulTraceTableRow {
@@ -698,66 +940,337 @@ impl LuminairOperator<MulColumn, MulTraceTable, ()> for LuminairMul {
                 lhs_id,
                 rhs_id,
                 idx: idx.into(),
+                lhs_idx: lhs_positions[idx].unwrap_or(0).into(),
+                rhs_idx: rhs_positions[idx].unwrap_or(0).into(),
                 is_last_idx: (is_last_idx).into(),
-                next_idx: (idx + 1).into(),
-                next_node_id: node_id,
-                next_lhs_id: lhs_id,
-                next_rhs_id: rhs_id,
                 lhs: lhs_val.to_m31(),
                 rhs: rhs_val.to_m31(),
                 out: out_val.to_m31(),
-                rem: rem_val.to_m31(),
-                lhs_mult,
-                rhs_mult,
-                out_mult,
+                lhs_mult: input_mult(lhs_positions[idx]),
+                rhs_mult: input_mult(rhs_positions[idx]),
+                out_mult: output_mult(node_info, idx),
             })
         }
 
-        vec![Tensor::new(StwoData(Arc::new(out_data)))]
+        Ok(vec![Tensor::new(StwoData::new(
+            out_data,
+            get_product_scale(&inp),
+        ))])
     }
 }
 
 impl Operator for LuminairMul {
     fn process(&mut self, inp: Vec<(InputTensor, ShapeTracker)>) -> Vec<Tensor> {
         let (out_data, _) = self.compute(&inp, false);
-        vec![Tensor::new(StwoData(Arc::new(out_data)))]
+        vec![Tensor::new(StwoData::new(
+            out_data,
+            get_product_scale(&inp),
+        ))]
     }
 }
 
-// ================== REDUCE ==================
-
-/// LuminAIR operator for sum reduction along a specified dimension.
+/// LuminAIR operator for element-wise less-than comparison (`a < b`).
 ///
+/// Outputs `1.0` where `a < b` and `0.0` otherwise, in fixed-point.
 /// Implements both the standard `Operator` trait for graph execution and the
-/// `LuminairOperator` trait to generate trace entries for `SumReduceTraceTable`,
-/// capturing the accumulation process step-by-step.
+/// `LuminairOperator` trait to generate trace entries for `LessThanTraceTable`.
 #[derive(Debug, Clone, Default, PartialEq)]
-struct LuminairSumReduce(pub usize);
+struct LuminairLessThan {}
 
-impl LuminairSumReduce {
-    /// Creates a new `LuminairSumReduce` operator instance for the given reduction dimension.
-    pub fn new(value: usize) -> Self {
-        Self(value)
+impl LuminairLessThan {
+    /// Creates a new `LuminairLessThan` operator instance.
+    pub fn new() -> Self {
+        Self {}
     }
 }
 
-impl LuminairSumReduce {
+impl LuminairLessThan {
     fn compute(
         &self,
         inp: &[(InputTensor, ShapeTracker)],
         trace_mode: bool,
-    ) -> (
-        Vec<Fixed<DEFAULT_FP_SCALE>>,
-        Option<
-            Vec<(
-                usize,
-                Fixed<DEFAULT_FP_SCALE>,
-                Fixed<DEFAULT_FP_SCALE>,
-                Fixed<DEFAULT_FP_SCALE>,
-                Fixed<DEFAULT_FP_SCALE>,
-                BaseField,
-            )>,
-        >,
+    ) -> (Vec<Fixed>, Option<Vec<(Fixed, Fixed, Fixed, Fixed)>>) {
+        let (lhs, rhs) = (
+            get_buffer_from_tensor(&inp[0].0).unwrap(),
+            get_buffer_from_tensor(&inp[1].0).unwrap(),
+        );
+        let lexpr = (inp[0].1.index_expression(), inp[0].1.valid_expression());
+        let rexpr = (inp[1].1.index_expression(), inp[1].1.valid_expression());
+
+        let mut stack: Vec<i64> = vec![];
+        let output_size = inp[0].1.n_elements().to_usize().unwrap();
+        let mut out_data = vec![Fixed::zero(); output_size];
+
+        // Only allocate for intermediate values if in trace mode
+        let mut intermediate_values = if trace_mode {
+            Some(Vec::with_capacity(output_size))
+        } else {
+            None
+        };
+
+        for (idx, out) in out_data.iter_mut().enumerate() {
+            let lhs_val = get_index(lhs, &lexpr, &mut stack, idx);
+            let rhs_val = get_index(rhs, &rexpr, &mut stack, idx);
+            // The difference witnesses the comparison and is non-negative in both branches.
+            let (out_val, diff_val) = if lhs_val.0 < rhs_val.0 {
+                (Fixed(1 << lhs.scale), Fixed(rhs_val.0 - lhs_val.0 - 1))
+            } else {
+                (Fixed::zero(), Fixed(lhs_val.0 - rhs_val.0))
+            };
+            *out = out_val;
+
+            // Only collect intermediate values if in trace mode
+            if let Some(values) = &mut intermediate_values {
+                values.push((lhs_val, rhs_val, out_val, diff_val));
+            }
+        }
+
+        (out_data, intermediate_values)
+    }
+}
+
+impl LuminairOperator<LessThanColumn, LessThanTraceTable, RangeCheckLookup> for LuminairLessThan {
+    fn process_trace(
+        &mut self,
+        inp: Vec<(InputTensor, ShapeTracker)>,
+        table: &mut LessThanTraceTable,
+        node_info: &NodeInfo,
+        lookup: &mut RangeCheckLookup,
+    ) -> Result<Vec<Tensor>, LuminairError> {
+        let (out_data, intermediate_values) = self.compute(&inp, true);
+        let intermediate_values = intermediate_values.unwrap();
+
+        let output_size = inp[0].1.n_elements().to_usize().unwrap();
+        let node_id: BaseField = node_info.id.into();
+        let lhs_id: BaseField = node_info.inputs[0].id.into();
+        let rhs_id: BaseField = node_info.inputs[1].id.into();
+
+        let lhs_positions = get_source_indices(&inp[0].1);
+        let rhs_positions = get_source_indices(&inp[1].1);
+
+        for (idx, (lhs_val, rhs_val, out_val, diff_val)) in
+            intermediate_values.into_iter().enumerate()
+        {
+            let is_last_idx: u32 = if idx == (output_size - 1) { 1 } else { 0 };
+
+            // Send the limbs of the difference to the shared range check.
+            let [diff_lo, diff_hi] = range_check_limbs(
+                "LessThan",
+                node_info.id,
+                idx,
+                diff_val.0,
+                N_DIFF_BITS,
+                lookup,
+            )?;
+
+            table.add_row(LessThanTraceTableRow {
+                node_id,
+                lhs_id,
+                rhs_id,
+                idx: idx.into(),
+                lhs_idx: lhs_positions[idx].unwrap_or(0).into(),
+                rhs_idx: rhs_positions[idx].unwrap_or(0).into(),
+                is_last_idx: (is_last_idx).into(),
+                lhs: lhs_val.to_m31(),
+                rhs: rhs_val.to_m31(),
+                out: out_val.to_m31(),
+                diff: diff_val.to_m31(),
+                diff_lo,
+                diff_hi,
+                lhs_mult: input_mult(lhs_positions[idx]),
+                rhs_mult: input_mult(rhs_positions[idx]),
+                out_mult: output_mult(node_info, idx),
+                range_check_mult: M31::one(),
+            })
+        }
+
+        Ok(vec![Tensor::new(StwoData::new(out_data, get_scale(&inp)))])
+    }
+}
+
+impl Operator for LuminairLessThan {
+    fn process(&mut self, inp: Vec<(InputTensor, ShapeTracker)>) -> Vec<Tensor> {
+        let (out_data, _) = self.compute(&inp, false);
+        vec![Tensor::new(StwoData::new(out_data, get_scale(&inp)))]
+    }
+}
+
+/// LuminAIR operator for element-wise modulo (`a % b`).
+///
+/// Computes the Euclidean remainder `r` such that `a = q * b + r` with `0 <= r < |b|`,
+/// in fixed-point. Implements both the standard `Operator` trait for graph execution and the
+/// `LuminairOperator` trait to generate trace entries for `ModTraceTable`.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct LuminairMod {}
+
+impl LuminairMod {
+    /// Creates a new `LuminairMod` operator instance.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl LuminairMod {
+    fn compute(
+        &self,
+        inp: &[(InputTensor, ShapeTracker)],
+        trace_mode: bool,
+    ) -> (Vec<Fixed>, Option<Vec<(Fixed, Fixed, Fixed, i64)>>) {
+        let (lhs, rhs) = (
+            get_buffer_from_tensor(&inp[0].0).unwrap(),
+            get_buffer_from_tensor(&inp[1].0).unwrap(),
+        );
+        let lexpr = (inp[0].1.index_expression(), inp[0].1.valid_expression());
+        let rexpr = (inp[1].1.index_expression(), inp[1].1.valid_expression());
+
+        let mut stack: Vec<i64> = vec![];
+        let output_size = inp[0].1.n_elements().to_usize().unwrap();
+        let mut out_data = vec![Fixed::zero(); output_size];
+
+        // Only allocate for intermediate values if in trace mode
+        let mut intermediate_values = if trace_mode {
+            Some(Vec::with_capacity(output_size))
+        } else {
+            None
+        };
+
+        for (idx, out) in out_data.iter_mut().enumerate() {
+            let lhs_val = get_index(lhs, &lexpr, &mut stack, idx);
+            let rhs_val = get_index(rhs, &rexpr, &mut stack, idx);
+            // A zero divisor has no valid remainder; it is rejected when generating the trace.
+            let (out_val, quotient) = if rhs_val.0 == 0 {
+                (Fixed::zero(), 0)
+            } else {
+                (
+                    Fixed(lhs_val.0.rem_euclid(rhs_val.0)),
+                    lhs_val.0.div_euclid(rhs_val.0),
+                )
+            };
+            *out = out_val;
+
+            // Only collect intermediate values if in trace mode
+            if let Some(values) = &mut intermediate_values {
+                values.push((lhs_val, rhs_val, out_val, quotient));
+            }
+        }
+
+        (out_data, intermediate_values)
+    }
+}
+
+impl LuminairOperator<ModColumn, ModTraceTable, RangeCheckLookup> for LuminairMod {
+    fn process_trace(
+        &mut self,
+        inp: Vec<(InputTensor, ShapeTracker)>,
+        table: &mut ModTraceTable,
+        node_info: &NodeInfo,
+        lookup: &mut RangeCheckLookup,
+    ) -> Result<Vec<Tensor>, LuminairError> {
+        let (out_data, intermediate_values) = self.compute(&inp, true);
+        let intermediate_values = intermediate_values.unwrap();
+
+        let output_size = inp[0].1.n_elements().to_usize().unwrap();
+        let node_id: BaseField = node_info.id.into();
+        let lhs_id: BaseField = node_info.inputs[0].id.into();
+        let rhs_id: BaseField = node_info.inputs[1].id.into();
+
+        let lhs_positions = get_source_indices(&inp[0].1);
+        let rhs_positions = get_source_indices(&inp[1].1);
+
+        for (idx, (lhs_val, rhs_val, out_val, quotient)) in
+            intermediate_values.into_iter().enumerate()
+        {
+            if rhs_val.0 == 0 {
+                return Err(LuminairError::ModByZero {
+                    node_id: node_info.id,
+                    idx,
+                });
+            }
+
+            let is_last_idx: u32 = if idx == (output_size - 1) { 1 } else { 0 };
+            let rhs_sign = if rhs_val.0 < 0 {
+                -BaseField::one()
+            } else {
+                BaseField::one()
+            };
+            // Non-negative slack witnessing `r < |rhs|`.
+            let slack = rhs_val.0.abs() - 1 - out_val.0;
+
+            // Send the limbs of the remainder, the slack and the quotient magnitude to the
+            // shared range check.
+            let rem_limbs =
+                range_check_limbs("Mod", node_info.id, idx, out_val.0, N_RANGE_BITS, lookup)?;
+            let slack_limbs =
+                range_check_limbs("Mod", node_info.id, idx, slack, N_RANGE_BITS, lookup)?;
+            let (quotient_sign, quotient_limbs) = range_check_signed_limbs(
+                "Mod",
+                node_info.id,
+                idx,
+                quotient,
+                N_QUOTIENT_BITS,
+                lookup,
+            )?;
+
+            table.add_row(ModTraceTableRow {
+                node_id,
+                lhs_id,
+                rhs_id,
+                idx: idx.into(),
+                lhs_idx: lhs_positions[idx].unwrap_or(0).into(),
+                rhs_idx: rhs_positions[idx].unwrap_or(0).into(),
+                is_last_idx: (is_last_idx).into(),
+                lhs: lhs_val.to_m31(),
+                rhs: rhs_val.to_m31(),
+                out: out_val.to_m31(),
+                quotient: Fixed(quotient).to_m31(),
+                rhs_sign,
+                rem_limbs,
+                slack_limbs,
+                quotient_sign,
+                quotient_limbs,
+                lhs_mult: input_mult(lhs_positions[idx]),
+                rhs_mult: input_mult(rhs_positions[idx]),
+                out_mult: output_mult(node_info, idx),
+                range_check_mult: M31::one(),
+            })
+        }
+
+        Ok(vec![Tensor::new(StwoData::new(out_data, get_scale(&inp)))])
+    }
+}
+
+impl Operator for LuminairMod {
+    fn process(&mut self, inp: Vec<(InputTensor, ShapeTracker)>) -> Vec<Tensor> {
+        let (out_data, _) = self.compute(&inp, false);
+        vec![Tensor::new(StwoData::new(out_data, get_scale(&inp)))]
+    }
+}
+
+// ================== REDUCE ==================
+
+/// LuminAIR operator for sum reduction along a specified dimension.
+///
+/// Implements both the standard `Operator` trait for graph execution and the
+/// `LuminairOperator` trait to generate trace entries for `SumReduceTraceTable`,
+/// capturing the accumulation process step-by-step.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct LuminairSumReduce(pub usize);
+
+impl LuminairSumReduce {
+    /// Creates a new `LuminairSumReduce` operator instance for the given reduction dimension.
+    pub fn new(value: usize) -> Self {
+        Self(value)
+    }
+}
+
+impl LuminairSumReduce {
+    fn compute(
+        &self,
+        inp: &[(InputTensor, ShapeTracker)],
+        trace_mode: bool,
+    ) -> (
+        Vec<Fixed>,
+        Option<Vec<(usize, usize, Fixed, Fixed, Fixed, Fixed, BaseField)>>,
     ) {
         let sh = inp[0].1.shape_usize();
         let front_size = sh.iter().take(self.0).product::<usize>().max(1);
@@ -765,7 +1278,7 @@ impl LuminairSumReduce {
         let dim_size = sh[self.0];
 
         let output_size = front_size * back_size;
-        let mut out_data = vec![Fixed::<DEFAULT_FP_SCALE>::zero(); output_size];
+        let mut out_data = vec![Fixed::zero(); output_size];
         let input = get_buffer_from_tensor(&inp[0].0).unwrap();
         let expr = (inp[0].1.index_expression(), inp[0].1.valid_expression());
         let mut stack: Vec<i64> = vec![];
@@ -779,7 +1292,7 @@ impl LuminairSumReduce {
 
         for i in 0..front_size {
             for j in 0..back_size {
-                let mut acc = Fixed::<DEFAULT_FP_SCALE>::zero(); // Initialize accumulator for each (i, j)
+                let mut acc = Fixed::zero(); // Initialize accumulator for each (i, j)
                 for k in 0..dim_size {
                     let orig_index = i * dim_size * back_size + k * back_size + j;
                     let input_val = get_index(input, &expr, &mut stack, orig_index);
@@ -791,12 +1304,20 @@ impl LuminairSumReduce {
                         out_data[idx] = next_acc;
                         (next_acc, BaseField::one())
                     } else {
-                        (Fixed::<DEFAULT_FP_SCALE>::zero(), BaseField::zero()) // Placeholder for incomplete reductions
+                        (Fixed::zero(), BaseField::zero()) // Placeholder for incomplete reductions
                     };
 
                     // Record intermediate values if in trace mode
                     if let Some(values) = &mut intermediate_values {
-                        values.push((idx, input_val, out_val, acc, next_acc, is_last_step));
+                        values.push((
+                            idx,
+                            orig_index,
+                            input_val,
+                            out_val,
+                            acc,
+                            next_acc,
+                            is_last_step,
+                        ));
                     }
                     // Update running sum
                     acc = next_acc;
@@ -815,7 +1336,7 @@ impl LuminairOperator<SumReduceColumn, SumReduceTraceTable, ()> for LuminairSumR
         table: &mut SumReduceTraceTable,
         node_info: &NodeInfo,
         _lookup: &mut (),
-    ) -> Vec<Tensor> {
+    ) -> Result<Vec<Tensor>, LuminairError> {
         let (out_data, intermediate_values) = self.compute(&inp, true);
         let intermediate_values = intermediate_values.unwrap();
 
@@ -823,21 +1344,12 @@ impl LuminairOperator<SumReduceColumn, SumReduceTraceTable, ()> for LuminairSumR
         let input_id: BaseField = node_info.inputs[0].id.into();
         let output_size = out_data.len();
 
-        let input_mult = if node_info.inputs[0].is_initializer {
-            BaseField::zero()
-        } else {
-            -BaseField::one()
-        };
-        let out_mult = if node_info.output.is_final_output {
-            BaseField::zero()
-        } else {
-            BaseField::one() * BaseField::from_u32_unchecked(node_info.num_consumers)
-        };
+        let input_positions = get_source_indices(&inp[0].1);
 
         for entry in intermediate_values {
-            let (idx, input_val, out_val, acc, next_acc, is_last_step) = entry;
+            let (idx, orig_index, input_val, out_val, acc, next_acc, is_last_step) = entry;
 
-            let out_mult = out_mult * is_last_step;
+            let out_mult = output_mult(node_info, idx) * is_last_step;
 
             let is_last_idx: u32 = if idx == (output_size - 1) { 1 } else { 0 };
 
@@ -845,28 +1357,26 @@ impl LuminairOperator<SumReduceColumn, SumReduceTraceTable, ()> for LuminairSumR
                 node_id,
                 input_id,
                 idx: idx.into(),
+                input_idx: input_positions[orig_index].unwrap_or(0).into(),
                 is_last_idx: (is_last_idx).into(),
-                next_node_id: node_id,
-                next_input_id: input_id,
-                next_idx: (idx + 1).into(),
                 input: input_val.to_m31(),
                 out: out_val.to_m31(),
                 acc: acc.to_m31(),
                 next_acc: next_acc.to_m31(),
                 is_last_step,
-                input_mult,
+                input_mult: input_mult(input_positions[orig_index]),
                 out_mult,
             });
         }
 
-        vec![Tensor::new(StwoData(Arc::new(out_data)))]
+        Ok(vec![Tensor::new(StwoData::new(out_data, get_scale(&inp)))])
     }
 }
 
 impl Operator for LuminairSumReduce {
     fn process(&mut self, inp: Vec<(InputTensor, ShapeTracker)>) -> Vec<Tensor> {
         let (out_data, _) = self.compute(&inp, false);
-        vec![Tensor::new(StwoData(Arc::new(out_data)))]
+        vec![Tensor::new(StwoData::new(out_data, get_scale(&inp)))]
     }
 }
 
@@ -891,14 +1401,15 @@ impl LuminairMaxReduce {
         inp: &[(InputTensor, ShapeTracker)],
         trace_mode: bool,
     ) -> (
-        Vec<Fixed<DEFAULT_FP_SCALE>>,
+        Vec<Fixed>,
         Option<
             Vec<(
                 usize,
-                Fixed<DEFAULT_FP_SCALE>,
-                Fixed<DEFAULT_FP_SCALE>,
-                Fixed<DEFAULT_FP_SCALE>,
-                Fixed<DEFAULT_FP_SCALE>,
+                usize,
+                Fixed,
+                Fixed,
+                Fixed,
+                Fixed,
                 BaseField,
                 BaseField,
             )>,
@@ -910,7 +1421,7 @@ impl LuminairMaxReduce {
         let dim_size = sh[self.0];
 
         let output_size = front_size * back_size;
-        let mut out_data = vec![Fixed::<DEFAULT_FP_SCALE>::zero(); output_size];
+        let mut out_data = vec![Fixed::zero(); output_size];
         let input = get_buffer_from_tensor(&inp[0].0).unwrap();
         let expr = (inp[0].1.index_expression(), inp[0].1.valid_expression());
         let mut stack: Vec<i64> = vec![];
@@ -932,8 +1443,8 @@ impl LuminairMaxReduce {
                     let orig_index = i * dim_size * back_size + k * back_size + j;
                     let input_val = get_index(input, &expr, &mut stack, orig_index);
 
-                    // Determine if this value is the new max
-                    let is_max = if input_val.to_f64() > max_val.to_f64() {
+                    // Determine if this value is the new max; the first one always is.
+                    let is_max = if k == 0 || input_val.0 > max_val.0 {
                         BaseField::one()
                     } else {
                         BaseField::zero()
@@ -951,7 +1462,7 @@ impl LuminairMaxReduce {
                         out_data[i * back_size + j] = next_max_val;
                         (next_max_val, BaseField::one())
                     } else {
-                        (Fixed::<DEFAULT_FP_SCALE>::zero(), BaseField::zero()) // Placeholder for incomplete reductions
+                        (Fixed::zero(), BaseField::zero()) // Placeholder for incomplete reductions
                     };
 
                     let idx = i * back_size + j; // Index for out_data
@@ -960,6 +1471,7 @@ impl LuminairMaxReduce {
                     if let Some(values) = &mut intermediate_values {
                         values.push((
                             idx,
+                            orig_index,
                             input_val,
                             out_val,
                             max_val,
@@ -979,14 +1491,16 @@ impl LuminairMaxReduce {
     }
 }
 
-impl LuminairOperator<MaxReduceColumn, MaxReduceTraceTable, ()> for LuminairMaxReduce {
+impl LuminairOperator<MaxReduceColumn, MaxReduceTraceTable, RangeCheckLookup>
+    for LuminairMaxReduce
+{
     fn process_trace(
         &mut self,
         inp: Vec<(InputTensor, ShapeTracker)>,
         table: &mut MaxReduceTraceTable,
         node_info: &NodeInfo,
-        _lookup: &mut (),
-    ) -> Vec<Tensor> {
+        lookup: &mut RangeCheckLookup,
+    ) -> Result<Vec<Tensor>, LuminairError> {
         let (out_data, intermediate_values) = self.compute(&inp, true);
         let intermediate_values = intermediate_values.unwrap();
 
@@ -994,52 +1508,942 @@ impl LuminairOperator<MaxReduceColumn, MaxReduceTraceTable, ()> for LuminairMaxR
         let input_id: BaseField = node_info.inputs[0].id.into();
         let output_size = out_data.len();
 
-        let input_mult = if node_info.inputs[0].is_initializer {
-            BaseField::zero()
-        } else {
-            -BaseField::one()
-        };
-
-        let out_mult = if node_info.output.is_final_output {
-            BaseField::zero()
-        } else {
-            BaseField::one() * BaseField::from_u32_unchecked(node_info.num_consumers)
-        };
+        let input_positions = get_source_indices(&inp[0].1);
 
         for entry in intermediate_values {
-            let (idx, input_val, out_val, max_val, next_max_val, is_max, is_last_step_flag) = entry;
+            let (
+                idx,
+                orig_index,
+                input_val,
+                out_val,
+                max_val,
+                next_max_val,
+                is_max,
+                is_last_step_flag,
+            ) = entry;
 
-            let out_mult = out_mult * is_last_step_flag;
+            let out_mult = output_mult(node_info, idx) * is_last_step_flag;
 
             let is_last_idx: u32 = if idx == (output_size - 1) { 1 } else { 0 };
 
+            // Non-negative difference witnessing the ordering between input and running max.
+            let diff = if is_max == BaseField::one() {
+                input_val.0 - max_val.0
+            } else {
+                max_val.0 - input_val.0
+            };
+            let [diff_lo, diff_hi] = range_check_limbs(
+                "MaxReduce",
+                node_info.id,
+                idx,
+                diff,
+                N_MAX_REDUCE_DIFF_BITS,
+                lookup,
+            )?;
+
             table.add_row(MaxReduceTraceTableRow {
                 node_id,
                 input_id,
                 idx: idx.into(),
+                input_idx: input_positions[orig_index].unwrap_or(0).into(),
                 is_last_idx: (is_last_idx).into(),
-                next_node_id: node_id,
-                next_input_id: input_id,
-                next_idx: (idx + 1).into(),
                 input: input_val.to_m31(),
                 out: out_val.to_m31(),
                 max_val: max_val.to_m31(),
                 next_max_val: next_max_val.to_m31(),
                 is_max,
+                diff: Fixed(diff).to_m31(),
+                diff_lo,
+                diff_hi,
                 is_last_step: is_last_step_flag,
-                input_mult,
+                input_mult: input_mult(input_positions[orig_index]),
                 out_mult,
+                range_check_mult: M31::one(),
             });
         }
 
-        vec![Tensor::new(StwoData(Arc::new(out_data)))]
+        Ok(vec![Tensor::new(StwoData::new(out_data, get_scale(&inp)))])
     }
 }
 
 impl Operator for LuminairMaxReduce {
     fn process(&mut self, inp: Vec<(InputTensor, ShapeTracker)>) -> Vec<Tensor> {
         let (out_data, _) = self.compute(&inp, false);
-        vec![Tensor::new(StwoData(Arc::new(out_data)))]
+        vec![Tensor::new(StwoData::new(out_data, get_scale(&inp)))]
+    }
+}
+
+// ================== MATMUL ==================
+
+/// LuminAIR operator for matrix multiplication, as the sum reduction of broadcast products.
+///
+/// Fuses luminal's lowering of `matmul`, a `Mul` of two broadcast inputs followed by a
+/// `SumReduce` along dimension `self.0`. Each output element is a dot product, accumulated
+/// exactly at the sum of the input scales, until a single `LuminairRescale` brings it back
+/// to the circuit scale.
+/// Implements both the standard `Operator` trait for graph execution and the
+/// `LuminairOperator` trait to generate trace entries for `MatMulTraceTable`,
+/// capturing the accumulation of each dot product step-by-step.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct LuminairMatMul(pub usize);
+
+impl LuminairMatMul {
+    /// Creates a new `LuminairMatMul` operator instance for the given reduction dimension.
+    pub fn new(value: usize) -> Self {
+        Self(value)
+    }
+}
+
+impl LuminairMatMul {
+    fn compute(
+        &self,
+        inp: &[(InputTensor, ShapeTracker)],
+        trace_mode: bool,
+    ) -> (
+        Vec<Fixed>,
+        Option<Vec<(usize, usize, Fixed, Fixed, Fixed, Fixed, Fixed, BaseField)>>,
+    ) {
+        let sh = inp[0].1.shape_usize();
+        let front_size = sh.iter().take(self.0).product::<usize>().max(1);
+        let back_size = sh.iter().skip(self.0 + 1).product::<usize>().max(1);
+        let dim_size = sh[self.0];
+
+        let output_size = front_size * back_size;
+        let mut out_data = vec![Fixed::zero(); output_size];
+        let (lhs, rhs) = (
+            get_buffer_from_tensor(&inp[0].0).unwrap(),
+            get_buffer_from_tensor(&inp[1].0).unwrap(),
+        );
+        let lexpr = (inp[0].1.index_expression(), inp[0].1.valid_expression());
+        let rexpr = (inp[1].1.index_expression(), inp[1].1.valid_expression());
+        let mut stack: Vec<i64> = vec![];
+
+        // Only allocate for intermediate values if in trace mode
+        let mut intermediate_values = if trace_mode {
+            Some(Vec::with_capacity(output_size * dim_size))
+        } else {
+            None
+        };
+
+        for i in 0..front_size {
+            for j in 0..back_size {
+                let mut acc = Fixed::zero(); // Initialize accumulator for each (i, j)
+                for k in 0..dim_size {
+                    let orig_index = i * dim_size * back_size + k * back_size + j;
+                    let lhs_val = get_index(lhs, &lexpr, &mut stack, orig_index);
+                    let rhs_val = get_index(rhs, &rexpr, &mut stack, orig_index);
+                    let next_acc = acc + Fixed(lhs_val.0 * rhs_val.0); // Exact product
+                    let idx = i * back_size + j; // Index for out_data
+
+                    // Set out_data only in the last reduction step
+                    let (out_val, is_last_step) = if k == dim_size - 1 {
+                        out_data[idx] = next_acc;
+                        (next_acc, BaseField::one())
+                    } else {
+                        (Fixed::zero(), BaseField::zero()) // Placeholder for incomplete reductions
+                    };
+
+                    // Record intermediate values if in trace mode
+                    if let Some(values) = &mut intermediate_values {
+                        values.push((
+                            idx,
+                            orig_index,
+                            lhs_val,
+                            rhs_val,
+                            out_val,
+                            acc,
+                            next_acc,
+                            is_last_step,
+                        ));
+                    }
+                    // Update running sum
+                    acc = next_acc;
+                }
+            }
+        }
+
+        (out_data, intermediate_values)
+    }
+}
+
+impl LuminairOperator<MatMulColumn, MatMulTraceTable, ()> for LuminairMatMul {
+    fn process_trace(
+        &mut self,
+        inp: Vec<(InputTensor, ShapeTracker)>,
+        table: &mut MatMulTraceTable,
+        node_info: &NodeInfo,
+        _lookup: &mut (),
+    ) -> Result<Vec<Tensor>, LuminairError> {
+        let (out_data, intermediate_values) = self.compute(&inp, true);
+        let intermediate_values = intermediate_values.unwrap();
+
+        let node_id: BaseField = node_info.id.into();
+        let lhs_id: BaseField = node_info.inputs[0].id.into();
+        let rhs_id: BaseField = node_info.inputs[1].id.into();
+        let output_size = out_data.len();
+
+        let lhs_positions = get_source_indices(&inp[0].1);
+        let rhs_positions = get_source_indices(&inp[1].1);
+
+        for entry in intermediate_values {
+            let (idx, orig_index, lhs_val, rhs_val, out_val, acc, next_acc, is_last_step) = entry;
+
+            let out_mult = output_mult(node_info, idx) * is_last_step;
+
+            let is_last_idx: u32 = if idx == (output_size - 1) { 1 } else { 0 };
+
+            table.add_row(MatMulTraceTableRow {
+                node_id,
+                lhs_id,
+                rhs_id,
+                idx: idx.into(),
+                lhs_idx: lhs_positions[orig_index].unwrap_or(0).into(),
+                rhs_idx: rhs_positions[orig_index].unwrap_or(0).into(),
+                is_last_idx: (is_last_idx).into(),
+                lhs: lhs_val.to_m31(),
+                rhs: rhs_val.to_m31(),
+                out: out_val.to_m31(),
+                acc: acc.to_m31(),
+                next_acc: next_acc.to_m31(),
+                is_last_step,
+                lhs_mult: input_mult(lhs_positions[orig_index]),
+                rhs_mult: input_mult(rhs_positions[orig_index]),
+                out_mult,
+            });
+        }
+
+        Ok(vec![Tensor::new(StwoData::new(
+            out_data,
+            get_product_scale(&inp),
+        ))])
+    }
+}
+
+impl Operator for LuminairMatMul {
+    fn process(&mut self, inp: Vec<(InputTensor, ShapeTracker)>) -> Vec<Tensor> {
+        let (out_data, _) = self.compute(&inp, false);
+        vec![Tensor::new(StwoData::new(
+            out_data,
+            get_product_scale(&inp),
+        ))]
+    }
+}
+
+// ================== SOFTMAX ==================
+
+/// LuminAIR operator for softmax along the last dimension.
+///
+/// Fuses luminal's lowering of `softmax`, `exp(x - max(x)) / sum(exp(x - max(x)))`, built from
+/// `MaxReduce`, `Exp2`, `SumReduce` and `Recip` nodes. The exponentials are looked up in the
+/// Exp LUT, and each reduction is normalized by the single reciprocal of their sum.
+/// Implements both the standard `Operator` trait for graph execution and the
+/// `LuminairOperator` trait to generate trace entries for `SoftmaxTraceTable`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct LuminairSoftmax {}
+
+impl LuminairSoftmax {
+    /// Creates a new `LuminairSoftmax` operator instance.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl LuminairSoftmax {
+    /// Reads the inputs, each along with the maximum of its reduction.
+    fn maxima(&self, inp: &[(InputTensor, ShapeTracker)]) -> Vec<(Fixed, Fixed)> {
+        let sh = inp[0].1.shape_usize();
+        let dim_size = *sh.last().unwrap();
+        let input = get_buffer_from_tensor(&inp[0].0).unwrap();
+        let expr = (inp[0].1.index_expression(), inp[0].1.valid_expression());
+        let mut stack: Vec<i64> = vec![];
+
+        let output_size = inp[0].1.n_elements().to_usize().unwrap();
+        let inputs = (0..output_size)
+            .map(|idx| get_index(input, &expr, &mut stack, idx))
+            .collect::<Vec<_>>();
+
+        inputs
+            .chunks(dim_size)
+            .flat_map(|reduction| {
+                let max_val = reduction.iter().map(|x| x.0).max().unwrap();
+                reduction.iter().map(move |x| (*x, Fixed(max_val)))
+            })
+            .collect()
+    }
+
+    /// Normalizes the exponentials of each reduction of `dim_size` inputs.
+    ///
+    /// Returns, for each input, its output and remainder, along with the total of its
+    /// reduction and the reciprocal of that total with its remainder.
+    fn normalize(
+        exps: &[Fixed],
+        dim_size: usize,
+        scale: u32,
+    ) -> Vec<(Fixed, Fixed, Fixed, Fixed, Fixed)> {
+        exps.chunks(dim_size)
+            .flat_map(|reduction| {
+                let total = reduction.iter().fold(Fixed::zero(), |acc, e| acc + *e);
+                let (recip, recip_rem) = total.recip(scale);
+                reduction.iter().map(move |e| {
+                    let (out, out_rem) = e.mul(recip, scale);
+                    (out, out_rem, total, recip, recip_rem)
+                })
+            })
+            .collect()
+    }
+}
+
+impl LuminairOperator<SoftmaxColumn, SoftmaxTraceTable, ExpLookup> for LuminairSoftmax {
+    fn process_trace(
+        &mut self,
+        inp: Vec<(InputTensor, ShapeTracker)>,
+        table: &mut SoftmaxTraceTable,
+        node_info: &NodeInfo,
+        lookup: &mut ExpLookup,
+    ) -> Result<Vec<Tensor>, LuminairError> {
+        let scale = get_scale(&inp);
+        let dim_size = *inp[0].1.shape_usize().last().unwrap();
+        let maxima = self.maxima(&inp);
+
+        let node_id: BaseField = node_info.id.into();
+        let input_id: BaseField = node_info.inputs[0].id.into();
+        let output_size = maxima.len();
+
+        let input_positions = get_source_indices(&inp[0].1);
+
+        // The exponentials are the ones proven from the LUT entries of x - max.
+        let diffs = maxima
+            .iter()
+            .map(|(x, max_val)| Fixed(x.0 - max_val.0))
+            .collect::<Vec<_>>();
+        let lut_inputs = resolve_lookup_inputs(node_info.id, &diffs, lookup)?;
+        let outputs = lut_inputs
+            .iter()
+            .map(|clamped| lookup.output(clamped.lut_input))
+            .collect::<Vec<_>>();
+        let exps = outputs.iter().map(|output| output.out).collect::<Vec<_>>();
+        let normalized = Self::normalize(&exps, dim_size, scale);
+
+        let mut max_count = 0u32;
+        let mut sum = Fixed::zero();
+        for (idx, ((input_val, max_val), (clamped, output))) in maxima
+            .iter()
+            .zip(lut_inputs.iter().zip(outputs.iter()))
+            .enumerate()
+        {
+            let (out_val, out_rem, total, recip, recip_rem) = normalized[idx];
+            let is_last_idx: u32 = if idx == (output_size - 1) { 1 } else { 0 };
+            let is_last_step = (idx + 1) % dim_size == 0;
+
+            // Only the first input equal to the max is flagged.
+            let is_max = max_count == 0 && input_val.0 == max_val.0;
+            let next_max_count = max_count + is_max as u32;
+            let next_sum = sum + output.out;
+            let diff = max_val.0 - input_val.0;
+            // The limbs of the difference are range-checked along with the other values of the
+            // row, once the whole table is built.
+            let diff_limbs = split_limbs(diff, N_SOFTMAX_DIFF_BITS).ok_or(
+                LuminairError::RangeCheckOverflow {
+                    op: "Softmax",
+                    node_id: node_info.id,
+                    idx,
+                    value: diff,
+                    bits: N_SOFTMAX_DIFF_BITS,
+                },
+            )?;
+
+            table.add_row(SoftmaxTraceTableRow {
+                node_id,
+                input_id,
+                idx: idx.into(),
+                input_idx: input_positions[idx].unwrap_or(0).into(),
+                is_last_idx: (is_last_idx).into(),
+                input: input_val.to_m31(),
+                out: out_val.to_m31(),
+                max: max_val.to_m31(),
+                diff: Fixed(diff).to_m31(),
+                diff_limbs: diff_limbs.map(|limb| BaseField::from_u32_unchecked(limb as u32)),
+                is_max: (is_max as u32).into(),
+                max_count: max_count.into(),
+                next_max_count: next_max_count.into(),
+                lut_input: clamped.lut_input.to_m31(),
+                clamp_low: (clamped.low as u32).into(),
+                clamp_high: (clamped.high as u32).into(),
+                clamp_dist: clamped.dist.into(),
+                clamp_dist_bits: clamped.dist_bits(),
+                offset: Fixed(output.offset).to_m31(),
+                base_exp: output.base.to_m31(),
+                slope: output.slope.to_m31(),
+                exp_rem: Fixed(output.rem).to_m31(),
+                exp: output.out.to_m31(),
+                sum: sum.to_m31(),
+                next_sum: next_sum.to_m31(),
+                total: total.to_m31(),
+                is_last_step: (is_last_step as u32).into(),
+                recip: recip.to_m31(),
+                recip_rem: recip_rem.to_m31(),
+                out_rem: out_rem.to_m31(),
+                scale: M31::from_u32_unchecked(1 << scale),
+                input_mult: input_mult(input_positions[idx]),
+                out_mult: output_mult(node_info, idx),
+                lookup_mult: M31::one(),
+                range_check_mult: M31::one(),
+            });
+
+            // The running sum and count of maxima restart with each reduction.
+            (max_count, sum) = if is_last_step {
+                (0, Fixed::zero())
+            } else {
+                (next_max_count, next_sum)
+            };
+        }
+
+        let out_data = normalized.into_iter().map(|(out, ..)| out).collect();
+        Ok(vec![Tensor::new(StwoData::new(out_data, scale))])
+    }
+}
+
+impl Operator for LuminairSoftmax {
+    fn process(&mut self, inp: Vec<(InputTensor, ShapeTracker)>) -> Vec<Tensor> {
+        let scale = get_scale(&inp);
+        let dim_size = *inp[0].1.shape_usize().last().unwrap();
+        let exps = self
+            .maxima(&inp)
+            .into_iter()
+            .map(|(x, max_val)| functions::Exp::eval_fixed(Fixed(x.0 - max_val.0), scale))
+            .collect::<Vec<_>>();
+        let out_data = Self::normalize(&exps, dim_size, scale)
+            .into_iter()
+            .map(|(out, ..)| out)
+            .collect();
+        vec![Tensor::new(StwoData::new(out_data, scale))]
+    }
+}
+
+// ================== NORM ==================
+
+/// Values of a normalized input, along with the statistics of its reduction.
+#[derive(Debug, Clone, Copy)]
+struct NormValues {
+    input: Fixed,
+    weight: Fixed,
+    bias: Fixed,
+    out: Fixed,
+    total: Fixed,
+    mean: Fixed,
+    mean_rem: Fixed,
+    centered: Fixed,
+    sq_total: Fixed,
+    var: Fixed,
+    var_rem: Fixed,
+    std: Fixed,
+    std_rem: Fixed,
+    inv_std: Fixed,
+    inv_std_rem: Fixed,
+    norm: Fixed,
+    norm_rem: Fixed,
+    out_rem: Fixed,
+}
+
+/// LuminAIR operator for LayerNorm and RMSNorm along the last dimension.
+///
+/// Fuses luminal's lowering of `layer_norm` and `std_norm`, `(x - mean(x)) / sqrt(var(x) + eps)`,
+/// built from `SumReduce`, constant `Mul`, `Sqrt` and `Recip` nodes, along with an optional
+/// affine weight and bias. Without centering (RMSNorm), the mean is zero.
+/// Its inputs are the input tensor, then the weight and the bias when present.
+/// Implements both the standard `Operator` trait for graph execution and the
+/// `LuminairOperator` trait to generate trace entries for `NormTraceTable`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct LuminairNorm {
+    /// Whether the inputs are centered by their mean (LayerNorm).
+    pub(crate) centered: bool,
+    /// Whether the normalized inputs are multiplied by a weight.
+    pub(crate) weight: bool,
+    /// Whether a bias is added to the output.
+    pub(crate) bias: bool,
+    /// Epsilon added to the variance.
+    pub(crate) eps: f32,
+}
+
+impl LuminairNorm {
+    /// Creates a new `LuminairNorm` operator instance.
+    pub fn new(centered: bool, weight: bool, bias: bool, eps: f32) -> Self {
+        Self {
+            centered,
+            weight,
+            bias,
+            eps,
+        }
+    }
+}
+
+impl LuminairNorm {
+    /// Normalizes each reduction along the last dimension.
+    fn compute(&self, inp: &[(InputTensor, ShapeTracker)]) -> Vec<NormValues> {
+        let scale = get_scale(inp);
+        let one = Fixed(1 << scale);
+        let eps = Fixed::from_f64(self.eps as f64, scale);
+        let dim_size = *inp[0].1.shape_usize().last().unwrap();
+        let output_size = inp[0].1.n_elements().to_usize().unwrap();
+
+        let read = |(tensor, shape): &(InputTensor, ShapeTracker)| {
+            let buffer = get_buffer_from_tensor(tensor).unwrap();
+            let expr = (shape.index_expression(), shape.valid_expression());
+            let mut stack: Vec<i64> = vec![];
+            (0..output_size)
+                .map(|idx| get_index(buffer, &expr, &mut stack, idx))
+                .collect::<Vec<_>>()
+        };
+        let inputs = read(&inp[0]);
+        let weights = if self.weight {
+            read(&inp[1])
+        } else {
+            vec![one; output_size]
+        };
+        let biases = if self.bias {
+            read(&inp[1 + self.weight as usize])
+        } else {
+            vec![Fixed::zero(); output_size]
+        };
+
+        let count = dim_size as i64;
+        let divisor = count << scale;
+        inputs
+            .chunks(dim_size)
+            .enumerate()
+            .flat_map(|(r, reduction)| {
+                let total = reduction.iter().fold(Fixed::zero(), |acc, x| acc + *x);
+                let (mean, mean_rem) = if self.centered {
+                    (
+                        Fixed(total.0.div_euclid(count)),
+                        Fixed(total.0.rem_euclid(count)),
+                    )
+                } else {
+                    (Fixed::zero(), Fixed::zero())
+                };
+                let sq_total = Fixed(reduction.iter().map(|x| (x.0 - mean.0).pow(2)).sum());
+                let var = Fixed(sq_total.0.div_euclid(divisor));
+                let var_rem = Fixed(sq_total.0.rem_euclid(divisor));
+                let (std, std_rem) = (var + eps).sqrt(scale);
+                let (inv_std, inv_std_rem) = std.recip(scale);
+
+                let (weights, biases) = (&weights, &biases);
+                reduction.iter().enumerate().map(move |(i, x)| {
+                    let idx = r * dim_size + i;
+                    let centered = Fixed(x.0 - mean.0);
+                    let (norm, norm_rem) = centered.mul(inv_std, scale);
+                    let (scaled, out_rem) = norm.mul(weights[idx], scale);
+                    NormValues {
+                        input: *x,
+                        weight: weights[idx],
+                        bias: biases[idx],
+                        out: scaled + biases[idx],
+                        total,
+                        mean,
+                        mean_rem,
+                        centered,
+                        sq_total,
+                        var,
+                        var_rem,
+                        std,
+                        std_rem,
+                        inv_std,
+                        inv_std_rem,
+                        norm,
+                        norm_rem,
+                        out_rem,
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+impl LuminairOperator<NormColumn, NormTraceTable, RangeCheckLookup> for LuminairNorm {
+    fn process_trace(
+        &mut self,
+        inp: Vec<(InputTensor, ShapeTracker)>,
+        table: &mut NormTraceTable,
+        node_info: &NodeInfo,
+        lookup: &mut RangeCheckLookup,
+    ) -> Result<Vec<Tensor>, LuminairError> {
+        let scale = get_scale(&inp);
+        let dim_size = *inp[0].1.shape_usize().last().unwrap();
+        let values = self.compute(&inp);
+
+        let node_id: BaseField = node_info.id.into();
+        let input_id: BaseField = node_info.inputs[0].id.into();
+        let output_size = values.len();
+
+        // The weight and bias follow the input, and read nothing when absent.
+        let bias_src = 1 + self.weight as usize;
+        let weight_id: BaseField = if self.weight {
+            node_info.inputs[1].id.into()
+        } else {
+            BaseField::zero()
+        };
+        let bias_id: BaseField = if self.bias {
+            node_info.inputs[bias_src].id.into()
+        } else {
+            BaseField::zero()
+        };
+        let input_positions = get_source_indices(&inp[0].1);
+        let weight_positions = if self.weight {
+            get_source_indices(&inp[1].1)
+        } else {
+            vec![None; output_size]
+        };
+        let bias_positions = if self.bias {
+            get_source_indices(&inp[bias_src].1)
+        } else {
+            vec![None; output_size]
+        };
+
+        let count = dim_size as i64;
+        let divisor = count << scale;
+        let one = 1i64 << scale;
+        let eps = Fixed::from_f64(self.eps as f64, scale);
+
+        let mut sum = Fixed::zero();
+        let mut sq_sum = Fixed::zero();
+        for (idx, v) in values.iter().enumerate() {
+            let is_last_idx: u32 = if idx == (output_size - 1) { 1 } else { 0 };
+            let step = idx % dim_size;
+            let is_last_step = step + 1 == dim_size;
+
+            // The sum of the squares must not wrap around the field.
+            if !v.sq_total.fits_m31() {
+                return Err(LuminairError::FixedPointOverflow {
+                    node_id: node_info.id,
+                    idx,
+                    value: v.sq_total.to_f64(2 * scale),
+                });
+            }
+
+            // Send the remainders and their gaps to their bounds to the shared range check.
+            let range_checked = [
+                v.mean_rem.0,
+                count - 1 - v.mean_rem.0,
+                v.var_rem.0,
+                divisor - 1 - v.var_rem.0,
+                v.std_rem.0,
+                2 * v.std.0 - v.std_rem.0,
+                v.inv_std_rem.0,
+                v.std.0 - 1 - v.inv_std_rem.0,
+                v.norm_rem.0,
+                one - 1 - v.norm_rem.0,
+                v.out_rem.0,
+                one - 1 - v.out_rem.0,
+            ];
+            range_check("Norm", node_info.id, &range_checked, lookup)?;
+            // Send the limbs of the magnitudes of the quotients to the range check.
+            let [
+                (mean_sign, mean_limbs),
+                (var_sign, var_limbs),
+                (inv_std_sign, inv_std_limbs),
+                (norm_sign, norm_limbs),
+                (out_sign, out_limbs),
+            ]: [_; 5] = [v.mean, v.var, v.inv_std, v.norm, v.out]
+                .into_iter()
+                .zip(quotient_bits(scale))
+                .map(|(q, bits)| {
+                    range_check_signed_limbs("Norm", node_info.id, idx, q.0, bits, lookup)
+                })
+                .collect::<Result<Vec<_>, _>>()?
+                .try_into()
+                .unwrap();
+
+            let next_sum = sum + v.input;
+            let next_sq_sum = Fixed(sq_sum.0 + v.centered.0 * v.centered.0);
+
+            table.add_row(NormTraceTableRow {
+                node_id,
+                input_id,
+                weight_id,
+                bias_id,
+                idx: idx.into(),
+                input_idx: input_positions[idx].unwrap_or(0).into(),
+                weight_idx: weight_positions[idx].unwrap_or(0).into(),
+                bias_idx: bias_positions[idx].unwrap_or(0).into(),
+                is_last_idx: (is_last_idx).into(),
+                input: v.input.to_m31(),
+                weight: v.weight.to_m31(),
+                bias: v.bias.to_m31(),
+                out: v.out.to_m31(),
+                is_centered: (self.centered as u32).into(),
+                has_weight: (self.weight as u32).into(),
+                has_bias: (self.bias as u32).into(),
+                eps: eps.to_m31(),
+                step: step.into(),
+                count: dim_size.into(),
+                is_last_step: (is_last_step as u32).into(),
+                sum: sum.to_m31(),
+                next_sum: next_sum.to_m31(),
+                total: v.total.to_m31(),
+                mean: v.mean.to_m31(),
+                mean_rem: v.mean_rem.to_m31(),
+                centered: v.centered.to_m31(),
+                sq_sum: sq_sum.to_m31(),
+                next_sq_sum: next_sq_sum.to_m31(),
+                sq_total: v.sq_total.to_m31(),
+                divisor: Fixed(divisor).to_m31(),
+                var: v.var.to_m31(),
+                var_rem: v.var_rem.to_m31(),
+                std: v.std.to_m31(),
+                std_rem: v.std_rem.to_m31(),
+                inv_std: v.inv_std.to_m31(),
+                inv_std_rem: v.inv_std_rem.to_m31(),
+                norm: v.norm.to_m31(),
+                norm_rem: v.norm_rem.to_m31(),
+                out_rem: v.out_rem.to_m31(),
+                scale: M31::from_u32_unchecked(1 << scale),
+                mean_sign,
+                mean_limbs,
+                var_sign,
+                var_limbs,
+                inv_std_sign,
+                inv_std_limbs,
+                norm_sign,
+                norm_limbs,
+                out_sign,
+                out_limbs,
+                input_mult: input_mult(input_positions[idx]),
+                weight_mult: input_mult(weight_positions[idx]),
+                bias_mult: input_mult(bias_positions[idx]),
+                out_mult: output_mult(node_info, idx),
+                range_check_mult: M31::one(),
+            });
+
+            // The running sums restart with each reduction.
+            (sum, sq_sum) = if is_last_step {
+                (Fixed::zero(), Fixed::zero())
+            } else {
+                (next_sum, next_sq_sum)
+            };
+        }
+
+        let out_data = values.into_iter().map(|v| v.out).collect();
+        Ok(vec![Tensor::new(StwoData::new(out_data, scale))])
+    }
+}
+
+impl Operator for LuminairNorm {
+    fn process(&mut self, inp: Vec<(InputTensor, ShapeTracker)>) -> Vec<Tensor> {
+        let scale = get_scale(&inp);
+        let out_data = self.compute(&inp).into_iter().map(|v| v.out).collect();
+        vec![Tensor::new(StwoData::new(out_data, scale))]
+    }
+}
+
+// ================== CONV2D ==================
+
+/// LuminAIR operator for 2D convolution, as sliding-window dot products over its input.
+///
+/// Fuses luminal's lowering of `Conv2D`, the matmul of the weights with an im2col matrix that
+/// `Contiguous` nodes gather from the input. The windows are instead read directly from the
+/// `(ch_in, height, width)` input, zero outside of it, and each output element is accumulated
+/// exactly at the sum of the input scales, until a single `LuminairRescale` brings it back to
+/// the circuit scale.
+/// Its inputs are the input tensor, read through a view broadcast over the output channels,
+/// and the `(ch_out, ch_in * kernel.0 * kernel.1)` weights, read through a view broadcast over
+/// the output positions.
+/// Implements both the standard `Operator` trait for graph execution and the
+/// `LuminairOperator` trait to generate trace entries for `Conv2dTraceTable`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct LuminairConv2d {
+    /// Height and width of the kernel.
+    pub(crate) kernel: (usize, usize),
+    /// Vertical and horizontal stride of the windows.
+    pub(crate) stride: (usize, usize),
+    /// Zeros added on each side of the input, vertically and horizontally.
+    pub(crate) padding: (usize, usize),
+}
+
+impl LuminairConv2d {
+    /// Creates a new `LuminairConv2d` operator instance.
+    pub fn new(kernel: (usize, usize), stride: (usize, usize), padding: (usize, usize)) -> Self {
+        Self {
+            kernel,
+            stride,
+            padding,
+        }
+    }
+
+    /// Returns the height and width of the output for an input of the given height and width,
+    /// or `None` if the kernel does not fit in the padded input.
+    pub(crate) fn output_dims(&self, height: usize, width: usize) -> Option<(usize, usize)> {
+        let out_dim = |size: usize, kernel: usize, stride: usize, padding: usize| {
+            (size + 2 * padding)
+                .checked_sub(kernel)
+                .map(|n| n / stride + 1)
+        };
+        Some((
+            out_dim(height, self.kernel.0, self.stride.0, self.padding.0)?,
+            out_dim(width, self.kernel.1, self.stride.1, self.padding.1)?,
+        ))
+    }
+
+    /// Returns the position of the input read by each product, in trace order: by output
+    /// channel, output position, then input channel and position in the kernel.
+    ///
+    /// `shape` is the `(ch_out, ch_in, height, width)` view of the input. Reads falling in the
+    /// padding are `None`.
+    pub(crate) fn input_positions(&self, shape: &ShapeTracker) -> Vec<Option<usize>> {
+        let [ch_out, ch_in, height, width] = <[usize; 4]>::try_from(shape.shape_usize())
+            .expect("Conv2d input must be viewed as (ch_out, ch_in, height, width)");
+        let Some((out_height, out_width)) = self.output_dims(height, width) else {
+            return Vec::new();
+        };
+        let sources = get_source_indices(shape);
+        let out_size = out_height * out_width;
+        let kernel_size = self.kernel.0 * self.kernel.1;
+        let window = ch_in * kernel_size;
+
+        (0..ch_out * out_size * window)
+            .map(|step| {
+                let (out, k) = (step / window, step % window);
+                let (co, pos) = (out / out_size, out % out_size);
+                let (ci, ky, kx) = (
+                    k / kernel_size,
+                    k % kernel_size / self.kernel.1,
+                    k % self.kernel.1,
+                );
+                let y = (pos / out_width * self.stride.0 + ky)
+                    .checked_sub(self.padding.0)
+                    .filter(|y| *y < height)?;
+                let x = (pos % out_width * self.stride.1 + kx)
+                    .checked_sub(self.padding.1)
+                    .filter(|x| *x < width)?;
+                sources[((co * ch_in + ci) * height + y) * width + x]
+            })
+            .collect()
+    }
+}
+
+impl LuminairConv2d {
+    fn compute(
+        &self,
+        inp: &[(InputTensor, ShapeTracker)],
+        trace_mode: bool,
+    ) -> (
+        Vec<Fixed>,
+        Option<Vec<(usize, usize, Fixed, Fixed, Fixed, Fixed, Fixed, BaseField)>>,
+    ) {
+        let input_positions = self.input_positions(&inp[0].1);
+        let window = *inp[1].1.shape_usize().last().unwrap();
+
+        let output_size = input_positions.len() / window;
+        let mut out_data = vec![Fixed::zero(); output_size];
+        let (input, weight) = (
+            get_buffer_from_tensor(&inp[0].0).unwrap(),
+            get_buffer_from_tensor(&inp[1].0).unwrap(),
+        );
+        let wexpr = (inp[1].1.index_expression(), inp[1].1.valid_expression());
+        let mut stack: Vec<i64> = vec![];
+
+        // Only allocate for intermediate values if in trace mode
+        let mut intermediate_values = if trace_mode {
+            Some(Vec::with_capacity(input_positions.len()))
+        } else {
+            None
+        };
+
+        for (idx, out) in out_data.iter_mut().enumerate() {
+            let mut acc = Fixed::zero();
+            for k in 0..window {
+                let step = idx * window + k;
+                let input_val = input_positions[step].map_or(Fixed::zero(), |i| input.values[i]);
+                let weight_val = get_index(weight, &wexpr, &mut stack, step);
+                let next_acc = acc + Fixed(input_val.0 * weight_val.0); // Exact product
+
+                // Set the output only in the last step of the window
+                let (out_val, is_last_step) = if k == window - 1 {
+                    *out = next_acc;
+                    (next_acc, BaseField::one())
+                } else {
+                    (Fixed::zero(), BaseField::zero())
+                };
+
+                if let Some(values) = &mut intermediate_values {
+                    values.push((
+                        idx,
+                        step,
+                        input_val,
+                        weight_val,
+                        out_val,
+                        acc,
+                        next_acc,
+                        is_last_step,
+                    ));
+                }
+                acc = next_acc;
+            }
+        }
+
+        (out_data, intermediate_values)
+    }
+}
+
+impl LuminairOperator<Conv2dColumn, Conv2dTraceTable, ()> for LuminairConv2d {
+    fn process_trace(
+        &mut self,
+        inp: Vec<(InputTensor, ShapeTracker)>,
+        table: &mut Conv2dTraceTable,
+        node_info: &NodeInfo,
+        _lookup: &mut (),
+    ) -> Result<Vec<Tensor>, LuminairError> {
+        let (out_data, intermediate_values) = self.compute(&inp, true);
+        let intermediate_values = intermediate_values.unwrap();
+
+        let node_id: BaseField = node_info.id.into();
+        let input_id: BaseField = node_info.inputs[0].id.into();
+        let weight_id: BaseField = node_info.inputs[1].id.into();
+        let output_size = out_data.len();
+
+        let input_positions = self.input_positions(&inp[0].1);
+        let weight_positions = get_source_indices(&inp[1].1);
+
+        for entry in intermediate_values {
+            let (idx, step, input_val, weight_val, out_val, acc, next_acc, is_last_step) = entry;
+
+            let out_mult = output_mult(node_info, idx) * is_last_step;
+
+            let is_last_idx: u32 = if idx == (output_size - 1) { 1 } else { 0 };
+
+            table.add_row(Conv2dTraceTableRow {
+                node_id,
+                input_id,
+                weight_id,
+                idx: idx.into(),
+                input_idx: input_positions[step].unwrap_or(0).into(),
+                weight_idx: weight_positions[step].unwrap_or(0).into(),
+                is_last_idx: (is_last_idx).into(),
+                input: input_val.to_m31(),
+                weight: weight_val.to_m31(),
+                out: out_val.to_m31(),
+                acc: acc.to_m31(),
+                next_acc: next_acc.to_m31(),
+                is_last_step,
+                input_mult: input_mult(input_positions[step]),
+                weight_mult: input_mult(weight_positions[step]),
+                out_mult,
+            });
+        }
+
+        Ok(vec![Tensor::new(StwoData::new(
+            out_data,
+            get_product_scale(&inp),
+        ))])
+    }
+}
+
+impl Operator for LuminairConv2d {
+    fn process(&mut self, inp: Vec<(InputTensor, ShapeTracker)>) -> Vec<Tensor> {
+        let (out_data, _) = self.compute(&inp, false);
+        vec![Tensor::new(StwoData::new(
+            out_data,
+            get_product_scale(&inp),
+        ))]
     }
 }
 
@@ -1158,10 +2562,18 @@ impl Compiler for PrimitiveCompiler {
                 *op_ref = LuminairAdd::new().into_operator()
             } else if is::<luminal::op::Mul>(op) {
                 *op_ref = LuminairMul::new().into_operator()
+            } else if is::<luminal::op::Mod>(op) {
+                *op_ref = LuminairMod::new().into_operator()
+            } else if is::<luminal::op::LessThan>(op) {
+                *op_ref = LuminairLessThan::new().into_operator()
             } else if is::<luminal::op::Recip>(op) {
                 *op_ref = LuminairRecip::new().into_operator()
             } else if is::<luminal::op::Sin>(op) {
                 *op_ref = LuminairSin::new().into_operator()
+            } else if is::<luminal::op::Exp2>(op) {
+                *op_ref = LuminairExp2::new().into_operator()
+            } else if is::<luminal::op::Log2>(op) {
+                *op_ref = LuminairLog2::new().into_operator()
             } else if is::<luminal::op::SumReduce>(op) {
                 let dim_index =
                     if let Some(sum_reduce) = op_ref.deref().as_any().downcast_ref::<SumReduce>() {
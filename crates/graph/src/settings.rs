@@ -0,0 +1,112 @@
+use luminair_air::{
+    components::lookups::OutOfRangePolicy, settings::CircuitSettings, DEFAULT_FP_SCALE,
+};
+use luminal::prelude::*;
+
+use crate::graph::infer_circuit_settings;
+
+/// Default margin by which the observed range of each lookup input is widened,
+/// as a fraction of its span.
+pub const DEFAULT_RANGE_MARGIN: f64 = 0.10;
+
+/// Input data of one calibration run: the values to set on each input tensor.
+pub type CalibrationSample = Vec<(NodeIndex, Vec<f32>)>;
+
+/// Builder inferring the `CircuitSettings` of a compiled graph.
+///
+/// Holds the options of the inference: the fixed-point scale of the circuit, the margin
+/// applied to the ranges of the lookup tables, the policy applied to lookup inputs
+/// falling outside of them, and the error allowed to interpolate the lookup tables.
+#[derive(Debug, Clone)]
+pub struct CircuitSettingsBuilder {
+    fp_scale: u32,
+    range_margin: f64,
+    lookup_policy: OutOfRangePolicy,
+    interpolation_error: Option<f64>,
+}
+
+impl Default for CircuitSettingsBuilder {
+    fn default() -> Self {
+        Self {
+            fp_scale: DEFAULT_FP_SCALE,
+            range_margin: DEFAULT_RANGE_MARGIN,
+            lookup_policy: OutOfRangePolicy::default(),
+            interpolation_error: None,
+        }
+    }
+}
+
+impl CircuitSettingsBuilder {
+    /// Creates a builder with the default fixed-point scale and range margin.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the fixed-point scale of the circuit.
+    pub fn fp_scale(mut self, fp_scale: u32) -> Self {
+        self.fp_scale = fp_scale;
+        self
+    }
+
+    /// Sets the margin by which the observed lookup ranges are widened, as a fraction of their span.
+    pub fn range_margin(mut self, range_margin: f64) -> Self {
+        self.range_margin = range_margin;
+        self
+    }
+
+    /// Sets the policy applied to lookup inputs not covered by the inferred ranges.
+    ///
+    /// Defaults to `OutOfRangePolicy::Error`.
+    pub fn lookup_policy(mut self, lookup_policy: OutOfRangePolicy) -> Self {
+        self.lookup_policy = lookup_policy;
+        self
+    }
+
+    /// Sets the maximum error allowed to interpolate the function lookup tables.
+    ///
+    /// Each table is interpolated with the sparsest breakpoints whose interpolation stays
+    /// within `max_error` of its function, and is exact if none does.
+    /// Defaults to exact tables, with an entry per value of their ranges.
+    pub fn interpolation_error(mut self, max_error: f64) -> Self {
+        self.interpolation_error = Some(max_error);
+        self
+    }
+
+    /// Infers the circuit settings of `graph` from the data currently set on its inputs.
+    ///
+    /// Panics if the fixed-point scale exceeds `MAX_FP_SCALE`.
+    pub fn build(&self, graph: &mut Graph) -> CircuitSettings {
+        let mut settings = infer_circuit_settings(
+            graph,
+            &[],
+            self.fp_scale,
+            self.range_margin,
+            self.interpolation_error,
+        );
+        settings.lookups.set_policy(self.lookup_policy);
+        settings
+    }
+
+    /// Infers the circuit settings of `graph` over several input samples.
+    ///
+    /// The graph runs once per sample, and the lookup ranges observed across all runs
+    /// are merged, so that the settings serve every sample. Inputs left out of a sample
+    /// keep the data currently set.
+    ///
+    /// Panics if the fixed-point scale exceeds `MAX_FP_SCALE`.
+    pub fn build_from_calibration(
+        &self,
+        graph: &mut Graph,
+        samples: &[CalibrationSample],
+    ) -> CircuitSettings {
+        let mut settings = infer_circuit_settings(
+            graph,
+            samples,
+            self.fp_scale,
+            self.range_margin,
+            self.interpolation_error,
+        );
+        settings.lookups.set_policy(self.lookup_policy);
+        settings
+    }
+}
@@ -40,13 +40,16 @@ where
 
     cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut result);
     let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
 
     let trace = cx
         .gen_trace(&mut settings)
         .map_err(|e| format!("Trace generation failed for {}: {:?}", name, e))?;
      let (proof, _) = prove(trace, settings.clone())
         .map_err(|e| format!("Proof generation failed for {}: {:?}", name, e))?;
-    verify(proof, settings)
+    verify(proof, &verifying_key, &[])
         .map_err(|e| format!("Proof verification failed for {}: {:?}", name, e))?;
 
     let stwo_result = result.data();
@@ -24,12 +24,16 @@ macro_rules! single_unary_test {
                 cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut c);
 
                 let mut settings = cx.gen_circuit_settings();
+                let verifying_key = cx
+                    .gen_verifying_key(&settings)
+                    .expect("Verifying key generation failed");
                 c.drop();
                 let trace = cx.gen_trace(&mut settings).expect("Trace generation failed");
                  let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
-                verify(proof, settings.clone()).expect("Proof verification failed");
                 // Retrieve output data
                 let stwo_output = c.data();
+                verify(proof, &verifying_key, &[(c.id.index() as u32, stwo_output.clone())])
+                    .expect("Proof verification failed");
 
                 // CPUCompiler comparison
                 let mut cx_cpu = Graph::new();
@@ -117,12 +121,16 @@ macro_rules! single_binary_test {
                 // Compilation and execution using StwoCompiler
                 cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut c);
                 let mut settings = cx.gen_circuit_settings();
+                let verifying_key = cx
+                    .gen_verifying_key(&settings)
+                    .expect("Verifying key generation failed");
                 c.drop();
                 let trace = cx.gen_trace(&mut settings).expect("Trace generation failed");
                  let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
-                verify(proof, settings).expect("Proof verification failed");
                 // Retrieve output data
                 let stwo_output = c.data();
+                verify(proof, &verifying_key, &[(c.id.index() as u32, stwo_output.clone())])
+                    .expect("Proof verification failed");
 
                 // CPUCompiler comparison
                 let mut cx_cpu = Graph::new();
@@ -1,12 +1,28 @@
-use super::{assert_close, random_vec_rng};
+use super::{assert_close, assert_close_precision, random_vec_rng};
 use crate::graph::LuminairGraph;
+use crate::settings::CircuitSettingsBuilder;
 use crate::StwoCompiler;
 use crate::{binary_test, unary_test};
-use luminair_prover::prover::prove;
+use luminair_air::{
+    components::{
+        lookups::{range_check::split_limbs, OutOfRangePolicy},
+        modulo::table::N_RANGE_BITS,
+        MulClaim,
+    },
+    pie::{LuminairPie, TraceTable},
+    settings::CircuitSettings,
+    verifying_key::VerifyingKey,
+};
+use luminair_prover::{prover::prove, LuminairProof};
+use luminair_utils::LuminairError;
 use luminair_verifier::verifier::verify;
 use luminal::prelude::*;
 use luminal_cpu::CPUCompiler;
 use rand::{rngs::StdRng, SeedableRng};
+use stwo_prover::core::{
+    fields::{m31::BaseField, FieldExpOps},
+    vcs::blake2_merkle::Blake2sMerkleHasher,
+};
 
 // The tests are inspired by Luminal's CUDA tests:
 // https://github.com/raphaelDkhn/luminal/blob/main/crates/luminal_cuda/src/tests/fp32.rs
@@ -15,11 +31,268 @@ use rand::{rngs::StdRng, SeedableRng};
 // unary_test!(|a| a.recip(), test_recip, f32, true);
 unary_test!(|a| a.sin(), test_sin, f32, true);
 unary_test!(|a| a.sqrt(), test_sqrt, f32, true);
+unary_test!(|a| a.exp2(), test_exp2, f32, false);
+unary_test!(|a| a.sigmoid(), test_sigmoid, f32, false);
+unary_test!(|a| a.tanh(), test_tanh, f32, false);
+unary_test!(|a| a.gelu(), test_gelu, f32, false);
+unary_test!(|a| a * a.sigmoid(), test_silu, f32, false);
+
+#[test]
+fn test_log2() {
+    // Shift inputs into [0.5, 1.5) to stay within the fixed-point precision of log2.
+    let mut rng = StdRng::seed_from_u64(42);
+    let data: Vec<f32> = random_vec_rng(3 * 4, &mut rng, false)
+        .into_iter()
+        .map(|v| v + 1.0)
+        .collect();
+
+    // Graph setup
+    let mut cx = Graph::new();
+    let a = cx.tensor((3, 4)).set(data.clone());
+    let mut b = a.log2().retrieve();
+
+    // Compilation and execution using StwoCompiler
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut b);
+    let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    b.drop();
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+
+    // CPUCompiler comparison
+    let mut cx_cpu = Graph::new();
+    let a_cpu = cx_cpu.tensor((3, 4)).set(data);
+    let mut b_cpu = a_cpu.log2().retrieve();
+    cx_cpu.compile(<(GenericCompiler, CPUCompiler)>::default(), &mut b_cpu);
+    cx_cpu.execute();
+
+    // Assert outputs are close
+    assert_close(&b.data(), &b_cpu.data());
+}
+
+#[test]
+fn test_log2_non_positive_input() {
+    let mut cx = Graph::new();
+    let a = cx.tensor((1, 4)).set(vec![0.5, 0.25, 0.0, -1.0]);
+    let mut b = a.log2().retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut b);
+    let mut settings = cx.gen_circuit_settings();
+    b.drop();
+
+    match cx.gen_trace(&mut settings) {
+        Err(LuminairError::NonPositiveLog2Input { idx, .. }) => assert_eq!(idx, 2),
+        other => panic!("Expected NonPositiveLog2Input error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_recip_tampered_output_fails() {
+    let mut cx = Graph::new();
+    let a = cx.tensor((1, 4)).set(vec![3.0, -1.5, 0.75, 2.5]);
+    let mut b = a.recip().retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut b);
+    let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    b.drop();
+    let mut trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+
+    // Replace the first remainder by another one below |x|, and solve the reciprocal for the
+    // output in the field: x * out + rem = SCALE^2 still holds, with an unbounded output.
+    let recip_table = trace
+        .trace_tables
+        .iter_mut()
+        .find_map(|table| match table {
+            TraceTable::Recip { table } => Some(table),
+            _ => None,
+        })
+        .expect("Recip trace table should exist");
+    let row = &mut recip_table.table[0];
+    let rem = row.rem + BaseField::from(1);
+    let out = (row.scale * row.scale - rem) * row.input.inverse();
+    row.rem = rem;
+    row.out = out;
+    trace.public_io.outputs[0].values[0] = out;
+
+    assert_tampered_trace_rejected(trace, settings, &verifying_key);
+}
 
 // =============== BINARY ===============
 
 binary_test!(|a, b| a + b, test_add, f32, false);
 binary_test!(|a, b| a * b, test_mul, f32, false);
+binary_test!(|a, b| a.less_than(b), test_less_than, f32, false);
+
+#[test]
+fn test_mod() {
+    // Snap inputs onto a coarse grid so that fixed-point rounding cannot flip the quotient.
+    let mut rng = StdRng::seed_from_u64(42);
+    let snap = |v: f32| (v * 64.0).round() / 64.0;
+    let lhs: Vec<f32> = random_vec_rng(3 * 4, &mut rng, false)
+        .into_iter()
+        .map(|v| snap(v * 4.0))
+        .collect();
+    let rhs: Vec<f32> = random_vec_rng(3 * 4, &mut rng, true)
+        .into_iter()
+        .map(|v| snap(v) + 0.125)
+        .collect();
+
+    // Graph setup
+    let mut cx = Graph::new();
+    let a = cx.tensor((3, 4)).set(lhs.clone());
+    let b = cx.tensor((3, 4)).set(rhs.clone());
+    let mut c = (a % b).retrieve();
+
+    // Compilation and execution using StwoCompiler
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut c);
+    let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    c.drop();
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+
+    // Expected Euclidean remainder
+    let expected: Vec<f32> = lhs
+        .iter()
+        .zip(rhs.iter())
+        .map(|(l, r)| l.rem_euclid(*r))
+        .collect();
+
+    // Assert outputs are close
+    assert_close(&c.data(), &expected);
+}
+
+#[test]
+fn test_mod_by_zero() {
+    let mut cx = Graph::new();
+    let a = cx.tensor((1, 4)).set(vec![0.5, 0.25, 0.75, 1.0]);
+    let b = cx.tensor((1, 4)).set(vec![0.25, 0.5, 0.0, 0.5]);
+    let mut c = (a % b).retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut c);
+    let mut settings = cx.gen_circuit_settings();
+    c.drop();
+
+    match cx.gen_trace(&mut settings) {
+        Err(LuminairError::ModByZero { idx, .. }) => assert_eq!(idx, 2),
+        other => panic!("Expected ModByZero error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_mod_tampered_remainder_fails() {
+    let mut cx = Graph::new();
+    let a = cx.tensor((1, 4)).set(vec![1.5, -0.75, 2.0, 0.5]);
+    let b = cx.tensor((1, 4)).set(vec![0.5, 0.5, 0.75, 0.25]);
+    let mut c = (a % b).retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut c);
+    let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    c.drop();
+    let mut trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+
+    // Replace the first remainder by another one below the divisor, and solve the division
+    // for the quotient in the field: lhs = quotient * rhs + out still holds, with an
+    // unbounded quotient.
+    let mod_table = trace
+        .trace_tables
+        .iter_mut()
+        .find_map(|table| match table {
+            TraceTable::Mod { table } => Some(table),
+            _ => None,
+        })
+        .expect("Mod trace table should exist");
+    let row = &mut mod_table.table[0];
+    let out = row.out + BaseField::from(1);
+    let slack = row.rhs - BaseField::from(1) - out;
+    let limbs = |value: BaseField| {
+        split_limbs(value.0 as i64, N_RANGE_BITS)
+            .unwrap()
+            .map(|limb| BaseField::from(limb as u32))
+    };
+    row.quotient = (row.lhs - out) * row.rhs.inverse();
+    row.out = out;
+    row.rem_limbs = limbs(out);
+    row.slack_limbs = limbs(slack);
+    trace.public_io.outputs[0].values[0] = out;
+
+    assert_tampered_trace_rejected(trace, settings, &verifying_key);
+}
+
+#[test]
+fn test_mul_large_product() {
+    // The product of 300 by itself fits in M31 once rounded back to the default scale,
+    // but not exactly at twice that scale, where the Rescale would only see it modulo P.
+    let mut cx = Graph::new();
+    let a = cx.tensor((1, 4)).set(vec![1.0, 2.0, 300.0, 4.0]);
+    let mut b = (a * a).retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut b);
+    let mut settings = cx.gen_circuit_settings();
+    b.drop();
+
+    match cx.gen_trace(&mut settings) {
+        Err(LuminairError::FixedPointOverflow { idx, .. }) => assert_eq!(idx, 2),
+        other => panic!("Expected FixedPointOverflow error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_mul_overflow() {
+    // The product of 1000 by itself does not fit in M31 at the default scale.
+    let mut cx = Graph::new();
+    let a = cx.tensor((1, 4)).set(vec![1.0, 2.0, 1000.0, 4.0]);
+    let mut b = (a * a).retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut b);
+    let mut settings = cx.gen_circuit_settings();
+    b.drop();
+
+    match cx.gen_trace(&mut settings) {
+        Err(LuminairError::FixedPointOverflow { idx, .. }) => assert_eq!(idx, 2),
+        other => panic!("Expected FixedPointOverflow error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_less_than_diff_overflow() {
+    // Operands 2^18 apart differ by 2^30 at the default scale, beyond the range-checked bits.
+    let mut cx = Graph::new();
+    let a = cx.tensor((1, 4)).set(vec![1.0, 2.0, 131072.0, 4.0]);
+    let b = cx.tensor((1, 4)).set(vec![2.0, 1.0, -131072.0, 4.0]);
+    let mut c = a.less_than(b).retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut c);
+    let mut settings = cx.gen_circuit_settings();
+    c.drop();
+
+    match cx.gen_trace(&mut settings) {
+        Err(LuminairError::RangeCheckOverflow { op, idx, .. }) => {
+            assert_eq!((op, idx), ("LessThan", 2))
+        }
+        other => panic!("Expected RangeCheckOverflow error, got {other:?}"),
+    }
+}
 
 // =============== REDUCE ===============
 
@@ -42,6 +315,9 @@ fn test_sum_reduce() {
     );
 
     let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
     b.drop();
     c.drop();
     d.drop();
@@ -49,7 +325,7 @@ fn test_sum_reduce() {
         .gen_trace(&mut settings)
         .expect("Trace generation failed");
      let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
-    verify(proof, settings)
+    verify(proof, &verifying_key, &[])
         .expect("Proof verification failed");
 
     // CPUCompiler comparison
@@ -88,6 +364,9 @@ fn test_max_reduce() {
         (&mut b, &mut c, &mut d),
     );
     let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
     b.drop();
     c.drop();
     d.drop();
@@ -95,7 +374,7 @@ fn test_max_reduce() {
         .gen_trace(&mut settings)
         .expect("Trace generation failed");
     let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
-    verify(proof, settings)
+    verify(proof, &verifying_key, &[])
         .expect("Proof verification failed");
 
     // CPUCompiler comparison
@@ -115,3 +394,1039 @@ fn test_max_reduce() {
     assert_close(&c.data(), &c_cpu.data());
     assert_close(&d.data(), &d_cpu.data());
 }
+
+#[test]
+fn test_max_reduce_diff_overflow() {
+    // An input 2^18 below the running max differs by 2^30 at the default scale, beyond the
+    // range-checked bits.
+    let mut cx = Graph::new();
+    let a = cx.tensor((1, 4)).set(vec![1.0, 131072.0, -131072.0, 4.0]);
+    let mut b = a.max_reduce(1).retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut b);
+    let mut settings = cx.gen_circuit_settings();
+    b.drop();
+
+    match cx.gen_trace(&mut settings) {
+        Err(LuminairError::RangeCheckOverflow { op, idx, .. }) => {
+            assert_eq!((op, idx), ("MaxReduce", 0))
+        }
+        other => panic!("Expected RangeCheckOverflow error, got {other:?}"),
+    }
+}
+
+// =============== DATAFLOW ===============
+
+/// Proves a tampered trace, and asserts that it is rejected: either by the prover, as the
+/// constraints no longer hold, or by the verifier.
+fn assert_tampered_trace_rejected(
+    trace: LuminairPie,
+    settings: CircuitSettings,
+    verifying_key: &VerifyingKey,
+) {
+    match prove(trace, settings) {
+        Err(LuminairError::ProverError(_)) => {}
+        Ok((proof, _)) => assert!(
+            verify(proof, verifying_key, &[]).is_err(),
+            "Tampered trace should fail verification"
+        ),
+        Err(other) => panic!("Expected ProverError, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_permuted_trace_fails_verification() {
+    // Graph setup: the Add node consumes the intermediate outputs of both Mul nodes.
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut cx = Graph::new();
+    let a = cx.tensor((2, 4)).set(random_vec_rng(2 * 4, &mut rng, false));
+    let b = cx.tensor((2, 4)).set(random_vec_rng(2 * 4, &mut rng, false));
+    let mut c = ((a * b) + (b * b)).retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut c);
+    let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    c.drop();
+    let mut trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+
+    // Swap the values of the first two Add rows, keeping their indices.
+    // Every row still satisfies `lhs + rhs = out` and the values read are the same as a multiset,
+    // but they no longer match the elements produced at those positions.
+    let add_table = trace
+        .trace_tables
+        .iter_mut()
+        .find_map(|table| match table {
+            TraceTable::Add { table } => Some(table),
+            _ => None,
+        })
+        .expect("Add trace table should exist");
+    let (first, second) = (add_table.table[0], add_table.table[1]);
+    for (row, from) in [(0, second), (1, first)] {
+        add_table.table[row].lhs = from.lhs;
+        add_table.table[row].rhs = from.rhs;
+        add_table.table[row].out = from.out;
+    }
+
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    match verify(proof, &verifying_key, &[]) {
+        Err(LuminairError::InvalidLogUp(_)) => {}
+        other => panic!("Expected InvalidLogUp error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_rewired_trace_fails_verification() {
+    // Graph setup: the Add node consumes the intermediate outputs of both Mul nodes.
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut cx = Graph::new();
+    let a = cx.tensor((2, 4)).set(random_vec_rng(2 * 4, &mut rng, false));
+    let b = cx.tensor((2, 4)).set(random_vec_rng(2 * 4, &mut rng, false));
+    let mut c = ((a * b) + (b * b)).retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut c);
+    let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    c.drop();
+    let mut trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+
+    // Make the first Add row read its left operand from the node feeding its right operand.
+    // The IDs of the rows are fixed by the preprocessed trace of the verifying key.
+    let add_table = trace
+        .trace_tables
+        .iter_mut()
+        .find_map(|table| match table {
+            TraceTable::Add { table } => Some(table),
+            _ => None,
+        })
+        .expect("Add trace table should exist");
+    add_table.table[0].lhs_id = add_table.table[0].rhs_id;
+
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    match verify(proof, &verifying_key, &[]) {
+        Err(LuminairError::PreprocessedTraceMismatch) => {}
+        other => panic!("Expected PreprocessedTraceMismatch error, got {other:?}"),
+    }
+}
+
+// =============== RESCALE ===============
+
+#[test]
+fn test_rescale_after_reduced_products() {
+    // Graph setup: a dot product followed by another product.
+    let mut rng = StdRng::seed_from_u64(3);
+    let lhs = random_vec_rng(3 * 8, &mut rng, false);
+    let rhs = random_vec_rng(3 * 8, &mut rng, false);
+    let scale = random_vec_rng(3, &mut rng, false);
+    let mut cx = Graph::new();
+    let a = cx.tensor((3, 8)).set(lhs.clone());
+    let b = cx.tensor((3, 8)).set(rhs.clone());
+    let s = cx.tensor(3).set(scale.clone());
+    let mut c = ((a * b).sum_reduce(1) * s).retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut c);
+    let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    c.drop();
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+
+    // Products are only rounded once summed, and again before the output.
+    let rescale_rows = trace
+        .trace_tables
+        .iter()
+        .find_map(|table| match table {
+            TraceTable::Rescale { table } => Some(table.table.len()),
+            _ => None,
+        })
+        .expect("Rescale trace table should exist");
+    assert_eq!(rescale_rows, 2 * 3);
+
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+
+    // CPUCompiler comparison
+    let mut cx_cpu = Graph::new();
+    let a_cpu = cx_cpu.tensor((3, 8)).set(lhs);
+    let b_cpu = cx_cpu.tensor((3, 8)).set(rhs);
+    let s_cpu = cx_cpu.tensor(3).set(scale);
+    let mut c_cpu = ((a_cpu * b_cpu).sum_reduce(1) * s_cpu).retrieve();
+    cx_cpu.compile(<(GenericCompiler, CPUCompiler)>::default(), &mut c_cpu);
+    cx_cpu.execute();
+
+    // Assert outputs are close
+    assert_close(&c.data(), &c_cpu.data());
+}
+
+#[test]
+fn test_rescale_tampered_remainder_fails() {
+    let mut cx = Graph::new();
+    let a = cx.tensor((1, 4)).set(vec![1.5, -2.0, 0.75, 3.0]);
+    let mut b = (a * a).retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut b);
+    let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    b.drop();
+    let mut trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+
+    // Replace the first remainder by another one below 2^s, and solve the rounding division
+    // for the output in the field: input + 2^(s - 1) = out * 2^s + rem still holds, with an
+    // output far outside (-2^(30 - s), 2^(30 - s)).
+    let divisor = BaseField::from_u32_unchecked(1 << settings.fp_scale);
+    let half = BaseField::from_u32_unchecked(1 << (settings.fp_scale - 1));
+    let rescale_table = trace
+        .trace_tables
+        .iter_mut()
+        .find_map(|table| match table {
+            TraceTable::Rescale { table } => Some(table),
+            _ => None,
+        })
+        .expect("Rescale trace table should exist");
+    let row = &mut rescale_table.table[0];
+    let rem = row.rem + BaseField::from(1);
+    let out = (row.input + half - rem) * divisor.inverse();
+    row.rem = rem;
+    row.out = out;
+    trace.public_io.outputs[0].values[0] = out;
+
+    assert_tampered_trace_rejected(trace, settings, &verifying_key);
+}
+
+// =============== PUBLIC IO ===============
+
+#[test]
+fn test_public_outputs() {
+    // Graph setup
+    let mut cx = Graph::new();
+    let a = cx.tensor((2, 2)).set(vec![1.0, 2.0, 3.0, 4.0]);
+    let b = cx.tensor((2, 2)).set(vec![1.0, 2.0, 3.0, 4.0]);
+    let mut c = (a * b).retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut c);
+    let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    c.drop();
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    let proof_bytes = proof.to_bincode().expect("Proof serialization failed");
+    let load_proof =
+        || LuminairProof::from_bincode(&proof_bytes).expect("Proof deserialization failed");
+
+    let output_id = c.id.index() as u32;
+    assert_eq!(proof.public_io.inputs.len(), 2);
+    assert_eq!(proof.public_io.outputs.len(), 1);
+    assert_eq!(proof.public_io.outputs[0].tensor_id, output_id);
+
+    // The proof verifies against the actual outputs...
+    verify(
+        load_proof(),
+        &verifying_key,
+        &[(output_id, vec![1.0, 4.0, 9.0, 16.0])],
+    )
+    .expect("Proof verification failed");
+
+    // ...but not against different ones.
+    match verify(
+        load_proof(),
+        &verifying_key,
+        &[(output_id, vec![1.0, 4.0, 9.0, 17.0])],
+    ) {
+        Err(LuminairError::PublicOutputMismatch { tensor_id }) => assert_eq!(tensor_id, output_id),
+        other => panic!("Expected PublicOutputMismatch error, got {other:?}"),
+    }
+
+    // Tampering with the public outputs breaks the LogUp balance.
+    let mut tampered = load_proof();
+    tampered.public_io.outputs[0].values[3] += BaseField::from(1);
+    match verify(tampered, &verifying_key, &[]) {
+        Err(LuminairError::InvalidLogUp(_)) => {}
+        other => panic!("Expected InvalidLogUp error, got {other:?}"),
+    }
+
+    // The public tensors must be those of the verifying key...
+    let mut tampered = load_proof();
+    tampered.public_io.inputs.pop();
+    match verify(tampered, &verifying_key, &[]) {
+        Err(LuminairError::PublicIoMismatch) => {}
+        other => panic!("Expected PublicIoMismatch error, got {other:?}"),
+    }
+
+    // ...and their multiplicities are taken from the key rather than from the proof.
+    let mut tampered = load_proof();
+    tampered.public_io.outputs[0].multiplicities[0] = 2;
+    tampered.public_io.inputs[0].multiplicities[0] = 0;
+    verify(tampered, &verifying_key, &[]).expect("Proof verification failed");
+}
+
+// =============== FIXED-POINT SCALE ===============
+
+#[test]
+fn test_fixed_point_scale() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let lhs: Vec<f32> = random_vec_rng(2 * 3, &mut rng, false)
+        .into_iter()
+        .map(|v| v + 1.0)
+        .collect();
+    let rhs: Vec<f32> = random_vec_rng(2 * 3, &mut rng, false)
+        .into_iter()
+        .map(|v| v + 1.0)
+        .collect();
+
+    // Graph setup
+    let mut cx = Graph::new();
+    let a = cx.tensor((2, 3)).set(lhs.clone());
+    let b = cx.tensor((2, 3)).set(rhs.clone());
+    let mut c = (a * b).sqrt().retrieve();
+
+    // Prove at a finer scale than the default one
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut c);
+    let mut settings = cx.gen_circuit_settings_with_scale(14);
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    let settings_default = cx.gen_circuit_settings();
+    let verifying_key_default = cx
+        .gen_verifying_key(&settings_default)
+        .expect("Verifying key generation failed");
+    assert_eq!(settings.fp_scale, 14);
+    assert_eq!(verifying_key.fp_scale, 14);
+    c.drop();
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    let proof_bytes = proof.to_bincode().expect("Proof serialization failed");
+    let load_proof =
+        || LuminairProof::from_bincode(&proof_bytes).expect("Proof deserialization failed");
+
+    // Outputs are checked at the scale of the verifying key
+    let output_id = c.id.index() as u32;
+    verify(load_proof(), &verifying_key, &[(output_id, c.data())])
+        .expect("Proof verification failed");
+
+    // The scale is part of the verifying key
+    match verify(load_proof(), &verifying_key_default, &[]) {
+        Err(LuminairError::VerifyingKeyMismatch) => {}
+        other => panic!("Expected VerifyingKeyMismatch error, got {other:?}"),
+    }
+
+    // Assert outputs are close
+    let expected: Vec<f32> = lhs
+        .iter()
+        .zip(rhs.iter())
+        .map(|(l, r)| (l * r).sqrt())
+        .collect();
+    assert_close(&c.data(), &expected);
+}
+
+// =============== CALIBRATION ===============
+
+#[test]
+fn test_circuit_settings_from_calibration() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let low = random_vec_rng(2 * 3, &mut rng, false);
+    let high: Vec<f32> = random_vec_rng(2 * 3, &mut rng, false)
+        .into_iter()
+        .map(|v| v + 4.0)
+        .collect();
+
+    // Graph setup
+    let mut cx = Graph::new();
+    let a = cx.tensor((2, 3)).set(low.clone());
+    let mut b = a.sin().retrieve();
+
+    // Calibrate over both samples, without widening the observed ranges
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut b);
+    let mut settings = CircuitSettingsBuilder::new()
+        .range_margin(0.0)
+        .build_from_calibration(&mut cx, &[vec![(a.id, low)], vec![(a.id, high.clone())]]);
+    let sin_layout = &settings.lookups.sin.as_ref().expect("Sin lookup should exist").layout;
+    assert_eq!(sin_layout.ranges.len(), 2);
+
+    // The settings also serve the data of the second sample
+    a.set(high.clone());
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    b.drop();
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+
+    // Assert outputs are close
+    let expected: Vec<f32> = high.iter().map(|v| v.sin()).collect();
+    assert_close(&b.data(), &expected);
+}
+
+// =============== OUT-OF-RANGE LOOKUPS ===============
+
+/// Builds `sin(a)` with a Sin table covering `[0, 1.5]` only, then sets inputs beyond it.
+fn sin_beyond_table(cx: &mut Graph, policy: OutOfRangePolicy) -> (GraphTensor, CircuitSettings) {
+    let a = cx.tensor((1, 4)).set(vec![0.0, 0.5, 1.0, 1.5]);
+    let mut b = a.sin().retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut b);
+    let settings = CircuitSettingsBuilder::new()
+        .range_margin(0.0)
+        .lookup_policy(policy)
+        .build(cx);
+    a.set(vec![-2.0, 0.5, 1.0, 3.0]);
+    (b, settings)
+}
+
+#[test]
+fn test_lookup_out_of_range_error() {
+    let mut cx = Graph::new();
+    let (mut b, mut settings) = sin_beyond_table(&mut cx, OutOfRangePolicy::Error);
+    b.drop();
+
+    match cx.gen_trace(&mut settings) {
+        Err(LuminairError::LookupOutOfRange {
+            op, value, layout, ..
+        }) => {
+            assert_eq!(op, "Sin");
+            assert_eq!(value, -2.0);
+            assert_eq!(layout, vec![(0.0, 1.5)]);
+        }
+        other => panic!("Expected LookupOutOfRange error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_lookup_out_of_range_clamp() {
+    let mut cx = Graph::new();
+    let (mut b, mut settings) = sin_beyond_table(&mut cx, OutOfRangePolicy::Clamp);
+
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    b.drop();
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+
+    // Inputs beyond the table take the output of its nearest bound
+    let expected: Vec<f32> = [0.0f32, 0.5, 1.0, 1.5].iter().map(|v| v.sin()).collect();
+    assert_close(&b.data(), &expected);
+}
+
+#[test]
+fn test_lookup_out_of_range_grow() {
+    let mut cx = Graph::new();
+    let (mut b, mut settings) = sin_beyond_table(&mut cx, OutOfRangePolicy::Grow);
+    b.drop();
+
+    match cx.gen_trace(&mut settings) {
+        Err(LuminairError::LookupOutOfRange { .. }) => {}
+        other => panic!("Expected LookupOutOfRange error, got {other:?}"),
+    }
+
+    // The grown settings cover the inputs: the trace is generated again
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    b.drop();
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+
+    // Assert outputs are close
+    let expected: Vec<f32> = [-2.0f32, 0.5, 1.0, 3.0].iter().map(|v| v.sin()).collect();
+    assert_close(&b.data(), &expected);
+}
+
+// =============== INTERPOLATED LOOKUPS ===============
+
+#[test]
+fn test_interpolated_lookup() {
+    let data: Vec<f32> = (0..16).map(|i| -3.0 + 0.4 * i as f32).collect();
+
+    // Graph setup
+    let mut cx = Graph::new();
+    let a = cx.tensor((4, 4)).set(data.clone());
+    let mut b = a.sin().retrieve();
+
+    // Interpolate the Sin table within 1e-3 of the function
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut b);
+    let mut settings = CircuitSettingsBuilder::new()
+        .interpolation_error(1e-3)
+        .build(&mut cx);
+    let exact_settings = CircuitSettingsBuilder::new().build(&mut cx);
+    let sin_layout = &settings.lookups.sin.as_ref().expect("Sin lookup should exist").layout;
+    let exact_layout = &exact_settings.lookups.sin.as_ref().unwrap().layout;
+    assert!(sin_layout.interpolation.is_some());
+    assert!(sin_layout.log_size < exact_layout.log_size);
+
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    b.drop();
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+
+    // Assert outputs are within the interpolation error, up to the fixed-point rounding
+    let expected: Vec<f32> = data.iter().map(|v| v.sin()).collect();
+    assert_close_precision(&b.data(), &expected, 2e-3);
+}
+
+// =============== FUSED ACTIVATIONS ===============
+
+#[test]
+fn test_activation_fusion() {
+    let mut cx = Graph::new();
+    let a = cx.tensor((2, 3)).set(vec![-2.0, -0.5, 0.0, 0.5, 1.0, 2.0]);
+    let mut b = a.sigmoid().retrieve();
+    let mut c = a.tanh().retrieve();
+
+    cx.compile(
+        <(GenericCompiler, StwoCompiler)>::default(),
+        (&mut b, &mut c),
+    );
+
+    let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+
+    // Each activation is a single operator, without the exp2 of its lowering
+    let op_counter = &trace.metadata.execution_resources.op_counter;
+    assert_eq!(op_counter.sigmoid, 1);
+    assert_eq!(op_counter.tanh, 1);
+    assert_eq!(op_counter.exp2, 0);
+
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+
+    let data = [-2.0f32, -0.5, 0.0, 0.5, 1.0, 2.0];
+    let expected: Vec<f32> = data.iter().map(|v| 1.0 / (1.0 + (-v).exp())).collect();
+    assert_close(&b.data(), &expected);
+    let expected: Vec<f32> = data.iter().map(|v| v.tanh()).collect();
+    assert_close(&c.data(), &expected);
+}
+
+#[test]
+fn test_gelu_silu_fusion() {
+    let data = vec![-2.0, -0.5, 0.0, 0.5, 1.0, 2.0];
+    let mut cx = Graph::new();
+    let a = cx.tensor((2, 3)).set(data.clone());
+    let mut b = a.gelu().retrieve();
+    let mut c = (a * a.sigmoid()).retrieve();
+
+    cx.compile(
+        <(GenericCompiler, StwoCompiler)>::default(),
+        (&mut b, &mut c),
+    );
+
+    let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+
+    // The products and sums around the tanh and sigmoid are fused with them
+    let op_counter = &trace.metadata.execution_resources.op_counter;
+    assert_eq!(op_counter.gelu, 1);
+    assert_eq!(op_counter.silu, 1);
+    assert_eq!(op_counter.tanh + op_counter.sigmoid, 0);
+    assert_eq!(op_counter.mul + op_counter.add, 0);
+
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+
+    let mut cx_cpu = Graph::new();
+    let a_cpu = cx_cpu.tensor((2, 3)).set(data);
+    let mut b_cpu = a_cpu.gelu().retrieve();
+    let mut c_cpu = (a_cpu * a_cpu.sigmoid()).retrieve();
+    cx_cpu.compile(
+        <(GenericCompiler, CPUCompiler)>::default(),
+        (&mut b_cpu, &mut c_cpu),
+    );
+    cx_cpu.execute();
+    assert_close(&b.data(), &b_cpu.data());
+    assert_close(&c.data(), &c_cpu.data());
+}
+
+// =============== MATMUL ===============
+
+#[test]
+fn test_matmul() {
+    // Graph setup: a (3, 8) x (8, 4) matrix multiplication.
+    let mut rng = StdRng::seed_from_u64(5);
+    let lhs = random_vec_rng(3 * 8, &mut rng, false);
+    let rhs = random_vec_rng(8 * 4, &mut rng, false);
+    let mut cx = Graph::new();
+    let a = cx.tensor((3, 8)).set(lhs.clone());
+    let b = cx.tensor((8, 4)).set(rhs.clone());
+    let mut c = a.matmul(b).retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut c);
+    let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+
+    // The broadcast products and their sum are fused into a single node
+    let op_counter = &trace.metadata.execution_resources.op_counter;
+    assert_eq!(op_counter.matmul, 1);
+    assert_eq!(op_counter.mul + op_counter.sum_reduce, 0);
+
+    // Each dot product is only rounded once, before the output.
+    let rescale_rows = trace
+        .trace_tables
+        .iter()
+        .find_map(|table| match table {
+            TraceTable::Rescale { table } => Some(table.table.len()),
+            _ => None,
+        })
+        .expect("Rescale trace table should exist");
+    assert_eq!(rescale_rows, 3 * 4);
+
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+
+    // CPUCompiler comparison
+    let mut cx_cpu = Graph::new();
+    let a_cpu = cx_cpu.tensor((3, 8)).set(lhs);
+    let b_cpu = cx_cpu.tensor((8, 4)).set(rhs);
+    let mut c_cpu = a_cpu.matmul(b_cpu).retrieve();
+    cx_cpu.compile(<(GenericCompiler, CPUCompiler)>::default(), &mut c_cpu);
+    cx_cpu.execute();
+
+    // Assert outputs are close
+    assert_close(&c.data(), &c_cpu.data());
+}
+
+// =============== SOFTMAX ===============
+
+#[test]
+fn test_softmax() {
+    // Graph setup: a row-wise softmax of a (3, 5) tensor.
+    let mut rng = StdRng::seed_from_u64(7);
+    let data = random_vec_rng(3 * 5, &mut rng, false);
+    let mut cx = Graph::new();
+    let a = cx.tensor((3, 5)).set(data.clone());
+    let mut b = a.softmax(1).retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut b);
+    let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+
+    // The max, exponentials, sum and reciprocal are fused into a single node
+    let op_counter = &trace.metadata.execution_resources.op_counter;
+    assert_eq!(op_counter.softmax, 1);
+    assert_eq!(op_counter.max_reduce + op_counter.exp2, 0);
+    assert_eq!(op_counter.sum_reduce + op_counter.recip, 0);
+
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+
+    // CPUCompiler comparison
+    let mut cx_cpu = Graph::new();
+    let a_cpu = cx_cpu.tensor((3, 5)).set(data);
+    let mut b_cpu = a_cpu.softmax(1).retrieve();
+    cx_cpu.compile(<(GenericCompiler, CPUCompiler)>::default(), &mut b_cpu);
+    cx_cpu.execute();
+
+    // Assert outputs are close
+    assert_close(&b.data(), &b_cpu.data());
+}
+
+// =============== NORM ===============
+
+#[test]
+fn test_layer_norm() {
+    // Graph setup: a row-wise layer norm of a (3, 8) tensor.
+    let mut rng = StdRng::seed_from_u64(11);
+    let data = random_vec_rng(3 * 8, &mut rng, false);
+    let mut cx = Graph::new();
+    let a = cx.tensor((3, 8)).set(data.clone());
+    let mut b = a.layer_norm(1, 1e-5).retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut b);
+    let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+
+    // The mean, variance, root and reciprocal are fused into a single node
+    let op_counter = &trace.metadata.execution_resources.op_counter;
+    assert_eq!(op_counter.norm, 1);
+    assert_eq!(op_counter.sum_reduce + op_counter.sqrt, 0);
+    assert_eq!(op_counter.recip, 0);
+
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+
+    // CPUCompiler comparison
+    let mut cx_cpu = Graph::new();
+    let a_cpu = cx_cpu.tensor((3, 8)).set(data);
+    let mut b_cpu = a_cpu.layer_norm(1, 1e-5).retrieve();
+    cx_cpu.compile(<(GenericCompiler, CPUCompiler)>::default(), &mut b_cpu);
+    cx_cpu.execute();
+
+    // The variance is rounded at the fixed-point scale
+    assert_close_precision(&b.data(), &b_cpu.data(), 1e-2);
+}
+
+#[test]
+fn test_rms_norm_with_affine() {
+    // Graph setup: a row-wise RMS norm of a (3, 8) tensor, scaled and shifted per column.
+    let mut rng = StdRng::seed_from_u64(13);
+    let data = random_vec_rng(3 * 8, &mut rng, false);
+    let weight = random_vec_rng(8, &mut rng, false);
+    let bias = random_vec_rng(8, &mut rng, false);
+    let rms_norm = |cx: &mut Graph| {
+        let a = cx.tensor((3, 8)).set(data.clone());
+        let w = cx.tensor(8).set(weight.clone());
+        let b = cx.tensor(8).set(bias.clone());
+        (a.std_norm(1, 1e-5) * w.expand(0, 3) + b.expand(0, 3)).retrieve()
+    };
+    let mut cx = Graph::new();
+    let mut out = rms_norm(&mut cx);
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut out);
+    let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+
+    // The weight and bias are applied by the norm itself
+    let op_counter = &trace.metadata.execution_resources.op_counter;
+    assert_eq!(op_counter.norm, 1);
+    assert_eq!(op_counter.sum_reduce + op_counter.sqrt, 0);
+    assert_eq!(op_counter.recip, 0);
+    assert_eq!(op_counter.mul + op_counter.add, 0);
+
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+
+    // CPUCompiler comparison
+    let mut cx_cpu = Graph::new();
+    let mut out_cpu = rms_norm(&mut cx_cpu);
+    cx_cpu.compile(<(GenericCompiler, CPUCompiler)>::default(), &mut out_cpu);
+    cx_cpu.execute();
+
+    // The mean of the squares is rounded at the fixed-point scale
+    assert_close_precision(&out.data(), &out_cpu.data(), 1e-2);
+}
+
+// =============== CONV2D ===============
+
+/// Convolves a `(ch_in, height, width)` input with `(ch_out, ch_in * kernel * kernel)` weights
+/// as luminal_nn's `Conv2D` does, through the matmul of the weights with the im2col matrix of
+/// the input.
+fn conv2d(
+    input: GraphTensor,
+    weight: GraphTensor,
+    ch_in: usize,
+    kernel: usize,
+    stride: usize,
+    out: (usize, usize),
+) -> GraphTensor {
+    let cols = input
+        .pool_last_dim(kernel, stride, 0)
+        .permute((0, 2, 3, 1))
+        .pool_last_dim(kernel, stride, 0)
+        .permute((0, 4, 2, 3, 1))
+        .reshape((ch_in * kernel * kernel, out.0 * out.1));
+    weight.matmul(cols)
+}
+
+#[test]
+fn test_conv2d() {
+    // Graph setup: a 3x3 convolution with stride 2 of a (2, 5, 5) input into 3 channels.
+    let mut rng = StdRng::seed_from_u64(13);
+    let input = random_vec_rng(2 * 5 * 5, &mut rng, false);
+    let weight = random_vec_rng(3 * 2 * 3 * 3, &mut rng, false);
+    let mut cx = Graph::new();
+    let a = cx.tensor((2, 5, 5)).set(input.clone());
+    let w = cx.tensor((3, 2 * 3 * 3)).set(weight.clone());
+    let mut b = conv2d(a, w, 2, 3, 2, (2, 2)).retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut b);
+    let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+
+    // The im2col gather and the matmul are fused into a single node, whose kernel, stride
+    // and padding are part of the verifying key.
+    let op_counter = &trace.metadata.execution_resources.op_counter;
+    assert_eq!(op_counter.conv2d, 1);
+    assert_eq!(op_counter.matmul, 0);
+    assert_eq!(op_counter.mul + op_counter.sum_reduce, 0);
+    let conv = "LuminairConv2d { kernel: (3, 3), stride: (2, 2), padding: (0, 0) }";
+    assert!(verifying_key.nodes.iter().any(|n| n.op.contains(conv)));
+
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+
+    // CPUCompiler comparison
+    let mut cx_cpu = Graph::new();
+    let a_cpu = cx_cpu.tensor((2, 5, 5)).set(input);
+    let w_cpu = cx_cpu.tensor((3, 2 * 3 * 3)).set(weight);
+    let mut b_cpu = conv2d(a_cpu, w_cpu, 2, 3, 2, (2, 2)).retrieve();
+    cx_cpu.compile(<(GenericCompiler, CPUCompiler)>::default(), &mut b_cpu);
+    cx_cpu.execute();
+
+    // Assert outputs are close
+    assert_close(&b.data(), &b_cpu.data());
+}
+
+// =============== RELU ===============
+
+#[test]
+fn test_relu() {
+    let data = vec![-3.5, -1.0, -0.25, 0.0, 0.25, 1.0, 2.5, 7.0];
+    let mut cx = Graph::new();
+    let a = cx.tensor((2, 4)).set(data.clone());
+    let mut b = a.relu().retrieve();
+
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut b);
+    let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+
+    // The comparison with zero and the selection around it are fused into a single node
+    let op_counter = &trace.metadata.execution_resources.op_counter;
+    assert_eq!(op_counter.relu, 1);
+    assert_eq!(op_counter.less_than, 0);
+    assert_eq!(op_counter.mul + op_counter.add, 0);
+
+    let (proof, _) = prove(trace, settings.clone()).expect("Proof generation failed");
+    verify(proof, &verifying_key, &[]).expect("Proof verification failed");
+
+    // CPUCompiler comparison
+    let mut cx_cpu = Graph::new();
+    let a_cpu = cx_cpu.tensor((2, 4)).set(data);
+    let mut b_cpu = a_cpu.relu().retrieve();
+    cx_cpu.compile(<(GenericCompiler, CPUCompiler)>::default(), &mut b_cpu);
+    cx_cpu.execute();
+
+    // Assert outputs are close
+    assert_close(&b.data(), &b_cpu.data());
+}
+
+// =============== VERIFYING KEY ===============
+
+#[test]
+fn test_proof_fails_with_other_verifying_key() {
+    let data = vec![1.0, 2.0, 3.0, 4.0];
+
+    // Graph setup for the proven model
+    let mut cx = Graph::new();
+    let a = cx.tensor((2, 2)).set(data.clone());
+    let mut b = (a * a).retrieve();
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut b);
+    let mut settings = cx.gen_circuit_settings();
+    b.drop();
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+    let (proof, _) = prove(trace, settings).expect("Proof generation failed");
+
+    // Same shapes, different operation
+    let mut cx_other = Graph::new();
+    let a_other = cx_other.tensor((2, 2)).set(data);
+    let mut b_other = (a_other + a_other).retrieve();
+    cx_other.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut b_other);
+    let settings_other = cx_other.gen_circuit_settings();
+    let verifying_key_other = cx_other
+        .gen_verifying_key(&settings_other)
+        .expect("Verifying key generation failed");
+
+    match verify(proof, &verifying_key_other, &[]) {
+        Err(LuminairError::VerifyingKeyMismatch) => {}
+        other => panic!("Expected VerifyingKeyMismatch error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_proof_fails_with_other_claim() {
+    let mut cx = Graph::new();
+    let a = cx.tensor((2, 2)).set(vec![1.0, 2.0, 3.0, 4.0]);
+    let mut b = (a * a).retrieve();
+    cx.compile(<(GenericCompiler, StwoCompiler)>::default(), &mut b);
+    let mut settings = cx.gen_circuit_settings();
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    assert!(verifying_key.claim.mul.is_some());
+    b.drop();
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+    let (mut proof, _) = prove(trace, settings).expect("Proof generation failed");
+
+    // The components of the proof and their sizes are fixed by the verifying key.
+    proof.claim.mul = proof
+        .claim
+        .mul
+        .map(|claim| MulClaim::new(claim.log_size + 1));
+    match verify(proof, &verifying_key, &[]) {
+        Err(LuminairError::ClaimMismatch) => {}
+        other => panic!("Expected ClaimMismatch error, got {other:?}"),
+    }
+}
+
+// =============== COMMITTED WEIGHTS ===============
+
+/// Builds `x * w` with `w` committed in the verifying key, and returns the proof,
+/// the verifying key and the IDs of the weights and output tensors.
+fn prove_with_committed_weights(
+    weights: Vec<f32>,
+) -> (LuminairProof<Blake2sMerkleHasher>, VerifyingKey, u32, u32) {
+    let mut cx = Graph::new();
+    let x = cx.tensor((2, 2)).set(vec![1.0, 2.0, 3.0, 4.0]);
+    let mut w = cx.tensor((2, 2)).set(weights);
+    let mut out = (x * w).retrieve();
+
+    cx.compile(
+        <(GenericCompiler, StwoCompiler)>::default(),
+        (&mut out, &mut w),
+    );
+    let mut settings = cx.gen_circuit_settings();
+    settings.committed_weights.push(w.id.index() as u32);
+    let verifying_key = cx
+        .gen_verifying_key(&settings)
+        .expect("Verifying key generation failed");
+    out.drop();
+    let trace = cx
+        .gen_trace(&mut settings)
+        .expect("Trace generation failed");
+    let (proof, _) = prove(trace, settings).expect("Proof generation failed");
+
+    (
+        proof,
+        verifying_key,
+        w.id.index() as u32,
+        out.id.index() as u32,
+    )
+}
+
+#[test]
+fn test_committed_weights() {
+    let (proof, verifying_key, weights_id, output_id) =
+        prove_with_committed_weights(vec![1.0, 2.0, 3.0, 4.0]);
+
+    // The weights are not part of the public inputs.
+    assert!(verifying_key.weights.is_some());
+    assert_eq!(
+        proof.weights_root,
+        verifying_key.weights.as_ref().map(|w| w.root)
+    );
+    assert!(proof
+        .public_io
+        .inputs
+        .iter()
+        .all(|input| input.tensor_id != weights_id));
+
+    let proof_bytes = proof.to_bincode().expect("Proof serialization failed");
+    let load_proof =
+        || LuminairProof::from_bincode(&proof_bytes).expect("Proof deserialization failed");
+
+    verify(
+        load_proof(),
+        &verifying_key,
+        &[(output_id, vec![1.0, 4.0, 9.0, 16.0])],
+    )
+    .expect("Proof verification failed");
+
+    // A model with other weights has another verifying key.
+    let (_, verifying_key_other, _, _) = prove_with_committed_weights(vec![1.0, 2.0, 3.0, 5.0]);
+    match verify(load_proof(), &verifying_key_other, &[]) {
+        Err(LuminairError::VerifyingKeyMismatch) => {}
+        other => panic!("Expected VerifyingKeyMismatch error, got {other:?}"),
+    }
+
+    // The weights cannot be opened from another root.
+    let mut tampered = load_proof();
+    tampered.weights_root = verifying_key_other.weights.map(|w| w.root);
+    match verify(tampered, &verifying_key, &[]) {
+        Err(LuminairError::WeightsCommitmentMismatch) => {}
+        other => panic!("Expected WeightsCommitmentMismatch error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_committed_weights_blinding() {
+    let mut cx = Graph::new();
+    let x = cx.tensor((2, 2)).set(vec![1.0, 2.0, 3.0, 4.0]);
+    let mut w = cx.tensor((2, 2)).set(vec![1.0, 2.0, 3.0, 4.0]);
+    let mut out = (x * w).retrieve();
+
+    cx.compile(
+        <(GenericCompiler, StwoCompiler)>::default(),
+        (&mut out, &mut w),
+    );
+    let mut settings = cx.gen_circuit_settings();
+    settings.committed_weights.push(w.id.index() as u32);
+    let weights_root = |settings: &CircuitSettings| {
+        cx.gen_verifying_key(settings)
+            .expect("Verifying key generation failed")
+            .weights
+            .map(|w| w.root)
+    };
+
+    // The same salt always rebuilds the same tree.
+    assert_eq!(weights_root(&settings), weights_root(&settings.clone()));
+
+    // The same weights blinded with another salt have another root.
+    let mut other_settings = settings.clone();
+    other_settings.weights_salt[0] ^= 1;
+    assert_ne!(weights_root(&settings), weights_root(&other_settings));
+}
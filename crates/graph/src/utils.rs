@@ -1,10 +1,25 @@
 use std::any::{Any, TypeId};
 
 use crate::data::StwoData;
-use luminair_air::{preprocessed::Range, DEFAULT_FP_SCALE};
+use itertools::Itertools;
+use luminair_air::{
+    components::{
+        lookups::{
+            range_check::{limb_bits, split_limbs, RangeCheckLookup},
+            unary::{functions::ExpLookup, LutFunction, UnaryLookup},
+            ClampedInput, OutOfRangePolicy,
+        },
+        softmax::table::{SoftmaxTraceTable, N_DIFF_BITS as N_SOFTMAX_DIFF_BITS},
+        unary::{component::interpolation_max_values, table::UnaryTraceTable},
+    },
+    fixed_point::Fixed,
+    pie::NodeInfo,
+    preprocessed::Range,
+};
+use luminair_utils::LuminairError;
 use luminal::prelude::*;
-use num_traits::Zero;
-use numerair::Fixed;
+use num_traits::{One, Zero};
+use stwo_prover::core::fields::m31::BaseField;
 
 /// Generic helper function to check if a given `TypeId` corresponds to the type `T`.
 pub(crate) fn is<T: Any>(type_id: TypeId) -> bool {
@@ -17,6 +32,16 @@ pub(crate) fn get_buffer_from_tensor<'a>(tensor: &'a InputTensor) -> Option<&'a
     tensor.borrowed().downcast_ref::<StwoData>()
 }
 
+/// Returns the fixed-point scale of the first input, which operators also produce their output at.
+pub(crate) fn get_scale(inp: &[(InputTensor, ShapeTracker)]) -> u32 {
+    get_buffer_from_tensor(&inp[0].0).unwrap().scale
+}
+
+/// Returns the scale of the exact product of the first two inputs, the sum of their scales.
+pub(crate) fn get_product_scale(inp: &[(InputTensor, ShapeTracker)]) -> u32 {
+    get_scale(inp) + get_buffer_from_tensor(&inp[1].0).unwrap().scale
+}
+
 /// Retrieves a value from `StwoData` using Luminal expressions for indexing and validity.
 ///
 /// This function evaluates the `ind` expression to get the target index and the `val`
@@ -26,24 +51,257 @@ pub(crate) fn get_buffer_from_tensor<'a>(tensor: &'a InputTensor) -> Option<&'a
 /// Requires the execution `stack` for evaluating expressions.
 pub(crate) fn get_index(
     data: &StwoData,
+    exprs: &(Expression, Expression),
+    stack: &mut Vec<i64>,
+    index: usize,
+) -> Fixed {
+    match get_source_index(exprs, stack, index) {
+        Some(i) => data.values[i],
+        None => Fixed::zero(),
+    }
+}
+
+/// Returns the position in the source buffer that is read for the given logical `index`.
+///
+/// Evaluates the `val` expression to check if the access is valid and, if so, the `ind`
+/// expression to get the position. Returns `None` for invalid (padded) accesses.
+pub(crate) fn get_source_index(
     (ind, val): &(Expression, Expression),
     stack: &mut Vec<i64>,
     index: usize,
-) -> Fixed<DEFAULT_FP_SCALE> {
+) -> Option<usize> {
     if val.exec_single_var_stack(index, stack) != 0 {
-        let i = ind.exec_single_var_stack(index, stack);
-        data.0[i]
+        Some(ind.exec_single_var_stack(index, stack))
     } else {
-        Fixed::zero()
+        None
     }
 }
 
+/// Returns the source buffer positions read by a view, one entry per logical element.
+///
+/// Entry `i` is the position read for logical index `i`, or `None` if that access is padded.
+pub(crate) fn get_source_indices(shape: &ShapeTracker) -> Vec<Option<usize>> {
+    let exprs = (shape.index_expression(), shape.valid_expression());
+    let mut stack: Vec<i64> = vec![];
+    (0..shape.n_elements().to_usize().unwrap())
+        .map(|i| get_source_index(&exprs, &mut stack, i))
+        .collect()
+}
+
+/// Returns the LogUp multiplicity of the output element at position `idx`.
+///
+/// Each read of the element by a consuming node is matched by one occurrence here,
+/// so that broadcast elements are emitted as many times as they are read.
+/// Final outputs get one more occurrence, consumed by the public output boundary term.
+pub(crate) fn output_mult(node_info: &NodeInfo, idx: usize) -> BaseField {
+    let reads = node_info.consumer_reads.get(idx).copied().unwrap_or(0);
+    let public_reads = u32::from(node_info.output.is_final_output);
+    BaseField::from_u32_unchecked(reads + public_reads)
+}
+
+/// Returns the LogUp multiplicity of an input read at `src_idx`.
+///
+/// Padded reads (`src_idx` is `None`) do not come from the input tensor and are not
+/// consumed from the relation. Reads of initializers are balanced by the public input
+/// boundary term.
+pub(crate) fn input_mult(src_idx: Option<usize>) -> BaseField {
+    if src_idx.is_none() {
+        BaseField::zero()
+    } else {
+        -BaseField::one()
+    }
+}
+
+/// Resolves the inputs of a function lookup against the layout of its table, and records
+/// their accesses to the table.
+///
+/// Inputs that the layout does not cover are handled by the policy of the lookup. Under
+/// `OutOfRangePolicy::Grow`, the layout is first extended to cover all of them.
+/// Returns `LuminairError::LookupOutOfRange` for the first input left unresolved.
+pub(crate) fn resolve_lookup_inputs<F: LutFunction>(
+    node_id: u32,
+    inputs: &[Fixed],
+    lookup: &mut UnaryLookup<F>,
+) -> Result<Vec<ClampedInput>, LuminairError> {
+    let (resolved, missing): (Vec<_>, Vec<_>) = inputs
+        .iter()
+        .map(|x| ClampedInput::resolve(*x, &lookup.layout, lookup.policy).ok_or(*x))
+        .partition_result();
+
+    if let Some(value) = missing.first() {
+        let error = LuminairError::LookupOutOfRange {
+            op: F::OP_NAME,
+            node_id,
+            value: value.to_f64(lookup.layout.scale),
+            layout: lookup.layout.real_ranges(),
+        };
+        if lookup.policy == OutOfRangePolicy::Grow {
+            lookup.grow(missing.iter().map(|x| x.0).collect());
+        }
+        return Err(error);
+    }
+
+    for input in &resolved {
+        // Resolved inputs are looked up at a value covered by the layout.
+        let address = lookup.layout.find_index(input.lut_input.0).unwrap();
+        lookup.multiplicities.increase_at(address);
+    }
+
+    Ok(resolved)
+}
+
+/// Records the accesses of `values` to the shared range-check table.
+///
+/// Returns `LuminairError::LookupOutOfRange` for the first value the table does not cover.
+pub(crate) fn range_check(
+    op: &'static str,
+    node_id: u32,
+    values: &[i64],
+    lookup: &mut RangeCheckLookup,
+) -> Result<(), LuminairError> {
+    for &value in values {
+        if lookup.add(value).is_none() {
+            return Err(LuminairError::LookupOutOfRange {
+                op,
+                node_id,
+                value: value as f64,
+                layout: lookup.layout.real_ranges(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Splits `value` into its low and high limbs, and records their range checks, along with
+/// their gaps to their maximal values, in the shared range-check table.
+///
+/// Returns `LuminairError::RangeCheckOverflow` if `value` lies outside `[0, 2^bits)`.
+pub(crate) fn range_check_limbs(
+    op: &'static str,
+    node_id: u32,
+    idx: usize,
+    value: i64,
+    bits: u32,
+    lookup: &mut RangeCheckLookup,
+) -> Result<[BaseField; 2], LuminairError> {
+    let [lo, hi] = split_limbs(value, bits).ok_or(LuminairError::RangeCheckOverflow {
+        op,
+        node_id,
+        idx,
+        value,
+        bits,
+    })?;
+    let (lo_bits, hi_bits) = limb_bits(bits);
+    let values = [lo, (1 << lo_bits) - 1 - lo, hi, (1 << hi_bits) - 1 - hi];
+    range_check(op, node_id, &values, lookup)?;
+    Ok([lo, hi].map(|limb| BaseField::from_u32_unchecked(limb as u32)))
+}
+
+/// Splits `value` into its sign (1 if negative) and the limbs of its magnitude, and records
+/// the range checks of the limbs as in `range_check_limbs`.
+///
+/// Returns `LuminairError::RangeCheckOverflow` if `value` lies outside `(-2^bits, 2^bits)`.
+pub(crate) fn range_check_signed_limbs(
+    op: &'static str,
+    node_id: u32,
+    idx: usize,
+    value: i64,
+    bits: u32,
+    lookup: &mut RangeCheckLookup,
+) -> Result<(BaseField, [BaseField; 2]), LuminairError> {
+    if value.unsigned_abs() >= 1 << bits {
+        return Err(LuminairError::RangeCheckOverflow {
+            op,
+            node_id,
+            idx,
+            value,
+            bits,
+        });
+    }
+    let limbs = range_check_limbs(op, node_id, idx, value.abs(), bits, lookup)?;
+    Ok((BaseField::from((value < 0) as u32), limbs))
+}
+
+/// Records the range checks of the rows of `table`, interpolated in the LUT of `F`.
+///
+/// Sends the offset, remainder and increment of each row, with their gaps to their bounds,
+/// to the shared range-check table. Rows of an exact LUT have no range checks.
+pub(crate) fn range_check_interpolation<F: LutFunction>(
+    table: &UnaryTraceTable,
+    lookup: &UnaryLookup<F>,
+    range_check_lookup: &mut RangeCheckLookup,
+) -> Result<(), LuminairError> {
+    if lookup.layout.interpolation.is_none() {
+        return Ok(());
+    }
+
+    let [max_offset, max_rem, max_delta] =
+        interpolation_max_values(lookup.layout.interpolation, lookup.layout.scale);
+    for row in &table.table {
+        let increment = row.out - row.base_out;
+        let values = [
+            row.offset,
+            max_offset - row.offset,
+            row.rem,
+            max_rem - row.rem,
+            max_delta + increment,
+            max_delta - increment,
+        ]
+        .map(|value| value.0 as i64);
+        range_check(F::OP_NAME, row.node_id.0, &values, range_check_lookup)?;
+    }
+    Ok(())
+}
+
+/// Records the range checks of the rows of `table`, normalized by the Softmax component.
+///
+/// Sends the interpolation values of each row, as in `range_check_interpolation`, followed by
+/// the output remainder in `[0, scale)`, the reciprocal remainder in `[0, total)`, the
+/// reciprocal itself, the output in `[0, scale]` and the limbs of the difference to the max,
+/// to the shared range-check table.
+pub(crate) fn range_check_softmax(
+    table: &SoftmaxTraceTable,
+    lookup: &ExpLookup,
+    range_check_lookup: &mut RangeCheckLookup,
+) -> Result<(), LuminairError> {
+    let [max_offset, max_rem, max_delta] =
+        interpolation_max_values(lookup.layout.interpolation, lookup.layout.scale);
+    let (lo_bits, hi_bits) = limb_bits(N_SOFTMAX_DIFF_BITS);
+    for row in &table.table {
+        let increment = row.exp - row.base_exp;
+        let [lo, hi] = row.diff_limbs.map(|limb| limb.0 as i64);
+        let values = [
+            row.offset,
+            max_offset - row.offset,
+            row.exp_rem,
+            max_rem - row.exp_rem,
+            max_delta + increment,
+            max_delta - increment,
+            row.out_rem,
+            row.scale - BaseField::one() - row.out_rem,
+            row.recip_rem,
+            row.total - BaseField::one() - row.recip_rem,
+            row.recip,
+            row.out,
+            row.scale - row.out,
+        ]
+        .map(|value| value.0 as i64);
+        range_check("Softmax", row.node_id.0, &values, range_check_lookup)?;
+        let limbs = [lo, (1 << lo_bits) - 1 - lo, hi, (1 << hi_bits) - 1 - hi];
+        range_check("Softmax", row.node_id.0, &limbs, range_check_lookup)?;
+    }
+    Ok(())
+}
+
 /// Computes the combined value range across multiple source tensors, adding padding.
 ///
 /// Iterates through the provided source tensors (`srcs`), extracts their `StwoData`,
 /// finds the overall minimum and maximum values, and then applies padding using `buffer_range`.
 /// This is used to determine the necessary range for lookup tables.
-pub(crate) fn compute_padded_range_from_srcs(srcs: &Vec<(InputTensor<'_>, ShapeTracker)>) -> Range {
+pub(crate) fn compute_padded_range_from_srcs(
+    srcs: &Vec<(InputTensor<'_>, ShapeTracker)>,
+    margin: f64,
+) -> Range {
     let mut min = Fixed(i64::MAX);
     let mut max = Fixed(i64::MIN);
 
@@ -60,25 +318,24 @@ pub(crate) fn compute_padded_range_from_srcs(srcs: &Vec<(InputTensor<'_>, ShapeT
         }
     }
 
-    buffer_range(Range(min, max))
+    buffer_range(Range(min, max), margin)
 }
 
-/// Expands a `Range` by a fixed percentage margin (currently 10%) on both ends.
+/// Expands a `Range` by a `margin` fraction of its span on both ends.
 ///
 /// This buffering helps ensure that lookup tables constructed based on observed ranges
 /// during `gen_circuit_settings` can accommodate potential minor variations in values
 /// encountered during actual trace generation.
-fn buffer_range(range: Range) -> Range {
-    // TODO (@raphaelDkhn): make it parametizeable maybe.
-    const RANGE_MARGIN: f64 = 0.10;
-
-    let min = range.0.to_f64();
-    let max = range.1.to_f64();
+fn buffer_range(range: Range, margin: f64) -> Range {
+    // The margin is relative to the span, so it can be applied to the raw values
+    // whatever their fixed-point scale.
+    let min = range.0 .0 as f64;
+    let max = range.1 .0 as f64;
     let span = max - min;
 
-    let delta = span * RANGE_MARGIN;
-    let low = Fixed::from_f64(min - delta);
-    let high = Fixed::from_f64(max + delta);
+    let delta = span * margin;
+    let low = Fixed((min - delta).round() as i64);
+    let high = Fixed((max + delta).round() as i64);
 
     Range(low, high)
 }
@@ -4,11 +4,14 @@ pub use luminal_nn::*;
 
 // --- luminair_graph ---
 pub use luminair_graph::graph::LuminairGraph;
+pub use luminair_graph::settings::{CalibrationSample, CircuitSettingsBuilder};
 pub use luminair_graph::StwoCompiler;
 
 // --- luminair_prover ---
 pub use luminair_prover::{prover::prove, LuminairProof};
 
 // --- luminair_verifier ---
-pub use luminair_air::{pie::LuminairPie, settings::CircuitSettings};
+pub use luminair_air::{
+    components::lookups::OutOfRangePolicy, pie::LuminairPie, settings::CircuitSettings,
+};
 pub use luminair_verifier::verifier::verify;
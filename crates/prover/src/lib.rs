@@ -1,12 +1,12 @@
 use ::serde::{Deserialize, Serialize};
-use luminair_air::{LuminairClaim, LuminairInteractionClaim};
+use luminair_air::{public_io::PublicIo, LuminairClaim, LuminairInteractionClaim};
 use luminair_utils::LuminairError;
 use std::{
     fs::File,
     io::{BufReader, BufWriter, Write},
     path::Path,
 };
-use stwo_prover::core::vcs::blake2_merkle::Blake2sMerkleHasher;
+use stwo_prover::core::vcs::{blake2_hash::Blake2sHash, blake2_merkle::Blake2sMerkleHasher};
 use stwo_prover::core::{prover::StarkProof, vcs::ops::MerkleHasher};
 
 pub mod prover;
@@ -14,7 +14,8 @@ pub mod prover;
 /// Represents the complete proof for a LuminAIR computation.
 ///
 /// This structure bundles the core STARK proof generated by the STWO prover
-/// along with the associated claims for both the main execution trace and the interaction trace phases.
+/// along with the associated claims for both the main execution trace and the interaction trace phases,
+/// and the public inputs and outputs of the computation.
 /// The claims contain commitments and other data necessary for the verifier.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct LuminairProof<H: MerkleHasher> {
@@ -22,6 +23,12 @@ pub struct LuminairProof<H: MerkleHasher> {
     pub claim: LuminairClaim,
     /// Claims associated with the interaction trace components (e.g., accumulated LogUp values).
     pub interaction_claim: LuminairInteractionClaim,
+    /// The public inputs and outputs the proof is bound to.
+    pub public_io: PublicIo,
+    /// Digest of the verifying key the proof is bound to.
+    pub verifying_key_digest: Blake2sHash,
+    /// Root of the committed weights tree, if the graph has committed weights.
+    pub weights_root: Option<Blake2sHash>,
     /// The cryptographic proof generated by the STWO STARK prover.
     pub proof: StarkProof<H>,
 }
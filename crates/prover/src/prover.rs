@@ -1,11 +1,17 @@
 use luminair_air::{
     components::{
-        add, lookups, max_reduce, mul, recip, sin, sqrt, sum_reduce, LuminairComponents,
-        LuminairInteractionElements,
+        add, conv2d, less_than, lookups, matmul, max_reduce, modulo, mul, norm, recip, relu,
+        rescale, softmax, sqrt, sum_reduce,
+        weights::{self, WEIGHTS_TRACE_IDX},
+        LuminairComponents, LuminairInteractionElements,
     },
     pie::{LuminairPie, Metadata, TraceTable},
-    preprocessed::{lookups_to_preprocessed_column, PreProcessedTrace, SinPreProcessed},
+    preprocessed::{
+        lookups_to_preprocessed_column, trace_tables_to_preprocessed_column, PreProcessedTrace,
+        RangeCheckPreProcessed,
+    },
     settings::CircuitSettings,
+    verifying_key::mix_digest,
     LuminairClaim, LuminairInteractionClaim, LuminairInteractionClaimGenerator,
 };
 use luminair_utils::LuminairError;
@@ -25,10 +31,12 @@ use crate::LuminairProof;
 /// Takes the `LuminairPie` (containing execution traces) and `CircuitSettings`.
 /// It orchestrates the STWO proving protocol:
 /// 1. Sets up the prover, channel, and commitment scheme.
-/// 2. Commits to the preprocessed trace.
-/// 3. Commits to the main execution trace components (add, mul, sin, etc.).
+/// 2. Commits to the preprocessed trace: the lookup tables and the structure of the traces.
+/// 3. Mixes the verifying key digest and the public inputs and outputs into the channel,
+///    and commits to the main execution trace components (add, mul, sin, etc.).
 /// 4. Commits to the interaction trace.
-/// 5. Executes the Stwo prover.
+/// 5. Commits to the weights tree, if the graph has committed weights.
+/// 6. Executes the Stwo prover.
 /// Returns a `LuminairProof` containing the claims and the STARK proof.
 pub fn prove(
     pie: LuminairPie,
@@ -55,9 +63,10 @@ pub fn prove(
     // └───────────────────────────────────────────────┘
 
     tracing::info!("Preprocessed Trace");
-    // Convert lookups in circuit settings to preprocessed column.
-    let lut_cols = lookups_to_preprocessed_column(&settings.lookups);
-    let preprocessed_trace = PreProcessedTrace::new(lut_cols);
+    // Convert lookups in circuit settings and the structure of the traces to preprocessed columns.
+    let mut preprocessed_cols = lookups_to_preprocessed_column(&settings.lookups);
+    preprocessed_cols.extend(trace_tables_to_preprocessed_column(&pie.trace_tables));
+    let preprocessed_trace = PreProcessedTrace::new(preprocessed_cols);
     let mut tree_builder = commitment_scheme.tree_builder();
     tree_builder.extend_evals(preprocessed_trace.gen_trace());
     // Commit the preprocessed trace
@@ -70,6 +79,7 @@ pub fn prove(
     tracing::info!("Main Trace");
     let mut main_claim = LuminairClaim::default();
     let mut interaction_claim_gen = LuminairInteractionClaimGenerator::default();
+    let mut weights_table = None;
     let mut tree_builder = commitment_scheme.tree_builder();
 
     for table in pie.trace_tables.clone() {
@@ -87,29 +97,23 @@ pub fn prove(
                 interaction_claim_gen.mul = Some(in_cl_gen);
             }
             TraceTable::Recip { table } => {
-                let claim_gen = recip::witness::ClaimGenerator::new(table);
+                let claim_gen = recip::witness::ClaimGenerator::new(table, settings.fp_scale);
                 let (cl, in_cl_gen) = claim_gen.write_trace(&mut tree_builder)?;
                 main_claim.recip = Some(cl.clone());
                 interaction_claim_gen.recip = Some(in_cl_gen);
             }
-            TraceTable::Sin { table } => {
-                let claim_gen = sin::witness::ClaimGenerator::new(table);
-                let (cl, in_cl_gen) = claim_gen.write_trace(&mut tree_builder)?;
-                main_claim.sin = Some(cl.clone());
-                interaction_claim_gen.sin = Some(in_cl_gen);
-            }
-            TraceTable::SinLookup { table } => {
-                let claim_gen = lookups::sin::witness::ClaimGenerator::new(table);
-                let (cl, in_cl_gen) = claim_gen.write_trace(&mut tree_builder)?;
-                main_claim.sin_lookup = Some(cl.clone());
-                interaction_claim_gen.sin_lookup = Some(in_cl_gen);
-            }
             TraceTable::SumReduce { table } => {
                 let claim_gen = sum_reduce::witness::ClaimGenerator::new(table);
                 let (cl, in_cl_gen) = claim_gen.write_trace(&mut tree_builder)?;
                 main_claim.sum_reduce = Some(cl.clone());
                 interaction_claim_gen.sum_reduce = Some(in_cl_gen);
             }
+            TraceTable::MatMul { table } => {
+                let claim_gen = matmul::witness::ClaimGenerator::new(table);
+                let (cl, in_cl_gen) = claim_gen.write_trace(&mut tree_builder)?;
+                main_claim.matmul = Some(cl.clone());
+                interaction_claim_gen.matmul = Some(in_cl_gen);
+            }
             TraceTable::MaxReduce { table } => {
                 let claim_gen = max_reduce::witness::ClaimGenerator::new(table);
                 let (cl, in_cl_gen) = claim_gen.write_trace(&mut tree_builder)?;
@@ -122,9 +126,73 @@ pub fn prove(
                 main_claim.sqrt = Some(cl.clone());
                 interaction_claim_gen.sqrt = Some(in_cl_gen);
             }
+            TraceTable::LessThan { table } => {
+                let claim_gen = less_than::witness::ClaimGenerator::new(table);
+                let (cl, in_cl_gen) = claim_gen.write_trace(&mut tree_builder)?;
+                main_claim.less_than = Some(cl.clone());
+                interaction_claim_gen.less_than = Some(in_cl_gen);
+            }
+            TraceTable::Lut { table } => table.write_trace(
+                &mut tree_builder,
+                &settings.lookups,
+                &mut main_claim.luts,
+                &mut interaction_claim_gen.luts,
+            )?,
+            TraceTable::Softmax { table } => {
+                let layout = &settings.lookups.exp.as_ref().unwrap().layout;
+                let claim_gen = softmax::witness::ClaimGenerator::new(table, layout);
+                let (cl, in_cl_gen) = claim_gen.write_trace(&mut tree_builder)?;
+                main_claim.softmax = Some(cl.clone());
+                interaction_claim_gen.softmax = Some(in_cl_gen);
+            }
+            TraceTable::Norm { table } => {
+                let claim_gen = norm::witness::ClaimGenerator::new(table, settings.fp_scale);
+                let (cl, in_cl_gen) = claim_gen.write_trace(&mut tree_builder)?;
+                main_claim.norm = Some(cl.clone());
+                interaction_claim_gen.norm = Some(in_cl_gen);
+            }
+            TraceTable::Conv2d { table } => {
+                let claim_gen = conv2d::witness::ClaimGenerator::new(table);
+                let (cl, in_cl_gen) = claim_gen.write_trace(&mut tree_builder)?;
+                main_claim.conv2d = Some(cl.clone());
+                interaction_claim_gen.conv2d = Some(in_cl_gen);
+            }
+            TraceTable::Relu { table } => {
+                let claim_gen = relu::witness::ClaimGenerator::new(table);
+                let (cl, in_cl_gen) = claim_gen.write_trace(&mut tree_builder)?;
+                main_claim.relu = Some(cl.clone());
+                interaction_claim_gen.relu = Some(in_cl_gen);
+            }
+            TraceTable::Mod { table } => {
+                let claim_gen = modulo::witness::ClaimGenerator::new(table);
+                let (cl, in_cl_gen) = claim_gen.write_trace(&mut tree_builder)?;
+                main_claim.modulo = Some(cl.clone());
+                interaction_claim_gen.modulo = Some(in_cl_gen);
+            }
+            TraceTable::Rescale { table } => {
+                let claim_gen = rescale::witness::ClaimGenerator::new(table, settings.fp_scale);
+                let (cl, in_cl_gen) = claim_gen.write_trace(&mut tree_builder)?;
+                main_claim.rescale = Some(cl.clone());
+                interaction_claim_gen.rescale = Some(in_cl_gen);
+            }
+            TraceTable::RangeCheckLookup { table } => {
+                let claim_gen = lookups::range_check::witness::ClaimGenerator::new(table);
+                let (cl, in_cl_gen) = claim_gen.write_trace(&mut tree_builder)?;
+                main_claim.range_check_lookup = Some(cl.clone());
+                interaction_claim_gen.range_check_lookup = Some(in_cl_gen);
+            }
+            TraceTable::Weights { table } => {
+                weights_table = Some(table.clone());
+                let claim_gen = weights::witness::ClaimGenerator::new(table);
+                let (cl, in_cl_gen) = claim_gen.write_trace(&mut tree_builder)?;
+                main_claim.weights = Some(cl.clone());
+                interaction_claim_gen.weights = Some(in_cl_gen);
+            }
         }
     }
-    // Mix the claim into the Fiat-Shamir channel.
+    // Mix the verifying key, the public inputs/outputs and the claim into the Fiat-Shamir channel.
+    mix_digest(pie.verifying_key_digest, channel);
+    pie.public_io.mix_into(channel);
     main_claim.mix_into(channel);
     // Commit the main trace.
     tree_builder.commit(channel);
@@ -148,42 +216,131 @@ pub fn prove(
         interaction_claim.mul = Some(claim)
     }
     if let Some(claim_gen) = interaction_claim_gen.recip {
-        let claim = claim_gen.write_interaction_trace(&mut tree_builder, node_elements);
-        interaction_claim.recip = Some(claim)
-    }
-    if let Some(claim_gen) = interaction_claim_gen.sin {
         let claim = claim_gen.write_interaction_trace(
             &mut tree_builder,
             node_elements,
-            &lookup_elements.sin,
+            &lookup_elements.range_check,
         );
-        interaction_claim.sin = Some(claim)
-    }
-    if let Some(claim_gen) = interaction_claim_gen.sin_lookup {
-        let mut sin_luts = preprocessed_trace.columns_of::<SinPreProcessed>();
-        sin_luts.sort_by_key(|c| c.col_index);
-
-        let claim =
-            claim_gen.write_interaction_trace(&mut tree_builder, &lookup_elements.sin, &sin_luts);
-        interaction_claim.sin_lookup = Some(claim)
+        interaction_claim.recip = Some(claim)
     }
     if let Some(claim_gen) = interaction_claim_gen.sum_reduce {
         let claim = claim_gen.write_interaction_trace(&mut tree_builder, node_elements);
         interaction_claim.sum_reduce = Some(claim)
     }
-    if let Some(claim_gen) = interaction_claim_gen.max_reduce {
+    if let Some(claim_gen) = interaction_claim_gen.matmul {
         let claim = claim_gen.write_interaction_trace(&mut tree_builder, node_elements);
+        interaction_claim.matmul = Some(claim)
+    }
+    if let Some(claim_gen) = interaction_claim_gen.max_reduce {
+        let claim = claim_gen.write_interaction_trace(
+            &mut tree_builder,
+            node_elements,
+            &lookup_elements.range_check,
+        );
         interaction_claim.max_reduce = Some(claim)
     }
     if let Some(claim_gen) = interaction_claim_gen.sqrt {
-        let claim = claim_gen.write_interaction_trace(&mut tree_builder, node_elements);
+        let claim = claim_gen.write_interaction_trace(
+            &mut tree_builder,
+            node_elements,
+            &lookup_elements.range_check,
+        );
         interaction_claim.sqrt = Some(claim)
     }
+    if let Some(claim_gen) = interaction_claim_gen.less_than {
+        let claim = claim_gen.write_interaction_trace(
+            &mut tree_builder,
+            node_elements,
+            &lookup_elements.range_check,
+        );
+        interaction_claim.less_than = Some(claim)
+    }
+    interaction_claim.luts = interaction_claim_gen.luts.write_interaction_trace(
+        &mut tree_builder,
+        node_elements,
+        lookup_elements,
+        &preprocessed_trace,
+    );
+    if let Some(claim_gen) = interaction_claim_gen.softmax {
+        let claim = claim_gen.write_interaction_trace(
+            &mut tree_builder,
+            node_elements,
+            &lookup_elements.exp,
+            &lookup_elements.range_check,
+        );
+        interaction_claim.softmax = Some(claim)
+    }
+    if let Some(claim_gen) = interaction_claim_gen.norm {
+        let claim = claim_gen.write_interaction_trace(
+            &mut tree_builder,
+            node_elements,
+            &lookup_elements.range_check,
+        );
+        interaction_claim.norm = Some(claim)
+    }
+    if let Some(claim_gen) = interaction_claim_gen.conv2d {
+        let claim = claim_gen.write_interaction_trace(&mut tree_builder, node_elements);
+        interaction_claim.conv2d = Some(claim)
+    }
+    if let Some(claim_gen) = interaction_claim_gen.relu {
+        let claim = claim_gen.write_interaction_trace(
+            &mut tree_builder,
+            node_elements,
+            &lookup_elements.range_check,
+        );
+        interaction_claim.relu = Some(claim)
+    }
+    if let Some(claim_gen) = interaction_claim_gen.modulo {
+        let claim = claim_gen.write_interaction_trace(
+            &mut tree_builder,
+            node_elements,
+            &lookup_elements.range_check,
+        );
+        interaction_claim.modulo = Some(claim)
+    }
+    if let Some(claim_gen) = interaction_claim_gen.rescale {
+        let claim = claim_gen.write_interaction_trace(
+            &mut tree_builder,
+            node_elements,
+            &lookup_elements.range_check,
+        );
+        interaction_claim.rescale = Some(claim)
+    }
+    if let Some(claim_gen) = interaction_claim_gen.range_check_lookup {
+        let range_check_lut = preprocessed_trace.columns_of::<RangeCheckPreProcessed>()[0];
+
+        let claim = claim_gen.write_interaction_trace(
+            &mut tree_builder,
+            &lookup_elements.range_check,
+            range_check_lut,
+        );
+        interaction_claim.range_check_lookup = Some(claim)
+    }
+    if let Some(claim_gen) = interaction_claim_gen.weights {
+        let claim = claim_gen.write_interaction_trace(&mut tree_builder, node_elements);
+        interaction_claim.weights = Some(claim)
+    }
     // Mix the interaction claim into the Fiat-Shamir channel.
     interaction_claim.mix_into(channel);
     // Commit the interaction trace.
     tree_builder.commit(channel);
 
+    // ┌───────────────────────────────────────────────┐
+    // │    Interaction Phase 3 - Committed Weights    │
+    // └───────────────────────────────────────────────┘
+
+    // The weights tree does not depend on the channel: its root is the one
+    // published in the verifying key.
+    let weights_root = if let Some(table) = weights_table {
+        tracing::info!("Committed Weights");
+        let mut tree_builder = commitment_scheme.tree_builder();
+        weights::witness::write_weights_trace(table, &mut tree_builder)?;
+        tree_builder.commit(channel);
+        Some(commitment_scheme.roots()[WEIGHTS_TRACE_IDX])
+    } else {
+        None
+    };
+
     // ┌──────────────────────────┐
     // │     Proof Generation     │
     // └──────────────────────────┘
@@ -194,6 +351,7 @@ pub fn prove(
         &interaction_claim,
         &preprocessed_trace,
         &settings.lookups,
+        settings.fp_scale,
     );
     let components = component_builder.provers();
     let proof = prover::prove::<SimdBackend, _>(&components, channel, commitment_scheme)?;
@@ -202,6 +360,9 @@ pub fn prove(
         LuminairProof {
             claim: main_claim,
             interaction_claim,
+            public_io: pie.public_io,
+            verifying_key_digest: pie.verifying_key_digest,
+            weights_root,
             proof,
         },
         Metadata {
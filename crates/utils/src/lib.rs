@@ -24,6 +24,64 @@ pub enum LuminairError {
 
     #[error("Serialization error: {0}")]
     SerializationError(String),
+
+    #[error("Log2 input must be strictly positive, got {value} at index {idx} of node {node_id}.")]
+    NonPositiveLog2Input {
+        node_id: u32,
+        idx: usize,
+        value: f64,
+    },
+
+    #[error("Mod divisor must be non-zero, got zero at index {idx} of node {node_id}.")]
+    ModByZero { node_id: u32, idx: usize },
+
+    #[error("Fixed-point value {value} at index {idx} of node {node_id} overflows the M31 range.")]
+    FixedPointOverflow {
+        node_id: u32,
+        idx: usize,
+        value: f64,
+    },
+
+    #[error("{op} input {value} of node {node_id} is not covered by the lookup ranges {layout:?}.")]
+    LookupOutOfRange {
+        op: &'static str,
+        node_id: u32,
+        value: f64,
+        layout: Vec<(f64, f64)>,
+    },
+
+    #[error("{op} value {value} at index {idx} of node {node_id} does not fit in its {bits} range-checked bits.")]
+    RangeCheckOverflow {
+        op: &'static str,
+        node_id: u32,
+        idx: usize,
+        value: i64,
+        bits: u32,
+    },
+
+    #[error("No public output found for tensor {tensor_id}.")]
+    MissingPublicOutput { tensor_id: u32 },
+
+    #[error("Public output of tensor {tensor_id} does not match the expected values.")]
+    PublicOutputMismatch { tensor_id: u32 },
+
+    #[error("The public inputs and outputs of the proof do not match the verifying key.")]
+    PublicIoMismatch,
+
+    #[error("The claim of the proof does not match the verifying key.")]
+    ClaimMismatch,
+
+    #[error("The preprocessed trace of the proof does not match the verifying key.")]
+    PreprocessedTraceMismatch,
+
+    #[error("The proof was not generated for this verifying key.")]
+    VerifyingKeyMismatch,
+
+    #[error("The proof does not open the weights committed in the verifying key.")]
+    WeightsCommitmentMismatch,
+
+    #[error("Fixed-point scale {scale} is not supported, the maximum is {max}.")]
+    UnsupportedFixedPointScale { scale: u32, max: u32 },
 }
 
 /// Errors that can occur during AIR trace generation or processing.
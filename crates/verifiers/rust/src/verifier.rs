@@ -1,8 +1,11 @@
 use luminair_air::{
-    components::{LuminairComponents, LuminairInteractionElements},
-    preprocessed::{lookups_to_preprocessed_column, PreProcessedTrace},
-    settings::CircuitSettings,
+    components::{weights::WEIGHTS_TRACE_IDX, LuminairComponents, LuminairInteractionElements},
+    preprocessed::{
+        claim_to_preprocessed_column, lookups_to_preprocessed_column, PreProcessedTrace,
+    },
     utils::log_sum_valid,
+    verifying_key::VerifyingKey,
+    MAX_FP_SCALE,
 };
 use luminair_prover::LuminairProof;
 use luminair_utils::LuminairError;
@@ -20,28 +23,80 @@ use stwo_prover::{
 
 /// Verifies a STWO proof.
 ///
-/// Takes a `LuminairProof` and `CircuitSettings` as input.
+/// Takes a `LuminairProof`, the `VerifyingKey` of the published graph and the expected outputs as input.
+/// Each expected output is given as the ID of the retrieved tensor and its values.
+/// The lookup tables and the structure of the traces are rebuilt from the verifying key rather
+/// than taken from the prover.
 /// It orchestrates the STWO verification protocol:
-/// 1. Sets up the verifier, channel, and commitment scheme.
-/// 2. Reads commitments for preprocessed, main, and interaction traces from the proof.
-/// 3. Derives interaction elements using Fiat-Shamir.
-/// 4. Constructs the AIR components (constraints) based on the claims and interaction elements.
-/// 5. Verifies the STARK proof.
+/// 1. Checks that the proof was generated for the verifying key, that its claim is the one of
+///    the key, that the fixed-point scale of the key is supported, rebuilds the public inputs
+///    and outputs of the proof from the layout of the key, checks that the proof opens its
+///    committed weights, checks the public outputs of the proof against the expected ones,
+///    and checks that the proof commits to the preprocessed trace of the key.
+/// 2. Sets up the verifier, channel, and commitment scheme.
+/// 3. Commits to the preprocessed trace of the verifying key, mixes the verifying key and the
+///    public inputs and outputs into the channel, then reads commitments for main and
+///    interaction traces from the proof.
+/// 4. Derives interaction elements using Fiat-Shamir, and commits to the weights tree of the
+///    verifying key, if any.
+/// 5. Constructs the AIR components (constraints) based on the claims, the interaction elements
+///    and the fixed-point scale of the verifying key.
+/// 6. Verifies the STARK proof.
 /// Returns `Ok(())` if the proof is valid, otherwise returns a `LuminairError`.
 pub fn verify(
     LuminairProof {
         claim,
         interaction_claim,
+        public_io,
+        verifying_key_digest,
+        weights_root,
         proof,
     }: LuminairProof<Blake2sMerkleHasher>,
-    settings: CircuitSettings,
+    verifying_key: &VerifyingKey,
+    expected_outputs: &[(u32, Vec<f32>)],
 ) -> Result<(), LuminairError> {
     let _span = span!(Level::INFO, "luminair_verification").entered();
     info!("🚀 Starting LuminAIR proof verification");
 
-    // Convert lookups in circuit settings to preprocessed column.
-    let lut_cols = lookups_to_preprocessed_column(&settings.lookups);
-    let preprocessed_trace = PreProcessedTrace::new(lut_cols);
+    // Check that the proof was generated for this verifying key.
+    if verifying_key_digest != verifying_key.digest() {
+        return Err(LuminairError::VerifyingKeyMismatch);
+    }
+
+    // Check that the proof has the components of the verifying key, with their sizes.
+    if claim != verifying_key.claim {
+        return Err(LuminairError::ClaimMismatch);
+    }
+
+    // Check that the fixed-point scale of the verifying key is supported.
+    let fp_scale = verifying_key.fp_scale;
+    if fp_scale > MAX_FP_SCALE {
+        return Err(LuminairError::UnsupportedFixedPointScale {
+            scale: fp_scale,
+            max: MAX_FP_SCALE,
+        });
+    }
+
+    // Rebuild the public inputs and outputs from the layout of the verifying key,
+    // keeping only their values from the proof.
+    let public_io = verifying_key.public_io(&public_io)?;
+
+    // Check that the proof opens the weights committed in the verifying key.
+    verifying_key.check_weights(claim.weights.as_ref(), weights_root, &public_io)?;
+
+    // Check the public outputs against the expected ones, at the scale of the verifying key.
+    public_io.check_outputs(expected_outputs, fp_scale)?;
+
+    // Check that the proof commits to the preprocessed trace of the verifying key.
+    if proof.commitments[PREPROCESSED_TRACE_IDX] != verifying_key.preprocessed_root {
+        return Err(LuminairError::PreprocessedTraceMismatch);
+    }
+
+    // Convert lookups and the claim in the verifying key to preprocessed column.
+    let lookups = verifying_key.lookups.to_lookups();
+    let mut preprocessed_cols = lookups_to_preprocessed_column(&lookups);
+    preprocessed_cols.extend(claim_to_preprocessed_column(&claim));
+    let preprocessed_trace = PreProcessedTrace::new(preprocessed_cols);
 
     // ┌──────────────────────────┐
     // │     Protocol Setup       │
@@ -69,7 +124,7 @@ pub fn verify(
             info!("🔄 Interaction Phase 0: Processing preprocessed trace");
             
             commitment_scheme_verifier.commit(
-                proof.commitments[PREPROCESSED_TRACE_IDX],
+                verifying_key.preprocessed_root,
                 &log_sizes[PREPROCESSED_TRACE_IDX],
                 channel,
             );
@@ -84,6 +139,8 @@ pub fn verify(
             let _span = span!(Level::INFO, "interaction_phase_1").entered();
             info!("🔄 Interaction Phase 1: Processing main trace");
             
+            verifying_key.mix_into(channel);
+            public_io.mix_into(channel);
             claim.mix_into(channel);
             commitment_scheme_verifier.commit(
                 proof.commitments[ORIGINAL_TRACE_IDX],
@@ -104,7 +161,11 @@ pub fn verify(
             let interaction_elements = LuminairInteractionElements::draw(channel);
 
             // Validate LogUp sum
-            if !log_sum_valid(&interaction_claim) {
+            if !log_sum_valid(
+                &interaction_claim,
+                &public_io,
+                &interaction_elements.node_elements,
+            ) {
                 return Err(LuminairError::InvalidLogUp("Invalid LogUp sum".to_string()));
             }
 
@@ -117,6 +178,17 @@ pub fn verify(
             
             info!("✅ Interaction Phase 2: Interaction trace committed");
 
+            // ┌───────────────────────────────────────────────┐
+            // │    Interaction Phase 3 - Committed Weights    │
+            // └───────────────────────────────────────────────┘
+            if let Some(root) = weights_root {
+                info!("🔄 Interaction Phase 3: Processing committed weights");
+
+                commitment_scheme_verifier.commit(root, &log_sizes[WEIGHTS_TRACE_IDX], channel);
+
+                info!("✅ Interaction Phase 3: Committed weights committed");
+            }
+
             // ┌──────────────────────────┐
             // │    Proof Verification    │
             // └──────────────────────────┘
@@ -129,7 +201,8 @@ pub fn verify(
                     &interaction_elements,
                     &interaction_claim,
                     &preprocessed_trace,
-                    &settings.lookups,
+                    &lookups,
+                    fp_scale,
                 );
                 let components = component_builder.components();
 
@@ -21,12 +21,12 @@ export default init;
 /**
  * Verifies a LuminAIR proof from binary data
  * @param proofBytes - Binary proof data as Uint8Array
- * @param settingsBytes - Binary settings data as Uint8Array
+ * @param verifyingKeyBytes - Binary verifying key data as Uint8Array
  * @returns Verification result
  */
 export function verify(
   proofBytes: Uint8Array,
-  settingsBytes: Uint8Array
+  verifyingKeyBytes: Uint8Array
 ): VerificationResult;
 
 /**
@@ -1,4 +1,4 @@
-use luminair_air::settings::CircuitSettings;
+use luminair_air::verifying_key::VerifyingKey;
 use luminair_prover::LuminairProof;
 use luminair_verifier::verifier::verify as verify_rust;
 use stwo_prover::core::vcs::blake2_merkle::Blake2sMerkleHasher;
@@ -30,10 +30,10 @@ impl VerificationResult {
 
 /// Verifies a LuminAIR proof in WASM.
 ///
-/// Takes binary data for both `LuminairProof` and `CircuitSettings` and returns a verification result.
+/// Takes binary data for both `LuminairProof` and `VerifyingKey` and returns a verification result.
 /// This is the main entry point for WASM-based proof verification.
 #[wasm_bindgen]
-pub fn verify(proof_bytes: &[u8], settings_bytes: &[u8]) -> VerificationResult {
+pub fn verify(proof_bytes: &[u8], verifying_key_bytes: &[u8]) -> VerificationResult {
     let _span = span!(Level::INFO, "wasm_verification_wrapper").entered();
     console_info("🌟 Starting WASM proof verification from binary...");
     info!("🌟 LuminAIR WASM Verifier: Beginning verification process");
@@ -56,17 +56,17 @@ pub fn verify(proof_bytes: &[u8], settings_bytes: &[u8]) -> VerificationResult {
         }
     };
 
-    // Parse the settings from bincode
-    let settings: CircuitSettings = match bincode::deserialize(settings_bytes) {
-        Ok(settings) => {
-            console_info("✅ Successfully parsed settings binary");
-            info!("⚙️  Settings parsing: Success");
-            settings
+    // Parse the verifying key from bincode
+    let verifying_key: VerifyingKey = match bincode::deserialize(verifying_key_bytes) {
+        Ok(verifying_key) => {
+            console_info("✅ Successfully parsed verifying key binary");
+            info!("🔑 Verifying key parsing: Success");
+            verifying_key
         },
         Err(e) => {
-            let error_msg = format!("Failed to parse settings binary: {}", e);
+            let error_msg = format!("Failed to parse verifying key binary: {}", e);
             console_error(&error_msg);
-            info!("❌ Settings parsing: Failed - {}", e);
+            info!("❌ Verifying key parsing: Failed - {}", e);
             return VerificationResult {
                 success: false,
                 error_message: Some(error_msg),
@@ -77,8 +77,9 @@ pub fn verify(proof_bytes: &[u8], settings_bytes: &[u8]) -> VerificationResult {
     console_info("🔍 Delegating to Rust verifier with detailed tracing...");
     info!("🔍 Delegating to Rust verifier core");
 
-    // Perform verification
-    match verify_rust(proof, settings) {
+    // Perform verification. The public inputs and outputs are bound to the proof,
+    // but no expected outputs are checked here.
+    match verify_rust(proof, &verifying_key, &[]) {
         Ok(()) => {
             console_info("🎉 Proof verification successful! ✅");
             info!("🎉 LuminAIR WASM Verifier: Verification completed successfully");
@@ -23,6 +23,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut settings = cx.gen_circuit_settings();
     println!("Settings generated successfully. ✅");
 
+    // ======= Generate verifying key =======
+    println!("Generating verifying key...");
+    let verifying_key = cx.gen_verifying_key(&settings)?;
+    println!("Verifying key generated successfully. ✅");
+
     // ======= Execute graph & generate trace =======
     println!("Executing graph and generating execution trace...");
     let trace = cx.gen_trace(&mut settings)?;
@@ -35,10 +40,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Proof generated successfully. ✅");
 
     settings.to_bincode_file("./settings.bin")?;
+    verifying_key.to_bincode_file("./verifying_key.bin")?;
     proof.to_bincode_file("./proof.bin")?;
 
     println!("Verifying proof...");
-    verify(proof, settings)?;
+    verify(proof, &verifying_key, &[(e.id.index() as u32, e.data())])?;
     println!("Proof verified successfully. Computation integrity ensured. 🎉");
 
     Ok(())
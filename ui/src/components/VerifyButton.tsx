@@ -85,8 +85,8 @@ interface VerificationState {
 export interface VerifyButtonProps {
   /** Path to the proof file (required) */
   proofPath: string;
-  /** Path to the settings file (required) */
-  settingsPath: string;
+  /** Path to the verifying key file (required) */
+  verifyingKeyPath: string;
   /** Title displayed in the modal (default: "Can't be evil.") */
   title?: string;
   /** Text displayed on the button (default: "VERIFY") */
@@ -103,7 +103,7 @@ export interface VerifyButtonProps {
 
 export function VerifyButton({
   proofPath,
-  settingsPath,
+  verifyingKeyPath,
   title = "Can't be evil.",
   buttonText = "VERIFY",
   author = "Giza",
@@ -261,19 +261,19 @@ export function VerifyButton({
     try {
       await init();
 
-      // Fetch proof and settings
+      // Fetch proof and verifying key
       const proofResp = await fetch(proofPath);
-      const settingsResp = await fetch(settingsPath);
+      const verifyingKeyResp = await fetch(verifyingKeyPath);
 
-      if (!proofResp.ok || !settingsResp.ok) {
-        throw new Error("Could not load proof or settings files");
+      if (!proofResp.ok || !verifyingKeyResp.ok) {
+        throw new Error("Could not load proof or verifying key files");
       }
 
       const proofBytes = new Uint8Array(await proofResp.arrayBuffer());
-      const settingsBytes = new Uint8Array(await settingsResp.arrayBuffer());
+      const verifyingKeyBytes = new Uint8Array(await verifyingKeyResp.arrayBuffer());
 
       // Run verification - this will output the console logs we're monitoring
-      const result = verify(proofBytes, settingsBytes);
+      const result = verify(proofBytes, verifyingKeyBytes);
 
       setState((prev) => ({
         ...prev,
@@ -518,9 +518,9 @@ export function VerifyButton({
                       const zip = new JSZip();
 
                       // Fetch the files
-                      const [proofResp, settingsResp] = await Promise.all([
+                      const [proofResp, verifyingKeyResp] = await Promise.all([
                         fetch(proofPath),
-                        fetch(settingsPath),
+                        fetch(verifyingKeyPath),
                       ]);
 
                       if (proofResp.ok) {
@@ -528,9 +528,9 @@ export function VerifyButton({
                         zip.file("proof.bin", proofBlob);
                       }
 
-                      if (settingsResp.ok) {
-                        const settingsBlob = await settingsResp.blob();
-                        zip.file("settings.bin", settingsBlob);
+                      if (verifyingKeyResp.ok) {
+                        const verifyingKeyBlob = await verifyingKeyResp.blob();
+                        zip.file("verifying_key.bin", verifyingKeyBlob);
                       }
 
                       // Generate and download the zip